@@ -0,0 +1,36 @@
+//! Parser and render benchmarks on fixture inputs
+//! `cargo bench` compares against the previous run, so a regression shows up
+//! as a change in the report
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use tachi_fetch::bench;
+
+static MEMINFO: &[u8] = include_bytes!("fixtures/meminfo");
+static EDID: &[u8] = include_bytes!("fixtures/edid.bin");
+
+fn parsers(c: &mut Criterion) {
+    c.bench_function("meminfo", |b| {
+        b.iter(|| bench::parse_meminfo(black_box(MEMINFO)));
+    });
+    c.bench_function("edid", |b| b.iter(|| bench::parse_edid(black_box(EDID))));
+    c.bench_function("logo lookup", |b| {
+        b.iter(|| bench::logo_width(black_box("Linux Mint")));
+    });
+
+    let colored = String::from_utf8(bench::render("Arch", true)).unwrap();
+    c.bench_function("strip ansi", |b| {
+        b.iter(|| bench::strip_ansi(black_box(&colored)));
+    });
+}
+
+fn render(c: &mut Criterion) {
+    c.bench_function("render", |b| {
+        b.iter(|| bench::render(black_box("Arch"), true))
+    });
+    c.bench_function("render without color", |b| {
+        b.iter(|| bench::render(black_box("Arch"), false));
+    });
+}
+
+criterion_group!(benches, parsers, render);
+criterion_main!(benches);
@@ -4,11 +4,54 @@ use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::Path;
 
+/// A color from `set_colors`: a palette index or a `#rrggbb` value
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Palette(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn parse(s: &str) -> Self {
+        if let Some(hex) = s.strip_prefix('#') {
+            let channel = |i: usize| {
+                hex.get(i..i + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+                    .unwrap_or(0)
+            };
+            return Color::Rgb(channel(0), channel(2), channel(4));
+        }
+
+        if s == "fg" {
+            // Treat "fg" as color 7 (light gray/white)
+            return Color::Palette(7);
+        }
+        Color::Palette(s.parse::<u8>().unwrap_or(0))
+    }
+
+    // Bold ANSI code; RGB colors are baked as 24-bit and downgraded at runtime
+    fn ansi(self) -> Option<String> {
+        match self {
+            Color::Palette(0) => None,
+            Color::Palette(n) if n <= 7 => Some(format!("\x1b[1;{}m", 30 + n)),
+            Color::Palette(n) => Some(format!("\x1b[1;38;5;{}m", n)),
+            Color::Rgb(r, g, b) => Some(format!("\x1b[1;38;2;{};{};{}m", r, g, b)),
+        }
+    }
+
+    fn to_rust(self) -> String {
+        match self {
+            Color::Palette(n) => format!("Color::Palette({})", n),
+            Color::Rgb(r, g, b) => format!("Color::Rgb({}, {}, {})", r, g, b),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Logo {
     name: String,
     is_wildcard: bool,
-    colors: Vec<u8>,
+    colors: Vec<Color>,
     ascii_art: String,
 }
 
@@ -17,6 +60,8 @@ struct ProcessedLogo {
     name: String,
     is_wildcard: bool,
     ascii_art: String,
+    raw_art: String,
+    colors: Vec<Color>,
     max_line_length: usize,
 }
 
@@ -51,39 +96,35 @@ fn main() -> io::Result<()> {
 fn extract_logos(content: &str) -> Vec<Logo> {
     let mut logos = Vec::new();
 
-    // Regex pattern to extract logo definitions - no change needed here
+    // Regex pattern to extract logo definitions, with the whole case pattern
+    // (e.g. `"Ubuntu"* | "i3buntu"*`) so every alternative gets an entry
     let dist_pattern = Regex::new(
-        r#"(?s)"([^"]*)"(\*?)\)\s*set_colors\s+(.*?)read -rd '' ascii_data <<'EOF'(.*?)EOF\s*;;"#,
+        r#"(?ms)^[ \t]*([^\n)]*)\)\s*set_colors\s+(.*?)read -rd '' ascii_data <<'EOF'(.*?)EOF\s*;;"#,
     )
     .unwrap();
 
     for cap in dist_pattern.captures_iter(content) {
-        let name = cap[1].to_string();
-        let is_wildcard = &cap[2] == "*";
-
-        // Parse colors with special handling for "fg"
-        let colors: Vec<u8> = cap[3]
-            .split_whitespace()
-            .map(|s| {
-                if s == "fg" {
-                    // Treat "fg" as color 7 (light gray/white)
-                    // You can choose a different value if preferred
-                    7
-                } else {
-                    s.parse::<u8>().unwrap_or(0)
-                }
-            })
-            .collect();
+        // Parse colors with special handling for "fg" and "#rrggbb"
+        let colors: Vec<Color> = cap[2].split_whitespace().map(Color::parse).collect();
 
         // Get ASCII art
-        let ascii_art = cap[4].strip_prefix('\n').unwrap_or(&cap[4]).to_string();
-
-        logos.push(Logo {
-            name,
-            is_wildcard,
-            colors,
-            ascii_art,
-        });
+        let ascii_art = cap[3].strip_prefix('\n').unwrap_or(&cap[3]).to_string();
+
+        for alternative in cap[1].split('|') {
+            let alternative = alternative.trim();
+            let is_wildcard = alternative.ends_with('*');
+            let name = alternative.trim_end_matches('*').trim_matches('"');
+            if name.is_empty() || name.contains(['*', '"', '$']) {
+                continue;
+            }
+
+            logos.push(Logo {
+                name: name.to_string(),
+                is_wildcard,
+                colors: colors.clone(),
+                ascii_art: ascii_art.clone(),
+            });
+        }
     }
 
     // Sort logos by name for binary search
@@ -103,16 +144,8 @@ fn process_logos(logos: &[Logo]) -> Vec<ProcessedLogo> {
             let mut color_map = std::collections::HashMap::new();
 
             // Create color map for all placeholders used in this logo
-            for i in 0..6 {
-                if i < logo.colors.len() && logo.colors[i] > 0 {
-                    let color_value = logo.colors[i];
-                    let ansi_code = if color_value <= 7 {
-                        // Basic colors (30-37) with bold
-                        format!("\x1b[1;{}m", 30 + color_value)
-                    } else {
-                        // Extended 256-color mode with bold
-                        format!("\x1b[1;38;5;{}m", color_value)
-                    };
+            for (i, color) in logo.colors.iter().take(6).enumerate() {
+                if let Some(ansi_code) = color.ansi() {
                     color_map.insert(format!("${{c{}}}", i + 1), ansi_code);
                 }
             }
@@ -134,6 +167,8 @@ fn process_logos(logos: &[Logo]) -> Vec<ProcessedLogo> {
                 name: logo.name.clone(),
                 is_wildcard: logo.is_wildcard,
                 ascii_art: formatted_art,
+                raw_art: logo.ascii_art.clone(),
+                colors: logo.colors.clone(),
                 max_line_length,
             }
         })
@@ -159,12 +194,15 @@ fn generate_logos_module(logos: &[ProcessedLogo]) -> String {
 
     // Add module header
     code.push_str("// Auto-generated code from build script\n\n");
+    code.push_str("use crate::color::Color;\n\n");
 
     // Define the Logo struct with max_line_length
     code.push_str("pub struct Logo {\n");
     code.push_str("    pub name: &'static str,\n");
     code.push_str("    pub is_wildcard: bool,\n");
     code.push_str("    pub ascii_art: &'static str,\n");
+    code.push_str("    pub raw_art: &'static str,\n");
+    code.push_str("    pub colors: &'static [Color],\n");
     code.push_str("    pub max_line_length: usize,\n");
     code.push_str("}\n\n");
 
@@ -173,28 +211,14 @@ fn generate_logos_module(logos: &[ProcessedLogo]) -> String {
 
     // Add each logo definition
     for logo in logos {
-        // Create a byte array representation to ensure all characters are properly escaped
-        let mut bytes = Vec::new();
-        for b in logo.ascii_art.as_bytes() {
-            match b {
-                b'\x1b' => bytes.extend_from_slice(b"\\x1b"), // Escape character
-                b'\\' => bytes.extend_from_slice(b"\\\\"),    // Backslash
-                b'"' => bytes.extend_from_slice(b"\\\""),     // Double quote
-                b'\n' => bytes.extend_from_slice(b"\\n"),     // Newline
-                b'\r' => bytes.extend_from_slice(b"\\r"),     // Carriage return
-                b'\t' => bytes.extend_from_slice(b"\\t"),     // Tab
-                // For normal printable ASCII characters, just use the character itself
-                b' '..=b'~' => bytes.push(*b),
-                // For any other character, use Unicode escape
-                _ => {
-                    let unicode = format!("\\u{{{:04x}}}", *b);
-                    bytes.extend_from_slice(unicode.as_bytes());
-                }
-            }
-        }
-
-        // Convert bytes to a string
-        let escaped_art = String::from_utf8(bytes).unwrap();
+        let escaped_art = escape_art(&logo.ascii_art);
+        let escaped_raw = escape_art(&logo.raw_art);
+        let colors = logo
+            .colors
+            .iter()
+            .map(|c| c.to_rust())
+            .collect::<Vec<_>>()
+            .join(", ");
 
         // Format the Logo instance with max_line_length
         code.push_str(&format!(
@@ -204,6 +228,11 @@ fn generate_logos_module(logos: &[ProcessedLogo]) -> String {
             escaped_art
         ));
 
+        code.push_str(&format!(
+            "        raw_art: \"{}\",\n        colors: &[{}],\n",
+            escaped_raw, colors
+        ));
+
         code.push_str(&format!(
             "        max_line_length: {},\n    }},\n",
             logo.max_line_length
@@ -217,23 +246,56 @@ fn generate_logos_module(logos: &[ProcessedLogo]) -> String {
     code.push_str(
         r#"
 pub fn find_logo(distro_name: &str) -> Option<&'static Logo> {
-    // First try exact match for non-wildcard logos
-    if let Ok(idx) = LOGOS.binary_search_by(|logo| {
-        if logo.is_wildcard {
-            std::cmp::Ordering::Greater // Skip wildcards for binary search
-        } else {
-            logo.name.cmp(distro_name)
-        }
-    }) {
+    // First try an exact match; a wildcard entry matches its own name too
+    if let Ok(idx) = LOGOS.binary_search_by(|logo| logo.name.cmp(distro_name)) {
         return Some(&LOGOS[idx]);
     }
-    
-    // Then try prefix match for wildcard logos
+
+    // Then the same ignoring case, whitespace, '-' and '_'
+    if let Some(logo) = LOGOS.iter().find(|logo| normalized(logo.name).eq(normalized(distro_name))) {
+        return Some(logo);
+    }
+
+    // Then the longest wildcard that is a prefix, so "Ubuntu 24.04" finds
+    // "Ubuntu" and "Ubuntu Budgie 24.04" finds "Ubuntu Budgie"
     LOGOS.iter()
-        .find(|logo| logo.is_wildcard && distro_name.starts_with(&logo.name))
+        .filter(|logo| logo.is_wildcard && is_normalized_prefix(logo.name, distro_name))
+        .max_by_key(|logo| logo.name.len())
+}
+
+fn normalized(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+}
+
+fn is_normalized_prefix(prefix: &str, name: &str) -> bool {
+    let mut name = normalized(name);
+    normalized(prefix).all(|c| name.next() == Some(c))
 }
 "#,
     );
 
     code
 }
+
+// Escape logo art for use inside a Rust string literal
+fn escape_art(art: &str) -> String {
+    let mut escaped = String::with_capacity(art.len());
+    for c in art.chars() {
+        match c {
+            '\x1b' => escaped.push_str("\\x1b"), // Escape character
+            '\\' => escaped.push_str("\\\\"),    // Backslash
+            '"' => escaped.push_str("\\\""),     // Double quote
+            '\n' => escaped.push_str("\\n"),     // Newline
+            '\r' => escaped.push_str("\\r"),     // Carriage return
+            '\t' => escaped.push_str("\\t"),     // Tab
+            // For normal printable ASCII characters, just use the character itself
+            ' '..='~' => escaped.push(c),
+            // Anything else by code point; escaping its UTF-8 bytes one by one
+            // would turn `´` into `Â´`
+            _ => escaped.push_str(&format!("\\u{{{:04x}}}", u32::from(c))),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,218 @@
+//! Global allocator options
+//! `mimalloc` swaps in mimalloc, `bump-alloc` serves allocations from a static
+//! arena (the process is short-lived, so nothing needs to be reclaimed) and
+//! `alloc-audit` counts allocations per module on top of either
+
+#[cfg(all(feature = "mimalloc", feature = "bump-alloc"))]
+compile_error!("the `mimalloc` and `bump-alloc` features are mutually exclusive");
+
+#[cfg(feature = "mimalloc")]
+type Inner = mimalloc::MiMalloc;
+#[cfg(feature = "mimalloc")]
+const INNER: Inner = mimalloc::MiMalloc;
+
+#[cfg(feature = "bump-alloc")]
+type Inner = bump::Bump;
+#[cfg(feature = "bump-alloc")]
+const INNER: Inner = bump::Bump;
+
+#[cfg(all(
+    feature = "alloc-audit",
+    not(any(feature = "mimalloc", feature = "bump-alloc"))
+))]
+type Inner = std::alloc::System;
+#[cfg(all(
+    feature = "alloc-audit",
+    not(any(feature = "mimalloc", feature = "bump-alloc"))
+))]
+const INNER: Inner = std::alloc::System;
+
+#[cfg(feature = "alloc-audit")]
+#[global_allocator]
+static GLOBAL: audit::Counting<Inner> = audit::Counting(INNER);
+
+#[cfg(all(
+    not(feature = "alloc-audit"),
+    any(feature = "mimalloc", feature = "bump-alloc")
+))]
+#[global_allocator]
+static GLOBAL: Inner = INNER;
+
+#[cfg(feature = "alloc-audit")]
+pub use audit::{report, section};
+
+#[cfg(feature = "bump-alloc")]
+mod bump {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::UnsafeCell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A normal run allocates well under this; anything past it goes to the
+    /// system allocator
+    const ARENA_SIZE: usize = 4 << 20;
+
+    #[repr(align(4096))]
+    struct Arena(UnsafeCell<[u8; ARENA_SIZE]>);
+
+    // Every byte is handed out to exactly one allocation by `NEXT`
+    unsafe impl Sync for Arena {}
+
+    static ARENA: Arena = Arena(UnsafeCell::new([0; ARENA_SIZE]));
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+    pub struct Bump;
+
+    fn in_arena(ptr: *mut u8) -> bool {
+        let base = ARENA.0.get().cast::<u8>();
+        (base..base.wrapping_add(ARENA_SIZE)).contains(&ptr)
+    }
+
+    unsafe impl GlobalAlloc for Bump {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let base = ARENA.0.get().cast::<u8>();
+            let mut current = NEXT.load(Ordering::Relaxed);
+            loop {
+                let start =
+                    (base as usize + current).next_multiple_of(layout.align()) - base as usize;
+                let end = start + layout.size();
+                if end > ARENA_SIZE {
+                    return unsafe { System.alloc(layout) };
+                }
+                match NEXT.compare_exchange_weak(current, end, Ordering::Relaxed, Ordering::Relaxed)
+                {
+                    Ok(_) => return unsafe { base.add(start) },
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // Arena memory is only given back when the process exits
+            if !in_arena(ptr) {
+                unsafe { System.dealloc(ptr, layout) };
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc-audit")]
+mod audit {
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::cell::Cell;
+    use std::io::Write;
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+    const MAX_SECTIONS: usize = 64;
+
+    /// Counters for one named section; slot 0 collects everything else
+    struct Slot {
+        name: AtomicPtr<u8>,
+        len: AtomicUsize,
+        count: AtomicUsize,
+        bytes: AtomicUsize,
+    }
+
+    impl Slot {
+        const fn new() -> Self {
+            Self {
+                name: AtomicPtr::new(std::ptr::null_mut()),
+                len: AtomicUsize::new(0),
+                count: AtomicUsize::new(0),
+                bytes: AtomicUsize::new(0),
+            }
+        }
+
+        fn name(&self) -> Option<&'static str> {
+            let ptr = self.name.load(Ordering::Acquire);
+            if ptr.is_null() {
+                return None;
+            }
+            let bytes =
+                unsafe { std::slice::from_raw_parts(ptr, self.len.load(Ordering::Acquire)) };
+            std::str::from_utf8(bytes).ok()
+        }
+    }
+
+    static SLOTS: [Slot; MAX_SECTIONS] = [const { Slot::new() }; MAX_SECTIONS];
+
+    thread_local! {
+        static CURRENT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    pub struct Counting<A>(pub A);
+
+    fn record(size: usize) {
+        let slot = &SLOTS[CURRENT.try_with(Cell::get).unwrap_or(0)];
+        slot.count.fetch_add(1, Ordering::Relaxed);
+        slot.bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    unsafe impl<A: GlobalAlloc> GlobalAlloc for Counting<A> {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            unsafe { self.0.alloc(layout) }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            unsafe { self.0.alloc_zeroed(layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size);
+            unsafe { self.0.realloc(ptr, layout, new_size) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { self.0.dealloc(ptr, layout) };
+        }
+    }
+
+    /// Attributes this thread's allocations to `name` until dropped
+    pub struct Section(usize);
+
+    impl Drop for Section {
+        fn drop(&mut self) {
+            CURRENT.with(|current| current.set(self.0));
+        }
+    }
+
+    /// Start counting this thread's allocations under `name`
+    ///
+    /// Sections are meant to be opened from the main thread only.
+    pub fn section(name: &'static str) -> Section {
+        let index = SLOTS
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find_map(|(i, slot)| match slot.name() {
+                Some(existing) => (existing == name).then_some(i),
+                None => {
+                    slot.len.store(name.len(), Ordering::Release);
+                    slot.name.store(name.as_ptr().cast_mut(), Ordering::Release);
+                    Some(i)
+                }
+            })
+            .unwrap_or(0);
+
+        Section(CURRENT.with(|current| current.replace(index)))
+    }
+
+    /// Print the counters to stderr
+    pub fn report() {
+        let mut err = std::io::stderr().lock();
+        let _ = writeln!(err, "{:<16} {:>8} {:>10}", "section", "allocs", "bytes");
+        for slot in &SLOTS {
+            let count = slot.count.load(Ordering::Relaxed);
+            if count == 0 {
+                continue;
+            }
+            let _ = writeln!(
+                err,
+                "{:<16} {count:>8} {:>10}",
+                slot.name().unwrap_or("other"),
+                slot.bytes.load(Ordering::Relaxed)
+            );
+        }
+    }
+}
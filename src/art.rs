@@ -0,0 +1,302 @@
+//! Logo selection
+//! Picks the compiled-in logo for the distro, unless the config provides
+//! replacement art for it
+
+use crate::banner;
+use crate::color::{self, Color, Depth};
+use crate::config::{BannerConfig, BannerPosition, Config, LogoOverride};
+use crate::logos;
+use crate::os::OsRelease;
+use std::borrow::Cow;
+
+/// The logo to draw, with color sequences already applied
+pub struct Art {
+    pub text: Cow<'static, str>,
+    /// Widest line in visible characters
+    pub width: usize,
+    /// Colors of the `${cN}` placeholders, after `logo_colors`
+    pub colors: Vec<Color>,
+}
+
+/// Compiled-in logo for os-release `ID`s that don't resemble any logo name;
+/// other IDs are looked up directly
+static ID_LOGOS: &[(&str, &str)] = &[
+    ("amzn", "Amazon"),
+    ("neon", "KDE"),
+    ("ol", "Oracle"),
+    ("pop", "Pop!_OS"),
+    ("sles", "SUSE"),
+];
+
+fn id_logo(id: &str) -> Option<&'static logos::Logo> {
+    let name = ID_LOGOS
+        .iter()
+        .find(|(i, _)| *i == id)
+        .map_or(id, |(_, name)| name);
+    logos::find_logo(name)
+}
+
+/// Select the logo for the running distro
+///
+/// The os-release `ID` is tried first, then `NAME`, then each `ID_LIKE`
+/// parent so derivatives without a logo get their base distro's.
+pub fn select(config: &Config, release: &OsRelease) -> Art {
+    let short = release.name.split_whitespace().next().unwrap_or("Linux");
+
+    if let Some(art) =
+        find_override(config, &release.name, short).and_then(|logo| load_override(config, logo))
+    {
+        return art;
+    }
+
+    let logo = release
+        .id
+        .as_deref()
+        .and_then(id_logo)
+        .or_else(|| logos::find_logo(&release.name))
+        .or_else(|| release.id_like.iter().find_map(|id| id_logo(id)))
+        .or_else(|| logos::find_logo("Linux"))
+        .unwrap_or(&logos::LOGOS[102]);
+
+    compiled(config, logo)
+}
+
+/// A compiled-in logo by name, for `--logo-preview`
+pub fn named(config: &Config, name: &str) -> Option<Art> {
+    logos::find_logo(name).map(|logo| compiled(config, logo))
+}
+
+/// Names of all compiled-in logos, for `--list-logos`
+pub fn names() -> Vec<&'static str> {
+    let mut names: Vec<&str> = logos::LOGOS.iter().map(|logo| logo.name).collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names.dedup();
+    names
+}
+
+fn compiled(config: &Config, logo: &'static logos::Logo) -> Art {
+    // The pre-baked art is only usable when the palette isn't being remapped
+    // and its 24-bit colors, if any, can be shown as they are
+    let needs_downgrade = color::depth() != Depth::TrueColor
+        && logo.colors.iter().any(|c| matches!(c, Color::Rgb(..)));
+
+    let colors = remap_colors(logo.colors, &config.logo_colors, config);
+    let text = if config.logo_colors.is_empty() && config.palette.is_none() && !needs_downgrade {
+        Cow::Borrowed(logo.ascii_art)
+    } else {
+        Cow::Owned(colorize(logo.raw_art, &colors))
+    };
+
+    Art {
+        text,
+        width: logo.max_line_length,
+        colors,
+    }
+}
+
+/// Put a large text banner in place of, or above, the logo
+pub fn with_banner(logo: Art, config: &BannerConfig, text: &str) -> Art {
+    let rows = banner::render(text, config.pixel);
+    let banner_width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+
+    let color = config.color.map_or_else(
+        || first_color(&logo.text).unwrap_or_default().to_string(),
+        |c| c.sequence(color::depth()),
+    );
+
+    // Same shape as the compiled-in logos: colored rows, then a reset line
+    let mut art = format!("{color}{}\n\x1b[0m", rows.join("\n"));
+
+    match config.position {
+        BannerPosition::Replace => Art {
+            text: Cow::Owned(art),
+            width: banner_width,
+            colors: logo.colors,
+        },
+        BannerPosition::Above => {
+            art.push('\n');
+            art.push_str(&logo.text);
+            Art {
+                text: Cow::Owned(art),
+                width: banner_width.max(logo.width),
+                colors: logo.colors,
+            }
+        }
+    }
+}
+
+/// First color sequence used in some art
+fn first_color(art: &str) -> Option<&str> {
+    let start = art.find("\x1b[")?;
+    let end = start + art[start..].find('m')? + 1;
+    Some(&art[start..end]).filter(|seq| *seq != "\x1b[0m")
+}
+
+/// Replace the leading colors of a palette with user-chosen ones, then match
+/// them all to the configured palette
+fn remap_colors(colors: &[Color], overrides: &[Color], config: &Config) -> Vec<Color> {
+    let mut remapped = colors.to_vec();
+    for (i, &color) in overrides.iter().enumerate() {
+        match remapped.get_mut(i) {
+            Some(slot) => *slot = color,
+            None => remapped.push(color),
+        }
+    }
+    if let Some(palette) = &config.palette {
+        for color in &mut remapped {
+            *color = palette.recolor(*color);
+        }
+    }
+    remapped
+}
+
+fn find_override<'a>(config: &'a Config, distro: &str, short: &str) -> Option<&'a LogoOverride> {
+    config
+        .logos
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(distro))
+        .or_else(|| {
+            config
+                .logos
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(short))
+        })
+        .map(|(_, logo)| logo)
+}
+
+fn load_override(config: &Config, logo: &LogoOverride) -> Option<Art> {
+    let raw = match std::fs::read_to_string(&logo.file) {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("tachi-fetch: logo {}: {e}", logo.file.display());
+            return None;
+        }
+    };
+
+    let width = raw
+        .lines()
+        .map(|line| strip_placeholders(line).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let colors = remap_colors(&logo.colors, &config.logo_colors, config);
+
+    Some(Art {
+        text: Cow::Owned(colorize(&raw, &colors)),
+        width,
+        colors,
+    })
+}
+
+/// Replace `${c1}`..`${c6}` placeholders with the given colors, the same way
+/// the build script prepares the compiled-in logos
+pub fn colorize(raw: &str, colors: &[Color]) -> String {
+    let depth = color::depth();
+    let mut art = String::with_capacity(raw.len() + 64);
+    let mut rest = raw;
+
+    while let Some(start) = rest.find("${c") {
+        art.push_str(&rest[..start]);
+        let after = &rest[start + 3..];
+
+        match placeholder_index(after) {
+            Some((index, len)) => {
+                if let Some(&color) = colors.get(index) {
+                    art.push_str(&color.sequence(depth));
+                }
+                rest = &after[len..];
+            }
+            None => {
+                art.push_str("${c");
+                rest = after;
+            }
+        }
+    }
+    art.push_str(rest);
+
+    if !art.ends_with("\x1b[0m") {
+        art.push_str("\x1b[0m");
+    }
+    art
+}
+
+/// Remove `${cN}` placeholders to measure the visible width of a line
+fn strip_placeholders(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find("${c") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 3..];
+        if let Some((_, len)) = placeholder_index(after) {
+            rest = &after[len..];
+        } else {
+            out.push_str("${c");
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Parse the `N}` tail of a placeholder into a zero-based index and its length
+fn placeholder_index(after: &str) -> Option<(usize, usize)> {
+    let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 || after.as_bytes().get(digits) != Some(&b'}') {
+        return None;
+    }
+    let n: usize = after[..digits].parse().ok()?;
+    Some((n.checked_sub(1)?, digits + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logos::find_logo;
+
+    fn logo_for(name: &str) -> Option<&'static str> {
+        find_logo(name).map(|logo| logo.name)
+    }
+
+    #[test]
+    fn exact_names() {
+        assert_eq!(logo_for("Arch"), Some("Arch"));
+        assert_eq!(logo_for("Linux"), Some("Linux"));
+        assert_eq!(logo_for("Pop!_OS"), Some("Pop!_OS"));
+    }
+
+    #[test]
+    fn case_and_whitespace_are_ignored() {
+        assert_eq!(logo_for("ubuntu"), Some("Ubuntu"));
+        assert_eq!(logo_for("DEBIAN"), Some("Debian"));
+        assert_eq!(logo_for("linuxmint"), Some("Linux Mint"));
+        assert_eq!(logo_for("endeavouros"), Some("EndeavourOS"));
+    }
+
+    #[test]
+    fn os_release_names() {
+        assert_eq!(logo_for("Arch Linux"), Some("Arch"));
+        assert_eq!(logo_for("Debian GNU/Linux"), Some("Debian"));
+        assert_eq!(logo_for("Fedora Linux"), Some("Fedora"));
+        assert_eq!(logo_for("Ubuntu 24.04"), Some("Ubuntu"));
+        assert_eq!(logo_for("Manjaro Linux"), Some("Manjaro"));
+        assert_eq!(logo_for("Gentoo"), Some("Gentoo"));
+        assert_eq!(logo_for("openSUSE Tumbleweed"), Some("openSUSE Tumbleweed"));
+        assert_eq!(logo_for("Rocky Linux"), Some("rocky"));
+    }
+
+    #[test]
+    fn longest_wildcard_prefix_wins() {
+        // "Ubuntu Budgie" and "Ubuntu-Budgie" are the same art
+        let art = |name| find_logo(name).map(|logo| logo.raw_art);
+        assert_eq!(art("Ubuntu Budgie 24.04"), art("Ubuntu Budgie"));
+        assert_ne!(art("Ubuntu Budgie 24.04"), art("Ubuntu"));
+        assert_eq!(logo_for("Archcraft"), Some("Archcraft"));
+        assert_eq!(logo_for("ArchStrike Linux"), Some("ArchStrike"));
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(logo_for(""), None);
+        assert_eq!(logo_for("Definitely Not A Distro"), None);
+    }
+}
@@ -0,0 +1,32 @@
+//! Detection backends
+//! Values with several ways of finding them try named backends in an order the
+//! config can change, e.g. `resolution_backends = ["x11", "drm"]`
+
+use crate::log;
+
+/// One way of detecting a value
+pub trait Backend: Sync {
+    /// Name used in the config
+    fn name(&self) -> &'static str;
+    fn detect(&self) -> Option<String>;
+}
+
+/// The first value found by the backends named in `order`, in that order
+pub fn detect(what: &str, backends: &[&dyn Backend], order: &[String]) -> Option<String> {
+    for name in order {
+        let Some(backend) = backends.iter().find(|b| b.name() == name) else {
+            continue;
+        };
+        log::trace!("{what}: trying {name}");
+        if let Some(value) = backend.detect() {
+            return Some(value);
+        }
+    }
+    log::fail!("{what}: nothing found by {}", order.join(", "));
+    None
+}
+
+/// Whether `backends` has one called `name`
+pub fn exists(backends: &[&dyn Backend], name: &str) -> bool {
+    backends.iter().any(|b| b.name() == name)
+}
@@ -0,0 +1,94 @@
+//! Large text banners
+//! Renders a word (usually the hostname) in an embedded 5-row block font,
+//! for MOTD-style identity banners drawn in place of or above the logo
+
+const ROWS: usize = 5;
+
+/// Glyphs as rows of `#` (set) and `.` (clear)
+static FONT: &[(char, [&str; ROWS])] = &[
+    ('A', [".###.", "#...#", "#####", "#...#", "#...#"]),
+    ('B', ["####.", "#...#", "####.", "#...#", "####."]),
+    ('C', [".####", "#....", "#....", "#....", ".####"]),
+    ('D', ["####.", "#...#", "#...#", "#...#", "####."]),
+    ('E', ["#####", "#....", "####.", "#....", "#####"]),
+    ('F', ["#####", "#....", "####.", "#....", "#...."]),
+    ('G', [".####", "#....", "#..##", "#...#", ".###."]),
+    ('H', ["#...#", "#...#", "#####", "#...#", "#...#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..###", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', ["#...#", "#..#.", "###..", "#..#.", "#...#"]),
+    ('L', ["#....", "#....", "#....", "#....", "#####"]),
+    ('M', ["#...#", "##.##", "#.#.#", "#...#", "#...#"]),
+    ('N', ["#...#", "##..#", "#.#.#", "#..##", "#...#"]),
+    ('O', [".###.", "#...#", "#...#", "#...#", ".###."]),
+    ('P', ["####.", "#...#", "####.", "#....", "#...."]),
+    ('Q', [".###.", "#...#", "#.#.#", "#..#.", ".##.#"]),
+    ('R', ["####.", "#...#", "####.", "#..#.", "#...#"]),
+    ('S', [".####", "#....", ".###.", "....#", "####."]),
+    ('T', ["#####", "..#..", "..#..", "..#..", "..#.."]),
+    ('U', ["#...#", "#...#", "#...#", "#...#", ".###."]),
+    ('V', ["#...#", "#...#", "#...#", ".#.#.", "..#.."]),
+    ('W', ["#...#", "#...#", "#.#.#", "##.##", "#...#"]),
+    ('X', ["#...#", ".#.#.", "..#..", ".#.#.", "#...#"]),
+    ('Y', ["#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["#####", "...#.", "..#..", ".#...", "#####"]),
+    ('0', [".###.", "#..##", "#.#.#", "##..#", ".###."]),
+    ('1', [".#.", "##.", ".#.", ".#.", "###"]),
+    ('2', [".###.", "#...#", "..##.", ".#...", "#####"]),
+    ('3', ["####.", "....#", ".###.", "....#", "####."]),
+    ('4', ["#...#", "#...#", "#####", "....#", "....#"]),
+    ('5', ["#####", "#....", "####.", "....#", "####."]),
+    ('6', [".###.", "#....", "####.", "#...#", ".###."]),
+    ('7', ["#####", "....#", "...#.", "..#..", "..#.."]),
+    ('8', [".###.", "#...#", ".###.", "#...#", ".###."]),
+    ('9', [".###.", "#...#", ".####", "....#", ".###."]),
+    ('-', ["...", "...", "###", "...", "..."]),
+    ('.', [".", ".", ".", ".", "#"]),
+    ('_', ["....", "....", "....", "....", "####"]),
+    (' ', ["...", "...", "...", "...", "..."]),
+    ('?', [".###.", "#...#", "..##.", ".....", "..#.."]),
+];
+
+/// Fold accented Latin letters onto the base letters the font has
+fn fold(c: char) -> char {
+    match c.to_ascii_uppercase() {
+        'À'..='Å' | 'à'..='å' => 'A',
+        'Ç' | 'ç' => 'C',
+        'È'..='Ë' | 'è'..='ë' => 'E',
+        'Ì'..='Ï' | 'ì'..='ï' => 'I',
+        'Ñ' | 'ñ' => 'N',
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' => 'O',
+        'Ù'..='Ü' | 'ù'..='ü' => 'U',
+        'Ý' | 'ý' | 'ÿ' => 'Y',
+        c => c,
+    }
+}
+
+fn glyph(c: char) -> &'static [&'static str; ROWS] {
+    let c = fold(c);
+    FONT.iter()
+        .find(|(g, _)| *g == c)
+        .or_else(|| FONT.iter().find(|(g, _)| *g == '?'))
+        .map(|(_, rows)| rows)
+        .unwrap_or(&FONT[0].1)
+}
+
+/// Render text as banner rows, using `pixel` for set cells
+pub fn render(text: &str, pixel: char) -> Vec<String> {
+    let mut rows = vec![String::new(); ROWS];
+
+    for (i, c) in text.chars().enumerate() {
+        let glyph = glyph(c);
+        for (row, line) in rows.iter_mut().zip(glyph) {
+            if i > 0 {
+                row.push(' ');
+            }
+            row.extend(line.chars().map(|p| if p == '#' { pixel } else { ' ' }));
+        }
+    }
+
+    for row in &mut rows {
+        row.truncate(row.trim_end().len());
+    }
+    rows
+}
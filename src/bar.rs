@@ -0,0 +1,46 @@
+//! Percentage bars
+//! `███████░░░ 68%`, shared by the lines that can show their percentage as
+//! a bar and colored by how close it is to running out
+
+use crate::config::BarConfig;
+
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// A bar for space or memory in use followed by its percentage, turning red
+/// as it fills up
+pub fn usage(config: &BarConfig, percent: u64, color: bool) -> String {
+    format!("{} {percent}%", render(config, percent, percent, color))
+}
+
+/// A bar for charge left followed by its percentage, turning red as it
+/// empties
+pub fn charge(config: &BarConfig, percent: u64, color: bool) -> String {
+    format!(
+        "{} {percent}%",
+        render(config, percent, 100 - percent.min(100), color)
+    )
+}
+
+/// `config.width` cells filled in proportion to `percent`, colored by
+/// `used` against the thresholds
+fn render(config: &BarConfig, percent: u64, used: u64, color: bool) -> String {
+    let width = config.width;
+    let filled = usize::try_from(percent.min(100)).unwrap_or(0) * width / 100;
+    let full = config.filled.to_string().repeat(filled);
+    let empty = config.empty.to_string().repeat(width - filled);
+    if !color || filled == 0 {
+        return full + &empty;
+    }
+
+    let sequence = if used >= config.critical {
+        RED
+    } else if used >= config.warning {
+        YELLOW
+    } else {
+        GREEN
+    };
+    format!("{sequence}{full}{RESET}{empty}")
+}
@@ -0,0 +1,185 @@
+//! Battery status from the power supply class in sysfs
+
+use crate::source;
+use crate::utils::{format_uptime, format_uptime_words};
+use std::path::Path;
+
+const POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+/// Charge, state, power draw, time left and health of each system battery,
+/// e.g. `87% (Discharging, 11.2 W, 4h 5m left, 93% health)`, or in words for
+/// `accessible` output: `87 percent, discharging, 11.2 watts, 4 hours 5
+/// minutes left, 93 percent health`
+///
+/// Returns `None` when the machine has no battery so the line can be skipped.
+pub fn get_battery_info(accessible: bool) -> Option<String> {
+    let entries = source::current().read_dir(Path::new(POWER_SUPPLY)).ok()?;
+
+    let mut batteries: Vec<(String, String)> = Vec::new();
+
+    for path in entries {
+        if !is_system_battery(&path) {
+            continue;
+        }
+
+        let Some(capacity) = read_trimmed(&path.join("capacity")) else {
+            continue;
+        };
+        let status = read_trimmed(&path.join("status")).unwrap_or_else(|| "Unknown".to_string());
+
+        let (percent, watts_unit) = if accessible {
+            (" percent", " watts")
+        } else {
+            ("%", " W")
+        };
+        let mut details = vec![if accessible {
+            status.to_lowercase()
+        } else {
+            status.clone()
+        }];
+        let watts = power_draw(&path).filter(|&w| w > 0.0);
+        if let Some(watts) = watts {
+            details.push(format!("{watts:.1}{watts_unit}"));
+        }
+        if let Some(seconds) = time_remaining(&path, &status) {
+            let until = if status == "Charging" {
+                "to full"
+            } else {
+                "left"
+            };
+            let time = if accessible {
+                format_uptime_words(seconds)
+            } else {
+                format_uptime(seconds)
+            };
+            details.push(format!("{time} {until}"));
+        }
+        if let Some(health) = health(&path) {
+            details.push(format!("{health}{percent} health"));
+        }
+
+        let name = file_name(&path);
+        let value = if accessible {
+            format!("{capacity} percent, {}", details.join(", "))
+        } else {
+            format!("{capacity}% ({})", details.join(", "))
+        };
+        batteries.push((name, value));
+    }
+
+    if batteries.is_empty() {
+        return None;
+    }
+
+    // Keep BAT0, BAT1, ... in a stable order
+    batteries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Some(
+        batteries
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+/// Charge percentage of each system battery, keyed by its sysfs name
+pub fn capacities() -> Vec<(String, u8)> {
+    let Ok(entries) = source::current().read_dir(Path::new(POWER_SUPPLY)) else {
+        return Vec::new();
+    };
+
+    let mut batteries: Vec<(String, u8)> = entries
+        .into_iter()
+        .filter(|path| is_system_battery(path))
+        .filter_map(|path| {
+            let capacity = read_trimmed(&path.join("capacity"))?.parse().ok()?;
+            Some((file_name(&path), capacity))
+        })
+        .collect();
+    batteries.sort();
+    batteries
+}
+
+/// Check for a battery powering the system
+pub fn has_system_battery() -> bool {
+    source::current()
+        .read_dir(Path::new(POWER_SUPPLY))
+        .is_ok_and(|entries| entries.iter().any(|path| is_system_battery(path)))
+}
+
+/// Batteries with `scope=Device` belong to peripherals (mice, headsets)
+fn is_system_battery(path: &Path) -> bool {
+    read_trimmed(&path.join("type")).is_some_and(|t| t == "Battery")
+        && read_trimmed(&path.join("scope")).is_none_or(|s| s != "Device")
+}
+
+/// Watts drawn or charged with: `power_now`, or `current_now` times
+/// `voltage_now` for batteries reporting charge rather than energy
+fn power_draw(path: &Path) -> Option<f64> {
+    if let Some(micro_watts) = read_number(&path.join("power_now")) {
+        return Some(micro_watts / 1e6);
+    }
+    let micro_amps = read_number(&path.join("current_now"))?;
+    let micro_volts = read_number(&path.join("voltage_now"))?;
+    Some(micro_amps * micro_volts / 1e12)
+}
+
+/// Seconds until empty when discharging or full when charging, at the
+/// current rate
+fn time_remaining(path: &Path, status: &str) -> Option<u64> {
+    // Energy in µWh with power in µW, or charge in µAh with current in µA
+    let (now, full, rate) = ["energy", "charge"].into_iter().find_map(|kind| {
+        let rate = if kind == "energy" {
+            "power_now"
+        } else {
+            "current_now"
+        };
+        Some((
+            read_number(&path.join(format!("{kind}_now")))?,
+            read_number(&path.join(format!("{kind}_full")))?,
+            read_number(&path.join(rate)).filter(|&r| r > 0.0)?,
+        ))
+    })?;
+    let hours = match status {
+        "Discharging" => now / rate,
+        "Charging" => (full - now).max(0.0) / rate,
+        _ => return None,
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let seconds = (hours * 3600.0) as u64;
+    Some(seconds)
+}
+
+/// Full capacity as a percentage of what the battery was designed for
+fn health(path: &Path) -> Option<u32> {
+    let (full, design) = ["energy", "charge"].into_iter().find_map(|kind| {
+        Some((
+            read_number(&path.join(format!("{kind}_full")))?,
+            read_number(&path.join(format!("{kind}_full_design"))).filter(|&d| d > 0.0)?,
+        ))
+    })?;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let health = (full / design * 100.0).round() as u32;
+    Some(health)
+}
+
+/// A sysfs value in micro-units; some drivers report the current as negative
+/// while discharging
+fn read_number(path: &Path) -> Option<f64> {
+    let value: i64 = read_trimmed(path)?.parse().ok()?;
+    #[allow(clippy::cast_precision_loss)]
+    let value = value.unsigned_abs() as f64;
+    Some(value)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let content = source::current().read_to_string(path).ok()?;
+    Some(content.trim().to_string())
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
@@ -0,0 +1,43 @@
+//! Entry points for the benchmarks in `benches/`
+//! Thin wrappers over internals that take fixture input instead of reading
+//! the live system; not a stable API
+
+use crate::config::Config;
+use crate::{art, edid, layout, modules, proc, utils};
+
+/// Used and total bytes from `/proc/meminfo` contents
+pub fn parse_meminfo(meminfo: &[u8]) -> (u64, u64) {
+    proc::parse_meminfo(meminfo, true)
+}
+
+/// Preferred resolution from a raw EDID block
+pub fn parse_edid(edid: &[u8]) -> Option<String> {
+    Some(edid::parse(edid)?.preferred()?.to_string())
+}
+
+/// Width of a compiled-in logo, found by distro name
+pub fn logo_width(name: &str) -> Option<usize> {
+    art::named(&Config::default(), name).map(|logo| logo.width)
+}
+
+pub fn strip_ansi(text: &str) -> String {
+    utils::strip_ansi(text)
+}
+
+/// A compiled-in logo next to made-up info, as `--logo-preview` draws it
+pub fn render(logo: &str, use_color: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+    if let Some(logo) = art::named(&Config::default(), logo) {
+        let lines = modules::sample_lines();
+        let _ = layout::render(
+            &mut out,
+            &logo.text,
+            logo.width,
+            &lines,
+            use_color,
+            &layout::Colors::PLAIN,
+            false,
+        );
+    }
+    out
+}
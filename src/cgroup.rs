@@ -0,0 +1,140 @@
+//! cgroup resource limits
+//! Memory and CPU limits of the cgroup we run in (and its ancestors), from
+//! cgroup v2 `memory.max`/`cpu.max` or the v1 equivalents
+
+use crate::source;
+use crate::utils::file_exists;
+use std::path::{Path, PathBuf};
+
+/// Effective limits, `None` where the cgroup doesn't restrict anything
+#[derive(Clone, Copy, Default)]
+pub struct Limits {
+    /// Current usage and limit of the tightest memory limit, in bytes
+    pub memory: Option<(u64, u64)>,
+    /// CPU bandwidth quota as a number of CPUs
+    pub cpus: Option<f64>,
+}
+
+/// Limits for the calling process
+pub fn limits() -> Limits {
+    let mut limits = Limits::default();
+    let Ok(membership) = source::current().read_to_string(Path::new("/proc/self/cgroup")) else {
+        return limits;
+    };
+
+    // Lines are `hierarchy-id:controllers:path`; v2 has no controller list
+    for line in membership.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (Some(_), Some(controllers), Some(path)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+
+        if controllers.is_empty() {
+            let Some(root) = v2_root() else {
+                continue;
+            };
+            let dirs = ancestors(&root, path);
+            limits.memory = limits.memory.or_else(|| tightest_memory(&dirs, v2_memory));
+            limits.cpus = limits.cpus.or_else(|| tightest_cpus(&dirs, v2_cpus));
+        } else {
+            let root = Path::new("/sys/fs/cgroup").join(controllers);
+            let dirs = ancestors(&root, path);
+            for controller in controllers.split(',') {
+                match controller {
+                    "memory" => limits.memory = tightest_memory(&dirs, v1_memory),
+                    "cpu" => limits.cpus = tightest_cpus(&dirs, v1_cpus),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    limits
+}
+
+/// Where the unified hierarchy is mounted, on pure v2 or hybrid systems
+fn v2_root() -> Option<PathBuf> {
+    ["/sys/fs/cgroup", "/sys/fs/cgroup/unified"]
+        .into_iter()
+        .map(PathBuf::from)
+        .find(|root| file_exists(&root.join("cgroup.controllers")))
+}
+
+/// The cgroup's directory and each parent up to the mount root
+///
+/// Inside a container without a cgroup namespace the path names a host
+/// cgroup that isn't visible, in which case only the mount root is used.
+fn ancestors(root: &Path, path: &str) -> Vec<PathBuf> {
+    let leaf = root.join(path.trim_start_matches('/'));
+    if !file_exists(&leaf) {
+        return vec![root.to_path_buf()];
+    }
+    leaf.ancestors()
+        .take_while(|dir| dir.starts_with(root))
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+fn tightest_memory(dirs: &[PathBuf], read: fn(&Path) -> Option<(u64, u64)>) -> Option<(u64, u64)> {
+    dirs.iter()
+        .filter_map(|dir| read(dir))
+        .min_by_key(|&(_, limit)| limit)
+}
+
+fn tightest_cpus(dirs: &[PathBuf], read: fn(&Path) -> Option<f64>) -> Option<f64> {
+    dirs.iter()
+        .filter_map(|dir| read(dir))
+        .min_by(f64::total_cmp)
+}
+
+fn read_number(path: &Path) -> Option<u64> {
+    source::current()
+        .read_to_string(path)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// `memory.max` is `max` when unlimited
+fn v2_memory(dir: &Path) -> Option<(u64, u64)> {
+    let limit = read_number(&dir.join("memory.max"))?;
+    Some((read_number(&dir.join("memory.current")).unwrap_or(0), limit))
+}
+
+/// `cpu.max` is `$QUOTA $PERIOD`, with `max` as the quota when unlimited
+fn v2_cpus(dir: &Path) -> Option<f64> {
+    let max = source::current()
+        .read_to_string(&dir.join("cpu.max"))
+        .ok()?;
+    let mut fields = max.split_whitespace();
+    let quota: u64 = fields.next()?.parse().ok()?;
+    let period: u64 = fields.next()?.parse().ok()?;
+    cpus(quota, period)
+}
+
+/// v1 reports "unlimited" as a huge page-aligned number
+fn v1_memory(dir: &Path) -> Option<(u64, u64)> {
+    let limit = read_number(&dir.join("memory.limit_in_bytes"))?;
+    if limit >= 1 << 62 {
+        return None;
+    }
+    Some((
+        read_number(&dir.join("memory.usage_in_bytes")).unwrap_or(0),
+        limit,
+    ))
+}
+
+/// `cpu.cfs_quota_us` is -1 when unlimited, which fails to parse as unsigned
+fn v1_cpus(dir: &Path) -> Option<f64> {
+    let quota = read_number(&dir.join("cpu.cfs_quota_us"))?;
+    let period = read_number(&dir.join("cpu.cfs_period_us"))?;
+    cpus(quota, period)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn cpus(quota: u64, period: u64) -> Option<f64> {
+    (period > 0).then(|| quota as f64 / period as f64)
+}
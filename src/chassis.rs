@@ -0,0 +1,55 @@
+//! Chassis / device type detection
+//! Classifies the machine from the hypervisor probe and the SMBIOS chassis
+//! type, falling back to power supply presence when firmware doesn't report
+//! anything useful
+
+use crate::battery;
+use crate::source;
+use crate::virt;
+use std::path::Path;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Chassis {
+    Desktop,
+    Laptop,
+    Server,
+    Vm,
+    Unknown,
+}
+
+impl Chassis {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Desktop => "Desktop",
+            Self::Laptop => "Laptop",
+            Self::Server => "Server",
+            Self::Vm => "VM",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Classify the machine we're running on
+pub fn detect() -> Chassis {
+    // Hypervisors report all sorts of chassis types, so check for one first
+    if virt::detect_hypervisor().is_some() {
+        return Chassis::Vm;
+    }
+
+    let chassis_type = source::current()
+        .read_to_string(Path::new("/sys/class/dmi/id/chassis_type"))
+        .ok()
+        .and_then(|s| s.trim().parse::<u8>().ok());
+
+    match chassis_type {
+        // Portable, Laptop, Notebook, Hand Held, Sub Notebook, Tablet, Convertible, Detachable
+        Some(8..=11 | 14 | 30..=32) => Chassis::Laptop,
+        // Desktop, Low Profile, Pizza Box, Mini Tower, Tower, All in One, Space-saving,
+        // Lunch Box, Sealed-case PC, Mini PC, Stick PC
+        Some(3..=7 | 13 | 15 | 16 | 24 | 35 | 36) => Chassis::Desktop,
+        // Main Server Chassis, Rack Mount, Multi-system, Blade, Blade Enclosure
+        Some(17 | 23 | 25 | 28 | 29) => Chassis::Server,
+        _ if battery::has_system_battery() => Chassis::Laptop,
+        _ => Chassis::Unknown,
+    }
+}
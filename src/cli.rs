@@ -0,0 +1,605 @@
+//! Command line argument parsing
+//! Hand-rolled to keep startup cost and dependencies at zero
+
+use crate::color::Color;
+use crate::completions::{self, Shell};
+use crate::config::{CpuFrequency, Hyperlinks, PaletteSource};
+use crate::export::Format;
+use crate::modules::Module;
+use crate::output::Target;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+/// Parsed command line options
+///
+/// Settings that can also come from the config file are `None` when not given.
+#[derive(Default)]
+pub struct Args {
+    pub config: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub cpu_frequency: Option<CpuFrequency>,
+    pub gpu_stats: bool,
+    pub hyperlinks: Option<Hyperlinks>,
+    pub accessible: bool,
+    pub privacy: bool,
+    pub show_unknown: bool,
+    pub output: Option<Target>,
+    pub format: Option<Format>,
+    pub logo_colors: Option<Vec<Color>>,
+    pub palette: Option<PaletteSource>,
+    pub banner: bool,
+    pub banner_text: Option<String>,
+    pub watch: Option<Duration>,
+    pub motd: bool,
+    pub screenshot: bool,
+    pub logo_only: bool,
+    pub info_only: bool,
+    /// Lay out and color the output as for a terminal even when it isn't one
+    pub force_ansi: bool,
+    pub serve_lines: bool,
+    /// Modules whose values alone are printed, from `--get`
+    pub get: Option<Vec<Module>>,
+    pub verbosity: u8,
+    /// A `--fixture` directory or `--replay` archive
+    pub fixture: Option<PathBuf>,
+    pub dump_probe_data: Option<PathBuf>,
+    pub list_logos: bool,
+    pub logo_preview: Option<String>,
+}
+
+/// Redraw interval of `--watch` without a value
+const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Description of a single command line option, used for parsing, help output,
+/// shell completions and the man page
+///
+/// A value name in brackets (`[seconds]`) marks the value as optional.
+pub struct OptSpec {
+    pub long: &'static str,
+    pub short: Option<char>,
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+/// What an option's value can be completed with
+pub enum ValueKind {
+    /// Takes no value, or one that can't be completed
+    None,
+    Choices(Vec<&'static str>),
+    File,
+    Dir,
+    Logo,
+}
+
+impl OptSpec {
+    pub fn value_kind(&self) -> ValueKind {
+        match self.value {
+            None => ValueKind::None,
+            _ if self.long == "logo-preview" => ValueKind::Logo,
+            Some("dir") => ValueKind::Dir,
+            Some(value) if value.contains("path") || value == "file" => ValueKind::File,
+            Some(value) if value.contains('|') => ValueKind::Choices(value.split('|').collect()),
+            Some(_) => ValueKind::None,
+        }
+    }
+}
+
+pub static OPTIONS: &[OptSpec] = &[
+    OptSpec {
+        long: "config",
+        short: Some('c'),
+        value: Some("path"),
+        help: "Read the config from this file instead of the default location",
+    },
+    OptSpec {
+        long: "profile",
+        short: Some('p'),
+        value: Some("name"),
+        help: "Apply the settings of a [profile.<name>] table from the config",
+    },
+    OptSpec {
+        long: "cpu-freq",
+        short: None,
+        value: Some("max|current"),
+        help: "Show the rated max or the current average CPU frequency",
+    },
+    OptSpec {
+        long: "gpu-stats",
+        short: None,
+        value: None,
+        help: "Show GPU utilization and temperature next to its name",
+    },
+    OptSpec {
+        long: "hyperlinks",
+        short: None,
+        value: Some("auto|always|never"),
+        help: "Render OS, Kernel and Theme as clickable terminal links",
+    },
+    OptSpec {
+        long: "logo-colors",
+        short: None,
+        value: Some("N..."),
+        help: "Replace the logo colors ${c1}, ${c2}, ... (0-255 or #rrggbb)",
+    },
+    OptSpec {
+        long: "palette",
+        short: None,
+        value: Some("none|wal|terminal"),
+        help: "Recolor logo and accents to the pywal or terminal palette",
+    },
+    OptSpec {
+        long: "list-logos",
+        short: None,
+        value: None,
+        help: "List the names of all built-in logos and exit",
+    },
+    OptSpec {
+        long: "logo-preview",
+        short: None,
+        value: Some("name"),
+        help: "Show a built-in logo next to sample info and exit",
+    },
+    OptSpec {
+        long: "banner",
+        short: None,
+        value: None,
+        help: "Draw the hostname as large text instead of the logo",
+    },
+    OptSpec {
+        long: "banner-text",
+        short: None,
+        value: Some("text"),
+        help: "Draw this text as the banner instead of the hostname",
+    },
+    OptSpec {
+        long: "watch",
+        short: None,
+        value: Some("[seconds]"),
+        help: "Redraw every N seconds (default 2), accenting values that changed",
+    },
+    OptSpec {
+        long: "motd",
+        short: None,
+        value: None,
+        help: "Print plain fixed-width text for /etc/motd or an SSH banner",
+    },
+    OptSpec {
+        long: "screenshot",
+        short: None,
+        value: None,
+        help: "Center the output in the terminal, ready for a screenshot",
+    },
+    OptSpec {
+        long: "logo-only",
+        short: None,
+        value: None,
+        help: "Print only the logo, for scripts that lay out the info themselves",
+    },
+    OptSpec {
+        long: "info-only",
+        short: None,
+        value: None,
+        help: "Print only the info lines, without the logo",
+    },
+    OptSpec {
+        long: "force-ansi",
+        short: None,
+        value: None,
+        help: "Keep the logo and colors when the output isn't a terminal",
+    },
+    OptSpec {
+        long: "get",
+        short: None,
+        value: Some("module,..."),
+        help: "Print only these modules' values, running only their probes",
+    },
+    OptSpec {
+        long: "serve-lines",
+        short: None,
+        value: None,
+        help: "Stay resident, answering each module name on stdin with its value",
+    },
+    OptSpec {
+        long: "accessible",
+        short: None,
+        value: None,
+        help: "Screen-reader friendly output without logo or glyphs",
+    },
+    OptSpec {
+        long: "privacy",
+        short: None,
+        value: None,
+        help: "Hide lines that identify this machine",
+    },
+    OptSpec {
+        long: "show-unknown",
+        short: None,
+        value: None,
+        help: "Keep lines that couldn't be detected, even inside a sandbox",
+    },
+    OptSpec {
+        long: "output",
+        short: Some('o'),
+        value: Some("path|fd:N|unix:path"),
+        help: "Write output to a file (atomically), descriptor or unix socket",
+    },
+    OptSpec {
+        long: "format",
+        short: None,
+        value: Some("terminal|html|markdown|prometheus|env|svg|png"),
+        help: "Write terminal output, HTML, Markdown, metrics, shell variables or an image",
+    },
+    OptSpec {
+        long: "verbose",
+        short: Some('v'),
+        value: None,
+        help: "Report on stderr why values couldn't be detected (-vv: every probe tried)",
+    },
+    OptSpec {
+        long: "fixture",
+        short: None,
+        value: Some("dir"),
+        help: "Read system data from a recorded machine instead (for tests)",
+    },
+    OptSpec {
+        long: "dump-probe-data",
+        short: None,
+        value: Some("file"),
+        help: "Also save the files and command output read, for a bug report",
+    },
+    OptSpec {
+        long: "replay",
+        short: None,
+        value: Some("file"),
+        help: "Render from a capture saved by --dump-probe-data",
+    },
+    OptSpec {
+        long: "generate-man",
+        short: None,
+        value: None,
+        help: "Print a man page and exit",
+    },
+    OptSpec {
+        long: "help",
+        short: Some('h'),
+        value: None,
+        help: "Print this help and exit",
+    },
+    OptSpec {
+        long: "version",
+        short: Some('V'),
+        value: None,
+        help: "Print version and exit",
+    },
+];
+
+/// Parse the process arguments, exiting on `--help`, `--version` or invalid input
+pub fn parse() -> Args {
+    match parse_from(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("tachi-fetch: {msg}");
+            eprintln!("Try 'tachi-fetch --help' for more information.");
+            process::exit(2);
+        }
+    }
+}
+
+fn parse_from(iter: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut iter = iter.peekable();
+
+    if iter.next_if(|arg| arg == "completions").is_some() {
+        let name = iter
+            .next()
+            .ok_or_else(|| "'completions' requires a shell (bash, zsh or fish)".to_string())?;
+        let shell = Shell::parse(&name)
+            .ok_or_else(|| format!("unsupported shell '{name}', expected bash, zsh or fish"))?;
+        completions::print(shell);
+        process::exit(0);
+    }
+
+    while let Some(arg) = iter.next() {
+        // `-vv` is `-v` given twice
+        if let Some(vs) = arg.strip_prefix('-')
+            && vs.len() > 1
+            && vs.bytes().all(|b| b == b'v')
+        {
+            args.verbosity = args
+                .verbosity
+                .saturating_add(vs.len().try_into().unwrap_or(u8::MAX));
+            continue;
+        }
+
+        let (spec, inline_value) = lookup(&arg)?;
+
+        let value = match (spec.value, inline_value) {
+            (Some(_), Some(v)) => Some(v),
+            // An optional value is only taken when the next argument isn't an option
+            (Some(name), None) if name.starts_with('[') => iter.next_if(|a| !a.starts_with('-')),
+            (Some(_), None) => Some(
+                iter.next()
+                    .ok_or_else(|| format!("option '--{}' requires a value", spec.long))?,
+            ),
+            (None, Some(_)) => {
+                return Err(format!("option '--{}' doesn't take a value", spec.long));
+            }
+            (None, None) => None,
+        };
+
+        match (spec.long, value.as_deref()) {
+            ("config", Some(v)) => args.config = Some(PathBuf::from(v)),
+            ("profile", Some(v)) => args.profile = Some(v.to_string()),
+            ("cpu-freq", Some(v)) => {
+                args.cpu_frequency = Some(
+                    CpuFrequency::parse(v)
+                        .ok_or_else(|| format!("invalid value '{v}' for '--cpu-freq'"))?,
+                );
+            }
+            ("hyperlinks", Some(v)) => {
+                args.hyperlinks = Some(
+                    Hyperlinks::parse(v)
+                        .ok_or_else(|| format!("invalid value '{v}' for '--hyperlinks'"))?,
+                );
+            }
+            ("logo-colors", Some(v)) => {
+                // Accept both `--logo-colors 4 6` and `--logo-colors=4,6`
+                let mut colors = parse_colors(v)?;
+                while let Some(next) = iter.next_if(|a| Color::parse(a).is_some()) {
+                    colors.extend(parse_colors(&next)?);
+                }
+                args.logo_colors = Some(colors);
+            }
+            ("palette", Some(v)) => {
+                args.palette = Some(
+                    PaletteSource::parse(v)
+                        .ok_or_else(|| format!("invalid value '{v}' for '--palette'"))?,
+                );
+            }
+            ("list-logos", _) => args.list_logos = true,
+            ("logo-preview", Some(v)) => args.logo_preview = Some(v.to_string()),
+            ("banner", _) => args.banner = true,
+            ("banner-text", Some(v)) => args.banner_text = Some(v.to_string()),
+            ("watch", None) => args.watch = Some(DEFAULT_WATCH_INTERVAL),
+            ("watch", Some(v)) => {
+                args.watch = Some(
+                    v.parse::<f64>()
+                        .ok()
+                        .filter(|secs| *secs > 0.0)
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .ok_or_else(|| format!("invalid interval '{v}' for '--watch'"))?,
+                );
+            }
+            ("motd", _) => args.motd = true,
+            ("screenshot", _) => args.screenshot = true,
+            ("logo-only", _) => args.logo_only = true,
+            ("info-only", _) => args.info_only = true,
+            ("force-ansi", _) => args.force_ansi = true,
+            ("serve-lines", _) => args.serve_lines = true,
+            ("get", Some(v)) => args.get = Some(parse_modules(v)?),
+            ("verbose", _) => args.verbosity = args.verbosity.saturating_add(1),
+            ("fixture" | "replay", Some(v)) => args.fixture = Some(PathBuf::from(v)),
+            ("dump-probe-data", Some(v)) => args.dump_probe_data = Some(PathBuf::from(v)),
+            ("gpu-stats", _) => args.gpu_stats = true,
+            ("accessible", _) => args.accessible = true,
+            ("privacy", _) => args.privacy = true,
+            ("show-unknown", _) => args.show_unknown = true,
+            ("output", Some(v)) => args.output = Some(Target::parse(v)?),
+            ("format", Some(v)) => {
+                args.format = Some(
+                    Format::parse(v)
+                        .ok_or_else(|| format!("invalid value '{v}' for '--format'"))?,
+                );
+            }
+            ("generate-man", _) => {
+                print_man();
+                process::exit(0);
+            }
+            ("help", _) => {
+                print_help();
+                process::exit(0);
+            }
+            ("version", _) => {
+                println!("tachi-fetch {}", env!("CARGO_PKG_VERSION"));
+                process::exit(0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    if args.motd && args.watch.is_some() {
+        return Err("'--motd' can't be combined with '--watch'".to_string());
+    }
+    if args.screenshot && (args.motd || args.watch.is_some()) {
+        return Err("'--screenshot' can't be combined with '--motd' or '--watch'".to_string());
+    }
+    if args.format.is_some_and(|f| f != Format::Terminal)
+        && (args.motd || args.screenshot || args.watch.is_some())
+    {
+        return Err(
+            "'--format' other than terminal can't be combined with '--motd', '--screenshot' or '--watch'"
+                .to_string(),
+        );
+    }
+    if args.serve_lines
+        && (args.motd
+            || args.screenshot
+            || args.watch.is_some()
+            || args.format.is_some_and(|f| f != Format::Terminal))
+    {
+        return Err(
+            "'--serve-lines' can't be combined with '--motd', '--screenshot', '--watch' or '--format'"
+                .to_string(),
+        );
+    }
+    if args.logo_only
+        && (args.info_only
+            || args.watch.is_some()
+            || args.serve_lines
+            || args.format.is_some_and(|f| f != Format::Terminal))
+    {
+        return Err(
+            "'--logo-only' can't be combined with '--info-only', '--watch', '--serve-lines' or '--format'"
+                .to_string(),
+        );
+    }
+    if args.get.is_some()
+        && (args.motd
+            || args.screenshot
+            || args.watch.is_some()
+            || args.serve_lines
+            || args.logo_only
+            || args.format.is_some_and(|f| f != Format::Terminal))
+    {
+        return Err(
+            "'--get' can't be combined with '--motd', '--screenshot', '--watch', '--serve-lines', '--logo-only' or '--format'"
+                .to_string(),
+        );
+    }
+    if args.dump_probe_data.is_some()
+        && (args.fixture.is_some() || args.watch.is_some() || args.serve_lines)
+    {
+        return Err(
+            "'--dump-probe-data' can't be combined with '--replay', '--fixture', '--watch' or '--serve-lines'"
+                .to_string(),
+        );
+    }
+
+    Ok(args)
+}
+
+fn parse_colors(value: &str) -> Result<Vec<Color>, String> {
+    value
+        .split(',')
+        .map(|c| {
+            Color::parse(c.trim())
+                .ok_or_else(|| format!("invalid color '{c}', expected 0-255 or #rrggbb"))
+        })
+        .collect()
+}
+
+fn parse_modules(value: &str) -> Result<Vec<Module>, String> {
+    value
+        .split(',')
+        .map(|name| {
+            Module::from_name(name.trim()).ok_or_else(|| format!("unknown module '{name}'"))
+        })
+        .collect()
+}
+
+/// Find the spec matching an argument, splitting off an inline `--opt=value`
+fn lookup(arg: &str) -> Result<(&'static OptSpec, Option<String>), String> {
+    if let Some(long) = arg.strip_prefix("--") {
+        let (name, value) = match long.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (long, None),
+        };
+        OPTIONS
+            .iter()
+            .find(|spec| spec.long == name)
+            .map(|spec| (spec, value))
+            .ok_or_else(|| format!("unrecognized option '{arg}'"))
+    } else if let Some(short) = arg.strip_prefix('-')
+        && short.chars().count() == 1
+    {
+        let c = short.chars().next();
+        OPTIONS
+            .iter()
+            .find(|spec| spec.short == c)
+            .map(|spec| (spec, None))
+            .ok_or_else(|| format!("invalid option '{arg}'"))
+    } else {
+        Err(format!("unexpected argument '{arg}'"))
+    }
+}
+
+fn print_help() {
+    println!(
+        "Usage: tachi-fetch [OPTIONS]\n       tachi-fetch completions <bash|zsh|fish>\n\nOptions:"
+    );
+    for spec in OPTIONS {
+        let short = spec
+            .short
+            .map_or_else(|| "    ".to_string(), |c| format!("-{c}, "));
+        let long = spec.value.map_or_else(
+            || format!("--{}", spec.long),
+            |value| {
+                if value.starts_with('[') {
+                    format!("--{} {value}", spec.long)
+                } else {
+                    format!("--{} <{value}>", spec.long)
+                }
+            },
+        );
+        println!("  {short}{long:<28} {}", spec.help);
+    }
+}
+
+/// A man page in roff, from the same option table as `--help`
+fn print_man() {
+    let mut page = format!(
+        ".TH TACHI-FETCH 1 \"\" \"tachi-fetch {}\" \"User Commands\"\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    page.push_str(
+        ".SH NAME\n\
+         tachi-fetch \\- show system information next to a distribution logo\n\
+         .SH SYNOPSIS\n\
+         .B tachi-fetch\n\
+         [\\fIOPTIONS\\fR]\n\
+         .br\n\
+         .B tachi-fetch completions\n\
+         \\fIbash\\fR|\\fIzsh\\fR|\\fIfish\\fR\n\
+         .SH DESCRIPTION\n\
+         Prints details about the running system, such as the distribution, kernel,\n\
+         shell, desktop and hardware, next to the distribution's logo.\n\
+         The \\fBcompletions\\fR command prints a completion script for the given shell.\n\
+         .SH OPTIONS\n",
+    );
+    for spec in OPTIONS {
+        let mut names = Vec::new();
+        if let Some(short) = spec.short {
+            names.push(format!("\\fB\\-{short}\\fR"));
+        }
+        names.push(format!("\\fB\\-\\-{}\\fR", roff_escape(spec.long)));
+        let mut line = names.join(", ");
+        if let Some(value) = spec.value {
+            let value = roff_escape(value);
+            if let Some(optional) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                line.push_str(&format!(" [\\fI{optional}\\fR]"));
+            } else {
+                line.push_str(&format!(" \\fI{value}\\fR"));
+            }
+        }
+        page.push_str(&format!(".TP\n{line}\n{}\n", roff_escape(spec.help)));
+    }
+    page.push_str(
+        ".SH FILES\n\
+         .TP\n\
+         .I $XDG_CONFIG_HOME/tachifetch/config.toml\n\
+         The config file, in \\fI~/.config\\fR when \\fBXDG_CONFIG_HOME\\fR is unset.\n\
+         .SH ENVIRONMENT\n\
+         .TP\n\
+         .B NO_COLOR\n\
+         Any non\\-empty value disables colors.\n\
+         .TP\n\
+         .B CLICOLOR_FORCE\n\
+         Any value other than 0 enables colors, even when not writing to a terminal.\n\
+         .TP\n\
+         .B CLICOLOR\n\
+         0 disables colors.\n",
+    );
+    print!("{page}");
+}
+
+/// Text with roff's special characters escaped
+fn roff_escape(text: &str) -> String {
+    let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+    // A leading `.` or `'` would start a request
+    if escaped.starts_with(['.', '\'']) {
+        format!("\\&{escaped}")
+    } else {
+        escaped
+    }
+}
@@ -0,0 +1,163 @@
+//! Color output policy
+//! Decides whether ANSI escape sequences should be emitted, following the
+//! `NO_COLOR`, `CLICOLOR`/`CLICOLOR_FORCE` and `FORCE_COLOR` conventions
+
+use crate::utils::ENV_CACHE;
+use std::sync::OnceLock;
+
+/// Resolve the color policy from the environment
+///
+/// Precedence, highest first:
+/// 1. `FORCE_COLOR` - `0`/`false` disables, any other value enables
+/// 2. `CLICOLOR_FORCE` - any value other than `0` enables
+/// 3. `NO_COLOR` - any non-empty value disables
+/// 4. `CLICOLOR=0` - disables
+/// 5. Otherwise colors are used only when the output is a terminal
+pub fn colors_enabled(is_terminal: bool) -> bool {
+    if let Some(force) = ENV_CACHE.get("FORCE_COLOR") {
+        return !matches!(*force, "0" | "false");
+    }
+
+    if ENV_CACHE.get("CLICOLOR_FORCE").is_some_and(|v| *v != "0") {
+        return true;
+    }
+
+    if ENV_CACHE.get("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+
+    if ENV_CACHE.get("CLICOLOR").is_some_and(|v| *v == "0") {
+        return false;
+    }
+
+    is_terminal
+}
+
+/// Color capability of the terminal
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Depth {
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+/// Depth to use instead of the detected one, for output that isn't shown on
+/// the current terminal
+static DEPTH_OVERRIDE: OnceLock<Depth> = OnceLock::new();
+
+/// Use `depth` whatever the current terminal supports
+pub fn override_depth(depth: Depth) {
+    let _ = DEPTH_OVERRIDE.set(depth);
+}
+
+/// The color depth to use
+pub fn depth() -> Depth {
+    DEPTH_OVERRIDE.get().copied().unwrap_or_else(detect_depth)
+}
+
+/// Detect the color depth from `COLORTERM`, falling back to `TERM`
+fn detect_depth() -> Depth {
+    if ENV_CACHE
+        .get("COLORTERM")
+        .is_some_and(|v| matches!(*v, "truecolor" | "24bit"))
+    {
+        return Depth::TrueColor;
+    }
+
+    if ENV_CACHE
+        .get("TERM")
+        .is_some_and(|t| t.contains("256color"))
+    {
+        return Depth::Ansi256;
+    }
+
+    Depth::Ansi16
+}
+
+/// A logo or accent color
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Index into the terminal palette, used as-is at every depth
+    Palette(u8),
+    /// 24-bit color, downgraded when the terminal can't show it
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Parse a palette index (`4`) or a hex color (`#89b4fa`)
+    pub fn parse(value: &str) -> Option<Self> {
+        let Some(hex) = value.strip_prefix('#') else {
+            return value.parse().ok().map(Self::Palette);
+        };
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Self::Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// Bold foreground escape sequence for this color at the given depth
+    pub fn sequence(self, depth: Depth) -> String {
+        match self {
+            Self::Palette(n) if n <= 7 => format!("\x1b[1;{}m", 30 + n),
+            Self::Palette(n) => format!("\x1b[1;38;5;{n}m"),
+            Self::Rgb(r, g, b) => match depth {
+                Depth::TrueColor => format!("\x1b[1;38;2;{r};{g};{b}m"),
+                Depth::Ansi256 => Self::Palette(to_256(r, g, b)).sequence(depth),
+                Depth::Ansi16 => Self::Palette(to_8(r, g, b)).sequence(depth),
+            },
+        }
+    }
+}
+
+/// Nearest entry of the 6x6x6 color cube or the grayscale ramp
+fn to_256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            // White is closer than the last gray (238) from here on
+            248..=255 => 231,
+            // 24 grays from 8 to 238 in steps of 10
+            _ => 232 + (r - 8) / 10,
+        };
+    }
+    let cube = |c: u8| match c {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (c - 35) / 40,
+    };
+    16 + 36 * cube(r) + 6 * cube(g) + cube(b)
+}
+
+/// Basic color with the same dominant channels; the bold attribute makes the
+/// terminal show its bright variant
+fn to_8(r: u8, g: u8, b: u8) -> u8 {
+    let max = r.max(g).max(b);
+    if max < 64 {
+        // Black would vanish on most backgrounds
+        return 7;
+    }
+    let on = |c: u8| u8::from(u16::from(c) * 5 >= u16::from(max) * 3);
+    on(r) | on(g) << 1 | on(b) << 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_256;
+
+    #[test]
+    fn grays_use_the_ramp_and_the_cube_ends() {
+        assert_eq!(to_256(0, 0, 0), 16);
+        assert_eq!(to_256(8, 8, 8), 232);
+        assert_eq!(to_256(238, 238, 238), 255);
+        assert_eq!(to_256(247, 247, 247), 255);
+        assert_eq!(to_256(248, 248, 248), 231);
+        assert_eq!(to_256(255, 255, 255), 231);
+    }
+
+    #[test]
+    fn colors_use_the_cube() {
+        assert_eq!(to_256(255, 0, 0), 196);
+        assert_eq!(to_256(0, 135, 255), 33);
+    }
+}
@@ -0,0 +1,195 @@
+//! Shell completion scripts
+//! Generated from the command line option table, so they can't fall behind
+//! the options the binary actually accepts
+
+use crate::cli::{OPTIONS, OptSpec, ValueKind};
+
+#[derive(Clone, Copy)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "bash" => Some(Self::Bash),
+            "zsh" => Some(Self::Zsh),
+            "fish" => Some(Self::Fish),
+            _ => None,
+        }
+    }
+}
+
+const SHELLS: &str = "bash zsh fish";
+
+/// Print the completion script for `shell`
+pub fn print(shell: Shell) {
+    let script = match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+    };
+    print!("{script}");
+}
+
+/// Both spellings of an option, e.g. `-c --config`
+fn names(spec: &OptSpec) -> Vec<String> {
+    let mut names: Vec<String> = spec.short.map(|c| format!("-{c}")).into_iter().collect();
+    names.push(format!("--{}", spec.long));
+    names
+}
+
+fn bash() -> String {
+    let mut values = String::new();
+    let mut no_values = Vec::new();
+    for spec in OPTIONS {
+        let words = match spec.value_kind() {
+            ValueKind::Choices(choices) => format!("-W \"{}\"", choices.join(" ")),
+            ValueKind::File => "-f".to_string(),
+            ValueKind::Dir => "-d".to_string(),
+            ValueKind::Logo => "-W \"$(tachi-fetch --list-logos 2>/dev/null)\"".to_string(),
+            // An optional value is never required, so offer the options instead
+            ValueKind::None if spec.value.is_some_and(|v| !v.starts_with('[')) => {
+                no_values.extend(names(spec));
+                continue;
+            }
+            ValueKind::None => continue,
+        };
+        values.push_str(&format!(
+            "        {})\n            COMPREPLY=($(compgen {words} -- \"$cur\"))\n            return\n            ;;\n",
+            names(spec).join("|")
+        ));
+    }
+    if !no_values.is_empty() {
+        values.push_str(&format!(
+            "        {})\n            return\n            ;;\n",
+            no_values.join("|")
+        ));
+    }
+
+    let options: Vec<String> = OPTIONS.iter().flat_map(names).collect();
+    format!(
+        r#"_tachi_fetch() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+
+    if [[ ${{COMP_WORDS[1]}} == completions ]]; then
+        [[ $COMP_CWORD -eq 2 ]] && COMPREPLY=($(compgen -W "{SHELLS}" -- "$cur"))
+        return
+    fi
+
+    case "$prev" in
+{values}    esac
+
+    if [[ $COMP_CWORD -eq 1 && $cur != -* ]]; then
+        COMPREPLY=($(compgen -W "completions" -- "$cur"))
+        return
+    fi
+    COMPREPLY=($(compgen -W "{options}" -- "$cur"))
+}}
+
+complete -F _tachi_fetch tachi-fetch
+"#,
+        options = options.join(" ")
+    )
+}
+
+fn zsh() -> String {
+    // `[`, `]` and `:` separate the fields of an `_arguments` spec
+    let escape = |text: &str| {
+        text.replace('\'', r"'\''")
+            .replace('[', r"\[")
+            .replace(']', r"\]")
+            .replace(':', r"\:")
+    };
+
+    let mut specs = String::new();
+    for spec in OPTIONS {
+        let names = names(spec);
+        let name = if spec.long == "verbose" {
+            // Repeatable
+            format!("'*'{{{}}}", names.join(","))
+        } else if names.len() > 1 {
+            format!("'({})'{{{}}}", names.join(" "), names.join(","))
+        } else {
+            names[0].clone()
+        };
+
+        let value = match (spec.value, spec.value_kind()) {
+            (None, _) => String::new(),
+            (Some(value), kind) => {
+                let action = match kind {
+                    ValueKind::Choices(choices) => format!("({})", choices.join(" ")),
+                    ValueKind::File => "_files".to_string(),
+                    ValueKind::Dir => "_files -/".to_string(),
+                    ValueKind::Logo => "($(tachi-fetch --list-logos 2>/dev/null))".to_string(),
+                    ValueKind::None => " ".to_string(),
+                };
+                let (colons, value) = match value.strip_prefix('[') {
+                    Some(optional) => ("::", optional.trim_end_matches(']')),
+                    None => (":", value),
+                };
+                format!("{colons}{}:{action}", escape(value))
+            }
+        };
+        specs.push_str(&format!(
+            "        {name}'[{}]{value}' \\\n",
+            escape(spec.help)
+        ));
+    }
+
+    format!(
+        r#"#compdef tachi-fetch
+
+_tachi-fetch() {{
+    if [[ $words[2] == completions ]]; then
+        (( CURRENT == 3 )) && _values 'shell' {SHELLS}
+        return
+    fi
+    if (( CURRENT == 2 )) && [[ $PREFIX != -* ]]; then
+        _values 'command' 'completions[print a shell completion script]'
+        return
+    fi
+
+    _arguments -s \
+{specs}        && return 0
+}}
+
+_tachi-fetch "$@"
+"#
+    )
+}
+
+fn fish() -> String {
+    let quote = |text: &str| format!("'{}'", text.replace('\\', r"\\").replace('\'', r"\'"));
+
+    let mut script = format!(
+        "complete -c tachi-fetch -n __fish_use_subcommand -f -a completions -d {}\n\
+         complete -c tachi-fetch -n '__fish_seen_subcommand_from completions' -f -a '{SHELLS}'\n",
+        quote("Print a shell completion script")
+    );
+    for spec in OPTIONS {
+        let mut line = "complete -c tachi-fetch".to_string();
+        if let Some(short) = spec.short {
+            line.push_str(&format!(" -s {short}"));
+        }
+        line.push_str(&format!(" -l {}", spec.long));
+        match spec.value_kind() {
+            ValueKind::Choices(choices) => {
+                line.push_str(&format!(" -x -a {}", quote(&choices.join(" "))));
+            }
+            ValueKind::File => line.push_str(" -r -F"),
+            ValueKind::Dir => line.push_str(" -x -a '(__fish_complete_directories)'"),
+            ValueKind::Logo => line.push_str(" -x -a '(tachi-fetch --list-logos 2>/dev/null)'"),
+            ValueKind::None if spec.value.is_some_and(|v| !v.starts_with('[')) => {
+                line.push_str(" -x");
+            }
+            ValueKind::None => {}
+        }
+        line.push_str(&format!(" -d {}\n", quote(spec.help)));
+        script.push_str(&line);
+    }
+    script
+}
@@ -0,0 +1,1317 @@
+//! User configuration
+//! Read from `$XDG_CONFIG_HOME/tachifetch/config.toml` (falling back to
+//! `~/.config/tachifetch/config.toml`), with command line flags applied on top
+
+use crate::backend::{self, Backend};
+use crate::chassis::{self, Chassis};
+use crate::cli::Args;
+use crate::color::Color;
+use crate::display;
+use crate::i18n;
+use crate::log;
+use crate::modules::Module;
+use crate::packages;
+use crate::palette::{self, Palette};
+use crate::scheme::{Paint, Registry, Scheme};
+use crate::source;
+use crate::theme;
+use crate::toml::{self, Table, Value};
+use crate::utils::{expand_path, get_env_var};
+use crate::virt;
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Which CPU frequency to show on the CPU line
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CpuFrequency {
+    /// Rated maximum from `cpuinfo_max_freq`
+    Max,
+    /// Live average of `scaling_cur_freq` across cores, plus the governor
+    Current,
+}
+
+impl CpuFrequency {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "max" => Some(Self::Max),
+            "current" => Some(Self::Current),
+            _ => None,
+        }
+    }
+}
+
+/// Unit temperatures are shown in
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TempUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "c" | "celsius" => Some(Self::Celsius),
+            "f" | "fahrenheit" => Some(Self::Fahrenheit),
+            _ => None,
+        }
+    }
+
+    /// Degrees in this unit, and the unit's letter
+    pub fn convert(self, celsius: f64) -> (f64, char) {
+        match self {
+            Self::Celsius => (celsius, 'C'),
+            Self::Fahrenheit => (celsius * 9.0 / 5.0 + 32.0, 'F'),
+        }
+    }
+}
+
+/// What the separator under the title looks like
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SeparatorStyle {
+    /// A fixed row of dashes
+    Fixed,
+    /// Dashes as wide as the title
+    Title,
+    /// Left out
+    None,
+}
+
+impl SeparatorStyle {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "fixed" => Some(Self::Fixed),
+            "title" => Some(Self::Title),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Icons before the keys
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum IconStyle {
+    None,
+    /// Glyphs from a patched Nerd Font
+    NerdFont,
+    /// Punctuation marks, for any font
+    Ascii,
+}
+
+impl IconStyle {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "nerd-font" => Some(Self::NerdFont),
+            "ascii" => Some(Self::Ascii),
+            _ => None,
+        }
+    }
+}
+
+/// Where to take the palette the logo and accents are recolored with
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PaletteSource {
+    /// Keep the configured colors
+    None,
+    /// pywal's `~/.cache/wal/colors`
+    Wal,
+    /// Asked from the terminal
+    Terminal,
+}
+
+impl PaletteSource {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "wal" => Some(Self::Wal),
+            "terminal" => Some(Self::Terminal),
+            _ => None,
+        }
+    }
+}
+
+/// When to emit OSC 8 hyperlinks
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Hyperlinks {
+    /// Only on terminals detected as supporting them
+    Auto,
+    Always,
+    Never,
+}
+
+impl Hyperlinks {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+/// How "used" memory is computed on the Memory line
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MemoryUsed {
+    /// Total minus free, buffers, cache and reclaimable slab, plus shmem
+    Formula,
+    /// Total minus the kernel's `MemAvailable` estimate, as free(1) does
+    Available,
+}
+
+impl MemoryUsed {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "formula" => Some(Self::Formula),
+            "available" => Some(Self::Available),
+            _ => None,
+        }
+    }
+}
+
+/// How usage is shown on each Disk line
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DiskStyle {
+    /// `16.6 GiB / 252.0 GiB (6%)`
+    Usage,
+    /// `6%`
+    Percent,
+    /// `[█░░░░░░░░░] 6%`
+    Bar,
+}
+
+impl DiskStyle {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "usage" => Some(Self::Usage),
+            "percent" => Some(Self::Percent),
+            "bar" => Some(Self::Bar),
+            _ => None,
+        }
+    }
+}
+
+pub struct DiskConfig {
+    /// Mount points to show, in order; `*` stands for every mounted filesystem
+    pub show: Vec<String>,
+    /// Leave tmpfs, proc, squashfs images and the like out of `*`
+    pub hide_pseudo: bool,
+    pub style: DiskStyle,
+}
+
+/// Percentages drawn as bars, from the `[bar]` table
+pub struct BarConfig {
+    /// Modules whose percentage is drawn as a bar; Disk listed here is the
+    /// same as `disk_style = "bar"`
+    pub modules: Vec<Module>,
+    /// Cells per bar
+    pub width: usize,
+    pub filled: char,
+    pub empty: char,
+    /// Percent used at which a bar turns yellow; batteries turn yellow at
+    /// this much charge left from full
+    pub warning: u64,
+    /// Percent used at which a bar turns red
+    pub critical: u64,
+}
+
+/// How the next greeting is picked from the greeting file
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum GreetingOrder {
+    /// Cycle through the lines, remembering the position between runs
+    Rotate,
+    Random,
+}
+
+impl GreetingOrder {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "rotate" => Some(Self::Rotate),
+            "random" => Some(Self::Random),
+            _ => None,
+        }
+    }
+}
+
+/// Whether cgroup memory/CPU limits are shown on the Memory and CPU lines
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CgroupLimits {
+    Off,
+    /// Host totals, with the limits after them
+    Alongside,
+    /// The cgroup's usage and limit instead of the host's
+    Replace,
+}
+
+impl CgroupLimits {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "alongside" => Some(Self::Alongside),
+            "replace" => Some(Self::Replace),
+            _ => None,
+        }
+    }
+}
+
+pub struct GreetingConfig {
+    /// File with one closing line per line; `#` starts a comment
+    pub file: Option<PathBuf>,
+    pub order: GreetingOrder,
+}
+
+/// Colors allowed in `--motd` output
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MotdColor {
+    None,
+    /// The 16 basic colors, which every terminal shows the same way
+    Basic,
+}
+
+impl MotdColor {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "basic" => Some(Self::Basic),
+            _ => None,
+        }
+    }
+}
+
+/// Settings for `--motd`, which renders text for /etc/motd and SSH banners
+pub struct MotdConfig {
+    /// Info lines to show instead of `modules`
+    pub modules: Vec<Module>,
+    pub color: MotdColor,
+    /// Lines are cut to fit this many columns
+    pub width: usize,
+}
+
+/// Drawing settings for `--format svg` and `--format png`
+#[cfg(feature = "image-export")]
+pub struct ImageConfig {
+    /// Monospace font for PNG; common system fonts are tried when unset
+    pub font: Option<PathBuf>,
+    /// Font size in pixels
+    pub font_size: usize,
+}
+
+/// Framing for `--screenshot`
+pub struct ScreenshotConfig {
+    /// Minimum blank columns left of the output
+    pub padding_x: usize,
+    /// Minimum blank lines above and below the output
+    pub padding_y: usize,
+    /// Print newlines down to the bottom of the terminal so the prompt ends
+    /// up at the edge instead of right under the output
+    pub hide_prompt: bool,
+}
+
+/// Optional lines for login banners, all off unless set
+#[derive(Default)]
+pub struct ExtrasConfig {
+    /// Days since the system was installed
+    pub install_age: bool,
+    /// Longest uptime seen across runs
+    pub uptime_record: bool,
+    /// File to draw a random quote from, one per line
+    pub quote_file: Option<PathBuf>,
+}
+
+pub struct WeatherConfig {
+    /// Endpoint answering with a single line, like wttr.in's `format=` output
+    pub url: String,
+    /// How long a fetched result is used before it is refreshed
+    pub ttl: Duration,
+}
+
+/// Where a text banner goes relative to the distro logo
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BannerPosition {
+    Above,
+    Replace,
+}
+
+impl BannerPosition {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "above" => Some(Self::Above),
+            "replace" => Some(Self::Replace),
+            _ => None,
+        }
+    }
+}
+
+pub struct BannerConfig {
+    /// Text to draw; the hostname when unset
+    pub text: Option<String>,
+    pub position: BannerPosition,
+    /// Palette index; the logo's first color when unset
+    pub color: Option<Color>,
+    /// Character used for the set cells of the font
+    pub pixel: char,
+}
+
+impl Default for BannerConfig {
+    fn default() -> Self {
+        Self {
+            text: None,
+            position: BannerPosition::Replace,
+            color: None,
+            pixel: '█',
+        }
+    }
+}
+
+/// Replacement art for a distro, from a `[logos."Name"]` table
+pub struct LogoOverride {
+    /// Text file with the art; may use `${c1}`..`${c6}` color placeholders
+    pub file: PathBuf,
+    /// Palette indices for the placeholders
+    pub colors: Vec<Color>,
+}
+
+/// A run of modules shown under a header, from `groups`
+pub struct Group {
+    /// Text above the group's lines; a group without one only gets spacing
+    pub header: Option<String>,
+    /// Color of the header; the key color when unset
+    pub color: Option<Color>,
+    /// Index in `modules` of the group's first module
+    pub start: usize,
+}
+
+pub struct Config {
+    /// Info lines to show, in order
+    pub modules: Vec<Module>,
+    /// `modules` is still the built-in list, to be adapted to the chassis
+    pub default_modules: bool,
+    /// Headers over parts of `modules`, in order
+    pub groups: Vec<Group>,
+    /// Blank lines before each group but the first
+    pub group_spacing: usize,
+    /// Title line, with `{user}`, `{host}`, `{os}`, `{os_short}` and
+    /// `{kernel}` filled in
+    pub title: String,
+    pub separator_style: SeparatorStyle,
+    /// The user's full name in the title, with the login name after it
+    pub full_name: bool,
+    /// Codename, or snapshot date on rolling releases, after the OS name
+    pub os_details: bool,
+    /// Language of the built-in labels; `auto` in the config follows the
+    /// locale
+    pub language: String,
+    /// Labels from `[labels]`, or from `[labels.<language>]` for one language
+    pub labels: Vec<(Option<String>, Module, String)>,
+    /// Pad keys so all values start in one column, however wide the keys
+    pub align_values: bool,
+    pub icons: IconStyle,
+    /// Icons from `[module_icons]`, used instead of the built-in ones
+    pub module_icons: Vec<(Module, String)>,
+    pub cpu_frequency: CpuFrequency,
+    /// Live utilization and temperature on the GPU line
+    pub gpu_stats: bool,
+    pub temp_unit: TempUnit,
+    /// Zones at or above this many °C are shown in red
+    pub temp_warning: usize,
+    /// `[dark]` or `[light]` after the theme, as apps are asked to style
+    pub theme_mode: bool,
+    /// Background opacity and padding after the terminal
+    pub terminal_details: bool,
+    pub hyperlinks: Hyperlinks,
+    pub greeting: GreetingConfig,
+    pub weather: WeatherConfig,
+    pub extras: ExtrasConfig,
+    pub motd: MotdConfig,
+    pub screenshot: ScreenshotConfig,
+    #[cfg(feature = "image-export")]
+    pub image: ImageConfig,
+    /// Colors replacing the logo's own `${c1}`, `${c2}`, ... colors
+    pub logo_colors: Vec<Color>,
+    /// Color for the keys, the separator and the title instead of the logo's
+    /// or the scheme's
+    pub accent_color: Option<Color>,
+    /// Colors of the info block, picked by `theme`
+    pub scheme: Scheme,
+    /// Schemes `theme` can name
+    pub schemes: Registry,
+    pub palette_source: PaletteSource,
+    /// The palette all colors are matched to, once loaded from `palette_source`
+    pub palette: Option<Palette>,
+    /// Per-distro logo replacements, keyed by distro name
+    pub logos: Vec<(String, LogoOverride)>,
+    /// Large text banner drawn instead of or above the logo
+    pub banner: Option<BannerConfig>,
+    pub cgroup_limits: CgroupLimits,
+    pub memory_used: MemoryUsed,
+    /// Memory pressure (PSI) on the Memory line
+    pub memory_pressure: bool,
+    pub disk: DiskConfig,
+    pub bar: BarConfig,
+    /// Detection backends to try, in order, for each value that has several
+    pub resolution_backends: Vec<String>,
+    pub theme_backends: Vec<String>,
+    pub icon_backends: Vec<String>,
+    /// Package managers counted on the Packages line, in order
+    pub package_backends: Vec<String>,
+    /// Print lines whose value couldn't be detected as `Unknown` instead of
+    /// leaving them out; off by default inside sandboxes
+    pub show_unknown: bool,
+    /// Hide lines that identify the machine, for sharing screenshots
+    pub privacy: bool,
+    /// Kind of machine, used to adapt the default module list
+    pub chassis: Chassis,
+    /// Screen-reader friendly output: no logo or glyph art, values in words
+    pub accessible: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            modules: Module::DEFAULT.to_vec(),
+            default_modules: true,
+            groups: Vec::new(),
+            group_spacing: 1,
+            title: "{user}@{host}".to_string(),
+            separator_style: SeparatorStyle::Fixed,
+            full_name: false,
+            os_details: false,
+            language: "en".to_string(),
+            labels: Vec::new(),
+            align_values: false,
+            icons: IconStyle::None,
+            module_icons: Vec::new(),
+            cpu_frequency: CpuFrequency::Max,
+            gpu_stats: false,
+            temp_unit: TempUnit::Celsius,
+            temp_warning: 80,
+            theme_mode: false,
+            terminal_details: false,
+            hyperlinks: Hyperlinks::Never,
+            greeting: GreetingConfig {
+                file: None,
+                order: GreetingOrder::Rotate,
+            },
+            weather: WeatherConfig {
+                url: "https://wttr.in/?format=%C,+%t".to_string(),
+                ttl: Duration::from_secs(30 * 60),
+            },
+            extras: ExtrasConfig::default(),
+            motd: MotdConfig {
+                modules: Module::MOTD.to_vec(),
+                color: MotdColor::None,
+                width: 80,
+            },
+            screenshot: ScreenshotConfig {
+                padding_x: 2,
+                padding_y: 1,
+                hide_prompt: true,
+            },
+            #[cfg(feature = "image-export")]
+            image: ImageConfig {
+                font: None,
+                font_size: 16,
+            },
+            logo_colors: Vec::new(),
+            accent_color: None,
+            scheme: Scheme::default(),
+            schemes: Registry::default(),
+            palette_source: PaletteSource::None,
+            palette: None,
+            logos: Vec::new(),
+            banner: None,
+            cgroup_limits: CgroupLimits::Off,
+            memory_used: MemoryUsed::Formula,
+            memory_pressure: false,
+            disk: DiskConfig {
+                show: vec!["/".to_string()],
+                hide_pseudo: true,
+                style: DiskStyle::Usage,
+            },
+            bar: BarConfig {
+                modules: Vec::new(),
+                width: 10,
+                filled: '█',
+                empty: '░',
+                warning: 70,
+                critical: 90,
+            },
+            resolution_backends: names(display::DEFAULT_BACKENDS),
+            theme_backends: names(theme::DEFAULT_BACKENDS),
+            icon_backends: names(theme::DEFAULT_BACKENDS),
+            package_backends: names(packages::DEFAULT_BACKENDS),
+            show_unknown: true,
+            privacy: false,
+            chassis: Chassis::Unknown,
+            accessible: false,
+        }
+    }
+}
+
+/// Directory holding the config file and user assets
+pub fn config_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("XDG_CONFIG_HOME")
+        && !dir.is_empty()
+    {
+        return Some(PathBuf::from(dir).join("tachifetch"));
+    }
+    std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config/tachifetch"))
+}
+
+/// Load the config file and apply command line overrides
+///
+/// A missing default config file is not an error; problems with the file
+/// are reported on stderr and the affected settings keep their defaults.
+pub fn load(args: &Args) -> Config {
+    let mut config = Config {
+        // Dumb terminals are a strong hint that output is consumed by a screen
+        // reader or an editor buffer rather than drawn on a canvas
+        accessible: get_env_var("TERM", "") == "dumb",
+        ..Config::default()
+    };
+
+    // Masked paths would leave a column of Unknowns
+    if let Some(sandbox) = virt::sandbox() {
+        log::trace!("running in {sandbox}, leaving out values that can't be detected");
+        config.show_unknown = false;
+    }
+
+    let explicit = args.config.is_some();
+    // A recorded machine brings the config it was rendered with
+    let recorded = source::current().config().filter(|_| !explicit);
+    let path = args
+        .config
+        .clone()
+        .or_else(|| config_dir().map(|dir| dir.join("config.toml")));
+
+    let mut profile_found = false;
+    if let Some(path) = path {
+        let content = match recorded {
+            Some(content) => Ok(content),
+            None => std::fs::read_to_string(&path),
+        };
+        match content {
+            Ok(content) => match toml::parse(&content) {
+                Ok(table) => {
+                    source::record_config(&content);
+                    config.apply_table(&table);
+                    // A profile's settings go on top of the top-level ones
+                    if let Some(name) = &args.profile
+                        && let Some(profile) = table
+                            .get("profile")
+                            .and_then(Value::as_table)
+                            .and_then(|profiles| profiles.get(name))
+                            .and_then(Value::as_table)
+                    {
+                        config.apply_table(profile);
+                        profile_found = true;
+                    }
+                }
+                Err(e) => warn(&format!("{}: {e}", path.display())),
+            },
+            Err(e) if explicit => warn(&format!("{}: {e}", path.display())),
+            Err(_) => {}
+        }
+    }
+    if let Some(name) = &args.profile
+        && !profile_found
+    {
+        warn(&format!("no [profile.{name}] table found"));
+    }
+
+    config.apply_args(args);
+    config.load_palette();
+
+    // Probing DMI and batteries only pays off for the default list, which
+    // depends on the chassis, or for the chassis line itself
+    if config.default_modules || config.has_module(Module::Chassis) {
+        config.chassis = chassis::detect();
+    }
+    if config.default_modules {
+        config.modules = Module::defaults_for(config.chassis);
+    }
+    config
+}
+
+fn warn(msg: &str) {
+    eprintln!("tachi-fetch: config: {msg}");
+}
+
+/// Parse an enum-like string setting, warning about invalid values
+fn read_choice<T>(table: &Table, key: &str, parse: fn(&str) -> Option<T>) -> Option<T> {
+    let value = table.get(key)?;
+    let parsed = value.as_str().and_then(parse);
+    if parsed.is_none() {
+        warn(&format!("invalid value for '{key}'"));
+    }
+    parsed
+}
+
+fn read_bool(table: &Table, key: &str) -> Option<bool> {
+    let value = table.get(key)?;
+    let parsed = value.as_bool();
+    if parsed.is_none() {
+        warn(&format!("'{key}' must be true or false"));
+    }
+    parsed
+}
+
+fn read_count(table: &Table, key: &str) -> Option<usize> {
+    let value = table.get(key)?;
+    let parsed = value.as_integer().and_then(|n| usize::try_from(n).ok());
+    if parsed.is_none() {
+        warn(&format!("'{key}' must be a non-negative integer"));
+    }
+    parsed
+}
+
+/// A palette index as an integer, or a `"#rrggbb"` string
+fn parse_color(value: &Value) -> Option<Color> {
+    match value.as_str() {
+        Some(s) => Color::parse(s),
+        None => u8::try_from(value.as_integer()?).ok().map(Color::Palette),
+    }
+}
+
+/// A string of exactly one character
+fn read_char(table: &Table, key: &str) -> Option<char> {
+    let mut chars = table.get(key)?.as_str().map(str::chars);
+    let c = chars
+        .as_mut()
+        .and_then(|c| c.next().filter(|_| c.next().is_none()));
+    if c.is_none() {
+        warn(&format!("'{key}' must be a single character"));
+    }
+    c
+}
+
+fn read_color(table: &Table, key: &str) -> Option<Color> {
+    let color = parse_color(table.get(key)?);
+    if color.is_none() {
+        warn(&format!("'{key}' must be a color 0-255 or \"#rrggbb\""));
+    }
+    color
+}
+
+/// A color, or `"logo"` to follow the logo or `"none"` for no color
+fn read_paint(table: &Table, key: &str) -> Option<Paint> {
+    let value = table.get(key)?;
+    let paint = match value.as_str() {
+        Some(s) => Paint::parse(s),
+        None => parse_color(value).map(Paint::Color),
+    };
+    if paint.is_none() {
+        warn(&format!(
+            "'{key}' must be \"logo\", \"none\", a color 0-255 or \"#rrggbb\""
+        ));
+    }
+    paint
+}
+
+fn read_strings(table: &Table, key: &str) -> Option<Vec<String>> {
+    let items = table.get(key)?.as_array();
+    let strings = items.and_then(|items| {
+        items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect::<Option<Vec<_>>>()
+    });
+    if strings.is_none() {
+        warn(&format!("'{key}' must be an array of strings"));
+    }
+    strings
+}
+
+/// Read a backend order, leaving out names that aren't among `backends`
+fn read_backends(table: &Table, key: &str, backends: &[&dyn Backend]) -> Option<Vec<String>> {
+    let mut names = read_strings(table, key)?;
+    names.retain(|name| {
+        let known = backend::exists(backends, name);
+        if !known {
+            warn(&format!("unknown backend '{name}' in '{key}'"));
+        }
+        known
+    });
+    Some(names)
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_string()).collect()
+}
+
+/// Read an array of palette indices and `#rrggbb` colors
+fn read_colors(table: &Table, key: &str) -> Option<Vec<Color>> {
+    let items = table.get(key)?.as_array();
+    let colors = items.and_then(|items| items.iter().map(parse_color).collect::<Option<Vec<_>>>());
+    if colors.is_none() {
+        warn(&format!(
+            "'{key}' must be an array of colors 0-255 or \"#rrggbb\""
+        ));
+    }
+    colors
+}
+
+/// Read a path setting; relative paths are taken relative to the config directory
+fn read_path(table: &Table, key: &str) -> Option<PathBuf> {
+    let value = table.get(key)?;
+    if let Some(s) = value.as_str() {
+        let path = expand_path(s);
+        if path.is_relative()
+            && let Some(dir) = config_dir()
+        {
+            return Some(dir.join(path));
+        }
+        Some(path)
+    } else {
+        warn(&format!("'{key}' must be a string"));
+        None
+    }
+}
+
+impl Config {
+    /// Whether a module is part of the configured layout
+    pub fn has_module(&self, module: Module) -> bool {
+        self.modules.contains(&module)
+    }
+
+    fn apply_table(&mut self, table: &Table) {
+        if let Some(value) = table.get("modules") {
+            match value.as_array() {
+                Some(items) => {
+                    self.modules = parse_modules(items);
+                    self.default_modules = false;
+                    self.groups.clear();
+                }
+                None => warn("'modules' must be an array of module names"),
+            }
+        }
+        if let Some(value) = table.get("groups") {
+            match value.as_array() {
+                Some(items) => self.apply_groups(items),
+                None => warn("'groups' must be an array of tables"),
+            }
+        }
+        if let Some(v) = read_count(table, "group_spacing") {
+            self.group_spacing = v;
+        }
+
+        if let Some(v) = table.get("title") {
+            match v.as_str() {
+                Some(title) => self.title = title.to_string(),
+                None => warn("'title' must be a string"),
+            }
+        }
+        if let Some(v) = read_choice(table, "separator_style", SeparatorStyle::parse) {
+            self.separator_style = v;
+        }
+        if let Some(v) = read_bool(table, "full_name") {
+            self.full_name = v;
+        }
+        if let Some(v) = read_bool(table, "align_values") {
+            self.align_values = v;
+        }
+        if let Some(v) = read_bool(table, "os_details") {
+            self.os_details = v;
+        }
+        if let Some(v) = read_choice(table, "cpu_frequency", CpuFrequency::parse) {
+            self.cpu_frequency = v;
+        }
+        if let Some(v) = read_bool(table, "gpu_stats") {
+            self.gpu_stats = v;
+        }
+        if let Some(v) = read_choice(table, "temp_unit", TempUnit::parse) {
+            self.temp_unit = v;
+        }
+        if let Some(v) = read_count(table, "temp_warning") {
+            self.temp_warning = v;
+        }
+        if let Some(v) = read_bool(table, "theme_mode") {
+            self.theme_mode = v;
+        }
+        if let Some(v) = read_bool(table, "terminal_details") {
+            self.terminal_details = v;
+        }
+        if let Some(v) = read_choice(table, "hyperlinks", Hyperlinks::parse) {
+            self.hyperlinks = v;
+        }
+
+        if let Some(v) = read_choice(table, "cgroup_limits", CgroupLimits::parse) {
+            self.cgroup_limits = v;
+        }
+        if let Some(v) = read_choice(table, "memory_used", MemoryUsed::parse) {
+            self.memory_used = v;
+        }
+        if let Some(v) = read_bool(table, "memory_pressure") {
+            self.memory_pressure = v;
+        }
+        if let Some(v) = read_strings(table, "disk_show") {
+            self.disk.show = v;
+        }
+        if let Some(v) = read_bool(table, "disk_hide_pseudo") {
+            self.disk.hide_pseudo = v;
+        }
+        if let Some(v) = read_choice(table, "disk_style", DiskStyle::parse) {
+            self.disk.style = v;
+        }
+
+        if let Some(v) = read_backends(table, "resolution_backends", display::RESOLUTION_BACKENDS) {
+            self.resolution_backends = v;
+        }
+        if let Some(v) = read_backends(table, "theme_backends", theme::THEME_BACKENDS) {
+            self.theme_backends = v;
+        }
+        if let Some(v) = read_backends(table, "icon_backends", theme::ICON_BACKENDS) {
+            self.icon_backends = v;
+        }
+        if let Some(v) = read_backends(table, "package_backends", packages::PACKAGE_BACKENDS) {
+            self.package_backends = v;
+        }
+
+        if let Some(v) = read_bool(table, "accessible") {
+            self.accessible = v;
+        }
+        if let Some(v) = read_bool(table, "show_unknown") {
+            self.show_unknown = v;
+        }
+        if let Some(v) = read_bool(table, "privacy") {
+            self.privacy = v;
+        }
+
+        if let Some(colors) = read_colors(table, "logo_colors") {
+            self.logo_colors = colors;
+        }
+        if let Some(color) = read_color(table, "accent_color") {
+            self.accent_color = Some(color);
+        }
+
+        if let Some(v) = read_choice(table, "palette", PaletteSource::parse) {
+            self.palette_source = v;
+        }
+
+        if let Some(schemes) = table.get("schemes").and_then(Value::as_table) {
+            self.apply_schemes(schemes);
+        }
+        if let Some(value) = table.get("theme") {
+            match value.as_str() {
+                Some(name) => match self.schemes.get(name) {
+                    Some(scheme) => self.scheme = scheme.clone(),
+                    None => warn(&format!("unknown theme '{name}'")),
+                },
+                None => warn("'theme' must be a string"),
+            }
+        }
+
+        if let Some(v) = table.get("language") {
+            match v.as_str() {
+                Some("auto") => self.language = i18n::system_language(),
+                Some(language) => self.language = language.to_string(),
+                None => warn("'language' must be a string"),
+            }
+        }
+        if let Some(labels) = table.get("labels").and_then(Value::as_table) {
+            self.apply_labels(labels);
+        }
+        if let Some(v) = read_choice(table, "icons", IconStyle::parse) {
+            self.icons = v;
+        }
+        if let Some(icons) = table.get("module_icons").and_then(Value::as_table) {
+            for (name, value) in icons.iter() {
+                let Some(module) = Module::from_name(name) else {
+                    warn(&format!("unknown module '{name}' in [module_icons]"));
+                    continue;
+                };
+                let Some(icon) = value.as_str() else {
+                    warn(&format!("icon for '{name}' must be a string"));
+                    continue;
+                };
+                self.module_icons.retain(|(m, _)| *m != module);
+                self.module_icons.push((module, icon.to_string()));
+            }
+        }
+
+        if let Some(logos) = table.get("logos").and_then(Value::as_table) {
+            self.apply_logos(logos);
+        }
+
+        if let Some(banner) = table.get("banner").and_then(Value::as_table) {
+            self.apply_banner(banner);
+        }
+
+        if let Some(bar) = table.get("bar").and_then(Value::as_table) {
+            self.apply_bar(bar);
+        }
+
+        if let Some(greeting) = table.get("greeting").and_then(Value::as_table) {
+            if let Some(file) = read_path(greeting, "file") {
+                self.greeting.file = Some(file);
+            }
+            if let Some(v) = read_choice(greeting, "order", GreetingOrder::parse) {
+                self.greeting.order = v;
+            }
+        }
+
+        if let Some(weather) = table.get("weather").and_then(Value::as_table) {
+            self.apply_weather(weather);
+        }
+
+        if let Some(motd) = table.get("motd").and_then(Value::as_table) {
+            self.apply_motd(motd);
+        }
+
+        if let Some(screenshot) = table.get("screenshot").and_then(Value::as_table) {
+            self.apply_screenshot(screenshot);
+        }
+
+        #[cfg(feature = "image-export")]
+        if let Some(image) = table.get("image").and_then(Value::as_table) {
+            if let Some(font) = read_path(image, "font") {
+                self.image.font = Some(font);
+            }
+            if let Some(v) = read_count(image, "font_size") {
+                self.image.font_size = v;
+            }
+        }
+
+        if let Some(extras) = table.get("extras").and_then(Value::as_table) {
+            if let Some(v) = read_bool(extras, "install_age") {
+                self.extras.install_age = v;
+            }
+            if let Some(v) = read_bool(extras, "uptime_record") {
+                self.extras.uptime_record = v;
+            }
+            if let Some(file) = read_path(extras, "quote_file") {
+                self.extras.quote_file = Some(file);
+            }
+        }
+    }
+
+    fn apply_motd(&mut self, table: &Table) {
+        if let Some(value) = table.get("modules") {
+            match value.as_array() {
+                Some(items) => self.motd.modules = parse_modules(items),
+                None => warn("'motd.modules' must be an array of module names"),
+            }
+        }
+        if let Some(v) = read_choice(table, "color", MotdColor::parse) {
+            self.motd.color = v;
+        }
+        if let Some(v) = read_count(table, "width") {
+            self.motd.width = v;
+        }
+    }
+
+    fn apply_screenshot(&mut self, table: &Table) {
+        if let Some(v) = read_count(table, "padding_x") {
+            self.screenshot.padding_x = v;
+        }
+        if let Some(v) = read_count(table, "padding_y") {
+            self.screenshot.padding_y = v;
+        }
+        if let Some(v) = read_bool(table, "hide_prompt") {
+            self.screenshot.hide_prompt = v;
+        }
+    }
+
+    fn apply_weather(&mut self, table: &Table) {
+        if let Some(url) = table.get("url") {
+            match url.as_str() {
+                Some(url) => self.weather.url = url.to_string(),
+                None => warn("'weather.url' must be a string"),
+            }
+        }
+        if let Some(minutes) = table.get("cache_minutes") {
+            match minutes.as_integer().and_then(|m| u64::try_from(m).ok()) {
+                Some(minutes) => self.weather.ttl = Duration::from_secs(minutes * 60),
+                None => warn("'weather.cache_minutes' must be a non-negative integer"),
+            }
+        }
+    }
+
+    fn apply_banner(&mut self, table: &Table) {
+        if read_bool(table, "enabled") == Some(false) {
+            return;
+        }
+
+        let mut banner = BannerConfig::default();
+        if let Some(text) = table.get("text") {
+            match text.as_str() {
+                Some(text) => banner.text = Some(text.to_string()),
+                None => warn("'banner.text' must be a string"),
+            }
+        }
+        if let Some(v) = read_choice(table, "position", BannerPosition::parse) {
+            banner.position = v;
+        }
+        if let Some(color) = read_color(table, "color") {
+            banner.color = Some(color);
+        }
+        if let Some(c) = read_char(table, "pixel") {
+            banner.pixel = c;
+        }
+        self.banner = Some(banner);
+    }
+
+    fn apply_bar(&mut self, table: &Table) {
+        if let Some(value) = table.get("modules") {
+            match value.as_array() {
+                Some(items) => self.bar.modules = parse_modules(items),
+                None => warn("'bar.modules' must be an array of module names"),
+            }
+            if self.bar.modules.contains(&Module::Disk) {
+                self.disk.style = DiskStyle::Bar;
+            }
+        }
+        if let Some(v) = read_count(table, "width") {
+            self.bar.width = v;
+        }
+        if let Some(c) = read_char(table, "filled") {
+            self.bar.filled = c;
+        }
+        if let Some(c) = read_char(table, "empty") {
+            self.bar.empty = c;
+        }
+        for (key, threshold) in [
+            ("warning", &mut self.bar.warning),
+            ("critical", &mut self.bar.critical),
+        ] {
+            if let Some(v) = read_count(table, key) {
+                *threshold = v.min(100) as u64;
+            }
+        }
+    }
+
+    /// Groups replace `modules` with their modules one after another
+    fn apply_groups(&mut self, items: &[Value]) {
+        self.modules.clear();
+        self.default_modules = false;
+        self.groups.clear();
+        for item in items {
+            let Some(table) = item.as_table() else {
+                warn("'groups' must be an array of tables");
+                continue;
+            };
+            let header = match table.get("header") {
+                Some(v) => match v.as_str() {
+                    Some(header) => Some(header.to_string()),
+                    None => {
+                        warn("group 'header' must be a string");
+                        None
+                    }
+                },
+                None => None,
+            };
+            let start = self.modules.len();
+            match table.get("modules").and_then(Value::as_array) {
+                Some(items) => self.modules.extend(parse_modules(items)),
+                None => warn("a group needs a 'modules' array"),
+            }
+            self.groups.push(Group {
+                header,
+                color: read_color(table, "color"),
+                start,
+            });
+        }
+    }
+
+    fn apply_labels(&mut self, labels: &Table) {
+        for (key, value) in labels.iter() {
+            match value {
+                Value::Table(language) => {
+                    for (name, value) in language.iter() {
+                        self.add_label(Some(key), name, value);
+                    }
+                }
+                value => self.add_label(None, key, value),
+            }
+        }
+    }
+
+    fn add_label(&mut self, language: Option<&str>, name: &str, value: &Value) {
+        let Some(module) = Module::from_name(name) else {
+            warn(&format!("unknown module '{name}' in [labels]"));
+            return;
+        };
+        let Some(label) = value.as_str() else {
+            warn(&format!("label for '{name}' must be a string"));
+            return;
+        };
+        // Later settings, like a profile's, win over earlier ones
+        self.labels
+            .retain(|(l, m, _)| !(l.as_deref() == language && *m == module));
+        self.labels
+            .push((language.map(str::to_string), module, label.to_string()));
+    }
+
+    /// The label shown for a module: the configured one, the built-in
+    /// translation, or the English name
+    ///
+    /// Right-to-left labels are isolated so the values next to them keep
+    /// their own direction.
+    pub fn label(&self, module: Module) -> Cow<'static, str> {
+        let configured = |language: Option<&str>| {
+            self.labels
+                .iter()
+                .find(|(l, m, _)| l.as_deref() == language && *m == module)
+                .map(|(_, _, label)| label.as_str())
+        };
+        let label = configured(Some(&self.language))
+            .or_else(|| configured(None))
+            .map(|label| Cow::Owned(label.to_string()))
+            .or_else(|| i18n::label(&self.language, module).map(Cow::Borrowed))
+            .unwrap_or(Cow::Borrowed(module.label()));
+
+        if i18n::is_rtl(&self.language) && !label.is_empty() {
+            Cow::Owned(format!("\u{2068}{label}\u{2069}"))
+        } else {
+            label
+        }
+    }
+
+    fn apply_logos(&mut self, logos: &Table) {
+        for (name, value) in logos.iter() {
+            let Some(table) = value.as_table() else {
+                warn(&format!("logos.{name} must be a table"));
+                continue;
+            };
+            let Some(file) = read_path(table, "file") else {
+                warn(&format!("logos.{name} needs a 'file'"));
+                continue;
+            };
+
+            let colors = read_colors(table, "colors").unwrap_or_default();
+
+            self.logos
+                .push((name.to_string(), LogoOverride { file, colors }));
+        }
+    }
+
+    fn apply_schemes(&mut self, schemes: &Table) {
+        for (name, value) in schemes.iter() {
+            let Some(table) = value.as_table() else {
+                warn(&format!("schemes.{name} must be a table"));
+                continue;
+            };
+
+            // Colors left out come from the scheme it's based on
+            let base = table
+                .get("base")
+                .and_then(Value::as_str)
+                .unwrap_or("default");
+            let Some(base) = self.schemes.get(base) else {
+                warn(&format!("schemes.{name}: unknown base '{base}'"));
+                continue;
+            };
+            let mut scheme = base.clone();
+
+            if let Some(paint) = read_paint(table, "key") {
+                scheme.key = paint;
+            }
+            if let Some(paint) = read_paint(table, "separator") {
+                scheme.separator = paint;
+            }
+            if table.get("blocks").and_then(Value::as_str) == Some("logo") {
+                scheme.blocks = None;
+            } else if let Some(colors) = read_colors(table, "blocks") {
+                scheme.blocks = Some(colors);
+            }
+
+            self.schemes.register(name, scheme);
+        }
+    }
+
+    /// Match the accent, banner and scheme colors to the configured palette;
+    /// the logo's are matched when it is drawn
+    fn load_palette(&mut self) {
+        let palette = match self.palette_source {
+            PaletteSource::None => return,
+            PaletteSource::Wal => palette::wal(),
+            PaletteSource::Terminal => palette::terminal(),
+        };
+        let Some(palette) = palette else {
+            return;
+        };
+
+        let recolor = |color| palette.recolor(color);
+        self.accent_color = self.accent_color.map(recolor);
+        for group in &mut self.groups {
+            group.color = group.color.map(recolor);
+        }
+        if let Some(banner) = &mut self.banner {
+            banner.color = banner.color.map(recolor);
+        }
+        for paint in [&mut self.scheme.key, &mut self.scheme.separator] {
+            if let Paint::Color(color) = paint {
+                *color = recolor(*color);
+            }
+        }
+        if let Some(blocks) = &mut self.scheme.blocks {
+            for color in blocks {
+                *color = recolor(*color);
+            }
+        }
+        self.palette = Some(palette);
+    }
+
+    fn apply_args(&mut self, args: &Args) {
+        if let Some(v) = args.cpu_frequency {
+            self.cpu_frequency = v;
+        }
+        if args.gpu_stats {
+            self.gpu_stats = true;
+        }
+        if let Some(v) = args.hyperlinks {
+            self.hyperlinks = v;
+        }
+        if let Some(v) = args.palette {
+            self.palette_source = v;
+        }
+        if let Some(colors) = &args.logo_colors {
+            self.logo_colors.clone_from(colors);
+        }
+        if args.accessible {
+            self.accessible = true;
+        }
+        if args.privacy {
+            self.privacy = true;
+        }
+        if args.show_unknown {
+            self.show_unknown = true;
+        }
+        if let Some(modules) = &args.get {
+            self.modules.clone_from(modules);
+            self.default_modules = false;
+            self.groups.clear();
+        }
+        if args.motd {
+            // A file read later on another terminal: no links, and the MOTD's
+            // own module list
+            self.modules = std::mem::take(&mut self.motd.modules);
+            self.default_modules = false;
+            self.groups.clear();
+            self.hyperlinks = Hyperlinks::Never;
+        }
+        if args.banner || args.banner_text.is_some() {
+            let banner = self.banner.get_or_insert_with(BannerConfig::default);
+            if let Some(text) = &args.banner_text {
+                banner.text = Some(text.clone());
+            }
+        }
+    }
+}
+
+fn parse_modules(items: &[Value]) -> Vec<Module> {
+    items
+        .iter()
+        .filter_map(|item| {
+            let name = item.as_str()?;
+            let module = Module::from_name(name);
+            if module.is_none() {
+                warn(&format!("unknown module '{name}'"));
+            }
+            module
+        })
+        .collect()
+}
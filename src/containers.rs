@@ -0,0 +1,89 @@
+//! Running container count
+//! Asks the Docker and Podman API sockets for their running containers with a
+//! bare HTTP request, so no client library is needed
+
+use crate::json;
+use crate::utils::get_env_var;
+use std::fs;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_millis(250);
+
+/// e.g. `7 running`, or `5 running (Docker), 2 running (Podman)` when both
+/// engines have containers; `None` when neither socket answers
+pub fn containers_summary() -> Option<String> {
+    let counts: Vec<(&str, usize)> = engines()
+        .into_iter()
+        .filter_map(|(engine, socket)| Some((engine, running(&socket)?)))
+        .collect();
+
+    match counts.as_slice() {
+        [] => None,
+        [(_, count)] => Some(format!("{count} running")),
+        _ => Some(
+            counts
+                .iter()
+                .map(|(engine, count)| format!("{count} running ({engine})"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+    }
+}
+
+/// The first existing socket of each engine, with symlinks resolved
+fn engines() -> Vec<(&'static str, PathBuf)> {
+    let docker_host = get_env_var("DOCKER_HOST", "");
+    let runtime = get_env_var("XDG_RUNTIME_DIR", "");
+
+    let docker = [
+        docker_host.strip_prefix("unix://").map(PathBuf::from),
+        Some(PathBuf::from("/var/run/docker.sock")),
+    ];
+    let podman = [
+        (!runtime.is_empty()).then(|| PathBuf::from(runtime).join("podman/podman.sock")),
+        Some(PathBuf::from("/run/podman/podman.sock")),
+    ];
+
+    let mut sockets: Vec<(&str, PathBuf)> = [("Docker", docker), ("Podman", podman)]
+        .into_iter()
+        .filter_map(|(engine, candidates)| {
+            let socket = candidates
+                .into_iter()
+                .flatten()
+                .find(|path| path.exists())?;
+            Some((engine, fs::canonicalize(&socket).unwrap_or(socket)))
+        })
+        .collect();
+    // DOCKER_HOST or a docker.sock link often lead to Podman's socket; its
+    // containers are counted once, as Podman's
+    if let [(_, docker), (_, podman)] = sockets.as_slice()
+        && docker == podman
+    {
+        sockets.remove(0);
+    }
+    sockets
+}
+
+/// Number of running containers, from `GET /containers/json`
+fn running(socket: &PathBuf) -> Option<usize> {
+    let mut stream = UnixStream::connect(socket).ok()?;
+    stream.set_read_timeout(Some(TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(TIMEOUT)).ok()?;
+
+    // HTTP/1.0 so the response is not chunked and ends when the socket closes
+    stream
+        .write_all(b"GET /containers/json HTTP/1.0\r\nHost: localhost\r\n\r\n")
+        .ok()?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    if !head.starts_with("HTTP/1.") || head.split(' ').nth(1) != Some("200") {
+        return None;
+    }
+    // Only running containers are listed by default
+    Some(json::parse(body)?.as_array()?.len())
+}
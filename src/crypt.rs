@@ -0,0 +1,108 @@
+//! Disk encryption
+//! Whether the root and home filesystems sit on dm-crypt, found by walking the
+//! device-mapper stack below them (LVM on LUKS included)
+
+use crate::source;
+use std::path::{Path, PathBuf};
+
+/// Mount points checked, with the name they're shown as
+const MOUNTS: &[(&str, &str)] = &[("/", "root"), ("/home", "home")];
+
+/// e.g. `LUKS2 (root, home)`; `Unencrypted` when root is on a plain device
+pub fn encryption_summary() -> Option<String> {
+    let source = source::current();
+    let mountinfo = source
+        .read_to_string(Path::new("/proc/self/mountinfo"))
+        .ok()?;
+
+    let mut kinds: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut root_found = false;
+    for &(point, name) in MOUNTS {
+        let Some((device, mount_source)) = mount_device(&mountinfo, point) else {
+            continue;
+        };
+        root_found |= point == "/";
+        let Some(kind) = dm_device(device, mount_source).and_then(|dm| crypt_kind(&dm)) else {
+            continue;
+        };
+        match kinds.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, names)) => names.push(name),
+            None => kinds.push((kind, vec![name])),
+        }
+    }
+
+    if kinds.is_empty() {
+        return root_found.then(|| "Unencrypted".to_string());
+    }
+    let kinds: Vec<String> = kinds
+        .into_iter()
+        .map(|(kind, names)| format!("{kind} ({})", names.join(", ")))
+        .collect();
+    Some(kinds.join(", "))
+}
+
+/// `major:minor` and source device of the filesystem mounted last on `point`
+fn mount_device<'a>(mountinfo: &'a str, point: &str) -> Option<(&'a str, &'a str)> {
+    // `36 35 253:0 / / rw,relatime shared:1 - ext4 /dev/mapper/root rw`
+    mountinfo.lines().rev().find_map(|line| {
+        let (mount, fs) = line.split_once(" - ")?;
+        let mut mount = mount.split(' ').skip(2);
+        let device = mount.next()?;
+        if mount.nth(1)? != point {
+            return None;
+        }
+        Some((device, fs.split(' ').nth(1)?))
+    })
+}
+
+/// The sysfs directory of a mount's device-mapper device; btrfs reports an
+/// anonymous device number, so `/dev/mapper/` names are looked up as well
+fn dm_device(device: &str, mount_source: &str) -> Option<PathBuf> {
+    let source = source::current();
+    let dm = Path::new("/sys/dev/block").join(device);
+    if source.exists(&dm.join("dm/uuid")) {
+        return Some(dm);
+    }
+
+    if let Some(name) = mount_source.strip_prefix("/dev/dm-") {
+        return Some(PathBuf::from(format!("/sys/block/dm-{name}")));
+    }
+    let name = mount_source.strip_prefix("/dev/mapper/")?;
+    source
+        .read_dir(Path::new("/sys/block"))
+        .unwrap_or_default()
+        .into_iter()
+        .find(|dm| {
+            source
+                .read_to_string(&dm.join("dm/name"))
+                .is_ok_and(|n| n.trim() == name)
+        })
+}
+
+/// `LUKS2`, `LUKS1`, ... for a dm-crypt device, or for the first one found
+/// among the devices it's built on
+fn crypt_kind(dm: &Path) -> Option<String> {
+    let source = source::current();
+    let uuid = source.read_to_string(&dm.join("dm/uuid")).ok()?;
+    // `CRYPT-LUKS2-<uuid>-<name>`, `CRYPT-PLAIN-<name>`, ...
+    if let Some(rest) = uuid.trim().strip_prefix("CRYPT-") {
+        let kind = rest.split('-').next().unwrap_or_default();
+        return Some(match kind {
+            "PLAIN" => "dm-crypt".to_string(),
+            "BITLK" => "BitLocker".to_string(),
+            "TCRYPT" => "VeraCrypt".to_string(),
+            kind => kind.to_string(),
+        });
+    }
+
+    source
+        .read_dir(&dm.join("slaves"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|slave| {
+            slave
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with("dm-"))
+        })
+        .find_map(|slave| crypt_kind(&Path::new("/sys/block").join(slave.file_name()?)))
+}
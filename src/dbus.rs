@@ -0,0 +1,597 @@
+//! Minimal D-Bus client
+//! Just enough of the wire protocol to make method calls on the system and
+//! session buses and read their replies, without linking libdbus
+
+use crate::utils::get_env_var;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::time::Duration;
+
+const SYSTEM_BUS: &str = "/run/dbus/system_bus_socket";
+
+/// How long to wait for the bus or a service before giving up on it
+const TIMEOUT: Duration = Duration::from_millis(250);
+
+const METHOD_CALL: u8 = 1;
+const METHOD_RETURN: u8 = 2;
+const ERROR: u8 = 3;
+
+const FIELD_PATH: u8 = 1;
+const FIELD_INTERFACE: u8 = 2;
+const FIELD_MEMBER: u8 = 3;
+const FIELD_REPLY_SERIAL: u8 = 5;
+const FIELD_DESTINATION: u8 = 6;
+const FIELD_SIGNATURE: u8 = 8;
+
+pub struct Connection {
+    stream: BufReader<UnixStream>,
+    serial: u32,
+}
+
+impl Connection {
+    pub fn system() -> io::Result<Self> {
+        Self::connect(Path::new(SYSTEM_BUS))
+    }
+
+    /// The session bus from `DBUS_SESSION_BUS_ADDRESS`, or the usual socket in
+    /// the runtime directory
+    pub fn session() -> io::Result<Self> {
+        let address = get_env_var("DBUS_SESSION_BUS_ADDRESS", "");
+        let path = address
+            .split(';')
+            .filter_map(|addr| addr.strip_prefix("unix:"))
+            .flat_map(|params| params.split(','))
+            .find_map(|param| param.strip_prefix("path="))
+            .map(str::to_string)
+            .or_else(|| {
+                let runtime = get_env_var("XDG_RUNTIME_DIR", "");
+                (!runtime.is_empty()).then(|| format!("{runtime}/bus"))
+            })
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no session bus"))?;
+        Self::connect(Path::new(&path))
+    }
+
+    fn connect(path: &Path) -> io::Result<Self> {
+        let stream = UnixStream::connect(path)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        let mut conn = Self {
+            stream: BufReader::new(stream),
+            serial: 0,
+        };
+        conn.authenticate()?;
+        conn.call(
+            "org.freedesktop.DBus",
+            "/org/freedesktop/DBus",
+            "org.freedesktop.DBus",
+            "Hello",
+            "",
+            &[],
+        )?;
+        Ok(conn)
+    }
+
+    /// SASL EXTERNAL: the bus checks our uid from the socket credentials
+    fn authenticate(&mut self) -> io::Result<()> {
+        let uid = unsafe { libc::getuid() }.to_string();
+        let hex: String = uid.bytes().map(|b| format!("{b:02x}")).collect();
+
+        let stream = self.stream.get_mut();
+        stream.write_all(b"\0")?;
+        stream.write_all(format!("AUTH EXTERNAL {hex}\r\n").as_bytes())?;
+
+        let mut line = String::new();
+        self.stream.read_line(&mut line)?;
+        if !line.starts_with("OK ") {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "bus rejected authentication",
+            ));
+        }
+        self.stream.get_mut().write_all(b"BEGIN\r\n")
+    }
+
+    /// Call a method and return its reply body
+    ///
+    /// `body` must already be marshalled to match `signature`.
+    pub fn call(
+        &mut self,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        member: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> io::Result<Reply> {
+        self.serial += 1;
+        let serial = self.serial;
+
+        let mut fields = Writer::default();
+        fields.header_field(FIELD_PATH, "o", |w| w.string(path));
+        fields.header_field(FIELD_DESTINATION, "s", |w| w.string(destination));
+        fields.header_field(FIELD_INTERFACE, "s", |w| w.string(interface));
+        fields.header_field(FIELD_MEMBER, "s", |w| w.string(member));
+        if !signature.is_empty() {
+            fields.header_field(FIELD_SIGNATURE, "g", |w| w.signature(signature));
+        }
+
+        let mut msg = Writer::default();
+        msg.buf.extend_from_slice(&[b'l', METHOD_CALL, 0, 1]);
+        msg.u32(len_u32(body.len())?);
+        msg.u32(serial);
+        msg.u32(len_u32(fields.buf.len())?);
+        msg.buf.extend_from_slice(&fields.buf);
+        msg.align(8);
+        msg.buf.extend_from_slice(body);
+        self.stream.get_mut().write_all(&msg.buf)?;
+
+        // Signals (e.g. `NameAcquired` after `Hello`) may arrive first
+        loop {
+            let reply = self.read_message()?;
+            if reply.reply_serial != Some(serial) {
+                continue;
+            }
+            return match reply.kind {
+                METHOD_RETURN => Ok(reply),
+                ERROR => Err(io::Error::other("method call failed")),
+                _ => continue,
+            };
+        }
+    }
+
+    fn read_message(&mut self) -> io::Result<Reply> {
+        let mut fixed = [0u8; 16];
+        self.stream.read_exact(&mut fixed)?;
+        if fixed[0] != b'l' {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "big-endian messages are not supported",
+            ));
+        }
+        let word =
+            |i: usize| u32::from_le_bytes([fixed[i], fixed[i + 1], fixed[i + 2], fixed[i + 3]]);
+        let body_len = word(4) as usize;
+        let fields_len = word(12) as usize;
+
+        // Header fields are padded so the body starts 8-aligned
+        let padded = (16 + fields_len).next_multiple_of(8) - 16;
+        let mut rest = vec![0u8; padded + body_len];
+        self.stream.read_exact(&mut rest)?;
+
+        let mut reply = Reply {
+            kind: fixed[1],
+            reply_serial: None,
+            body: rest.split_off(padded),
+        };
+
+        // The reader works on offsets from the message start for alignment
+        let mut header = fixed.to_vec();
+        header.extend_from_slice(&rest[..fields_len]);
+        let mut reader = Reader::new(&header);
+        reader.pos = 16;
+        while reader.pos < header.len() {
+            reader.align(8);
+            let Some(code) = reader.u8() else { break };
+            let Some(sig) = reader.signature() else { break };
+            match (code, sig) {
+                (FIELD_REPLY_SERIAL, "u") => reply.reply_serial = reader.u32(),
+                (_, "s" | "o") => {
+                    reader.string();
+                }
+                (_, "g") => {
+                    reader.signature();
+                }
+                (_, "u") => {
+                    reader.u32();
+                }
+                _ => break,
+            }
+        }
+        Ok(reply)
+    }
+}
+
+fn len_u32(len: usize) -> io::Result<u32> {
+    u32::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "message too long"))
+}
+
+pub struct Reply {
+    kind: u8,
+    reply_serial: Option<u32>,
+    pub body: Vec<u8>,
+}
+
+impl Reply {
+    pub fn reader(&self) -> Reader<'_> {
+        Reader::new(&self.body)
+    }
+}
+
+/// Marshals values in little-endian D-Bus wire format
+#[derive(Default)]
+pub struct Writer {
+    pub buf: Vec<u8>,
+}
+
+impl Writer {
+    pub fn align(&mut self, to: usize) {
+        self.buf.resize(self.buf.len().next_multiple_of(to), 0);
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.align(4);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn string(&mut self, value: &str) {
+        self.u32(u32::try_from(value.len()).unwrap_or(u32::MAX));
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+    }
+
+    pub fn signature(&mut self, value: &str) {
+        self.buf.push(u8::try_from(value.len()).unwrap_or(u8::MAX));
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+    }
+
+    /// An array of strings (`as`)
+    pub fn strings(&mut self, values: &[&str]) {
+        self.u32(0);
+        let start = self.buf.len();
+        for value in values {
+            self.string(value);
+        }
+        let len = u32::try_from(self.buf.len() - start).unwrap_or(u32::MAX);
+        self.buf[start - 4..start].copy_from_slice(&len.to_le_bytes());
+    }
+
+    /// One `(yv)` entry of the header field array
+    fn header_field(&mut self, code: u8, signature: &str, value: impl FnOnce(&mut Self)) {
+        self.align(8);
+        self.buf.push(code);
+        self.signature(signature);
+        value(self);
+    }
+}
+
+/// Reads values out of a little-endian D-Bus message body
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pub pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn align(&mut self, to: usize) {
+        self.pos = self.pos.next_multiple_of(to);
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    pub fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        self.align(4);
+        self.take(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    pub fn string(&mut self) -> Option<&'a str> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        self.pos += 1;
+        std::str::from_utf8(bytes).ok()
+    }
+
+    pub fn signature(&mut self) -> Option<&'a str> {
+        let len = usize::from(self.u8()?);
+        let bytes = self.take(len)?;
+        self.pos += 1;
+        std::str::from_utf8(bytes).ok()
+    }
+
+    /// An array of strings (`as`)
+    pub fn strings(&mut self) -> Option<Vec<&'a str>> {
+        let end = self.array_end(4)?;
+        let mut strings = Vec::new();
+        while self.pos < end {
+            strings.push(self.string()?);
+        }
+        Some(strings)
+    }
+
+    /// Read an array's length and return where its elements end
+    pub fn array_end(&mut self, element_align: usize) -> Option<usize> {
+        let len = self.u32()? as usize;
+        self.align(element_align);
+        let end = self.pos.checked_add(len)?;
+        (end <= self.data.len()).then_some(end)
+    }
+
+    /// Skip one value of the first complete type in `signature`, returning the
+    /// rest of the signature
+    pub fn skip<'s>(&mut self, signature: &'s str) -> Option<&'s str> {
+        let code = *signature.as_bytes().first()?;
+        let rest = &signature[1..];
+        match code {
+            b'y' => {
+                self.take(1)?;
+            }
+            b'n' | b'q' => {
+                self.align(2);
+                self.take(2)?;
+            }
+            b'b' | b'i' | b'u' | b'h' => {
+                self.u32()?;
+            }
+            b'x' | b't' | b'd' => {
+                self.align(8);
+                self.take(8)?;
+            }
+            b's' | b'o' => {
+                self.string()?;
+            }
+            b'g' => {
+                self.signature()?;
+            }
+            b'v' => {
+                let inner = self.signature()?;
+                self.skip(inner)?;
+            }
+            b'a' => {
+                let end = self.array_end(alignment(*rest.as_bytes().first()?))?;
+                self.pos = end;
+                return rest.get(type_len(rest.as_bytes())?..);
+            }
+            b'(' | b'{' => {
+                self.align(8);
+                let close = if code == b'(' { ')' } else { '}' };
+                let mut inner = rest;
+                while !inner.starts_with(close) {
+                    inner = self.skip(inner)?;
+                }
+                return Some(&inner[1..]);
+            }
+            _ => return None,
+        }
+        Some(rest)
+    }
+}
+
+/// Alignment of values whose type signature starts with `code`
+const fn alignment(code: u8) -> usize {
+    match code {
+        b'y' | b'g' | b'v' => 1,
+        b'n' | b'q' => 2,
+        b'x' | b't' | b'd' | b'(' | b'{' => 8,
+        _ => 4,
+    }
+}
+
+/// Length of the first complete type in a signature
+fn type_len(signature: &[u8]) -> Option<usize> {
+    match *signature.first()? {
+        b'a' => Some(1 + type_len(&signature[1..])?),
+        open @ (b'(' | b'{') => {
+            let close = if open == b'(' { b')' } else { b'}' };
+            let mut len = 1;
+            while *signature.get(len)? != close {
+                len += type_len(&signature[len..])?;
+            }
+            Some(len + 1)
+        }
+        _ => Some(1),
+    }
+}
+
+/// Names currently owned on the bus
+pub fn list_names(conn: &mut Connection) -> io::Result<Vec<String>> {
+    let reply = conn.call(
+        "org.freedesktop.DBus",
+        "/org/freedesktop/DBus",
+        "org.freedesktop.DBus",
+        "ListNames",
+        "",
+        &[],
+    )?;
+    reply
+        .reader()
+        .strings()
+        .map(|names| names.into_iter().map(str::to_string).collect())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed ListNames reply"))
+}
+
+/// `org.freedesktop.DBus.Properties.Get`; the reply body is the variant
+pub fn get_property(
+    conn: &mut Connection,
+    destination: &str,
+    path: &str,
+    interface: &str,
+    property: &str,
+) -> io::Result<Reply> {
+    let mut body = Writer::default();
+    body.string(interface);
+    body.string(property);
+
+    conn.call(
+        destination,
+        path,
+        "org.freedesktop.DBus.Properties",
+        "Get",
+        "ss",
+        &body.buf,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Connection, ERROR, FIELD_REPLY_SERIAL, FIELD_SIGNATURE, METHOD_RETURN, Reader, TIMEOUT,
+        Writer, type_len,
+    };
+    use std::io::{BufReader, ErrorKind, Write};
+    use std::net::Shutdown;
+    use std::os::unix::net::UnixStream;
+
+    const SIGNAL: u8 = 4;
+
+    /// A message from the bus, as `read_message` expects it
+    fn message(kind: u8, reply_serial: Option<u32>, signature: &str, body: &[u8]) -> Vec<u8> {
+        let mut fields = Writer::default();
+        if let Some(serial) = reply_serial {
+            fields.header_field(FIELD_REPLY_SERIAL, "u", |w| w.u32(serial));
+        }
+        if !signature.is_empty() {
+            fields.header_field(FIELD_SIGNATURE, "g", |w| w.signature(signature));
+        }
+
+        let mut msg = Writer::default();
+        msg.buf.extend_from_slice(&[b'l', kind, 0, 1]);
+        msg.u32(u32::try_from(body.len()).unwrap());
+        msg.u32(1000);
+        msg.u32(u32::try_from(fields.buf.len()).unwrap());
+        msg.buf.extend_from_slice(&fields.buf);
+        msg.align(8);
+        msg.buf.extend_from_slice(body);
+        msg.buf
+    }
+
+    /// A connection whose bus side has already sent `messages`
+    fn connection(messages: &[Vec<u8>]) -> (Connection, UnixStream) {
+        let (ours, mut bus) = UnixStream::pair().unwrap();
+        ours.set_read_timeout(Some(TIMEOUT)).unwrap();
+        for msg in messages {
+            bus.write_all(msg).unwrap();
+        }
+        let conn = Connection {
+            stream: BufReader::new(ours),
+            serial: 0,
+        };
+        (conn, bus)
+    }
+
+    fn call(conn: &mut Connection) -> std::io::Result<super::Reply> {
+        conn.call("org.example", "/org/example", "org.example", "Get", "", &[])
+    }
+
+    #[test]
+    fn strings_round_trip() {
+        let mut w = Writer::default();
+        w.buf.push(1);
+        w.string("first");
+        w.strings(&["a", "", "longer one"]);
+        w.signature("a{sv}");
+        w.u32(7);
+
+        let mut r = Reader::new(&w.buf);
+        assert_eq!(r.u8(), Some(1));
+        assert_eq!(r.string(), Some("first"));
+        assert_eq!(r.strings(), Some(vec!["a", "", "longer one"]));
+        assert_eq!(r.signature(), Some("a{sv}"));
+        assert_eq!(r.u32(), Some(7));
+        assert_eq!(r.u8(), None);
+    }
+
+    #[test]
+    fn skip_complete_types() {
+        // a{sv} holding {"k": <u32 5>}, then a struct (ys) and a trailing u32
+        let mut w = Writer::default();
+        w.u32(0);
+        w.align(8);
+        let start = w.buf.len();
+        w.string("k");
+        w.signature("u");
+        w.u32(5);
+        let len = u32::try_from(w.buf.len() - start).unwrap();
+        w.buf[..4].copy_from_slice(&len.to_le_bytes());
+        w.align(8);
+        w.buf.push(2);
+        w.string("in struct");
+        w.u32(9);
+
+        let mut r = Reader::new(&w.buf);
+        assert_eq!(r.skip("a{sv}(ys)u"), Some("(ys)u"));
+        assert_eq!(r.skip("(ys)u"), Some("u"));
+        assert_eq!(r.u32(), Some(9));
+
+        assert_eq!(type_len(b"a{sa(ii)}x"), Some(9));
+        assert_eq!(type_len(b"(ii"), None);
+        assert_eq!(Reader::new(&[]).skip("z"), None);
+    }
+
+    #[test]
+    fn truncated_values() {
+        let mut w = Writer::default();
+        w.string("complete");
+        for len in [0, 3, 4, 8] {
+            assert_eq!(Reader::new(&w.buf[..len]).string(), None, "{len} bytes");
+        }
+        // An array claiming more bytes than there are
+        let mut w = Writer::default();
+        w.u32(64);
+        w.string("short");
+        assert_eq!(Reader::new(&w.buf).strings(), None);
+        assert_eq!(Reader::new(&w.buf).skip("as"), None);
+    }
+
+    #[test]
+    fn reply_after_signals_and_other_replies() {
+        let mut body = Writer::default();
+        body.strings(&["org.freedesktop.DBus", ":1.42"]);
+        let (mut conn, _bus) = connection(&[
+            message(SIGNAL, None, "s", &{
+                let mut w = Writer::default();
+                w.string(":1.42");
+                w.buf
+            }),
+            message(METHOD_RETURN, Some(99), "", &[]),
+            message(METHOD_RETURN, Some(1), "as", &body.buf),
+        ]);
+
+        let reply = call(&mut conn).unwrap();
+        assert_eq!(
+            reply.reader().strings(),
+            Some(vec!["org.freedesktop.DBus", ":1.42"])
+        );
+    }
+
+    #[test]
+    fn error_reply() {
+        let (mut conn, _bus) = connection(&[message(ERROR, Some(1), "", &[])]);
+        assert!(call(&mut conn).is_err());
+    }
+
+    #[test]
+    fn big_endian_message() {
+        let mut msg = message(METHOD_RETURN, Some(1), "", &[]);
+        msg[0] = b'B';
+        let (mut conn, _bus) = connection(&[msg]);
+        assert_eq!(
+            call(&mut conn).err().map(|e| e.kind()),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+
+    #[test]
+    fn truncated_message() {
+        let msg = message(METHOD_RETURN, Some(1), "s", &[5, 0, 0, 0, b'h']);
+        let (mut conn, bus) = connection(&[msg[..msg.len() - 2].to_vec()]);
+        bus.shutdown(Shutdown::Write).unwrap();
+        assert_eq!(
+            call(&mut conn).err().map(|e| e.kind()),
+            Some(ErrorKind::UnexpectedEof)
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Disk usage
+//! Used and total space of a mount point; btrfs and ZFS get usage computed the
+//! way their own tools do, since plain statvfs numbers mislead for pooled and
+//! RAID-profiled storage
+
+use crate::source;
+use crate::utils::run_command;
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::path::Path;
+
+/// Filesystem types that don't hold user data
+static PSEUDO_FILESYSTEMS: &[&str] = &[
+    "autofs",
+    "binfmt_misc",
+    "bpf",
+    "cgroup",
+    "cgroup2",
+    "configfs",
+    "debugfs",
+    "devpts",
+    "devtmpfs",
+    "efivarfs",
+    "fuse.gvfs-fuse-daemon",
+    "fuse.gvfsd-fuse",
+    "fuse.lxcfs",
+    "fuse.portal",
+    "fuse.snapfuse",
+    "fusectl",
+    "hugetlbfs",
+    "mqueue",
+    "nsfs",
+    "overlay",
+    "proc",
+    "pstore",
+    "ramfs",
+    "rpc_pipefs",
+    "securityfs",
+    "squashfs",
+    "sysfs",
+    "tmpfs",
+    "tracefs",
+];
+
+#[derive(Clone)]
+pub struct Disk {
+    pub mount: String,
+    pub fstype: String,
+    pub read_only: bool,
+    pub used: u64,
+    pub total: u64,
+}
+
+struct Mount {
+    /// `major:minor` of the filesystem, shared by its bind mounts
+    device: String,
+    source: String,
+    point: String,
+    fstype: String,
+    read_only: bool,
+}
+
+/// Usage of each mount point in `show`, in that order; `*` expands to every
+/// mounted filesystem, once however many times it's bind mounted, leaving out
+/// pseudo filesystems if `hide_pseudo` is set. Mount points listed by name
+/// are always shown.
+pub fn disks(show: &[String], hide_pseudo: bool) -> Vec<Disk> {
+    let mounts = mounts();
+
+    let mut points: Vec<&str> = Vec::new();
+    for entry in show {
+        if entry == "*" {
+            let mut devices = Vec::new();
+            for mount in &mounts {
+                if hide_pseudo && PSEUDO_FILESYSTEMS.contains(&mount.fstype.as_str()) {
+                    continue;
+                }
+                if !devices.contains(&mount.device.as_str()) {
+                    devices.push(mount.device.as_str());
+                    points.push(mount.point.as_str());
+                }
+            }
+        } else {
+            points.push(entry);
+        }
+    }
+
+    let mut seen = Vec::new();
+    points
+        .into_iter()
+        .filter(|point| {
+            let new = !seen.contains(point);
+            seen.push(*point);
+            new
+        })
+        .filter_map(|point| {
+            // Later lines are mounted on top of earlier ones
+            let mount = mounts.iter().rfind(|m| m.point == point)?;
+            usage(mount)
+        })
+        .collect()
+}
+
+fn usage(mount: &Mount) -> Option<Disk> {
+    let point = mount.point.as_str();
+    let (used, total) = match mount.fstype.as_str() {
+        // The ioctls need the filesystem itself, not a recording of it
+        "btrfs" if source::current().is_live() => {
+            btrfs::usage(point).or_else(|| statvfs_usage(point))
+        }
+        "zfs" => zfs_usage(&mount.source).or_else(|| statvfs_usage(point)),
+        _ => statvfs_usage(point),
+    }?;
+
+    Some(Disk {
+        mount: mount.point.clone(),
+        fstype: mount.fstype.clone(),
+        read_only: mount.read_only,
+        used,
+        total,
+    })
+}
+
+fn mounts() -> Vec<Mount> {
+    let Ok(mountinfo) = source::current().read_to_string(Path::new("/proc/self/mountinfo")) else {
+        return Vec::new();
+    };
+
+    // `36 35 98:0 /root /mnt rw,noatime master:1 - ext4 /dev/sda1 rw,errors=remount-ro`:
+    // a variable number of optional fields end at the `-` separator
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let (mount, fs) = line.split_once(" - ")?;
+            let mut mount = mount.split(' ').skip(2);
+            let device = mount.next()?;
+            let point = mount.nth(1)?;
+            let mount_options = mount.next()?;
+
+            let mut fs = fs.split(' ');
+            let fstype = fs.next()?;
+            let source = fs.next()?;
+            let super_options = fs.next().unwrap_or("");
+
+            let read_only = |options: &str| options.split(',').any(|o| o == "ro");
+            Some(Mount {
+                device: device.to_string(),
+                source: unescape(source),
+                point: unescape(point),
+                fstype: fstype.to_string(),
+                read_only: read_only(mount_options) || read_only(super_options),
+            })
+        })
+        .collect()
+}
+
+/// Undo the octal escapes (`\040` for space) in mountinfo fields
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut rest = field;
+    while let Some(pos) = rest.find('\\') {
+        out.push_str(&rest[..pos]);
+        let code = rest.get(pos + 1..pos + 4);
+        match code.and_then(|c| u8::from_str_radix(c, 8).ok()) {
+            Some(byte) => {
+                out.push(char::from(byte));
+                rest = &rest[pos + 4..];
+            }
+            None => {
+                out.push('\\');
+                rest = &rest[pos + 1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Used and total bytes as df(1) counts them
+fn statvfs_usage(mount: &str) -> Option<(u64, u64)> {
+    let (used, total) = source::current().statvfs(Path::new(mount)).ok()?;
+    (total > 0).then_some((used, total))
+}
+
+/// Usage of a ZFS dataset: its own `used`, out of that plus what is still
+/// available to it in the pool
+fn zfs_usage(dataset: &str) -> Option<(u64, u64)> {
+    let output = run_command("zfs", &["list", "-Hp", "-o", "used,avail", dataset])?;
+    let mut fields = output.split_whitespace();
+    let used: u64 = fields.next()?.parse().ok()?;
+    let avail: u64 = fields.next()?.parse().ok()?;
+    Some((used, used + avail))
+}
+
+/// btrfs usage from the same ioctls as `btrfs filesystem usage`
+mod btrfs {
+    use super::{AsRawFd, File};
+    use std::mem::size_of;
+
+    const IOCTL_MAGIC: u64 = 0x94;
+
+    const BLOCK_GROUP_DATA: u64 = 1 << 0;
+    const BLOCK_GROUP_RAID1: u64 = 1 << 4;
+    const BLOCK_GROUP_DUP: u64 = 1 << 5;
+    const BLOCK_GROUP_RAID10: u64 = 1 << 6;
+    const BLOCK_GROUP_RAID1C3: u64 = 1 << 9;
+    const BLOCK_GROUP_RAID1C4: u64 = 1 << 10;
+    const SPACE_INFO_GLOBAL_RSV: u64 = 1 << 49;
+
+    #[repr(C)]
+    struct SpaceInfo {
+        flags: u64,
+        total_bytes: u64,
+        used_bytes: u64,
+    }
+
+    #[repr(C)]
+    struct SpaceArgs {
+        space_slots: u64,
+        total_spaces: u64,
+    }
+
+    // The argument structs mirror the kernel's layout, unused fields included
+
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct FsInfoArgs {
+        max_id: u64,
+        num_devices: u64,
+        fsid: [u8; 16],
+        reserved: [u8; 992],
+    }
+
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct DevInfoArgs {
+        devid: u64,
+        uuid: [u8; 16],
+        bytes_used: u64,
+        total_bytes: u64,
+        reserved: [u8; 4056],
+    }
+
+    /// `_IOWR`/`_IOR` request numbers
+    const fn ioc(read_write: u64, nr: u64, size: usize) -> u64 {
+        (read_write << 30) | ((size as u64) << 16) | (IOCTL_MAGIC << 8) | nr
+    }
+    const READ: u64 = 2;
+    const READ_WRITE: u64 = 3;
+
+    const IOC_SPACE_INFO: u64 = ioc(READ_WRITE, 20, size_of::<SpaceArgs>());
+    const IOC_DEV_INFO: u64 = ioc(READ_WRITE, 30, size_of::<DevInfoArgs>());
+    const IOC_FS_INFO: u64 = ioc(READ, 31, size_of::<FsInfoArgs>());
+
+    /// Copies kept of each block for a RAID profile
+    fn ratio(flags: u64) -> u64 {
+        if flags & BLOCK_GROUP_RAID1C4 != 0 {
+            4
+        } else if flags & BLOCK_GROUP_RAID1C3 != 0 {
+            3
+        } else if flags & (BLOCK_GROUP_RAID1 | BLOCK_GROUP_DUP | BLOCK_GROUP_RAID10) != 0 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Logical used bytes, and used plus the estimated free space: unused room
+    /// in data chunks plus unallocated device space at the data profile's ratio
+    pub fn usage(mount: &str) -> Option<(u64, u64)> {
+        let dir = File::open(mount).ok()?;
+        let fd = dir.as_raw_fd();
+
+        let spaces = space_info(fd)?;
+        let device_size = device_size(fd)?;
+
+        let mut used = 0;
+        let mut allocated = 0;
+        let mut data_free = 0;
+        let mut data_ratio = 1;
+        for space in spaces
+            .iter()
+            .filter(|s| s.flags & SPACE_INFO_GLOBAL_RSV == 0)
+        {
+            used += space.used_bytes;
+            allocated += space.total_bytes * ratio(space.flags);
+            if space.flags & BLOCK_GROUP_DATA != 0 {
+                data_free += space.total_bytes.saturating_sub(space.used_bytes);
+                data_ratio = ratio(space.flags);
+            }
+        }
+
+        let free = data_free + device_size.saturating_sub(allocated) / data_ratio;
+        Some((used, used + free))
+    }
+
+    fn space_info(fd: i32) -> Option<Vec<SpaceInfo>> {
+        // A first call with no slots reports how many there are
+        let mut args = SpaceArgs {
+            space_slots: 0,
+            total_spaces: 0,
+        };
+        if unsafe { libc::ioctl(fd, IOC_SPACE_INFO as _, &raw mut args) } != 0 {
+            return None;
+        }
+        let count = usize::try_from(args.total_spaces).ok()?;
+
+        // The header followed by the slots, in u64 units to keep alignment
+        let words = (size_of::<SpaceArgs>() + count * size_of::<SpaceInfo>()) / 8;
+        let mut buf = vec![0u64; words];
+        buf[0] = args.total_spaces;
+        if unsafe { libc::ioctl(fd, IOC_SPACE_INFO as _, buf.as_mut_ptr()) } != 0 {
+            return None;
+        }
+
+        let filled = usize::try_from(buf[1]).ok()?.min(count);
+        Some(
+            buf[2..]
+                .chunks_exact(3)
+                .take(filled)
+                .map(|s| SpaceInfo {
+                    flags: s[0],
+                    total_bytes: s[1],
+                    used_bytes: s[2],
+                })
+                .collect(),
+        )
+    }
+
+    /// Combined size of all member devices
+    fn device_size(fd: i32) -> Option<u64> {
+        let mut fs_info: FsInfoArgs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::ioctl(fd, IOC_FS_INFO as _, &raw mut fs_info) } != 0 {
+            return None;
+        }
+
+        // Device IDs can have gaps after a device was removed
+        let total = (1..=fs_info.max_id)
+            .filter_map(|devid| {
+                let mut dev: DevInfoArgs = unsafe { std::mem::zeroed() };
+                dev.devid = devid;
+                let rc = unsafe { libc::ioctl(fd, IOC_DEV_INFO as _, &raw mut dev) };
+                (rc == 0).then_some(dev.total_bytes)
+            })
+            .sum();
+        Some(total)
+    }
+}
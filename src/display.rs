@@ -1,100 +1,248 @@
-use crate::utils::file_exists;
-use std::fs;
+use crate::backend::{self, Backend};
+use crate::edid;
+use crate::hyprland;
+use crate::log;
+use crate::portal;
+use crate::source;
+use crate::sway;
+use crate::utils::{get_env_var, run_command};
 use std::path::Path;
 
-// EDID constants
-const EDID_HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
-const EDID_SIZE: usize = 128;
+/// Resolution backends, usable in `resolution_backends`
+pub static RESOLUTION_BACKENDS: &[&dyn Backend] = &[&Hyprland, &Sway, &Drm, &X11];
 
-/// Cache display resolutions to avoid repeated parsing
-pub fn get_screen_resolution() -> String {
-    if let Ok(resolution) = get_drm_resolution() {
-        return resolution;
+/// Order tried unless the config says otherwise
+pub static DEFAULT_BACKENDS: &[&str] = &["hyprland", "sway", "drm", "x11"];
+
+/// Resolutions of all connected displays, trying the backends in `order`
+pub fn get_screen_resolution(order: &[String]) -> String {
+    backend::detect("display", RESOLUTION_BACKENDS, order).unwrap_or_else(|| "Unknown".to_string())
+}
+
+/// Hyprland's monitor list, with refresh rates
+struct Hyprland;
+
+impl Backend for Hyprland {
+    fn name(&self) -> &'static str {
+        "hyprland"
     }
 
-    "Unknown".to_string()
+    fn detect(&self) -> Option<String> {
+        hyprland::monitors()
+    }
 }
 
-/// Get all display resolutions from DRM/EDID
-fn get_drm_resolution() -> Result<String, ()> {
-    let drm_path = Path::new("/sys/class/drm");
-    if !drm_path.exists() {
-        return Err(());
-    }
-
-    let mut resolutions = rustc_hash::FxHashMap::default();
-    let mut active_connectors = smallvec::SmallVec::<[std::path::PathBuf; 4]>::new();
-
-    // First find all potential connectors
-    if let Ok(entries) = fs::read_dir(drm_path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
-
-            // Look for card outputs like card0-HDMI-A-1
-            if file_name.starts_with("card") && file_name.contains('-') {
-                let status_path = path.join("status");
-                let edid_path = path.join("edid");
-
-                // Check if connected and has EDID data
-                if file_exists(&status_path) && file_exists(&edid_path) {
-                    if let Ok(status) = fs::read_to_string(&status_path) {
-                        if status.trim() == "connected" {
-                            active_connectors.push(path);
-                        }
-                    }
-                }
-            }
-        }
+/// Sway's and i3's outputs, with refresh rates on sway
+struct Sway;
+
+impl Backend for Sway {
+    fn name(&self) -> &'static str {
+        "sway"
     }
 
-    // Read EDID for each active connector
-    for path in active_connectors {
-        let edid_path = path.join("edid");
-        if let Ok(edid_data) = fs::read(&edid_path) {
-            if let Some(resolution) = parse_edid_resolution(&edid_data) {
-                let connector_name = path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string();
-                resolutions.insert(connector_name, resolution);
-            }
-        }
+    fn detect(&self) -> Option<String> {
+        sway::outputs()
     }
+}
+
+/// Connectors in /sys/class/drm and their EDID
+struct Drm;
 
-    // Combine all resolutions
-    if !resolutions.is_empty() {
-        let mut result = String::new();
-        for (i, (_, res)) in resolutions.iter().enumerate() {
-            if i > 0 {
-                result.push_str(", ");
-            }
-            result.push_str(res);
+impl Backend for Drm {
+    fn name(&self) -> &'static str {
+        "drm"
+    }
+
+    fn detect(&self) -> Option<String> {
+        get_drm_resolution()
+            .inspect_err(|reason| log::fail!("display: {reason}"))
+            .ok()
+            .map(with_desktop_scale)
+    }
+}
+
+/// `xrandr`, for X servers that don't sit on a local DRM device
+struct X11;
+
+impl Backend for X11 {
+    fn name(&self) -> &'static str {
+        "x11"
+    }
+
+    fn detect(&self) -> Option<String> {
+        if get_env_var("DISPLAY", "").is_empty() {
+            log::fail!("display: DISPLAY isn't set, not asking X11");
+            return None;
         }
-        return Ok(result);
+
+        // Outputs in use look like `HDMI-1 connected primary 2560x1440+0+0 ...`
+        let output = run_command("xrandr", &["--current"])?;
+        let resolutions: Vec<&str> = output
+            .lines()
+            .filter(|line| line.contains(" connected"))
+            .filter_map(|line| {
+                let geometry = line.split_whitespace().find(|w| w.contains('+'))?;
+                geometry.split('+').next()
+            })
+            .collect();
+        (!resolutions.is_empty()).then(|| with_desktop_scale(resolutions.join(", ")))
+    }
+}
+
+/// A scale factor as shown after a mode, e.g. `1.5x`; `None` at 1
+pub fn format_scale(factor: f64) -> Option<String> {
+    let factor = (factor * 100.0).round() / 100.0;
+    (factor > 0.0 && factor != 1.0).then(|| format!("{factor}x"))
+}
+
+/// Resolutions followed by the desktop-wide scale, for the backends that
+/// can't tell each output's
+fn with_desktop_scale(resolutions: String) -> String {
+    match desktop_scale() {
+        Some(scale) => format!("{resolutions} ({scale})"),
+        None => resolutions,
+    }
+}
+
+/// The toolkits' scale from the environment, else GNOME's integer scale, then
+/// GNOME's text scaling on top, e.g. `2x, text 1.25x`
+fn desktop_scale() -> Option<String> {
+    let mut parts = Vec::new();
+
+    let toolkit = ["GDK_SCALE", "QT_SCALE_FACTOR"]
+        .into_iter()
+        .find_map(|var| get_env_var(var, "").parse::<f64>().ok());
+    let scale = toolkit.or_else(|| {
+        // `uint32 2` from gsettings; 0 leaves it to the monitor's DPI
+        let factor: f64 = gnome_setting("scaling-factor")?
+            .rsplit(' ')
+            .next()?
+            .parse()
+            .ok()?;
+        (factor > 0.0).then_some(factor)
+    });
+    if let Some(scale) = scale.and_then(format_scale) {
+        parts.push(scale);
+    }
+
+    let text = gnome_setting("text-scaling-factor").and_then(|factor| factor.parse().ok());
+    if let Some(text) = text.and_then(format_scale) {
+        parts.push(format!("text {text}"));
     }
 
-    Err(())
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// An `org.gnome.desktop.interface` key, from the settings portal, or from
+/// gsettings in GNOME sessions only, as elsewhere the key is rarely set and
+/// not worth a process
+fn gnome_setting(key: &str) -> Option<String> {
+    portal::setting("org.gnome.desktop.interface", key).or_else(|| {
+        if !get_env_var("XDG_CURRENT_DESKTOP", "").contains("GNOME") {
+            return None;
+        }
+        run_command("gsettings", &["get", "org.gnome.desktop.interface", key])
+    })
 }
 
-/// Parse EDID data to extract resolution
-/// The resolution is stored in bytes 54-61 of the EDID data
-fn parse_edid_resolution(edid: &[u8]) -> Option<String> {
-    // Validate EDID size and header
-    if edid.len() < EDID_SIZE || &edid[0..8] != EDID_HEADER.as_ref() {
-        return None;
+/// No graphical session set up in the environment, or connectors in
+/// /sys/class/drm with none of them connected
+pub fn is_headless() -> bool {
+    let session = ["DISPLAY", "WAYLAND_DISPLAY"]
+        .into_iter()
+        .any(|var| !get_env_var(var, "").is_empty())
+        || matches!(get_env_var("XDG_SESSION_TYPE", ""), "x11" | "wayland");
+    if !session {
+        log::trace!("display: no DISPLAY, WAYLAND_DISPLAY or graphical session type");
+        return true;
     }
 
-    // Horizontal resolution: low 8 bits in byte 56, high 4 bits in upper nibble of byte 58
-    let h_res = ((u16::from(edid[58]) & 0xF0) << 4) + u16::from(edid[56]);
+    // Only the connectors, like card0-HDMI-A-1, have a status
+    let source = source::current();
+    let statuses: Vec<String> = source
+        .read_dir(Path::new("/sys/class/drm"))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| source.read_to_string(&path.join("status")).ok())
+        .collect();
+    if !statuses.is_empty() && statuses.iter().all(|status| status.trim() != "connected") {
+        log::trace!("display: no connector in /sys/class/drm has a display connected");
+        return true;
+    }
+    false
+}
 
-    // Vertical resolution: low 8 bits in byte 59, high 4 bits in upper nibble of byte 61
-    let v_res = ((u16::from(edid[61]) & 0xF0) << 4) + u16::from(edid[59]);
+/// Preferred resolution of each connected display, from its EDID
+fn get_drm_resolution() -> Result<String, &'static str> {
+    let monitors = connected_monitors()?;
+    let resolutions: Vec<String> = monitors
+        .iter()
+        .filter_map(|(_, monitor)| Some(monitor.preferred()?.to_string()))
+        .collect();
+    if resolutions.is_empty() {
+        return Err("no connected output has a preferred mode in its EDID");
+    }
+    Ok(resolutions.join(", "))
+}
+
+/// Model, preferred mode and size of each connected display, e.g.
+/// `DELL U2720Q 3840x2160 @ 60Hz (27")`
+pub fn monitors_summary() -> Option<String> {
+    let monitors = connected_monitors()
+        .inspect_err(|reason| log::fail!("display: {reason}"))
+        .ok()?;
+    let described: Vec<String> = monitors
+        .iter()
+        .filter_map(|(_, monitor)| monitor.describe())
+        .collect();
+    (!described.is_empty()).then(|| described.join(", "))
+}
 
-    if h_res > 0 && v_res > 0 {
-        return Some(format!("{h_res}x{v_res}"));
+/// Parsed EDIDs of the connected connectors in /sys/class/drm, by
+/// connector name in order
+fn connected_monitors() -> Result<Vec<(String, edid::Monitor)>, &'static str> {
+    let source = source::current();
+    let drm_path = Path::new("/sys/class/drm");
+    if !source.exists(drm_path) {
+        return Err("no /sys/class/drm, DRM isn't available");
     }
 
-    None
+    let mut connected = false;
+    let mut monitors = Vec::new();
+    for path in source.read_dir(drm_path).unwrap_or_default() {
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        // Card outputs like card0-HDMI-A-1
+        if !name.starts_with("card") || !name.contains('-') {
+            continue;
+        }
+        let is_connected = source
+            .read_to_string(&path.join("status"))
+            .is_ok_and(|status| status.trim() == "connected");
+        if !is_connected {
+            continue;
+        }
+        connected = true;
+
+        log::trace!("display: reading the EDID of {name}");
+        if let Ok(data) = source.read(&path.join("edid"))
+            && let Some(monitor) = edid::parse(&data)
+        {
+            monitors.push((name, monitor));
+        }
+    }
+    monitors.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if monitors.is_empty() {
+        return Err(if connected {
+            "no connected output has a readable EDID"
+        } else {
+            "no connected outputs with EDID data in /sys/class/drm"
+        });
+    }
+    Ok(monitors)
 }
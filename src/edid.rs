@@ -0,0 +1,325 @@
+//! EDID parsing
+//! Monitor name, physical size and modes from the base block and its CTA-861
+//! extension blocks, each checked against its checksum
+
+use std::fmt;
+
+const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+const BLOCK_SIZE: usize = 128;
+/// Detailed timing descriptors in the base block
+const BASE_DESCRIPTORS: [usize; 4] = [54, 72, 90, 108];
+const DESCRIPTOR_SIZE: usize = 18;
+/// Display descriptor tag of the monitor name
+const TAG_NAME: u8 = 0xFC;
+/// Extension block tag of CTA-861
+const TAG_CTA: u8 = 0x02;
+const MM_PER_INCH: f64 = 25.4;
+
+/// A detailed timing
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Mode {
+    pub width: u16,
+    pub height: u16,
+    /// Refresh rate in Hz, rounded
+    pub refresh: u32,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+pub struct Monitor {
+    /// Model name from the monitor name descriptor, e.g. `DELL U2720Q`
+    pub name: Option<String>,
+    /// Diagonal in inches, rounded
+    pub inches: Option<u32>,
+    /// All detailed timings, base block first; the first one is preferred
+    pub modes: Vec<Mode>,
+}
+
+impl Monitor {
+    pub fn preferred(&self) -> Option<Mode> {
+        self.modes.first().copied()
+    }
+
+    /// e.g. `DELL U2720Q 3840x2160 @ 60Hz (27")`
+    pub fn describe(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(name) = &self.name {
+            parts.push(name.clone());
+        }
+        if let Some(mode) = self.preferred() {
+            parts.push(format!("{mode} @ {}Hz", mode.refresh));
+        }
+        if let Some(inches) = self.inches {
+            parts.push(format!("({inches}\")"));
+        }
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+}
+
+/// Parse an EDID, `None` when the base block is missing or corrupt;
+/// extension blocks that fail their checksum are skipped
+pub fn parse(edid: &[u8]) -> Option<Monitor> {
+    let base = edid.get(..BLOCK_SIZE)?;
+    if base[..8] != HEADER || !checksum_ok(base) {
+        return None;
+    }
+
+    let mut monitor = Monitor {
+        name: None,
+        inches: None,
+        modes: Vec::new(),
+    };
+    let mut image_size = None;
+    for offset in BASE_DESCRIPTORS {
+        let descriptor = &base[offset..offset + DESCRIPTOR_SIZE];
+        if let Some((mode, size)) = timing(descriptor) {
+            monitor.modes.push(mode);
+            image_size = image_size.or(size);
+        } else if descriptor[3] == TAG_NAME {
+            monitor.name = monitor.name.or_else(|| text(&descriptor[5..]));
+        }
+    }
+
+    // Blocks following the base one, as many as it announces
+    let extensions = usize::from(base[126]);
+    for block in edid[BLOCK_SIZE..]
+        .chunks_exact(BLOCK_SIZE)
+        .take(extensions)
+        .filter(|block| block[0] == TAG_CTA && checksum_ok(block))
+    {
+        // Byte 2 holds where the timings start, after the data blocks; 0 and
+        // 4 both mean there are none
+        let start = usize::from(block[2]);
+        if start < 4 {
+            continue;
+        }
+        // The last byte is the checksum
+        let timings = &block[start.min(BLOCK_SIZE - 1)..BLOCK_SIZE - 1];
+        for descriptor in timings.chunks_exact(DESCRIPTOR_SIZE) {
+            let Some((mode, size)) = timing(descriptor) else {
+                break;
+            };
+            monitor.modes.push(mode);
+            image_size = image_size.or(size);
+        }
+    }
+
+    // The timing's image size is in mm; bytes 21 and 22 only round it to cm
+    let image_size = image_size.or_else(|| {
+        let (width, height) = (u16::from(base[21]), u16::from(base[22]));
+        (width > 0 && height > 0).then_some((width * 10, height * 10))
+    });
+    monitor.inches = image_size.map(|(width_mm, height_mm)| {
+        let diagonal = f64::from(width_mm).hypot(f64::from(height_mm)) / MM_PER_INCH;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let inches = diagonal.round() as u32;
+        inches
+    });
+    Some(monitor)
+}
+
+/// Every byte of a block, checksum included, adds up to 0
+fn checksum_ok(block: &[u8]) -> bool {
+    block.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) == 0
+}
+
+/// The mode of a detailed timing descriptor and its image size in mm, or
+/// `None` for a display descriptor, which has no pixel clock
+fn timing(d: &[u8]) -> Option<(Mode, Option<(u16, u16)>)> {
+    // In units of 10 kHz
+    let clock = u32::from(u16::from_le_bytes([d[0], d[1]]));
+    if clock == 0 {
+        return None;
+    }
+
+    // Low 8 bits, and the high 4 in a shared nibble
+    let twelve_bits = |low: u8, high: u8| u16::from(low) | u16::from(high) << 8;
+    let h_active = twelve_bits(d[2], d[4] >> 4);
+    let h_blank = twelve_bits(d[3], d[4] & 0x0F);
+    let v_active = twelve_bits(d[5], d[7] >> 4);
+    let v_blank = twelve_bits(d[6], d[7] & 0x0F);
+    if h_active == 0 || v_active == 0 {
+        return None;
+    }
+
+    let total = u32::from(h_active + h_blank) * u32::from(v_active + v_blank);
+    let refresh = (clock * 10_000 + total / 2) / total.max(1);
+
+    let width_mm = twelve_bits(d[12], d[14] >> 4);
+    let height_mm = twelve_bits(d[13], d[14] & 0x0F);
+    let size = (width_mm > 0 && height_mm > 0).then_some((width_mm, height_mm));
+
+    let mode = Mode {
+        width: h_active,
+        height: v_active,
+        refresh,
+    };
+    Some((mode, size))
+}
+
+/// A descriptor's text, ended by a line feed and padded with spaces
+fn text(bytes: &[u8]) -> Option<String> {
+    let end = bytes
+        .iter()
+        .position(|&b| b == b'\n')
+        .unwrap_or(bytes.len());
+    let text = String::from_utf8_lossy(&bytes[..end]).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BLOCK_SIZE, HEADER, Mode, parse};
+
+    /// A detailed timing: pixel clock in 10 kHz units, active and blanking
+    /// pixels/lines, and the image size in mm
+    fn timing(clock: u16, h: (u16, u16), v: (u16, u16), mm: (u16, u16)) -> [u8; 18] {
+        let mut d = [0u8; 18];
+        d[..2].copy_from_slice(&clock.to_le_bytes());
+        let low = |n: u16| (n & 0xFF) as u8;
+        let high = |n: u16| (n >> 8) as u8;
+        (d[2], d[3], d[4]) = (low(h.0), low(h.1), high(h.0) << 4 | high(h.1));
+        (d[5], d[6], d[7]) = (low(v.0), low(v.1), high(v.0) << 4 | high(v.1));
+        (d[12], d[13], d[14]) = (low(mm.0), low(mm.1), high(mm.0) << 4 | high(mm.1));
+        d
+    }
+
+    fn name(text: &str) -> [u8; 18] {
+        let mut d = [0u8; 18];
+        d[3] = 0xFC;
+        d[5..].fill(b' ');
+        d[5..5 + text.len()].copy_from_slice(text.as_bytes());
+        if text.len() < 13 {
+            d[5 + text.len()] = b'\n';
+        }
+        d
+    }
+
+    /// Make the block's bytes add up to 0
+    fn seal(block: &mut [u8]) {
+        let sum = block[..BLOCK_SIZE - 1]
+            .iter()
+            .fold(0u8, |sum, b| sum.wrapping_add(*b));
+        block[BLOCK_SIZE - 1] = 0u8.wrapping_sub(sum);
+    }
+
+    fn base(descriptors: &[[u8; 18]], size_cm: (u8, u8), extensions: u8) -> Vec<u8> {
+        let mut block = vec![0u8; BLOCK_SIZE];
+        block[..8].copy_from_slice(&HEADER);
+        (block[21], block[22]) = size_cm;
+        for (i, descriptor) in descriptors.iter().enumerate() {
+            block[54 + 18 * i..72 + 18 * i].copy_from_slice(descriptor);
+        }
+        block[126] = extensions;
+        seal(&mut block);
+        block
+    }
+
+    fn cta(descriptors: &[[u8; 18]]) -> Vec<u8> {
+        let mut block = vec![0u8; BLOCK_SIZE];
+        (block[0], block[1], block[2]) = (0x02, 3, 4);
+        for (i, descriptor) in descriptors.iter().enumerate() {
+            block[4 + 18 * i..22 + 18 * i].copy_from_slice(descriptor);
+        }
+        seal(&mut block);
+        block
+    }
+
+    const FHD: [u8; 18] = [
+        0x02, 0x3A, 0x80, 0x18, 0x71, 0x38, 0x2D, 0x40, 0, 0, 0, 0, 0x0F, 0x28, 0x21, 0, 0, 0,
+    ];
+
+    #[test]
+    fn base_block() {
+        let edid = base(&[FHD, name("DELL P2419H")], (53, 30), 0);
+        let monitor = parse(&edid).unwrap();
+        assert_eq!(monitor.name.as_deref(), Some("DELL P2419H"));
+        assert_eq!(monitor.inches, Some(24));
+        assert_eq!(
+            monitor.describe().as_deref(),
+            Some("DELL P2419H 1920x1080 @ 60Hz (24\")")
+        );
+    }
+
+    #[test]
+    fn twelve_bit_fields() {
+        // 3840x2160 at 60Hz on a 27" panel, 597x336 mm
+        let uhd = timing(59_400, (3840, 560), (2160, 90), (597, 336));
+        let monitor = parse(&base(&[uhd], (0, 0), 0)).unwrap();
+        let mode = Mode {
+            width: 3840,
+            height: 2160,
+            refresh: 60,
+        };
+        assert!(monitor.preferred() == Some(mode));
+        assert_eq!(monitor.inches, Some(27));
+        assert!(monitor.name.is_none());
+    }
+
+    #[test]
+    fn size_from_centimetres_without_timing_size() {
+        let no_size = timing(14_850, (1920, 280), (1080, 45), (0, 0));
+        let monitor = parse(&base(&[no_size], (60, 34), 0)).unwrap();
+        assert_eq!(monitor.inches, Some(27));
+        assert_eq!(parse(&base(&[no_size], (0, 0), 0)).unwrap().inches, None);
+    }
+
+    #[test]
+    fn cta_extension_modes() {
+        let hd = timing(7_425, (1280, 370), (720, 30), (0, 0));
+        let mut edid = base(&[FHD], (53, 30), 1);
+        edid.extend(cta(&[hd]));
+        let modes: Vec<String> = parse(&edid)
+            .unwrap()
+            .modes
+            .iter()
+            .map(|mode| format!("{mode}@{}", mode.refresh))
+            .collect();
+        assert_eq!(modes, ["1920x1080@60", "1280x720@60"]);
+    }
+
+    #[test]
+    fn extensions_beyond_the_count_or_corrupt_are_skipped() {
+        let hd = timing(7_425, (1280, 370), (720, 30), (0, 0));
+        let mut uncounted = base(&[FHD], (53, 30), 0);
+        uncounted.extend(cta(&[hd]));
+        assert_eq!(parse(&uncounted).unwrap().modes.len(), 1);
+
+        let mut corrupt = base(&[FHD], (53, 30), 1);
+        let mut block = cta(&[hd]);
+        block[10] ^= 1;
+        corrupt.extend(block);
+        assert_eq!(parse(&corrupt).unwrap().modes.len(), 1);
+
+        // Announced but missing
+        assert_eq!(parse(&base(&[FHD], (53, 30), 2)).unwrap().modes.len(), 1);
+    }
+
+    #[test]
+    fn bad_base_block() {
+        let edid = base(&[FHD], (53, 30), 0);
+
+        let mut bad_checksum = edid.clone();
+        bad_checksum[127] = bad_checksum[127].wrapping_add(1);
+        assert!(parse(&bad_checksum).is_none());
+
+        let mut bad_header = edid.clone();
+        bad_header[0] = 0xFF;
+        seal(&mut bad_header);
+        assert!(parse(&bad_header).is_none());
+
+        assert!(parse(&edid[..BLOCK_SIZE - 1]).is_none());
+        assert!(parse(&[]).is_none());
+    }
+
+    #[test]
+    fn nothing_to_describe() {
+        let monitor = parse(&base(&[name("   ")], (0, 0), 0)).unwrap();
+        assert!(monitor.name.is_none());
+        assert!(monitor.describe().is_none());
+    }
+}
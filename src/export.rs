@@ -0,0 +1,316 @@
+//! Export formats
+//! Turns the rendered terminal output into HTML with the colors kept, or into
+//! a plain Markdown code block, for pasting into blogs, wikis and issues; with
+//! the `image-export` feature also into SVG and PNG images
+
+use crate::color::Color;
+use crate::config::Config;
+use crate::modules::{self, Line};
+use crate::os::SysInfo;
+use crate::utils::{get_env_var, sanitized, strip_ansi};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Terminal,
+    Html,
+    Markdown,
+    /// Metrics in the Prometheus text format instead of the rendered output
+    Prometheus,
+    /// `TF_KEY="value"` assignments for shell scripts to `eval`
+    Env,
+    #[cfg(feature = "image-export")]
+    Svg,
+    #[cfg(feature = "image-export")]
+    Png,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "terminal" => Some(Self::Terminal),
+            "html" => Some(Self::Html),
+            "markdown" | "md" => Some(Self::Markdown),
+            "prometheus" => Some(Self::Prometheus),
+            "env" => Some(Self::Env),
+            #[cfg(feature = "image-export")]
+            "svg" => Some(Self::Svg),
+            #[cfg(feature = "image-export")]
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+}
+
+/// The xterm defaults for the 16 basic colors
+const BASIC: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0xcd, 0x00, 0x00),
+    (0x00, 0xcd, 0x00),
+    (0xcd, 0xcd, 0x00),
+    (0x00, 0x00, 0xee),
+    (0xcd, 0x00, 0xcd),
+    (0x00, 0xcd, 0xcd),
+    (0xe5, 0xe5, 0xe5),
+    (0x7f, 0x7f, 0x7f),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x5c, 0x5c, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+/// Info lines as shell variable assignments, e.g. `TF_OS="Arch Linux"`;
+/// `Disk (/home)` becomes `TF_DISK_HOME`
+pub fn env(config: &Config, info: &SysInfo) -> String {
+    let mut out = String::new();
+    for &module in &config.modules {
+        let Some(module) = modules::shown_as(config, info, module) else {
+            continue;
+        };
+        for line in modules::module_lines(module, config, info, false, false) {
+            match line {
+                Line::Title(_) => {
+                    assign(&mut out, "USER", &sanitized(get_env_var("USER", "user")));
+                    assign(&mut out, "HOST", &info.hostname);
+                }
+                Line::Entry { key, value } => assign(&mut out, &key, &value),
+                Line::Text(text) => assign(&mut out, module.name(), &text),
+                Line::Separator(_) | Line::Header { .. } | Line::Colors => {}
+            }
+        }
+    }
+    out
+}
+
+fn assign(out: &mut String, key: &str, value: &str) {
+    let mut name = String::from("TF");
+    for word in key
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        name.push('_');
+        name.push_str(&word.to_ascii_uppercase());
+    }
+
+    // Inside double quotes only these keep a special meaning
+    let mut quoted = String::with_capacity(value.len());
+    for c in strip_ansi(value).chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    let _ = writeln!(out, "{name}=\"{quoted}\"");
+}
+
+/// Output as a fenced code block, without escape sequences
+pub fn markdown(rendered: &str) -> String {
+    let text = strip_ansi(rendered);
+    // A fence longer than any backtick run in the text can't be closed early
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{fence}text\n{}\n{fence}\n", text.trim_end_matches('\n'))
+}
+
+/// Output as a `<pre>` block, with SGR colors as styled spans and OSC 8
+/// hyperlinks as anchors
+pub fn html(rendered: &str) -> String {
+    let mut out = String::with_capacity(rendered.len() * 2);
+    out.push_str("<pre class=\"tachi-fetch\">");
+
+    let mut style = Style::default();
+    let mut open = Style::default();
+    let mut span = false;
+    let mut chars = rendered.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            match chars.next() {
+                Some('[') => {
+                    let mut params = String::new();
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            if c == 'm' {
+                                style.apply(&params);
+                            }
+                            break;
+                        }
+                        params.push(c);
+                    }
+                }
+                Some(']') => {
+                    let mut body = String::new();
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                        body.push(c);
+                    }
+                    // Anchors and spans must not overlap
+                    if let Some(url) = body.strip_prefix("8;").and_then(|b| b.split_once(';')) {
+                        if span {
+                            out.push_str("</span>");
+                            span = false;
+                        }
+                        if url.1.is_empty() {
+                            out.push_str("</a>");
+                        } else {
+                            let _ = write!(out, "<a href=\"{}\">", escape(url.1));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if span && style != open {
+            out.push_str("</span>");
+            span = false;
+        }
+        if !span && style != Style::default() {
+            let _ = write!(out, "<span style=\"{}\">", style.css());
+            open = style;
+            span = true;
+        }
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            c => out.push(c),
+        }
+    }
+
+    if span {
+        out.push_str("</span>");
+    }
+    out.push_str("</pre>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Split output into lines of characters with the SGR style they are drawn
+/// in, dropping every escape sequence
+#[cfg(feature = "image-export")]
+pub fn cells(rendered: &str) -> Vec<Vec<(char, Style)>> {
+    let mut lines = vec![Vec::new()];
+    let mut style = Style::default();
+    let mut chars = rendered.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                Some('[') => {
+                    let mut params = String::new();
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            if c == 'm' {
+                                style.apply(&params);
+                            }
+                            break;
+                        }
+                        params.push(c);
+                    }
+                }
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || c == '\x1b' && chars.next_if_eq(&'\\').is_some() {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\n' => lines.push(Vec::new()),
+            c if c.is_control() => {}
+            c => {
+                if let Some(line) = lines.last_mut() {
+                    line.push((c, style));
+                }
+            }
+        }
+    }
+
+    if lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub color: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    /// Apply the parameters of an SGR sequence, e.g. `1;38;2;137;180;250`
+    fn apply(&mut self, params: &str) {
+        let mut params = params.split(';').map(|p| p.parse::<u8>().unwrap_or(0));
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                22 => self.bold = false,
+                30..=37 => self.color = Some(Color::Palette(param - 30)),
+                90..=97 => self.color = Some(Color::Palette(param - 90 + 8)),
+                39 => self.color = None,
+                38 => match params.next() {
+                    Some(5) => self.color = params.next().map(Color::Palette),
+                    Some(2) => {
+                        let (r, g, b) = (params.next(), params.next(), params.next());
+                        self.color =
+                            Some(Color::Rgb(r.unwrap_or(0), g.unwrap_or(0), b.unwrap_or(0)));
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn css(self) -> String {
+        let mut css = String::new();
+        if let Some(color) = self.color {
+            let (r, g, b) = rgb(color);
+            let _ = write!(css, "color:#{r:02x}{g:02x}{b:02x}");
+        }
+        if self.bold {
+            if !css.is_empty() {
+                css.push(';');
+            }
+            css.push_str("font-weight:bold");
+        }
+        css
+    }
+}
+
+/// The RGB value terminals use for a color
+pub fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Palette(n @ 0..=15) => BASIC[usize::from(n)],
+        Color::Palette(n @ 16..=231) => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let n = n - 16;
+            (level(n / 36), level(n / 6 % 6), level(n % 6))
+        }
+        Color::Palette(n) => {
+            let gray = 8 + (n - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Extra lines for login banners
+//! Install age, the longest uptime seen and a quote, each switched on in the
+//! `[extras]` config table
+
+use crate::config::{ExtrasConfig, GreetingConfig, GreetingOrder};
+use crate::greeting;
+use crate::install;
+use crate::source;
+use crate::utils::state_dir;
+use std::fs;
+
+#[derive(Clone, Default)]
+pub struct Extras {
+    /// e.g. `412 days ago`
+    pub install_age: Option<String>,
+    pub uptime_record: Option<UptimeRecord>,
+    pub quote: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct UptimeRecord {
+    /// Longest uptime seen by any run, in seconds
+    pub seconds: u64,
+    /// Whether the current boot holds the record
+    pub current: bool,
+}
+
+pub fn collect(config: &ExtrasConfig, uptime: u64) -> Extras {
+    Extras {
+        install_age: config.install_age.then(install::install_age).flatten(),
+        uptime_record: config.uptime_record.then(|| uptime_record(uptime)),
+        quote: config.quote_file.as_ref().and_then(|file| {
+            greeting::pick(&GreetingConfig {
+                file: Some(file.clone()),
+                order: GreetingOrder::Random,
+            })
+        }),
+    }
+}
+
+/// Compare against the persisted record, raising it when this boot beats it
+fn uptime_record(uptime: u64) -> UptimeRecord {
+    let Some(dir) = state_dir() else {
+        return UptimeRecord {
+            seconds: uptime,
+            current: true,
+        };
+    };
+    let path = dir.join("uptime-record");
+
+    let source = source::current();
+    let record = (source.read_to_string(&path).ok())
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+
+    if uptime < record {
+        return UptimeRecord {
+            seconds: record,
+            current: false,
+        };
+    }
+
+    // Only runs see the uptime, so a missed record is just a lower one;
+    // replays show the record without raising it
+    if source.is_live() {
+        let _ = fs::create_dir_all(&dir);
+        let _ = fs::write(&path, uptime.to_string());
+    }
+    UptimeRecord {
+        seconds: uptime,
+        current: true,
+    }
+}
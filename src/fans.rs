@@ -0,0 +1,60 @@
+//! Fans
+//! RPM of the fans hwmon drivers report, grouped by the chip they hang off
+
+use crate::source;
+use std::path::Path;
+
+/// e.g. `thinkpad 2400 RPM, nct6798 1200 / 850 RPM`; fans reading 0 are
+/// stopped or headers with nothing plugged in, and left out
+pub fn fans_summary() -> Option<String> {
+    let source = source::current();
+    let mut chips: Vec<(String, Vec<u64>)> = Vec::new();
+
+    let mut hwmons = source
+        .read_dir(Path::new("/sys/class/hwmon"))
+        .unwrap_or_default();
+    hwmons.sort();
+    for hwmon in hwmons {
+        let mut inputs: Vec<_> = source
+            .read_dir(&hwmon)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?;
+                let index: u32 = name
+                    .strip_prefix("fan")?
+                    .strip_suffix("_input")?
+                    .parse()
+                    .ok()?;
+                Some((index, path))
+            })
+            .collect();
+        inputs.sort();
+
+        let rpms: Vec<u64> = inputs
+            .iter()
+            .filter_map(|(_, path)| source.read_to_string(path).ok()?.trim().parse().ok())
+            .filter(|&rpm| rpm > 0)
+            .collect();
+        if rpms.is_empty() {
+            continue;
+        }
+
+        let name = source
+            .read_to_string(&hwmon.join("name"))
+            .map_or_else(|_| "fan".to_string(), |n| n.trim().to_string());
+        match chips.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => existing.extend(rpms),
+            None => chips.push((name, rpms)),
+        }
+    }
+
+    let chips: Vec<String> = chips
+        .into_iter()
+        .map(|(name, rpms)| {
+            let rpms: Vec<String> = rpms.iter().map(u64::to_string).collect();
+            format!("{name} {} RPM", rpms.join(" / "))
+        })
+        .collect();
+    (!chips.is_empty()).then(|| chips.join(", "))
+}
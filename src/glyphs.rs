@@ -0,0 +1,67 @@
+//! Icons before the keys
+//! A Nerd Font glyph per module, or a plain ASCII mark for fonts without them
+
+use crate::config::IconStyle;
+use crate::modules::Module;
+
+/// Nerd Font glyph and ASCII mark of each module with a key
+static GLYPHS: &[(Module, char, char)] = &[
+    (Module::Os, '\u{f17c}', '*'),
+    (Module::Kernel, '\u{f013}', '&'),
+    (Module::Uptime, '\u{f017}', '~'),
+    (Module::Boot, '\u{f011}', '^'),
+    (Module::SessionUptime, '\u{f2be}', '~'),
+    (Module::InstallDate, '\u{f073}', '^'),
+    (Module::Packages, '\u{f187}', '#'),
+    (Module::Updates, '\u{f021}', '+'),
+    (Module::Shell, '\u{f120}', '$'),
+    (Module::Resolution, '\u{f108}', '='),
+    (Module::Display, '\u{f26c}', '='),
+    (Module::De, '\u{f2d0}', '='),
+    (Module::Wm, '\u{f2d2}', '='),
+    (Module::Session, '\u{f2bd}', '$'),
+    (Module::Theme, '\u{f1fc}', '%'),
+    (Module::Icons, '\u{f03e}', '%'),
+    (Module::Cursor, '\u{f245}', '%'),
+    (Module::Font, '\u{f031}', '%'),
+    (Module::GnomeExtensions, '\u{f12e}', '+'),
+    (Module::Terminal, '\u{f489}', '>'),
+    (Module::Cpu, '\u{f2db}', '@'),
+    (Module::Load, '\u{f0e4}', '@'),
+    (Module::Gpu, '\u{f26c}', '@'),
+    (Module::Graphics, '\u{f06e}', '@'),
+    (Module::Temps, '\u{f2c9}', '!'),
+    (Module::Fans, '\u{f0210}', '!'),
+    (Module::Memory, '\u{f035b}', '@'),
+    (Module::Disk, '\u{f0a0}', '/'),
+    (Module::Nvme, '\u{f0a0}', '/'),
+    (Module::Raid, '\u{f1c0}', '/'),
+    (Module::Encryption, '\u{f023}', '/'),
+    (Module::Numa, '\u{f0e8}', '@'),
+    (Module::Hugepages, '\u{f1b3}', '@'),
+    (Module::Network, '\u{f0ac}', '<'),
+    (Module::Wifi, '\u{f1eb}', '<'),
+    (Module::Sound, '\u{f028}', ')'),
+    (Module::Media, '\u{f001}', ')'),
+    (Module::Virtualization, '\u{f233}', '&'),
+    (Module::Preemption, '\u{f0e7}', '&'),
+    (Module::KernelModules, '\u{f12e}', '&'),
+    (Module::Cmdline, '\u{f120}', '&'),
+    (Module::Security, '\u{f132}', '&'),
+    (Module::Systemd, '\u{f085}', '&'),
+    (Module::Containers, '\u{f1b2}', '&'),
+    (Module::Chassis, '\u{f109}', '@'),
+    (Module::MachineId, '\u{f2c2}', '&'),
+    (Module::Battery, '\u{f240}', '!'),
+    (Module::Weather, '\u{f0c2}', '~'),
+];
+
+/// The built-in icon for a module in `style`
+pub fn icon(style: IconStyle, module: Module) -> Option<char> {
+    let &(_, nerd, ascii) = GLYPHS.iter().find(|(m, _, _)| *m == module)?;
+    match style {
+        IconStyle::None => None,
+        IconStyle::NerdFont => Some(nerd),
+        IconStyle::Ascii => Some(ascii),
+    }
+}
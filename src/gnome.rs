@@ -0,0 +1,35 @@
+//! GNOME Shell extensions
+//! Counted from the `enabled-extensions` key the shell keeps in dconf
+
+use crate::utils::run_command;
+
+/// Extensions named after the count
+const SHOWN: usize = 3;
+
+/// e.g. `5 (dash-to-dock, appindicatorsupport, blur-my-shell, ...)`
+pub fn extensions_summary() -> Option<String> {
+    let list = run_command(
+        "gsettings",
+        &["get", "org.gnome.shell", "enabled-extensions"],
+    )
+    .or_else(|| run_command("dconf", &["read", "/org/gnome/shell/enabled-extensions"]))?;
+
+    // `['dash-to-dock@micxgx.gmail.com', ...]`, or `@as []` when empty; the
+    // part before the `@` is the extension's own name
+    let names: Vec<&str> = list
+        .trim_start_matches("@as")
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|uuid| uuid.trim().trim_matches('\''))
+        .filter(|uuid| !uuid.is_empty())
+        .map(|uuid| uuid.split('@').next().unwrap_or(uuid))
+        .collect();
+
+    Some(match names.len() {
+        0 => "0".to_string(),
+        n if n > SHOWN => format!("{n} ({}, ...)", names[..SHOWN].join(", ")),
+        n => format!("{n} ({})", names.join(", ")),
+    })
+}
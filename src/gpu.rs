@@ -0,0 +1,241 @@
+//! Graphics card detection
+//! GPUs from the DRM class in sysfs, named via `pci.ids`, optionally with live
+//! utilization and temperature from sysfs or, for NVIDIA, NVML
+
+use crate::source;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+pub static PCI_IDS_PATHS: &[&str] = &[
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+];
+
+/// Short names for the vendors whose `pci.ids` names are long-winded
+static VENDORS: &[(u16, &str)] = &[(0x1002, "AMD"), (0x10de, "NVIDIA"), (0x8086, "Intel")];
+
+const NVIDIA: u16 = 0x10de;
+
+/// Every GPU, e.g. `AMD Radeon RX 6800 (34%, 52°C), Intel UHD Graphics 630`
+pub fn gpu_summary(stats: bool) -> Option<String> {
+    let mut cards: Vec<PathBuf> = source::current()
+        .read_dir(Path::new("/sys/class/drm"))
+        .ok()?
+        .into_iter()
+        .filter(|path| {
+            // `card0` is the GPU, `card0-HDMI-A-1` one of its connectors
+            path.file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("card"))
+                .is_some_and(|n| n.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .map(|path| path.join("device"))
+        .collect();
+    cards.sort_unstable();
+
+    let gpus: Vec<String> = cards
+        .iter()
+        .filter_map(|device| {
+            let vendor = read_hex(&device.join("vendor"))?;
+            let id = read_hex(&device.join("device"))?;
+            let name = gpu_name(vendor, id);
+
+            let usage = if stats {
+                if vendor == NVIDIA {
+                    nvml::usage(device)
+                } else {
+                    sysfs_usage(device)
+                }
+            } else {
+                Usage::default()
+            };
+            Some(match usage.describe() {
+                Some(usage) => format!("{name} ({usage})"),
+                None => name,
+            })
+        })
+        .collect();
+
+    (!gpus.is_empty()).then(|| gpus.join(", "))
+}
+
+#[derive(Default)]
+struct Usage {
+    /// Busy percentage
+    busy: Option<u32>,
+    /// Temperature in °C
+    temp: Option<u32>,
+}
+
+impl Usage {
+    fn describe(&self) -> Option<String> {
+        match (self.busy, self.temp) {
+            (Some(busy), Some(temp)) => Some(format!("{busy}%, {temp}°C")),
+            (Some(busy), None) => Some(format!("{busy}%")),
+            (None, Some(temp)) => Some(format!("{temp}°C")),
+            (None, None) => None,
+        }
+    }
+}
+
+/// `gpu_busy_percent` (amdgpu) and the first hwmon temperature
+fn sysfs_usage(device: &Path) -> Usage {
+    let busy = read_trimmed(&device.join("gpu_busy_percent")).and_then(|v| v.parse().ok());
+
+    let temp = source::current()
+        .read_dir(&device.join("hwmon"))
+        .into_iter()
+        .flatten()
+        .find_map(|hwmon| {
+            let millis: u32 = read_trimmed(&hwmon.join("temp1_input"))?.parse().ok()?;
+            Some(millis / 1000)
+        });
+
+    Usage { busy, temp }
+}
+
+/// Name from `pci.ids`, preferring the marketing name in brackets, so
+/// `Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]` loses the codename
+fn gpu_name(vendor: u16, device: u16) -> String {
+    let (vendor_name, device_name) = pci_names(vendor, device);
+
+    let vendor_name = VENDORS
+        .iter()
+        .find(|(id, _)| *id == vendor)
+        .map(|(_, name)| (*name).to_string())
+        .or(vendor_name)
+        .unwrap_or_else(|| format!("{vendor:04x}"));
+
+    match device_name {
+        Some(name) => {
+            let name = name
+                .rsplit_once('[')
+                .and_then(|(_, bracketed)| bracketed.strip_suffix(']'))
+                .unwrap_or(&name);
+            format!("{vendor_name} {name}")
+        }
+        None => format!("{vendor_name} {device:04x}"),
+    }
+}
+
+static PCI_IDS: LazyLock<Option<String>> = LazyLock::new(|| {
+    PCI_IDS_PATHS
+        .iter()
+        .find_map(|path| source::current().read_to_string(Path::new(path)).ok())
+});
+
+/// Vendor and device names from the `pci.ids` database
+fn pci_names(vendor: u16, device: u16) -> (Option<String>, Option<String>) {
+    let Some(ids) = PCI_IDS.as_deref() else {
+        return (None, None);
+    };
+
+    // Vendors start at column 0 (`1002  Advanced Micro Devices...`), their
+    // devices follow indented by one tab
+    let vendor_prefix = format!("{vendor:04x}  ");
+    let device_prefix = format!("\t{device:04x}  ");
+
+    let mut lines = ids.lines();
+    let Some(vendor_name) = lines.find_map(|line| line.strip_prefix(&vendor_prefix)) else {
+        return (None, None);
+    };
+    let device_name = lines
+        .take_while(|line| line.starts_with('\t') || line.starts_with('#') || line.is_empty())
+        .find_map(|line| line.strip_prefix(&device_prefix));
+
+    (
+        Some(vendor_name.to_string()),
+        device_name.map(str::to_string),
+    )
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let content = source::current().read_to_string(path).ok()?;
+    Some(content.trim().to_string())
+}
+
+/// sysfs IDs look like `0x1002`
+fn read_hex(path: &Path) -> Option<u16> {
+    let value = read_trimmed(path)?;
+    u16::from_str_radix(value.strip_prefix("0x").unwrap_or(&value), 16).ok()
+}
+
+/// Minimal NVML client, loaded at runtime so the proprietary driver stays
+/// optional
+mod nvml {
+    use super::Usage;
+    use std::ffi::{CString, c_char, c_int, c_uint, c_void};
+    use std::path::Path;
+
+    type Device = *mut c_void;
+
+    #[repr(C)]
+    struct Utilization {
+        gpu: c_uint,
+        #[allow(dead_code)]
+        memory: c_uint,
+    }
+
+    const SUCCESS: c_int = 0;
+    const TEMPERATURE_GPU: c_int = 0;
+
+    pub fn usage(device: &Path) -> Usage {
+        // NVML finds devices by PCI address, which the sysfs device link names
+        let Some(bus_id) = device
+            .canonicalize()
+            .ok()
+            .and_then(|path| CString::new(path.file_name()?.to_str()?).ok())
+        else {
+            return Usage::default();
+        };
+
+        let lib = unsafe { libc::dlopen(c"libnvidia-ml.so.1".as_ptr(), libc::RTLD_NOW) };
+        if lib.is_null() {
+            return Usage::default();
+        }
+
+        let usage = unsafe { query(lib, &bus_id) }.unwrap_or_default();
+        unsafe { libc::dlclose(lib) };
+        usage
+    }
+
+    unsafe fn symbol<T>(lib: *mut c_void, name: &std::ffi::CStr) -> Option<T> {
+        let sym = unsafe { libc::dlsym(lib, name.as_ptr()) };
+        // Only instantiated with `extern "C" fn` pointer types
+        (!sym.is_null()).then(|| unsafe { std::mem::transmute_copy(&sym) })
+    }
+
+    unsafe fn query(lib: *mut c_void, bus_id: &CString) -> Option<Usage> {
+        type Init = unsafe extern "C" fn() -> c_int;
+        type Shutdown = unsafe extern "C" fn() -> c_int;
+        type ByBusId = unsafe extern "C" fn(*const c_char, *mut Device) -> c_int;
+        type GetUtilization = unsafe extern "C" fn(Device, *mut Utilization) -> c_int;
+        type GetTemperature = unsafe extern "C" fn(Device, c_int, *mut c_uint) -> c_int;
+
+        let init: Init = unsafe { symbol(lib, c"nvmlInit_v2")? };
+        let shutdown: Shutdown = unsafe { symbol(lib, c"nvmlShutdown")? };
+        let by_bus_id: ByBusId = unsafe { symbol(lib, c"nvmlDeviceGetHandleByPciBusId_v2")? };
+        let utilization: GetUtilization = unsafe { symbol(lib, c"nvmlDeviceGetUtilizationRates")? };
+        let temperature: GetTemperature = unsafe { symbol(lib, c"nvmlDeviceGetTemperature")? };
+
+        if unsafe { init() } != SUCCESS {
+            return None;
+        }
+
+        let mut usage = Usage::default();
+        let mut device: Device = std::ptr::null_mut();
+        if unsafe { by_bus_id(bus_id.as_ptr(), &raw mut device) } == SUCCESS {
+            let mut rates = Utilization { gpu: 0, memory: 0 };
+            if unsafe { utilization(device, &raw mut rates) } == SUCCESS {
+                usage.busy = Some(rates.gpu);
+            }
+            let mut temp: c_uint = 0;
+            if unsafe { temperature(device, TEMPERATURE_GPU, &raw mut temp) } == SUCCESS {
+                usage.temp = Some(temp);
+            }
+        }
+
+        unsafe { shutdown() };
+        Some(usage)
+    }
+}
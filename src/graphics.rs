@@ -0,0 +1,196 @@
+//! Graphics APIs
+//! Vulkan API level from the installed ICD manifests and the Mesa version
+//! embedded in its driver, without running vulkaninfo or glxinfo
+
+use crate::json::{self, Value};
+use crate::source;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Where the Vulkan loader looks for driver manifests
+const ICD_DIRS: &[&str] = &[
+    "/etc/vulkan/icd.d",
+    "/usr/local/share/vulkan/icd.d",
+    "/usr/share/vulkan/icd.d",
+];
+
+/// Where a manifest's bare library name is looked up
+const LIB_DIRS: &[&str] = &[
+    "/usr/lib64",
+    "/usr/lib",
+    "/usr/lib/x86_64-linux-gnu",
+    "/usr/lib/aarch64-linux-gnu",
+];
+
+/// ICD names for the kernel drivers of the GPUs present
+const KERNEL_DRIVERS: &[(&str, &str)] = &[
+    ("amdgpu", "radeon"),
+    ("radeon", "radeon"),
+    ("i915", "intel"),
+    ("xe", "intel"),
+    ("nouveau", "nouveau"),
+    ("nvidia", "nvidia"),
+    ("virtio-pci", "virtio"),
+    ("msm", "freedreno"),
+    ("panfrost", "panfrost"),
+    ("v3d", "broadcom"),
+];
+
+struct Icd {
+    /// `radeon` for `radeon_icd.x86_64.json`
+    name: String,
+    /// `major.minor`
+    api: String,
+    library: PathBuf,
+}
+
+/// e.g. `Mesa 24.1.3, Vulkan 1.3 (radeon)`
+pub fn api_summary() -> Option<String> {
+    let icds = icds();
+    let mut parts = Vec::new();
+
+    if let Some(mesa) = icds
+        .iter()
+        .filter(|icd| icd.name != "nvidia")
+        .find_map(|icd| mesa_version(&icd.library))
+    {
+        parts.push(format!("Mesa {mesa}"));
+    }
+
+    // One entry per API level, with the drivers that reach it
+    let mut levels: Vec<(&str, Vec<&str>)> = Vec::new();
+    for icd in &icds {
+        match levels.iter_mut().find(|(api, _)| *api == icd.api) {
+            Some((_, names)) => names.push(&icd.name),
+            None => levels.push((&icd.api, vec![&icd.name])),
+        }
+    }
+    let levels: Vec<String> = levels
+        .into_iter()
+        .map(|(api, names)| format!("{api} ({})", names.join(", ")))
+        .collect();
+    if !levels.is_empty() {
+        parts.push(format!("Vulkan {}", levels.join(", ")));
+    }
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// The manifests of the drivers for the GPUs present, or of all installed
+/// drivers when the GPUs' kernel drivers aren't known; one per driver, since
+/// 32-bit and 64-bit builds each have their own
+fn icds() -> Vec<Icd> {
+    let source = source::current();
+    let mut manifests: Vec<PathBuf> = ICD_DIRS
+        .iter()
+        .flat_map(|dir| source.read_dir(Path::new(dir)).unwrap_or_default())
+        .filter(|path| path.extension().is_some_and(|e| e == "json"))
+        .collect();
+    manifests.sort();
+
+    let wanted = gpu_icd_names();
+    let mut icds: Vec<Icd> = Vec::new();
+    for manifest in manifests {
+        let Some(name) = manifest
+            .file_name()
+            .and_then(|n| n.to_str()?.split(['_', '.']).next())
+        else {
+            continue;
+        };
+        if icds.iter().any(|icd| icd.name == name)
+            || (!wanted.is_empty() && !wanted.contains(&name))
+        {
+            continue;
+        }
+        let Some(icd) = source
+            .read_to_string(&manifest)
+            .ok()
+            .and_then(|text| json::parse(&text))
+        else {
+            continue;
+        };
+        let Some(icd) = icd.get("ICD") else {
+            continue;
+        };
+
+        // `1.3.289`
+        let api = icd
+            .get("api_version")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let api: Vec<&str> = api.split('.').take(2).collect();
+        if api.len() < 2 {
+            continue;
+        }
+        let Some(library) = icd.get("library_path").and_then(Value::as_str) else {
+            continue;
+        };
+        icds.push(Icd {
+            name: name.to_string(),
+            api: api.join("."),
+            library: library_path(library),
+        });
+    }
+    icds
+}
+
+/// ICD names matching the kernel drivers bound to `/sys/class/drm/card*`
+fn gpu_icd_names() -> Vec<&'static str> {
+    let source = source::current();
+    let mut names = Vec::new();
+    for card in source
+        .read_dir(Path::new("/sys/class/drm"))
+        .unwrap_or_default()
+    {
+        // `DRIVER=amdgpu`
+        let Ok(uevent) = source.read_to_string(&card.join("device/uevent")) else {
+            continue;
+        };
+        let Some(driver) = uevent.lines().find_map(|l| l.strip_prefix("DRIVER=")) else {
+            continue;
+        };
+        if let Some((_, icd)) = KERNEL_DRIVERS.iter().find(|(d, _)| *d == driver)
+            && !names.contains(icd)
+        {
+            names.push(*icd);
+        }
+    }
+    names
+}
+
+/// Manifests may name the library bare for the dynamic linker to find
+fn library_path(library: &str) -> PathBuf {
+    if library.contains('/') {
+        return PathBuf::from(library);
+    }
+    LIB_DIRS
+        .iter()
+        .map(|dir| Path::new(dir).join(library))
+        .find(|path| source::current().exists(path))
+        .unwrap_or_else(|| PathBuf::from(library))
+}
+
+/// The version in the `Mesa 24.1.3` string Mesa drivers report as their
+/// driver info; mapped rather than read, since the drivers are tens of MiB
+fn mesa_version(library: &Path) -> Option<String> {
+    let source = source::current();
+    if !source.is_live() {
+        return find_mesa_version(&source.read(library).ok()?);
+    }
+    let file = File::open(library).ok()?;
+    let mmap = unsafe { memmap2::MmapOptions::new().map(&file) }.ok()?;
+    find_mesa_version(&mmap)
+}
+
+fn find_mesa_version(data: &[u8]) -> Option<String> {
+    memchr::memmem::find_iter(data, b"Mesa ").find_map(|start| {
+        let rest = &data[start + 5..];
+        let len = rest
+            .iter()
+            .take_while(|b| b.is_ascii_digit() || **b == b'.')
+            .count();
+        let version = std::str::from_utf8(&rest[..len]).ok()?.trim_matches('.');
+        // At least `major.minor`, to skip prose like `Mesa 3D`
+        version.contains('.').then(|| version.to_string())
+    })
+}
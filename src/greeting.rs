@@ -0,0 +1,72 @@
+//! Closing greeting lines
+//! Picks one line from a user-provided file of quotes/fortunes, either
+//! rotating through them across runs or at random
+
+use crate::config::{GreetingConfig, GreetingOrder};
+use crate::source;
+use crate::utils::{sanitize, state_dir};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Pick the greeting for this run, if a greeting file is configured
+pub fn pick(config: &GreetingConfig) -> Option<String> {
+    let content = source::current()
+        .read_to_string(config.file.as_ref()?)
+        .ok()?;
+
+    let lines: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    let index = match config.order {
+        GreetingOrder::Rotate => next_rotation_index(),
+        GreetingOrder::Random => random_index(),
+    };
+
+    let mut greeting = lines[index % lines.len()].to_string();
+    sanitize(&mut greeting);
+    Some(greeting)
+}
+
+/// Read and advance the persisted rotation position
+fn next_rotation_index() -> usize {
+    let Some(dir) = state_dir() else {
+        return 0;
+    };
+    let path = dir.join("greeting-index");
+
+    let source = source::current();
+    let index = (source.read_to_string(&path).ok())
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    // Replays leave the position where the recording had it
+    if !source.is_live() {
+        return index;
+    }
+    // Losing the position only means repeating a greeting, so errors are ignored
+    let _ = fs::create_dir_all(&dir);
+    let _ = fs::write(&path, index.wrapping_add(1).to_string());
+
+    index
+}
+
+/// Cheap non-cryptographic random index seeded from the clock and pid
+fn random_index() -> usize {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+
+    // xorshift32
+    let mut x = nanos ^ std::process::id().rotate_left(16) | 1;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    x as usize
+}
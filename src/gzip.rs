@@ -0,0 +1,334 @@
+//! Minimal gzip decompression
+//! Just enough inflate (RFC 1951) to read small compressed files such as
+//! `/proc/config.gz`; checksums are not verified
+
+const MAX_BITS: usize = 15;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Order code length code lengths are stored in
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// Decompress a gzip file, or `None` if it is malformed
+pub fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let body = skip_header(data)?;
+    inflate(body)
+}
+
+fn skip_header(data: &[u8]) -> Option<&[u8]> {
+    const FEXTRA: u8 = 4;
+    const FNAME: u8 = 8;
+    const FCOMMENT: u8 = 16;
+    const FHCRC: u8 = 2;
+
+    if data.get(..3)? != [0x1f, 0x8b, 8] {
+        return None;
+    }
+    let flags = data[3];
+    let mut rest = data.get(10..)?;
+
+    if flags & FEXTRA != 0 {
+        let len = usize::from(u16::from_le_bytes([*rest.first()?, *rest.get(1)?]));
+        rest = rest.get(2 + len..)?;
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let end = memchr::memchr(0, rest)?;
+            rest = &rest[end + 1..];
+        }
+    }
+    if flags & FHCRC != 0 {
+        rest = rest.get(2..)?;
+    }
+    Some(rest)
+}
+
+struct Bits<'a> {
+    data: &'a [u8],
+    pos: usize,
+    buf: u32,
+    count: u32,
+}
+
+impl Bits<'_> {
+    fn take(&mut self, n: u32) -> Option<u32> {
+        while self.count < n {
+            let byte = *self.data.get(self.pos)?;
+            self.pos += 1;
+            self.buf |= u32::from(byte) << self.count;
+            self.count += 8;
+        }
+        let value = self.buf & ((1 << n) - 1);
+        self.buf >>= n;
+        self.count -= n;
+        Some(value)
+    }
+
+    /// Drop the rest of the current byte, for stored blocks
+    fn align(&mut self) {
+        self.buf = 0;
+        self.count = 0;
+    }
+}
+
+/// Canonical Huffman code as symbol counts per length and symbols in order
+struct Huffman {
+    count: [u16; MAX_BITS + 1],
+    symbol: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut count = [0u16; MAX_BITS + 1];
+        for &len in lengths {
+            count[usize::from(len)] += 1;
+        }
+
+        let mut offsets = [0u16; MAX_BITS + 2];
+        for len in 1..=MAX_BITS {
+            offsets[len + 1] = offsets[len] + count[len];
+        }
+
+        let mut symbol = vec![0; lengths.len()];
+        for (sym, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                let slot = &mut offsets[usize::from(len)];
+                symbol[usize::from(*slot)] = u16::try_from(sym).unwrap_or(0);
+                *slot += 1;
+            }
+        }
+
+        Self { count, symbol }
+    }
+
+    fn decode(&self, bits: &mut Bits) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0u32, 0u32, 0usize);
+        for len in 1..=MAX_BITS {
+            code |= bits.take(1)?;
+            let count = u32::from(self.count[len]);
+            if code < first + count {
+                return self.symbol.get(index + (code - first) as usize).copied();
+            }
+            index += count as usize;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+fn inflate(data: &[u8]) -> Option<Vec<u8>> {
+    let mut bits = Bits {
+        data,
+        pos: 0,
+        buf: 0,
+        count: 0,
+    };
+    let mut out = Vec::with_capacity(data.len() * 4);
+
+    loop {
+        let last = bits.take(1)? == 1;
+        match bits.take(2)? {
+            0 => stored(&mut bits, &mut out)?,
+            1 => {
+                let (lengths, distances) = fixed_codes();
+                codes(&mut bits, &mut out, &lengths, &distances)?;
+            }
+            2 => {
+                let (lengths, distances) = dynamic_codes(&mut bits)?;
+                codes(&mut bits, &mut out, &lengths, &distances)?;
+            }
+            _ => return None,
+        }
+        if last {
+            return Some(out);
+        }
+    }
+}
+
+fn stored(bits: &mut Bits, out: &mut Vec<u8>) -> Option<()> {
+    bits.align();
+    let header = bits.data.get(bits.pos..bits.pos + 4)?;
+    let len = usize::from(u16::from_le_bytes([header[0], header[1]]));
+    bits.pos += 4;
+    out.extend_from_slice(bits.data.get(bits.pos..bits.pos + len)?);
+    bits.pos += len;
+    Some(())
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    (Huffman::new(&lengths), Huffman::new(&[5; 30]))
+}
+
+fn dynamic_codes(bits: &mut Bits) -> Option<(Huffman, Huffman)> {
+    let nlen = bits.take(5)? as usize + 257;
+    let ndist = bits.take(5)? as usize + 1;
+    let ncode = bits.take(4)? as usize + 4;
+
+    let mut code_lengths = [0u8; 19];
+    for &i in &CODE_LENGTH_ORDER[..ncode] {
+        code_lengths[i] = u8::try_from(bits.take(3)?).ok()?;
+    }
+    let code_lengths = Huffman::new(&code_lengths);
+
+    let mut lengths = vec![0u8; nlen + ndist];
+    let mut i = 0;
+    while i < lengths.len() {
+        let sym = code_lengths.decode(bits)?;
+        let (value, repeat) = match sym {
+            0..=15 => (u8::try_from(sym).ok()?, 1),
+            16 => (*lengths.get(i.checked_sub(1)?)?, 3 + bits.take(2)?),
+            17 => (0, 3 + bits.take(3)?),
+            _ => (0, 11 + bits.take(7)?),
+        };
+        let end = i + repeat as usize;
+        lengths.get_mut(i..end)?.fill(value);
+        i = end;
+    }
+
+    Some((
+        Huffman::new(&lengths[..nlen]),
+        Huffman::new(&lengths[nlen..]),
+    ))
+}
+
+fn codes(bits: &mut Bits, out: &mut Vec<u8>, lengths: &Huffman, distances: &Huffman) -> Option<()> {
+    loop {
+        let sym = lengths.decode(bits)?;
+        match sym {
+            0..=255 => out.push(u8::try_from(sym).ok()?),
+            256 => return Some(()),
+            _ => {
+                let i = usize::from(sym - 257);
+                let len = usize::from(*LENGTH_BASE.get(i)?)
+                    + bits.take(u32::from(LENGTH_EXTRA[i]))? as usize;
+
+                let d = usize::from(distances.decode(bits)?);
+                let dist =
+                    usize::from(*DIST_BASE.get(d)?) + bits.take(u32::from(DIST_EXTRA[d]))? as usize;
+
+                let start = out.len().checked_sub(dist)?;
+                // Copies may overlap their own output, so go byte by byte
+                for j in 0..len {
+                    out.push(out[start + j]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decompress;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const PANGRAMS: &str = "The quick brown fox jumps over the lazy dog. Pack my box with five \
+        dozen liquor jugs. How vexingly quick daft zebras jump! The quick brown fox jumps over \
+        the lazy dog again.\n";
+
+    /// `PANGRAMS` at level 9, a single dynamic block with back references
+    const DYNAMIC: &str = concat!(
+        "1f8b08000000000002ff8d8dcb1502211004ef46d126401c1e3d6c02830bec28cbb87c17a297e733",
+        "00cf55ddb56c0647e1c70b3a4a0bb072e259f6778254139127f6343a56710a779adedea1a7d4386f",
+        "b05ccd44c304783e8ac4b97549e1260dd59c1c9cefbffb956cc6303a52fa06ae58fe4f831c715097",
+        "0f6a3c5a2cae000000",
+    );
+
+    #[test]
+    fn stored_block() {
+        let gz = hex("1f8b08000000000000ff010600f9ff68656c6c6f0a20303a3606000000");
+        assert_eq!(decompress(&gz).as_deref(), Some(&b"hello\n"[..]));
+    }
+
+    #[test]
+    fn fixed_block_with_back_references() {
+        let gz = hex("1f8b08000000000002ffcb48cdc9c957c840905c003b7c8adf12000000");
+        assert_eq!(
+            decompress(&gz).as_deref(),
+            Some(&b"hello hello hello\n"[..])
+        );
+    }
+
+    #[test]
+    fn dynamic_block() {
+        let out = decompress(&hex(DYNAMIC)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), PANGRAMS);
+    }
+
+    #[test]
+    fn stored_then_fixed_block() {
+        let gz = hex(concat!(
+            "1f8b08000000000002ff000800f7ff73746f7265642c202bc948cd5348cbac484de10200c69873",
+            "2013000000",
+        ));
+        assert_eq!(
+            decompress(&gz).as_deref(),
+            Some(&b"stored, then fixed\n"[..])
+        );
+    }
+
+    #[test]
+    fn file_name_in_header() {
+        let gz = hex(concat!(
+            "1f8b08080000000002ff636f6e6669670073f6f773f3748f77b4ade47286309d6c73614c67a0a8",
+            "b20294e3a29059ac90975fa2509c5a0253e08ad0e606d40600ac55734c4d000000",
+        ));
+        let config = "CONFIG_A=y\nCONFIG_B=m\nCONFIG_C=y\n# CONFIG_D is not set\n\
+            CONFIG_E=y\nCONFIG_F=m\n";
+        assert_eq!(decompress(&gz).as_deref(), Some(config.as_bytes()));
+    }
+
+    #[test]
+    fn not_gzip() {
+        assert!(decompress(b"").is_none());
+        assert!(decompress(b"CONFIG_A=y\n").is_none());
+        // zlib rather than gzip framing
+        assert!(decompress(&hex("789ccb48cdc9c9e702000849020d")).is_none());
+    }
+
+    #[test]
+    fn truncated() {
+        let gz = hex(DYNAMIC);
+        for len in [4, 10, 11, 40, gz.len() - 9] {
+            assert!(decompress(&gz[..len]).is_none(), "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn reserved_block_type() {
+        assert!(decompress(&hex("1f8b08000000000002ff07")).is_none());
+    }
+
+    #[test]
+    fn back_reference_before_start() {
+        // A fixed block whose first symbol copies from distance 1
+        assert!(decompress(&hex("1f8b08000000000002ff030200")).is_none());
+    }
+}
@@ -0,0 +1,114 @@
+//! OSC 8 terminal hyperlinks
+//! Wraps selected values in clickable links when the terminal is known to support them
+
+use crate::utils::ENV_CACHE;
+
+/// Known GTK/Qt themes and where they live
+static THEME_URLS: &[(&str, &str)] = &[
+    ("Adwaita", "https://gitlab.gnome.org/GNOME/libadwaita"),
+    ("Arc", "https://github.com/jnsh/arc-theme"),
+    ("Breeze", "https://invent.kde.org/plasma/breeze"),
+    ("Catppuccin", "https://github.com/catppuccin/gtk"),
+    ("Dracula", "https://github.com/dracula/gtk"),
+    (
+        "Gruvbox",
+        "https://github.com/Fausto-Korpsvart/Gruvbox-GTK-Theme",
+    ),
+    ("Materia", "https://github.com/nana-4/materia-theme"),
+    ("Nordic", "https://github.com/EliverLara/Nordic"),
+    ("Orchis", "https://github.com/vinceliuice/Orchis-theme"),
+    ("Qogir", "https://github.com/vinceliuice/Qogir-theme"),
+    (
+        "WhiteSur",
+        "https://github.com/vinceliuice/WhiteSur-gtk-theme",
+    ),
+    ("Yaru", "https://github.com/ubuntu/yaru"),
+];
+
+/// Check whether the terminal is known to render OSC 8 hyperlinks
+///
+/// There is no reliable query for OSC 8 support, so this relies on the
+/// environment variables set by terminals that implement it.
+pub fn supported() -> bool {
+    let term = ENV_CACHE.get("TERM").copied().unwrap_or("");
+    if term == "dumb" || term == "linux" {
+        return false;
+    }
+
+    if ENV_CACHE
+        .get("VTE_VERSION")
+        .is_some_and(|v| v.parse::<u32>().is_ok_and(|v| v >= 5000))
+    {
+        return true;
+    }
+
+    if [
+        "KITTY_WINDOW_ID",
+        "WEZTERM_EXECUTABLE",
+        "WT_SESSION",
+        "KONSOLE_VERSION",
+    ]
+    .iter()
+    .any(|var| ENV_CACHE.contains_key(var))
+    {
+        return true;
+    }
+
+    if let Some(program) = ENV_CACHE.get("TERM_PROGRAM")
+        && matches!(
+            *program,
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+        )
+    {
+        return true;
+    }
+
+    matches!(
+        term,
+        "xterm-kitty" | "xterm-ghostty" | "foot" | "alacritty" | "wezterm"
+    )
+}
+
+/// Wrap text in an OSC 8 hyperlink
+pub fn wrap(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Link to the changelog of a kernel release like `6.8.1-arch1-1`
+///
+/// Stable point releases have a `ChangeLog` on kernel.org, while `.0`
+/// releases are summarized on kernelnewbies.
+pub fn kernel_changelog_url(release: &str) -> Option<String> {
+    let version = release
+        .split(|c: char| c != '.' && !c.is_ascii_digit())
+        .next()?;
+
+    let mut parts = version.split('.').filter(|p| !p.is_empty());
+    let major = parts.next()?.parse::<u32>().ok()?;
+    let minor = parts.next()?.parse::<u32>().ok()?;
+    let patch = parts
+        .next()
+        .and_then(|p| p.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    if patch == 0 {
+        Some(format!("https://kernelnewbies.org/Linux_{major}.{minor}"))
+    } else {
+        Some(format!(
+            "https://cdn.kernel.org/pub/linux/kernel/v{major}.x/ChangeLog-{major}.{minor}.{patch}"
+        ))
+    }
+}
+
+/// Look up the project page of a theme by its name prefix (e.g. `Arc-Dark` -> Arc)
+pub fn theme_url(theme: &str) -> Option<&'static str> {
+    let theme = theme.trim_matches(|c| c == '\'' || c == '"');
+    THEME_URLS
+        .iter()
+        .find(|(name, _)| {
+            theme
+                .get(..name.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+        })
+        .map(|(_, url)| *url)
+}
@@ -0,0 +1,100 @@
+//! Hyprland
+//! Version, tiling layout and monitors asked from the compositor's IPC socket
+//! when running under Hyprland
+
+use crate::display::format_scale;
+use crate::json::{self, Value};
+use crate::log;
+use crate::utils::get_env_var;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long to wait for the compositor before giving up on it
+const TIMEOUT: Duration = Duration::from_millis(250);
+
+/// The request socket of the running instance; `$XDG_RUNTIME_DIR/hypr` since
+/// 0.40, `/tmp/hypr` before
+fn socket() -> Option<PathBuf> {
+    let signature = get_env_var("HYPRLAND_INSTANCE_SIGNATURE", "");
+    if signature.is_empty() {
+        return None;
+    }
+    let runtime = get_env_var("XDG_RUNTIME_DIR", "");
+    [format!("{runtime}/hypr"), "/tmp/hypr".to_string()]
+        .into_iter()
+        .map(|dir| PathBuf::from(dir).join(signature).join(".socket.sock"))
+        .find(|path| path.exists())
+}
+
+/// The JSON reply to a `hyprctl` command
+fn request(command: &str) -> Option<Value> {
+    let path = socket()?;
+    let reply = (|| {
+        let mut stream = UnixStream::connect(&path)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        stream.write_all(format!("j/{command}").as_bytes())?;
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply)?;
+        Ok::<_, std::io::Error>(reply)
+    })();
+
+    match reply {
+        Ok(reply) => json::parse(&reply),
+        Err(e) => {
+            log::fail!("hyprland: cannot ask {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// e.g. `Hyprland 0.41.2 (dwindle)`
+pub fn wm() -> Option<String> {
+    let version = request("version")?;
+    // `version` is newer; `tag` is `v0.34.0`
+    let number = version
+        .get("version")
+        .or_else(|| version.get("tag"))
+        .and_then(Value::as_str)
+        .map(|v| v.trim_start_matches('v'));
+    let mut wm = match number {
+        Some(number) => format!("Hyprland {number}"),
+        None => "Hyprland".to_string(),
+    };
+
+    let layout = request("getoption general:layout");
+    if let Some(layout) = layout.as_ref().and_then(|l| l.get("str")?.as_str()) {
+        wm.push_str(&format!(" ({layout})"));
+    }
+    Some(wm)
+}
+
+/// Each active monitor's mode and scale, e.g.
+/// `2560x1440 @ 144Hz (1.5x), 1920x1080 @ 60Hz`
+pub fn monitors() -> Option<String> {
+    let monitors = request("monitors")?;
+    let modes: Vec<String> = monitors
+        .as_array()?
+        .iter()
+        .filter(|m| m.get("disabled").and_then(Value::as_bool) != Some(true))
+        .filter_map(|m| {
+            let width = m.get("width")?.as_f64()?;
+            let height = m.get("height")?.as_f64()?;
+            let mut mode = match m.get("refreshRate").and_then(Value::as_f64) {
+                Some(rate) => format!("{width}x{height} @ {}Hz", rate.round()),
+                None => format!("{width}x{height}"),
+            };
+            if let Some(scale) = m
+                .get("scale")
+                .and_then(Value::as_f64)
+                .and_then(format_scale)
+            {
+                mode.push_str(&format!(" ({scale})"));
+            }
+            Some(mode)
+        })
+        .collect();
+    (!modes.is_empty()).then(|| modes.join(", "))
+}
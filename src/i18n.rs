@@ -0,0 +1,224 @@
+//! Label translations
+//! Built-in labels for the common modules in a few languages; anything not
+//! translated keeps its English label
+
+use crate::modules::Module;
+use crate::utils::get_env_var;
+
+/// Languages written right to left, whose labels get isolated from the
+/// left-to-right values next to them
+const RTL: &[&str] = &["ar", "he", "fa"];
+
+type Labels = &'static [(Module, &'static str)];
+
+static TRANSLATIONS: &[(&str, Labels)] = &[
+    (
+        "de",
+        &[
+            (Module::Os, "Betriebssystem"),
+            (Module::Uptime, "Laufzeit"),
+            (Module::Packages, "Pakete"),
+            (Module::Resolution, "Auflösung"),
+            (Module::Theme, "Design"),
+            (Module::Icons, "Symbole"),
+            (Module::Cursor, "Zeiger"),
+            (Module::Font, "Schrift"),
+            (Module::Memory, "Speicher"),
+            (Module::Disk, "Datenträger"),
+            (Module::Network, "Netzwerk"),
+            (Module::Battery, "Akku"),
+            (Module::Weather, "Wetter"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (Module::Os, "SO"),
+            (Module::Kernel, "Núcleo"),
+            (Module::Uptime, "Tiempo activo"),
+            (Module::Packages, "Paquetes"),
+            (Module::Resolution, "Resolución"),
+            (Module::Theme, "Tema"),
+            (Module::Icons, "Iconos"),
+            (Module::Cursor, "Cursor"),
+            (Module::Font, "Fuente"),
+            (Module::Memory, "Memoria"),
+            (Module::Disk, "Disco"),
+            (Module::Network, "Red"),
+            (Module::Battery, "Batería"),
+            (Module::Weather, "Clima"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (Module::Os, "SE"),
+            (Module::Kernel, "Noyau"),
+            (Module::Uptime, "Durée d'activité"),
+            (Module::Packages, "Paquets"),
+            (Module::Resolution, "Résolution"),
+            (Module::Theme, "Thème"),
+            (Module::Icons, "Icônes"),
+            (Module::Cursor, "Curseur"),
+            (Module::Font, "Police"),
+            (Module::Terminal, "Terminal"),
+            (Module::Memory, "Mémoire"),
+            (Module::Disk, "Disque"),
+            (Module::Network, "Réseau"),
+            (Module::Battery, "Batterie"),
+            (Module::Weather, "Météo"),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            (Module::Os, "SO"),
+            (Module::Kernel, "Núcleo"),
+            (Module::Uptime, "Tempo ativo"),
+            (Module::Packages, "Pacotes"),
+            (Module::Resolution, "Resolução"),
+            (Module::Theme, "Tema"),
+            (Module::Icons, "Ícones"),
+            (Module::Cursor, "Cursor"),
+            (Module::Font, "Fonte"),
+            (Module::Memory, "Memória"),
+            (Module::Disk, "Disco"),
+            (Module::Network, "Rede"),
+            (Module::Battery, "Bateria"),
+            (Module::Weather, "Clima"),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (Module::Os, "ОС"),
+            (Module::Kernel, "Ядро"),
+            (Module::Uptime, "Время работы"),
+            (Module::Packages, "Пакеты"),
+            (Module::Shell, "Оболочка"),
+            (Module::Resolution, "Разрешение"),
+            (Module::Theme, "Тема"),
+            (Module::Icons, "Значки"),
+            (Module::Cursor, "Курсор"),
+            (Module::Font, "Шрифт"),
+            (Module::Terminal, "Терминал"),
+            (Module::Cpu, "ЦП"),
+            (Module::Gpu, "ГП"),
+            (Module::Memory, "Память"),
+            (Module::Disk, "Диск"),
+            (Module::Network, "Сеть"),
+            (Module::Battery, "Батарея"),
+            (Module::Weather, "Погода"),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            (Module::Os, "OS"),
+            (Module::Kernel, "カーネル"),
+            (Module::Uptime, "稼働時間"),
+            (Module::Packages, "パッケージ"),
+            (Module::Shell, "シェル"),
+            (Module::Resolution, "解像度"),
+            (Module::Theme, "テーマ"),
+            (Module::Icons, "アイコン"),
+            (Module::Cursor, "カーソル"),
+            (Module::Font, "フォント"),
+            (Module::Terminal, "端末"),
+            (Module::Memory, "メモリ"),
+            (Module::Disk, "ディスク"),
+            (Module::Network, "ネットワーク"),
+            (Module::Battery, "バッテリー"),
+            (Module::Weather, "天気"),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            (Module::Os, "系统"),
+            (Module::Kernel, "内核"),
+            (Module::Uptime, "运行时间"),
+            (Module::Packages, "软件包"),
+            (Module::Shell, "终端外壳"),
+            (Module::Resolution, "分辨率"),
+            (Module::De, "桌面环境"),
+            (Module::Wm, "窗口管理器"),
+            (Module::Theme, "主题"),
+            (Module::Icons, "图标"),
+            (Module::Cursor, "光标"),
+            (Module::Font, "字体"),
+            (Module::Terminal, "终端"),
+            (Module::Cpu, "处理器"),
+            (Module::Gpu, "显卡"),
+            (Module::Memory, "内存"),
+            (Module::Disk, "磁盘"),
+            (Module::Network, "网络"),
+            (Module::Battery, "电池"),
+            (Module::Weather, "天气"),
+        ],
+    ),
+    (
+        "ar",
+        &[
+            (Module::Os, "نظام التشغيل"),
+            (Module::Kernel, "النواة"),
+            (Module::Uptime, "مدة التشغيل"),
+            (Module::Packages, "الحزم"),
+            (Module::Resolution, "الدقة"),
+            (Module::Theme, "السمة"),
+            (Module::Icons, "الأيقونات"),
+            (Module::Font, "الخط"),
+            (Module::Terminal, "الطرفية"),
+            (Module::Cpu, "المعالج"),
+            (Module::Memory, "الذاكرة"),
+            (Module::Disk, "القرص"),
+            (Module::Network, "الشبكة"),
+            (Module::Battery, "البطارية"),
+            (Module::Weather, "الطقس"),
+        ],
+    ),
+    (
+        "he",
+        &[
+            (Module::Os, "מערכת הפעלה"),
+            (Module::Kernel, "ליבה"),
+            (Module::Uptime, "זמן פעולה"),
+            (Module::Packages, "חבילות"),
+            (Module::Resolution, "רזולוציה"),
+            (Module::Theme, "ערכת נושא"),
+            (Module::Icons, "סמלים"),
+            (Module::Font, "גופן"),
+            (Module::Terminal, "מסוף"),
+            (Module::Cpu, "מעבד"),
+            (Module::Memory, "זיכרון"),
+            (Module::Disk, "דיסק"),
+            (Module::Network, "רשת"),
+            (Module::Battery, "סוללה"),
+            (Module::Weather, "מזג אוויר"),
+        ],
+    ),
+];
+
+/// The language of the messages locale, e.g. `de` for `de_DE.UTF-8`
+pub fn system_language() -> String {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .map(|var| get_env_var(var, ""))
+        .find(|value| !value.is_empty())
+        .unwrap_or("en");
+    locale
+        .split(['_', '.', '@'])
+        .next()
+        .unwrap_or("en")
+        .to_string()
+}
+
+/// A module's label in `language`, if there's a translation
+pub fn label(language: &str, module: Module) -> Option<&'static str> {
+    let (_, labels) = TRANSLATIONS.iter().find(|(lang, _)| *lang == language)?;
+    labels.iter().find(|(m, _)| *m == module).map(|(_, l)| *l)
+}
+
+pub fn is_rtl(language: &str) -> bool {
+    RTL.contains(&language)
+}
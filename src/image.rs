@@ -0,0 +1,214 @@
+//! Image export
+//! Draws the rendered output as an SVG or PNG on a dark terminal-like
+//! background, mapping ANSI colors to the xterm palette
+
+use crate::export::{self, Style};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const BACKGROUND: (u8, u8, u8) = (0x1e, 0x1e, 0x2e);
+/// Color of text without an SGR color
+const FOREGROUND: (u8, u8, u8) = (0xcd, 0xd6, 0xf4);
+
+/// Blank space around the text, in pixels
+const MARGIN: usize = 16;
+
+/// Monospace fonts tried for PNG export when none is configured
+static FONT_PATHS: &[&str] = &[
+    "/usr/share/fonts/truetype/dejavu/DejaVuSansMono.ttf",
+    "/usr/share/fonts/TTF/DejaVuSansMono.ttf",
+    "/usr/share/fonts/dejavu/DejaVuSansMono.ttf",
+    "/usr/share/fonts/truetype/liberation/LiberationMono-Regular.ttf",
+    "/usr/share/fonts/liberation-mono/LiberationMono-Regular.ttf",
+    "/usr/share/fonts/noto/NotoSansMono-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansMono-Regular.ttf",
+];
+
+fn color(style: Style) -> (u8, u8, u8) {
+    style.color.map_or(FOREGROUND, export::rgb)
+}
+
+/// The output as an SVG document; glyphs come from the viewer's monospace font
+pub fn svg(rendered: &str, font_size: usize) -> String {
+    let lines = export::cells(rendered);
+    let columns = lines.iter().map(Vec::len).max().unwrap_or(0);
+
+    // Typical monospace proportions: advance 0.6em, line height 1.25em
+    #[allow(clippy::cast_precision_loss)]
+    let cell_width = font_size as f64 * 0.6;
+    let line_height = font_size * 5 / 4;
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let width = (columns as f64 * cell_width).ceil() as usize + 2 * MARGIN;
+    let height = lines.len() * line_height + 2 * MARGIN;
+
+    let (r, g, b) = BACKGROUND;
+    let mut svg = String::with_capacity(rendered.len() * 4);
+    let _ = write!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-family=\"monospace\" font-size=\"{font_size}\" xml:space=\"preserve\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n"
+    );
+
+    for (row, line) in lines.iter().enumerate() {
+        let y = MARGIN + row * line_height + font_size;
+        let _ = write!(svg, "<text y=\"{y}\">");
+
+        // One tspan per run of equally styled characters, each placed at its
+        // column so the layout holds whatever the font's advance
+        let mut column = 0;
+        for run in line.chunk_by(|a, b| a.1 == b.1) {
+            let text: String = run.iter().map(|&(c, _)| c).collect();
+            if !text.trim().is_empty() {
+                let (r, g, b) = color(run[0].1);
+                #[allow(clippy::cast_precision_loss)]
+                let x = MARGIN as f64 + column as f64 * cell_width;
+                let weight = if run[0].1.bold {
+                    " font-weight=\"bold\""
+                } else {
+                    ""
+                };
+                let _ = write!(
+                    svg,
+                    "<tspan x=\"{x:.1}\" fill=\"#{r:02x}{g:02x}{b:02x}\"{weight}>{}</tspan>",
+                    escape(&text)
+                );
+            }
+            column += run.len();
+        }
+        svg.push_str("</text>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The output as a PNG, drawn with `font` or the first monospace font found
+pub fn png(rendered: &str, font: Option<&Path>, font_size: usize) -> io::Result<Vec<u8>> {
+    use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+
+    let path = font
+        .map(Path::to_path_buf)
+        .or_else(|| FONT_PATHS.iter().map(PathBuf::from).find(|p| p.exists()))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "no monospace font found, set [image] font",
+            )
+        })?;
+    let data = fs::read(&path)?;
+    let font = FontRef::try_from_slice(&data)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    #[allow(clippy::cast_precision_loss)]
+    let scaled = font.as_scaled(PxScale::from(font_size as f32));
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (cell_width, line_height, ascent) = (
+        scaled.h_advance(scaled.glyph_id('M')).ceil() as usize,
+        (scaled.height() + scaled.line_gap()).ceil() as usize,
+        scaled.ascent(),
+    );
+
+    let lines = export::cells(rendered);
+    let columns = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let width = columns * cell_width + 2 * MARGIN;
+    let height = lines.len() * line_height + 2 * MARGIN;
+
+    let mut canvas = Canvas::new(width, height);
+    for (row, line) in lines.iter().enumerate() {
+        for (column, &(c, style)) in line.iter().enumerate() {
+            if c == ' ' {
+                continue;
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let origin = ab_glyph::point(
+                (MARGIN + column * cell_width) as f32,
+                (MARGIN + row * line_height) as f32 + ascent,
+            );
+            let glyph = scaled.scaled_glyph(c);
+            let Some(outline) = font.outline_glyph(ab_glyph::Glyph {
+                position: origin,
+                ..glyph
+            }) else {
+                continue;
+            };
+
+            let bounds = outline.px_bounds();
+            let color = color(style);
+            // Faux bold: the glyph drawn twice, one pixel apart
+            let passes = if style.bold { 2 } else { 1 };
+            for pass in 0..passes {
+                outline.draw(|x, y, coverage| {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let (x, y) = (
+                        (bounds.min.x as i64 + i64::from(x) + pass) as usize,
+                        (bounds.min.y as i64 + i64::from(y)) as usize,
+                    );
+                    canvas.blend(x, y, color, coverage);
+                });
+            }
+        }
+    }
+
+    canvas.encode()
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        let (r, g, b) = BACKGROUND;
+        Self {
+            width,
+            height,
+            pixels: [r, g, b].repeat(width * height),
+        }
+    }
+
+    fn blend(&mut self, x: usize, y: usize, (r, g, b): (u8, u8, u8), coverage: f32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let i = (y * self.width + x) * 3;
+        for (channel, value) in self.pixels[i..i + 3].iter_mut().zip([r, g, b]) {
+            let mixed = f32::from(*channel) + (f32::from(value) - f32::from(*channel)) * coverage;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            {
+                *channel = mixed.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+
+    fn encode(&self) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        let (width, height) = (
+            u32::try_from(self.width).map_err(io::Error::other)?,
+            u32::try_from(self.height).map_err(io::Error::other)?,
+        );
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)?;
+        Ok(out)
+    }
+}
@@ -0,0 +1,118 @@
+//! Installation date
+//! Inferred from the birth time of the root filesystem, or the first entry of
+//! the package manager's log when the filesystem doesn't record birth times
+
+use crate::gzip;
+use crate::source;
+use crate::utils::{days_from_civil, format_date};
+use std::ffi::CString;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Anything before this (2000-01-01) is a placeholder, not a real birth time
+const EARLIEST_PLAUSIBLE: i64 = 946_684_800;
+
+/// Install date with its age, e.g. `2023-05-14 (412 days ago)`
+pub fn install_date() -> Option<String> {
+    let installed = installed_at()?;
+    Some(format!(
+        "{} ({})",
+        format_date(installed),
+        days_ago(installed)?
+    ))
+}
+
+/// Age of the installation, e.g. `412 days ago`
+pub fn install_age() -> Option<String> {
+    days_ago(installed_at()?)
+}
+
+fn installed_at() -> Option<i64> {
+    root_birth_time()
+        .or_else(pacman_log_start)
+        .or_else(dpkg_log_start)
+}
+
+fn days_ago(timestamp: i64) -> Option<String> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    let days = (i64::try_from(now).ok()? - timestamp).max(0) / 86_400;
+    Some(match days {
+        0 => "today".to_string(),
+        1 => "1 day ago".to_string(),
+        n => format!("{n} days ago"),
+    })
+}
+
+fn root_birth_time() -> Option<i64> {
+    // A recording has no birth times, only the logs
+    if !source::current().is_live() {
+        return None;
+    }
+    let path = CString::new("/").ok()?;
+    let mut stx: libc::statx = unsafe { std::mem::zeroed() };
+    let ret = unsafe {
+        libc::statx(
+            libc::AT_FDCWD,
+            path.as_ptr(),
+            0,
+            libc::STATX_BTIME,
+            &raw mut stx,
+        )
+    };
+    if ret != 0 || stx.stx_mask & libc::STATX_BTIME == 0 {
+        return None;
+    }
+    Some(stx.stx_btime.tv_sec).filter(|&t| t >= EARLIEST_PLAUSIBLE)
+}
+
+/// First entry of pacman's log, e.g. `[2023-05-14T09:12:44+0200] ...`
+fn pacman_log_start() -> Option<i64> {
+    let log = source::current()
+        .read_to_string(Path::new("/var/log/pacman.log"))
+        .ok()?;
+    let first = log.lines().next()?.strip_prefix('[')?;
+    parse_date(first)
+}
+
+/// Earliest entry across dpkg's current and rotated logs
+fn dpkg_log_start() -> Option<i64> {
+    let source = source::current();
+    source
+        .read_dir(Path::new("/var/log"))
+        .ok()?
+        .into_iter()
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("dpkg.log"))
+        })
+        .filter_map(|path| {
+            let data = source.read(&path).ok()?;
+            let data = if path.extension().is_some_and(|ext| ext == "gz") {
+                gzip::decompress(&data)?
+            } else {
+                data
+            };
+            // Lines start with `2023-05-14 09:12:44 ...`
+            let first = data.split(|&b| b == b'\n').next()?;
+            parse_date(std::str::from_utf8(first).ok()?)
+        })
+        .min()
+}
+
+/// Midnight UTC of a leading `YYYY-MM-DD` date
+fn parse_date(text: &str) -> Option<i64> {
+    let date = text.get(..10)?;
+    let mut fields = date.split('-');
+    let year = fields.next()?.parse().ok()?;
+    let month = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|m| (1..=12).contains(m))?;
+    let day = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|d| (1..=31).contains(d))?;
+    Some(days_from_civil(year, month, day) * 86_400)
+}
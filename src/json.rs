@@ -0,0 +1,267 @@
+//! Minimal JSON reader
+//! Enough to read the replies of compositor IPC sockets; numbers are kept as
+//! floats and string escapes other than `\uXXXX` surrogate pairs are decoded
+
+pub enum Value {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// A member of an object
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+}
+
+/// The value `input` holds, `None` if it isn't valid JSON
+pub fn parse(input: &str) -> Option<Value> {
+    let mut parser = Parser {
+        input: input.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    parser.whitespace();
+    (parser.pos == parser.input.len()).then_some(value)
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn whitespace(&mut self) {
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip whitespace and take `byte` if it comes next
+    fn eat(&mut self, byte: u8) -> bool {
+        self.whitespace();
+        let found = self.input.get(self.pos) == Some(&byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Option<Value> {
+        let end = self.pos + word.len();
+        (self.input.get(self.pos..end)? == word.as_bytes()).then(|| {
+            self.pos = end;
+            value
+        })
+    }
+
+    fn value(&mut self) -> Option<Value> {
+        self.whitespace();
+        match *self.input.get(self.pos)? {
+            b'n' => self.literal("null", Value::Null),
+            b't' => self.literal("true", Value::Boolean(true)),
+            b'f' => self.literal("false", Value::Boolean(false)),
+            b'"' => self.string().map(Value::String),
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        self.eat(b',').then_some(())?;
+                    }
+                }
+                Some(Value::Array(items))
+            }
+            b'{' => {
+                self.pos += 1;
+                let mut members = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.whitespace();
+                        let key = self.string()?;
+                        self.eat(b':').then_some(())?;
+                        members.push((key, self.value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        self.eat(b',').then_some(())?;
+                    }
+                }
+                Some(Value::Object(members))
+            }
+            _ => self.number(),
+        }
+    }
+
+    fn number(&mut self) -> Option<Value> {
+        let start = self.pos;
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(|&b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E'))
+        {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.input[start..self.pos]).ok()?;
+        text.parse().ok().map(Value::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.input.get(self.pos) != Some(&b'"') {
+            return None;
+        }
+        self.pos += 1;
+
+        let mut bytes = Vec::new();
+        loop {
+            match *self.input.get(self.pos)? {
+                b'"' => break,
+                b'\\' => {
+                    self.pos += 1;
+                    let escaped = match *self.input.get(self.pos)? {
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let hex = self.input.get(self.pos + 1..self.pos + 5)?;
+                            let code = u32::from_str_radix(std::str::from_utf8(hex).ok()?, 16);
+                            self.pos += 4;
+                            char::from_u32(code.ok()?).unwrap_or('\u{fffd}')
+                        }
+                        other => char::from(other),
+                    };
+                    let mut buf = [0u8; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                byte => bytes.push(byte),
+            }
+            self.pos += 1;
+        }
+        self.pos += 1;
+        String::from_utf8(bytes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Value, parse};
+
+    #[test]
+    fn nested_values() {
+        let value = parse(
+            r#" { "name": "DP-1", "scale": 1.5, "focused": true, "mode": null,
+                "workspaces": [ { "id": -2 }, { "id": 3e1 } ], "empty": {} } "#,
+        )
+        .unwrap();
+        assert_eq!(value.get("name").and_then(Value::as_str), Some("DP-1"));
+        assert_eq!(value.get("scale").and_then(Value::as_f64), Some(1.5));
+        assert_eq!(value.get("focused").and_then(Value::as_bool), Some(true));
+        assert!(matches!(value.get("mode"), Some(Value::Null)));
+        assert!(value.get("missing").is_none());
+
+        let ids: Vec<f64> = value
+            .get("workspaces")
+            .and_then(Value::as_array)
+            .unwrap()
+            .iter()
+            .filter_map(|w| w.get("id")?.as_f64())
+            .collect();
+        assert_eq!(ids, [-2.0, 30.0]);
+        assert!(matches!(value.get("empty"), Some(Value::Object(m)) if m.is_empty()));
+    }
+
+    #[test]
+    fn string_escapes() {
+        let value = parse(r#""a\"b\\c\/d\n\t\u00e9\u2603""#).unwrap();
+        assert_eq!(value.as_str(), Some("a\"b\\c/d\n\té☃"));
+        assert_eq!(
+            parse("\"raw ☃ utf-8\"").unwrap().as_str(),
+            Some("raw ☃ utf-8")
+        );
+    }
+
+    #[test]
+    fn surrogate_pairs_are_not_combined() {
+        let value = parse(r#""\ud83d\ude00""#).unwrap();
+        assert_eq!(value.as_str(), Some("\u{fffd}\u{fffd}"));
+    }
+
+    #[test]
+    fn malformed() {
+        for input in [
+            "",
+            "   ",
+            "[1, 2",
+            "[1 2]",
+            "[1,]",
+            "{\"a\" 1}",
+            "{\"a\": 1,}",
+            "{a: 1}",
+            "\"unterminated",
+            "\"bad \\u12\"",
+            "tru",
+            "nul",
+            "1.2.3",
+            "-",
+            "[] []",
+            "{} trailing",
+        ] {
+            assert!(parse(input).is_none(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn top_level_scalars() {
+        assert_eq!(parse(" 42 ").and_then(|v| v.as_f64()), Some(42.0));
+        assert_eq!(parse("false").and_then(|v| v.as_bool()), Some(false));
+        assert_eq!(
+            parse("[]").map(|v| v.as_array().map(<[Value]>::len)),
+            Some(Some(0))
+        );
+    }
+}
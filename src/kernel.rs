@@ -0,0 +1,299 @@
+//! Kernel build details
+//! Preemption model, tick rate and kernel flavor, from `/proc/version` and the
+//! kernel config where it is exposed, plus loaded modules, taint state and
+//! notable boot parameters
+
+use crate::gzip;
+use crate::source;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Kernel flavors worth calling out, matched against release components
+static FLAVORS: &[&str] = &[
+    "zen", "rt", "lqx", "xanmod", "cachyos", "hardened", "liquorix",
+];
+
+/// Letter the kernel uses for each taint bit, lowest bit first
+static TAINT_FLAGS: &[char] = &[
+    'P', 'F', 'S', 'R', 'M', 'B', 'U', 'D', 'A', 'W', 'C', 'I', 'O', 'E', 'L', 'K', 'X', 'T', 'N',
+];
+
+/// Boot parameters worth showing; names ending in `.` match any parameter of
+/// that kernel module, e.g. `nvidia-drm.modeset`
+static NOTABLE_PARAMS: &[&str] = &[
+    "quiet",
+    "splash",
+    "single",
+    "emergency",
+    "init",
+    "systemd.unit",
+    "rd.break",
+    "nomodeset",
+    "mitigations",
+    "nosmt",
+    "spectre_v2",
+    "pti",
+    "lockdown",
+    "lsm",
+    "security",
+    "selinux",
+    "apparmor",
+    "iommu",
+    "intel_iommu",
+    "amd_iommu",
+    "iommu.passthrough",
+    "acpi",
+    "noapic",
+    "nolapic",
+    "pcie_aspm",
+    "preempt",
+    "threadirqs",
+    "isolcpus",
+    "nohz_full",
+    "rcu_nocbs",
+    "transparent_hugepage",
+    "hugepages",
+    "default_hugepagesz",
+    "zswap.enabled",
+    "resume",
+    "amdgpu.",
+    "i915.",
+    "nouveau.",
+    "nvidia-drm.",
+];
+
+static KERNEL_CONFIG: LazyLock<Option<String>> = LazyLock::new(read_kernel_config);
+
+/// The running kernel's build config, from `/proc/config.gz` or `/boot`
+pub fn kernel_config() -> Option<&'static str> {
+    KERNEL_CONFIG.as_deref()
+}
+
+fn read_kernel_config() -> Option<String> {
+    let source = source::current();
+    if let Ok(compressed) = source.read(Path::new("/proc/config.gz"))
+        && let Some(config) = gzip::decompress(&compressed)
+    {
+        return String::from_utf8(config).ok();
+    }
+
+    let release = source.uname().release;
+    source
+        .read_to_string(Path::new(&format!("/boot/config-{release}")))
+        .or_else(|_| source.read_to_string(Path::new(&format!("/lib/modules/{release}/config"))))
+        .ok()
+}
+
+/// Value of a `CONFIG_*` option, e.g. `y` or `1000`
+fn config_value<'a>(config: &'a str, key: &str) -> Option<&'a str> {
+    config
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+}
+
+/// Preemption model, tick rate, flavor, cpufreq governor and sched_ext
+/// scheduler, e.g.
+/// `PREEMPT_DYNAMIC (full), 1000 Hz, tickless idle, zen, schedutil governor`
+pub fn preemption_info(release: &str) -> String {
+    let version = source::current()
+        .read_to_string(Path::new("/proc/version"))
+        .unwrap_or_default();
+    let config = kernel_config();
+    let enabled = |key| config.and_then(|c| config_value(c, key)) == Some("y");
+
+    let mut parts = Vec::new();
+
+    // The build banner names the model, e.g. `#1 SMP PREEMPT_DYNAMIC ...`
+    let model = if version.contains("PREEMPT_RT") || enabled("CONFIG_PREEMPT_RT") {
+        "PREEMPT_RT".to_string()
+    } else if version.contains("PREEMPT_DYNAMIC") || enabled("CONFIG_PREEMPT_DYNAMIC") {
+        match dynamic_mode(config) {
+            Some(mode) => format!("PREEMPT_DYNAMIC ({mode})"),
+            None => "PREEMPT_DYNAMIC".to_string(),
+        }
+    } else if version.contains(" PREEMPT ") || enabled("CONFIG_PREEMPT") {
+        "PREEMPT".to_string()
+    } else if enabled("CONFIG_PREEMPT_VOLUNTARY") {
+        "PREEMPT_VOLUNTARY".to_string()
+    } else if config.is_some() {
+        "PREEMPT_NONE".to_string()
+    } else {
+        // Without the config the banner can't tell these two apart
+        "PREEMPT_VOLUNTARY or PREEMPT_NONE".to_string()
+    };
+    parts.push(model);
+
+    if let Some(config) = config {
+        if let Some(hz) = config_value(config, "CONFIG_HZ") {
+            parts.push(format!("{hz} Hz"));
+        }
+        if config_value(config, "CONFIG_NO_HZ_FULL") == Some("y") {
+            parts.push("tickless".to_string());
+        } else if config_value(config, "CONFIG_NO_HZ_IDLE") == Some("y") {
+            parts.push("tickless idle".to_string());
+        }
+    }
+
+    if let Some(flavor) = flavor(release) {
+        parts.push(flavor.to_string());
+    }
+
+    parts.extend(governors());
+    parts.extend(sched_ext());
+
+    parts.join(", ")
+}
+
+/// The cpufreq governors in use, e.g. `schedutil governor`, or with the number
+/// of policies each when they differ, e.g. `performance (2) / powersave (6)
+/// governors`
+fn governors() -> Option<String> {
+    let source = source::current();
+    let mut policies = source
+        .read_dir(Path::new("/sys/devices/system/cpu/cpufreq"))
+        .ok()?;
+    policies.sort();
+
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for policy in policies {
+        let Ok(governor) = source.read_to_string(&policy.join("scaling_governor")) else {
+            continue;
+        };
+        let governor = governor.trim();
+        match counts.iter_mut().find(|(g, _)| g == governor) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((governor.to_string(), 1)),
+        }
+    }
+
+    match counts.as_slice() {
+        [] => None,
+        [(governor, _)] => Some(format!("{governor} governor")),
+        counts => {
+            let counts: Vec<String> = counts
+                .iter()
+                .map(|(governor, count)| format!("{governor} ({count})"))
+                .collect();
+            Some(format!("{} governors", counts.join(" / ")))
+        }
+    }
+}
+
+/// The BPF scheduler loaded through sched_ext, e.g. `sched_ext lavd`
+fn sched_ext() -> Option<String> {
+    let source = source::current();
+    let state = source
+        .read_to_string(Path::new("/sys/kernel/sched_ext/state"))
+        .ok()?;
+    if state.trim() != "enabled" {
+        return None;
+    }
+    let ops = source
+        .read_to_string(Path::new("/sys/kernel/sched_ext/root/ops"))
+        .unwrap_or_default();
+    Some(match ops.trim() {
+        "" => "sched_ext".to_string(),
+        ops => format!("sched_ext {ops}"),
+    })
+}
+
+/// Mode a `PREEMPT_DYNAMIC` kernel is running in
+fn dynamic_mode(config: Option<&str>) -> Option<String> {
+    // debugfs marks the active mode, e.g. `none voluntary (full) lazy`
+    if let Ok(modes) =
+        source::current().read_to_string(Path::new("/sys/kernel/debug/sched/preempt"))
+        && let Some(start) = modes.find('(')
+        && let Some(len) = modes[start + 1..].find(')')
+    {
+        return Some(modes[start + 1..start + 1 + len].to_string());
+    }
+
+    if let Ok(cmdline) = source::current().read_to_string(Path::new("/proc/cmdline"))
+        && let Some(mode) = cmdline
+            .split_whitespace()
+            .find_map(|arg| arg.strip_prefix("preempt="))
+    {
+        return Some(mode.to_string());
+    }
+
+    // Otherwise the build-time default applies
+    let config = config?;
+    [
+        ("CONFIG_PREEMPT", "full"),
+        ("CONFIG_PREEMPT_LAZY", "lazy"),
+        ("CONFIG_PREEMPT_VOLUNTARY", "voluntary"),
+        ("CONFIG_PREEMPT_NONE", "none"),
+    ]
+    .iter()
+    .find(|(key, _)| config_value(config, key) == Some("y"))
+    .map(|(_, mode)| (*mode).to_string())
+}
+
+/// Known kernel flavor in the release string, e.g. `zen` in `6.9.1-zen1-1-zen`
+fn flavor(release: &str) -> Option<&'static str> {
+    release.split(['-', '.', '_']).skip(1).find_map(|part| {
+        FLAVORS.iter().copied().find(|flavor| {
+            part.strip_prefix(flavor)
+                .is_some_and(|rest| rest.bytes().all(|b| b.is_ascii_digit()))
+        })
+    })
+}
+
+/// Loaded module count and taint flags, e.g. `142 loaded, tainted (P, O, E)`
+pub fn modules_info() -> String {
+    let modules = match source::current().read_to_string(Path::new("/proc/modules")) {
+        Ok(list) => format!("{} loaded", list.lines().count()),
+        // Kernels built without module support have no list
+        Err(_) => "built-in only".to_string(),
+    };
+
+    let flags = source::current()
+        .read_to_string(Path::new("/proc/sys/kernel/tainted"))
+        .ok()
+        .and_then(|mask| mask.trim().parse::<u64>().ok())
+        .map(taint_flags);
+
+    match flags {
+        Some(flags) if flags.is_empty() => format!("{modules}, not tainted"),
+        Some(flags) => format!("{modules}, tainted ({})", flags.join(", ")),
+        None => modules,
+    }
+}
+
+/// Decode a taint mask into the kernel's flag letters; unknown bits are shown
+/// by number
+fn taint_flags(mask: u64) -> Vec<String> {
+    (0..64)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| {
+            TAINT_FLAGS
+                .get(bit)
+                .map_or_else(|| format!("bit {bit}"), char::to_string)
+        })
+        .collect()
+}
+
+/// Notable parameters from /proc/cmdline, e.g. `quiet splash mitigations=off`
+pub fn cmdline_flags() -> Option<String> {
+    let cmdline = source::current()
+        .read_to_string(Path::new("/proc/cmdline"))
+        .ok()?;
+    let flags: Vec<&str> = cmdline
+        .split_whitespace()
+        // Everything after `--` is passed to init
+        .take_while(|param| *param != "--")
+        .filter(|param| {
+            let name = param.split_once('=').map_or(*param, |(name, _)| name);
+            NOTABLE_PARAMS
+                .iter()
+                .any(|notable| match notable.strip_suffix('.') {
+                    Some(module) => name
+                        .strip_prefix(module)
+                        .is_some_and(|p| p.starts_with('.')),
+                    None => name == *notable,
+                })
+        })
+        .collect();
+
+    (!flags.is_empty()).then(|| flags.join(" "))
+}
@@ -0,0 +1,354 @@
+//! Side-by-side layout of the logo and the info block
+
+use crate::color::{self, Color};
+use crate::config::ScreenshotConfig;
+use crate::modules::Line;
+use crate::utils::{char_width, display_width, escape_len, strip_ansi};
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+const RESET: &str = "\x1b[0m";
+
+/// Space between logo and info
+pub const PADDING: usize = 3;
+
+/// Colors of the info block
+pub struct Colors<'a> {
+    /// Sequence for the keys and the title; `None` follows the logo
+    pub key: Option<&'a str>,
+    /// Sequence for the separator; `None` follows the logo
+    pub separator: Option<&'a str>,
+    /// Blocks of the `colors` line
+    pub blocks: &'a [Color],
+}
+
+impl Colors<'_> {
+    /// Keys following the logo, everything else uncolored
+    pub const PLAIN: Colors<'static> = Colors {
+        key: None,
+        separator: Some(""),
+        blocks: &[],
+    };
+}
+
+/// Write the logo with the info lines to its right
+///
+/// Keys and the title are colored with whatever color the logo line they
+/// sit next to ended on, so the info block follows the logo's palette,
+/// unless `colors` sets their color. With `align`, values start in the
+/// column after the widest key.
+pub fn render(
+    out: &mut (impl Write + ?Sized),
+    logo_art: &str,
+    logo_width: usize,
+    lines: &[Line],
+    use_color: bool,
+    colors: &Colors,
+    align: bool,
+) -> io::Result<()> {
+    let logo_lines: Vec<&str> = logo_art.lines().collect();
+    let key_width = if align { key_width(lines) } else { 0 };
+    let max_lines = std::cmp::max(logo_lines.len(), lines.len());
+
+    // Track color state
+    let mut current_color = String::new();
+
+    for i in 0..max_lines {
+        let logo_line = logo_lines.get(i).copied().unwrap_or("");
+
+        // Print logo line
+        write!(out, "{logo_line}")?;
+
+        if use_color {
+            track_color(logo_line, &mut current_color);
+        }
+
+        // Print info with padding
+        if let Some(line) = lines.get(i) {
+            // Calculate required padding to reach the logo width; without a
+            // logo the info starts at the left edge
+            let gap = if logo_lines.is_empty() { 0 } else { PADDING };
+            let padding_needed = logo_width.saturating_sub(visible_length(logo_line)) + gap;
+
+            // Reset color, add padding
+            let reset = if use_color { RESET } else { "" };
+            write!(out, "{reset}{:padding_needed$}", "")?;
+
+            match line {
+                Line::Separator(text) => {
+                    let color = colors.separator.unwrap_or(&current_color);
+                    if color.is_empty() {
+                        write!(out, "{text}")?;
+                    } else {
+                        write!(out, "{color}{text}{RESET}")?;
+                    }
+                }
+                Line::Header { text, color } => {
+                    let own = color
+                        .filter(|_| use_color)
+                        .map(|c| c.sequence(color::depth()));
+                    let color = own.as_deref().or(colors.key).unwrap_or(&current_color);
+                    if color.is_empty() {
+                        write!(out, "{text}")?;
+                    } else {
+                        write!(out, "{color}{text}{RESET}")?;
+                    }
+                }
+                Line::Colors if use_color => {
+                    let depth = color::depth();
+                    for block in colors.blocks {
+                        write!(out, "{}███", block.sequence(depth))?;
+                    }
+                    write!(out, "{RESET}")?;
+                }
+                _ => write_line(out, line, colors.key.unwrap_or(&current_color), key_width)?,
+            }
+
+            // Only restore color if there's more logo lines coming
+            if i + 1 < logo_lines.len() && !current_color.is_empty() {
+                write!(out, "{current_color}")?;
+            }
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// Write already rendered output centered in a terminal of `size` columns and
+/// rows, keeping at least the configured padding around it
+pub fn frame(
+    out: &mut (impl Write + ?Sized),
+    rendered: &str,
+    size: Option<(usize, usize)>,
+    config: &ScreenshotConfig,
+) -> io::Result<()> {
+    let lines: Vec<&str> = rendered.lines().collect();
+    let width = lines
+        .iter()
+        .map(|line| visible_length(line))
+        .max()
+        .unwrap_or(0);
+    let (columns, rows) = size.unwrap_or((0, 0));
+
+    // The prompt takes the last row
+    let free_rows = rows.saturating_sub(lines.len() + 1);
+    let top = (free_rows / 2).max(config.padding_y);
+    let bottom = if config.hide_prompt {
+        free_rows.saturating_sub(top).max(config.padding_y)
+    } else {
+        config.padding_y
+    };
+    let left = (columns.saturating_sub(width) / 2).max(config.padding_x);
+
+    write!(out, "{}", "\n".repeat(top))?;
+    for line in lines {
+        writeln!(out, "{:left$}{line}", "")?;
+    }
+    write!(out, "{}", "\n".repeat(bottom))
+}
+
+/// Cut info lines so none is wider than `columns`, for output with a fixed
+/// width like /etc/motd; `align` as given to [`render`]
+pub fn fit(lines: &mut [Line], columns: usize, align: bool) {
+    let key_width = if align { key_width(lines) } else { 0 };
+    for line in lines {
+        match line {
+            Line::Title(parts) => {
+                // Whatever room is left goes to the last piece
+                let Some((last, rest)) = parts.split_last_mut() else {
+                    continue;
+                };
+                let used: usize = rest.iter().map(|p| display_width(&p.text)).sum();
+                truncate(&mut last.text, columns.saturating_sub(used));
+            }
+            Line::Separator(text) | Line::Header { text, .. } | Line::Text(text) => {
+                truncate(text, columns);
+            }
+            Line::Colors => {}
+            Line::Entry { key, value } => {
+                let value_columns = columns.saturating_sub(display_width(key).max(key_width) + 2);
+                truncate(value, value_columns);
+            }
+        }
+    }
+}
+
+/// Shorten text to `columns` visible columns, marking the cut with `...`
+///
+/// Escape sequences take no room and are never split: those after the cut
+/// still follow the mark, so hyperlinks get closed, and a reset ends any
+/// color left open.
+fn truncate(text: &mut Cow<str>, columns: usize) {
+    if display_width(&strip_ansi(text)) <= columns {
+        return;
+    }
+    let keep = columns.saturating_sub(3);
+    let mut used = 0;
+    let mut cut = String::with_capacity(text.len() + RESET.len());
+    let mut escapes = String::new();
+    let mut rest = text.as_ref();
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '\x1b' {
+            let len = escape_len(rest);
+            let target = if used > keep { &mut escapes } else { &mut cut };
+            target.push_str(&rest[..len]);
+            len
+        } else {
+            used += char_width(c);
+            if used <= keep {
+                cut.push(c);
+            }
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+    cut.push_str(&"..."[..columns.min(3)]);
+    if !escapes.is_empty() || cut.contains('\x1b') {
+        cut.push_str(&escapes);
+        cut.push_str(RESET);
+    }
+    *text = Cow::Owned(cut);
+}
+
+/// Write the info lines alone, one per line, for screen readers
+pub fn render_list(out: &mut (impl Write + ?Sized), lines: &[Line]) -> io::Result<()> {
+    for line in lines {
+        write_line(out, line, "", 0)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// Widest key among the lines, in columns
+fn key_width(lines: &[Line]) -> usize {
+    lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Entry { key, .. } => Some(display_width(key)),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Write one info line, padding its key to `key_width` columns
+fn write_line(
+    out: &mut (impl Write + ?Sized),
+    line: &Line,
+    color: &str,
+    key_width: usize,
+) -> io::Result<()> {
+    match line {
+        Line::Title(parts) => {
+            for part in parts {
+                if part.field && !color.is_empty() {
+                    write!(out, "{color}{}{RESET}", part.text)?;
+                } else {
+                    write!(out, "{}", part.text)?;
+                }
+            }
+            Ok(())
+        }
+        Line::Separator(text) | Line::Header { text, .. } | Line::Text(text) => {
+            write!(out, "{text}")
+        }
+        Line::Colors => Ok(()),
+        Line::Entry { key, value } => {
+            let pad = key_width.saturating_sub(display_width(key));
+            if color.is_empty() {
+                write!(out, "{key}: {:pad$}{value}", "")
+            } else {
+                // Color the key and keep the reset before the colon
+                write!(out, "{color}{key}{RESET}: {:pad$}{value}", "")
+            }
+        }
+    }
+}
+
+/// Columns a line takes, excluding ANSI escape sequences
+fn visible_length(line: &str) -> usize {
+    display_width(&strip_ansi(line))
+}
+
+/// Update the active color with the color sequences found in a logo line
+fn track_color(line: &str, current_color: &mut String) {
+    let mut start_idx = 0;
+
+    while let Some(esc_idx) = line[start_idx..].find("\x1b[") {
+        let abs_idx = start_idx + esc_idx;
+
+        // Find the end of the sequence (the 'm')
+        let Some(m_idx) = line[abs_idx..].find('m') else {
+            break;
+        };
+        let end_idx = abs_idx + m_idx + 1;
+        let sequence = &line[abs_idx..end_idx];
+
+        if sequence == RESET {
+            current_color.clear();
+        } else {
+            *current_color = sequence.to_string();
+        }
+
+        start_idx = end_idx;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate, visible_length};
+    use std::borrow::Cow;
+
+    fn cut(text: &str, columns: usize) -> String {
+        let mut text = Cow::Borrowed(text);
+        truncate(&mut text, columns);
+        text.into_owned()
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(cut("short", 10), "short");
+        assert_eq!(cut("Intel Core i7-8700K", 10), "Intel C...");
+        assert_eq!(cut("wide 漢字 text", 9), "wide ...");
+    }
+
+    #[test]
+    fn escapes_are_kept_whole_and_take_no_room() {
+        // Fits once the color codes are left out
+        assert_eq!(cut("\x1b[1;31m92°C\x1b[0m", 4), "\x1b[1;31m92°C\x1b[0m");
+        assert_eq!(
+            cut("\x1b[1;31m92°C\x1b[0m (high)", 8),
+            "\x1b[1;31m92°C\x1b[0m ...\x1b[0m"
+        );
+        assert_eq!(
+            cut("\x1b[38;2;255;0;0mcritical\x1b[0m", 6),
+            "\x1b[38;2;255;0;0mcri...\x1b[0m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn hyperlinks_are_closed() {
+        let link = "\x1b]8;;https://gnome.org\x1b\\Adwaita-dark\x1b]8;;\x1b\\";
+        assert_eq!(
+            cut(link, 8),
+            "\x1b]8;;https://gnome.org\x1b\\Adwai...\x1b]8;;\x1b\\\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn visible_length_skips_csi_and_osc() {
+        assert_eq!(visible_length("\x1b[1;36mOS\x1b[0m: Fedora"), 10);
+        // The URL has an `m` in it, which doesn't end an OSC
+        assert_eq!(
+            visible_length("Theme: \x1b]8;;https://gnome.org\x1b\\Adwaita\x1b]8;;\x1b\\"),
+            14
+        );
+        assert_eq!(
+            visible_length("\x1b]8;;https://kernel.org\x076.8\x1b]8;;\x07"),
+            3
+        );
+    }
+}
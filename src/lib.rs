@@ -0,0 +1,411 @@
+//! tachi-fetch
+//! The whole program lives in this library so `benches/` can reach its
+//! internals through [`bench`]; `main.rs` only calls [`run`]
+
+use std::io::{IsTerminal, Write};
+use std::sync::LazyLock;
+use std::time::Instant;
+
+mod alloc;
+mod art;
+mod backend;
+mod banner;
+mod bar;
+mod battery;
+pub mod bench;
+mod cgroup;
+mod chassis;
+mod cli;
+mod color;
+mod completions;
+mod config;
+mod containers;
+mod crypt;
+mod dbus;
+mod disk;
+mod display;
+mod edid;
+mod export;
+mod extras;
+mod fans;
+mod glyphs;
+mod gnome;
+mod gpu;
+mod graphics;
+mod greeting;
+mod gzip;
+mod hyperlink;
+mod hyprland;
+mod i18n;
+mod identity;
+#[cfg(feature = "image-export")]
+mod image;
+mod install;
+mod json;
+mod kernel;
+mod layout;
+mod log;
+#[rustfmt::skip]
+mod logos;
+mod media;
+mod memory;
+mod metrics;
+mod modules;
+mod net;
+mod nvme;
+mod os;
+mod output;
+mod packages;
+mod palette;
+mod portal;
+#[cfg(feature = "prefetch")]
+mod prefetch;
+mod proc;
+mod raid;
+mod scheme;
+mod security;
+mod serve;
+mod session;
+mod shell;
+mod sound;
+mod source;
+mod sway;
+mod systemd;
+mod tar;
+mod terminal;
+mod theme;
+mod thermal;
+mod toml;
+mod updates;
+mod utils;
+mod virt;
+mod watch;
+mod weather;
+mod wifi;
+mod x11;
+mod xsettings;
+
+use config::{Hyperlinks, MotdColor};
+use export::Format;
+use modules::{Module, Needs};
+use utils::{ENV_CACHE, strip_ansi};
+
+/// Fetch and print, as configured by the command line and config file
+pub fn run() {
+    let start_time = Instant::now();
+
+    let args = cli::parse();
+    log::set_verbosity(args.verbosity);
+    if let Some(dir) = &args.fixture {
+        let fixture = source::Fixture::load(dir).unwrap_or_else(|e| {
+            eprintln!("tachi-fetch: cannot load fixture {}: {e}", dir.display());
+            std::process::exit(1);
+        });
+        let _ = source::set(Box::new(fixture));
+    } else if args.dump_probe_data.is_some() {
+        let _ = source::record();
+    }
+    let config = config::load(&args);
+
+    let format = args.format.unwrap_or(Format::Terminal);
+    if args.motd && config.motd.color == MotdColor::Basic {
+        color::override_depth(color::Depth::Ansi16);
+    } else if format != Format::Terminal {
+        color::override_depth(color::Depth::TrueColor);
+    }
+
+    if args.list_logos {
+        let mut out = std::io::stdout().lock();
+        // Stop quietly once the reader (e.g. `head`) goes away
+        let _ = art::names()
+            .into_iter()
+            .try_for_each(|name| writeln!(out, "{name}"));
+        return;
+    }
+    if let Some(name) = &args.logo_preview {
+        preview_logo(&config, name);
+        return;
+    }
+    if args.logo_only {
+        print_logo(&config, args.force_ansi);
+        return;
+    }
+
+    let shell_path = utils::get_env_var("SHELL", "/bin/sh").to_string();
+    #[cfg(feature = "prefetch")]
+    prefetch::start(&config, &shell_path);
+
+    // Probes run only for the values the output shows, so `--get` runs no
+    // others
+    let mut needs = Needs::for_output(&config, format);
+    // A server has no desktop to ask about
+    let headless = needs.graphical() && display::is_headless();
+    if headless {
+        needs = needs.headless();
+    }
+    let version_thread = needs
+        .has(Module::Shell)
+        .then(|| shell::start_version_detection(&shell_path));
+    let theme_thread = needs
+        .has(Module::Theme)
+        .then(|| theme::start_theme_detection(config.theme_backends.clone()));
+    let icon_thread = needs
+        .has(Module::Icons)
+        .then(|| theme::start_icon_detection(config.icon_backends.clone()));
+    let color_scheme_thread =
+        (config.theme_mode && needs.has(Module::Theme)).then(theme::start_color_scheme_detection);
+
+    LazyLock::force(&ENV_CACHE);
+
+    #[cfg(feature = "alloc-audit")]
+    let collect_section = alloc::section("collect");
+
+    let mut info = os::collect_system_info(&config, needs);
+    info.headless = headless;
+
+    if let Some(handle) = version_thread {
+        info.shell = shell::join_version_thread(handle, &shell_path);
+    }
+    if let Some(handle) = theme_thread {
+        info.theme = theme::join_theme_detection_thread(handle);
+    }
+    if let Some(handle) = icon_thread {
+        info.icons = theme::join_icon_detection_thread(handle);
+    }
+    info.color_scheme = color_scheme_thread.and_then(|handle| handle.join().ok().flatten());
+    info.sanitize();
+
+    #[cfg(feature = "alloc-audit")]
+    drop(collect_section);
+
+    // Find the appropriate logo
+    let mut logo = art::select(&config, os::os_release());
+
+    if let Some(banner) = &config.banner {
+        let text = banner.text.as_deref().unwrap_or(&info.hostname);
+        logo = art::with_banner(logo, banner, text);
+    }
+
+    let target = args.output.as_ref().unwrap_or(&output::Target::Stdout);
+    let mut out = output::Output::open(target).unwrap_or_else(|e| {
+        eprintln!("tachi-fetch: cannot open output: {e}");
+        std::process::exit(1);
+    });
+    let is_terminal = out.is_terminal() || args.force_ansi;
+
+    // Piped into another program, the info alone is easier to grep
+    let info_only = args.info_only
+        || !is_terminal
+            && format == Format::Terminal
+            && !args.motd
+            && !args.screenshot
+            && args.watch.is_none();
+    if info_only {
+        // The logo's colors still fill the colors line
+        logo.text = std::borrow::Cow::Borrowed("");
+        logo.width = 0;
+    }
+
+    #[cfg(feature = "image-export")]
+    if format == Format::Png && out.is_terminal() {
+        eprintln!("tachi-fetch: not writing a PNG to a terminal, use -o <file>");
+        std::process::exit(1);
+    }
+
+    let use_color = match format {
+        _ if args.motd => config.motd.color == MotdColor::Basic,
+        Format::Terminal => color::colors_enabled(is_terminal),
+        Format::Markdown | Format::Prometheus | Format::Env => false,
+        // The other exports all keep the colors
+        _ => true,
+    } && !config.accessible;
+    let logo_art = if use_color {
+        logo.text
+    } else {
+        std::borrow::Cow::Owned(strip_ansi(&logo.text))
+    };
+
+    let use_links = match config.hyperlinks {
+        Hyperlinks::Always => true,
+        Hyperlinks::Never => false,
+        // A browser shows links wherever the page came from
+        Hyperlinks::Auto => format == Format::Html || use_color && hyperlink::supported(),
+    };
+
+    // An accent color wins over the scheme's key and separator colors
+    let depth = color::depth();
+    let accent = config.accent_color.map(|c| c.sequence(depth));
+    let paint = |paint: scheme::Paint| accent.clone().or_else(|| paint.sequence(depth));
+    let (key_color, separator_color) = if use_color {
+        (paint(config.scheme.key), paint(config.scheme.separator))
+    } else {
+        (None, Some(String::new()))
+    };
+    let colors = layout::Colors {
+        key: key_color.as_deref(),
+        separator: separator_color.as_deref(),
+        blocks: config.scheme.blocks.as_deref().unwrap_or(&logo.colors),
+    };
+
+    let draw = |out: &mut dyn Write, lines: &[modules::Line]| {
+        if config.accessible {
+            layout::render_list(out, lines)
+        } else {
+            layout::render(
+                out,
+                &logo_art,
+                logo.width,
+                lines,
+                use_color,
+                &colors,
+                config.align_values,
+            )
+        }
+    };
+
+    let result = if let Some(interval) = args.watch {
+        watch::run(
+            &mut out, &config, info, use_links, use_color, interval, draw,
+        )
+    } else if args.serve_lines {
+        serve::run(&mut out, config, info)
+    } else if args.get.is_some() {
+        serve::print(&mut out, &config, &info)
+    } else if format == Format::Prometheus {
+        out.write_all(metrics::prometheus(&config, &info).as_bytes())
+    } else if format == Format::Env {
+        out.write_all(export::env(&config, &info).as_bytes())
+    } else if format != Format::Terminal {
+        let mut rendered = Vec::new();
+        draw(
+            &mut rendered,
+            &modules::build_lines(&config, &info, use_links, use_color),
+        )
+        .and_then(|()| {
+            let rendered = String::from_utf8_lossy(&rendered);
+            let exported = match format {
+                Format::Html => export::html(&rendered).into_bytes(),
+                #[cfg(feature = "image-export")]
+                Format::Svg => image::svg(&rendered, config.image.font_size).into_bytes(),
+                #[cfg(feature = "image-export")]
+                Format::Png => image::png(
+                    &rendered,
+                    config.image.font.as_deref(),
+                    config.image.font_size,
+                )?,
+                _ => export::markdown(&rendered).into_bytes(),
+            };
+            out.write_all(&exported)
+        })
+    } else if args.screenshot {
+        let mut rendered = Vec::new();
+        let size = out.terminal_size();
+        draw(
+            &mut rendered,
+            &modules::build_lines(&config, &info, use_links, use_color),
+        )
+        .and_then(|()| {
+            layout::frame(
+                &mut out,
+                &String::from_utf8_lossy(&rendered),
+                size,
+                &config.screenshot,
+            )
+        })
+    } else if args.motd {
+        let mut lines = modules::build_lines(&config, &info, use_links, use_color);
+        let info_columns = if config.accessible || info_only {
+            config.motd.width
+        } else {
+            config
+                .motd
+                .width
+                .saturating_sub(logo.width + layout::PADDING)
+        };
+        layout::fit(&mut lines, info_columns, config.align_values);
+        draw(&mut out, &lines)
+    } else {
+        draw(
+            &mut out,
+            &modules::build_lines(&config, &info, use_links, use_color),
+        )
+    };
+
+    // On a render error `out` is dropped unfinished, which discards a
+    // half-written file
+    if let Err(e) = result.and_then(|()| out.finish()) {
+        // A closed pipe just means the reader is done with us
+        if e.kind() != std::io::ErrorKind::BrokenPipe {
+            eprintln!("tachi-fetch: cannot write output: {e}");
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = &args.dump_probe_data {
+        if let Err(e) = source::save_recording(path) {
+            eprintln!("tachi-fetch: cannot save probe data: {e}");
+            std::process::exit(1);
+        }
+        eprintln!("tachi-fetch: probe data saved to {}", path.display());
+    }
+
+    #[cfg(feature = "alloc-audit")]
+    alloc::report();
+
+    let elapsed = start_time.elapsed();
+    eprintln!("Time elapsed: {elapsed:?}");
+}
+
+/// Draw the distro's logo alone, as it would be drawn next to the info
+fn print_logo(config: &config::Config, force_ansi: bool) {
+    let mut logo = art::select(config, os::os_release());
+    if let Some(banner) = &config.banner {
+        let hostname = banner.text.clone().unwrap_or_else(os::hostname);
+        logo = art::with_banner(logo, banner, &hostname);
+    }
+
+    let mut out = std::io::stdout().lock();
+    let use_color = color::colors_enabled(out.is_terminal() || force_ansi);
+    let logo_art = if use_color {
+        logo.text
+    } else {
+        std::borrow::Cow::Owned(strip_ansi(&logo.text))
+    };
+    let _ = layout::render(
+        &mut out,
+        &logo_art,
+        logo.width,
+        &[],
+        use_color,
+        &layout::Colors::PLAIN,
+        false,
+    );
+}
+
+/// Draw a built-in logo next to made-up info
+fn preview_logo(config: &config::Config, name: &str) {
+    let Some(logo) = art::named(config, name) else {
+        eprintln!("tachi-fetch: no logo named '{name}' (see --list-logos)");
+        std::process::exit(1);
+    };
+
+    let mut out = std::io::stdout().lock();
+    let use_color = color::colors_enabled(out.is_terminal());
+    let logo_art = if use_color {
+        logo.text
+    } else {
+        std::borrow::Cow::Owned(strip_ansi(&logo.text))
+    };
+
+    let lines = modules::sample_lines();
+    // Nothing useful to do if stdout went away
+    let _ = layout::render(
+        &mut out,
+        &logo_art,
+        logo.width,
+        &lines,
+        use_color,
+        &layout::Colors::PLAIN,
+        false,
+    );
+}
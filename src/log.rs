@@ -0,0 +1,35 @@
+//! Diagnostics on stderr
+//! `-v` reports why probes came up empty, `-vv` also every probe attempted, so
+//! an "Unknown" in the output can be traced back to its cause
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn enabled(level: u8) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level
+}
+
+/// Why a probe failed, shown from `-v` on
+macro_rules! fail {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled(1) {
+            eprintln!("tachi-fetch: {}", format_args!($($arg)*));
+        }
+    };
+}
+
+/// A probe being attempted, shown from `-vv` on
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled(2) {
+            eprintln!("tachi-fetch: {}", format_args!($($arg)*));
+        }
+    };
+}
+
+pub(crate) use {fail, trace};
@@ -1,9 +1,13 @@
 // Auto-generated code from build script
 
+use crate::color::Color;
+
 pub struct Logo {
     pub name: &'static str,
     pub is_wildcard: bool,
     pub ascii_art: &'static str,
+    pub raw_art: &'static str,
+    pub colors: &'static [Color],
     pub max_line_length: usize,
 }
 
@@ -12,1608 +16,2494 @@ pub static LOGOS: &[Logo] = &[
         name: "AIX",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m           `:+ssssossossss+-`\n        .oys///oyhddddhyo///sy+.\n      /yo:+hNNNNNNNNNNNNNNNNh+:oy/\n    :h/:yNNNNNNNNNNNNNNNNNNNNNNy-+h:\n  `ys.yNNNNNNNNNNNNNNNNNNNNNNNNNNy.ys\n `h+-mNNNNNNNNNNNNNNNNNNNNNNNNNNNNm-oh\n h+-NNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNN.oy\n/d`mNNNNNNN/::mNNNd::m+:/dNNNo::dNNNd`m:\nh//NNNNNNN: . .NNNh  mNo  od. -dNNNNN:+y\nN.sNNNNNN+ -N/ -NNh  mNNd.   sNNNNNNNo-m\nN.sNNNNNs  +oo  /Nh  mNNs` ` /mNNNNNNo-m\nh//NNNNh  ossss` +h  md- .hm/ `sNNNNN:+y\n:d`mNNN+/yNNNNNd//y//h//oNNNNy//sNNNd`m-\n yo-NNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNm.ss\n `h+-mNNNNNNNNNNNNNNNNNNNNNNNNNNNNm-oy\n   sy.yNNNNNNNNNNNNNNNNNNNNNNNNNNs.yo\n    :h+-yNNNNNNNNNNNNNNNNNNNNNNs-oh-\n      :ys:/yNNNNNNNNNNNNNNNmy/:sy:\n        .+ys///osyhhhhys+///sy+.\n            -/osssossossso/-\n\x1b[0m",
+        raw_art: "${c1}           `:+ssssossossss+-`\n        .oys///oyhddddhyo///sy+.\n      /yo:+hNNNNNNNNNNNNNNNNh+:oy/\n    :h/:yNNNNNNNNNNNNNNNNNNNNNNy-+h:\n  `ys.yNNNNNNNNNNNNNNNNNNNNNNNNNNy.ys\n `h+-mNNNNNNNNNNNNNNNNNNNNNNNNNNNNm-oh\n h+-NNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNN.oy\n/d`mNNNNNNN/::mNNNd::m+:/dNNNo::dNNNd`m:\nh//NNNNNNN: . .NNNh  mNo  od. -dNNNNN:+y\nN.sNNNNNN+ -N/ -NNh  mNNd.   sNNNNNNNo-m\nN.sNNNNNs  +oo  /Nh  mNNs` ` /mNNNNNNo-m\nh//NNNNh  ossss` +h  md- .hm/ `sNNNNN:+y\n:d`mNNN+/yNNNNNd//y//h//oNNNNy//sNNNd`m-\n yo-NNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNm.ss\n `h+-mNNNNNNNNNNNNNNNNNNNNNNNNNNNNm-oy\n   sy.yNNNNNNNNNNNNNNNNNNNNNNNNNNs.yo\n    :h+-yNNNNNNNNNNNNNNNNNNNNNNs-oh-\n      :ys:/yNNNNNNNNNNNNNNNmy/:sy:\n        .+ys///osyhhhhys+///sy+.\n            -/osssossossso/-\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "AOSC OS",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m             .:+syhhhhys+:.\n         .ohNMMMMMMMMMMMMMMNho.\n      `+mMMMMMMMMMMmdmNMMMMMMMMm+`\n     +NMMMMMMMMMMMM/   `./smMMMMMN+\n   .mMMMMMMMMMMMMMMo        -yMMMMMm.\n  :NMMMMMMMMMMMMMMMs          .hMMMMN:\n .NMMMMhmMMMMMMMMMMm+/-         oMMMMN.\n dMMMMs  ./ymMMMMMMMMMMNy.       sMMMMd\n-MMMMN`      oMMMMMMMMMMMN:      `NMMMM-\n/MMMMh       NMMMMMMMMMMMMm       hMMMM/\n/MMMMh       NMMMMMMMMMMMMm       hMMMM/\n-MMMMN`      :MMMMMMMMMMMMy.     `NMMMM-\n dMMMMs       .yNMMMMMMMMMMMNy/. sMMMMd\n .NMMMMo         -/+sMMMMMMMMMMMmMMMMN.\n  :NMMMMh.          .MMMMMMMMMMMMMMMN:\n   .mMMMMMy-         NMMMMMMMMMMMMMm.\n     +NMMMMMms/.`    mMMMMMMMMMMMN+\n      `+mMMMMMMMMNmddMMMMMMMMMMm+`\n         .ohNMMMMMMMMMMMMMMNho.\n             .:+syhhhhys+:.\n\x1b[0m",
+        raw_art: "${c2}             .:+syhhhhys+:.\n         .ohNMMMMMMMMMMMMMMNho.\n      `+mMMMMMMMMMMmdmNMMMMMMMMm+`\n     +NMMMMMMMMMMMM/   `./smMMMMMN+\n   .mMMMMMMMMMMMMMMo        -yMMMMMm.\n  :NMMMMMMMMMMMMMMMs          .hMMMMN:\n .NMMMMhmMMMMMMMMMMm+/-         oMMMMN.\n dMMMMs  ./ymMMMMMMMMMMNy.       sMMMMd\n-MMMMN`      oMMMMMMMMMMMN:      `NMMMM-\n/MMMMh       NMMMMMMMMMMMMm       hMMMM/\n/MMMMh       NMMMMMMMMMMMMm       hMMMM/\n-MMMMN`      :MMMMMMMMMMMMy.     `NMMMM-\n dMMMMs       .yNMMMMMMMMMMMNy/. sMMMMd\n .NMMMMo         -/+sMMMMMMMMMMMmMMMMN.\n  :NMMMMh.          .MMMMMMMMMMMMMMMN:\n   .mMMMMMy-         NMMMMMMMMMMMMMm.\n     +NMMMMMms/.`    mMMMMMMMMMMMN+\n      `+mMMMMMMMMNmddMMMMMMMMMMm+`\n         .ohNMMMMMMMMMMMMMMNho.\n             .:+syhhhhys+:.\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "AOSC OS/Retro",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m          .........\n     ...................\n   .....................\x1b[1;34m################\x1b[1;37m\n ..............     ....\x1b[1;34m################\x1b[1;37m\n..............       ...\x1b[1;34m################\x1b[1;37m\n.............         ..\x1b[1;34m****************\x1b[1;37m\n............     .     .\x1b[1;34m****************\x1b[1;37m\n...........     ...     \x1b[1;34m................\x1b[1;37m\n..........     .....     \x1b[1;34m...............\x1b[1;37m\n.........     .......     ...\n .\x1b[1;31m......                   \x1b[1;37m.\n  \x1b[1;31m.....      .....\x1b[1;37m....    \x1b[1;33m...........\n  \x1b[1;31m....      ......\x1b[1;37m.       \x1b[1;33m...........\n  \x1b[1;31m...      .......        \x1b[1;33m...........\n  \x1b[1;31m................        \x1b[1;33m***********\n  \x1b[1;31m................        \x1b[1;33m###########\n  \x1b[1;31m****************\n  \x1b[1;31m################\n\x1b[0m",
+        raw_art: "${c2}          .........\n     ...................\n   .....................${c1}################${c2}\n ..............     ....${c1}################${c2}\n..............       ...${c1}################${c2}\n.............         ..${c1}****************${c2}\n............     .     .${c1}****************${c2}\n...........     ...     ${c1}................${c2}\n..........     .....     ${c1}...............${c2}\n.........     .......     ...\n .${c3}......                   ${c2}.\n  ${c3}.....      .....${c2}....    ${c4}...........\n  ${c3}....      ......${c2}.       ${c4}...........\n  ${c3}...      .......        ${c4}...........\n  ${c3}................        ${c4}***********\n  ${c3}................        ${c4}###########\n  ${c3}****************\n  ${c3}################\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "ARCHlabs",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                     'c'\n                    'kKk,\n                   .dKKKx.\n                  .oKXKXKd.\n                 .l0XXXXKKo.\n                 c0KXXXXKX0l.\n                :0XKKOxxOKX0l.\n               :OXKOc. .c0XX0l.\n              :OK0o. \x1b[1;31m...\x1b[1;36m'dKKX0l.\n             :OX0c  \x1b[1;31m;xOx'\x1b[1;36m'dKXX0l.\n            :0KKo.\x1b[1;31m.o0XXKd'.\x1b[1;36mlKXX0l.\n           c0XKd.\x1b[1;31m.oKXXXXKd..\x1b[1;36moKKX0l.\n         .c0XKk;\x1b[1;31m.l0K0OO0XKd..\x1b[1;36moKXXKo.\n        .l0XXXk:\x1b[1;31m,dKx,.'l0XKo.\x1b[1;36m.kXXXKo.\n       .o0XXXX0d,\x1b[1;31m:x;   .oKKx'\x1b[1;36m.dXKXXKd.\n      .oKXXXXKK0c.\x1b[1;31m;.    :00c'\x1b[1;36mcOXXXXXKd.\n     .dKXXXXXXXXk,\x1b[1;31m.     cKx'\x1b[1;36m'xKXXXXXXKx'\n    'xKXXXXK0kdl:.     \x1b[1;31m.ok; \x1b[1;36m.cdk0KKXXXKx'\n   'xKK0koc,..         \x1b[1;31m'c, \x1b[1;36m    ..,cok0KKk,\n  ,xko:'.             \x1b[1;31m.. \x1b[1;36m           .':okx;\n .,'.                                   .',.\n\x1b[0m",
+        raw_art: "${c1}                     'c'\n                    'kKk,\n                   .dKKKx.\n                  .oKXKXKd.\n                 .l0XXXXKKo.\n                 c0KXXXXKX0l.\n                :0XKKOxxOKX0l.\n               :OXKOc. .c0XX0l.\n              :OK0o. ${c4}...${c1}'dKKX0l.\n             :OX0c  ${c4};xOx'${c1}'dKXX0l.\n            :0KKo.${c4}.o0XXKd'.${c1}lKXX0l.\n           c0XKd.${c4}.oKXXXXKd..${c1}oKKX0l.\n         .c0XKk;${c4}.l0K0OO0XKd..${c1}oKXXKo.\n        .l0XXXk:${c4},dKx,.'l0XKo.${c1}.kXXXKo.\n       .o0XXXX0d,${c4}:x;   .oKKx'${c1}.dXKXXKd.\n      .oKXXXXKK0c.${c4};.    :00c'${c1}cOXXXXXKd.\n     .dKXXXXXXXXk,${c4}.     cKx'${c1}'xKXXXXXXKx'\n    'xKXXXXK0kdl:.     ${c4}.ok; ${c1}.cdk0KKXXXKx'\n   'xKK0koc,..         ${c4}'c, ${c1}    ..,cok0KKk,\n  ,xko:'.             ${c4}.. ${c1}           .':okx;\n .,'.                                   .',.\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 44,
     },
     Logo {
         name: "AlmaLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m         'c:.\n\x1b[1;31m        lkkkx, ..       \x1b[1;33m..   ,cc,\n\x1b[1;31m        okkkk:ckkx'  \x1b[1;33m.lxkkx.okkkkd\n\x1b[1;31m        .:llcokkx'  \x1b[1;33m:kkkxkko:xkkd,\n\x1b[1;31m      .xkkkkdood:  \x1b[1;33m;kx,  .lkxlll;\n\x1b[1;31m       xkkx.       \x1b[1;33mxk'     xkkkkk:\n\x1b[1;31m       'xkx.       \x1b[1;33mxd      .....,.\n\x1b[1;34m      .. \x1b[1;31m:xkl'     \x1b[1;33m:c      ..''..\n\x1b[1;34m    .dkx'  \x1b[1;31m.:ldl:'. \x1b[1;33m'  \x1b[1;32m':lollldkkxo;\n\x1b[1;34m  .''lkkko'                     \x1b[1;32mckkkx.\n\x1b[1;34m'xkkkd:kkd.       ..  \x1b[1;36m;'        \x1b[1;32m:kkxo.\n\x1b[1;34m,xkkkd;kk'      ,d;    \x1b[1;36mld.   \x1b[1;32m':dkd::cc,\n\x1b[1;34m .,,.;xkko'.';lxo.      \x1b[1;36mdx,  \x1b[1;32m:kkk'xkkkkc\n\x1b[1;34m     'dkkkkkxo:.        \x1b[1;36m;kx  \x1b[1;32m.kkk:;xkkd.\n\x1b[1;34m       .....   \x1b[1;36m.;dk:.   \x1b[1;36mlkk.  \x1b[1;32m:;,\n             \x1b[1;36m:kkkkkkkdoxkkx\n              ,c,,;;;:xkkd.\n                ;kkkkl...\n                ;kkkkl\n                 ,od;\n\x1b[0m",
+        raw_art: "${c1}         'c:.\n${c1}        lkkkx, ..       ${c2}..   ,cc,\n${c1}        okkkk:ckkx'  ${c2}.lxkkx.okkkkd\n${c1}        .:llcokkx'  ${c2}:kkkxkko:xkkd,\n${c1}      .xkkkkdood:  ${c2};kx,  .lkxlll;\n${c1}       xkkx.       ${c2}xk'     xkkkkk:\n${c1}       'xkx.       ${c2}xd      .....,.\n${c3}      .. ${c1}:xkl'     ${c2}:c      ..''..\n${c3}    .dkx'  ${c1}.:ldl:'. ${c2}'  ${c4}':lollldkkxo;\n${c3}  .''lkkko'                     ${c4}ckkkx.\n${c3}'xkkkd:kkd.       ..  ${c5};'        ${c4}:kkxo.\n${c3},xkkkd;kk'      ,d;    ${c5}ld.   ${c4}':dkd::cc,\n${c3} .,,.;xkko'.';lxo.      ${c5}dx,  ${c4}:kkk'xkkkkc\n${c3}     'dkkkkkxo:.        ${c5};kx  ${c4}.kkk:;xkkd.\n${c3}       .....   ${c5}.;dk:.   ${c5}lkk.  ${c4}:;,\n             ${c5}:kkkkkkkdoxkkx\n              ,c,,;;;:xkkd.\n                ;kkkkl...\n                ;kkkkl\n                 ,od;\n",
+        colors: &[Color::Palette(1), Color::Palette(3), Color::Palette(4), Color::Palette(2), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
         name: "Alpine",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m       .hddddddddddddddddddddddh.\n      :dddddddddddddddddddddddddd:\n     /dddddddddddddddddddddddddddd/\n    +dddddddddddddddddddddddddddddd+\n  `sdddddddddddddddddddddddddddddddds`\n `ydddddddddddd++hdddddddddddddddddddy`\n.hddddddddddd+`  `+ddddh:-sdddddddddddh.\nhdddddddddd+`      `+y:    .sddddddddddh\nddddddddh+`   `//`   `.`     -sddddddddd\nddddddh+`   `/hddh/`   `:s-    -sddddddd\nddddh+`   `/+/dddddh/`   `+s-    -sddddd\nddd+`   `/o` :dddddddh/`   `oy-    .yddd\nhdddyo+ohddyosdddddddddho+oydddy++ohdddh\n.hddddddddddddddddddddddddddddddddddddh.\n `yddddddddddddddddddddddddddddddddddy`\n  `sdddddddddddddddddddddddddddddddds`\n    +dddddddddddddddddddddddddddddd+\n     /dddddddddddddddddddddddddddd/\n      :dddddddddddddddddddddddddd:\n       .hddddddddddddddddddddddh.\n\x1b[0m",
+        raw_art: "${c1}       .hddddddddddddddddddddddh.\n      :dddddddddddddddddddddddddd:\n     /dddddddddddddddddddddddddddd/\n    +dddddddddddddddddddddddddddddd+\n  `sdddddddddddddddddddddddddddddddds`\n `ydddddddddddd++hdddddddddddddddddddy`\n.hddddddddddd+`  `+ddddh:-sdddddddddddh.\nhdddddddddd+`      `+y:    .sddddddddddh\nddddddddh+`   `//`   `.`     -sddddddddd\nddddddh+`   `/hddh/`   `:s-    -sddddddd\nddddh+`   `/+/dddddh/`   `+s-    -sddddd\nddd+`   `/o` :dddddddh/`   `oy-    .yddd\nhdddyo+ohddyosdddddddddho+oydddy++ohdddh\n.hddddddddddddddddddddddddddddddddddddh.\n `yddddddddddddddddddddddddddddddddddy`\n  `sdddddddddddddddddddddddddddddddds`\n    +dddddddddddddddddddddddddddddd+\n     /dddddddddddddddddddddddddddd/\n      :dddddddddddddddddddddddddd:\n       .hddddddddddddddddddddddh.\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
         name: "Alter",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                      %,\n                    ^WWWw\n                   'wwwwww\n                  !wwwwwwww\n                 #`wwwwwwwww\n                @wwwwwwwwwwww\n               wwwwwwwwwwwwwww\n              wwwwwwwwwwwwwwwww\n             wwwwwwwwwwwwwwwwwww\n            wwwwwwwwwwwwwwwwwwww,\n           w~1i.wwwwwwwwwwwwwwwww,\n         3~:~1lli.wwwwwwwwwwwwwwww.\n        :~~:~?ttttzwwwwwwwwwwwwwwww\n       #<~:~~~~?llllltO-.wwwwwwwwwww\n      #~:~~:~:~~?ltlltlttO-.wwwwwwwww\n     @~:~~:~:~:~~(zttlltltlOda.wwwwwww\n    @~:~~: ~:~~:~:(zltlltlO    a,wwwwww\n   8~~:~~:~~~~:~~~~_1ltltu          ,www\n  5~~:~~:~~:~~:~~:~~~_1ltq             N,,\n g~:~~:~~~:~~:~~:~:~~~~1q                N,\n\x1b[0m",
+        raw_art: "${c1}                      %,\n                    ^WWWw\n                   'wwwwww\n                  !wwwwwwww\n                 #`wwwwwwwww\n                @wwwwwwwwwwww\n               wwwwwwwwwwwwwww\n              wwwwwwwwwwwwwwwww\n             wwwwwwwwwwwwwwwwwww\n            wwwwwwwwwwwwwwwwwwww,\n           w~1i.wwwwwwwwwwwwwwwww,\n         3~:~1lli.wwwwwwwwwwwwwwww.\n        :~~:~?ttttzwwwwwwwwwwwwwwww\n       #<~:~~~~?llllltO-.wwwwwwwwwww\n      #~:~~:~:~~?ltlltlttO-.wwwwwwwww\n     @~:~~:~:~:~~(zttlltltlOda.wwwwwww\n    @~:~~: ~:~~:~:(zltlltlO    a,wwwwww\n   8~~:~~:~~~~:~~~~_1ltltu          ,www\n  5~~:~~:~~:~~:~~:~~~_1ltq             N,,\n g~:~~:~~~:~~:~~:~:~~~~1q                N,\n",
+        colors: &[Color::Palette(6), Color::Palette(6)],
         max_line_length: 43,
     },
     Logo {
         name: "Amazon",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m             `-/oydNNdyo:.`\n      `.:+shmMMMMMMMMMMMMMMmhs+:.`\n    -+hNNMMMMMMMMMMMMMMMMMMMMMMNNho-\n.``      -/+shmNNMMMMMMNNmhs+/-      ``.\ndNmhs+:.       `.:/oo/:.`       .:+shmNd\ndMMMMMMMNdhs+:..        ..:+shdNMMMMMMMd\ndMMMMMMMMMMMMMMNds    odNMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\n.:+ydNMMMMMMMMMMMh    yMMMMMMMMMMMNdy+:.\n     `.:+shNMMMMMh    yMMMMMNhs+:``\n            `-+shy    shs+:`\n\x1b[0m",
+        raw_art: "${c1}             `-/oydNNdyo:.`\n      `.:+shmMMMMMMMMMMMMMMmhs+:.`\n    -+hNNMMMMMMMMMMMMMMMMMMMMMMNNho-\n.``      -/+shmNNMMMMMMNNmhs+/-      ``.\ndNmhs+:.       `.:/oo/:.`       .:+shmNd\ndMMMMMMMNdhs+:..        ..:+shdNMMMMMMMd\ndMMMMMMMMMMMMMMNds    odNMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\ndMMMMMMMMMMMMMMMMh    yMMMMMMMMMMMMMMMMd\n.:+ydNMMMMMMMMMMMh    yMMMMMMMMMMMNdy+:.\n     `.:+shNMMMMMh    yMMMMMNhs+:``\n            `-+shy    shs+:`\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Anarchy",
         is_wildcard: true,
         ascii_art: "                         \x1b[1;34m..\x1b[1;37m\n                        \x1b[1;34m..\x1b[1;37m\n                      \x1b[1;34m:..\x1b[1;37m\n                    \x1b[1;34m:+++.\x1b[1;37m\n              .:::++\x1b[1;34m++++\x1b[1;37m+::.\n          .:+######\x1b[1;34m++++\x1b[1;37m######+:.\n       .+#########\x1b[1;34m+++++\x1b[1;37m##########:.\n     .+##########\x1b[1;34m+++++++\x1b[1;37m##\x1b[1;34m+\x1b[1;37m#########+.\n    +###########\x1b[1;34m+++++++++\x1b[1;37m############:\n   +##########\x1b[1;34m++++++\x1b[1;37m#\x1b[1;34m++++\x1b[1;37m#\x1b[1;34m+\x1b[1;37m###########+\n  +###########\x1b[1;34m+++++\x1b[1;37m###\x1b[1;34m++++\x1b[1;37m#\x1b[1;34m+\x1b[1;37m###########+\n :##########\x1b[1;34m+\x1b[1;37m#\x1b[1;34m++++\x1b[1;37m####\x1b[1;34m++++\x1b[1;37m#\x1b[1;34m+\x1b[1;37m############:\n ###########\x1b[1;34m+++++\x1b[1;37m#####\x1b[1;34m+++++\x1b[1;37m#\x1b[1;34m+\x1b[1;37m###\x1b[1;34m++\x1b[1;37m######+\n.##########\x1b[1;34m++++++\x1b[1;37m#####\x1b[1;34m++++++++++++\x1b[1;37m#######.\n.##########\x1b[1;34m+++++++++++++++++++\x1b[1;37m###########.\n #####\x1b[1;34m++++++++++++++\x1b[1;37m###\x1b[1;34m++++++++\x1b[1;37m#########+\n :###\x1b[1;34m++++++++++\x1b[1;37m#########\x1b[1;34m+++++++\x1b[1;37m#########:\n  +######\x1b[1;34m+++++\x1b[1;37m##########\x1b[1;34m++++++++\x1b[1;37m#######+\n   +####\x1b[1;34m+++++\x1b[1;37m###########\x1b[1;34m+++++++++\x1b[1;37m#####+\n    :##\x1b[1;34m++++++\x1b[1;37m############\x1b[1;34m++++++++++\x1b[1;37m##:\n     .\x1b[1;34m++++++\x1b[1;37m#############\x1b[1;34m++++++++++\x1b[1;37m+.\n      :\x1b[1;34m++++\x1b[1;37m###############\x1b[1;34m+++++++\x1b[1;37m::\n     .\x1b[1;34m++. .:+\x1b[1;37m##############\x1b[1;34m+++++++\x1b[1;37m..\n     \x1b[1;34m.:.\x1b[1;37m      ..::++++++::..:\x1b[1;34m++++\x1b[1;37m+.\n     \x1b[1;34m.\x1b[1;37m                       \x1b[1;34m.:+++\x1b[1;37m.\n                                \x1b[1;34m.:\x1b[1;37m:\n                                   \x1b[1;34m..\x1b[1;37m\n                                    \x1b[1;34m..\x1b[1;37m\n\x1b[0m",
+        raw_art: "                         ${c2}..${c1}\n                        ${c2}..${c1}\n                      ${c2}:..${c1}\n                    ${c2}:+++.${c1}\n              .:::++${c2}++++${c1}+::.\n          .:+######${c2}++++${c1}######+:.\n       .+#########${c2}+++++${c1}##########:.\n     .+##########${c2}+++++++${c1}##${c2}+${c1}#########+.\n    +###########${c2}+++++++++${c1}############:\n   +##########${c2}++++++${c1}#${c2}++++${c1}#${c2}+${c1}###########+\n  +###########${c2}+++++${c1}###${c2}++++${c1}#${c2}+${c1}###########+\n :##########${c2}+${c1}#${c2}++++${c1}####${c2}++++${c1}#${c2}+${c1}############:\n ###########${c2}+++++${c1}#####${c2}+++++${c1}#${c2}+${c1}###${c2}++${c1}######+\n.##########${c2}++++++${c1}#####${c2}++++++++++++${c1}#######.\n.##########${c2}+++++++++++++++++++${c1}###########.\n #####${c2}++++++++++++++${c1}###${c2}++++++++${c1}#########+\n :###${c2}++++++++++${c1}#########${c2}+++++++${c1}#########:\n  +######${c2}+++++${c1}##########${c2}++++++++${c1}#######+\n   +####${c2}+++++${c1}###########${c2}+++++++++${c1}#####+\n    :##${c2}++++++${c1}############${c2}++++++++++${c1}##:\n     .${c2}++++++${c1}#############${c2}++++++++++${c1}+.\n      :${c2}++++${c1}###############${c2}+++++++${c1}::\n     .${c2}++. .:+${c1}##############${c2}+++++++${c1}..\n     ${c2}.:.${c1}      ..::++++++::..:${c2}++++${c1}+.\n     ${c2}.${c1}                       ${c2}.:+++${c1}.\n                                ${c2}.:${c1}:\n                                   ${c2}..${c1}\n                                    ${c2}..${c1}\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 42,
     },
     Logo {
         name: "Android",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m         -o          o-\n          +hydNNNNdyh+\n        +mMMMMMMMMMMMMm+\n      `dMM\x1b[1;37mm:\x1b[1;32mNMMMMMMN\x1b[1;37m:m\x1b[1;32mMMd`\n      hMMMMMMMMMMMMMMMMMMh\n  ..  yyyyyyyyyyyyyyyyyyyy  ..\n.mMMm`MMMMMMMMMMMMMMMMMMMM`mMMm.\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n-MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM-\n +yy+ MMMMMMMMMMMMMMMMMMMM +yy+\n      mMMMMMMMMMMMMMMMMMMm\n      `/++MMMMh++hMMMM++/`\n          MMMMo  oMMMM\n          MMMMo  oMMMM\n          oNMm-  -mMNs\n\x1b[0m",
+        raw_art: "${c1}         -o          o-\n          +hydNNNNdyh+\n        +mMMMMMMMMMMMMm+\n      `dMM${c2}m:${c1}NMMMMMMN${c2}:m${c1}MMd`\n      hMMMMMMMMMMMMMMMMMMh\n  ..  yyyyyyyyyyyyyyyyyyyy  ..\n.mMMm`MMMMMMMMMMMMMMMMMMMM`mMMm.\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:\n-MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM-\n +yy+ MMMMMMMMMMMMMMMMMMMM +yy+\n      mMMMMMMMMMMMMMMMMMMm\n      `/++MMMMh++hMMMM++/`\n          MMMMo  oMMMM\n          MMMMo  oMMMM\n          oNMm-  -mMNs\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 32,
     },
     Logo {
         name: "Antergos",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m              `.-/::/-``\n            .-/osssssssso/.\n           :osyysssssssyyys+-\n        `.+yyyysssssssssyyyyy+.\n       `/syyyyyssssssssssyyyyys-`\n      `/yhyyyyysss\x1b[1;34m++\x1b[1;36mssosyyyyhhy/`\n     .ohhhyyyys\x1b[1;34mo++/+o\x1b[1;36mso\x1b[1;34m+\x1b[1;36msyy\x1b[1;34m+\x1b[1;36mshhhho.\n    .shhhhys\x1b[1;34moo++//+\x1b[1;36msss\x1b[1;34m+++\x1b[1;36myyy\x1b[1;34m+s\x1b[1;36mhhhhs.\n   -yhhhhs\x1b[1;34m+++++++o\x1b[1;36mssso\x1b[1;34m+++\x1b[1;36myyy\x1b[1;34ms+o\x1b[1;36mhhddy:\n  -yddhhy\x1b[1;34mo+++++o\x1b[1;36msyyss\x1b[1;34m++++\x1b[1;36myyy\x1b[1;34myooy\x1b[1;36mhdddy-\n .yddddhs\x1b[1;34mo++o\x1b[1;36msyyyyys\x1b[1;34m+++++\x1b[1;36myyhh\x1b[1;34msos\x1b[1;36mhddddy`\n`odddddhyosyhyyyyyy\x1b[1;34m++++++\x1b[1;36myhhhyosddddddo\n.dmdddddhhhhhhhyyyo\x1b[1;34m+++++\x1b[1;36mshhhhhohddddmmh.\nddmmdddddhhhhhhhso\x1b[1;34m++++++\x1b[1;36myhhhhhhdddddmmdy\ndmmmdddddddhhhyso\x1b[1;34m++++++\x1b[1;36mshhhhhddddddmmmmh\n-dmmmdddddddhhys\x1b[1;34mo++++o\x1b[1;36mshhhhdddddddmmmmd-\n.smmmmddddddddhhhhhhhhhdddddddddmmmms.\n   `+ydmmmdddddddddddddddddddmmmmdy/.\n      `.:+ooyyddddddddddddyyso+:.`\n\x1b[0m",
+        raw_art: "${c2}              `.-/::/-``\n            .-/osssssssso/.\n           :osyysssssssyyys+-\n        `.+yyyysssssssssyyyyy+.\n       `/syyyyyssssssssssyyyyys-`\n      `/yhyyyyysss${c1}++${c2}ssosyyyyhhy/`\n     .ohhhyyyys${c1}o++/+o${c2}so${c1}+${c2}syy${c1}+${c2}shhhho.\n    .shhhhys${c1}oo++//+${c2}sss${c1}+++${c2}yyy${c1}+s${c2}hhhhs.\n   -yhhhhs${c1}+++++++o${c2}ssso${c1}+++${c2}yyy${c1}s+o${c2}hhddy:\n  -yddhhy${c1}o+++++o${c2}syyss${c1}++++${c2}yyy${c1}yooy${c2}hdddy-\n .yddddhs${c1}o++o${c2}syyyyys${c1}+++++${c2}yyhh${c1}sos${c2}hddddy`\n`odddddhyosyhyyyyyy${c1}++++++${c2}yhhhyosddddddo\n.dmdddddhhhhhhhyyyo${c1}+++++${c2}shhhhhohddddmmh.\nddmmdddddhhhhhhhso${c1}++++++${c2}yhhhhhhdddddmmdy\ndmmmdddddddhhhyso${c1}++++++${c2}shhhhhddddddmmmmh\n-dmmmdddddddhhys${c1}o++++o${c2}shhhhdddddddmmmmd-\n.smmmmddddddddhhhhhhhhhdddddddddmmmms.\n   `+ydmmmdddddddddddddddddddmmmmdy/.\n      `.:+ooyyddddddddddddyyso+:.`\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
         name: "Aperio GNU/Linux",
         is_wildcard: true,
         ascii_art: "${c2}\n _.._  _ ._.. _\n(_][_)(/,[  |(_)\n   |   GNU/Linux\n\x1b[0m",
+        raw_art: "${c2}\n _.._  _ ._.. _\n(_][_)(/,[  |(_)\n   |   GNU/Linux\n",
+        colors: &[Color::Palette(255)],
         max_line_length: 16,
     },
     Logo {
         name: "Apricity",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                                    ./o-\n          ``...``              `:. -/:\n     `-+ymNMMMMMNmho-`      :sdNNm/\n   `+dMMMMMMMMMMMMMMMmo` sh:.:::-\n  /mMMMMMMMMMMMMMMMMMMMm/`sNd/\n oMMMMMMMMMMMMMMMMMMMMMMMs -`\n:MMMMMMMMMMMMMMMMMMMMMMMMM/\nNMMMMMMMMMMMMMMMMMMMMMMMMMd\nMMMMMMMmdmMMMMMMMMMMMMMMMMd\nMMMMMMy` .mMMMMMMMMMMMmho:`\nMMMMMMNo/sMMMMMMMNdy+-.`-/\nMMMMMMMMMMMMNdy+:.`.:ohmm:\nMMMMMMMmhs+-.`.:+ymNMMMy.\nMMMMMM/`.-/ohmNMMMMMMy-\nMMMMMMNmNNMMMMMMMMmo.\nMMMMMMMMMMMMMMMms:`\nMMMMMMMMMMNds/.\ndhhyys+/-`\n\x1b[0m",
+        raw_art: "${c2}                                    ./o-\n          ``...``              `:. -/:\n     `-+ymNMMMMMNmho-`      :sdNNm/\n   `+dMMMMMMMMMMMMMMMmo` sh:.:::-\n  /mMMMMMMMMMMMMMMMMMMMm/`sNd/\n oMMMMMMMMMMMMMMMMMMMMMMMs -`\n:MMMMMMMMMMMMMMMMMMMMMMMMM/\nNMMMMMMMMMMMMMMMMMMMMMMMMMd\nMMMMMMMmdmMMMMMMMMMMMMMMMMd\nMMMMMMy` .mMMMMMMMMMMMmho:`\nMMMMMMNo/sMMMMMMMNdy+-.`-/\nMMMMMMMMMMMMNdy+:.`.:ohmm:\nMMMMMMMmhs+-.`.:+ymNMMMy.\nMMMMMM/`.-/ohmNMMMMMMy-\nMMMMMMNmNNMMMMMMMMmo.\nMMMMMMMMMMMMMMMms:`\nMMMMMMMMMMNds/.\ndhhyys+/-`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Arch",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                   -`\n                  .o+`\n                 `ooo/\n                `+oooo:\n               `+oooooo:\n               -+oooooo+:\n             `/:-:++oooo+:\n            `/++++/+++++++:\n           `/++++++++++++++:\n          `/+++o\x1b[1;36moooooooo\x1b[1;36moooo/`\n\x1b[1;36m         \x1b[1;36m./\x1b[1;36mooosssso++osssssso\x1b[1;36m+`\n\x1b[1;36m        .oossssso-````/ossssss+`\n       -osssssso.      :ssssssso.\n      :osssssss/        osssso+++.\n     /ossssssss/        +ssssooo/-\n   `/ossssso+/:-        -:/+osssso+-\n  `+sso+:-`                 `.-/+oso:\n `++:.                           `-/+/\n .`                                 `/\n\x1b[0m",
+        raw_art: "${c1}                   -`\n                  .o+`\n                 `ooo/\n                `+oooo:\n               `+oooooo:\n               -+oooooo+:\n             `/:-:++oooo+:\n            `/++++/+++++++:\n           `/++++++++++++++:\n          `/+++o${c2}oooooooo${c1}oooo/`\n${c2}         ${c1}./${c2}ooosssso++osssssso${c1}+`\n${c2}        .oossssso-````/ossssss+`\n       -osssssso.      :ssssssso.\n      :osssssss/        osssso+++.\n     /ossssssss/        +ssssooo/-\n   `/ossssso+/:-        -:/+osssso+-\n  `+sso+:-`                 `.-/+oso:\n `++:.                           `-/+/\n .`                                 `/\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 38,
     },
     Logo {
         name: "ArchBox",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m              ...:+oh/:::..\n         ..-/oshhhhhh`   `::::-.\n     .:/ohhhhhhhhhhhh`        `-::::.\n .+shhhhhhhhhhhhhhhhh`             `.::-.\n /`-:+shhhhhhhhhhhhhh`            .-/+shh\n /      .:/ohhhhhhhhh`       .:/ohhhhhhhh\n /           `-:+shhh`  ..:+shhhhhhhhhhhh\n /                 .:ohhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /      .+o+        `hhhhhhhhhhhhhhhhhhhh\n /     -hhhhh       `hhhhhhhhhhhhhhhhhhhh\n /     ohhhhho      `hhhhhhhhhhhhhhhhhhhh\n /:::+`hhhhoos`     `hhhhhhhhhhhhhhhhhs+`\n    `--/:`   /:     `hhhhhhhhhhhho/-\n             -/:.   `hhhhhhs+:-`\n                ::::/ho/-`\n\x1b[0m",
+        raw_art: "${c1}              ...:+oh/:::..\n         ..-/oshhhhhh`   `::::-.\n     .:/ohhhhhhhhhhhh`        `-::::.\n .+shhhhhhhhhhhhhhhhh`             `.::-.\n /`-:+shhhhhhhhhhhhhh`            .-/+shh\n /      .:/ohhhhhhhhh`       .:/ohhhhhhhh\n /           `-:+shhh`  ..:+shhhhhhhhhhhh\n /                 .:ohhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /                  `hhhhhhhhhhhhhhhhhhhh\n /      .+o+        `hhhhhhhhhhhhhhhhhhhh\n /     -hhhhh       `hhhhhhhhhhhhhhhhhhhh\n /     ohhhhho      `hhhhhhhhhhhhhhhhhhhh\n /:::+`hhhhoos`     `hhhhhhhhhhhhhhhhhs+`\n    `--/:`   /:     `hhhhhhhhhhhho/-\n             -/:.   `hhhhhhs+:-`\n                ::::/ho/-`\n",
+        colors: &[Color::Palette(2), Color::Palette(7), Color::Palette(1)],
         max_line_length: 41,
     },
     Logo {
         name: "ArchMerge",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                    y:\n                  sMN-\n                 +MMMm`\n                /MMMMMd`\n               :NMMMMMMy\n              -NMMMMMMMMs\n             .NMMMMMMMMMM+\n            .mMMMMMMMMMMMM+\n            oNMMMMMMMMMMMMM+\n          `+:-+NMMMMMMMMMMMM+\n          .sNMNhNMMMMMMMMMMMM/\n        `hho/sNMMMMMMMMMMMMMMM/\n       `.`omMMmMMMMMMMMMMMMMMMM+\n      .mMNdshMMMMd+::oNMMMMMMMMMo\n     .mMMMMMMMMM+     `yMMMMMMMMMs\n    .NMMMMMMMMM/        yMMMMMMMMMy\n   -NMMMMMMMMMh         `mNMMMMMMMMd`\n  /NMMMNds+:.`             `-/oymMMMm.\n +Mmy/.                          `:smN:\n/+.                                  -o.\n\x1b[0m",
+        raw_art: "${c1}                    y:\n                  sMN-\n                 +MMMm`\n                /MMMMMd`\n               :NMMMMMMy\n              -NMMMMMMMMs\n             .NMMMMMMMMMM+\n            .mMMMMMMMMMMMM+\n            oNMMMMMMMMMMMMM+\n          `+:-+NMMMMMMMMMMMM+\n          .sNMNhNMMMMMMMMMMMM/\n        `hho/sNMMMMMMMMMMMMMMM/\n       `.`omMMmMMMMMMMMMMMMMMMM+\n      .mMNdshMMMMd+::oNMMMMMMMMMo\n     .mMMMMMMMMM+     `yMMMMMMMMMs\n    .NMMMMMMMMM/        yMMMMMMMMMy\n   -NMMMMMMMMMh         `mNMMMMMMMMd`\n  /NMMMNds+:.`             `-/oymMMMm.\n +Mmy/.                          `:smN:\n/+.                                  -o.\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "ArchStrike",
         is_wildcard: true,
-        ascii_art: "\x1b[1;38;5;8m \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} *\u{00c2}\u{00a0} \u{00c2}\u{00a0}\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} **.\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}****\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} ******\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} *******\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} ** *******\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}**** *******\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \x1b[1;38;5;8m****\x1b[1;36m_____\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m*\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}***\x1b[1;36m/\x1b[1;38;5;8m*******\x1b[1;36m//\x1b[1;38;5;8m***\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} **\x1b[1;36m/\x1b[1;38;5;8m********\x1b[1;36m///\x1b[1;38;5;8m*\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}**\x1b[1;36m/\x1b[1;38;5;8m*******\x1b[1;36m////\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} **\x1b[1;36m/\x1b[1;38;5;8m****\x1b[1;36m//////.,\x1b[1;38;5;8m****\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}***\x1b[1;36m/\x1b[1;38;5;8m*****\x1b[1;36m/////////\x1b[1;38;5;8m**\x1b[1;36m/\x1b[1;38;5;8m***\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0} ****\x1b[1;36m/\x1b[1;38;5;8m**** \u{00c2}\u{00a0} \u{00c2}\u{00a0}\x1b[1;36m/////\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m****\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} \u{00c2}\u{00a0}******\x1b[1;36m/\x1b[1;38;5;8m***  \x1b[1;36m//// \u{00c2}\u{00a0} \x1b[1;38;5;8m**\x1b[1;36m/\x1b[1;38;5;8m******\n\u{00c2}\u{00a0} \u{00c2}\u{00a0} ********\x1b[1;36m/\x1b[1;38;5;8m* \x1b[1;36m/// \u{00c2}\u{00a0} \u{00c2}\u{00a0}\u{00c2}\u{00a0} \x1b[1;38;5;8m*\x1b[1;36m/\x1b[1;38;5;8m********\n\u{00c2}\u{00a0} ,****** \u{00c2}\u{00a0} \u{00c2}\u{00a0} \x1b[1;36m// ______ / \u{00c2}\u{00a0} \u{00c2}\u{00a0}\x1b[1;38;5;8m******,\n\x1b[0m",
+        ascii_art: "\x1b[1;38;5;8m \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} *\u{00a0} \u{00a0}\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} **.\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}****\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} ******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} ** *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}**** *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \x1b[1;38;5;8m****\x1b[1;36m_____\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m*\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}***\x1b[1;36m/\x1b[1;38;5;8m*******\x1b[1;36m//\x1b[1;38;5;8m***\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} **\x1b[1;36m/\x1b[1;38;5;8m********\x1b[1;36m///\x1b[1;38;5;8m*\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}**\x1b[1;36m/\x1b[1;38;5;8m*******\x1b[1;36m////\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} **\x1b[1;36m/\x1b[1;38;5;8m****\x1b[1;36m//////.,\x1b[1;38;5;8m****\x1b[1;36m/\x1b[1;38;5;8m**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0}***\x1b[1;36m/\x1b[1;38;5;8m*****\x1b[1;36m/////////\x1b[1;38;5;8m**\x1b[1;36m/\x1b[1;38;5;8m***\n\u{00a0} \u{00a0} \u{00a0} ****\x1b[1;36m/\x1b[1;38;5;8m**** \u{00a0} \u{00a0}\x1b[1;36m/////\x1b[1;38;5;8m***\x1b[1;36m/\x1b[1;38;5;8m****\n\u{00a0} \u{00a0} \u{00a0}******\x1b[1;36m/\x1b[1;38;5;8m***  \x1b[1;36m//// \u{00a0} \x1b[1;38;5;8m**\x1b[1;36m/\x1b[1;38;5;8m******\n\u{00a0} \u{00a0} ********\x1b[1;36m/\x1b[1;38;5;8m* \x1b[1;36m/// \u{00a0} \u{00a0}\u{00a0} \x1b[1;38;5;8m*\x1b[1;36m/\x1b[1;38;5;8m********\n\u{00a0} ,****** \u{00a0} \u{00a0} \x1b[1;36m// ______ / \u{00a0} \u{00a0}\x1b[1;38;5;8m******,\n\x1b[0m",
+        raw_art: "${c1} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} *\u{00a0} \u{00a0}\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} **.\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}****\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} ******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} ** *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}**** *******\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} ${c1}****${c2}_____${c1}***${c2}/${c1}*\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}***${c2}/${c1}*******${c2}//${c1}***\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0} **${c2}/${c1}********${c2}///${c1}*${c2}/${c1}**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} \u{00a0}**${c2}/${c1}*******${c2}////${c1}***${c2}/${c1}**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0} **${c2}/${c1}****${c2}//////.,${c1}****${c2}/${c1}**\n\u{00a0} \u{00a0} \u{00a0} \u{00a0}***${c2}/${c1}*****${c2}/////////${c1}**${c2}/${c1}***\n\u{00a0} \u{00a0} \u{00a0} ****${c2}/${c1}**** \u{00a0} \u{00a0}${c2}/////${c1}***${c2}/${c1}****\n\u{00a0} \u{00a0} \u{00a0}******${c2}/${c1}***  ${c2}//// \u{00a0} ${c1}**${c2}/${c1}******\n\u{00a0} \u{00a0} ********${c2}/${c1}* ${c2}/// \u{00a0} \u{00a0}\u{00a0} ${c1}*${c2}/${c1}********\n\u{00a0} ,****** \u{00a0} \u{00a0} ${c2}// ______ / \u{00a0} \u{00a0}${c1}******,\n",
+        colors: &[Color::Palette(8), Color::Palette(6)],
         max_line_length: 36,
     },
     Logo {
         name: "Archcraft",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                   -m:\n                  :NMM+      .+\n                 +MMMMMo    -NMy\n                sMMMMMMMy  -MMMMh`\n               yMMMMMMMMMd` oMMMMd`\n             `dMMMMMMMMMMMm. /MMMMm-\n            .mMMMMMm-dMMMMMN- :NMMMN:\n           -NMMMMMd`  yMMMMMN: .mMMMM/\n          :NMMMMMy     sMMMMMM+ `dMMMMo\n         +MMMMMMs       +MMMMMMs `hMMMMy\n        oMMMMMMMds-      :NMMMMMy  sMMMMh`\n       yMMMMMNoydMMmo`    -NMMMMMd` +MMMMd.\n     `dMMMMMN-   `:yNNs`   .mMMMMMm. /MMMMm-\n    .mMMMMMm.        :hN/   `dMMMMMN- -NMMMN:\n   -NMMMMMd`           -hh`  `yMMMMMN: .mMMMM/\n  :NMMMMMy         `s`   :h.   oMMMMMM+ `-----\n +MMMMMMo         .dMm.   `o.   +MMMMMMo\nsMMMMMM+         .mMMMN:    :`   :NMMMMMy\n\x1b[0m",
+        raw_art: "${c1}                   -m:\n                  :NMM+      .+\n                 +MMMMMo    -NMy\n                sMMMMMMMy  -MMMMh`\n               yMMMMMMMMMd` oMMMMd`\n             `dMMMMMMMMMMMm. /MMMMm-\n            .mMMMMMm-dMMMMMN- :NMMMN:\n           -NMMMMMd`  yMMMMMN: .mMMMM/\n          :NMMMMMy     sMMMMMM+ `dMMMMo\n         +MMMMMMs       +MMMMMMs `hMMMMy\n        oMMMMMMMds-      :NMMMMMy  sMMMMh`\n       yMMMMMNoydMMmo`    -NMMMMMd` +MMMMd.\n     `dMMMMMN-   `:yNNs`   .mMMMMMm. /MMMMm-\n    .mMMMMMm.        :hN/   `dMMMMMN- -NMMMN:\n   -NMMMMMd`           -hh`  `yMMMMMN: .mMMMM/\n  :NMMMMMy         `s`   :h.   oMMMMMM+ `-----\n +MMMMMMo         .dMm.   `o.   +MMMMMMo\nsMMMMMM+         .mMMMN:    :`   :NMMMMMy\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 46,
     },
     Logo {
         name: "ArcoLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                    /-\n                   ooo:\n                  yoooo/\n                 yooooooo\n                yooooooooo\n               yooooooooooo\n             .yooooooooooooo\n            .oooooooooooooooo\n           .oooooooarcoooooooo\n          .ooooooooo-oooooooooo\n         .ooooooooo-  oooooooooo\n        :ooooooooo.    :ooooooooo\n       :ooooooooo.      :ooooooooo\n      :oooarcooo         .oooarcooo\n     :ooooooooy           .ooooooooo\n    :ooooooooo   \x1b[1;37m/ooooooooooooooooooo\x1b[1;34m\n   :ooooooooo      \x1b[1;37m.-ooooooooooooooooo.\x1b[1;34m\n  ooooooooo-             \x1b[1;37m-ooooooooooooo.\x1b[1;34m\n ooooooooo-                 \x1b[1;37m.-oooooooooo.\x1b[1;34m\nooooooooo.                     \x1b[1;37m-ooooooooo\x1b[1;34m\n\x1b[0m",
+        raw_art: "${c2}                    /-\n                   ooo:\n                  yoooo/\n                 yooooooo\n                yooooooooo\n               yooooooooooo\n             .yooooooooooooo\n            .oooooooooooooooo\n           .oooooooarcoooooooo\n          .ooooooooo-oooooooooo\n         .ooooooooo-  oooooooooo\n        :ooooooooo.    :ooooooooo\n       :ooooooooo.      :ooooooooo\n      :oooarcooo         .oooarcooo\n     :ooooooooy           .ooooooooo\n    :ooooooooo   ${c1}/ooooooooooooooooooo${c2}\n   :ooooooooo      ${c1}.-ooooooooooooooooo.${c2}\n  ooooooooo-             ${c1}-ooooooooooooo.${c2}\n ooooooooo-                 ${c1}.-oooooooooo.${c2}\nooooooooo.                     ${c1}-ooooooooo${c2}\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 41,
     },
     Logo {
         name: "Artix",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                   '\n                  'o'\n                 'ooo'\n                'ooxoo'\n               'ooxxxoo'\n              'oookkxxoo'\n             'oiioxkkxxoo'\n            ':;:iiiioxxxoo'\n               `'.;::ioxxoo'\n          '-.      `':;jiooo'\n         'oooio-..     `'i:io'\n        'ooooxxxxoio:,.   `'-;'\n       'ooooxxxxxkkxoooIi:-.  `'\n      'ooooxxxxxkkkkxoiiiiiji'\n     'ooooxxxxxkxxoiiii:'`     .i'\n    'ooooxxxxxoi:::'`       .;ioxo'\n   'ooooxooi::'`         .:iiixkxxo'\n  'ooooi:'`                `'';ioxxo'\n 'i:'`                          '':io'\n'`                                   `'\n\x1b[0m",
+        raw_art: "${c1}                   '\n                  'o'\n                 'ooo'\n                'ooxoo'\n               'ooxxxoo'\n              'oookkxxoo'\n             'oiioxkkxxoo'\n            ':;:iiiioxxxoo'\n               `'.;::ioxxoo'\n          '-.      `':;jiooo'\n         'oooio-..     `'i:io'\n        'ooooxxxxoio:,.   `'-;'\n       'ooooxxxxxkkxoooIi:-.  `'\n      'ooooxxxxxkkkkxoiiiiiji'\n     'ooooxxxxxkxxoiiii:'`     .i'\n    'ooooxxxxxoi:::'`       .;ioxo'\n   'ooooxooi::'`         .:iiixkxxo'\n  'ooooi:'`                `'';ioxxo'\n 'i:'`                          '':io'\n'`                                   `'\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 39,
     },
     Logo {
         name: "Arya",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                `oyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m               -syyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m              .syyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m              :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m           `/ :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m          .+s :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m         .oys :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m        -oyys :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m       :syyys :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m      /syyyys :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m     +yyyyyys :yyyyyy/\x1b[1;31m-yyyyyy+\n\x1b[1;32m   .oyyyyyyo. :yyyyyy/\x1b[1;31m-yyyyyy+ ---------\n\x1b[1;32m  .syyyyyy+`  :yyyyyy/\x1b[1;31m-yyyyy+-+syyyyyyyy\n\x1b[1;32m -syyyyyy/    :yyyyyy/\x1b[1;31m-yyys:.syyyyyyyyyy\n\x1b[1;32m:syyyyyy/     :yyyyyy/\x1b[1;31m-yyo.:syyyyyyyyyyy\n\x1b[0m",
+        raw_art: "${c1}                `oyyy/${c2}-yyyyyy+\n${c1}               -syyyy/${c2}-yyyyyy+\n${c1}              .syyyyy/${c2}-yyyyyy+\n${c1}              :yyyyyy/${c2}-yyyyyy+\n${c1}           `/ :yyyyyy/${c2}-yyyyyy+\n${c1}          .+s :yyyyyy/${c2}-yyyyyy+\n${c1}         .oys :yyyyyy/${c2}-yyyyyy+\n${c1}        -oyys :yyyyyy/${c2}-yyyyyy+\n${c1}       :syyys :yyyyyy/${c2}-yyyyyy+\n${c1}      /syyyys :yyyyyy/${c2}-yyyyyy+\n${c1}     +yyyyyys :yyyyyy/${c2}-yyyyyy+\n${c1}   .oyyyyyyo. :yyyyyy/${c2}-yyyyyy+ ---------\n${c1}  .syyyyyy+`  :yyyyyy/${c2}-yyyyy+-+syyyyyyyy\n${c1} -syyyyyy/    :yyyyyy/${c2}-yyys:.syyyyyyyyyy\n${c1}:syyyyyy/     :yyyyyy/${c2}-yyo.:syyyyyyyyyyy\n",
+        colors: &[Color::Palette(2), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "AsteroidOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;160m                    ***\n\x1b[1;38;5;160m                   *****\n\x1b[1;38;5;160m                **********\n\x1b[1;38;5;160m              ***************\n\x1b[1;38;5;160m           *///****////****////.\n\x1b[1;38;5;208m         (/////// /////// ///////(\n\x1b[1;38;5;208m      /(((((//*     //,     //((((((.\n\x1b[1;38;5;208m    (((((((((((     (((        ((((((((\n\x1b[1;38;5;208m *(((((((((((((((((((((((        ((((((((\n\x1b[1;38;5;202m    (((((#(((((((#(((((        ((#(((((\n\x1b[1;38;5;202m     (#(#(#####(#(#,       ####(#(#\n\x1b[1;38;5;202m         #########        ########\n\x1b[1;38;5;202m           /########   ########\n\x1b[1;38;5;214m              #######%#######\n\x1b[1;38;5;214m                (#%%%%%%%#\n\x1b[1;38;5;214m                   %%%%%\n\x1b[1;38;5;214m                    %%%\n\x1b[0m",
+        raw_art: "${c1}                    ***\n${c1}                   *****\n${c1}                **********\n${c1}              ***************\n${c1}           *///****////****////.\n${c2}         (/////// /////// ///////(\n${c2}      /(((((//*     //,     //((((((.\n${c2}    (((((((((((     (((        ((((((((\n${c2} *(((((((((((((((((((((((        ((((((((\n${c3}    (((((#(((((((#(((((        ((#(((((\n${c3}     (#(#(#####(#(#,       ####(#(#\n${c3}         #########        ########\n${c3}           /########   ########\n${c4}              #######%#######\n${c4}                (#%%%%%%%#\n${c4}                   %%%%%\n${c4}                    %%%\n",
+        colors: &[Color::Palette(160), Color::Palette(208), Color::Palette(202), Color::Palette(214)],
         max_line_length: 41,
     },
     Logo {
         name: "Ataraxia",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo \x1b[1;35mlooooooc\n\x1b[1;34mlooooooooo loo   cloooooool    \x1b[1;35mlooooc\n\x1b[1;34m cooo       cooooooooooo       \x1b[1;35mlooolooooool\n\x1b[1;34m            cooo:     \x1b[1;35mcoooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n\x1b[0m",
+        raw_art: "${c1}               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo ${c2}looooooc\n${c1}looooooooo loo   cloooooool    ${c2}looooc\n${c1} cooo       cooooooooooo       ${c2}looolooooool\n${c1}            cooo:     ${c2}coooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(6), Color::Palette(2)],
+        max_line_length: 52,
+    },
+    Logo {
+        name: "Ataraxia Linux",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo \x1b[1;35mlooooooc\n\x1b[1;34mlooooooooo loo   cloooooool    \x1b[1;35mlooooc\n\x1b[1;34m cooo       cooooooooooo       \x1b[1;35mlooolooooool\n\x1b[1;34m            cooo:     \x1b[1;35mcoooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n\x1b[0m",
+        raw_art: "${c1}               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo ${c2}looooooc\n${c1}looooooooo loo   cloooooool    ${c2}looooc\n${c1} cooo       cooooooooooo       ${c2}looolooooool\n${c1}            cooo:     ${c2}coooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(6), Color::Palette(2)],
         max_line_length: 52,
     },
     Logo {
         name: "BLAG",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m             d\n            ,MK:\n            xMMMX:\n           .NMMMMMX;\n           lMMMMMMMM0clodkO0KXWW:\n           KMMMMMMMMMMMMMMMMMMX'\n      .;d0NMMMMMMMMMMMMMMMMMMK.\n .;dONMMMMMMMMMMMMMMMMMMMMMMx\n'dKMMMMMMMMMMMMMMMMMMMMMMMMl\n   .:xKWMMMMMMMMMMMMMMMMMMM0.\n       .:xNMMMMMMMMMMMMMMMMMK.\n          lMMMMMMMMMMMMMMMMMMK.\n          ,MMMMMMMMWkOXWMMMMMM0\n          .NMMMMMNd.     `':ldko\n           OMMMK:\n           oWk,\n           ;:\n\x1b[0m",
+        raw_art: "${c1}             d\n            ,MK:\n            xMMMX:\n           .NMMMMMX;\n           lMMMMMMMM0clodkO0KXWW:\n           KMMMMMMMMMMMMMMMMMMX'\n      .;d0NMMMMMMMMMMMMMMMMMMK.\n .;dONMMMMMMMMMMMMMMMMMMMMMMx\n'dKMMMMMMMMMMMMMMMMMMMMMMMMl\n   .:xKWMMMMMMMMMMMMMMMMMMM0.\n       .:xNMMMMMMMMMMMMMMMMMK.\n          lMMMMMMMMMMMMMMMMMMK.\n          ,MMMMMMMMWkOXWMMMMMM0\n          .NMMMMMNd.     `':ldko\n           OMMMK:\n           oWk,\n           ;:\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 33,
     },
     Logo {
         name: "BSD",
         is_wildcard: false,
         ascii_art: "\x1b[1;31m             ,        ,\n            /(        )`\n            \\ \\___   / |\n            /- _  `-/  '\n           (\x1b[1;37m/\\/ \\ \x1b[1;31m\\   /\\\n           \x1b[1;37m/ /   | `    \x1b[1;31m\\\n           \x1b[1;34mO O   \x1b[1;37m) \x1b[1;31m/    |\n           \x1b[1;37m`-^--'\x1b[1;31m`<     '\n          (_.)  _  )   /\n           `.___/`    /\n             `-----' /\n\x1b[1;33m<----.     __ / __   \\\n\x1b[1;33m<----|====\x1b[1;31mO)))\x1b[1;33m==\x1b[1;31m) \\) /\x1b[1;33m====|\n<----'    \x1b[1;31m`--' `.__,' \\\n             |        |\n              \\       /       /\\\n         \x1b[1;36m______\x1b[1;31m( (_  / \\______/\n       \x1b[1;36m,'  ,-----'   |\n       `--{__________)\n\x1b[0m",
-        max_line_length: 32,
-    },
-    Logo {
-        name: "BSD",
-        is_wildcard: false,
-        ascii_art: "\x1b[1;31m             ,        ,\n            /(        )`\n            \\ \\___   / |\n            /- _  `-/  '\n           (\x1b[1;37m/\\/ \\ \x1b[1;31m\\   /\\\n           \x1b[1;37m/ /   | `    \x1b[1;31m\\\n           \x1b[1;34mO O   \x1b[1;37m) \x1b[1;31m/    |\n           \x1b[1;37m`-^--'\x1b[1;31m`<     '\n          (_.)  _  )   /\n           `.___/`    /\n             `-----' /\n\x1b[1;33m<----.     __ / __   \\\n\x1b[1;33m<----|====\x1b[1;31mO)))\x1b[1;33m==\x1b[1;31m) \\) /\x1b[1;33m====|\n<----'    \x1b[1;31m`--' `.__,' \\\n             |        |\n              \\       /       /\\\n         \x1b[1;36m______\x1b[1;31m( (_  / \\______/\n       \x1b[1;36m,'  ,-----'   |\n       `--{__________)\n\x1b[0m",
+        raw_art: "${c1}             ,        ,\n            /(        )`\n            \\ \\___   / |\n            /- _  `-/  '\n           (${c2}/\\/ \\ ${c1}\\   /\\\n           ${c2}/ /   | `    ${c1}\\\n           ${c3}O O   ${c2}) ${c1}/    |\n           ${c2}`-^--'${c1}`<     '\n          (_.)  _  )   /\n           `.___/`    /\n             `-----' /\n${c4}<----.     __ / __   \\\n${c4}<----|====${c1}O)))${c4}==${c1}) \\) /${c4}====|\n<----'    ${c1}`--' `.__,' \\\n             |        |\n              \\       /       /\\\n         ${c5}______${c1}( (_  / \\______/\n       ${c5},'  ,-----'   |\n       `--{__________)\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(4), Color::Palette(3), Color::Palette(6)],
         max_line_length: 32,
     },
     Logo {
         name: "Bedrock",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m--------------------------------------\n--------------------------------------\n--------------------------------------\n---\x1b[1;37m\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\x1b[1;38;5;8m-----------------------\n----\x1b[1;37m\\\\\\\\\\\\      \\\\\\\\\\\\\x1b[1;38;5;8m----------------------\n-----\x1b[1;37m\\\\\\\\\\\\      \\\\\\\\\\\\\x1b[1;38;5;8m---------------------\n------\x1b[1;37m\\\\\\\\\\\\      \\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\x1b[1;38;5;8m------\n-------\x1b[1;37m\\\\\\\\\\\\                    \\\\\\\\\\\\\x1b[1;38;5;8m-----\n--------\x1b[1;37m\\\\\\\\\\\\                    \\\\\\\\\\\\\x1b[1;38;5;8m----\n---------\x1b[1;37m\\\\\\\\\\\\        ______      \\\\\\\\\\\\\x1b[1;38;5;8m---\n----------\x1b[1;37m\\\\\\\\\\\\                   ///\x1b[1;38;5;8m---\n-----------\x1b[1;37m\\\\\\\\\\\\                 ///\x1b[1;38;5;8m----\n------------\x1b[1;37m\\\\\\\\\\\\               ///\x1b[1;38;5;8m-----\n-------------\x1b[1;37m\\\\\\\\\\\\////////////////\x1b[1;38;5;8m------\n--------------------------------------\n--------------------------------------\n--------------------------------------\n\x1b[0m",
+        raw_art: "${c1}--------------------------------------\n--------------------------------------\n--------------------------------------\n---${c2}\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\${c1}-----------------------\n----${c2}\\\\\\\\\\\\      \\\\\\\\\\\\${c1}----------------------\n-----${c2}\\\\\\\\\\\\      \\\\\\\\\\\\${c1}---------------------\n------${c2}\\\\\\\\\\\\      \\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\\${c1}------\n-------${c2}\\\\\\\\\\\\                    \\\\\\\\\\\\${c1}-----\n--------${c2}\\\\\\\\\\\\                    \\\\\\\\\\\\${c1}----\n---------${c2}\\\\\\\\\\\\        ______      \\\\\\\\\\\\${c1}---\n----------${c2}\\\\\\\\\\\\                   ///${c1}---\n-----------${c2}\\\\\\\\\\\\                 ///${c1}----\n------------${c2}\\\\\\\\\\\\               ///${c1}-----\n-------------${c2}\\\\\\\\\\\\////////////////${c1}------\n--------------------------------------\n--------------------------------------\n--------------------------------------\n",
+        colors: &[Color::Palette(8), Color::Palette(7)],
         max_line_length: 58,
     },
     Logo {
         name: "Bitrig",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m   `hMMMMN+\n   -MMo-dMd`\n   oMN- oMN`\n   yMd  /NM:\n  .mMmyyhMMs\n  :NMMMhsmMh\n  +MNhNNoyMm-\n  hMd.-hMNMN:\n  mMmsssmMMMo\n .MMdyyhNMMMd\n oMN.`/dMddMN`\n yMm/hNm+./MM/\n.dMMMmo.``.NMo\n:NMMMNmmmmmMMh\n/MN/-------oNN:\nhMd.       .dMh\nsm/         /ms\n\x1b[0m",
+        raw_art: "${c1}   `hMMMMN+\n   -MMo-dMd`\n   oMN- oMN`\n   yMd  /NM:\n  .mMmyyhMMs\n  :NMMMhsmMh\n  +MNhNNoyMm-\n  hMd.-hMNMN:\n  mMmsssmMMMo\n .MMdyyhNMMMd\n oMN.`/dMddMN`\n yMm/hNm+./MM/\n.dMMMmo.``.NMo\n:NMMMNmmmmmMMh\n/MN/-------oNN:\nhMd.       .dMh\nsm/         /ms\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 15,
     },
     Logo {
         name: "BlackArch",
         is_wildcard: true,
         ascii_art: "${c3}                   00\n                   11\n                  ====\x1b[1;31m\n                  .${c3}//\x1b[1;31m\n                 `o${c3}//\x1b[1;31m:\n                `+o${c3}//\x1b[1;31mo:\n               `+oo${c3}//\x1b[1;31moo:\n               -+oo${c3}//\x1b[1;31moo+:\n             `/:-:+${c3}//\x1b[1;31mooo+:\n            `/+++++${c3}//\x1b[1;31m+++++:\n           `/++++++${c3}//\x1b[1;31m++++++:\n          `/+++o\x1b[1;31mooo${c3}//\x1b[1;31mooo\x1b[1;31moooo/`\n\x1b[1;31m         \x1b[1;31m./\x1b[1;31mooosssso${c3}//\x1b[1;31mosssssso\x1b[1;31m+`\n\x1b[1;31m        .oossssso-`${c3}//\x1b[1;31m`/ossssss+`\n       -osssssso.  ${c3}//\x1b[1;31m  :ssssssso.\n      :osssssss/   ${c3}//\x1b[1;31m   osssso+++.\n     /ossssssss/   ${c3}//\x1b[1;31m   +ssssooo/-\n   `/ossssso+/:-   ${c3}//\x1b[1;31m   -:/+osssso+-\n  `+sso+:-`        ${c3}//\x1b[1;31m       `.-/+oso:\n `++:.             ${c3}//\x1b[1;31m            `-/+/\n .`                ${c3}/\x1b[1;31m                `/\n\x1b[0m",
+        raw_art: "${c3}                   00\n                   11\n                  ====${c1}\n                  .${c3}//${c1}\n                 `o${c3}//${c1}:\n                `+o${c3}//${c1}o:\n               `+oo${c3}//${c1}oo:\n               -+oo${c3}//${c1}oo+:\n             `/:-:+${c3}//${c1}ooo+:\n            `/+++++${c3}//${c1}+++++:\n           `/++++++${c3}//${c1}++++++:\n          `/+++o${c2}ooo${c3}//${c2}ooo${c1}oooo/`\n${c2}         ${c1}./${c2}ooosssso${c3}//${c2}osssssso${c1}+`\n${c2}        .oossssso-`${c3}//${c1}`/ossssss+`\n       -osssssso.  ${c3}//${c1}  :ssssssso.\n      :osssssss/   ${c3}//${c1}   osssso+++.\n     /ossssssss/   ${c3}//${c1}   +ssssooo/-\n   `/ossssso+/:-   ${c3}//${c1}   -:/+osssso+-\n  `+sso+:-`        ${c3}//${c1}       `.-/+oso:\n `++:.             ${c3}//${c1}            `-/+/\n .`                ${c3}/${c1}                `/\n",
+        colors: &[Color::Palette(1), Color::Palette(1), Color::Palette(0), Color::Palette(1)],
         max_line_length: 38,
     },
     Logo {
         name: "BlankOn",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m        `./ohdNMMMMNmho+.` \x1b[1;31m       .+oo:`\n\x1b[1;37m      -smMMMMMMMMMMMMMMMMmy-`    \x1b[1;31m`yyyyy+\n\x1b[1;37m   `:dMMMMMMMMMMMMMMMMMMMMMMd/`  \x1b[1;31m`yyyyys\n\x1b[1;37m  .hMMMMMMMNmhso/++symNMMMMMMMh- \x1b[1;31m`yyyyys\n\x1b[1;37m -mMMMMMMms-`         -omMMMMMMN-\x1b[1;31m.yyyyys\n\x1b[1;37m.mMMMMMMy.              .yMMMMMMm:\x1b[1;31myyyyys\n\x1b[1;37msMMMMMMy                 `sMMMMMMh\x1b[1;31myyyyys\n\x1b[1;37mNMMMMMN:                  .NMMMMMN\x1b[1;31myyyyys\n\x1b[1;37mMMMMMMm.                   NMMMMMN\x1b[1;31myyyyys\n\x1b[1;37mhMMMMMM+                  /MMMMMMN\x1b[1;31myyyyys\n\x1b[1;37m:NMMMMMN:                :mMMMMMM+\x1b[1;31myyyyys\n\x1b[1;37m oMMMMMMNs-            .sNMMMMMMs.\x1b[1;31myyyyys\n\x1b[1;37m  +MMMMMMMNho:.`  `.:ohNMMMMMMNo \x1b[1;31m`yyyyys\n\x1b[1;37m   -hMMMMMMMMNNNmmNNNMMMMMMMMh-  \x1b[1;31m`yyyyys\n\x1b[1;37m     :yNMMMMMMMMMMMMMMMMMMNy:`   \x1b[1;31m`yyyyys\n\x1b[1;37m       .:sdNMMMMMMMMMMNds/.      \x1b[1;31m`yyyyyo\n\x1b[1;37m           `.:/++++/:.`           \x1b[1;31m:oys+.\n\x1b[0m",
+        raw_art: "${c2}        `./ohdNMMMMNmho+.` ${c1}       .+oo:`\n${c2}      -smMMMMMMMMMMMMMMMMmy-`    ${c1}`yyyyy+\n${c2}   `:dMMMMMMMMMMMMMMMMMMMMMMd/`  ${c1}`yyyyys\n${c2}  .hMMMMMMMNmhso/++symNMMMMMMMh- ${c1}`yyyyys\n${c2} -mMMMMMMms-`         -omMMMMMMN-${c1}.yyyyys\n${c2}.mMMMMMMy.              .yMMMMMMm:${c1}yyyyys\n${c2}sMMMMMMy                 `sMMMMMMh${c1}yyyyys\n${c2}NMMMMMN:                  .NMMMMMN${c1}yyyyys\n${c2}MMMMMMm.                   NMMMMMN${c1}yyyyys\n${c2}hMMMMMM+                  /MMMMMMN${c1}yyyyys\n${c2}:NMMMMMN:                :mMMMMMM+${c1}yyyyys\n${c2} oMMMMMMNs-            .sNMMMMMMs.${c1}yyyyys\n${c2}  +MMMMMMMNho:.`  `.:ohNMMMMMMNo ${c1}`yyyyys\n${c2}   -hMMMMMMMMNNNmmNNNMMMMMMMMh-  ${c1}`yyyyys\n${c2}     :yNMMMMMMMMMMMMMMMMMMNy:`   ${c1}`yyyyys\n${c2}       .:sdNMMMMMMMMMMNds/.      ${c1}`yyyyyo\n${c2}           `.:/++++/:.`           ${c1}:oys+.\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "BlueLight",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m              oMMNMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              -+++++++++++++++++++++++mM\x1b[1;34m\n             ```````````````````````..\x1b[1;37mdM\x1b[1;34m\n           ```````````````````````....\x1b[1;37mdM\x1b[1;34m\n         ```````````````````````......\x1b[1;37mdM\x1b[1;34m\n       ```````````````````````........\x1b[1;37mdM\x1b[1;34m\n     ```````````````````````..........\x1b[1;37mdM\x1b[1;34m\n   ```````````````````````............\x1b[1;37mdM\x1b[1;34m\n.::::::::::::::::::::::-..............\x1b[1;37mdM\x1b[1;34m\n `-+yyyyyyyyyyyyyyyyyyyo............\x1b[1;37m+mMM\x1b[1;34m\n     -+yyyyyyyyyyyyyyyyo..........\x1b[1;37m+mMMMM\x1b[1;34m\n        ./syyyyyyyyyyyyo........\x1b[1;37m+mMMMMMM\x1b[1;34m\n           ./oyyyyyyyyyo......\x1b[1;37m+mMMMMMMMM\x1b[1;34m\n              omdyyyyyyo....\x1b[1;37m+mMMMMMMMMMM\x1b[1;34m\n              \x1b[1;37moMMM\x1b[1;34mmdhyyo..\x1b[1;37m+mMMMMMMMMMMMM\n              oNNNNNNm\x1b[1;34mdso\x1b[1;37mmMMMMMMMMMMMMMM\n\x1b[0m",
+        raw_art: "${c1}              oMMNMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              oMMMMMMMMMMMMMMMMMMMMMMMMM\n              -+++++++++++++++++++++++mM${c2}\n             ```````````````````````..${c1}dM${c2}\n           ```````````````````````....${c1}dM${c2}\n         ```````````````````````......${c1}dM${c2}\n       ```````````````````````........${c1}dM${c2}\n     ```````````````````````..........${c1}dM${c2}\n   ```````````````````````............${c1}dM${c2}\n.::::::::::::::::::::::-..............${c1}dM${c2}\n `-+yyyyyyyyyyyyyyyyyyyo............${c1}+mMM${c2}\n     -+yyyyyyyyyyyyyyyyo..........${c1}+mMMMM${c2}\n        ./syyyyyyyyyyyyo........${c1}+mMMMMMM${c2}\n           ./oyyyyyyyyyo......${c1}+mMMMMMMMM${c2}\n              omdyyyyyyo....${c1}+mMMMMMMMMMM${c2}\n              ${c1}oMMM${c2}mdhyyo..${c1}+mMMMMMMMMMMMM\n              oNNNNNNm${c2}dso${c1}mMMMMMMMMMMMMMM\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "Bodhi",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m|           \x1b[1;38;5;11m,,mmKKKKKKKKWm,,\n \x1b[1;37m'      \x1b[1;38;5;11m,aKKP\x1b[1;37mLL**********|L*\x1b[1;38;5;11mTKp,\n   \x1b[1;37mt  \x1b[1;38;5;11maKP\x1b[1;37mL**```          ```**L\x1b[1;38;5;11m*Kp\n    IX\x1b[1;37mEL\x1b[1;32mL,wwww,              \x1b[1;37m``*||\x1b[1;38;5;11mKp\n  ,#P\x1b[1;37mL|\x1b[1;32mKKKpPP@IPPTKmw,          \x1b[1;37m`*||\x1b[1;38;5;11mK\n ,K\x1b[1;37mLL*\x1b[1;32m{KKKKKKPPb$KPhpKKPKp        \x1b[1;37m`||\x1b[1;38;5;11mK\n #\x1b[1;37mPL  \x1b[1;32m!KKKKKKPhKPPP$KKEhKKKKp      \x1b[1;37m`||\x1b[1;38;5;11mK\n!H\x1b[1;37mL*   \x1b[1;32m1KKKKKKKphKbPKKKKKK$KKp      \x1b[1;37m`|I\x1b[1;38;5;11mW\n$\x1b[1;37mbL     \x1b[1;32mKKKKKKKKBQKhKbKKKKKKKK       \x1b[1;37m|I\x1b[1;38;5;11mN\n$\x1b[1;37mbL     \x1b[1;32m!KKKKKKKKKKNKKKKKKKPP`       \x1b[1;37m|I\x1b[1;38;5;11mb\nTH\x1b[1;37mL*     \x1b[1;32mTKKKKKK##KKKN@KKKK^         \x1b[1;37m|I\x1b[1;38;5;11mM\n K@\x1b[1;37mL      \x1b[1;32m*KKKKKKKKKKKEKE5          \x1b[1;37m||\x1b[1;38;5;11mK\n `NL\x1b[1;37mL      \x1b[1;32m`KKKKKKKKKK\"```|L       \x1b[1;37m||\x1b[1;38;5;11m#P\n  `K@\x1b[1;37mLL       \x1b[1;32m`\"**\"`        \x1b[1;37m'.   :||\x1b[1;38;5;11m#P\n    Yp\x1b[1;37mLL                      \x1b[1;37m' |L\x1b[1;38;5;11m$M`\n     `Tp\x1b[1;37mpLL,                ,|||\x1b[1;38;5;11mp'L\n        \"Kpp\x1b[1;37mLL++,.,    ,,|||$\x1b[1;38;5;11m#K*   \x1b[1;37m'.\n           \x1b[1;38;5;11m`\"MKWpppppppp#KM\"`        \x1b[1;37m`h,\n\x1b[0m",
+        raw_art: "${c1}|           ${c2},,mmKKKKKKKKWm,,\n ${c1}'      ${c2},aKKP${c1}LL**********|L*${c2}TKp,\n   ${c1}t  ${c2}aKP${c1}L**```          ```**L${c2}*Kp\n    IX${c1}EL${c3}L,wwww,              ${c1}``*||${c2}Kp\n  ,#P${c1}L|${c3}KKKpPP@IPPTKmw,          ${c1}`*||${c2}K\n ,K${c1}LL*${c3}{KKKKKKPPb$KPhpKKPKp        ${c1}`||${c2}K\n #${c1}PL  ${c3}!KKKKKKPhKPPP$KKEhKKKKp      ${c1}`||${c2}K\n!H${c1}L*   ${c3}1KKKKKKKphKbPKKKKKK$KKp      ${c1}`|I${c2}W\n$${c1}bL     ${c3}KKKKKKKKBQKhKbKKKKKKKK       ${c1}|I${c2}N\n$${c1}bL     ${c3}!KKKKKKKKKKNKKKKKKKPP`       ${c1}|I${c2}b\nTH${c1}L*     ${c3}TKKKKKK##KKKN@KKKK^         ${c1}|I${c2}M\n K@${c1}L      ${c3}*KKKKKKKKKKKEKE5          ${c1}||${c2}K\n `NL${c1}L      ${c3}`KKKKKKKKKK\"```|L       ${c1}||${c2}#P\n  `K@${c1}LL       ${c3}`\"**\"`        ${c1}'.   :||${c2}#P\n    Yp${c1}LL                      ${c1}' |L${c2}$M`\n     `Tp${c1}pLL,                ,|||${c2}p'L\n        \"Kpp${c1}LL++,.,    ,,|||$${c2}#K*   ${c1}'.\n           ${c2}`\"MKWpppppppp#KM\"`        ${c1}`h,\n",
+        colors: &[Color::Palette(7), Color::Palette(11), Color::Palette(2)],
         max_line_length: 40,
     },
     Logo {
         name: "BunsenLabs",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m        `++\n      -yMMs\n    `yMMMMN`\n   -NMMMMMMm.\n  :MMMMMMMMMN-\n .NMMMMMMMMMMM/\n yMMMMMMMMMMMMM/\n`MMMMMMNMMMMMMMN.\n-MMMMN+ /mMMMMMMy\n-MMMm`   `dMMMMMM\n`MMN.     .NMMMMM.\n hMy       yMMMMM`\n -Mo       +MMMMN\n  /o       +MMMMs\n           +MMMN`\n           hMMM:\n          `NMM/\n          +MN:\n          mh.\n         -/\n\x1b[0m",
+        raw_art: "${c1}        `++\n      -yMMs\n    `yMMMMN`\n   -NMMMMMMm.\n  :MMMMMMMMMN-\n .NMMMMMMMMMMM/\n yMMMMMMMMMMMMM/\n`MMMMMMNMMMMMMMN.\n-MMMMN+ /mMMMMMMy\n-MMMm`   `dMMMMMM\n`MMN.     .NMMMMM.\n hMy       yMMMMM`\n -Mo       +MMMMN\n  /o       +MMMMs\n           +MMMN`\n           hMMM:\n          `NMM/\n          +MN:\n          mh.\n         -/\n",
+        colors: &[Color::Palette(7), Color::Palette(7)],
         max_line_length: 18,
     },
     Logo {
         name: "CBL-Mariner",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                    .\n                  :-  .\n                :==. .=:\n              :===:  -==:\n            :-===:  .====:\n          :-====-   -=====:\n         -======   :=======:\n        -======.  .=========:\n       -======:   -==========.\n      -======-    -===========.\n     :======-      :===========.\n    :=======.       .-==========.\n   :=======:          -==========.\n  :=======-            :==========.\n :=======-              .-========-\n:--------.                :========-\n                    ..:::--=========-\n            ..::---================-=-\n\x1b[0m",
+        raw_art: "${c1}                    .\n                  :-  .\n                :==. .=:\n              :===:  -==:\n            :-===:  .====:\n          :-====-   -=====:\n         -======   :=======:\n        -======.  .=========:\n       -======:   -==========.\n      -======-    -===========.\n     :======-      :===========.\n    :=======.       .-==========.\n   :=======:          -==========.\n  :=======-            :==========.\n :=======-              .-========-\n:--------.                :========-\n                    ..:::--=========-\n            ..::---================-=-\n",
+        colors: &[Color::Palette(6)],
         max_line_length: 38,
     },
     Logo {
         name: "CRUX",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m         odddd\n      oddxkkkxxdoo\n     ddcoddxxxdoool\n     xdclodod  olol\n     xoc  xdd  olol\n     xdc  \x1b[1;35mk00\x1b[1;34mOkdlol\n     xxd\x1b[1;35mkOKKKOkd\x1b[1;34mldd\n     xdco\x1b[1;35mxOkdlo\x1b[1;34mdldd\n     ddc:cl\x1b[1;35mlll\x1b[1;34moooodo\n   odxxdd\x1b[1;37mxkO000kx\x1b[1;34mooxdo\n  oxdd\x1b[1;37mx0NMMMMMMWW0od\x1b[1;34mkkxo\n oooxd\x1b[1;37m0WMMMMMMMMMW0o\x1b[1;34mdxkx\ndocldkXW\x1b[1;37mMMMMMMMWWN\x1b[1;34mOdolco\nxx\x1b[1;35mdx\x1b[1;34mkxxOKN\x1b[1;37mWMMWN\x1b[1;34m0xdoxo::c\n\x1b[1;35mxOkkO\x1b[1;34m0oo\x1b[1;37modOW\x1b[1;35mWW\x1b[1;34mXkdodOxc:l\n\x1b[1;35mdkkkxkkk\x1b[1;37mOKX\x1b[1;35mNNNX0Oxx\x1b[1;34mxc:cd\n\x1b[1;35m odxxdx\x1b[1;37mxllod\x1b[1;35mddooxx\x1b[1;34mdc:ldo\n\x1b[1;35m   lodd\x1b[1;34mdolccc\x1b[1;35mccox\x1b[1;34mxoloo\n\x1b[0m",
+        raw_art: "${c1}         odddd\n      oddxkkkxxdoo\n     ddcoddxxxdoool\n     xdclodod  olol\n     xoc  xdd  olol\n     xdc  ${c2}k00${c1}Okdlol\n     xxd${c2}kOKKKOkd${c1}ldd\n     xdco${c2}xOkdlo${c1}dldd\n     ddc:cl${c2}lll${c1}oooodo\n   odxxdd${c3}xkO000kx${c1}ooxdo\n  oxdd${c3}x0NMMMMMMWW0od${c1}kkxo\n oooxd${c3}0WMMMMMMMMMW0o${c1}dxkx\ndocldkXW${c3}MMMMMMMWWN${c1}Odolco\nxx${c2}dx${c1}kxxOKN${c3}WMMWN${c1}0xdoxo::c\n${c2}xOkkO${c1}0oo${c3}odOW${c2}WW${c1}XkdodOxc:l\n${c2}dkkkxkkk${c3}OKX${c2}NNNX0Oxx${c1}xc:cd\n${c2} odxxdx${c3}xllod${c2}ddooxx${c1}dc:ldo\n${c2}   lodd${c1}dolccc${c2}ccox${c1}xoloo\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 24,
     },
     Logo {
         name: "Calculate",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                              ......\n                           ,,+++++++,.\n                         .,,,....,,,\x1b[1;33m+**+,,.\x1b[1;37m\n                       ............,\x1b[1;33m++++,,,\x1b[1;37m\n                      ...............\n                    ......,,,........\n                  .....+*#####+,,,*+.\n              .....,*###############,..,,,,,,..\n           ......,*#################*..,,,,,..,,,..\n         .,,....*####################+***+,,,,...,++,\n       .,,..,..*#####################*,\n     ,+,.+*..*#######################.\n   ,+,,+*+..,########################*\n.,++++++.  ..+##**###################+\n.....      ..+##***#################*.\n           .,.*#*****##############*.\n           ..,,*********#####****+.\n     \x1b[1;33m.,++*****+++\x1b[1;37m*****************\x1b[1;33m+++++,.\x1b[1;37m\n      \x1b[1;33m,++++++**+++++\x1b[1;37m***********\x1b[1;33m+++++++++,\x1b[1;37m\n     \x1b[1;33m.,,,,++++,..  .,,,,,.....,+++,.,,\x1b[1;37m\n\x1b[0m",
+        raw_art: "${c1}                              ......\n                           ,,+++++++,.\n                         .,,,....,,,${c2}+**+,,.${c1}\n                       ............,${c2}++++,,,${c1}\n                      ...............\n                    ......,,,........\n                  .....+*#####+,,,*+.\n              .....,*###############,..,,,,,,..\n           ......,*#################*..,,,,,..,,,..\n         .,,....*####################+***+,,,,...,++,\n       .,,..,..*#####################*,\n     ,+,.+*..*#######################.\n   ,+,,+*+..,########################*\n.,++++++.  ..+##**###################+\n.....      ..+##***#################*.\n           .,.*#*****##############*.\n           ..,,*********#####****+.\n     ${c2}.,++*****+++${c1}*****************${c2}+++++,.${c1}\n      ${c2},++++++**+++++${c1}***********${c2}+++++++++,${c1}\n     ${c2}.,,,,++++,..  .,,,,,.....,+++,.,,${c1}\n",
+        colors: &[Color::Palette(7), Color::Palette(3)],
         max_line_length: 53,
     },
     Logo {
         name: "Carbs",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m             ..........\n          ..,;:ccccccc:;'..\n       ..,clllc:;;;;;:cllc,.\n      .,cllc,...     ..';;'.\n     .;lol;..           ..\n    .,lol;.\n    .coo:.\n   .'lol,.\n   .,lol,.\n   .,lol,.\n    'col;.\n    .:ooc'.\n    .'col:.\n     .'cllc'..          .''.\n      ..:lolc,'.......',cll,.\n        ..;cllllccccclllc;'.\n          ...',;;;;;;,,...\n                .....\n\x1b[0m",
+        raw_art: "${c2}             ..........\n          ..,;:ccccccc:;'..\n       ..,clllc:;;;;;:cllc,.\n      .,cllc,...     ..';;'.\n     .;lol;..           ..\n    .,lol;.\n    .coo:.\n   .'lol,.\n   .,lol,.\n   .,lol,.\n    'col;.\n    .:ooc'.\n    .'col:.\n     .'cllc'..          .''.\n      ..:lolc,'.......',cll,.\n        ..;cllllccccclllc;'.\n          ...',;;;;;;,,...\n                .....\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(4), Color::Palette(4), Color::Palette(4), Color::Palette(4)],
         max_line_length: 29,
     },
     Logo {
         name: "CelOS",
         is_wildcard: true,
         ascii_art: "\n\x1b[1;35m                     .,cmmmmmmmmmmmc,.\n                .,cmMMMMMMMMMMMMMMMMMMMMmc.\n             .cMMMMMMMMMMMMMMMMMMMMMMMMMMMmc.\n           .cMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMc.\n         ,:MMM ${c3}####################################\x1b[1;35m\n        cMMMMMMmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmc.\n       .MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM.\n      .MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMc\n      \"******************************MMMMMMMMMMMMMc:\n${c3}#################################### \x1b[1;35mMMMMMMMMMMMMMc\n      \"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n       \"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\"\n       'MMMMMMMMM*******************************:\n        \\\"MMMMMM ${c3}#####################################\n         \x1b[1;35m`:MMMMMMmmmmmmmmmmmmmmmmmmmmmmmmmmmmm;\n           `\"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\"\n             `\":MMMMMMMMMMMMMMMMMMMMMMMMM;'\n                `\":MMMMMMMMMMMMMMMMMMM:\"\n                     \"************\"\n\n\n\n\n\x1b[0m",
+        raw_art: "\n${c4}                     .,cmmmmmmmmmmmc,.\n                .,cmMMMMMMMMMMMMMMMMMMMMmc.\n             .cMMMMMMMMMMMMMMMMMMMMMMMMMMMmc.\n           .cMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMc.\n         ,:MMM ${c3}####################################${c4}\n        cMMMMMMmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmc.\n       .MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM.\n      .MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMc\n      \"******************************MMMMMMMMMMMMMc:\n${c3}#################################### ${c4}MMMMMMMMMMMMMc\n      \"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n       \"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\"\n       'MMMMMMMMM*******************************:\n        \\\"MMMMMM ${c3}#####################################\n         ${c4}`:MMMMMMmmmmmmmmmmmmmmmmmmmmmmmmmmmmm;\n           `\"MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\"\n             `\":MMMMMMMMMMMMMMMMMMMMMMMMM;'\n                `\":MMMMMMMMMMMMMMMMMMM:\"\n                     \"************\"\n\n\n\n\n",
+        colors: &[Color::Palette(4), Color::Palette(6), Color::Palette(0), Color::Palette(5)],
         max_line_length: 54,
     },
     Logo {
         name: "CentOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m                 ..\n               .PLTJ.\n              <><><><>\n     \x1b[1;32mKKSSV' 4KKK \x1b[1;33mLJ\x1b[1;35m KKKL.'VSSKK\n     \x1b[1;32mKKV' 4KKKKK \x1b[1;33mLJ\x1b[1;35m KKKKAL 'VKK\n     \x1b[1;32mV' ' 'VKKKK \x1b[1;33mLJ\x1b[1;35m KKKKV' ' 'V\n     \x1b[1;32m.4MA.' 'VKK \x1b[1;33mLJ\x1b[1;35m KKV' '.4Mb.\n\x1b[1;35m   . \x1b[1;32mKKKKKA.' 'V \x1b[1;33mLJ\x1b[1;35m V' '.4KKKKK \x1b[1;34m.\n\x1b[1;35m .4D \x1b[1;32mKKKKKKKA.'' \x1b[1;33mLJ\x1b[1;35m ''.4KKKKKKK \x1b[1;34mFA.\n\x1b[1;35m<QDD ++++++++++++  \x1b[1;34m++++++++++++ GFD>\n\x1b[1;35m 'VD \x1b[1;34mKKKKKKKK'.. \x1b[1;32mLJ \x1b[1;33m..'KKKKKKKK \x1b[1;34mFV\n\x1b[1;35m   ' \x1b[1;34mVKKKKK'. .4 \x1b[1;32mLJ \x1b[1;33mK. .'KKKKKV \x1b[1;34m'\n     \x1b[1;34m 'VK'. .4KK \x1b[1;32mLJ \x1b[1;33mKKA. .'KV'\n     \x1b[1;34mA. . .4KKKK \x1b[1;32mLJ \x1b[1;33mKKKKA. . .4\n     \x1b[1;34mKKA. 'KKKKK \x1b[1;32mLJ \x1b[1;33mKKKKK' .4KK\n     \x1b[1;34mKKSSA. VKKK \x1b[1;32mLJ \x1b[1;33mKKKV .4SSKK\n\x1b[1;32m              <><><><>\n               'MKKM'\n                 ''\n\x1b[0m",
+        raw_art: "${c1}                 ..\n               .PLTJ.\n              <><><><>\n     ${c2}KKSSV' 4KKK ${c1}LJ${c4} KKKL.'VSSKK\n     ${c2}KKV' 4KKKKK ${c1}LJ${c4} KKKKAL 'VKK\n     ${c2}V' ' 'VKKKK ${c1}LJ${c4} KKKKV' ' 'V\n     ${c2}.4MA.' 'VKK ${c1}LJ${c4} KKV' '.4Mb.\n${c4}   . ${c2}KKKKKA.' 'V ${c1}LJ${c4} V' '.4KKKKK ${c3}.\n${c4} .4D ${c2}KKKKKKKA.'' ${c1}LJ${c4} ''.4KKKKKKK ${c3}FA.\n${c4}<QDD ++++++++++++  ${c3}++++++++++++ GFD>\n${c4} 'VD ${c3}KKKKKKKK'.. ${c2}LJ ${c1}..'KKKKKKKK ${c3}FV\n${c4}   ' ${c3}VKKKKK'. .4 ${c2}LJ ${c1}K. .'KKKKKV ${c3}'\n     ${c3} 'VK'. .4KK ${c2}LJ ${c1}KKA. .'KV'\n     ${c3}A. . .4KKKK ${c2}LJ ${c1}KKKKA. . .4\n     ${c3}KKA. 'KKKKK ${c2}LJ ${c1}KKKKK' .4KK\n     ${c3}KKSSA. VKKK ${c2}LJ ${c1}KKKV .4SSKK\n${c2}              <><><><>\n               'MKKM'\n                 ''\n",
+        colors: &[Color::Palette(3), Color::Palette(2), Color::Palette(4), Color::Palette(5), Color::Palette(7)],
         max_line_length: 36,
     },
     Logo {
         name: "Chakra",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m     _ _ _        \"kkkkkkkk.\n   ,kkkkkkkk.,    'kkkkkkkkk,\n   ,kkkkkkkkkkkk., 'kkkkkkkkk.\n  ,kkkkkkkkkkkkkkkk,'kkkkkkkk,\n ,kkkkkkkkkkkkkkkkkkk'kkkkkkk.\n  \"''\"''',;::,,\"''kkk''kkkkk;   __\n      ,kkkkkkkkkk, \"k''kkkkk' ,kkkk\n    ,kkkkkkk' ., ' .: 'kkkk',kkkkkk\n  ,kkkkkkkk'.k'   ,  ,kkkk;kkkkkkkkk\n ,kkkkkkkk';kk 'k  \"'k',kkkkkkkkkkkk\n.kkkkkkkkk.kkkk.'kkkkkkkkkkkkkkkkkk'\n;kkkkkkkk''kkkkkk;'kkkkkkkkkkkkk''\n'kkkkkkk; 'kkkkkkkk.,\"\"''\"''\"\"\n  ''kkkk;  'kkkkkkkkkk.,\n     ';'    'kkkkkkkkkkkk.,\n             ';kkkkkkkkkk'\n               ';kkkkkk'\n                  \"''\"\n\x1b[0m",
+        raw_art: "${c1}     _ _ _        \"kkkkkkkk.\n   ,kkkkkkkk.,    'kkkkkkkkk,\n   ,kkkkkkkkkkkk., 'kkkkkkkkk.\n  ,kkkkkkkkkkkkkkkk,'kkkkkkkk,\n ,kkkkkkkkkkkkkkkkkkk'kkkkkkk.\n  \"''\"''',;::,,\"''kkk''kkkkk;   __\n      ,kkkkkkkkkk, \"k''kkkkk' ,kkkk\n    ,kkkkkkk' ., ' .: 'kkkk',kkkkkk\n  ,kkkkkkkk'.k'   ,  ,kkkk;kkkkkkkkk\n ,kkkkkkkk';kk 'k  \"'k',kkkkkkkkkkkk\n.kkkkkkkkk.kkkk.'kkkkkkkkkkkkkkkkkk'\n;kkkkkkkk''kkkkkk;'kkkkkkkkkkkkk''\n'kkkkkkk; 'kkkkkkkk.,\"\"''\"''\"\"\n  ''kkkk;  'kkkkkkkkkk.,\n     ';'    'kkkkkkkkkkkk.,\n             ';kkkkkkkkkk'\n               ';kkkkkk'\n                  \"''\"\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 36,
     },
     Logo {
         name: "ChaletOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m             `.//+osso+/:``\n         `/sdNNmhyssssydmNNdo:`\n       :hNmy+-`          .-+hNNs-\n     /mMh/`       `+:`       `+dMd:\n   .hMd-        -sNNMNo.  /yyy  /mMs`\n  -NM+       `/dMd/--omNh::dMM   `yMd`\n .NN+      .sNNs:/dMNy:/hNmo/s     yMd`\n hMs    `/hNd+-smMMMMMMd+:omNy-    `dMo\n:NM.  .omMy:/hNMMMMMMMMMMNy:/hMd+`  :Md`\n/Md` `sm+.omMMMMMMMMMMMMMMMMd/-sm+  .MN:\n/Md`      MMMMMMMMMMMMMMMMMMMN      .MN:\n:NN.      MMMMMMm....--NMMMMMN      -Mm.\n`dMo      MMMMMMd      mMMMMMN      hMs\n -MN:     MMMMMMd      mMMMMMN     oMm`\n  :NM:    MMMMMMd      mMMMMMN    +Mm-\n   -mMy.  mmmmmmh      dmmmmmh  -hMh.\n     oNNs-                    :yMm/\n      .+mMdo:`            `:smMd/`\n         -ohNNmhsoo++osshmNNh+.\n            `./+syyhhyys+:``\n\x1b[0m",
+        raw_art: "${c1}             `.//+osso+/:``\n         `/sdNNmhyssssydmNNdo:`\n       :hNmy+-`          .-+hNNs-\n     /mMh/`       `+:`       `+dMd:\n   .hMd-        -sNNMNo.  /yyy  /mMs`\n  -NM+       `/dMd/--omNh::dMM   `yMd`\n .NN+      .sNNs:/dMNy:/hNmo/s     yMd`\n hMs    `/hNd+-smMMMMMMd+:omNy-    `dMo\n:NM.  .omMy:/hNMMMMMMMMMMNy:/hMd+`  :Md`\n/Md` `sm+.omMMMMMMMMMMMMMMMMd/-sm+  .MN:\n/Md`      MMMMMMMMMMMMMMMMMMMN      .MN:\n:NN.      MMMMMMm....--NMMMMMN      -Mm.\n`dMo      MMMMMMd      mMMMMMN      hMs\n -MN:     MMMMMMd      mMMMMMN     oMm`\n  :NM:    MMMMMMd      mMMMMMN    +Mm-\n   -mMy.  mmmmmmh      dmmmmmh  -hMh.\n     oNNs-                    :yMm/\n      .+mMdo:`            `:smMd/`\n         -ohNNmhsoo++osshmNNh+.\n            `./+syyhhyys+:``\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Chapeau",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m               .-/-.\n            ////////.\n          ////////\x1b[1;37my+\x1b[1;32m//.\n        ////////\x1b[1;37mmMN\x1b[1;32m/////.\n      ////////\x1b[1;37mmMN+\x1b[1;32m////////.\n    ////////////////////////.\n  /////////+\x1b[1;37mshhddhyo\x1b[1;32m+////////.\n ////////\x1b[1;37mymMNmdhhdmNNdo\x1b[1;32m///////.\n///////+\x1b[1;37mmMms\x1b[1;32m////////\x1b[1;37mhNMh\x1b[1;32m///////.\n///////\x1b[1;37mNMm+\x1b[1;32m//////////\x1b[1;37msMMh\x1b[1;32m///////\n//////\x1b[1;37moMMNmmmmmmmmmmmmMMm\x1b[1;32m///////\n//////\x1b[1;37m+MMmssssssssssssss+\x1b[1;32m///////\n`//////\x1b[1;37myMMy\x1b[1;32m////////////////////\n `//////\x1b[1;37msmMNhso++oydNm\x1b[1;32m////////\n  `///////\x1b[1;37mohmNMMMNNdy+\x1b[1;32m///////\n    `//////////\x1b[1;37m++\x1b[1;32m//////////\n       `////////////////.\n           -////////-\n\x1b[0m",
+        raw_art: "${c1}               .-/-.\n            ////////.\n          ////////${c2}y+${c1}//.\n        ////////${c2}mMN${c1}/////.\n      ////////${c2}mMN+${c1}////////.\n    ////////////////////////.\n  /////////+${c2}shhddhyo${c1}+////////.\n ////////${c2}ymMNmdhhdmNNdo${c1}///////.\n///////+${c2}mMms${c1}////////${c2}hNMh${c1}///////.\n///////${c2}NMm+${c1}//////////${c2}sMMh${c1}///////\n//////${c2}oMMNmmmmmmmmmmmmMMm${c1}///////\n//////${c2}+MMmssssssssssssss+${c1}///////\n`//////${c2}yMMy${c1}////////////////////\n `//////${c2}smMNhso++oydNm${c1}////////\n  `///////${c2}ohmNMMMNNdy+${c1}///////\n    `//////////${c2}++${c1}//////////\n       `////////////////.\n           -////////-\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 32,
     },
     Logo {
         name: "Chrom",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m            .,:loool:,.\n        .,coooooooooooooc,.\n     .,lllllllllllllllllllll,.\n    ;ccccccccccccccccccccccccc;\n\x1b[1;32m  '\x1b[1;31mccccccccccccccccccccccccccccc.\n\x1b[1;32m ,oo\x1b[1;31mc::::::::okO\x1b[1;37m000\x1b[1;33m0OOkkkkkkkkkkk:\n\x1b[1;32m.ooool\x1b[1;31m;;;;:x\x1b[1;37mK0\x1b[1;34mkxxxxxk\x1b[1;37m0X\x1b[1;33mK0000000000.\n\x1b[1;32m:oooool\x1b[1;31m;,;O\x1b[1;37mK\x1b[1;34mddddddddddd\x1b[1;37mKX\x1b[1;33m000000000d\n\x1b[1;32mlllllool\x1b[1;31m;l\x1b[1;37mN\x1b[1;34mdllllllllllld\x1b[1;37mN\x1b[1;33mK000000000\n\x1b[1;32mlllllllll\x1b[1;31mo\x1b[1;37mM\x1b[1;34mdccccccccccco\x1b[1;37mW\x1b[1;33mK000000000\n\x1b[1;32m;cllllllllX\x1b[1;37mX\x1b[1;34mc:::::::::c\x1b[1;37m0X\x1b[1;33m000000000d\n\x1b[1;32m.ccccllllllO\x1b[1;37mNk\x1b[1;34mc;,,,;cx\x1b[1;37mKK\x1b[1;33m0000000000.\n\x1b[1;32m .cccccclllllxOO\x1b[1;37mOOO\x1b[1;32mOkx\x1b[1;33mO0000000000;\n\x1b[1;32m  .:ccccccccllllllllo\x1b[1;33mO0000000OOO,\n\x1b[1;32m    ,:ccccccccclllcd\x1b[1;33m0000OOOOOOl.\n\x1b[1;32m      '::ccccccccc\x1b[1;33mdOOOOOOOkx:.\n\x1b[1;32m        ..,::cccc\x1b[1;33mxOOOkkko;.\n\x1b[1;32m            ..,:\x1b[1;33mdOkxl:.\n\x1b[0m",
+        raw_art: "${c2}            .,:loool:,.\n        .,coooooooooooooc,.\n     .,lllllllllllllllllllll,.\n    ;ccccccccccccccccccccccccc;\n${c1}  '${c2}ccccccccccccccccccccccccccccc.\n${c1} ,oo${c2}c::::::::okO${c5}000${c3}0OOkkkkkkkkkkk:\n${c1}.ooool${c2};;;;:x${c5}K0${c4}kxxxxxk${c5}0X${c3}K0000000000.\n${c1}:oooool${c2};,;O${c5}K${c4}ddddddddddd${c5}KX${c3}000000000d\n${c1}lllllool${c2};l${c5}N${c4}dllllllllllld${c5}N${c3}K000000000\n${c1}lllllllll${c2}o${c5}M${c4}dccccccccccco${c5}W${c3}K000000000\n${c1};cllllllllX${c5}X${c4}c:::::::::c${c5}0X${c3}000000000d\n${c1}.ccccllllllO${c5}Nk${c4}c;,,,;cx${c5}KK${c3}0000000000.\n${c1} .cccccclllllxOO${c5}OOO${c1}Okx${c3}O0000000000;\n${c1}  .:ccccccccllllllllo${c3}O0000000OOO,\n${c1}    ,:ccccccccclllcd${c3}0000OOOOOOl.\n${c1}      '::ccccccccc${c3}dOOOOOOOkx:.\n${c1}        ..,::cccc${c3}xOOOkkko;.\n${c1}            ..,:${c3}dOkxl:.\n",
+        colors: &[Color::Palette(2), Color::Palette(1), Color::Palette(3), Color::Palette(4), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "Cleanjaro",
         is_wildcard: true,
-        ascii_art: "\x1b[1;37m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c} \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c} \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c} \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{008c}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\n\x1b[0m",
+        ascii_art: "\x1b[1;37m\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\n\x1b[0m",
+        raw_art: "${c1}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{258c}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\n",
+        colors: &[Color::Palette(7), Color::Palette(7)],
         max_line_length: 25,
     },
+    Logo {
+        name: "Clear Linux OS",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m          BBB\n       BBBBBBBBB\n     BBBBBBBBBBBBBBB\n   BBBBBBBBBBBBBBBBBBBB\n   BBBBBBBBBBB         BBB\n  BBBBBBBB\x1b[1;33mYYYYY\n\x1b[1;34m  BBBBBBBB\x1b[1;33mYYYYYY\n\x1b[1;34m  BBBBBBBB\x1b[1;33mYYYYYYY\n\x1b[1;34m  BBBBBBBBB\x1b[1;33mYYYYY\x1b[1;37mW\n\x1b[1;36m GG\x1b[1;34mBBBBBBBY\x1b[1;33mYYYY\x1b[1;37mWWW\n\x1b[1;36m GGG\x1b[1;34mBBBBBBB\x1b[1;33mYY\x1b[1;37mWWWWWWWW\n\x1b[1;36m GGGGGG\x1b[1;34mBBBBBB\x1b[1;37mWWWWWWWW\n\x1b[1;36m GGGGGGGG\x1b[1;34mBBBB\x1b[1;37mWWWWWWWW\n\x1b[1;36mGGGGGGGGGGG\x1b[1;34mBBB\x1b[1;37mWWWWWWW\n\x1b[1;36mGGGGGGGGGGGGG\x1b[1;34mB\x1b[1;37mWWWWWW\n\x1b[1;36mGGGGGGGG\x1b[1;37mWWWWWWWWWWW\n\x1b[1;36mGG\x1b[1;37mWWWWWWWWWWWWWWWW\n WWWWWWWWWWWWWWWW\n      WWWWWWWWWW\n          WWW\n\x1b[0m",
+        raw_art: "${c1}          BBB\n       BBBBBBBBB\n     BBBBBBBBBBBBBBB\n   BBBBBBBBBBBBBBBBBBBB\n   BBBBBBBBBBB         BBB\n  BBBBBBBB${c2}YYYYY\n${c1}  BBBBBBBB${c2}YYYYYY\n${c1}  BBBBBBBB${c2}YYYYYYY\n${c1}  BBBBBBBBB${c2}YYYYY${c3}W\n${c4} GG${c1}BBBBBBBY${c2}YYYY${c3}WWW\n${c4} GGG${c1}BBBBBBB${c2}YY${c3}WWWWWWWW\n${c4} GGGGGG${c1}BBBBBB${c3}WWWWWWWW\n${c4} GGGGGGGG${c1}BBBB${c3}WWWWWWWW\n${c4}GGGGGGGGGGG${c1}BBB${c3}WWWWWWW\n${c4}GGGGGGGGGGGGG${c1}B${c3}WWWWWW\n${c4}GGGGGGGG${c3}WWWWWWWWWWW\n${c4}GG${c3}WWWWWWWWWWWWWWWW\n WWWWWWWWWWWWWWWW\n      WWWWWWWWWW\n          WWW\n",
+        colors: &[Color::Palette(4), Color::Palette(3), Color::Palette(7), Color::Palette(6)],
+        max_line_length: 26,
+    },
     Logo {
         name: "ClearOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m             `.--::::::--.`\n         .-:////////////////:-.\n      `-////////////////////////-`\n     -////////////////////////////-\n   `//////////////-..-//////////////`\n  ./////////////:      ://///////////.\n `//////:..-////:      :////-..-//////`\n ://////`    -///:.``.:///-`    ://///:\n`///////:.     -////////-`    `:///////`\n.//:--////:.     -////-`    `:////--://.\n./:    .////:.     --`    `:////-    :/.\n`//-`    .////:.        `:////-    `-//`\n :///-`    .////:.    `:////-    `-///:\n `/////-`    -///:    :///-    `-/////`\n  `//////-   `///:    :///`   .//////`\n   `:////:   `///:    :///`   -////:`\n     .://:   `///:    :///`   -//:.\n       .::   `///:    :///`   -:.\n             `///:    :///`\n              `...    ...`\n\x1b[0m",
+        raw_art: "${c1}             `.--::::::--.`\n         .-:////////////////:-.\n      `-////////////////////////-`\n     -////////////////////////////-\n   `//////////////-..-//////////////`\n  ./////////////:      ://///////////.\n `//////:..-////:      :////-..-//////`\n ://////`    -///:.``.:///-`    ://///:\n`///////:.     -////////-`    `:///////`\n.//:--////:.     -////-`    `:////--://.\n./:    .////:.     --`    `:////-    :/.\n`//-`    .////:.        `:////-    `-//`\n :///-`    .////:.    `:////-    `-///:\n `/////-`    -///:    :///-    `-/////`\n  `//////-   `///:    :///`   .//////`\n   `:////:   `///:    :///`   -////:`\n     .://:   `///:    :///`   -//:.\n       .::   `///:    :///`   -:.\n             `///:    :///`\n              `...    ...`\n",
+        colors: &[Color::Palette(2)],
         max_line_length: 40,
     },
     Logo {
         name: "Clear_Linux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m          BBB\n       BBBBBBBBB\n     BBBBBBBBBBBBBBB\n   BBBBBBBBBBBBBBBBBBBB\n   BBBBBBBBBBB         BBB\n  BBBBBBBB\x1b[1;33mYYYYY\n\x1b[1;34m  BBBBBBBB\x1b[1;33mYYYYYY\n\x1b[1;34m  BBBBBBBB\x1b[1;33mYYYYYYY\n\x1b[1;34m  BBBBBBBBB\x1b[1;33mYYYYY\x1b[1;37mW\n\x1b[1;36m GG\x1b[1;34mBBBBBBBY\x1b[1;33mYYYY\x1b[1;37mWWW\n\x1b[1;36m GGG\x1b[1;34mBBBBBBB\x1b[1;33mYY\x1b[1;37mWWWWWWWW\n\x1b[1;36m GGGGGG\x1b[1;34mBBBBBB\x1b[1;37mWWWWWWWW\n\x1b[1;36m GGGGGGGG\x1b[1;34mBBBB\x1b[1;37mWWWWWWWW\n\x1b[1;36mGGGGGGGGGGG\x1b[1;34mBBB\x1b[1;37mWWWWWWW\n\x1b[1;36mGGGGGGGGGGGGG\x1b[1;34mB\x1b[1;37mWWWWWW\n\x1b[1;36mGGGGGGGG\x1b[1;37mWWWWWWWWWWW\n\x1b[1;36mGG\x1b[1;37mWWWWWWWWWWWWWWWW\n WWWWWWWWWWWWWWWW\n      WWWWWWWWWW\n          WWW\n\x1b[0m",
+        raw_art: "${c1}          BBB\n       BBBBBBBBB\n     BBBBBBBBBBBBBBB\n   BBBBBBBBBBBBBBBBBBBB\n   BBBBBBBBBBB         BBB\n  BBBBBBBB${c2}YYYYY\n${c1}  BBBBBBBB${c2}YYYYYY\n${c1}  BBBBBBBB${c2}YYYYYYY\n${c1}  BBBBBBBBB${c2}YYYYY${c3}W\n${c4} GG${c1}BBBBBBBY${c2}YYYY${c3}WWW\n${c4} GGG${c1}BBBBBBB${c2}YY${c3}WWWWWWWW\n${c4} GGGGGG${c1}BBBBBB${c3}WWWWWWWW\n${c4} GGGGGGGG${c1}BBBB${c3}WWWWWWWW\n${c4}GGGGGGGGGGG${c1}BBB${c3}WWWWWWW\n${c4}GGGGGGGGGGGGG${c1}B${c3}WWWWWW\n${c4}GGGGGGGG${c3}WWWWWWWWWWW\n${c4}GG${c3}WWWWWWWWWWWWWWWW\n WWWWWWWWWWWWWWWW\n      WWWWWWWWWW\n          WWW\n",
+        colors: &[Color::Palette(4), Color::Palette(3), Color::Palette(7), Color::Palette(6)],
         max_line_length: 26,
     },
     Logo {
         name: "Clover",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m               `omo``omo`\n             `oNMMMNNMMMNo`\n           `oNMMMMMMMMMMMMNo`\n          oNMMMMMMMMMMMMMMMMNo\n          `sNMMMMMMMMMMMMMMNs`\n     `omo`  `sNMMMMMMMMMMNs`  `omo`\n   `oNMMMNo`  `sNMMMMMMNs`  `oNMMMNo`\n `oNMMMMMMMNo`  `oNMMNs`  `oNMMMMMMMNo`\noNMMMMMMMMMMMNo`  `sy`  `oNMMMMMMMMMMMNo\n`sNMMMMMMMMMMMMNo.\x1b[1;36moNNs\x1b[1;32m.oNMMMMMMMMMMMMNs`\n`oNMMMMMMMMMMMMNs.\x1b[1;36moNNs\x1b[1;32m.oNMMMMMMMMMMMMNo`\noNMMMMMMMMMMMNs`  `sy`  `oNMMMMMMMMMMMNo\n `oNMMMMMMMNs`  `oNMMNo`  `oNMMMMMMMNs`\n   `oNMMMNs`  `sNMMMMMMNs`  `oNMMMNs`\n     `oNs`  `sNMMMMMMMMMMNs`  `oNs`\n          `sNMMMMMMMMMMMMMMNs`\n          +NMMMMMMMMMMMMMMMMNo\n           `oNMMMMMMMMMMMMNo`\n             `oNMMMNNMMMNs`\n               `omo``oNs`\n\x1b[0m",
+        raw_art: "${c1}               `omo``omo`\n             `oNMMMNNMMMNo`\n           `oNMMMMMMMMMMMMNo`\n          oNMMMMMMMMMMMMMMMMNo\n          `sNMMMMMMMMMMMMMMNs`\n     `omo`  `sNMMMMMMMMMMNs`  `omo`\n   `oNMMMNo`  `sNMMMMMMNs`  `oNMMMNo`\n `oNMMMMMMMNo`  `oNMMNs`  `oNMMMMMMMNo`\noNMMMMMMMMMMMNo`  `sy`  `oNMMMMMMMMMMMNo\n`sNMMMMMMMMMMMMNo.${c2}oNNs${c1}.oNMMMMMMMMMMMMNs`\n`oNMMMMMMMMMMMMNs.${c2}oNNs${c1}.oNMMMMMMMMMMMMNo`\noNMMMMMMMMMMMNs`  `sy`  `oNMMMMMMMMMMMNo\n `oNMMMMMMMNs`  `oNMMNo`  `oNMMMMMMMNs`\n   `oNMMMNs`  `sNMMMMMMNs`  `oNMMMNs`\n     `oNs`  `sNMMMMMMMMMMNs`  `oNs`\n          `sNMMMMMMMMMMMMMMNs`\n          +NMMMMMMMMMMMMMMMMNo\n           `oNMMMMMMMMMMMMNo`\n             `oNMMMNNMMMNs`\n               `omo``oNs`\n",
+        colors: &[Color::Palette(2), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
         name: "Condres",
         is_wildcard: true,
         ascii_art: "\x1b[1;32msyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy+\x1b[1;36m.+.\n\x1b[1;32m`oyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy+\x1b[1;36m:++.\n\x1b[1;33m/o\x1b[1;32m+oyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy/\x1b[1;36moo++.\n\x1b[1;33m/y+\x1b[1;32msyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\x1b[1;36m+ooo++.\n\x1b[1;33m/hy+\x1b[1;32moyyyhhhhhhhhhhhhhhyyyyyyyyy\x1b[1;36m+oo+++++.\n\x1b[1;33m/hhh+\x1b[1;32mshhhhhdddddhhhhhhhyyyyyyy\x1b[1;36m+oo++++++.\n\x1b[1;33m/hhdd+\x1b[1;32moddddddddddddhhhhhyyyys\x1b[1;36m+oo+++++++.\n\x1b[1;33m/hhddd+\x1b[1;32modmmmdddddddhhhhyyyy\x1b[1;36m+ooo++++++++.\n\x1b[1;33m/hhdddmo\x1b[1;32modmmmdddddhhhhhyyy\x1b[1;36m+oooo++++++++.\n\x1b[1;33m/hdddmmms\x1b[1;32m/dmdddddhhhhyyys\x1b[1;36m+oooo+++++++++.\n\x1b[1;33m/hddddmmmy\x1b[1;32m/hdddhhhhyyyyo\x1b[1;36m+oooo++++++++++:\n\x1b[1;33m/hhdddmmmmy\x1b[1;32m:yhhhhyyyyy+\x1b[1;36m+oooo+++++++++++:\n\x1b[1;33m/hhddddddddy\x1b[1;32m-syyyyyys+\x1b[1;36mooooo++++++++++++:\n\x1b[1;33m/hhhddddddddy\x1b[1;32m-+yyyy+\x1b[1;36m/ooooo+++++++++++++:\n\x1b[1;33m/hhhhhdddddhhy\x1b[1;32m./yo:\x1b[1;36m+oooooo+++++++++++++/\n\x1b[1;33m/hhhhhhhhhhhhhy\x1b[1;32m:-.\x1b[1;36m+sooooo+++++++++++///:\n\x1b[1;33m:sssssssssssso++\x1b[1;32m\x1b[1;36m`:/:--------.````````\n\x1b[0m",
+        raw_art: "${c1}syyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy+${c3}.+.\n${c1}`oyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy+${c3}:++.\n${c2}/o${c1}+oyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy/${c3}oo++.\n${c2}/y+${c1}syyyyyyyyyyyyyyyyyyyyyyyyyyyyy${c3}+ooo++.\n${c2}/hy+${c1}oyyyhhhhhhhhhhhhhhyyyyyyyyy${c3}+oo+++++.\n${c2}/hhh+${c1}shhhhhdddddhhhhhhhyyyyyyy${c3}+oo++++++.\n${c2}/hhdd+${c1}oddddddddddddhhhhhyyyys${c3}+oo+++++++.\n${c2}/hhddd+${c1}odmmmdddddddhhhhyyyy${c3}+ooo++++++++.\n${c2}/hhdddmo${c1}odmmmdddddhhhhhyyy${c3}+oooo++++++++.\n${c2}/hdddmmms${c1}/dmdddddhhhhyyys${c3}+oooo+++++++++.\n${c2}/hddddmmmy${c1}/hdddhhhhyyyyo${c3}+oooo++++++++++:\n${c2}/hhdddmmmmy${c1}:yhhhhyyyyy+${c3}+oooo+++++++++++:\n${c2}/hhddddddddy${c1}-syyyyyys+${c3}ooooo++++++++++++:\n${c2}/hhhddddddddy${c1}-+yyyy+${c3}/ooooo+++++++++++++:\n${c2}/hhhhhdddddhhy${c1}./yo:${c3}+oooooo+++++++++++++/\n${c2}/hhhhhhhhhhhhhy${c1}:-.${c3}+sooooo+++++++++++///:\n${c2}:sssssssssssso++${c1}${c3}`:/:--------.````````\n",
+        colors: &[Color::Palette(2), Color::Palette(3), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
-        name: "Container_Linux",
+        name: "Container Linux by CoreOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                .....\n          .';:cccccccc:;'.\n        ':ccccclc\x1b[1;31mlllllllll\x1b[1;34mcc:.\n     .;cccccccc\x1b[1;31mlllllllllllllll\x1b[1;34mc,\n    ;clllccccc\x1b[1;31mllllllllllllllllll\x1b[1;34mc,\n  .cllclccccc\x1b[1;31mlllll\x1b[1;37mlll\x1b[1;31mllllllllllll\x1b[1;34mc:\n  ccclclcccc\x1b[1;31mcllll\x1b[1;37mkWMMNKk\x1b[1;31mllllllllll\x1b[1;34mc:\n :ccclclcccc\x1b[1;31mllll\x1b[1;37moWMMMMMMWO\x1b[1;31mlllllllll\x1b[1;34mc,\n.ccllllllccc\x1b[1;31mclll\x1b[1;37mOMMMMMMMMM0\x1b[1;31mlllllllll\x1b[1;34mc\n.lllllclcccc\x1b[1;31mllll\x1b[1;37mKMMMMMMMMMMo\x1b[1;31mllllllll\x1b[1;34mc.\n.lllllllcccc\x1b[1;31mclll\x1b[1;37mKMMMMMMMMN0\x1b[1;31mlllllllll\x1b[1;34mc.\n.cclllllcccc\x1b[1;31mlllld\x1b[1;37mxkkxxdo\x1b[1;31mllllllllllc\x1b[1;34mlc\n :cccllllllcccc\x1b[1;31mlllccllllcclccc\x1b[1;34mcccccc;\n .ccclllllllcccccccc\x1b[1;31mlll\x1b[1;34mccccclccccccc\n  .cllllllllllclcccclccclccllllcllc\n    :cllllllllccclcllllllllllllcc;\n     .cccccccccccccclcccccccccc:.\n       .;cccclccccccllllllccc,.\n          .';ccccclllccc:;..\n                .....\n\x1b[0m",
+        raw_art: "${c1}                .....\n          .';:cccccccc:;'.\n        ':ccccclc${c3}lllllllll${c1}cc:.\n     .;cccccccc${c3}lllllllllllllll${c1}c,\n    ;clllccccc${c3}llllllllllllllllll${c1}c,\n  .cllclccccc${c3}lllll${c2}lll${c3}llllllllllll${c1}c:\n  ccclclcccc${c3}cllll${c2}kWMMNKk${c3}llllllllll${c1}c:\n :ccclclcccc${c3}llll${c2}oWMMMMMMWO${c3}lllllllll${c1}c,\n.ccllllllccc${c3}clll${c2}OMMMMMMMMM0${c3}lllllllll${c1}c\n.lllllclcccc${c3}llll${c2}KMMMMMMMMMMo${c3}llllllll${c1}c.\n.lllllllcccc${c3}clll${c2}KMMMMMMMMN0${c3}lllllllll${c1}c.\n.cclllllcccc${c3}lllld${c2}xkkxxdo${c3}llllllllllc${c1}lc\n :cccllllllcccc${c3}lllccllllcclccc${c1}cccccc;\n .ccclllllllcccccccc${c3}lll${c1}ccccclccccccc\n  .cllllllllllclcccclccclccllllcllc\n    :cllllllllccclcllllllllllllcc;\n     .cccccccccccccclcccccccccc:.\n       .;cccclccccccllllllccc,.\n          .';ccccclllccc:;..\n                .....\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 38,
     },
     Logo {
-        name: "Crystal Linux",
-        is_wildcard: true,
-        ascii_art: "\x1b[1;38;5;13m                        mysssym\n\x1b[1;38;5;13m                      mysssym\n\x1b[1;38;5;13m                    mysssym\n\x1b[1;38;5;13m                  mysssym\n\x1b[1;38;5;13m                mysssyd\n\x1b[1;38;5;13m              mysssyd    N\n\x1b[1;38;5;13m            mysssyd    mysym\n\x1b[1;38;5;13m          mysssyd      dysssym\n\x1b[1;38;5;13m        mysssyd          dysssym\n\x1b[1;38;5;13m      mysssyd              dysssym\n\x1b[1;38;5;13m      mysssyd              dysssym\n\x1b[1;38;5;13m        mysssyd          dysssym\n\x1b[1;38;5;13m          mysssyd      dysssym\n\x1b[1;38;5;13m            mysym    dysssym\n\x1b[1;38;5;13m              N    dysssym\n\x1b[1;38;5;13m                 dysssym\n\x1b[1;38;5;13m               dysssym\n\x1b[1;38;5;13m             dysssym\n\x1b[1;38;5;13m           dysssym\n\x1b[1;38;5;13m         dysssym\n\x1b[0m",
-        max_line_length: 34,
-    },
-    Logo {
-        name: "Cucumber",
+        name: "Container_Linux",
         is_wildcard: true,
-        ascii_art: "\x1b[1;32m           `.-://++++++//:-.`\n        `:/+//\x1b[1;33m::--------\x1b[1;32m:://+/:`\n      -++/:\x1b[1;33m----..........----\x1b[1;32m:/++-\n    .++:\x1b[1;33m---...........-......---\x1b[1;32m:++.\n   /+:\x1b[1;33m---....-::/:/--//:::-....---\x1b[1;32m:+/\n `++:\x1b[1;33m--.....:---::/--/::---:.....--\x1b[1;32m:++`\n /+:\x1b[1;33m--.....--.--::::-/::--.--.....--\x1b[1;32m:+/\n-o:\x1b[1;33m--.......-:::://--/:::::-.......--\x1b[1;32m:o-\n/+:\x1b[1;33m--...-:-::---:::..:::---:--:-...--\x1b[1;32m:+/\no/:\x1b[1;33m-...-:.:.-/:::......::/:.--.:-...-\x1b[1;32m:/o\no/\x1b[1;33m--...::-:/::/:-......-::::::-/-...-\x1b[1;32m:/o\n/+:\x1b[1;33m--..-/:/:::--:::..:::--::////-..--\x1b[1;32m:+/\n-o:\x1b[1;33m--...----::/:::/--/:::::-----...--\x1b[1;32m:o-\n /+:\x1b[1;33m--....://:::.:/--/:.::://:....--\x1b[1;32m:+/\n `++:\x1b[1;33m--...-:::.--.:..:.--.:/:-...--\x1b[1;32m:++`\n   /+:\x1b[1;33m---....----:-..-:----....---\x1b[1;32m:+/\n    .++:\x1b[1;33m---..................---\x1b[1;32m:++.\n      -/+/:\x1b[1;33m----..........----\x1b[1;32m:/+/-\n        `:/+//\x1b[1;33m::--------:::\x1b[1;32m/+/:`\n           `.-://++++++//:-.`\n\x1b[0m",
-        max_line_length: 40,
+        ascii_art: "\x1b[1;34m                .....\n          .';:cccccccc:;'.\n        ':ccccclc\x1b[1;31mlllllllll\x1b[1;34mcc:.\n     .;cccccccc\x1b[1;31mlllllllllllllll\x1b[1;34mc,\n    ;clllccccc\x1b[1;31mllllllllllllllllll\x1b[1;34mc,\n  .cllclccccc\x1b[1;31mlllll\x1b[1;37mlll\x1b[1;31mllllllllllll\x1b[1;34mc:\n  ccclclcccc\x1b[1;31mcllll\x1b[1;37mkWMMNKk\x1b[1;31mllllllllll\x1b[1;34mc:\n :ccclclcccc\x1b[1;31mllll\x1b[1;37moWMMMMMMWO\x1b[1;31mlllllllll\x1b[1;34mc,\n.ccllllllccc\x1b[1;31mclll\x1b[1;37mOMMMMMMMMM0\x1b[1;31mlllllllll\x1b[1;34mc\n.lllllclcccc\x1b[1;31mllll\x1b[1;37mKMMMMMMMMMMo\x1b[1;31mllllllll\x1b[1;34mc.\n.lllllllcccc\x1b[1;31mclll\x1b[1;37mKMMMMMMMMN0\x1b[1;31mlllllllll\x1b[1;34mc.\n.cclllllcccc\x1b[1;31mlllld\x1b[1;37mxkkxxdo\x1b[1;31mllllllllllc\x1b[1;34mlc\n :cccllllllcccc\x1b[1;31mlllccllllcclccc\x1b[1;34mcccccc;\n .ccclllllllcccccccc\x1b[1;31mlll\x1b[1;34mccccclccccccc\n  .cllllllllllclcccclccclccllllcllc\n    :cllllllllccclcllllllllllllcc;\n     .cccccccccccccclcccccccccc:.\n       .;cccclccccccllllllccc,.\n          .';ccccclllccc:;..\n                .....\n\x1b[0m",
+        raw_art: "${c1}                .....\n          .';:cccccccc:;'.\n        ':ccccclc${c3}lllllllll${c1}cc:.\n     .;cccccccc${c3}lllllllllllllll${c1}c,\n    ;clllccccc${c3}llllllllllllllllll${c1}c,\n  .cllclccccc${c3}lllll${c2}lll${c3}llllllllllll${c1}c:\n  ccclclcccc${c3}cllll${c2}kWMMNKk${c3}llllllllll${c1}c:\n :ccclclcccc${c3}llll${c2}oWMMMMMMWO${c3}lllllllll${c1}c,\n.ccllllllccc${c3}clll${c2}OMMMMMMMMM0${c3}lllllllll${c1}c\n.lllllclcccc${c3}llll${c2}KMMMMMMMMMMo${c3}llllllll${c1}c.\n.lllllllcccc${c3}clll${c2}KMMMMMMMMN0${c3}lllllllll${c1}c.\n.cclllllcccc${c3}lllld${c2}xkkxxdo${c3}llllllllllc${c1}lc\n :cccllllllcccc${c3}lllccllllcclccc${c1}cccccc;\n .ccclllllllcccccccc${c3}lll${c1}ccccclccccccc\n  .cllllllllllclcccclccclccllllcllc\n    :cllllllllccclcllllllllllllcc;\n     .cccccccccccccclcccccccccc:.\n       .;cccclccccccllllllccc,.\n          .';ccccclllccc:;..\n                .....\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 38,
     },
     Logo {
         name: "CyberOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;57m             !M$EEEEEEEEEEEP\n            .MMMMM000000Nr.\n            \x1b[1;38;5;57m&MMMMMM\x1b[1;38;5;32mMMMMMMMMMMMMM9\n           \x1b[1;38;5;57m~MMM\x1b[1;38;5;50mMMMM\x1b[1;38;5;32mMMMMMMMMMMMMC\n      \x1b[1;38;5;50m\"    \x1b[1;38;5;57mM\x1b[1;38;5;50mMMMMMMM\x1b[1;38;5;32mMMMMMMMMMMs\n    \x1b[1;38;5;50miM\x1b[1;38;5;32mMMM&&\x1b[1;38;5;50mMMMMMMMM\x1b[1;38;5;32mMMMMMMMM\\\\\n   \x1b[1;38;5;50mBMMM\x1b[1;38;5;32mMMMMM\x1b[1;38;5;50mMMMMMMM\x1b[1;38;5;32mMMMMMM\x1b[1;38;5;57m\"\n  \x1b[1;38;5;50m9MMMMM\x1b[1;38;5;32mMMMMMMM\x1b[1;38;5;50mMMMM\x1b[1;38;5;32mMMMM\x1b[1;38;5;57mMMMf-\n        \x1b[1;38;5;32msMMMMMMMM\x1b[1;38;5;50mMM\x1b[1;38;5;32mM\x1b[1;38;5;57mMMMMMMMMM3_\n         \x1b[1;38;5;32m+ffffffff\x1b[1;38;5;50mP\x1b[1;38;5;57mMMMMMMMMMMMM0\n                    \x1b[1;38;5;32mCMMMMMMMMMMM\n                      }MMMMMMMMM\n                        ~MMMMMMM\n                          \"RMMMM\n                            .PMB\n\x1b[0m",
+        raw_art: "${c3}             !M$EEEEEEEEEEEP\n            .MMMMM000000Nr.\n            ${c3}&MMMMMM${c2}MMMMMMMMMMMMM9\n           ${c3}~MMM${c1}MMMM${c2}MMMMMMMMMMMMC\n      ${c1}\"    ${c3}M${c1}MMMMMMM${c2}MMMMMMMMMMs\n    ${c1}iM${c2}MMM&&${c1}MMMMMMMM${c2}MMMMMMMM\\\\\n   ${c1}BMMM${c2}MMMMM${c1}MMMMMMM${c2}MMMMMM${c3}\"\n  ${c1}9MMMMM${c2}MMMMMMM${c1}MMMM${c2}MMMM${c3}MMMf-\n        ${c2}sMMMMMMMM${c1}MM${c2}M${c3}MMMMMMMMM3_\n         ${c2}+ffffffff${c1}P${c3}MMMMMMMMMMMM0\n                    ${c2}CMMMMMMMMMMM\n                      }MMMMMMMMM\n                        ~MMMMMMM\n                          \"RMMMM\n                            .PMB\n",
+        colors: &[Color::Palette(50), Color::Palette(32), Color::Palette(57)],
         max_line_length: 33,
     },
     Logo {
         name: "DarkOs",
         is_wildcard: false,
-        ascii_art: "\n\x1b[1;35m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}  \u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{00a0}\u{00e2}\u{00a0}\u{00a2}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;31m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a1}\u{0086}\u{00e2}\u{00a2}\u{00b9}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;32m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a1}\u{0086}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a2}\u{009b}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a1}\u{008f}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n${c6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{009f}\u{00e2}\u{00a3}\u{00a1}\u{00e2}\u{00a0}\u{008a}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0083}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;36m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00b4}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{008a}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0083}\u{00e2}\u{00a3}\u{00b4}\u{00e2}\u{00a3}\u{00a7}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a3}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;31m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a1}\u{009f}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a2}\u{008b}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a0}\u{00bf}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a2}\u{0081}\u{00e2}\u{00a3}\u{00ad}\u{00e2}\u{00a3}\u{00bd}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;33m  \u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080} \u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a0}\u{0096}\u{00e2}\u{00a1}\u{00ad}\u{00e2}\u{00a2}\u{0089}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{009f}\u{00e2}\u{00a3}\u{00a7}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a2}\u{0089}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;32m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00b4}\u{00e2}\u{00a3}\u{00ab}\u{00e2}\u{00a0}\u{0093}\u{00e2}\u{00a2}\u{00b1}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a2}\u{009b}\u{00e2}\u{00a0}\u{009f}\u{00e2}\u{00a0}\u{00af}\u{00e2}\u{00a0}\u{00b6}\u{00e2}\u{00a2}\u{009f}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;36m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a1}\u{00ae}\u{00e2}\u{00a2}\u{0081}\u{00e2}\u{00a3}\u{00b4}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0096}\u{00e2}\u{00a3}\u{00a0}\u{00e2}\u{00a0}\u{0090}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;35m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00b7}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{00bf}\u{00e2}\u{00a2}\u{009b}\u{00e2}\u{00a3}\u{00ad}\u{00e2}\u{00a0}\u{0092}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{0080}\u{00e2}\u{00a3}\u{0080}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00b4}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{00bf}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a0}\u{0081}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;31m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a0}\u{008f}\u{00e2}\u{00a0}\u{009f}\u{00e2}\u{00a0}\u{009d}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{009f}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a7}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n${c6}\u{00e2}\u{00a2}\u{00b4}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a1}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0088}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a1}\u{0080}\u{00e2}\u{00a3}\u{009f}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;32m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a7}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{0086}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0083}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a1}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{00bb}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;35m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00ae}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00bc}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00af}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00b7}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0088}\u{00e2}\u{00a2}\u{00bb}\u{00e2}\u{00a3}\u{0086}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;36m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{00a2}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a2}\u{00a1}\u{00e2}\u{00a0}\u{0083}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00a4}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n${c6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{0080}\u{00e2}\u{00a2}\u{008b}\u{00e2}\u{00a1}\u{009f}\u{00e2}\u{00a2}\u{00a0}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{008b}\u{00e2}\u{00a2}\u{00bf}\u{00e2}\u{00a3}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0088}\u{00e2}\u{00a1}\u{0084}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a3}\u{00b6}\u{00e2}\u{00a3}\u{0088}\u{00e2}\u{00a1}\u{0084}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;31m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0090}\u{00e2}\u{00a0}\u{009a}\u{00e2}\u{00a2}\u{00b2}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0081}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a2}\u{00b7}\u{00e2}\u{00a1}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{0087}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0088}\u{00e2}\u{00a0}\u{00bb}\u{00e2}\u{00a1}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;33m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{00a2}\u{00e2}\u{00a3}\u{0080}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a1}\u{008f}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a1}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a3}\u{00a6}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{00a7}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;35m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a2}\u{00b8}\u{00e2}\u{00a0}\u{00bf}\u{00e2}\u{00a3}\u{00a7}\u{00e2}\u{00a3}\u{00be}\u{00e2}\u{00a3}\u{00bf}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a3}\u{00ae}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[1;32m\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0089}\u{00e2}\u{00a0}\u{0099}\u{00e2}\u{00a0}\u{009b}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\n\x1b[0m",
+        ascii_art: "\n\x1b[1;35m\u{2800}\u{2800}\u{2800}\u{2800}  \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28a0}\u{2822}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;31m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28f6}\u{280b}\u{2846}\u{28b9}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;32m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{2846}\u{2880}\u{28e4}\u{289b}\u{281b}\u{28e0}\u{28ff}\u{2800}\u{284f}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28f6}\u{28ff}\u{281f}\u{28e1}\u{280a}\u{28e0}\u{28fe}\u{28ff}\u{2803}\u{28e0}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;36m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28f4}\u{28ef}\u{28ff}\u{2800}\u{280a}\u{28e4}\u{28ff}\u{28ff}\u{28ff}\u{2803}\u{28f4}\u{28e7}\u{28c4}\u{28c0}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;31m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28e4}\u{28f6}\u{28ff}\u{28ff}\u{285f}\u{28e0}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{288b}\u{28e4}\u{283f}\u{281b}\u{2809}\u{2881}\u{28ed}\u{28fd}\u{280b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;33m  \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800} \u{2800}\u{28e0}\u{2816}\u{286d}\u{2889}\u{28ff}\u{28ef}\u{28ff}\u{28ef}\u{28ff}\u{28ff}\u{28ff}\u{28df}\u{28e7}\u{281b}\u{2889}\u{28e4}\u{28f6}\u{28fe}\u{28ff}\u{28ff}\u{280b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;32m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28f4}\u{28eb}\u{2813}\u{28b1}\u{28ef}\u{28ff}\u{28bf}\u{280b}\u{281b}\u{289b}\u{281f}\u{282f}\u{2836}\u{289f}\u{28ff}\u{28ef}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e6}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;36m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{286e}\u{2881}\u{28f4}\u{28ff}\u{28ff}\u{28ff}\u{2816}\u{28e0}\u{2810}\u{2809}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2809}\u{2809}\u{281b}\u{281b}\u{281b}\u{28bf}\u{28f6}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;35m\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28e4}\u{28f7}\u{28ff}\u{28ff}\u{283f}\u{289b}\u{28ed}\u{2812}\u{2809}\u{2800}\u{2800}\u{2800}\u{28c0}\u{28c0}\u{28c4}\u{28e4}\u{28e4}\u{28f4}\u{28f6}\u{28f6}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{283f}\u{280b}\u{2801}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;31m\u{2800}\u{2880}\u{28f6}\u{280f}\u{281f}\u{281d}\u{2809}\u{2880}\u{28e4}\u{28ff}\u{28ff}\u{28f6}\u{28fe}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28df}\u{28bf}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e7}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{28b4}\u{28ef}\u{28e4}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{287f}\u{28ff}\u{28ef}\u{2809}\u{2809}\u{2809}\u{2809}\u{2800}\u{2800}\u{2800}\u{2808}\u{28ff}\u{2840}\u{28df}\u{28ff}\u{28ff}\u{28bf}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;32m\u{2800}\u{2800}\u{2800}\u{2809}\u{281b}\u{28ff}\u{28e7}\u{2800}\u{28c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28ff}\u{2803}\u{28ff}\u{28ff}\u{28ef}\u{28ff}\u{28e6}\u{2840}\u{2800}\u{2809}\u{283b}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;35m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{28bf}\u{28ee}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28fc}\u{28ff}\u{2800}\u{28ef}\u{2809}\u{2809}\u{281b}\u{28bf}\u{28ff}\u{28f7}\u{28c4}\u{2800}\u{2808}\u{28bb}\u{28c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;36m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2822}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28a1}\u{2803}\u{28fe}\u{28ff}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2819}\u{28bf}\u{28ff}\u{28e4}\u{2800}\u{2819}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{288b}\u{285f}\u{28a0}\u{28ff}\u{28ff}\u{28ff}\u{280b}\u{28bf}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2808}\u{2844}\u{2819}\u{28f6}\u{28c8}\u{2844}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;31m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2810}\u{281a}\u{28b2}\u{28ff}\u{2800}\u{28fe}\u{28ff}\u{28ff}\u{2801}\u{2800}\u{2800}\u{2809}\u{28b7}\u{2840}\u{2800}\u{2800}\u{28c7}\u{2800}\u{2800}\u{2808}\u{283b}\u{2840}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;33m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28a2}\u{28c0}\u{28ff}\u{284f}\u{2800}\u{28ff}\u{287f}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2819}\u{28e6}\u{2800}\u{28a7}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;35m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28b8}\u{283f}\u{28e7}\u{28fe}\u{28ff}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2819}\u{28ee}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[1;32m\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2819}\u{281b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\n\x1b[0m",
+        raw_art: "\n${c3}\u{2800}\u{2800}\u{2800}\u{2800}  \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28a0}\u{2822}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c1}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28f6}\u{280b}\u{2846}\u{28b9}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c5}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{2846}\u{2880}\u{28e4}\u{289b}\u{281b}\u{28e0}\u{28ff}\u{2800}\u{284f}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28f6}\u{28ff}\u{281f}\u{28e1}\u{280a}\u{28e0}\u{28fe}\u{28ff}\u{2803}\u{28e0}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c2}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28f4}\u{28ef}\u{28ff}\u{2800}\u{280a}\u{28e4}\u{28ff}\u{28ff}\u{28ff}\u{2803}\u{28f4}\u{28e7}\u{28c4}\u{28c0}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c1}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28e4}\u{28f6}\u{28ff}\u{28ff}\u{285f}\u{28e0}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{288b}\u{28e4}\u{283f}\u{281b}\u{2809}\u{2881}\u{28ed}\u{28fd}\u{280b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c4}  \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800} \u{2800}\u{28e0}\u{2816}\u{286d}\u{2889}\u{28ff}\u{28ef}\u{28ff}\u{28ef}\u{28ff}\u{28ff}\u{28ff}\u{28df}\u{28e7}\u{281b}\u{2889}\u{28e4}\u{28f6}\u{28fe}\u{28ff}\u{28ff}\u{280b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c5}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28f4}\u{28eb}\u{2813}\u{28b1}\u{28ef}\u{28ff}\u{28bf}\u{280b}\u{281b}\u{289b}\u{281f}\u{282f}\u{2836}\u{289f}\u{28ff}\u{28ef}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e6}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c2}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{286e}\u{2881}\u{28f4}\u{28ff}\u{28ff}\u{28ff}\u{2816}\u{28e0}\u{2810}\u{2809}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2809}\u{2809}\u{281b}\u{281b}\u{281b}\u{28bf}\u{28f6}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c3}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28e4}\u{28f7}\u{28ff}\u{28ff}\u{283f}\u{289b}\u{28ed}\u{2812}\u{2809}\u{2800}\u{2800}\u{2800}\u{28c0}\u{28c0}\u{28c4}\u{28e4}\u{28e4}\u{28f4}\u{28f6}\u{28f6}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{283f}\u{280b}\u{2801}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c1}\u{2800}\u{2880}\u{28f6}\u{280f}\u{281f}\u{281d}\u{2809}\u{2880}\u{28e4}\u{28ff}\u{28ff}\u{28f6}\u{28fe}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28df}\u{28bf}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e7}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{28b4}\u{28ef}\u{28e4}\u{28f6}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{287f}\u{28ff}\u{28ef}\u{2809}\u{2809}\u{2809}\u{2809}\u{2800}\u{2800}\u{2800}\u{2808}\u{28ff}\u{2840}\u{28df}\u{28ff}\u{28ff}\u{28bf}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c5}\u{2800}\u{2800}\u{2800}\u{2809}\u{281b}\u{28ff}\u{28e7}\u{2800}\u{28c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28ff}\u{2803}\u{28ff}\u{28ff}\u{28ef}\u{28ff}\u{28e6}\u{2840}\u{2800}\u{2809}\u{283b}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c3}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{28bf}\u{28ee}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28fc}\u{28ff}\u{2800}\u{28ef}\u{2809}\u{2809}\u{281b}\u{28bf}\u{28ff}\u{28f7}\u{28c4}\u{2800}\u{2808}\u{28bb}\u{28c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c2}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2822}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{28a1}\u{2803}\u{28fe}\u{28ff}\u{28ff}\u{28e6}\u{2800}\u{2800}\u{2800}\u{2819}\u{28bf}\u{28ff}\u{28e4}\u{2800}\u{2819}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c6}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2880}\u{288b}\u{285f}\u{28a0}\u{28ff}\u{28ff}\u{28ff}\u{280b}\u{28bf}\u{28c4}\u{2800}\u{2800}\u{2800}\u{2808}\u{2844}\u{2819}\u{28f6}\u{28c8}\u{2844}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c1}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2810}\u{281a}\u{28b2}\u{28ff}\u{2800}\u{28fe}\u{28ff}\u{28ff}\u{2801}\u{2800}\u{2800}\u{2809}\u{28b7}\u{2840}\u{2800}\u{2800}\u{28c7}\u{2800}\u{2800}\u{2808}\u{283b}\u{2840}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c4}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28a2}\u{28c0}\u{28ff}\u{284f}\u{2800}\u{28ff}\u{287f}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2819}\u{28e6}\u{2800}\u{28a7}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c3}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{28b8}\u{283f}\u{28e7}\u{28fe}\u{28ff}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2819}\u{28ee}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n${c5}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2809}\u{2819}\u{281b}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\n",
+        colors: &[Color::Palette(1), Color::Palette(6), Color::Palette(5), Color::Palette(3), Color::Palette(2)],
         max_line_length: 43,
     },
     Logo {
         name: "Darwin",
         is_wildcard: false,
         ascii_art: "\x1b[1;32m                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n\x1b[1;33m .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n\x1b[1;31m;MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n\x1b[1;31m.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n \x1b[1;35m'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    \x1b[1;34mkMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n\x1b[0m",
+        raw_art: "${c1}                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n${c2} .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n${c3};MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n${c4}.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n ${c5}'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    ${c6}kMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n",
+        colors: &[Color::Palette(2), Color::Palette(3), Color::Palette(1), Color::Palette(1), Color::Palette(5), Color::Palette(4)],
         max_line_length: 30,
     },
     Logo {
         name: "Darwin",
         is_wildcard: false,
         ascii_art: "\x1b[1;32m                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n\x1b[1;33m .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n\x1b[1;31m;MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n\x1b[1;31m.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n \x1b[1;35m'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    \x1b[1;34mkMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n\x1b[0m",
+        raw_art: "${c1}                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n${c2} .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n${c3};MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n${c4}.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n ${c5}'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    ${c6}kMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n",
+        colors: &[Color::Palette(2), Color::Palette(3), Color::Palette(1), Color::Palette(1), Color::Palette(5), Color::Palette(4)],
         max_line_length: 30,
     },
     Logo {
         name: "Debian",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m       _,met$$$$$gg.\n    ,g$$$$$$$$$$$$$$$P.\n  ,g$$P\"        \"\"\"Y$$.\".\n ,$$P'              `$$$.\n',$$P       ,ggs.     `$$b:\n`d$$'     ,$P\"'   \x1b[1;31m.\x1b[1;37m    $$$\n $$P      d$'     \x1b[1;31m,\x1b[1;37m    $$P\n $$:      $$.   \x1b[1;31m-\x1b[1;37m    ,d$$'\n $$;      Y$b._   _,d$P'\n Y$$.    \x1b[1;31m`.\x1b[1;37m`\"Y$$$$P\"'\n\x1b[1;37m `$$b      \x1b[1;31m\"-.__\n\x1b[1;37m  `Y$$\n   `Y$$.\n     `$$b.\n       `Y$$b.\n          `\"Y$b._\n              `\"\"\"\n\x1b[0m",
+        raw_art: "${c2}       _,met$$$$$gg.\n    ,g$$$$$$$$$$$$$$$P.\n  ,g$$P\"        \"\"\"Y$$.\".\n ,$$P'              `$$$.\n',$$P       ,ggs.     `$$b:\n`d$$'     ,$P\"'   ${c1}.${c2}    $$$\n $$P      d$'     ${c1},${c2}    $$P\n $$:      $$.   ${c1}-${c2}    ,d$$'\n $$;      Y$b._   _,d$P'\n Y$$.    ${c1}`.${c2}`\"Y$$$$P\"'\n${c2} `$$b      ${c1}\"-.__\n${c2}  `Y$$\n   `Y$$.\n     `$$b.\n       `Y$$b.\n          `\"Y$b._\n              `\"\"\"\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 27,
     },
     Logo {
         name: "Deepin",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m             ............\n         .';;;;;.       .,;,.\n      .,;;;;;;;.       ';;;;;;;.\n    .;::::::::'     .,::;;,''''',.\n   ,'.::::::::    .;;'.          ';\n  ;'  'cccccc,   ,' :: '..        .:\n ,,    :ccccc.  ;: .c, '' :.       ,;\n.l.     cllll' ., .lc  :; .l'       l.\n.c       :lllc  ;cl:  .l' .ll.      :'\n.l        'looc. .   ,o:  'oo'      c,\n.o.         .:ool::coc'  .ooo'      o.\n ::            .....   .;dddo      ;c\n  l:...            .';lddddo.     ,o\n   lxxxxxdoolllodxxxxxxxxxc      :l\n    ,dxxxxxxxxxxxxxxxxxxl.     'o,\n      ,dkkkkkkkkkkkkko;.    .;o;\n        .;okkkkkdl;.    .,cl:.\n            .,:cccccccc:,.\n\x1b[0m",
+        raw_art: "${c1}             ............\n         .';;;;;.       .,;,.\n      .,;;;;;;;.       ';;;;;;;.\n    .;::::::::'     .,::;;,''''',.\n   ,'.::::::::    .;;'.          ';\n  ;'  'cccccc,   ,' :: '..        .:\n ,,    :ccccc.  ;: .c, '' :.       ,;\n.l.     cllll' ., .lc  :; .l'       l.\n.c       :lllc  ;cl:  .l' .ll.      :'\n.l        'looc. .   ,o:  'oo'      c,\n.o.         .:ool::coc'  .ooo'      o.\n ::            .....   .;dddo      ;c\n  l:...            .';lddddo.     ,o\n   lxxxxxdoolllodxxxxxxxxxc      :l\n    ,dxxxxxxxxxxxxxxxxxxl.     'o,\n      ,dkkkkkkkkkkkkko;.    .;o;\n        .;okkkkkdl;.    .,cl:.\n            .,:cccccccc:,.\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 38,
     },
     Logo {
         name: "DesaOS",
         is_wildcard: false,
-        ascii_art: "\x1b[1;32m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\x1b[0m",
+        ascii_art: "\x1b[1;32m\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\x1b[0m",
+        raw_art: "${c1}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 30,
     },
     Logo {
         name: "Devuan",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m   ..,,;;;::;,..\n           `':ddd;:,.\n                 `'dPPd:,.\n                     `:b$$b`.\n                        'P$$$d`\n                         .$$$$$`\n                         ;$$$$$P\n                      .:P$$$$$$`\n                  .,:b$$$$$$$;'\n             .,:dP$$$$$$$$b:'\n      .,:;db$$$$$$$$$$Pd'`\n ,db$$$$$$$$$$$$$$b:'`\n:$$$$$$$$$$$$b:'`\n `$$$$$bd:''`\n   `'''`\n\x1b[0m",
+        raw_art: "${c1}   ..,,;;;::;,..\n           `':ddd;:,.\n                 `'dPPd:,.\n                     `:b$$b`.\n                        'P$$$d`\n                         .$$$$$`\n                         ;$$$$$P\n                      .:P$$$$$$`\n                  .,:b$$$$$$$;'\n             .,:dP$$$$$$$$b:'\n      .,:;db$$$$$$$$$$Pd'`\n ,db$$$$$$$$$$$$$$b:'`\n:$$$$$$$$$$$$b:'`\n `$$$$$bd:''`\n   `'''`\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 32,
     },
     Logo {
         name: "DracOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m       `-:/-\n          -os:\n            -os/`\n              :sy+-`\n               `/yyyy+.\n                 `+yyyyo-\n                   `/yyyys:\n`:osssoooo++-        +yyyyyy/`\n   ./yyyyyyo         yo`:syyyy+.\n      -oyyy+         +-   :yyyyyo-\n        `:sy:        `.    `/yyyyys:\n           ./o/.`           .oyyso+oo:`\n              :+oo+//::::///:-.`     `.`\n\x1b[0m",
+        raw_art: "${c1}       `-:/-\n          -os:\n            -os/`\n              :sy+-`\n               `/yyyy+.\n                 `+yyyyo-\n                   `/yyyys:\n`:osssoooo++-        +yyyyyy/`\n   ./yyyyyyo         yo`:syyyy+.\n      -oyyy+         +-   :yyyyyo-\n        `:sy:        `.    `/yyyyys:\n           ./o/.`           .oyyso+oo:`\n              :+oo+//::::///:-.`     `.`\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "DragonFly",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m,--,           \x1b[1;31m|           \x1b[1;37m,--,\n\x1b[1;37m|   `-,       \x1b[1;31m,^,       \x1b[1;37m,-'   |\n\x1b[1;37m `,    `-,   \x1b[1;33m(/ \\)   \x1b[1;37m,-'    ,'\n\x1b[1;37m   `-,    `-,\x1b[1;31m/   \\\x1b[1;37m,-'    ,-'\n\x1b[1;37m      `------\x1b[1;31m(   )\x1b[1;37m------'\n\x1b[1;37m  ,----------\x1b[1;31m(   )\x1b[1;37m----------,\n\x1b[1;37m |        _,-\x1b[1;31m(   )\x1b[1;37m-,_        |\n\x1b[1;37m  `-,__,-'   \x1b[1;31m\\   /\x1b[1;37m   `-,__,-'\n\x1b[1;31m              | |\n              | |\n              | |\n              | |\n              | |\n              | |\n              `|'\n\x1b[0m",
+        raw_art: "${c2},--,           ${c1}|           ${c2},--,\n${c2}|   `-,       ${c1},^,       ${c2},-'   |\n${c2} `,    `-,   ${c3}(/ \\)   ${c2},-'    ,'\n${c2}   `-,    `-,${c1}/   \\${c2},-'    ,-'\n${c2}      `------${c1}(   )${c2}------'\n${c2}  ,----------${c1}(   )${c2}----------,\n${c2} |        _,-${c1}(   )${c2}-,_        |\n${c2}  `-,__,-'   ${c1}\\   /${c2}   `-,__,-'\n${c1}              | |\n              | |\n              | |\n              | |\n              | |\n              | |\n              `|'\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 31,
     },
     Logo {
         name: "Drauger",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                  -``-\n                `:+``+:`\n               `/++``++/.\n              .++/.  ./++.\n             :++/`    `/++:\n           `/++:        :++/`\n          ./+/-          -/+/.\n         -++/.            ./++-\n        :++:`              `:++:\n      `/++-                  -++/`\n     ./++.                    ./+/.\n    -++/`                      `/++-\n   :++:`                        `:++:\n `/++-                            -++/`\n.:-.`..............................`.-:.\n`.-/++++++++++++++++++++++++++++++++/-.`\n\x1b[0m",
+        raw_art: "${c1}                  -``-\n                `:+``+:`\n               `/++``++/.\n              .++/.  ./++.\n             :++/`    `/++:\n           `/++:        :++/`\n          ./+/-          -/+/.\n         -++/.            ./++-\n        :++:`              `:++:\n      `/++-                  -++/`\n     ./++.                    ./+/.\n    -++/`                      `/++-\n   :++:`                        `:++:\n `/++-                            -++/`\n.:-.`..............................`.-:.\n`.-/++++++++++++++++++++++++++++++++/-.`\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Elementary",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m         eeeeeeeeeeeeeeeee\n      eeeeeeeeeeeeeeeeeeeeeee\n    eeeee  eeeeeeeeeeee   eeeee\n  eeee   eeeee       eee     eeee\n eeee   eeee          eee     eeee\neee    eee            eee       eee\neee   eee            eee        eee\nee    eee           eeee       eeee\nee    eee         eeeee      eeeeee\nee    eee       eeeee      eeeee ee\neee   eeee   eeeeee      eeeee  eee\neee    eeeeeeeeee     eeeeee    eee\n eeeeeeeeeeeeeeeeeeeeeeee    eeeee\n  eeeeeeee eeeeeeeeeeee      eeee\n    eeeee                 eeeee\n      eeeeeee         eeeeeee\n         eeeeeeeeeeeeeeeee\n\x1b[0m",
+        raw_art: "${c2}         eeeeeeeeeeeeeeeee\n      eeeeeeeeeeeeeeeeeeeeeee\n    eeeee  eeeeeeeeeeee   eeeee\n  eeee   eeeee       eee     eeee\n eeee   eeee          eee     eeee\neee    eee            eee       eee\neee   eee            eee        eee\nee    eee           eeee       eeee\nee    eee         eeeee      eeeeee\nee    eee       eeeee      eeeee ee\neee   eeee   eeeeee      eeeee  eee\neee    eeeeeeeeee     eeeeee    eee\n eeeeeeeeeeeeeeeeeeeeeeee    eeeee\n  eeeeeeee eeeeeeeeeeee      eeee\n    eeeee                 eeeee\n      eeeeeee         eeeeeee\n         eeeeeeeeeeeeeeeee\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "EndeavourOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                     ./\x1b[1;35mo\x1b[1;34m.\n\x1b[1;31m                   ./\x1b[1;35msssso\x1b[1;34m-\n\x1b[1;31m                 `:\x1b[1;35mosssssss+\x1b[1;34m-\n\x1b[1;31m               `:+\x1b[1;35msssssssssso\x1b[1;34m/.\n\x1b[1;31m             `-/o\x1b[1;35mssssssssssssso\x1b[1;34m/.\n\x1b[1;31m           `-/+\x1b[1;35msssssssssssssssso\x1b[1;34m+:`\n\x1b[1;31m         `-:/+\x1b[1;35msssssssssssssssssso\x1b[1;34m+/.\n\x1b[1;31m       `.://o\x1b[1;35msssssssssssssssssssso\x1b[1;34m++-\n\x1b[1;31m      .://+\x1b[1;35mssssssssssssssssssssssso\x1b[1;34m++:\n\x1b[1;31m    .:///o\x1b[1;35mssssssssssssssssssssssssso\x1b[1;34m++:\n\x1b[1;31m  `:////\x1b[1;35mssssssssssssssssssssssssssso\x1b[1;34m+++.\n\x1b[1;31m`-////+\x1b[1;35mssssssssssssssssssssssssssso\x1b[1;34m++++-\n\x1b[1;31m `..-+\x1b[1;35moosssssssssssssssssssssssso\x1b[1;34m+++++/`\n   ./++++++++++++++++++++++++++++++/:.\n  `:::::::::::::::::::::::::------``\n\x1b[0m",
+        raw_art: "${c1}                     ./${c2}o${c3}.\n${c1}                   ./${c2}sssso${c3}-\n${c1}                 `:${c2}osssssss+${c3}-\n${c1}               `:+${c2}sssssssssso${c3}/.\n${c1}             `-/o${c2}ssssssssssssso${c3}/.\n${c1}           `-/+${c2}sssssssssssssssso${c3}+:`\n${c1}         `-:/+${c2}sssssssssssssssssso${c3}+/.\n${c1}       `.://o${c2}sssssssssssssssssssso${c3}++-\n${c1}      .://+${c2}ssssssssssssssssssssssso${c3}++:\n${c1}    .:///o${c2}ssssssssssssssssssssssssso${c3}++:\n${c1}  `:////${c2}ssssssssssssssssssssssssssso${c3}+++.\n${c1}`-////+${c2}ssssssssssssssssssssssssssso${c3}++++-\n${c1} `..-+${c2}oosssssssssssssssssssssssso${c3}+++++/`\n   ./++++++++++++++++++++++++++++++/:.\n  `:::::::::::::::::::::::::------``\n",
+        colors: &[Color::Palette(1), Color::Palette(5), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "Endless",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m           `:+yhmNMMMMNmhy+:`\n        -odMMNhso//////oshNMMdo-\n      /dMMh+.              .+hMMd/\n    /mMNo`                    `oNMm:\n  `yMMo`                        `oMMy`\n `dMN-                            -NMd`\n hMN.                              .NMh\n/MM/                  -os`          /MM/\ndMm    `smNmmhs/- `:sNMd+   ``       mMd\nMMy    oMd--:+yMMMMMNo.:ohmMMMNy`    yMM\nMMy    -NNyyhmMNh+oNMMMMMy:.  dMo    yMM\ndMm     `/++/-``/yNNh+/sdNMNddMm-    mMd\n/MM/          `dNy:       `-::-     /MM/\n hMN.                              .NMh\n `dMN-                            -NMd`\n  `yMMo`                        `oMMy`\n    /mMNo`                    `oNMm/\n      /dMMh+.              .+hMMd/\n        -odMMNhso//////oshNMMdo-\n           `:+yhmNMMMMNmhy+:`\n\x1b[0m",
+        raw_art: "${c1}           `:+yhmNMMMMNmhy+:`\n        -odMMNhso//////oshNMMdo-\n      /dMMh+.              .+hMMd/\n    /mMNo`                    `oNMm:\n  `yMMo`                        `oMMy`\n `dMN-                            -NMd`\n hMN.                              .NMh\n/MM/                  -os`          /MM/\ndMm    `smNmmhs/- `:sNMd+   ``       mMd\nMMy    oMd--:+yMMMMMNo.:ohmMMMNy`    yMM\nMMy    -NNyyhmMNh+oNMMMMMy:.  dMo    yMM\ndMm     `/++/-``/yNNh+/sdNMNddMm-    mMd\n/MM/          `dNy:       `-::-     /MM/\n hMN.                              .NMh\n `dMN-                            -NMd`\n  `yMMo`                        `oMMy`\n    /mMNo`                    `oNMm/\n      /dMMh+.              .+hMMd/\n        -odMMNhso//////oshNMMdo-\n           `:+yhmNMMMMNmhy+:`\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "EuroLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                __\n         -wwwWWWWWWWWWwww-\n        -WWWWWWWWWWWWWWWWWWw-\n          \\WWWWWWWWWWWWWWWWWWW-\n  _Ww      `WWWWWWWWWWWWWWWWWWWw\n -W\x1b[1;37mE\x1b[1;34mWww                -WWWWWWWWW-\n_WW\x1b[1;37mU\x1b[1;34mWWWW-                _WWWWWWWW\n_WW\x1b[1;37mR\x1b[1;34mWWWWWWWWWWWWWWWWWWWWWWWWWWWWWW-\nwWW\x1b[1;37mO\x1b[1;34mWWWWWWWWWWWWWWWWWWWWWWWWWWWWWWW\nWWW\x1b[1;37mL\x1b[1;34mWWWWWWWWWWWWWWWWWWWWWWWWWWWWWWw\nWWW\x1b[1;37mI\x1b[1;34mWWWWWWWWWWWWWWWWWWWWWWWWWWWWww-\nwWW\x1b[1;37mN\x1b[1;34mWWWWw\n WW\x1b[1;37mU\x1b[1;34mWWWWWWw\n wW\x1b[1;37mX\x1b[1;34mWWWWWWWWww\n   wWWWWWWWWWWWWWWWw\n    wWWWWWWWWWWWWWWWw\n       WWWWWWWWWWWWWw\n           wWWWWWWWw\n\x1b[0m",
+        raw_art: "${c1}                __\n         -wwwWWWWWWWWWwww-\n        -WWWWWWWWWWWWWWWWWWw-\n          \\WWWWWWWWWWWWWWWWWWW-\n  _Ww      `WWWWWWWWWWWWWWWWWWWw\n -W${c2}E${c1}Www                -WWWWWWWWW-\n_WW${c2}U${c1}WWWW-                _WWWWWWWW\n_WW${c2}R${c1}WWWWWWWWWWWWWWWWWWWWWWWWWWWWWW-\nwWW${c2}O${c1}WWWWWWWWWWWWWWWWWWWWWWWWWWWWWWW\nWWW${c2}L${c1}WWWWWWWWWWWWWWWWWWWWWWWWWWWWWWw\nWWW${c2}I${c1}WWWWWWWWWWWWWWWWWWWWWWWWWWWWww-\nwWW${c2}N${c1}WWWWw\n WW${c2}U${c1}WWWWWWw\n wW${c2}X${c1}WWWWWWWWww\n   wWWWWWWWWWWWWWWWw\n    wWWWWWWWWWWWWWWWw\n       WWWWWWWWWWWWWw\n           wWWWWWWWw\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "Exherbo",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m ,\nOXo.\nNXdX0:    .cok0KXNNXXK0ko:.\nKX  '0XdKMMK;.xMMMk, .0MMMMMXx;  ...\n'NO..xWkMMx   kMMM    cMMMMMX,NMWOxOXd.\n  cNMk  NK    .oXM.   OMMMMO. 0MMNo  kW.\n  lMc   o:       .,   .oKNk;   ;NMMWlxW'\n ;Mc    ..   .,,'    .0M\x1b[1;34mg;\x1b[1;37mWMN'dWMMMMMMO\n XX        ,WMMMMW.  cM\x1b[1;34mcfli\x1b[1;37mWMKlo.   .kMk\n.Mo        .WM\x1b[1;34mGD\x1b[1;37mMW.   XM\x1b[1;34mWO0\x1b[1;37mMMk        oMl\n,M:         ,XMMWx::,''oOK0x;          NM.\n'Ml      ,kNKOxxxxxkkO0XXKOd:.         oMk\n NK    .0Nxc\x1b[1;31m:::::::::::::::\x1b[1;37mfkKNk,      .MW\n ,Mo  .NXc\x1b[1;31m::\x1b[1;37mqXWXb\x1b[1;31m::::::::::\x1b[1;37moo\x1b[1;31m::\x1b[1;37mlNK.    .MW\n  ;Wo oMd\x1b[1;31m:::\x1b[1;37moNMNP\x1b[1;31m::::::::\x1b[1;37moWMMMx\x1b[1;31m:\x1b[1;37mc0M;   lMO\n   'NO;W0c\x1b[1;31m:::::::::::::::\x1b[1;37mdMMMMO\x1b[1;31m::\x1b[1;37mlMk  .WM'\n     xWONXdc\x1b[1;31m::::::::::::::\x1b[1;37moOOo\x1b[1;31m::\x1b[1;37mlXN. ,WMd\n      'KWWNXXK0Okxxo,\x1b[1;31m:::::::\x1b[1;37m,lkKNo  xMMO\n        :XMNxl,';:lodxkOO000Oxc. .oWMMo\n          'dXMMXkl;,.        .,o0MMNo'\n             ':d0XWMMMMWNNNNMMMNOl'\n                   ':okKXWNKkl'\n\x1b[0m",
+        raw_art: "${c2} ,\nOXo.\nNXdX0:    .cok0KXNNXXK0ko:.\nKX  '0XdKMMK;.xMMMk, .0MMMMMXx;  ...\n'NO..xWkMMx   kMMM    cMMMMMX,NMWOxOXd.\n  cNMk  NK    .oXM.   OMMMMO. 0MMNo  kW.\n  lMc   o:       .,   .oKNk;   ;NMMWlxW'\n ;Mc    ..   .,,'    .0M${c1}g;${c2}WMN'dWMMMMMMO\n XX        ,WMMMMW.  cM${c1}cfli${c2}WMKlo.   .kMk\n.Mo        .WM${c1}GD${c2}MW.   XM${c1}WO0${c2}MMk        oMl\n,M:         ,XMMWx::,''oOK0x;          NM.\n'Ml      ,kNKOxxxxxkkO0XXKOd:.         oMk\n NK    .0Nxc${c3}:::::::::::::::${c2}fkKNk,      .MW\n ,Mo  .NXc${c3}::${c2}qXWXb${c3}::::::::::${c2}oo${c3}::${c2}lNK.    .MW\n  ;Wo oMd${c3}:::${c2}oNMNP${c3}::::::::${c2}oWMMMx${c3}:${c2}c0M;   lMO\n   'NO;W0c${c3}:::::::::::::::${c2}dMMMMO${c3}::${c2}lMk  .WM'\n     xWONXdc${c3}::::::::::::::${c2}oOOo${c3}::${c2}lXN. ,WMd\n      'KWWNXXK0Okxxo,${c3}:::::::${c2},lkKNo  xMMO\n        :XMNxl,';:lodxkOO000Oxc. .oWMMo\n          'dXMMXkl;,.        .,o0MMNo'\n             ':d0XWMMMMWNNNNMMMNOl'\n                   ':okKXWNKkl'\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 42,
     },
     Logo {
         name: "Fedora",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;12m             .',;::::;,'.\n         .';:cccccccccccc:;,.\n      .;cccccccccccccccccccccc;.\n    .:cccccccccccccccccccccccccc:.\n  .;ccccccccccccc;\x1b[1;37m.:dddl:.\x1b[1;38;5;12m;ccccccc;.\n .:ccccccccccccc;\x1b[1;37mOWMKOOXMWd\x1b[1;38;5;12m;ccccccc:.\n.:ccccccccccccc;\x1b[1;37mKMMc\x1b[1;38;5;12m;cc;\x1b[1;37mxMMc\x1b[1;38;5;12m;ccccccc:.\n,cccccccccccccc;\x1b[1;37mMMM.\x1b[1;38;5;12m;cc;\x1b[1;37m;WW:\x1b[1;38;5;12m;cccccccc,\n:cccccccccccccc;\x1b[1;37mMMM.\x1b[1;38;5;12m;cccccccccccccccc:\n:ccccccc;\x1b[1;37moxOOOo\x1b[1;38;5;12m;\x1b[1;37mMMM0OOk.\x1b[1;38;5;12m;cccccccccccc:\ncccccc;\x1b[1;37m0MMKxdd:\x1b[1;38;5;12m;\x1b[1;37mMMMkddc.\x1b[1;38;5;12m;cccccccccccc;\nccccc;\x1b[1;37mXM0'\x1b[1;38;5;12m;cccc;\x1b[1;37mMMM.\x1b[1;38;5;12m;cccccccccccccccc'\nccccc;\x1b[1;37mMMo\x1b[1;38;5;12m;ccccc;\x1b[1;37mMMW.\x1b[1;38;5;12m;ccccccccccccccc;\nccccc;\x1b[1;37m0MNc.\x1b[1;38;5;12mccc\x1b[1;37m.xMMd\x1b[1;38;5;12m;ccccccccccccccc;\ncccccc;\x1b[1;37mdNMWXXXWM0:\x1b[1;38;5;12m;cccccccccccccc:,\ncccccccc;\x1b[1;37m.:odl:.\x1b[1;38;5;12m;cccccccccccccc:,.\n:cccccccccccccccccccccccccccc:'.\n.:cccccccccccccccccccccc:;,..\n  '::cccccccccccccc::;,.\n\x1b[0m",
+        raw_art: "${c1}             .',;::::;,'.\n         .';:cccccccccccc:;,.\n      .;cccccccccccccccccccccc;.\n    .:cccccccccccccccccccccccccc:.\n  .;ccccccccccccc;${c2}.:dddl:.${c1};ccccccc;.\n .:ccccccccccccc;${c2}OWMKOOXMWd${c1};ccccccc:.\n.:ccccccccccccc;${c2}KMMc${c1};cc;${c2}xMMc${c1};ccccccc:.\n,cccccccccccccc;${c2}MMM.${c1};cc;${c2};WW:${c1};cccccccc,\n:cccccccccccccc;${c2}MMM.${c1};cccccccccccccccc:\n:ccccccc;${c2}oxOOOo${c1};${c2}MMM0OOk.${c1};cccccccccccc:\ncccccc;${c2}0MMKxdd:${c1};${c2}MMMkddc.${c1};cccccccccccc;\nccccc;${c2}XM0'${c1};cccc;${c2}MMM.${c1};cccccccccccccccc'\nccccc;${c2}MMo${c1};ccccc;${c2}MMW.${c1};ccccccccccccccc;\nccccc;${c2}0MNc.${c1}ccc${c2}.xMMd${c1};ccccccccccccccc;\ncccccc;${c2}dNMWXXXWM0:${c1};cccccccccccccc:,\ncccccccc;${c2}.:odl:.${c1};cccccccccccccc:,.\n:cccccccccccccccccccccccccccc:'.\n.:cccccccccccccccccccccc:;,..\n  '::cccccccccccccc::;,.\n",
+        colors: &[Color::Palette(12), Color::Palette(7)],
         max_line_length: 38,
     },
+    Logo {
+        name: "Fedora_old",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m          /:-------------:\\\\\n       :-------------------::\n     :-----------\x1b[1;37m/shhOHbmp\x1b[1;34m---:\\\\\n   /-----------\x1b[1;37momMMMNNNMMD  \x1b[1;34m---:\n  :-----------\x1b[1;37msMMMMNMNMP\x1b[1;34m.    ---:\n :-----------\x1b[1;37m:MMMdP\x1b[1;34m-------    ---\\\\\n,------------\x1b[1;37m:MMMd\x1b[1;34m--------    ---:\n:------------\x1b[1;37m:MMMd\x1b[1;34m-------    .---:\n:----    \x1b[1;37moNMMMMMMMMMNho\x1b[1;34m     .----:\n:--     .\x1b[1;37m+shhhMMMmhhy++\x1b[1;34m   .------/\n:-    -------\x1b[1;37m:MMMd\x1b[1;34m--------------:\n:-   --------\x1b[1;37m/MMMd\x1b[1;34m-------------;\n:-    ------\x1b[1;37m/hMMMy\x1b[1;34m------------:\n:--\x1b[1;37m :dMNdhhdNMMNo\x1b[1;34m------------;\n:---\x1b[1;37m:sdNMMMMNds:\x1b[1;34m------------:\n:------\x1b[1;37m:://:\x1b[1;34m-------------::\n:---------------------://\n\x1b[0m",
+        raw_art: "${c1}          /:-------------:\\\\\n       :-------------------::\n     :-----------${c2}/shhOHbmp${c1}---:\\\\\n   /-----------${c2}omMMMNNNMMD  ${c1}---:\n  :-----------${c2}sMMMMNMNMP${c1}.    ---:\n :-----------${c2}:MMMdP${c1}-------    ---\\\\\n,------------${c2}:MMMd${c1}--------    ---:\n:------------${c2}:MMMd${c1}-------    .---:\n:----    ${c2}oNMMMMMMMMMNho${c1}     .----:\n:--     .${c2}+shhhMMMmhhy++${c1}   .------/\n:-    -------${c2}:MMMd${c1}--------------:\n:-   --------${c2}/MMMd${c1}-------------;\n:-    ------${c2}/hMMMy${c1}------------:\n:--${c2} :dMNdhhdNMMNo${c1}------------;\n:---${c2}:sdNMMMMNds:${c1}------------:\n:------${c2}:://:${c1}-------------::\n:---------------------://\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 35,
+    },
     Logo {
         name: "Feren",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m `----------`\n :+ooooooooo+.\n-o+oooooooooo+-\n..`/+++++++++++/...`````````````````\n   .++++++++++++++++++++++++++/////-\n    ++++++++++++++++++++++++++++++++//:`\n    -++++++++++++++++++++++++++++++/-`\n     ++++++++++++++++++++++++++++:.\n     -++++++++++++++++++++++++/.\n      +++++++++++++++++++++/-`\n      -++++++++++++++++++//-`\n        .:+++++++++++++//////-\n           .:++++++++//////////-\n             `-++++++---:::://///.\n           `.:///+++.             `\n          `.........\n\x1b[0m",
+        raw_art: "${c1} `----------`\n :+ooooooooo+.\n-o+oooooooooo+-\n..`/+++++++++++/...`````````````````\n   .++++++++++++++++++++++++++/////-\n    ++++++++++++++++++++++++++++++++//:`\n    -++++++++++++++++++++++++++++++/-`\n     ++++++++++++++++++++++++++++:.\n     -++++++++++++++++++++++++/.\n      +++++++++++++++++++++/-`\n      -++++++++++++++++++//-`\n        .:+++++++++++++//////-\n           .:++++++++//////////-\n             `-++++++---:::://///.\n           `.:///+++.             `\n          `.........\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "FreeMiNT",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m          ##\n          ##         #########\n                    ####      ##\n            ####  ####        ##\n####        ####  ##        ##\n        ####    ####      ##  ##\n        ####  ####  ##  ##  ##\n            ####  ######\n        ######  ##  ##  ####\n      ####    ################\n    ####        ##  ####\n    ##            ####  ######\n    ##      ##    ####  ####\n    ##    ##  ##    ##  ##  ####\n      ####  ##          ##  ##\n\x1b[0m",
+        raw_art: "${c1}          ##\n          ##         #########\n                    ####      ##\n            ####  ####        ##\n####        ####  ##        ##\n        ####    ####      ##  ##\n        ####  ####  ##  ##  ##\n            ####  ######\n        ######  ##  ##  ####\n      ####    ################\n    ####        ##  ####\n    ##            ####  ######\n    ##      ##    ####  ####\n    ##    ##  ##    ##  ##  ####\n      ####  ##          ##  ##\n",
+        colors: &[Color::Palette(7)],
         max_line_length: 32,
     },
     Logo {
         name: "Frugalware",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m          `++/::-.`\n         /o+++++++++/::-.`\n        `o+++++++++++++++o++/::-.`\n        /+++++++++++++++++++++++oo++/:-.``\n       .o+ooooooooooooooooooosssssssso++oo++/:-`\n       ++osoooooooooooosssssssssssssyyo+++++++o:\n      -o+ssoooooooooooosssssssssssssyyo+++++++s`\n      o++ssoooooo++++++++++++++sssyyyyo++++++o:\n     :o++ssoooooo\x1b[1;37m/-------------\x1b[1;34m+syyyyyo+++++oo\n    `o+++ssoooooo\x1b[1;37m/-----\x1b[1;34m+++++ooosyyyyyyo++++os:\n    /o+++ssoooooo\x1b[1;37m/-----\x1b[1;34mooooooosyyyyyyyo+oooss\n   .o++++ssooooos\x1b[1;37m/------------\x1b[1;34msyyyyyyhsosssy-\n   ++++++ssooooss\x1b[1;37m/-----\x1b[1;34m+++++ooyyhhhhhdssssso\n  -s+++++syssssss\x1b[1;37m/-----\x1b[1;34myyhhhhhhhhhhhddssssy.\n  sooooooyhyyyyyh\x1b[1;37m/-----\x1b[1;34mhhhhhhhhhhhddddyssy+\n :yooooooyhyyyhhhyyyyyyhhhhhhhhhhdddddyssy`\n yoooooooyhyyhhhhhhhhhhhhhhhhhhhddddddysy/\n-ysooooooydhhhhhhhhhhhddddddddddddddddssy\n .-:/+osssyyyysyyyyyyyyyyyyyyyyyyyyyyssy:\n       ``.-/+oosysssssssssssssssssssssss\n               ``.:/+osyysssssssssssssh.\n                        `-:/+osyyssssyo\n                                .-:+++`\n\x1b[0m",
+        raw_art: "${c1}          `++/::-.`\n         /o+++++++++/::-.`\n        `o+++++++++++++++o++/::-.`\n        /+++++++++++++++++++++++oo++/:-.``\n       .o+ooooooooooooooooooosssssssso++oo++/:-`\n       ++osoooooooooooosssssssssssssyyo+++++++o:\n      -o+ssoooooooooooosssssssssssssyyo+++++++s`\n      o++ssoooooo++++++++++++++sssyyyyo++++++o:\n     :o++ssoooooo${c2}/-------------${c1}+syyyyyo+++++oo\n    `o+++ssoooooo${c2}/-----${c1}+++++ooosyyyyyyo++++os:\n    /o+++ssoooooo${c2}/-----${c1}ooooooosyyyyyyyo+oooss\n   .o++++ssooooos${c2}/------------${c1}syyyyyyhsosssy-\n   ++++++ssooooss${c2}/-----${c1}+++++ooyyhhhhhdssssso\n  -s+++++syssssss${c2}/-----${c1}yyhhhhhhhhhhhddssssy.\n  sooooooyhyyyyyh${c2}/-----${c1}hhhhhhhhhhhddddyssy+\n :yooooooyhyyyhhhyyyyyyhhhhhhhhhhdddddyssy`\n yoooooooyhyyhhhhhhhhhhhhhhhhhhhddddddysy/\n-ysooooooydhhhhhhhhhhhddddddddddddddddssy\n .-:/+osssyyyysyyyyyyyyyyyyyyyyyyyyyyssy:\n       ``.-/+oosysssssssssssssssssssssss\n               ``.:/+osyysssssssssssssh.\n                        `-:/+osyyssssyo\n                                .-:+++`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 48,
     },
     Logo {
         name: "Funtoo",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m   .dKXXd                         .\n  :XXl;:.                      .OXo\n.'OXO''  .''''''''''''''''''''':XNd..'oco.lco,\nxXXXXXX, cXXXNNNXXXXNNXXXXXXXXNNNNKOOK; d0O .k\n  kXX  xXo  KNNN0  KNN.       'xXNo   :c; 'cc.\n  kXX  xNo  KNNN0  KNN. :xxxx. 'NNo\n  kXX  xNo  loooc  KNN. oNNNN. 'NNo\n  kXX  xN0:.       KNN' oNNNX' ,XNk\n  kXX  xNNXNNNNNNNNXNNNNNNNNXNNOxXNX0Xl\n  ...  ......................... .;cc;.\n\x1b[0m",
+        raw_art: "${c1}   .dKXXd                         .\n  :XXl;:.                      .OXo\n.'OXO''  .''''''''''''''''''''':XNd..'oco.lco,\nxXXXXXX, cXXXNNNXXXXNNXXXXXXXXNNNNKOOK; d0O .k\n  kXX  xXo  KNNN0  KNN.       'xXNo   :c; 'cc.\n  kXX  xNo  KNNN0  KNN. :xxxx. 'NNo\n  kXX  xNo  loooc  KNN. oNNNN. 'NNo\n  kXX  xN0:.       KNN' oNNNX' ,XNk\n  kXX  xNNXNNNNNNNNXNNNNNNNNXNNOxXNX0Xl\n  ...  ......................... .;cc;.\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 46,
     },
     Logo {
         name: "GNOME",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                               ,@@@@@@@@,\n                 @@@@@@      @@@@@@@@@@@@\n        ,@@.    @@@@@@@    *@@@@@@@@@@@@\n       @@@@@%   @@@@@@(    @@@@@@@@@@@&\n       @@@@@@    @@@@*     @@@@@@@@@#\n@@@@*   @@@@,              *@@@@@%\n@@@@@.\n @@@@#         @@@@@@@@@@@@@@@@\n         ,@@@@@@@@@@@@@@@@@@@@@@@,\n      ,@@@@@@@@@@@@@@@@@@@@@@@@@@&\n    .@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n    @@@@@@@@@@@@@@@@@@@@@@@@@@@\n   @@@@@@@@@@@@@@@@@@@@@@@@(\n   @@@@@@@@@@@@@@@@@@@@%\n    @@@@@@@@@@@@@@@@\n     @@@@@@@@@@@@*        @@@@@@@@/\n      &@@@@@@@@@@        @@@@@@@@@*\n        @@@@@@@@@@@,    @@@@@@@@@*\n          ,@@@@@@@@@@@@@@@@@@@@&\n              &@@@@@@@@@@@@@@\n                     ...\n\x1b[0m",
+        raw_art: "${c1}                               ,@@@@@@@@,\n                 @@@@@@      @@@@@@@@@@@@\n        ,@@.    @@@@@@@    *@@@@@@@@@@@@\n       @@@@@%   @@@@@@(    @@@@@@@@@@@&\n       @@@@@@    @@@@*     @@@@@@@@@#\n@@@@*   @@@@,              *@@@@@%\n@@@@@.\n @@@@#         @@@@@@@@@@@@@@@@\n         ,@@@@@@@@@@@@@@@@@@@@@@@,\n      ,@@@@@@@@@@@@@@@@@@@@@@@@@@&\n    .@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n    @@@@@@@@@@@@@@@@@@@@@@@@@@@\n   @@@@@@@@@@@@@@@@@@@@@@@@(\n   @@@@@@@@@@@@@@@@@@@@%\n    @@@@@@@@@@@@@@@@\n     @@@@@@@@@@@@*        @@@@@@@@/\n      &@@@@@@@@@@        @@@@@@@@@*\n        @@@@@@@@@@@,    @@@@@@@@@*\n          ,@@@@@@@@@@@@@@@@@@@@&\n              &@@@@@@@@@@@@@@\n                     ...\n",
+        colors: &[Color::Palette(4)],
         max_line_length: 41,
     },
     Logo {
         name: "GNU",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m    _-`````-,           ,- '- .\n  .'   .- - |          | - -.  `.\n /.'  /                     `.   \\\n:/   :      _...   ..._      ``   :\n::   :     /._ .`:'_.._\\.    ||   :\n::    `._ ./  ,`  :    \\ . _.''   .\n`:.      /   |  -.  \\-. \\\\_      /\n  \\:._ _/  .'   .@)  \\@) ` `\\ ,.'\n     _/,--'       .- .\\,-.`--`.\n       ,'/''     (( \\ `  )\n        /'/'  \\    `-'  (\n         '/''  `._,-----'\n          ''/'    .,---'\n           ''/'      ;:\n             ''/''  ''/\n               ''/''/''\n                 '/'/'\n                  `;\n\x1b[0m",
+        raw_art: "${c1}    _-`````-,           ,- '- .\n  .'   .- - |          | - -.  `.\n /.'  /                     `.   \\\n:/   :      _...   ..._      ``   :\n::   :     /._ .`:'_.._\\.    ||   :\n::    `._ ./  ,`  :    \\ . _.''   .\n`:.      /   |  -.  \\-. \\\\_      /\n  \\:._ _/  .'   .@)  \\@) ` `\\ ,.'\n     _/,--'       .- .\\,-.`--`.\n       ,'/''     (( \\ `  )\n        /'/'  \\    `-'  (\n         '/''  `._,-----'\n          ''/'    .,---'\n           ''/'      ;:\n             ''/''  ''/\n               ''/''/''\n                 '/'/'\n                  `;\n",
+        colors: &[Color::Palette(7), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "GalliumOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34msooooooooooooooooooooooooooooooooooooo+:\nyyooooooooooooooooooooooooooooooooo+/:::\nyyysoooooooooooooooooooooooooooo+/::::::\nyyyyyoooooooooooooooooooooooo+/:::::::::\nyyyyyysoooooooooooooooooo++/::::::::::::\nyyyyyyysoooooooooooooo++/:::::::::::::::\nyyyyyyyyysoooooo\x1b[1;37msydddys\x1b[1;34m+/:::::::::::::::\nyyyyyyyyyysooo\x1b[1;37msmMMMMMMMNd\x1b[1;34m+::::::::::::::\nyyyyyyyyyyyyo\x1b[1;37msMMMMMMMMMMMN\x1b[1;34m/:::::::::::::\nyyyyyyyyyyyyy\x1b[1;37mdMMMMMMMMMMMM\x1b[1;34mo//:::::::::::\nyyyyyyyyyyyyy\x1b[1;37mhMMMMMMMMMMMm\x1b[1;34m--//::::::::::\nyyyyyyyyyyyyyy\x1b[1;37mhmMMMMMMMNy\x1b[1;34m:..-://::::::::\nyyyyyyyyyyyyyyy\x1b[1;37myyhhyys+:\x1b[1;34m......://:::::::\nyyyyyyyyyyyyyyys+:--...........-///:::::\nyyyyyyyyyyyys+:--................://::::\nyyyyyyyyyo+:-.....................-//:::\nyyyyyyo+:-..........................://:\nyyyo+:-..............................-//\no/:-...................................:\n\x1b[0m",
+        raw_art: "${c1}sooooooooooooooooooooooooooooooooooooo+:\nyyooooooooooooooooooooooooooooooooo+/:::\nyyysoooooooooooooooooooooooooooo+/::::::\nyyyyyoooooooooooooooooooooooo+/:::::::::\nyyyyyysoooooooooooooooooo++/::::::::::::\nyyyyyyysoooooooooooooo++/:::::::::::::::\nyyyyyyyyysoooooo${c2}sydddys${c1}+/:::::::::::::::\nyyyyyyyyyysooo${c2}smMMMMMMMNd${c1}+::::::::::::::\nyyyyyyyyyyyyo${c2}sMMMMMMMMMMMN${c1}/:::::::::::::\nyyyyyyyyyyyyy${c2}dMMMMMMMMMMMM${c1}o//:::::::::::\nyyyyyyyyyyyyy${c2}hMMMMMMMMMMMm${c1}--//::::::::::\nyyyyyyyyyyyyyy${c2}hmMMMMMMMNy${c1}:..-://::::::::\nyyyyyyyyyyyyyyy${c2}yyhhyys+:${c1}......://:::::::\nyyyyyyyyyyyyyyys+:--...........-///:::::\nyyyyyyyyyyyys+:--................://::::\nyyyyyyyyyo+:-.....................-//:::\nyyyyyyo+:-..........................://:\nyyyo+:-..............................-//\no/:-...................................:\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Garuda",
         is_wildcard: true,
-        ascii_art: "\n\x1b[1;33m\n                     .%;888:8898898:\n                   x;XxXB%89b8:b8%b88:\n                .8Xxd                8X:.\n              .8Xx;                    8x:.\n            .tt8x          \x1b[1;34m.d\x1b[1;33m            x88;\n         .@8x8;          \x1b[1;34m.db:\x1b[1;33m              xx@;\n       \x1b[1;37m,tSXX\u{00c2}\u{00b0}          .bbbbbbbbbbbbbbbbbbbB8x@;\n     .SXxx            bBBBBBBBBBBBBBBBBBBBbSBX8;\n   ,888S                                     pd!\n  8X88/                                       q\n  GBB.\n   \x1b[1;32mx%88        d888@8@X@X@X88X@@XX@@X@8@X.\n     dxXd    dB8b8b8B8B08bB88b998888b88x.\n      dxx8o                      .@@;.\n        dx88                   .t@x.\n          d:SS@8ba89aa67a853Sxxad.\n            .d988999889889899dd.\n\n\x1b[0m",
+        ascii_art: "\n\x1b[1;33m\n                     .%;888:8898898:\n                   x;XxXB%89b8:b8%b88:\n                .8Xxd                8X:.\n              .8Xx;                    8x:.\n            .tt8x          \x1b[1;34m.d\x1b[1;33m            x88;\n         .@8x8;          \x1b[1;34m.db:\x1b[1;33m              xx@;\n       \x1b[1;37m,tSXX\u{00b0}          .bbbbbbbbbbbbbbbbbbbB8x@;\n     .SXxx            bBBBBBBBBBBBBBBBBBBBbSBX8;\n   ,888S                                     pd!\n  8X88/                                       q\n  GBB.\n   \x1b[1;32mx%88        d888@8@X@X@X88X@@XX@@X@8@X.\n     dxXd    dB8b8b8B8B08bB88b998888b88x.\n      dxx8o                      .@@;.\n        dx88                   .t@x.\n          d:SS@8ba89aa67a853Sxxad.\n            .d988999889889899dd.\n\n\x1b[0m",
+        raw_art: "\n${c3}\n                     .%;888:8898898:\n                   x;XxXB%89b8:b8%b88:\n                .8Xxd                8X:.\n              .8Xx;                    8x:.\n            .tt8x          ${c6}.d${c3}            x88;\n         .@8x8;          ${c6}.db:${c3}              xx@;\n       ${c4},tSXX\u{00b0}          .bbbbbbbbbbbbbbbbbbbB8x@;\n     .SXxx            bBBBBBBBBBBBBBBBBBBBbSBX8;\n   ,888S                                     pd!\n  8X88/                                       q\n  GBB.\n   ${c5}x%88        d888@8@X@X@X88X@@XX@@X@8@X.\n     dxXd    dB8b8b8B8B08bB88b998888b88x.\n      dxx8o                      .@@;.\n        dx88                   .t@x.\n          d:SS@8ba89aa67a853Sxxad.\n            .d988999889889899dd.\n\n",
+        colors: &[Color::Palette(7), Color::Palette(7), Color::Palette(3), Color::Palette(7), Color::Palette(2), Color::Palette(4)],
         max_line_length: 48,
     },
     Logo {
         name: "Gentoo",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m         -/oyddmdhs+:.\n     -o\x1b[1;37mdNMMMMMMMMNNmhy+\x1b[1;35m-`\n   -y\x1b[1;37mNMMMMMMMMMMMNNNmmdhy\x1b[1;35m+-\n `o\x1b[1;37mmMMMMMMMMMMMMNmdmmmmddhhy\x1b[1;35m/`\n om\x1b[1;37mMMMMMMMMMMMN\x1b[1;35mhhyyyo\x1b[1;37mhmdddhhhd\x1b[1;35mo`\n.y\x1b[1;37mdMMMMMMMMMMd\x1b[1;35mhs++so/s\x1b[1;37mmdddhhhhdm\x1b[1;35m+`\n oy\x1b[1;37mhdmNMMMMMMMN\x1b[1;35mdyooy\x1b[1;37mdmddddhhhhyhN\x1b[1;35md.\n  :o\x1b[1;37myhhdNNMMMMMMMNNNmmdddhhhhhyym\x1b[1;35mMh\n    .:\x1b[1;37m+sydNMMMMMNNNmmmdddhhhhhhmM\x1b[1;35mmy\n       /m\x1b[1;37mMMMMMMNNNmmmdddhhhhhmMNh\x1b[1;35ms:\n    `o\x1b[1;37mNMMMMMMMNNNmmmddddhhdmMNhs\x1b[1;35m+`\n  `s\x1b[1;37mNMMMMMMMMNNNmmmdddddmNMmhs\x1b[1;35m/.\n /N\x1b[1;37mMMMMMMMMNNNNmmmdddmNMNdso\x1b[1;35m:`\n+M\x1b[1;37mMMMMMMNNNNNmmmmdmNMNdso\x1b[1;35m/-\nyM\x1b[1;37mMNNNNNNNmmmmmNNMmhs+/\x1b[1;35m-`\n/h\x1b[1;37mMMNNNNNNNNMNdhs++/\x1b[1;35m-`\n`/\x1b[1;37mohdmmddhys+++/:\x1b[1;35m.`\n  `-//////:--.\n\x1b[0m",
+        raw_art: "${c1}         -/oyddmdhs+:.\n     -o${c2}dNMMMMMMMMNNmhy+${c1}-`\n   -y${c2}NMMMMMMMMMMMNNNmmdhy${c1}+-\n `o${c2}mMMMMMMMMMMMMNmdmmmmddhhy${c1}/`\n om${c2}MMMMMMMMMMMN${c1}hhyyyo${c2}hmdddhhhd${c1}o`\n.y${c2}dMMMMMMMMMMd${c1}hs++so/s${c2}mdddhhhhdm${c1}+`\n oy${c2}hdmNMMMMMMMN${c1}dyooy${c2}dmddddhhhhyhN${c1}d.\n  :o${c2}yhhdNNMMMMMMMNNNmmdddhhhhhyym${c1}Mh\n    .:${c2}+sydNMMMMMNNNmmmdddhhhhhhmM${c1}my\n       /m${c2}MMMMMMNNNmmmdddhhhhhmMNh${c1}s:\n    `o${c2}NMMMMMMMNNNmmmddddhhdmMNhs${c1}+`\n  `s${c2}NMMMMMMMMNNNmmmdddddmNMmhs${c1}/.\n /N${c2}MMMMMMMMNNNNmmmdddmNMNdso${c1}:`\n+M${c2}MMMMMMNNNNNmmmmdmNMNdso${c1}/-\nyM${c2}MNNNNNNNmmmmmNNMmhs+/${c1}-`\n/h${c2}MMNNNNNNNNMNdhs++/${c1}-`\n`/${c2}ohdmmddhys+++/:${c1}.`\n  `-//////:--.\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "GoboLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m  _____       _\n / ____|     | |\n| |  __  ___ | |__   ___\n| | |_ |/ _ \\| '_ \\ / _ \\\n| |__| | (_) | |_) | (_) |\n \\_____|\\___/|_.__/ \\___/\n\x1b[0m",
+        raw_art: "${c1}  _____       _\n / ____|     | |\n| |  __  ___ | |__   ___\n| | |_ |/ _ \\| '_ \\ / _ \\\n| |__| | (_) | |_) | (_) |\n \\_____|\\___/|_.__/ \\___/\n",
+        colors: &[Color::Palette(5), Color::Palette(4), Color::Palette(6), Color::Palette(2)],
         max_line_length: 26,
     },
     Logo {
         name: "Grombyang",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m            eeeeeeeeeeee\n         eeeeeeeeeeeeeeeee\n      eeeeeeeeeeeeeeeeeeeeeee\n    eeeee       \x1b[1;32m.o+       \x1b[1;34meeee\n  eeee         \x1b[1;32m`ooo/         \x1b[1;34meeee\n eeee         \x1b[1;32m`+oooo:         \x1b[1;34meeee\neee          \x1b[1;32m`+oooooo:          \x1b[1;34meee\neee          \x1b[1;32m-+oooooo+:         \x1b[1;34meee\nee         \x1b[1;32m`/:oooooooo+:         \x1b[1;34mee\nee        \x1b[1;32m`/+   +++    +:        \x1b[1;34mee\nee              \x1b[1;32m+o+\\             \x1b[1;34mee\neee             \x1b[1;32m+o+\\            \x1b[1;34meee\neee        \x1b[1;32m//  \\\\ooo/  \\\\\\        \x1b[1;34meee\n eee      \x1b[1;32m//++++oooo++++\\\\\\     \x1b[1;34meee\n  eeee    \x1b[1;32m::::++oooo+:::::   \x1b[1;34meeee\n    eeeee   \x1b[1;31mGrombyang OS \x1b[1;34m  eeee\n      eeeeeeeeeeeeeeeeeeeeeee\n         eeeeeeeeeeeeeeeee\n\x1b[0m",
+        raw_art: "${c1}            eeeeeeeeeeee\n         eeeeeeeeeeeeeeeee\n      eeeeeeeeeeeeeeeeeeeeeee\n    eeeee       ${c2}.o+       ${c1}eeee\n  eeee         ${c2}`ooo/         ${c1}eeee\n eeee         ${c2}`+oooo:         ${c1}eeee\neee          ${c2}`+oooooo:          ${c1}eee\neee          ${c2}-+oooooo+:         ${c1}eee\nee         ${c2}`/:oooooooo+:         ${c1}ee\nee        ${c2}`/+   +++    +:        ${c1}ee\nee              ${c2}+o+\\             ${c1}ee\neee             ${c2}+o+\\            ${c1}eee\neee        ${c2}//  \\\\ooo/  \\\\\\        ${c1}eee\n eee      ${c2}//++++oooo++++\\\\\\     ${c1}eee\n  eeee    ${c2}::::++oooo+:::::   ${c1}eeee\n    eeeee   ${c3}Grombyang OS ${c1}  eeee\n      eeeeeeeeeeeeeeeeeeeeeee\n         eeeeeeeeeeeeeeeee\n",
+        colors: &[Color::Palette(4), Color::Palette(2), Color::Palette(1)],
         max_line_length: 37,
     },
     Logo {
         name: "Guix",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m ..                             `.\n `--..```..`           `..```..--`\n   .-:///-:::.       `-:::///:-.\n      ````.:::`     `:::.````\n           -//:`    -::-\n            ://:   -::-\n            `///- .:::`\n             -+++-:::.\n              :+/:::-\n              `-....`\n\x1b[0m",
+        raw_art: "${c1} ..                             `.\n `--..```..`           `..```..--`\n   .-:///-:::.       `-:::///:-.\n      ````.:::`     `:::.````\n           -//:`    -::-\n            ://:   -::-\n            `///- .:::`\n             -+++-:::.\n              :+/:::-\n              `-....`\n",
+        colors: &[Color::Palette(3), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
         max_line_length: 34,
     },
     Logo {
         name: "Haiku",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m\n\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM\x1b[1;32m       .ciO| /YMMMMM*\"\n\x1b[1;37m           MMMM\x1b[1;32m   .cOMMMMM|/MMMMM/`\n ,         ,iMM|/MMMMMMMMMMMMMMM*\n  `*.__,-cMMMMMMMMMMMMMMMMM/`\x1b[1;37m.MMM\n           MM\x1b[1;32mMMMMMMM/`:MMM/  \x1b[1;37mMMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           \"\"\"\"              \"\"\"\"\n\x1b[0m",
+        raw_art: "${c3}\n\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           MMMM${c4}       .ciO| /YMMMMM*\"\n${c3}           MMMM${c4}   .cOMMMMM|/MMMMM/`\n ,         ,iMM|/MMMMMMMMMMMMMMM*\n  `*.__,-cMMMMMMMMMMMMMMMMM/`${c3}.MMM\n           MM${c4}MMMMMMM/`:MMM/  ${c3}MMMM\n           MMMM              MMMM\n           MMMM              MMMM\n           \"\"\"\"              \"\"\"\"\n",
+        colors: &[Color::Palette(1), Color::Palette(3), Color::Palette(7), Color::Palette(2)],
         max_line_length: 37,
     },
     Logo {
         name: "Hash",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;123m\n\n      +   ######   +\n    ###   ######   ###\n  #####   ######   #####\n ######   ######   ######\n\n####### '\"###### '\"########\n#######   ######   ########\n#######   ######   ########\n\n ###### '\"###### '\"######\n  #####   ######   #####\n    ###   ######   ###\n      ~   ######   ~\n\n\x1b[0m",
+        raw_art: "${c1}\n\n      +   ######   +\n    ###   ######   ###\n  #####   ######   #####\n ######   ######   ######\n\n####### '\"###### '\"########\n#######   ######   ########\n#######   ######   ########\n\n ###### '\"###### '\"######\n  #####   ######   #####\n    ###   ######   ###\n      ~   ######   ~\n\n",
+        colors: &[Color::Palette(123)],
         max_line_length: 27,
     },
     Logo {
         name: "Huayra",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                     `\n            .       .       `\n       ``    -      .      .\n        `.`   -` `. -  `` .`\n          ..`-`-` + -  / .`     ```\n          .--.+--`+:- :/.` .-``.`\n            -+/so::h:.d-`./:`.`\n              :hNhyMomy:os-...-.  ````\n               .dhsshNmNhoo+:-``.```\n                \x1b[1;34m`ohy:-\x1b[1;37mNMds+::-.``\n            ````\x1b[1;34m.hNN+`\x1b[1;37mmMNho/:-....````\n       `````     `../dmNhoo+/:..``\n    ````            .dh++o/:....`\n.+s/`                `/s-.-.:.`` ````\n::`                    `::`..`\n                          .` `..\n                                ``\n\x1b[0m",
+        raw_art: "${c2}                     `\n            .       .       `\n       ``    -      .      .\n        `.`   -` `. -  `` .`\n          ..`-`-` + -  / .`     ```\n          .--.+--`+:- :/.` .-``.`\n            -+/so::h:.d-`./:`.`\n              :hNhyMomy:os-...-.  ````\n               .dhsshNmNhoo+:-``.```\n                ${c1}`ohy:-${c2}NMds+::-.``\n            ````${c1}.hNN+`${c2}mMNho/:-....````\n       `````     `../dmNhoo+/:..``\n    ````            .dh++o/:....`\n.+s/`                `/s-.-.:.`` ````\n::`                    `::`..`\n                          .` `..\n                                ``\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 38,
     },
     Logo {
         name: "HydroOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m\n  _    _           _            ____   _____\n | |  | |         | |          / __ \\ / ____|\n | |__| |_   _  __| |_ __ ___ | |  | | (___\n |  __  | | | |/ _` | '__/ _ \\| |  | |\\___ \\\n | |  | | |_| | (_| | | | (_) | |__| |____) |\n |_|  |_|\\__, |\\__,_|_|  \\___/ \\____/|_____/\n          __/ |\n         |___/\n\x1b[0m",
+        raw_art: "${c1}\n  _    _           _            ____   _____\n | |  | |         | |          / __ \\ / ____|\n | |__| |_   _  __| |_ __ ___ | |  | | (___\n |  __  | | | |/ _` | '__/ _ \\| |  | |\\___ \\\n | |  | | |_| | (_| | | | (_) | |__| |____) |\n |_|  |_|\\__, |\\__,_|_|  \\___/ \\____/|_____/\n          __/ |\n         |___/\n",
+        colors: &[Color::Palette(1), Color::Palette(2), Color::Palette(3), Color::Palette(4), Color::Palette(5)],
         max_line_length: 45,
     },
     Logo {
         name: "Hyperbola",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m                     WW\n                     KX              W\n                    WO0W          NX0O\n                    NOO0NW  WNXK0OOKW\n                    W0OOOOOOOOOOOOKN\n                     N0OOOOOOO0KXW\n                       WNXXXNW\n                 NXK00000KN\n             WNK0OOOOOOOOOO0W\n           NK0OOOOOOOOOOOOOO0W\n         X0OOOOOOO00KK00OOOOOK\n       X0OOOO0KNWW      WX0OO0W\n     X0OO0XNW              KOOW\n   N00KNW                   KOW\n NKXN                       W0W\nWW                           W\n\x1b[0m",
+        raw_art: "${c1}                     WW\n                     KX              W\n                    WO0W          NX0O\n                    NOO0NW  WNXK0OOKW\n                    W0OOOOOOOOOOOOKN\n                     N0OOOOOOO0KXW\n                       WNXXXNW\n                 NXK00000KN\n             WNK0OOOOOOOOOO0W\n           NK0OOOOOOOOOOOOOO0W\n         X0OOOOOOO00KK00OOOOOK\n       X0OOOO0KNWW      WX0OO0W\n     X0OO0XNW              KOOW\n   N00KNW                   KOW\n NKXN                       W0W\nWW                           W\n",
+        colors: &[Color::Palette(8)],
         max_line_length: 38,
     },
     Logo {
         name: "IRIX",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           ./ohmNd/  +dNmho/-\n     `:+ydNMMMMMMMM.-MMMMMMMMMdyo:.\n   `hMMMMMMNhs/sMMM-:MMM+/shNMMMMMMh`\n   -NMMMMMmo-` /MMM-/MMM- `-omMMMMMN.\n `.`-+hNMMMMMNhyMMM-/MMMshmMMMMMmy+...`\n+mMNds:-:sdNMMMMMMMyyMMMMMMMNdo:.:sdMMm+\ndMMMMMMmy+.-/ymNMMMMMMMMNmy/-.+hmMMMMMMd\noMMMMmMMMMNds:.+MMMmmMMN/.-odNMMMMmMMMM+\n.MMMM-/ymMMMMMmNMMy..hMMNmMMMMMmy/-MMMM.\n hMMM/ `/dMMMMMMMN////NMMMMMMMd/. /MMMh\n /MMMdhmMMMmyyMMMMMMMMMMMMhymMMMmhdMMM:\n `mMMMMNho//sdMMMMM//NMMMMms//ohNMMMMd\n  `/so/:+ymMMMNMMMM` mMMMMMMMmh+::+o/`\n     `yNMMNho-yMMMM` NMMMm.+hNMMNh`\n     -MMMMd:  oMMMM. NMMMh  :hMMMM-\n      -yNMMMmooMMMM- NMMMyomMMMNy-\n        .omMMMMMMMM-`NMMMMMMMmo.\n          `:hMMMMMM. NMMMMMh/`\n             .odNm+  /dNms.\n\x1b[0m",
+        raw_art: "${c1}           ./ohmNd/  +dNmho/-\n     `:+ydNMMMMMMMM.-MMMMMMMMMdyo:.\n   `hMMMMMMNhs/sMMM-:MMM+/shNMMMMMMh`\n   -NMMMMMmo-` /MMM-/MMM- `-omMMMMMN.\n `.`-+hNMMMMMNhyMMM-/MMMshmMMMMMmy+...`\n+mMNds:-:sdNMMMMMMMyyMMMMMMMNdo:.:sdMMm+\ndMMMMMMmy+.-/ymNMMMMMMMMNmy/-.+hmMMMMMMd\noMMMMmMMMMNds:.+MMMmmMMN/.-odNMMMMmMMMM+\n.MMMM-/ymMMMMMmNMMy..hMMNmMMMMMmy/-MMMM.\n hMMM/ `/dMMMMMMMN////NMMMMMMMd/. /MMMh\n /MMMdhmMMMmyyMMMMMMMMMMMMhymMMMmhdMMM:\n `mMMMMNho//sdMMMMM//NMMMMms//ohNMMMMd\n  `/so/:+ymMMMNMMMM` mMMMMMMMmh+::+o/`\n     `yNMMNho-yMMMM` NMMMm.+hNMMNh`\n     -MMMMd:  oMMMM. NMMMh  :hMMMM-\n      -yNMMMmooMMMM- NMMMyomMMMNy-\n        .omMMMMMMMM-`NMMMMMMMmo.\n          `:hMMMMMM. NMMMMMh/`\n             .odNm+  /dNms.\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "IRIX",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           ./ohmNd/  +dNmho/-\n     `:+ydNMMMMMMMM.-MMMMMMMMMdyo:.\n   `hMMMMMMNhs/sMMM-:MMM+/shNMMMMMMh`\n   -NMMMMMmo-` /MMM-/MMM- `-omMMMMMN.\n `.`-+hNMMMMMNhyMMM-/MMMshmMMMMMmy+...`\n+mMNds:-:sdNMMMMMMMyyMMMMMMMNdo:.:sdMMm+\ndMMMMMMmy+.-/ymNMMMMMMMMNmy/-.+hmMMMMMMd\noMMMMmMMMMNds:.+MMMmmMMN/.-odNMMMMmMMMM+\n.MMMM-/ymMMMMMmNMMy..hMMNmMMMMMmy/-MMMM.\n hMMM/ `/dMMMMMMMN////NMMMMMMMd/. /MMMh\n /MMMdhmMMMmyyMMMMMMMMMMMMhymMMMmhdMMM:\n `mMMMMNho//sdMMMMM//NMMMMms//ohNMMMMd\n  `/so/:+ymMMMNMMMM` mMMMMMMMmh+::+o/`\n     `yNMMNho-yMMMM` NMMMm.+hNMMNh`\n     -MMMMd:  oMMMM. NMMMh  :hMMMM-\n      -yNMMMmooMMMM- NMMMyomMMMNy-\n        .omMMMMMMMM-`NMMMMMMMmo.\n          `:hMMMMMM. NMMMMMh/`\n             .odNm+  /dNms.\n\x1b[0m",
+        raw_art: "${c1}           ./ohmNd/  +dNmho/-\n     `:+ydNMMMMMMMM.-MMMMMMMMMdyo:.\n   `hMMMMMMNhs/sMMM-:MMM+/shNMMMMMMh`\n   -NMMMMMmo-` /MMM-/MMM- `-omMMMMMN.\n `.`-+hNMMMMMNhyMMM-/MMMshmMMMMMmy+...`\n+mMNds:-:sdNMMMMMMMyyMMMMMMMNdo:.:sdMMm+\ndMMMMMMmy+.-/ymNMMMMMMMMNmy/-.+hmMMMMMMd\noMMMMmMMMMNds:.+MMMmmMMN/.-odNMMMMmMMMM+\n.MMMM-/ymMMMMMmNMMy..hMMNmMMMMMmy/-MMMM.\n hMMM/ `/dMMMMMMMN////NMMMMMMMd/. /MMMh\n /MMMdhmMMMmyyMMMMMMMMMMMMhymMMMmhdMMM:\n `mMMMMNho//sdMMMMM//NMMMMms//ohNMMMMd\n  `/so/:+ymMMMNMMMM` mMMMMMMMmh+::+o/`\n     `yNMMNho-yMMMM` NMMMm.+hNMMNh`\n     -MMMMd:  oMMMM. NMMMh  :hMMMM-\n      -yNMMMmooMMMM- NMMMyomMMMNy-\n        .omMMMMMMMM-`NMMMMMMMmo.\n          `:hMMMMMM. NMMMMMh/`\n             .odNm+  /dNms.\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Itc",
         is_wildcard: true,
-        ascii_art: "\x1b[1;31m....................-==============+...\n\x1b[1;31m....................-==============:...\n\x1b[1;31m...:===========-....-==============:...\n\x1b[1;31m...-===========:....-==============-...\n\x1b[1;31m....*==========+........-::********-...\n\x1b[1;31m....*===========+.:*====**==*+-.-......\n\x1b[1;31m....:============*+-..--:+**====*---...\n\x1b[1;31m......::--........................::...\n\x1b[1;31m..+-:+-.+::*:+::+:-++::++-.:-.*.:++:++.\n\x1b[1;31m..:-:-++++:-::--:+::-::.:++-++:++--:-:.    \u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\u{00e2}\u{00a0}\u{0080}\n\x1b[0m",
+        ascii_art: "\x1b[1;31m....................-==============+...\n\x1b[1;31m....................-==============:...\n\x1b[1;31m...:===========-....-==============:...\n\x1b[1;31m...-===========:....-==============-...\n\x1b[1;31m....*==========+........-::********-...\n\x1b[1;31m....*===========+.:*====**==*+-.-......\n\x1b[1;31m....:============*+-..--:+**====*---...\n\x1b[1;31m......::--........................::...\n\x1b[1;31m..+-:+-.+::*:+::+:-++::++-.:-.*.:++:++.\n\x1b[1;31m..:-:-++++:-::--:+::-::.:++-++:++--:-:.    \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\x1b[0m",
+        raw_art: "${c1}....................-==============+...\n${c1}....................-==============:...\n${c1}...:===========-....-==============:...\n${c1}...-===========:....-==============-...\n${c1}....*==========+........-::********-...\n${c1}....*===========+.:*====**==*+-.-......\n${c1}....:============*+-..--:+**====*---...\n${c1}......::--........................::...\n${c1}..+-:+-.+::*:+::+:-++::++-.:-.*.:++:++.\n${c1}..:-:-++++:-::--:+::-::.:++-++:++--:-:.    \u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\u{2800}\n",
+        colors: &[Color::Palette(1)],
         max_line_length: 48,
     },
     Logo {
         name: "KDE",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m             `..---+/---..`\n         `---.``   ``   `.---.`\n      .--.`        ``        `-:-.\n    `:/:     `.----//----.`     :/-\n   .:.    `---`          `--.`    .:`\n  .:`   `--`                .:-    `:.\n `/    `:.      `.-::-.`      -:`   `/`\n /.    /.     `:++++++++:`     .:    .:\n`/    .:     `+++++++++++/      /`   `+`\n/+`   --     .++++++++++++`     :.   .+:\n`/    .:     `+++++++++++/      /`   `+`\n /`    /.     `:++++++++:`     .:    .:\n ./    `:.      `.:::-.`      -:`   `/`\n  .:`   `--`                .:-    `:.\n   .:.    `---`          `--.`    .:`\n    `:/:     `.----//----.`     :/-\n      .-:.`        ``        `-:-.\n         `---.``   ``   `.---.`\n             `..---+/---..`\n\x1b[0m",
+        raw_art: "${c1}             `..---+/---..`\n         `---.``   ``   `.---.`\n      .--.`        ``        `-:-.\n    `:/:     `.----//----.`     :/-\n   .:.    `---`          `--.`    .:`\n  .:`   `--`                .:-    `:.\n `/    `:.      `.-::-.`      -:`   `/`\n /.    /.     `:++++++++:`     .:    .:\n`/    .:     `+++++++++++/      /`   `+`\n/+`   --     .++++++++++++`     :.   .+:\n`/    .:     `+++++++++++/      /`   `+`\n /`    /.     `:++++++++:`     .:    .:\n ./    `:.      `.:::-.`      -:`   `/`\n  .:`   `--`                .:-    `:.\n   .:.    `---`          `--.`    .:`\n    `:/:     `.----//----.`     :/-\n      .-:.`        ``        `-:-.\n         `---.``   ``   `.---.`\n             `..---+/---..`\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
+    Logo {
+        name: "KISS",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m    ___\n   (\x1b[1;37m.\u{00b7} \x1b[1;34m|\n   (\x1b[1;35m<> \x1b[1;34m|\n  / \x1b[1;37m__  \x1b[1;34m\\\\\n ( \x1b[1;37m/  \\\\ \x1b[1;34m/|\n\x1b[1;35m_\x1b[1;34m/\\\\ \x1b[1;37m__)\x1b[1;34m/\x1b[1;35m_\x1b[1;34m)\n\x1b[1;35m\\/\x1b[1;34m-____\x1b[1;35m\\/\n\x1b[0m",
+        raw_art: "${c1}    ___\n   (${c3}.\u{00b7} ${c1}|\n   (${c2}<> ${c1}|\n  / ${c3}__  ${c1}\\\\\n ( ${c3}/  \\\\ ${c1}/|\n${c2}_${c1}/\\\\ ${c3}__)${c1}/${c2}_${c1})\n${c2}\\/${c1}-____${c2}\\/\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
+        max_line_length: 11,
+    },
     Logo {
         name: "KSLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m K   K U   U RRRR   ooo\n K  K  U   U R   R o   o\n KKK   U   U RRRR  o   o\n K  K  U   U R  R  o   o\n K   K  UUU  R   R  ooo\n\n\x1b[1;37m  SSS   AAA  W   W  AAA\n S     A   A W   W A   A\n  SSS  AAAAA W W W AAAAA\n     S A   A WW WW A   A\n  SSS  A   A W   W A   A\n\x1b[0m",
+        raw_art: "${c1} K   K U   U RRRR   ooo\n K  K  U   U R   R o   o\n KKK   U   U RRRR  o   o\n K  K  U   U R  R  o   o\n K   K  UUU  R   R  ooo\n\n${c2}  SSS   AAA  W   W  AAA\n S     A   A W   W A   A\n  SSS  AAAAA W W W AAAAA\n     S A   A WW WW A   A\n  SSS  A   A W   W A   A\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 24,
     },
     Logo {
         name: "KaOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                     ..\n  .....         ..OSSAAAAAAA..\n .KKKKSS.     .SSAAAAAAAAAAA.\n.KKKKKSO.    .SAAAAAAAAAA...\nKKKKKKS.   .OAAAAAAAA.\nKKKKKKS.  .OAAAAAA.\nKKKKKKS. .SSAA..\n.KKKKKS..OAAAAAAAAAAAA........\n DKKKKO.=AA=========A===AASSSO..\n  AKKKS.==========AASSSSAAAAAASS.\n  .=KKO..========ASS.....SSSSASSSS.\n    .KK.       .ASS..O.. =SSSSAOSS:\n     .OK.      .ASSSSSSSO...=A.SSA.\n       .K      ..SSSASSSS.. ..SSA.\n                 .SSS.AAKAKSSKA.\n                    .SSS....S..\n\x1b[0m",
+        raw_art: "${c1}                     ..\n  .....         ..OSSAAAAAAA..\n .KKKKSS.     .SSAAAAAAAAAAA.\n.KKKKKSO.    .SAAAAAAAAAA...\nKKKKKKS.   .OAAAAAAAA.\nKKKKKKS.  .OAAAAAA.\nKKKKKKS. .SSAA..\n.KKKKKS..OAAAAAAAAAAAA........\n DKKKKO.=AA=========A===AASSSO..\n  AKKKS.==========AASSSSAAAAAASS.\n  .=KKO..========ASS.....SSSSASSSS.\n    .KK.       .ASS..O.. =SSSSAOSS:\n     .OK.      .ASSSSSSSO...=A.SSA.\n       .K      ..SSSASSSS.. ..SSA.\n                 .SSS.AAKAKSSKA.\n                    .SSS....S..\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "Kaisen",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                          `\n                  `:+oyyho.\n             `+:`sdddddd/\n        `+` :ho oyo++ohds-`\n       .ho :dd.  .: `sddddddhhyso+/-\n       ody.ddd-:yd- +hysssyhddddddddho`\n       yddddddhddd` ` `--`   -+hddddddh.\n       hddy-+dddddy+ohh/..+sddddy/:::+ys\n      :ddd/sdddddddddd- oddddddd       `\n     `yddddddddddddddd/ /ddddddd/\n:.  :ydddddddddddddddddo..sddddddy/`\nodhdddddddo- `ddddh+-``....-+hdddddds.\n-ddddddhd:   /dddo  -ydddddddhdddddddd-\n /hdy:o - `:sddds   .`./hdddddddddddddo\n  `/-  `+hddyosy+       :dddddddy-.-od/\n      :sydds           -hddddddd`    /\n       .+shd-      `:ohddddddddd`\n                `:+ooooooooooooo:\n\x1b[0m",
+        raw_art: "${c1}                          `\n                  `:+oyyho.\n             `+:`sdddddd/\n        `+` :ho oyo++ohds-`\n       .ho :dd.  .: `sddddddhhyso+/-\n       ody.ddd-:yd- +hysssyhddddddddho`\n       yddddddhddd` ` `--`   -+hddddddh.\n       hddy-+dddddy+ohh/..+sddddy/:::+ys\n      :ddd/sdddddddddd- oddddddd       `\n     `yddddddddddddddd/ /ddddddd/\n:.  :ydddddddddddddddddo..sddddddy/`\nodhdddddddo- `ddddh+-``....-+hdddddds.\n-ddddddhd:   /dddo  -ydddddddhdddddddd-\n /hdy:o - `:sddds   .`./hdddddddddddddo\n  `/-  `+hddyosy+       :dddddddy-.-od/\n      :sydds           -hddddddd`    /\n       .+shd-      `:ohddddddddd`\n                `:+ooooooooooooo:\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "Kali",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m..............\n            ..,;:ccc,.\n          ......''';lxO.\n.....''''..........,:ld;\n           .';;;:::;,,.x,\n      ..'''.            0Xxoc:,.  ...\n  ....                ,ONkc;,;cokOdc',.\n .                   OMo           ':\x1b[1;38;5;8mdd\x1b[1;34mo.\n                    dMc               :OO;\n                    0M.                 .:o.\n                    ;Wd\n                     ;XO,\n                       ,d0Odlc;,..\n                           ..',;:cdOOd::,.\n                                    .:d;.':;.\n                                       'd,  .'\n                                         ;l   ..\n                                          .o\n                                            c\n                                            .'\n                                             .\n\x1b[0m",
+        raw_art: "${c1}..............\n            ..,;:ccc,.\n          ......''';lxO.\n.....''''..........,:ld;\n           .';;;:::;,,.x,\n      ..'''.            0Xxoc:,.  ...\n  ....                ,ONkc;,;cokOdc',.\n .                   OMo           ':${c2}dd${c1}o.\n                    dMc               :OO;\n                    0M.                 .:o.\n                    ;Wd\n                     ;XO,\n                       ,d0Odlc;,..\n                           ..',;:cdOOd::,.\n                                    .:d;.':;.\n                                       'd,  .'\n                                         ;l   ..\n                                          .o\n                                            c\n                                            .'\n                                             .\n",
+        colors: &[Color::Palette(4), Color::Palette(8)],
         max_line_length: 48,
     },
     Logo {
         name: "Kibojoe",
         is_wildcard: true,
         ascii_art: "            \x1b[1;34m           ./+oooooo+/.\n           -/+ooooo+/:.`\n          \x1b[1;32m`\x1b[1;34myyyo\x1b[1;37m+++/++\x1b[1;34mosss\x1b[1;32m.\n         \x1b[1;32m+NMN\x1b[1;34myssssssssssss\x1b[1;32m.\n       \x1b[1;32m.dMMMMN\x1b[1;34msssssssssssy\x1b[1;32mNs`\n      +MMMMMMMm\x1b[1;34msssssssssssh\x1b[1;32mMNo`\n    `hMMMMMNNNMd\x1b[1;34msssssssssssd\x1b[1;32mMMN/\n   .\x1b[1;34msyyyssssssy\x1b[1;32mNNmmmmd\x1b[1;34msssss\x1b[1;32mhMMMMd:\n  -NMmh\x1b[1;34myssssssssyhhhhyssyh\x1b[1;32mmMMMMMMMy`\n -NMMMMMNN\x1b[1;34mmdhyyyyyyyhdm\x1b[1;32mNMMMMMMMMMMMN+\n`NMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMd.\nods+/:-----://+oyydmNMMMMMMMMMMMMMMMMMN-\n`                     .-:+osyhhdmmNNNmdo\n\x1b[0m",
+        raw_art: "            ${c3}           ./+oooooo+/.\n           -/+ooooo+/:.`\n          ${c1}`${c3}yyyo${c2}+++/++${c3}osss${c1}.\n         ${c1}+NMN${c3}yssssssssssss${c1}.\n       ${c1}.dMMMMN${c3}sssssssssssy${c1}Ns`\n      +MMMMMMMm${c3}sssssssssssh${c1}MNo`\n    `hMMMMMNNNMd${c3}sssssssssssd${c1}MMN/\n   .${c3}syyyssssssy${c1}NNmmmmd${c3}sssss${c1}hMMMMd:\n  -NMmh${c3}yssssssssyhhhhyssyh${c1}mMMMMMMMy`\n -NMMMMMNN${c3}mdhyyyyyyyhdm${c1}NMMMMMMMMMMMN+\n`NMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMd.\nods+/:-----://+oyydmNMMMMMMMMMMMMMMMMMN-\n`                     .-:+osyhhdmmNNNmdo\n",
+        colors: &[Color::Palette(2), Color::Palette(7), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "Kogaion",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m            ;;      ,;\n           ;;;     ,;;\n         ,;;;;     ;;;;\n      ,;;;;;;;;    ;;;;\n     ;;;;;;;;;;;   ;;;;;\n    ,;;;;;;;;;;;;  ';;;;;,\n    ;;;;;;;;;;;;;;, ';;;;;;;\n    ;;;;;;;;;;;;;;;;;, ';;;;;\n;    ';;;;;;;;;;;;;;;;;;, ;;;\n;;;,  ';;;;;;;;;;;;;;;;;;;,;;\n;;;;;,  ';;;;;;;;;;;;;;;;;;,\n;;;;;;;;,  ';;;;;;;;;;;;;;;;,\n;;;;;;;;;;;;, ';;;;;;;;;;;;;;\n';;;;;;;;;;;;; ';;;;;;;;;;;;;\n ';;;;;;;;;;;;;, ';;;;;;;;;;;\n  ';;;;;;;;;;;;;  ;;;;;;;;;;\n    ';;;;;;;;;;;; ;;;;;;;;\n        ';;;;;;;; ;;;;;;\n           ';;;;; ;;;;\n             ';;; ;;\n\x1b[0m",
+        raw_art: "${c1}            ;;      ,;\n           ;;;     ,;;\n         ,;;;;     ;;;;\n      ,;;;;;;;;    ;;;;\n     ;;;;;;;;;;;   ;;;;;\n    ,;;;;;;;;;;;;  ';;;;;,\n    ;;;;;;;;;;;;;;, ';;;;;;;\n    ;;;;;;;;;;;;;;;;;, ';;;;;\n;    ';;;;;;;;;;;;;;;;;;, ;;;\n;;;,  ';;;;;;;;;;;;;;;;;;;,;;\n;;;;;,  ';;;;;;;;;;;;;;;;;;,\n;;;;;;;;,  ';;;;;;;;;;;;;;;;,\n;;;;;;;;;;;;, ';;;;;;;;;;;;;;\n';;;;;;;;;;;;; ';;;;;;;;;;;;;\n ';;;;;;;;;;;;;, ';;;;;;;;;;;\n  ';;;;;;;;;;;;;  ;;;;;;;;;;\n    ';;;;;;;;;;;; ;;;;;;;;\n        ';;;;;;;; ;;;;;;\n           ';;;;; ;;;;\n             ';;; ;;\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 29,
     },
     Logo {
         name: "Korora",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                ____________\n             _add55555555554\x1b[1;34m:\n           _w?'\x1b[1;34m``````````'\x1b[1;37m)k\x1b[1;34m:\n          _Z'\x1b[1;34m`\x1b[1;37m            ]k\x1b[1;34m:\n          m(\x1b[1;34m`\x1b[1;37m             )k\x1b[1;34m:\n     _.ss\x1b[1;34m`\x1b[1;37mm[\x1b[1;34m`\x1b[1;37m,            ]e\x1b[1;34m:\n   .uY\"^`\x1b[1;34m`\x1b[1;37mXc\x1b[1;34m`\x1b[1;37m?Ss.         d(\x1b[1;34m`\n  jF'\x1b[1;34m`\x1b[1;37m    `@.  \x1b[1;34m`\x1b[1;37mSc      .jr\x1b[1;34m`\n jr\x1b[1;34m`\x1b[1;37m       `?n_ \x1b[1;34m`\x1b[1;37m$;   _a2\"\x1b[1;34m`\n.m\x1b[1;34m:\x1b[1;37m          `~M\x1b[1;34m`\x1b[1;37m1k\x1b[1;34m`\x1b[1;37m5?!`\x1b[1;34m`\n:#\x1b[1;34m:\x1b[1;37m             \x1b[1;34m`\x1b[1;37m)e\x1b[1;34m```\n:m\x1b[1;34m:\x1b[1;37m             ,#'\x1b[1;34m`\n:#\x1b[1;34m:\x1b[1;37m           .s2'\x1b[1;34m`\n:m,________.aa7^\x1b[1;34m`\n:#baaaaaaas!J'\x1b[1;34m`\n ```````````\n\x1b[0m",
+        raw_art: "${c2}                ____________\n             _add55555555554${c1}:\n           _w?'${c1}``````````'${c2})k${c1}:\n          _Z'${c1}`${c2}            ]k${c1}:\n          m(${c1}`${c2}             )k${c1}:\n     _.ss${c1}`${c2}m[${c1}`${c2},            ]e${c1}:\n   .uY\"^`${c1}`${c2}Xc${c1}`${c2}?Ss.         d(${c1}`\n  jF'${c1}`${c2}    `@.  ${c1}`${c2}Sc      .jr${c1}`\n jr${c1}`${c2}       `?n_ ${c1}`${c2}$;   _a2\"${c1}`\n.m${c1}:${c2}          `~M${c1}`${c2}1k${c1}`${c2}5?!`${c1}`\n:#${c1}:${c2}             ${c1}`${c2})e${c1}```\n:m${c1}:${c2}             ,#'${c1}`\n:#${c1}:${c2}           .s2'${c1}`\n:m,________.aa7^${c1}`\n:#baaaaaaas!J'${c1}`\n ```````````\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 29,
     },
     Logo {
         name: "Kubuntu",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           `.:/ossyyyysso/:.\n        .:oyyyyyyyyyyyyyyyyyyo:`\n      -oyyyyyyyo\x1b[1;37mdMMy\x1b[1;34myyyyyyysyyyyo-\n    -syyyyyyyyyy\x1b[1;37mdMMy\x1b[1;34moyyyy\x1b[1;37mdmMMy\x1b[1;34myyyys-\n   oyyys\x1b[1;37mdMy\x1b[1;34msyyyy\x1b[1;37mdMMMMMMMMMMMMMy\x1b[1;34myyyyyyo\n `oyyyy\x1b[1;37mdMMMMy\x1b[1;34msyysoooooo\x1b[1;37mdMMMMy\x1b[1;34myyyyyyyyo`\n oyyyyyy\x1b[1;37mdMMMMy\x1b[1;34myyyyyyyyyyys\x1b[1;37mdMMy\x1b[1;34msssssyyyo\n-yyyyyyyy\x1b[1;37mdMy\x1b[1;34msyyyyyyyyyyyyyys\x1b[1;37mdMMMMMy\x1b[1;34msyyy-\noyyyysoo\x1b[1;37mdMy\x1b[1;34myyyyyyyyyyyyyyyyyy\x1b[1;37mdMMMMy\x1b[1;34msyyyo\nyyys\x1b[1;37mdMMMMMy\x1b[1;34myyyyyyyyyyyyyyyyyysosyyyyyyyy\nyyys\x1b[1;37mdMMMMMy\x1b[1;34myyyyyyyyyyyyyyyyyyyyyyyyyyyyy\noyyyyysos\x1b[1;37mdy\x1b[1;34myyyyyyyyyyyyyyyyyy\x1b[1;37mdMMMMy\x1b[1;34msyyyo\n-yyyyyyyy\x1b[1;37mdMy\x1b[1;34msyyyyyyyyyyyyyys\x1b[1;37mdMMMMMy\x1b[1;34msyyy-\n oyyyyyy\x1b[1;37mdMMMy\x1b[1;34msyyyyyyyyyyys\x1b[1;37mdMMy\x1b[1;34moyyyoyyyo\n `oyyyy\x1b[1;37mdMMMy\x1b[1;34msyyyoooooo\x1b[1;37mdMMMMy\x1b[1;34moyyyyyyyyo\n   oyyysyyoyyyys\x1b[1;37mdMMMMMMMMMMMy\x1b[1;34myyyyyyyo\n    -syyyyyyyyy\x1b[1;37mdMMMy\x1b[1;34msyyy\x1b[1;37mdMMMy\x1b[1;34msyyyys-\n      -oyyyyyyy\x1b[1;37mdMMy\x1b[1;34myyyyyysosyyyyo-\n        ./oyyyyyyyyyyyyyyyyyyo/.\n           `.:/oosyyyysso/:.`\n\x1b[0m",
+        raw_art: "${c1}           `.:/ossyyyysso/:.\n        .:oyyyyyyyyyyyyyyyyyyo:`\n      -oyyyyyyyo${c2}dMMy${c1}yyyyyyysyyyyo-\n    -syyyyyyyyyy${c2}dMMy${c1}oyyyy${c2}dmMMy${c1}yyyys-\n   oyyys${c2}dMy${c1}syyyy${c2}dMMMMMMMMMMMMMy${c1}yyyyyyo\n `oyyyy${c2}dMMMMy${c1}syysoooooo${c2}dMMMMy${c1}yyyyyyyyo`\n oyyyyyy${c2}dMMMMy${c1}yyyyyyyyyyys${c2}dMMy${c1}sssssyyyo\n-yyyyyyyy${c2}dMy${c1}syyyyyyyyyyyyyys${c2}dMMMMMy${c1}syyy-\noyyyysoo${c2}dMy${c1}yyyyyyyyyyyyyyyyyy${c2}dMMMMy${c1}syyyo\nyyys${c2}dMMMMMy${c1}yyyyyyyyyyyyyyyyyysosyyyyyyyy\nyyys${c2}dMMMMMy${c1}yyyyyyyyyyyyyyyyyyyyyyyyyyyyy\noyyyyysos${c2}dy${c1}yyyyyyyyyyyyyyyyyy${c2}dMMMMy${c1}syyyo\n-yyyyyyyy${c2}dMy${c1}syyyyyyyyyyyyyys${c2}dMMMMMy${c1}syyy-\n oyyyyyy${c2}dMMMy${c1}syyyyyyyyyyys${c2}dMMy${c1}oyyyoyyyo\n `oyyyy${c2}dMMMy${c1}syyyoooooo${c2}dMMMMy${c1}oyyyyyyyyo\n   oyyysyyoyyyys${c2}dMMMMMMMMMMMy${c1}yyyyyyyo\n    -syyyyyyyyy${c2}dMMMy${c1}syyy${c2}dMMMy${c1}syyyys-\n      -oyyyyyyy${c2}dMMy${c1}yyyyyysosyyyyo-\n        ./oyyyyyyyyyyyyyyyyyyo/.\n           `.:/oosyyyysso/:.`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "LEDE",
         is_wildcard: true,
         ascii_art: "    \x1b[1;34m _________\n    /        /\\\n   /  LE    /  \\\n  /    DE  /    \\\n /________/  LE  \\\n \\        \\   DE /\n  \\    LE  \\    /\n   \\  DE    \\  /\n    \\________\\/\n\x1b[0m",
+        raw_art: "    ${c1} _________\n    /        /\\\n   /  LE    /  \\\n  /    DE  /    \\\n /________/  LE  \\\n \\        \\   DE /\n  \\    LE  \\    /\n   \\  DE    \\  /\n    \\________\\/\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 18,
     },
     Logo {
         name: "LMDE",
         is_wildcard: true,
         ascii_art: "         \x1b[1;37m`.-::---..\n\x1b[1;32m      .:++++ooooosssoo:.\n    .+o++::.      `.:oos+.\n\x1b[1;32m   :oo:.`             -+oo\x1b[1;37m:\n\x1b[1;32m \x1b[1;37m`\x1b[1;32m+o/`    .\x1b[1;37m::::::\x1b[1;32m-.    .++-\x1b[1;37m`\n\x1b[1;32m\x1b[1;37m`\x1b[1;32m/s/    .yyyyyyyyyyo:   +o-\x1b[1;37m`\n\x1b[1;32m\x1b[1;37m`\x1b[1;32mso     .ss       ohyo` :s-\x1b[1;37m:\n\x1b[1;32m\x1b[1;37m`\x1b[1;32ms/     .ss  h  m  myy/ /s`\x1b[1;37m`\n\x1b[1;32m`s:     `oo  s  m  Myy+-o:`\n`oo      :+sdoohyoydyso/.\n :o.      .:////////++:\n\x1b[1;32m `/++        \x1b[1;37m-:::::-\n\x1b[1;32m  \x1b[1;37m`\x1b[1;32m++-\n\x1b[1;32m   \x1b[1;37m`\x1b[1;32m/+-\n\x1b[1;32m     \x1b[1;37m.\x1b[1;32m+/.\n\x1b[1;32m       \x1b[1;37m.\x1b[1;32m:+-.\n          `--.``\n\x1b[0m",
+        raw_art: "         ${c2}`.-::---..\n${c1}      .:++++ooooosssoo:.\n    .+o++::.      `.:oos+.\n${c1}   :oo:.`             -+oo${c2}:\n${c1} ${c2}`${c1}+o/`    .${c2}::::::${c1}-.    .++-${c2}`\n${c1}${c2}`${c1}/s/    .yyyyyyyyyyo:   +o-${c2}`\n${c1}${c2}`${c1}so     .ss       ohyo` :s-${c2}:\n${c1}${c2}`${c1}s/     .ss  h  m  myy/ /s`${c2}`\n${c1}`s:     `oo  s  m  Myy+-o:`\n`oo      :+sdoohyoydyso/.\n :o.      .:////////++:\n${c1} `/++        ${c2}-:::::-\n${c1}  ${c2}`${c1}++-\n${c1}   ${c2}`${c1}/+-\n${c1}     ${c2}.${c1}+/.\n${c1}       ${c2}.${c1}:+-.\n          `--.``\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 28,
     },
     Logo {
         name: "LangitKetujuh",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n      'L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n   L7L.   'L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L             L7L7L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                'L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                    'L7L7L7L7L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L.   'L7L\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L.\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n\x1b[1;34m\n\x1b[0m",
+        raw_art: "${c1}\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n      'L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n   L7L.   'L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L             L7L7L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                'L7L7L7L7L7L7L7L7L7L\n   L7L7L7L                    'L7L7L7L7L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L                             L7L7L7L\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L.   'L7L\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L.\n   L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L7L\n${c2}\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 46,
     },
     Logo {
         name: "LaxerOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m\n                    /.\n                 `://:-\n                `//////:\n               .////////:`\n              -//////////:`\n             -/////////////`\n            :///////////////.\n          `://////.```-//////-\n         `://///:`     .//////-\n        `//////:        `//////:\n       .//////-          `://///:`\n      -//////-            `://///:`\n     -//////.               ://////`\n    ://////`                 -//////.\n   `/////:`                   ./////:\n    .-::-`                     .:::-`\n\n.:://////////////////////////////////::.\n////////////////////////////////////////\n.:////////////////////////////////////:.\n\n\x1b[0m",
+        raw_art: "${c2}\n                    /.\n                 `://:-\n                `//////:\n               .////////:`\n              -//////////:`\n             -/////////////`\n            :///////////////.\n          `://////.```-//////-\n         `://///:`     .//////-\n        `//////:        `//////:\n       .//////-          `://///:`\n      -//////-            `://///:`\n     -//////.               ://////`\n    ://////`                 -//////.\n   `/////:`                   ./////:\n    .-::-`                     .:::-`\n\n.:://////////////////////////////////::.\n////////////////////////////////////////\n.:////////////////////////////////////:.\n\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "LibreELEC",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m          :+ooo/.      \x1b[1;33m./ooo+:\n\x1b[1;32m        :+ooooooo/.  \x1b[1;33m./ooooooo+:\n\x1b[1;32m      :+ooooooooooo:\x1b[1;33m:ooooooooooo+:\n\x1b[1;32m    :+ooooooooooo+-  \x1b[1;33m-+ooooooooooo+:\n\x1b[1;32m  :+ooooooooooo+-  \x1b[1;37m--  \x1b[1;33m-+ooooooooooo+:\n\x1b[1;32m.+ooooooooooo+-  \x1b[1;37m:+oo+:  \x1b[1;33m-+ooooooooooo+-\n\x1b[1;32m-+ooooooooo+-  \x1b[1;37m:+oooooo+:  \x1b[1;33m-+oooooooooo-\n\x1b[1;32m  :+ooooo+-  \x1b[1;37m:+oooooooooo+:  \x1b[1;33m-+oooooo:\n\x1b[1;32m    :+o+-  \x1b[1;37m:+oooooooooooooo+:  \x1b[1;33m-+oo:\n\x1b[1;38;5;14m     ./   \x1b[1;37m:oooooooooooooooooo:   \x1b[1;38;5;13m/.\n\x1b[1;38;5;14m   ./oo+:  \x1b[1;37m-+oooooooooooooo+-  \x1b[1;38;5;13m:+oo/.\n\x1b[1;38;5;14m ./oooooo+:  \x1b[1;37m-+oooooooooo+-  \x1b[1;38;5;13m:+oooooo/.\n\x1b[1;38;5;14m-oooooooooo+:  \x1b[1;37m-+oooooo+-  \x1b[1;38;5;13m:+oooooooooo-\n\x1b[1;38;5;14m.+ooooooooooo+:  \x1b[1;37m-+oo+-  \x1b[1;38;5;13m:+ooooooooooo+.\n\x1b[1;38;5;14m  -+ooooooooooo+:  \x1b[1;37m..  \x1b[1;38;5;13m:+ooooooooooo+-\n\x1b[1;38;5;14m    -+ooooooooooo+:  \x1b[1;38;5;13m:+ooooooooooo+-\n\x1b[1;38;5;14m      -+oooooooooo+:\x1b[1;38;5;13m:+oooooooooo+-\n\x1b[1;38;5;14m        -+oooooo+:    \x1b[1;38;5;13m:+oooooo+-\n\x1b[1;38;5;14m          -+oo+:        \x1b[1;38;5;13m:+oo+-\n\x1b[1;38;5;14m            ..            \x1b[1;38;5;13m..\n\x1b[0m",
+        raw_art: "${c1}          :+ooo/.      ${c2}./ooo+:\n${c1}        :+ooooooo/.  ${c2}./ooooooo+:\n${c1}      :+ooooooooooo:${c2}:ooooooooooo+:\n${c1}    :+ooooooooooo+-  ${c2}-+ooooooooooo+:\n${c1}  :+ooooooooooo+-  ${c3}--  ${c2}-+ooooooooooo+:\n${c1}.+ooooooooooo+-  ${c3}:+oo+:  ${c2}-+ooooooooooo+-\n${c1}-+ooooooooo+-  ${c3}:+oooooo+:  ${c2}-+oooooooooo-\n${c1}  :+ooooo+-  ${c3}:+oooooooooo+:  ${c2}-+oooooo:\n${c1}    :+o+-  ${c3}:+oooooooooooooo+:  ${c2}-+oo:\n${c4}     ./   ${c3}:oooooooooooooooooo:   ${c5}/.\n${c4}   ./oo+:  ${c3}-+oooooooooooooo+-  ${c5}:+oo/.\n${c4} ./oooooo+:  ${c3}-+oooooooooo+-  ${c5}:+oooooo/.\n${c4}-oooooooooo+:  ${c3}-+oooooo+-  ${c5}:+oooooooooo-\n${c4}.+ooooooooooo+:  ${c3}-+oo+-  ${c5}:+ooooooooooo+.\n${c4}  -+ooooooooooo+:  ${c3}..  ${c5}:+ooooooooooo+-\n${c4}    -+ooooooooooo+:  ${c5}:+ooooooooooo+-\n${c4}      -+oooooooooo+:${c5}:+oooooooooo+-\n${c4}        -+oooooo+:    ${c5}:+oooooo+-\n${c4}          -+oo+:        ${c5}:+oo+-\n${c4}            ..            ${c5}..\n",
+        colors: &[Color::Palette(2), Color::Palette(3), Color::Palette(7), Color::Palette(14), Color::Palette(13)],
         max_line_length: 40,
     },
     Logo {
         name: "Linux",
         is_wildcard: false,
         ascii_art: "\x1b[1;38;5;8m        #####\n\x1b[1;38;5;8m       #######\n\x1b[1;38;5;8m       ##\x1b[1;37mO\x1b[1;38;5;8m#\x1b[1;37mO\x1b[1;38;5;8m##\n\x1b[1;38;5;8m       #\x1b[1;33m#####\x1b[1;38;5;8m#\n\x1b[1;38;5;8m     ##\x1b[1;37m##\x1b[1;33m###\x1b[1;37m##\x1b[1;38;5;8m##\n\x1b[1;38;5;8m    #\x1b[1;37m##########\x1b[1;38;5;8m##\n\x1b[1;38;5;8m   #\x1b[1;37m############\x1b[1;38;5;8m##\n\x1b[1;38;5;8m   #\x1b[1;37m############\x1b[1;38;5;8m###\n\x1b[1;33m  ##\x1b[1;38;5;8m#\x1b[1;37m###########\x1b[1;38;5;8m##\x1b[1;33m#\n\x1b[1;33m######\x1b[1;38;5;8m#\x1b[1;37m#######\x1b[1;38;5;8m#\x1b[1;33m######\n\x1b[1;33m#######\x1b[1;38;5;8m#\x1b[1;37m#####\x1b[1;38;5;8m#\x1b[1;33m#######\n\x1b[1;33m  #####\x1b[1;38;5;8m#######\x1b[1;33m#####\n\x1b[0m",
+        raw_art: "${c2}        #####\n${c2}       #######\n${c2}       ##${c1}O${c2}#${c1}O${c2}##\n${c2}       #${c3}#####${c2}#\n${c2}     ##${c1}##${c3}###${c1}##${c2}##\n${c2}    #${c1}##########${c2}##\n${c2}   #${c1}############${c2}##\n${c2}   #${c1}############${c2}###\n${c3}  ##${c2}#${c1}###########${c2}##${c3}#\n${c3}######${c2}#${c1}#######${c2}#${c3}######\n${c3}#######${c2}#${c1}#####${c2}#${c3}#######\n${c3}  #####${c2}#######${c3}#####\n",
+        colors: &[Color::Palette(7), Color::Palette(8), Color::Palette(3)],
         max_line_length: 21,
     },
+    Logo {
+        name: "Linux Lite",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;33m          ,xXc\n      .l0MMMMMO\n   .kNMMMMMWMMMN,\n   KMMMMMMKMMMMMMo\n  'MMMMMMNKMMMMMM:\n  kMMMMMMOMMMMMMO\n .MMMMMMX0MMMMMW.\n oMMMMMMxWMMMMM:\n WMMMMMNkMMMMMO\n:MMMMMMOXMMMMW\n.0MMMMMxMMMMM;\n:;cKMMWxMMMMO\n'MMWMMXOMMMMl\n kMMMMKOMMMMMX:\n .WMMMMKOWMMM0c\n  lMMMMMWO0MNd:'\n   oollXMKXoxl;.\n     ':. .: .'\n              ..\n                .\n\x1b[0m",
+        raw_art: "${c1}          ,xXc\n      .l0MMMMMO\n   .kNMMMMMWMMMN,\n   KMMMMMMKMMMMMMo\n  'MMMMMMNKMMMMMM:\n  kMMMMMMOMMMMMMO\n .MMMMMMX0MMMMMW.\n oMMMMMMxWMMMMM:\n WMMMMMNkMMMMMO\n:MMMMMMOXMMMMW\n.0MMMMMxMMMMM;\n:;cKMMWxMMMMO\n'MMWMMXOMMMMl\n kMMMMKOMMMMMX:\n .WMMMMKOWMMM0c\n  lMMMMMWO0MNd:'\n   oollXMKXoxl;.\n     ':. .: .'\n              ..\n                .\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
+        max_line_length: 18,
+    },
+    Logo {
+        name: "Linux Mint",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m             ...-:::::-...\n\x1b[1;37m          .-MMMMMMMMMMMMMMM-.\n      .-MMMM\x1b[1;32m`..-:::::::-..`\x1b[1;37mMMMM-.\n    .:MMMM\x1b[1;32m.:MMMMMMMMMMMMMMM:.\x1b[1;37mMMMM:.\n   -MMM\x1b[1;32m-M---MMMMMMMMMMMMMMMMMMM.\x1b[1;37mMMM-\n `:MMM\x1b[1;32m:MM`  :MMMM:....::-...-MMMM:\x1b[1;37mMMM:`\n :MMM\x1b[1;32m:MMM`  :MM:`  ``    ``  `:MMM:\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM.  -MM.  .MM-  `MMMM.\x1b[1;37mMMM.\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM:\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM:--:MM:--:MM:  `MMMM.\x1b[1;37mMMM.\n :MMM\x1b[1;32m:MMM-  `-MMMMMMMMMMMM-`  -MMM-\x1b[1;37mMMM:\n  :MMM\x1b[1;32m:MMM:`                `:MMM:\x1b[1;37mMMM:\n   .MMM\x1b[1;32m.MMMM:--------------:MMMM.\x1b[1;37mMMM.\n     '-MMMM\x1b[1;32m.-MMMMMMMMMMMMMMM-.\x1b[1;37mMMMM-'\n       '.-MMMM\x1b[1;32m``--:::::--``\x1b[1;37mMMMM-.'\n\x1b[1;37m            '-MMMMMMMMMMMMM-'\n\x1b[1;37m               ``-:::::-``\n\x1b[0m",
+        raw_art: "${c2}             ...-:::::-...\n${c2}          .-MMMMMMMMMMMMMMM-.\n      .-MMMM${c1}`..-:::::::-..`${c2}MMMM-.\n    .:MMMM${c1}.:MMMMMMMMMMMMMMM:.${c2}MMMM:.\n   -MMM${c1}-M---MMMMMMMMMMMMMMMMMMM.${c2}MMM-\n `:MMM${c1}:MM`  :MMMM:....::-...-MMMM:${c2}MMM:`\n :MMM${c1}:MMM`  :MM:`  ``    ``  `:MMM:${c2}MMM:\n.MMM${c1}.MMMM`  :MM.  -MM.  .MM-  `MMMM.${c2}MMM.\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM:${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n.MMM${c1}.MMMM`  :MM:--:MM:--:MM:  `MMMM.${c2}MMM.\n :MMM${c1}:MMM-  `-MMMMMMMMMMMM-`  -MMM-${c2}MMM:\n  :MMM${c1}:MMM:`                `:MMM:${c2}MMM:\n   .MMM${c1}.MMMM:--------------:MMMM.${c2}MMM.\n     '-MMMM${c1}.-MMMMMMMMMMMMMMM-.${c2}MMMM-'\n       '.-MMMM${c1}``--:::::--``${c2}MMMM-.'\n${c2}            '-MMMMMMMMMMMMM-'\n${c2}               ``-:::::-``\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Linux Mint Old",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;32mMMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      \x1b[1;37m/++                \x1b[1;32m-sNMd:\nMMNso/`  \x1b[1;37mdMM    `.::-. .-::.` \x1b[1;32m.hMN:\nddddMMh  \x1b[1;37mdMM   :hNMNMNhNMNMNh: \x1b[1;32m`NMm\n    NMm  \x1b[1;37mdMM  .NMN/-+MMM+-/NMN` \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  .mmd  `mmm   yMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM`  ..`   ...   ydm. \x1b[1;32mdMM\n    hMM- \x1b[1;37m+MMd/-------...-:sdds  \x1b[1;32mdMM\n    -NMm- \x1b[1;37m:hNMNNNmdddddddddy/`  \x1b[1;32mdMM\n     -dMNs-\x1b[1;37m``-::::-------.``    \x1b[1;32mdMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n\x1b[0m",
+        raw_art: "${c1}MMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      ${c2}/++                ${c1}-sNMd:\nMMNso/`  ${c2}dMM    `.::-. .-::.` ${c1}.hMN:\nddddMMh  ${c2}dMM   :hNMNMNhNMNMNh: ${c1}`NMm\n    NMm  ${c2}dMM  .NMN/-+MMM+-/NMN` ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  .mmd  `mmm   yMM. ${c1}dMM\n    NMm  ${c2}dMM`  ..`   ...   ydm. ${c1}dMM\n    hMM- ${c2}+MMd/-------...-:sdds  ${c1}dMM\n    -NMm- ${c2}:hNMNNNmdddddddddy/`  ${c1}dMM\n     -dMNs-${c2}``-::::-------.``    ${c1}dMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 35,
+    },
+    Logo {
+        name: "LinuxMint",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m             ...-:::::-...\n\x1b[1;37m          .-MMMMMMMMMMMMMMM-.\n      .-MMMM\x1b[1;32m`..-:::::::-..`\x1b[1;37mMMMM-.\n    .:MMMM\x1b[1;32m.:MMMMMMMMMMMMMMM:.\x1b[1;37mMMMM:.\n   -MMM\x1b[1;32m-M---MMMMMMMMMMMMMMMMMMM.\x1b[1;37mMMM-\n `:MMM\x1b[1;32m:MM`  :MMMM:....::-...-MMMM:\x1b[1;37mMMM:`\n :MMM\x1b[1;32m:MMM`  :MM:`  ``    ``  `:MMM:\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM.  -MM.  .MM-  `MMMM.\x1b[1;37mMMM.\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM:\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM:--:MM:--:MM:  `MMMM.\x1b[1;37mMMM.\n :MMM\x1b[1;32m:MMM-  `-MMMMMMMMMMMM-`  -MMM-\x1b[1;37mMMM:\n  :MMM\x1b[1;32m:MMM:`                `:MMM:\x1b[1;37mMMM:\n   .MMM\x1b[1;32m.MMMM:--------------:MMMM.\x1b[1;37mMMM.\n     '-MMMM\x1b[1;32m.-MMMMMMMMMMMMMMM-.\x1b[1;37mMMMM-'\n       '.-MMMM\x1b[1;32m``--:::::--``\x1b[1;37mMMMM-.'\n\x1b[1;37m            '-MMMMMMMMMMMMM-'\n\x1b[1;37m               ``-:::::-``\n\x1b[0m",
+        raw_art: "${c2}             ...-:::::-...\n${c2}          .-MMMMMMMMMMMMMMM-.\n      .-MMMM${c1}`..-:::::::-..`${c2}MMMM-.\n    .:MMMM${c1}.:MMMMMMMMMMMMMMM:.${c2}MMMM:.\n   -MMM${c1}-M---MMMMMMMMMMMMMMMMMMM.${c2}MMM-\n `:MMM${c1}:MM`  :MMMM:....::-...-MMMM:${c2}MMM:`\n :MMM${c1}:MMM`  :MM:`  ``    ``  `:MMM:${c2}MMM:\n.MMM${c1}.MMMM`  :MM.  -MM.  .MM-  `MMMM.${c2}MMM.\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM:${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n.MMM${c1}.MMMM`  :MM:--:MM:--:MM:  `MMMM.${c2}MMM.\n :MMM${c1}:MMM-  `-MMMMMMMMMMMM-`  -MMM-${c2}MMM:\n  :MMM${c1}:MMM:`                `:MMM:${c2}MMM:\n   .MMM${c1}.MMMM:--------------:MMMM.${c2}MMM.\n     '-MMMM${c1}.-MMMMMMMMMMMMMMM-.${c2}MMMM-'\n       '.-MMMM${c1}``--:::::--``${c2}MMMM-.'\n${c2}            '-MMMMMMMMMMMMM-'\n${c2}               ``-:::::-``\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "LinuxMintOld",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;32mMMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      \x1b[1;37m/++                \x1b[1;32m-sNMd:\nMMNso/`  \x1b[1;37mdMM    `.::-. .-::.` \x1b[1;32m.hMN:\nddddMMh  \x1b[1;37mdMM   :hNMNMNhNMNMNh: \x1b[1;32m`NMm\n    NMm  \x1b[1;37mdMM  .NMN/-+MMM+-/NMN` \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  .mmd  `mmm   yMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM`  ..`   ...   ydm. \x1b[1;32mdMM\n    hMM- \x1b[1;37m+MMd/-------...-:sdds  \x1b[1;32mdMM\n    -NMm- \x1b[1;37m:hNMNNNmdddddddddy/`  \x1b[1;32mdMM\n     -dMNs-\x1b[1;37m``-::::-------.``    \x1b[1;32mdMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n\x1b[0m",
+        raw_art: "${c1}MMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      ${c2}/++                ${c1}-sNMd:\nMMNso/`  ${c2}dMM    `.::-. .-::.` ${c1}.hMN:\nddddMMh  ${c2}dMM   :hNMNMNhNMNMNh: ${c1}`NMm\n    NMm  ${c2}dMM  .NMN/-+MMM+-/NMN` ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  .mmd  `mmm   yMM. ${c1}dMM\n    NMm  ${c2}dMM`  ..`   ...   ydm. ${c1}dMM\n    hMM- ${c2}+MMd/-------...-:sdds  ${c1}dMM\n    -NMm- ${c2}:hNMNNNmdddddddddy/`  ${c1}dMM\n     -dMNs-${c2}``-::::-------.``    ${c1}dMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 35,
+    },
     Logo {
         name: "Linux_Lite",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m          ,xXc\n      .l0MMMMMO\n   .kNMMMMMWMMMN,\n   KMMMMMMKMMMMMMo\n  'MMMMMMNKMMMMMM:\n  kMMMMMMOMMMMMMO\n .MMMMMMX0MMMMMW.\n oMMMMMMxWMMMMM:\n WMMMMMNkMMMMMO\n:MMMMMMOXMMMMW\n.0MMMMMxMMMMM;\n:;cKMMWxMMMMO\n'MMWMMXOMMMMl\n kMMMMKOMMMMMX:\n .WMMMMKOWMMM0c\n  lMMMMMWO0MNd:'\n   oollXMKXoxl;.\n     ':. .: .'\n              ..\n                .\n\x1b[0m",
+        raw_art: "${c1}          ,xXc\n      .l0MMMMMO\n   .kNMMMMMWMMMN,\n   KMMMMMMKMMMMMMo\n  'MMMMMMNKMMMMMM:\n  kMMMMMMOMMMMMMO\n .MMMMMMX0MMMMMW.\n oMMMMMMxWMMMMM:\n WMMMMMNkMMMMMO\n:MMMMMMOXMMMMW\n.0MMMMMxMMMMM;\n:;cKMMWxMMMMO\n'MMWMMXOMMMMl\n kMMMMKOMMMMMX:\n .WMMMMKOWMMM0c\n  lMMMMMWO0MNd:'\n   oollXMKXoxl;.\n     ':. .: .'\n              ..\n                .\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 18,
     },
+    Logo {
+        name: "Live Raizo",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;33m             `......`\n        -+shmNMMMMMMNmhs/.\n     :smMMMMMmmhyyhmmMMMMMmo-\n   -hMMMMd+:. `----` .:odMMMMh-\n `hMMMN+. .odNMMMMMMNdo. .yMMMMs`\n hMMMd. -dMMMMmdhhdNMMMNh` .mMMMh\noMMMm` :MMMNs.:sddy:-sMMMN- `NMMM+\nmMMMs  dMMMo sMMMMMMd yMMMd  sMMMm\n----`  .---` oNMMMMMh `---.  .----\n              .sMMy:\n               /MM/\n              +dMMms.\n             hMMMMMMN\n            `dMMMMMMm:\n      .+ss+sMNysMMoomMd+ss+.\n     +MMMMMMN` +MM/  hMMMMMNs\n     sMMMMMMm-hNMMMd-hMMMMMMd\n      :yddh+`hMMMMMMN :yddy/`\n             .hMMMMd:\n               `..`\n\x1b[0m",
+        raw_art: "${c1}             `......`\n        -+shmNMMMMMMNmhs/.\n     :smMMMMMmmhyyhmmMMMMMmo-\n   -hMMMMd+:. `----` .:odMMMMh-\n `hMMMN+. .odNMMMMMMNdo. .yMMMMs`\n hMMMd. -dMMMMmdhhdNMMMNh` .mMMMh\noMMMm` :MMMNs.:sddy:-sMMMN- `NMMM+\nmMMMs  dMMMo sMMMMMMd yMMMd  sMMMm\n----`  .---` oNMMMMMh `---.  .----\n              .sMMy:\n               /MM/\n              +dMMms.\n             hMMMMMMN\n            `dMMMMMMm:\n      .+ss+sMNysMMoomMd+ss+.\n     +MMMMMMN` +MM/  hMMMMMNs\n     sMMMMMMm-hNMMMd-hMMMMMMd\n      :yddh+`hMMMMMMN :yddy/`\n             .hMMMMd:\n               `..`\n",
+        colors: &[Color::Palette(3)],
+        max_line_length: 34,
+    },
     Logo {
         name: "Live_Raizo",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m             `......`\n        -+shmNMMMMMMNmhs/.\n     :smMMMMMmmhyyhmmMMMMMmo-\n   -hMMMMd+:. `----` .:odMMMMh-\n `hMMMN+. .odNMMMMMMNdo. .yMMMMs`\n hMMMd. -dMMMMmdhhdNMMMNh` .mMMMh\noMMMm` :MMMNs.:sddy:-sMMMN- `NMMM+\nmMMMs  dMMMo sMMMMMMd yMMMd  sMMMm\n----`  .---` oNMMMMMh `---.  .----\n              .sMMy:\n               /MM/\n              +dMMms.\n             hMMMMMMN\n            `dMMMMMMm:\n      .+ss+sMNysMMoomMd+ss+.\n     +MMMMMMN` +MM/  hMMMMMNs\n     sMMMMMMm-hNMMMd-hMMMMMMd\n      :yddh+`hMMMMMMN :yddy/`\n             .hMMMMd:\n               `..`\n\x1b[0m",
+        raw_art: "${c1}             `......`\n        -+shmNMMMMMMNmhs/.\n     :smMMMMMmmhyyhmmMMMMMmo-\n   -hMMMMd+:. `----` .:odMMMMh-\n `hMMMN+. .odNMMMMMMNdo. .yMMMMs`\n hMMMd. -dMMMMmdhhdNMMMNh` .mMMMh\noMMMm` :MMMNs.:sddy:-sMMMN- `NMMM+\nmMMMs  dMMMo sMMMMMMd yMMMd  sMMMm\n----`  .---` oNMMMMMh `---.  .----\n              .sMMy:\n               /MM/\n              +dMMms.\n             hMMMMMMN\n            `dMMMMMMm:\n      .+ss+sMNysMMoomMd+ss+.\n     +MMMMMMN` +MM/  hMMMMMNs\n     sMMMMMMm-hNMMMd-hMMMMMMd\n      :yddh+`hMMMMMMN :yddy/`\n             .hMMMMd:\n               `..`\n",
+        colors: &[Color::Palette(3)],
         max_line_length: 34,
     },
     Logo {
         name: "Lubuntu",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           `.:/ossyyyysso/:.\n        `.:yyyyyyyyyyyyyyyyyy:.`\n      .:yyyyyyyyyyyyyyyyyyyyyyyy:.\n    .:yyyyyyyyyyyyyyyyyyyyyyyyyyyy:.\n   -yyyyyyyyyyyyyy\x1b[1;37m+hNMMMNh+\x1b[1;34myyyyyyyyy-\n  :yy\x1b[1;37mmNy+\x1b[1;34myyyyyyyy\x1b[1;37m+Nmso++smMdhyysoo+\x1b[1;34myy:\n -yy\x1b[1;37m+MMMmmy\x1b[1;34myyyyyy\x1b[1;37mhh\x1b[1;34myyyyyyyyyyyyyyyyyyy-\n.yyyy\x1b[1;37mNMN\x1b[1;34myy\x1b[1;37mshhs\x1b[1;34myyy\x1b[1;37m+o\x1b[1;34myyyyyyyyyyyyyyyyyyyy.\n:yyyy\x1b[1;37moNM+\x1b[1;34myyyy\x1b[1;37m+sso\x1b[1;34myyyyyyy\x1b[1;37mss\x1b[1;34myyyyyyyyyyyyy:\n:yyyyy\x1b[1;37m+dNs\x1b[1;34myyyyyyy\x1b[1;37m++\x1b[1;34myyyyy\x1b[1;37moN+\x1b[1;34myyyyyyyyyyyy:\n:yyyyy\x1b[1;37moMMmhysso\x1b[1;34myyyyyyyyyy\x1b[1;37mmN+\x1b[1;34myyyyyyyyyyy:\n:yyyyyy\x1b[1;37mhMm\x1b[1;34myyyyy\x1b[1;37m+++\x1b[1;34myyyyyyy\x1b[1;37m+MN\x1b[1;34myyyyyyyyyyy:\n.yyyyyyy\x1b[1;37mohmy+\x1b[1;34myyyyyyyyyyyyy\x1b[1;37mNMh\x1b[1;34myyyyyyyyyy.\n -yyyyyyyyyy\x1b[1;37m++\x1b[1;34myyyyyyyyyyyy\x1b[1;37mMMh\x1b[1;34myyyyyyyyy-\n  :yyyyyyyyyyyyyyyyyyyyy\x1b[1;37m+mMN+\x1b[1;34myyyyyyyy:\n   -yyyyyyyyyyyyyyyyy\x1b[1;37m+sdMMd+\x1b[1;34myyyyyyyy-\n    .:yyyyyyyyy\x1b[1;37mhmdmmNMNdy+\x1b[1;34myyyyyyyy:.\n      .:yyyyyyy\x1b[1;37mmy\x1b[1;34myyyyyyyyyyyyyyy:.\n        `.:yyyy\x1b[1;37ms\x1b[1;34myyyyyyyyyyyyy:.`\n           `.:/oosyyyysso/:.`\n\x1b[0m",
+        raw_art: "${c1}           `.:/ossyyyysso/:.\n        `.:yyyyyyyyyyyyyyyyyy:.`\n      .:yyyyyyyyyyyyyyyyyyyyyyyy:.\n    .:yyyyyyyyyyyyyyyyyyyyyyyyyyyy:.\n   -yyyyyyyyyyyyyy${c2}+hNMMMNh+${c1}yyyyyyyyy-\n  :yy${c2}mNy+${c1}yyyyyyyy${c2}+Nmso++smMdhyysoo+${c1}yy:\n -yy${c2}+MMMmmy${c1}yyyyyy${c2}hh${c1}yyyyyyyyyyyyyyyyyyy-\n.yyyy${c2}NMN${c1}yy${c2}shhs${c1}yyy${c2}+o${c1}yyyyyyyyyyyyyyyyyyyy.\n:yyyy${c2}oNM+${c1}yyyy${c2}+sso${c1}yyyyyyy${c2}ss${c1}yyyyyyyyyyyyy:\n:yyyyy${c2}+dNs${c1}yyyyyyy${c2}++${c1}yyyyy${c2}oN+${c1}yyyyyyyyyyyy:\n:yyyyy${c2}oMMmhysso${c1}yyyyyyyyyy${c2}mN+${c1}yyyyyyyyyyy:\n:yyyyyy${c2}hMm${c1}yyyyy${c2}+++${c1}yyyyyyy${c2}+MN${c1}yyyyyyyyyyy:\n.yyyyyyy${c2}ohmy+${c1}yyyyyyyyyyyyy${c2}NMh${c1}yyyyyyyyyy.\n -yyyyyyyyyy${c2}++${c1}yyyyyyyyyyyy${c2}MMh${c1}yyyyyyyyy-\n  :yyyyyyyyyyyyyyyyyyyyy${c2}+mMN+${c1}yyyyyyyy:\n   -yyyyyyyyyyyyyyyyy${c2}+sdMMd+${c1}yyyyyyyy-\n    .:yyyyyyyyy${c2}hmdmmNMNdy+${c1}yyyyyyyy:.\n      .:yyyyyyy${c2}my${c1}yyyyyyyyyyyyyyy:.\n        `.:yyyy${c2}s${c1}yyyyyyyyyyyyy:.`\n           `.:/oosyyyysso/:.`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Lunar",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m`-.                                 `-.\n  -ohys/-`                    `:+shy/`\n     -omNNdyo/`          :+shmNNy/`\n             \x1b[1;33m      -\n                 /mMmo\n                 hMMMN`\n                 .NMMs\n    \x1b[1;34m  -:+oooo+//: \x1b[1;33m/MN\x1b[1;34m. -///oooo+/-`\n     /:.`          \x1b[1;33m/\x1b[1;34m           `.:/`\n\x1b[1;33m          __\n         |  |   _ _ ___ ___ ___\n         |  |__| | |   | .'|  _|\n         |_____|___|_|_|__,|_|\n\x1b[0m",
+        raw_art: "${c1}`-.                                 `-.\n  -ohys/-`                    `:+shy/`\n     -omNNdyo/`          :+shmNNy/`\n             ${c3}      -\n                 /mMmo\n                 hMMMN`\n                 .NMMs\n    ${c1}  -:+oooo+//: ${c3}/MN${c1}. -///oooo+/-`\n     /:.`          ${c3}/${c1}           `.:/`\n${c3}          __\n         |  |   _ _ ___ ___ ___\n         |  |__| | |   | .'|  _|\n         |_____|___|_|_|__,|_|\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(3)],
         max_line_length: 39,
     },
     Logo {
         name: "MX",
         is_wildcard: true,
         ascii_art: "\x1b[1;37mMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMNMMMMMMMMM\nMMMMMMMMMMNs..yMMMMMMMMMMMMMm: +NMMMMMMM\nMMMMMMMMMN+    :mMMMMMMMMMNo` -dMMMMMMMM\nMMMMMMMMMMMs.   `oNMMMMMMh- `sNMMMMMMMMM\nMMMMMMMMMMMMN/    -hMMMN+  :dMMMMMMMMMMM\nMMMMMMMMMMMMMMh-    +ms. .sMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMN+`   `  +NMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMNMMd:    .dMMMMMMMMMMMMMMM\nMMMMMMMMMMMMm/-hMd-     `sNMMMMMMMMMMMMM\nMMMMMMMMMMNo`   -` :h/    -dMMMMMMMMMMMM\nMMMMMMMMMd:       /NMMh-   `+NMMMMMMMMMM\nMMMMMMMNo`         :mMMN+`   `-hMMMMMMMM\nMMMMMMh.            `oNMMd:    `/mMMMMMM\nMMMMm/                -hMd-      `sNMMMM\nMMNs`                   -          :dMMM\nMm:                                 `oMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n\x1b[0m",
+        raw_art: "${c3}MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMNMMMMMMMMM\nMMMMMMMMMMNs..yMMMMMMMMMMMMMm: +NMMMMMMM\nMMMMMMMMMN+    :mMMMMMMMMMNo` -dMMMMMMMM\nMMMMMMMMMMMs.   `oNMMMMMMh- `sNMMMMMMMMM\nMMMMMMMMMMMMN/    -hMMMN+  :dMMMMMMMMMMM\nMMMMMMMMMMMMMMh-    +ms. .sMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMN+`   `  +NMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMNMMd:    .dMMMMMMMMMMMMMMM\nMMMMMMMMMMMMm/-hMd-     `sNMMMMMMMMMMMMM\nMMMMMMMMMMNo`   -` :h/    -dMMMMMMMMMMMM\nMMMMMMMMMd:       /NMMh-   `+NMMMMMMMMMM\nMMMMMMMNo`         :mMMN+`   `-hMMMMMMMM\nMMMMMMh.            `oNMMd:    `/mMMMMMM\nMMMMm/                -hMd-      `sNMMMM\nMMNs`                   -          :dMMM\nMm:                                 `oMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n",
+        colors: &[Color::Palette(4), Color::Palette(6), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Mageia",
         is_wildcard: true,
-        ascii_art: "\x1b[1;36m        .\u{00c2}\u{00b0}\u{00c2}\u{00b0}.\n         \u{00c2}\u{00b0}\u{00c2}\u{00b0}   .\u{00c2}\u{00b0}\u{00c2}\u{00b0}.\n         .\u{00c2}\u{00b0}\u{00c2}\u{00b0}\u{00c2}\u{00b0}. \u{00c2}\u{00b0}\u{00c2}\u{00b0}\n         .   .\n          \u{00c2}\u{00b0}\u{00c2}\u{00b0}\u{00c2}\u{00b0} .\u{00c2}\u{00b0}\u{00c2}\u{00b0}\u{00c2}\u{00b0}.\n      .\u{00c2}\u{00b0}\u{00c2}\u{00b0}\u{00c2}\u{00b0}.   '___'\n\x1b[1;37m     .\x1b[1;36m'___'     \x1b[1;37m   .\n   :dkxc;'.  ..,cxkd;\n .dkk. kkkkkkkkkk .kkd.\n.dkk.  ';cloolc;.  .kkd\nckk.                .kk;\nxO:                  cOd\nxO:                  lOd\nlOO.                .OO:\n.k00.              .00x\n .k00;            ;00O.\n  .lO0Kc;,,,,,,;c0KOc.\n     ;d00KKKKKK00d;\n        .,KKKK,.\n\x1b[0m",
+        ascii_art: "\x1b[1;36m        .\u{00b0}\u{00b0}.\n         \u{00b0}\u{00b0}   .\u{00b0}\u{00b0}.\n         .\u{00b0}\u{00b0}\u{00b0}. \u{00b0}\u{00b0}\n         .   .\n          \u{00b0}\u{00b0}\u{00b0} .\u{00b0}\u{00b0}\u{00b0}.\n      .\u{00b0}\u{00b0}\u{00b0}.   '___'\n\x1b[1;37m     .\x1b[1;36m'___'     \x1b[1;37m   .\n   :dkxc;'.  ..,cxkd;\n .dkk. kkkkkkkkkk .kkd.\n.dkk.  ';cloolc;.  .kkd\nckk.                .kk;\nxO:                  cOd\nxO:                  lOd\nlOO.                .OO:\n.k00.              .00x\n .k00;            ;00O.\n  .lO0Kc;,,,,,,;c0KOc.\n     ;d00KKKKKK00d;\n        .,KKKK,.\n\x1b[0m",
+        raw_art: "${c1}        .\u{00b0}\u{00b0}.\n         \u{00b0}\u{00b0}   .\u{00b0}\u{00b0}.\n         .\u{00b0}\u{00b0}\u{00b0}. \u{00b0}\u{00b0}\n         .   .\n          \u{00b0}\u{00b0}\u{00b0} .\u{00b0}\u{00b0}\u{00b0}.\n      .\u{00b0}\u{00b0}\u{00b0}.   '___'\n${c2}     .${c1}'___'     ${c2}   .\n   :dkxc;'.  ..,cxkd;\n .dkk. kkkkkkkkkk .kkd.\n.dkk.  ';cloolc;.  .kkd\nckk.                .kk;\nxO:                  cOd\nxO:                  lOd\nlOO.                .OO:\n.k00.              .00x\n .k00;            ;00O.\n  .lO0Kc;,,,,,,;c0KOc.\n     ;d00KKKKKK00d;\n        .,KKKK,.\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 24,
     },
     Logo {
         name: "MagpieOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m        ;00000     :000Ol\n     .x00kk00:    O0kk00k;\n    l00:   :00.  o0k   :O0k.\n  .k0k.     x\x1b[1;31md$dddd\x1b[1;32mk'    .d00;\n  k0k.      \x1b[1;31m.dddddl       \x1b[1;32mo00,\n o00.        \x1b[1;31m':cc:.        \x1b[1;32md0O\n.00l                       ,00.\nl00.                       d0x\nk0O                     .:k0o\nO0k                 ;dO0000d.\nk0O               .O0O\x1b[1;31mxxxxk\x1b[1;32m00:\no00.              k0O\x1b[1;31mdddddd\x1b[1;32mocc\n'00l              x0O\x1b[1;31mdddddo\x1b[1;33m;..\x1b[1;32m\n x00.             .x00\x1b[1;31mkxxd\x1b[1;33m:..\x1b[1;32m\n .O0x               .:oxxx\x1b[1;35mOkl.\x1b[1;32m\n  .x0d                     \x1b[1;35m,xx,\x1b[1;32m\n    .:o.          \x1b[1;35m.xd       ckd\x1b[1;32m\n       ..          \x1b[1;35mdxl     .xx;\n                    :xxolldxd'\n                      ;oxdl.\n\x1b[0m",
+        raw_art: "${c1}        ;00000     :000Ol\n     .x00kk00:    O0kk00k;\n    l00:   :00.  o0k   :O0k.\n  .k0k.     x${c2}d$dddd${c1}k'    .d00;\n  k0k.      ${c2}.dddddl       ${c1}o00,\n o00.        ${c2}':cc:.        ${c1}d0O\n.00l                       ,00.\nl00.                       d0x\nk0O                     .:k0o\nO0k                 ;dO0000d.\nk0O               .O0O${c2}xxxxk${c1}00:\no00.              k0O${c2}dddddd${c1}occ\n'00l              x0O${c2}dddddo${c3};..${c1}\n x00.             .x00${c2}kxxd${c3}:..${c1}\n .O0x               .:oxxx${c4}Okl.${c1}\n  .x0d                     ${c4},xx,${c1}\n    .:o.          ${c4}.xd       ckd${c1}\n       ..          ${c4}dxl     .xx;\n                    :xxolldxd'\n                      ;oxdl.\n",
+        colors: &[Color::Palette(2), Color::Palette(1), Color::Palette(3), Color::Palette(5)],
         max_line_length: 31,
     },
     Logo {
         name: "Mandrake",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m                        ``\n                       `-.\n\x1b[1;34m      `               \x1b[1;33m.---\n\x1b[1;34m    -/               \x1b[1;33m-::--`\n\x1b[1;34m  `++    \x1b[1;33m`----...```-:::::.\n\x1b[1;34m `os.      \x1b[1;33m.::::::::::::::-```     `  `\n\x1b[1;34m +s+         \x1b[1;33m.::::::::::::::::---...--`\n\x1b[1;34m-ss:          \x1b[1;33m`-::::::::::::::::-.``.``\n\x1b[1;34m/ss-           \x1b[1;33m.::::::::::::-.``   `\n\x1b[1;34m+ss:          \x1b[1;33m.::::::::::::-\n\x1b[1;34m/sso         \x1b[1;33m.::::::-::::::-\n\x1b[1;34m.sss/       \x1b[1;33m-:::-.`   .:::::\n\x1b[1;34m /sss+.    \x1b[1;33m..`\x1b[1;34m  `--`    \x1b[1;33m.:::\n\x1b[1;34m  -ossso+/:://+/-`        \x1b[1;33m.:`\n\x1b[1;34m    -/+ooo+/-.              \x1b[1;33m`\n\x1b[0m",
+        raw_art: "${c2}                        ``\n                       `-.\n${c1}      `               ${c2}.---\n${c1}    -/               ${c2}-::--`\n${c1}  `++    ${c2}`----...```-:::::.\n${c1} `os.      ${c2}.::::::::::::::-```     `  `\n${c1} +s+         ${c2}.::::::::::::::::---...--`\n${c1}-ss:          ${c2}`-::::::::::::::::-.``.``\n${c1}/ss-           ${c2}.::::::::::::-.``   `\n${c1}+ss:          ${c2}.::::::::::::-\n${c1}/sso         ${c2}.::::::-::::::-\n${c1}.sss/       ${c2}-:::-.`   .:::::\n${c1} /sss+.    ${c2}..`${c1}  `--`    ${c2}.:::\n${c1}  -ossso+/:://+/-`        ${c2}.:`\n${c1}    -/+ooo+/-.              ${c2}`\n",
+        colors: &[Color::Palette(4), Color::Palette(3)],
+        max_line_length: 39,
+    },
+    Logo {
+        name: "Mandriva",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;33m                        ``\n                       `-.\n\x1b[1;34m      `               \x1b[1;33m.---\n\x1b[1;34m    -/               \x1b[1;33m-::--`\n\x1b[1;34m  `++    \x1b[1;33m`----...```-:::::.\n\x1b[1;34m `os.      \x1b[1;33m.::::::::::::::-```     `  `\n\x1b[1;34m +s+         \x1b[1;33m.::::::::::::::::---...--`\n\x1b[1;34m-ss:          \x1b[1;33m`-::::::::::::::::-.``.``\n\x1b[1;34m/ss-           \x1b[1;33m.::::::::::::-.``   `\n\x1b[1;34m+ss:          \x1b[1;33m.::::::::::::-\n\x1b[1;34m/sso         \x1b[1;33m.::::::-::::::-\n\x1b[1;34m.sss/       \x1b[1;33m-:::-.`   .:::::\n\x1b[1;34m /sss+.    \x1b[1;33m..`\x1b[1;34m  `--`    \x1b[1;33m.:::\n\x1b[1;34m  -ossso+/:://+/-`        \x1b[1;33m.:`\n\x1b[1;34m    -/+ooo+/-.              \x1b[1;33m`\n\x1b[0m",
+        raw_art: "${c2}                        ``\n                       `-.\n${c1}      `               ${c2}.---\n${c1}    -/               ${c2}-::--`\n${c1}  `++    ${c2}`----...```-:::::.\n${c1} `os.      ${c2}.::::::::::::::-```     `  `\n${c1} +s+         ${c2}.::::::::::::::::---...--`\n${c1}-ss:          ${c2}`-::::::::::::::::-.``.``\n${c1}/ss-           ${c2}.::::::::::::-.``   `\n${c1}+ss:          ${c2}.::::::::::::-\n${c1}/sso         ${c2}.::::::-::::::-\n${c1}.sss/       ${c2}-:::-.`   .:::::\n${c1} /sss+.    ${c2}..`${c1}  `--`    ${c2}.:::\n${c1}  -ossso+/:://+/-`        ${c2}.:`\n${c1}    -/+ooo+/-.              ${c2}`\n",
+        colors: &[Color::Palette(4), Color::Palette(3)],
         max_line_length: 39,
     },
     Logo {
         name: "Manjaro",
         is_wildcard: true,
-        ascii_art: "\x1b[1;32m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}            \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\x1b[0m",
+        ascii_art: "\x1b[1;32m\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}            \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\x1b[0m",
+        raw_art: "${c1}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}            \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 28,
     },
     Logo {
         name: "Maui",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m             `.-://////:--`\n         .:/oooooooooooooooo+:.\n      `:+ooooooooooooooooooooooo:`\n    `:oooooooooooooooooooooooooooo/`\n    ..```-oooooo/-`` `:oooooo+:.` `--\n  :.      +oo+-`       /ooo/`       -/\n -o.     `o+-          +o/`         -o:\n`oo`     ::`  :o/     `+.  .+o`     /oo.\n/o+      .  -+oo-     `   /oo/     `ooo/\n+o-        /ooo+`       .+ooo.     :ooo+\n++       .+oooo:       -oooo+     `oooo+\n:.      .oooooo`      :ooooo-     :oooo:\n`      .oooooo:      :ooooo+     `ooo+-`\n      .+oooooo`     -oooooo:     `o/-\n      +oooooo:     .ooooooo.\n     /ooooooo`     /ooooooo/       ..\n    `:oooooooo/:::/ooooooooo+:--:/:`\n      `:+oooooooooooooooooooooo+:`\n         .:+oooooooooooooooo+:.\n             `.-://////:-.`\n\x1b[0m",
+        raw_art: "${c1}             `.-://////:--`\n         .:/oooooooooooooooo+:.\n      `:+ooooooooooooooooooooooo:`\n    `:oooooooooooooooooooooooooooo/`\n    ..```-oooooo/-`` `:oooooo+:.` `--\n  :.      +oo+-`       /ooo/`       -/\n -o.     `o+-          +o/`         -o:\n`oo`     ::`  :o/     `+.  .+o`     /oo.\n/o+      .  -+oo-     `   /oo/     `ooo/\n+o-        /ooo+`       .+ooo.     :ooo+\n++       .+oooo:       -oooo+     `oooo+\n:.      .oooooo`      :ooooo-     :oooo:\n`      .oooooo:      :ooooo+     `ooo+-`\n      .+oooooo`     -oooooo:     `o/-\n      +oooooo:     .ooooooo.\n     /ooooooo`     /ooooooo/       ..\n    `:oooooooo/:::/ooooooooo+:--:/:`\n      `:+oooooooooooooooooooooo+:`\n         .:+oooooooooooooooo+:.\n             `.-://////:-.`\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Mer",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                         dMs\n                         .-`\n                       `y`-o+`\n                        ``NMMy\n                      .--`:++.\n                    .hNNNNs\n                    /MMMMMN\n                    `ommmd/ +/\n                      ````  +/\n                     `:+sssso/-`\n  .-::. `-::-`     `smNMNmdmNMNd/      .://-`\n.ymNMNNdmNMMNm+`  -dMMh:.....+dMMs   `sNNMMNo\ndMN+::NMMy::hMM+  mMMo `ohhy/ `dMM+  yMMy::-\nMMm   yMM-  :MMs  NMN` `:::::--sMMh  dMM`\nMMm   yMM-  -MMs  mMM+ `ymmdsymMMMs  dMM`\nNNd   sNN-  -NNs  -mMNs-.--..:dMMh`  dNN\n---   .--`  `--.   .smMMmdddmMNdo`   .--\n                     ./ohddds+:`\n                     +h- `.:-.\n                     ./`.dMMMN+\n                        +MMMMMd\n                        `+dmmy-\n                      ``` .+`\n                     .dMNo-y.\n                     `hmm/\n                         .:`\n                         dMs\n\x1b[0m",
+        raw_art: "${c1}                         dMs\n                         .-`\n                       `y`-o+`\n                        ``NMMy\n                      .--`:++.\n                    .hNNNNs\n                    /MMMMMN\n                    `ommmd/ +/\n                      ````  +/\n                     `:+sssso/-`\n  .-::. `-::-`     `smNMNmdmNMNd/      .://-`\n.ymNMNNdmNMMNm+`  -dMMh:.....+dMMs   `sNNMMNo\ndMN+::NMMy::hMM+  mMMo `ohhy/ `dMM+  yMMy::-\nMMm   yMM-  :MMs  NMN` `:::::--sMMh  dMM`\nMMm   yMM-  -MMs  mMM+ `ymmdsymMMMs  dMM`\nNNd   sNN-  -NNs  -mMNs-.--..:dMMh`  dNN\n---   .--`  `--.   .smMMmdddmMNdo`   .--\n                     ./ohddds+:`\n                     +h- `.:-.\n                     ./`.dMMMN+\n                        +MMMMMd\n                        `+dmmy-\n                      ``` .+`\n                     .dMNo-y.\n                     `hmm/\n                         .:`\n                         dMs\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 45,
     },
     Logo {
         name: "Minix",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m   -sdhyo+:-`                -/syymm:\n   sdyooymmNNy.     ``    .smNmmdysNd\n   odyoso+syNNmysoyhhdhsoomNmm+/osdm/\n    :hhy+-/syNNmddhddddddmNMNo:sdNd:\n     `smNNdNmmNmddddddddddmmmmmmmy`\n   `ohhhhdddddmmNNdmddNmNNmdddddmdh-\n   odNNNmdyo/:/-/hNddNy-`..-+ydNNNmd:\n `+mNho:`   smmd/ sNNh :dmms`   -+ymmo.\n-od/       -m\x1b[1;31mmm\x1b[1;37mmo -NN+ +m\x1b[1;31mmm\x1b[1;37mm-       yms:\n+sms -.`    :so:  .NN+  :os/     .-`mNh:\n.-hyh+:////-     -sNNd:`    .--://ohNs-\n `:hNNNNNNNMMd/sNMmhsdMMh/ymmNNNmmNNy/\n  -+sNNNNMMNNNsmNMo: :NNmymNNNNMMMms:\n    //oydNMMMMydMMNysNMMmsMMMMMNyo/`\n       ../-yNMMy--/::/-.sMMmos+.`\n           -+oyhNsooo+omy/```\n              `::ohdmds-`\n\x1b[0m",
+        raw_art: "${c2}   -sdhyo+:-`                -/syymm:\n   sdyooymmNNy.     ``    .smNmmdysNd\n   odyoso+syNNmysoyhhdhsoomNmm+/osdm/\n    :hhy+-/syNNmddhddddddmNMNo:sdNd:\n     `smNNdNmmNmddddddddddmmmmmmmy`\n   `ohhhhdddddmmNNdmddNmNNmdddddmdh-\n   odNNNmdyo/:/-/hNddNy-`..-+ydNNNmd:\n `+mNho:`   smmd/ sNNh :dmms`   -+ymmo.\n-od/       -m${c1}mm${c2}mo -NN+ +m${c1}mm${c2}m-       yms:\n+sms -.`    :so:  .NN+  :os/     .-`mNh:\n.-hyh+:////-     -sNNd:`    .--://ohNs-\n `:hNNNNNNNMMd/sNMmhsdMMh/ymmNNNmmNNy/\n  -+sNNNNMMNNNsmNMo: :NNmymNNNNMMMms:\n    //oydNMMMMydMMNysNMMmsMMMMMNyo/`\n       ../-yNMMy--/::/-.sMMmos+.`\n           -+oyhNsooo+omy/```\n              `::ohdmds-`\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "Namib",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m          .:+shysyhhhhysyhs+:.\n       -/yyys              syyy/-\n     -shy                      yhs-\n   -yhs                          shy-\n  +hy                              yh+\n +ds                                sd+\n/ys                  so              sy/\nsh                 smMMNdyo           hs\nyo               ymMMMMNNMMNho        oy\nN             ydMMMNNMMMMMMMMMmy       N\nN         shmMMMMNNMMMMMMMMMMMMMNy     N\nyo  ooshmNMMMNNNNMMMMMMMMMMMMMMMMMms  oy\nsd yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy ds\n/ys                                  sy/\n +ds                                sd+\n  +hy                              yh+\n   -yhs                          shy-\n     -shy                      yhs-\n       -/yyys              syyy/-\n          .:+shysyhyhhysyhs+:.\n\x1b[0m",
+        raw_art: "${c1}          .:+shysyhhhhysyhs+:.\n       -/yyys              syyy/-\n     -shy                      yhs-\n   -yhs                          shy-\n  +hy                              yh+\n +ds                                sd+\n/ys                  so              sy/\nsh                 smMMNdyo           hs\nyo               ymMMMMNNMMNho        oy\nN             ydMMMNNMMMMMMMMMmy       N\nN         shmMMMMNNMMMMMMMMMMMMMNy     N\nyo  ooshmNMMMNNNNMMMMMMMMMMMMMMMMMms  oy\nsd yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy ds\n/ys                                  sy/\n +ds                                sd+\n  +hy                              yh+\n   -yhs                          shy-\n     -shy                      yhs-\n       -/yyys              syyy/-\n          .:+shysyhyhhysyhs+:.\n",
+        colors: &[Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Neptune",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m            ./+sydddddddys/-.\n        .+ymNNdyooo/:+oooymNNmy/`\n     `/hNNh/.`             `-+dNNy:`\n    /mMd/.          .++.:oy/   .+mMd-\n  `sMN/             oMMmdy+.     `oNNo\n `hMd.           `/ymy/.           :NMo\n oMN-          `/dMd:               /MM-\n`mMy          -dMN+`                 mMs\n.MMo         -NMM/                   yMs\n dMh         mMMMo:`                `NMo\n /MM/        /ymMMMm-               sMN.\n  +Mm:         .hMMd`              oMN/\n   +mNs.      `yNd/`             -dMm-\n    .yMNs:    `/.`            `/yNNo`\n      .odNNy+-`           .:ohNNd/.\n         -+ymNNmdyyyyyyydmNNmy+.\n             `-//sssssss//.\n\x1b[0m",
+        raw_art: "${c1}            ./+sydddddddys/-.\n        .+ymNNdyooo/:+oooymNNmy/`\n     `/hNNh/.`             `-+dNNy:`\n    /mMd/.          .++.:oy/   .+mMd-\n  `sMN/             oMMmdy+.     `oNNo\n `hMd.           `/ymy/.           :NMo\n oMN-          `/dMd:               /MM-\n`mMy          -dMN+`                 mMs\n.MMo         -NMM/                   yMs\n dMh         mMMMo:`                `NMo\n /MM/        /ymMMMm-               sMN.\n  +Mm:         .hMMd`              oMN/\n   +mNs.      `yNd/`             -dMm-\n    .yMNs:    `/.`            `/yNNo`\n      .odNNy+-`           .:ohNNd/.\n         -+ymNNmdyyyyyyydmNNmy+.\n             `-//sssssss//.\n",
+        colors: &[Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "NetBSD",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m                     `-/oshdmNMNdhyo+:-`\n\x1b[1;37my\x1b[1;35m/s+:-``    `.-:+oydNMMMMNhs/-``\n\x1b[1;37m-m+\x1b[1;35mNMMMMMMMMMMMMMMMMMMMNdhmNMMMmdhs+/-`\n \x1b[1;37m-m+\x1b[1;35mNMMMMMMMMMMMMMMMMMMMMmy+:`\n  \x1b[1;37m-N/\x1b[1;35mdMMMMMMMMMMMMMMMds:`\n   \x1b[1;37m-N/\x1b[1;35mhMMMMMMMMMmho:`\n    \x1b[1;37m-N/\x1b[1;35m-:/++/:.`\n\x1b[1;37m     :M+\n      :Mo\n       :Ms\n        :Ms\n         :Ms\n          :Ms\n           :Ms\n            :Ms\n             :Ms\n              :Ms\n\x1b[0m",
+        raw_art: "${c1}                     `-/oshdmNMNdhyo+:-`\n${c2}y${c1}/s+:-``    `.-:+oydNMMMMNhs/-``\n${c2}-m+${c1}NMMMMMMMMMMMMMMMMMMMNdhmNMMMmdhs+/-`\n ${c2}-m+${c1}NMMMMMMMMMMMMMMMMMMMMmy+:`\n  ${c2}-N/${c1}dMMMMMMMMMMMMMMMds:`\n   ${c2}-N/${c1}hMMMMMMMMMmho:`\n    ${c2}-N/${c1}-:/++/:.`\n${c2}     :M+\n      :Mo\n       :Ms\n        :Ms\n         :Ms\n          :Ms\n           :Ms\n            :Ms\n             :Ms\n              :Ms\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Netrunner",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           .:oydmMMMMMMmdyo:`\n        -smMMMMMMMMMMMMMMMMMMds-\n      +mMMMMMMMMMMMMMMMMMMMMMMMMd+\n    /mMMMMMMMMMMMMMMMMMMMMMMMMMMMMm/\n  `hMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMy`\n .mMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMd`\n dMMMMMMMMMMMMMMMMMMMMMMNdhmMMMMMMMMMMh\n+MMMMMMMMMMMMMNmhyo+/-.   -MMMMMMMMMMMM/\nmMMMMMMMMd+:.`           `mMMMMMMMMMMMMd\nMMMMMMMMMMMdy/.          yMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMNh+`     +MMMMMMMMMMMMMMM\nmMMMMMMMMMMMMMMMMMs    -NMMMMMMMMMMMMMMd\n+MMMMMMMMMMMMMMMMMN.  `mMMMMMMMMMMMMMMM/\n dMMMMMMMMMMMMMMMMMy  hMMMMMMMMMMMMMMMh\n `dMMMMMMMMMMMMMMMMM-+MMMMMMMMMMMMMMMd`\n  `hMMMMMMMMMMMMMMMMmMMMMMMMMMMMMMMMy\n    /mMMMMMMMMMMMMMMMMMMMMMMMMMMMMm:\n      +dMMMMMMMMMMMMMMMMMMMMMMMMd/\n        -odMMMMMMMMMMMMMMMMMMdo-\n           `:+ydmNMMMMNmhy+-`\n\x1b[0m",
+        raw_art: "${c1}           .:oydmMMMMMMmdyo:`\n        -smMMMMMMMMMMMMMMMMMMds-\n      +mMMMMMMMMMMMMMMMMMMMMMMMMd+\n    /mMMMMMMMMMMMMMMMMMMMMMMMMMMMMm/\n  `hMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMy`\n .mMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMd`\n dMMMMMMMMMMMMMMMMMMMMMMNdhmMMMMMMMMMMh\n+MMMMMMMMMMMMMNmhyo+/-.   -MMMMMMMMMMMM/\nmMMMMMMMMd+:.`           `mMMMMMMMMMMMMd\nMMMMMMMMMMMdy/.          yMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMNh+`     +MMMMMMMMMMMMMMM\nmMMMMMMMMMMMMMMMMMs    -NMMMMMMMMMMMMMMd\n+MMMMMMMMMMMMMMMMMN.  `mMMMMMMMMMMMMMMM/\n dMMMMMMMMMMMMMMMMMy  hMMMMMMMMMMMMMMMh\n `dMMMMMMMMMMMMMMMMM-+MMMMMMMMMMMMMMMd`\n  `hMMMMMMMMMMMMMMMMmMMMMMMMMMMMMMMMy\n    /mMMMMMMMMMMMMMMMMMMMMMMMMMMMMm:\n      +dMMMMMMMMMMMMMMMMMMMMMMMMd/\n        -odMMMMMMMMMMMMMMMMMMdo-\n           `:+ydmNMMMMNmhy+-`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Nitrux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m`:/.\n`/yo\n`/yo\n`/yo      .+:.\n`/yo      .sys+:.`\n`/yo       `-/sys+:.`\n`/yo           ./sss+:.`\n`/yo              .:oss+:-`\n`/yo                 ./o///:-`\n`/yo              `.-:///////:`\n`/yo           `.://///++//-``\n`/yo       `.-:////++++/-`\n`/yo    `-://///++o+/-`\n`/yo `-/+o+++ooo+/-`\n`/s+:+oooossso/.`\n`//+sssssso:.\n`+syyyy+:`\n:+s+-\n\x1b[0m",
+        raw_art: "${c1}`:/.\n`/yo\n`/yo\n`/yo      .+:.\n`/yo      .sys+:.`\n`/yo       `-/sys+:.`\n`/yo           ./sss+:.`\n`/yo              .:oss+:-`\n`/yo                 ./o///:-`\n`/yo              `.-:///////:`\n`/yo           `.://///++//-``\n`/yo       `.-:////++++/-`\n`/yo    `-://///++o+/-`\n`/yo `-/+o+++ooo+/-`\n`/s+:+oooossso/.`\n`//+sssssso:.\n`+syyyy+:`\n:+s+-\n",
+        colors: &[Color::Palette(4)],
         max_line_length: 31,
     },
     Logo {
         name: "NixOS",
         is_wildcard: true,
-        ascii_art: "\x1b[1;34m          \u{00e2}\u{0096}\u{0097}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}       \x1b[1;36m\u{00e2}\u{0096}\u{0097}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}    \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0096}\n\x1b[1;34m          \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}  \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;34m           \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;34m            \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;34m     \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099} \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}     \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0099}\n\x1b[1;34m    \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099} \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}    \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\n\x1b[1;36m           \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0096}           \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}  \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m          \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}             \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b} \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m         \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}               \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{009b} \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}                  \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\n\x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}                  \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m      \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b} \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0099}               \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m     \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b} \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}             \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m    \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}  \x1b[1;34m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}           \u{00e2}\u{0096}\u{009d}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\n\x1b[1;36m    \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}    \x1b[1;34m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099} \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;36m     \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{009b}     \x1b[1;34m\u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099} \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\n\x1b[1;34m           \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\n\x1b[1;34m          \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\n\x1b[1;34m         \u{00e2}\u{0096}\u{009f}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{009b}  \u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}       \x1b[1;36m\u{00e2}\u{0096}\u{009c}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0099}\n\x1b[1;34m         \u{00e2}\u{0096}\u{009d}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}    \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0098}       \x1b[1;36m\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0098}\n\x1b[0m",
+        ascii_art: "\x1b[1;34m          \u{2597}\u{2584}\u{2584}\u{2584}       \x1b[1;36m\u{2597}\u{2584}\u{2584}\u{2584}\u{2584}    \u{2584}\u{2584}\u{2584}\u{2596}\n\x1b[1;34m          \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}  \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;34m           \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;34m            \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;34m     \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}     \x1b[1;34m\u{259f}\u{2599}\n\x1b[1;34m    \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}    \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2599}\n\x1b[1;36m           \u{2584}\u{2584}\u{2584}\u{2584}\u{2596}           \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}  \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m          \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}             \u{259c}\u{2588}\u{2588}\u{259b} \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m         \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}               \u{259c}\u{259b} \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}                  \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599}\n\x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}                  \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m      \u{259f}\u{2588}\u{2588}\u{2588}\u{259b} \x1b[1;34m\u{259f}\u{2599}               \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m     \u{259f}\u{2588}\u{2588}\u{2588}\u{259b} \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2599}             \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m    \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}  \x1b[1;34m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}           \u{259d}\u{2580}\u{2580}\u{2580}\u{2580}\n\x1b[1;36m    \u{259c}\u{2588}\u{2588}\u{259b}    \x1b[1;34m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599} \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;36m     \u{259c}\u{259b}     \x1b[1;34m\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n\x1b[1;34m           \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n\x1b[1;34m          \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n\x1b[1;34m         \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}  \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       \x1b[1;36m\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n\x1b[1;34m         \u{259d}\u{2580}\u{2580}\u{2580}    \u{2580}\u{2580}\u{2580}\u{2580}\u{2598}       \x1b[1;36m\u{2580}\u{2580}\u{2580}\u{2598}\n\x1b[0m",
+        raw_art: "${c1}          \u{2597}\u{2584}\u{2584}\u{2584}       ${c2}\u{2597}\u{2584}\u{2584}\u{2584}\u{2584}    \u{2584}\u{2584}\u{2584}\u{2596}\n${c1}          \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}  \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c1}           \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c1}            \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n${c1}     \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}     ${c1}\u{259f}\u{2599}\n${c1}    \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}    ${c1}\u{259f}\u{2588}\u{2588}\u{2599}\n${c2}           \u{2584}\u{2584}\u{2584}\u{2584}\u{2596}           \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}  ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}          \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}             \u{259c}\u{2588}\u{2588}\u{259b} ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}         \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}               \u{259c}\u{259b} ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}                  ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599}\n${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}                  ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}      \u{259f}\u{2588}\u{2588}\u{2588}\u{259b} ${c1}\u{259f}\u{2599}               \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}     \u{259f}\u{2588}\u{2588}\u{2588}\u{259b} ${c1}\u{259f}\u{2588}\u{2588}\u{2599}             \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}    \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}  ${c1}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}           \u{259d}\u{2580}\u{2580}\u{2580}\u{2580}\n${c2}    \u{259c}\u{2588}\u{2588}\u{259b}    ${c1}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599} ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n${c2}     \u{259c}\u{259b}     ${c1}\u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599} ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{259b}\n${c1}           \u{259f}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n${c1}          \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n${c1}         \u{259f}\u{2588}\u{2588}\u{2588}\u{259b}  \u{259c}\u{2588}\u{2588}\u{2588}\u{2599}       ${c2}\u{259c}\u{2588}\u{2588}\u{2588}\u{2599}\n${c1}         \u{259d}\u{2580}\u{2580}\u{2580}    \u{2580}\u{2580}\u{2580}\u{2580}\u{2598}       ${c2}\u{2580}\u{2580}\u{2580}\u{2598}\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 43,
     },
     Logo {
         name: "NuTyX",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                                      .\n                                    .\n                                 ...\n                               ...\n            ....     .........--.\n       ..-++-----....--++++++---.\n    .-++++++-.   .-++++++++++++-----..\n  .--...  .++..-+++--.....-++++++++++--..\n .     .-+-. .**-            ....  ..-+----..\n     .+++.  .*+.         +            -++-----.\n   .+++++-  ++.         .*+.     .....-+++-----.\n  -+++-++. .+.          .-+***++***++--++++.  .\n -+-. --   -.          -*- ......        ..--.\n.-. .+-    .          -+.\n.  .+-                +.\n   --                 --\n  -+----.              .-\n  -++-.+.                .\n .++. --\n  +.  ----.\n  .  .+. ..\n      -  .\n      .\n\x1b[0m",
+        raw_art: "${c1}                                      .\n                                    .\n                                 ...\n                               ...\n            ....     .........--.\n       ..-++-----....--++++++---.\n    .-++++++-.   .-++++++++++++-----..\n  .--...  .++..-+++--.....-++++++++++--..\n .     .-+-. .**-            ....  ..-+----..\n     .+++.  .*+.         +            -++-----.\n   .+++++-  ++.         .*+.     .....-+++-----.\n  -+++-++. .+.          .-+***++***++--++++.  .\n -+-. --   -.          -*- ......        ..--.\n.-. .+-    .          -+.\n.  .+-                +.\n   --                 --\n  -+----.              .-\n  -++-.+.                .\n .++. --\n  +.  ----.\n  .  .+. ..\n      -  .\n      .\n",
+        colors: &[Color::Palette(4), Color::Palette(1)],
         max_line_length: 48,
     },
     Logo {
         name: "Nurunner",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                  ,xc\n                ;00cxXl\n              ;K0,   .xNo.\n            :KO'       .lXx.\n          cXk.    ;xl     cXk.\n        cXk.    ;k:.,xo.    cXk.\n     .lXx.    :x::0MNl,dd.    :KO,\n   .xNx.    cx;:KMMMMMNo'dx.    ;KK;\n .dNl.    cd,cXMMMMMMMMMWd,ox'    'OK:\n;WK.    'K,.KMMMMMMMMMMMMMWc.Kx     lMO\n 'OK:    'dl'xWMMMMMMMMMM0::x:    'OK:\n   .kNo    .xo'xWMMMMMM0;:O:    ;KK;\n     .dXd.   .do,oNMMO;ck:    ;00,\n        oNd.   .dx,;'cO;    ;K0,\n          oNx.    okk;    ;K0,\n            lXx.        :KO'\n              cKk'    cXk.\n                ;00:lXx.\n                  ,kd.\n\x1b[0m",
+        raw_art: "${c1}                  ,xc\n                ;00cxXl\n              ;K0,   .xNo.\n            :KO'       .lXx.\n          cXk.    ;xl     cXk.\n        cXk.    ;k:.,xo.    cXk.\n     .lXx.    :x::0MNl,dd.    :KO,\n   .xNx.    cx;:KMMMMMNo'dx.    ;KK;\n .dNl.    cd,cXMMMMMMMMMWd,ox'    'OK:\n;WK.    'K,.KMMMMMMMMMMMMMWc.Kx     lMO\n 'OK:    'dl'xWMMMMMMMMMM0::x:    'OK:\n   .kNo    .xo'xWMMMMMM0;:O:    ;KK;\n     .dXd.   .do,oNMMO;ck:    ;00,\n        oNd.   .dx,;'cO;    ;K0,\n          oNx.    okk;    ;K0,\n            lXx.        :KO'\n              cKk'    cXk.\n                ;00:lXx.\n                  ,kd.\n",
+        colors: &[Color::Palette(4)],
         max_line_length: 39,
     },
     Logo {
         name: "OBRevenge",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m   __   __\n     _@@@@   @@@g_\n   _@@@@@@   @@@@@@\n  _@@@@@@M   W@@@@@@_\n j@@@@P        ^W@@@@\n @@@@L____  _____Q@@@@\nQ@@@@@@@@@@j@@@@@@@@@@\n@@@@@    T@j@    T@@@@@\n@@@@@ ___Q@J@    _@@@@@\n@@@@@fMMM@@j@jggg@@@@@@\n@@@@@    j@j@^MW@P @@@@\nQ@@@@@ggg@@f@   @@@@@@L\n^@@@@WWMMP  ^    Q@@@@\n @@@@@_         _@@@@l\n  W@@@@@g_____g@@@@@P\n   @@@@@@@@@@@@@@@@l\n    ^W@@@@@@@@@@@P\n       ^TMMMMTll\n\x1b[0m",
+        raw_art: "${c1}   __   __\n     _@@@@   @@@g_\n   _@@@@@@   @@@@@@\n  _@@@@@@M   W@@@@@@_\n j@@@@P        ^W@@@@\n @@@@L____  _____Q@@@@\nQ@@@@@@@@@@j@@@@@@@@@@\n@@@@@    T@j@    T@@@@@\n@@@@@ ___Q@J@    _@@@@@\n@@@@@fMMM@@j@jggg@@@@@@\n@@@@@    j@j@^MW@P @@@@\nQ@@@@@ggg@@f@   @@@@@@L\n^@@@@WWMMP  ^    Q@@@@\n @@@@@_         _@@@@l\n  W@@@@@g_____g@@@@@P\n   @@@@@@@@@@@@@@@@l\n    ^W@@@@@@@@@@@P\n       ^TMMMMTll\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 23,
     },
     Logo {
         name: "OS Elbrus",
         is_wildcard: true,
-        ascii_art: "\x1b[1;34m   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}                       \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}                  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}                       \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\x1b[0m",
+        ascii_art: "\x1b[1;34m   \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\n   \u{2588}\u{2588}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2588}\u{2588}\n   \u{2588}\u{2588}                       \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}                  \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2588}\u{2588}\n   \u{2588}\u{2588}                       \u{2588}\u{2588}\n   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\x1b[0m",
+        raw_art: "${c1}   \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\n   \u{2588}\u{2588}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2588}\u{2588}\n   \u{2588}\u{2588}                       \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}                  \u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2588}\u{2588}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2588}\u{2588}\n   \u{2588}\u{2588}   \u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2580}\u{2588}\u{2588}\n   \u{2588}\u{2588}                       \u{2588}\u{2588}\n   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(3)],
         max_line_length: 30,
     },
     Logo {
         name: "Obarun",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                    ,;::::;\n                ;cooolc;,\n             ,coool;\n           ,loool,\n          loooo;\n        :ooool\n       cooooc            ,:ccc;\n      looooc           :oooooool\n     cooooo          ;oooooooooo,\n    :ooooo;         :ooooooooooo\n    oooooo          oooooooooooc\n   :oooooo         :ooooooooool\n   loooooo         ;oooooooool\n   looooooc        .coooooooc\n   cooooooo:           ,;co;\n   ,ooooooool;       ,:loc\n    cooooooooooooloooooc\n     ;ooooooooooooool;\n       ;looooooolc;\n\x1b[0m",
+        raw_art: "${c1}                    ,;::::;\n                ;cooolc;,\n             ,coool;\n           ,loool,\n          loooo;\n        :ooool\n       cooooc            ,:ccc;\n      looooc           :oooooool\n     cooooo          ;oooooooooo,\n    :ooooo;         :ooooooooooo\n    oooooo          oooooooooooc\n   :oooooo         :ooooooooool\n   loooooo         ;oooooooool\n   looooooc        .coooooooc\n   cooooooo:           ,;co;\n   ,ooooooool;       ,:loc\n    cooooooooooooloooooc\n     ;ooooooooooooool;\n       ;looooooolc;\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 33,
     },
+    Logo {
+        name: "Open Source Media Center",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m            -+shdmNNNNmdhs+-\n        .+hMNho/:..``..:/ohNMh+.\n      :hMdo.                .odMh:\n    -dMy-                      -yMd-\n   sMd-                          -dMs\n  hMy       +.            .+       yMh\n yMy        dMs.        .sMd        yMy\n:Mm         dMNMs`    `sMNMd        `mM:\nyM+         dM//mNs``sNm//Md         +My\nmM-         dM:  +NNNN+  :Md         -Mm\nmM-         dM: `oNN+    :Md         -Mm\nyM+         dM/+NNo`     :Md         +My\n:Mm`        dMMNs`       :Md        `mM:\n yMy        dMs`         -ms        yMy\n  hMy       +.                     yMh\n   sMd-                          -dMs\n    -dMy-                      -yMd-\n      :hMdo.                .odMh:\n        .+hMNho/:..``..:/ohNMh+.\n            -+shdmNNNNmdhs+-\n\x1b[0m",
+        raw_art: "${c1}            -+shdmNNNNmdhs+-\n        .+hMNho/:..``..:/ohNMh+.\n      :hMdo.                .odMh:\n    -dMy-                      -yMd-\n   sMd-                          -dMs\n  hMy       +.            .+       yMh\n yMy        dMs.        .sMd        yMy\n:Mm         dMNMs`    `sMNMd        `mM:\nyM+         dM//mNs``sNm//Md         +My\nmM-         dM:  +NNNN+  :Md         -Mm\nmM-         dM: `oNN+    :Md         -Mm\nyM+         dM/+NNo`     :Md         +My\n:Mm`        dMMNs`       :Md        `mM:\n yMy        dMs`         -ms        yMy\n  hMy       +.                     yMh\n   sMd-                          -dMs\n    -dMy-                      -yMd-\n      :hMdo.                .odMh:\n        .+hMNho/:..``..:/ohNMh+.\n            -+shdmNNNNmdhs+-\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 40,
+    },
     Logo {
         name: "OpenBSD",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m                                     _\n                                    (_)\n\x1b[1;33m              |    .\n\x1b[1;33m          .   |L  /|   .         \x1b[1;36m _\n\x1b[1;33m      _ . |\\ _| \\--+._/| .       \x1b[1;36m(_)\n\x1b[1;33m     / ||\\| Y J  )   / |/| ./\n    J  |)'( |        ` F`.'/       \x1b[1;36m _\n\x1b[1;33m  -<|  F         __     .-<        \x1b[1;36m(_)\n\x1b[1;33m    | /       .-'\x1b[1;36m. \x1b[1;33m`.  /\x1b[1;36m-. \x1b[1;33mL___\n    J \\\\      <    \x1b[1;36m\\ \x1b[1;33m | | \x1b[1;38;5;8mO\x1b[1;36m\\\\\x1b[1;33m|.-' \x1b[1;36m _\n\x1b[1;33m  _J \\\\  .-    \\\\\x1b[1;36m/ \x1b[1;38;5;8mO \x1b[1;36m| \x1b[1;33m| \\\\  |\x1b[1;33mF    \x1b[1;36m(_)\n\x1b[1;33m '-F  -<_.     \\\\   .-'  `-' L__\n__J  _   _.     >-'  \x1b[1;33m)\x1b[1;31m._.   \x1b[1;33m|-'\n\x1b[1;33m `-|.'   /_.          \x1b[1;31m\\_|  \x1b[1;33m F\n  /.-   .                _.<\n /'    /.'             .'  `\\\\\n  /L  /'   |/      _.-'-\\\\\n /'J       ___.---'\\|\n   |\\  .--' V  | `. `\n   |/`. `-.     `._)\n      / .-.\\\\\n      \\\\ (  `\\\\\n       `.\\\\\n\x1b[0m",
+        raw_art: "${c3}                                     _\n                                    (_)\n${c1}              |    .\n${c1}          .   |L  /|   .         ${c3} _\n${c1}      _ . |\\ _| \\--+._/| .       ${c3}(_)\n${c1}     / ||\\| Y J  )   / |/| ./\n    J  |)'( |        ` F`.'/       ${c3} _\n${c1}  -<|  F         __     .-<        ${c3}(_)\n${c1}    | /       .-'${c3}. ${c1}`.  /${c3}-. ${c1}L___\n    J \\\\      <    ${c3}\\ ${c1} | | ${c5}O${c3}\\\\${c1}|.-' ${c3} _\n${c1}  _J \\\\  .-    \\\\${c3}/ ${c5}O ${c3}| ${c1}| \\\\  |${c1}F    ${c3}(_)\n${c1} '-F  -<_.     \\\\   .-'  `-' L__\n__J  _   _.     >-'  ${c1})${c4}._.   ${c1}|-'\n${c1} `-|.'   /_.          ${c4}\\_|  ${c1} F\n  /.-   .                _.<\n /'    /.'             .'  `\\\\\n  /L  /'   |/      _.-'-\\\\\n /'J       ___.---'\\|\n   |\\  .--' V  | `. `\n   |/`. `-.     `._)\n      / .-.\\\\\n      \\\\ (  `\\\\\n       `.\\\\\n",
+        colors: &[Color::Palette(3), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
         max_line_length: 39,
     },
     Logo {
         name: "OpenIndiana",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                         .sy/\n                         .yh+\n\n           \x1b[1;34m-+syyyo+-     \x1b[1;37m /+.\n         \x1b[1;34m+ddo/---/sdh/   \x1b[1;37m ym-\n       \x1b[1;34m`hm+        `sms\x1b[1;37m   ym-```````.-.\n       \x1b[1;34msm+           sm/ \x1b[1;37m ym-         +s\n       \x1b[1;34mhm.           /mo \x1b[1;37m ym-         /h\n       \x1b[1;34momo           ym: \x1b[1;37m ym-       `os`\n        \x1b[1;34msmo`       .ym+ \x1b[1;37m  ym-     .os-\n     ``  \x1b[1;34m:ymy+///oyms- \x1b[1;37m   ym-  .+s+.\n   ..`     \x1b[1;34m`:+oo+/-`  \x1b[1;37m    -//oyo-\n -:`                   .:oys/.\n+-               `./oyys/.\nh+`      `.-:+oyyyo/-`\n`/ossssysso+/-.`\n\x1b[0m",
+        raw_art: "${c2}                         .sy/\n                         .yh+\n\n           ${c1}-+syyyo+-     ${c2} /+.\n         ${c1}+ddo/---/sdh/   ${c2} ym-\n       ${c1}`hm+        `sms${c2}   ym-```````.-.\n       ${c1}sm+           sm/ ${c2} ym-         +s\n       ${c1}hm.           /mo ${c2} ym-         /h\n       ${c1}omo           ym: ${c2} ym-       `os`\n        ${c1}smo`       .ym+ ${c2}  ym-     .os-\n     ``  ${c1}:ymy+///oyms- ${c2}   ym-  .+s+.\n   ..`     ${c1}`:+oo+/-`  ${c2}    -//oyo-\n -:`                   .:oys/.\n+-               `./oyys/.\nh+`      `.-:+oyyyo/-`\n`/ossssysso+/-.`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "OpenMandriva",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                  ``````\n            `-:/+++++++//:-.`\n         .:+++oooo+/:.``   ``\n      `:+ooooooo+:.  `-:/++++++/:.`\n     -+oooooooo:` `-++o+/::::://+o+/-\n   `/ooooooooo-  -+oo/.`        `-/oo+.\n  `+ooooooooo.  :os/`              .+so:\n  +sssssssss/  :ss/                 `+ss-\n :ssssssssss`  sss`                  .sso\n ossssssssss  `yyo                    sys\n`sssssssssss` `yys                   `yys\n`sssssssssss:  +yy/                  +yy:\n oyyyyyyyyyys. `oyy/`              `+yy+\n :yyyyyyyyyyyo. `+yhs:.         `./shy/\n  oyyyyyyyyyyys:` .oyhys+:----/+syhy+. `\n  `syyyyyyyyyyyyo-` .:osyhhhhhyys+:``.:`\n   `oyyyyyyyyyyyyys+-`` `.----.```./oo.\n     /yhhhhhhhhhhhhhhyso+//://+osyhy/`\n      `/yhhhhhhhhhhhhhhhhhhhhhhhhy/`\n        `:oyhhhhhhhhhhhhhhhhhhyo:`\n            .:+syhhhhhhhhys+:-`\n                 ``....``\n\x1b[0m",
+        raw_art: "${c1}                  ``````\n            `-:/+++++++//:-.`\n         .:+++oooo+/:.``   ``\n      `:+ooooooo+:.  `-:/++++++/:.`\n     -+oooooooo:` `-++o+/::::://+o+/-\n   `/ooooooooo-  -+oo/.`        `-/oo+.\n  `+ooooooooo.  :os/`              .+so:\n  +sssssssss/  :ss/                 `+ss-\n :ssssssssss`  sss`                  .sso\n ossssssssss  `yyo                    sys\n`sssssssssss` `yys                   `yys\n`sssssssssss:  +yy/                  +yy:\n oyyyyyyyyyys. `oyy/`              `+yy+\n :yyyyyyyyyyyo. `+yhs:.         `./shy/\n  oyyyyyyyyyyys:` .oyhys+:----/+syhy+. `\n  `syyyyyyyyyyyyo-` .:osyhhhhhyys+:``.:`\n   `oyyyyyyyyyyyyys+-`` `.----.```./oo.\n     /yhhhhhhhhhhhhhhyso+//://+osyhy/`\n      `/yhhhhhhhhhhhhhhhhhhhhhhhhy/`\n        `:oyhhhhhhhhhhhhhhhhhhyo:`\n            .:+syhhhhhhhhys+:-`\n                 ``....``\n",
+        colors: &[Color::Palette(4)],
         max_line_length: 41,
     },
     Logo {
         name: "OpenStage",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                 /(/\n              .(((((((,\n             /(((((((((/\n           .(((((/,/(((((,\n          *(((((*   ,(((((/\n          (((((*      .*/((\n         *((((/  (//(/*\n         /((((*  ((((((((((,\n      .  /((((*  (((((((((((((.\n     ((. *((((/        ,((((((((\n   ,(((/  (((((/     **   ,((((((*\n  /(((((. .(((((/   //(((*  *(((((/\n .(((((,    ((/   .(((((/.   .(((((,\n /((((*        ,(((((((/      ,(((((\n /(((((((((((((((((((/.  /(((((((((/\n /(((((((((((((((((,   /(((((((((((/\n     */(((((//*.      */((/(/(/*\n\x1b[0m",
+        raw_art: "${c1}                 /(/\n              .(((((((,\n             /(((((((((/\n           .(((((/,/(((((,\n          *(((((*   ,(((((/\n          (((((*      .*/((\n         *((((/  (//(/*\n         /((((*  ((((((((((,\n      .  /((((*  (((((((((((((.\n     ((. *((((/        ,((((((((\n   ,(((/  (((((/     **   ,((((((*\n  /(((((. .(((((/   //(((*  *(((((/\n .(((((,    ((/   .(((((/.   .(((((,\n /((((*        ,(((((((/      ,(((((\n /(((((((((((((((((((/.  /(((((((((/\n /(((((((((((((((((,   /(((((((((((/\n     */(((((//*.      */((/(/(/*\n",
+        colors: &[Color::Palette(2)],
         max_line_length: 36,
     },
     Logo {
         name: "OpenWrt",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m _______\n|       |.-----.-----.-----.\n|   -   ||  _  |  -__|     |\n|_______||   __|_____|__|__|\n         |__|\n ________        __\n|  |  |  |.----.|  |_\n|  |  |  ||   _||   _|\n|________||__|  |____|\n\x1b[0m",
+        raw_art: "${c1} _______\n|       |.-----.-----.-----.\n|   -   ||  _  |  -__|     |\n|_______||   __|_____|__|__|\n         |__|\n ________        __\n|  |  |  |.----.|  |_\n|  |  |  ||   _||   _|\n|________||__|  |____|\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 28,
     },
     Logo {
         name: "Oracle",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m\n      `-/+++++++++++++++++/-.`\n   `/syyyyyyyyyyyyyyyyyyyyyyys/.\n  :yyyyo/-...............-/oyyyy/\n /yyys-                     .oyyy+\n.yyyy`                       `syyy-\n:yyyo                         /yyy/\n.yyyy`                       `syyy-\n /yyys.                     .oyyyo\n  /yyyyo:-...............-:oyyyy/`\n   `/syyyyyyyyyyyyyyyyyyyyyyys+.\n     `.:/+ooooooooooooooo+/:.`\n\x1b[0m",
+        raw_art: "${c1}\n      `-/+++++++++++++++++/-.`\n   `/syyyyyyyyyyyyyyyyyyyyyyys/.\n  :yyyyo/-...............-/oyyyy/\n /yyys-                     .oyyy+\n.yyyy`                       `syyy-\n:yyyo                         /yyy/\n.yyyy`                       `syyy-\n /yyys.                     .oyyyo\n  /yyyyo:-...............-:oyyyy/`\n   `/syyyyyyyyyyyyyyyyyyyyyyys+.\n     `.:/+ooooooooooooooo+/:.`\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 35,
     },
+    Logo {
+        name: "PCBSD",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m                       ..\n                        s.\n                        +y\n                        yN\n                       -MN  `.\n                      :NMs `m\n                    .yMMm` `No\n            `-/+++sdMMMNs+-`+Ms\n        `:oo+-` .yMMMMy` `-+oNMh\n      -oo-     +NMMMM/       oMMh-\n    .s+` `    oMMMMM/     -  oMMMhy.\n   +s`- ::   :MMMMMd     -o `mMMMy`s+\n  y+  h .Ny+oNMMMMMN/    sh+NMMMMo  +y\n s+ .ds  -NMMMMMMMMMMNdhdNMMMMMMh`   +s\n-h .NM`   `hMMMMMMMMMMMMMMNMMNy:      h-\ny- hMN`     hMMmMMMMMMMMMNsdMNs.      -y\nm` mMMy`    oMMNoNMMMMMMo`  sMMMo     `m\nm` :NMMMdyydMMMMo+MdMMMs     sMMMd`   `m\nh-  `+ymMMMMMMMM--M+hMMN/    +MMMMy   -h\n:y     `.sMMMMM/ oMM+.yMMNddNMMMMMm   y:\n y:   `s  dMMN- .MMMM/ :MMMMMMMMMMh  :y\n `h:  `mdmMMM/  yMMMMs  sMMMMMMMMN- :h`\n   so  -NMMMN   /mmd+  `dMMMMMMMm- os\n    :y: `yMMM`       `+NMMMMMMNo`:y:\n      /s+`.omy      /NMMMMMNh/.+s:\n        .+oo:-.     /mdhs+::oo+.\n            -/o+++++++++++/-\n\x1b[0m",
+        raw_art: "${c1}                       ..\n                        s.\n                        +y\n                        yN\n                       -MN  `.\n                      :NMs `m\n                    .yMMm` `No\n            `-/+++sdMMMNs+-`+Ms\n        `:oo+-` .yMMMMy` `-+oNMh\n      -oo-     +NMMMM/       oMMh-\n    .s+` `    oMMMMM/     -  oMMMhy.\n   +s`- ::   :MMMMMd     -o `mMMMy`s+\n  y+  h .Ny+oNMMMMMN/    sh+NMMMMo  +y\n s+ .ds  -NMMMMMMMMMMNdhdNMMMMMMh`   +s\n-h .NM`   `hMMMMMMMMMMMMMMNMMNy:      h-\ny- hMN`     hMMmMMMMMMMMMNsdMNs.      -y\nm` mMMy`    oMMNoNMMMMMMo`  sMMMo     `m\nm` :NMMMdyydMMMMo+MdMMMs     sMMMd`   `m\nh-  `+ymMMMMMMMM--M+hMMN/    +MMMMy   -h\n:y     `.sMMMMM/ oMM+.yMMNddNMMMMMm   y:\n y:   `s  dMMN- .MMMM/ :MMMMMMMMMMh  :y\n `h:  `mdmMMM/  yMMMMs  sMMMMMMMMN- :h`\n   so  -NMMMN   /mmd+  `dMMMMMMMm- os\n    :y: `yMMM`       `+NMMMMMMNo`:y:\n      /s+`.omy      /NMMMMMNh/.+s:\n        .+oo:-.     /mdhs+::oo+.\n            -/o+++++++++++/-\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
+        max_line_length: 40,
+    },
     Logo {
         name: "PCLinuxOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m            mhhhyyyyhhhdN\n        dyssyhhhhhhhhhhhssyhN\n     Nysyhhyo/:-.....-/oyhhhssd\n   Nsshhy+.              `/shhysm\n  dohhy/                    -shhsy\n dohhs`                       /hhys\nN+hho   \x1b[1;37m+ssssss+-   .+syhys+   \x1b[1;34m/hhsy\nohhh`   \x1b[1;37mymmo++hmm+`smmy/::+y`   \x1b[1;34mshh+\n+hho    \x1b[1;37mymm-  /mmy+mms          \x1b[1;34m:hhod\n/hh+    \x1b[1;37mymmhhdmmh.smm/          \x1b[1;34m.hhsh\n+hhs    \x1b[1;37mymm+::-`  /mmy`    `    \x1b[1;34m/hh+m\nyyhh-   \x1b[1;37mymm-       /dmdyosyd`  \x1b[1;34m`yhh+\n ohhy`  \x1b[1;37m://`         -/+++/-   \x1b[1;34mohhom\n N+hhy-                      `shhoh\n   sshho.                  `+hhyom\n    dsyhhs/.            `:ohhhoy\n      dysyhhhso///://+syhhhssh\n         dhyssyhhhhhhyssyyhN\n              mddhdhdmN\n\x1b[0m",
+        raw_art: "${c1}            mhhhyyyyhhhdN\n        dyssyhhhhhhhhhhhssyhN\n     Nysyhhyo/:-.....-/oyhhhssd\n   Nsshhy+.              `/shhysm\n  dohhy/                    -shhsy\n dohhs`                       /hhys\nN+hho   ${c2}+ssssss+-   .+syhys+   ${c1}/hhsy\nohhh`   ${c2}ymmo++hmm+`smmy/::+y`   ${c1}shh+\n+hho    ${c2}ymm-  /mmy+mms          ${c1}:hhod\n/hh+    ${c2}ymmhhdmmh.smm/          ${c1}.hhsh\n+hhs    ${c2}ymm+::-`  /mmy`    `    ${c1}/hh+m\nyyhh-   ${c2}ymm-       /dmdyosyd`  ${c1}`yhh+\n ohhy`  ${c2}://`         -/+++/-   ${c1}ohhom\n N+hhy-                      `shhoh\n   sshho.                  `+hhyom\n    dsyhhs/.            `:ohhhoy\n      dysyhhhso///://+syhhhssh\n         dhyssyhhhhhhyssyyhN\n              mddhdhdmN\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 37,
     },
+    Logo {
+        name: "PNM Linux",
+        is_wildcard: true,
+        ascii_art: "\n\x1b[1;38;5;33m\n               ``.---..` `--`\n            ``.---........-:.\x1b[1;38;5;9m-::`\x1b[1;38;5;33m\n           \x1b[1;38;5;9m./::-\x1b[1;38;5;33m........\x1b[1;38;5;9m--::.````\x1b[1;38;5;33m\n          \x1b[1;38;5;9m.:://:::\x1b[1;38;5;33m----\x1b[1;38;5;9m::::-..\x1b[1;38;5;33m\n          ..\x1b[1;38;5;9m--:::::--::::++-\x1b[1;38;5;33m.`\n  \x1b[1;38;5;9m`-:-`\x1b[1;38;5;33m   .-ohy+::\x1b[1;38;5;9m-:::\x1b[1;38;5;33m/sdmdd:.\x1b[1;38;5;9m   `-:-\n   .-:::\x1b[1;38;5;33m...\x1b[1;38;5;15msNNmds$y\x1b[1;38;5;33mo/+\x1b[1;38;5;15msy+NN$m\x1b[1;38;5;33md+.`\x1b[1;38;5;9m-:::-.\n     `.-:-\x1b[1;38;5;33m./\x1b[1;38;5;15mdN\x1b[1;38;5;33m()\x1b[1;38;5;15myyooosd\x1b[1;38;5;33m()\x1b[1;38;5;15m$m\x1b[1;38;5;33mdy\x1b[1;38;5;9m-.::-.`\x1b[1;38;5;33m\n      \x1b[1;38;5;9m`.\x1b[1;38;5;33m-...-\x1b[1;38;5;15m+hNdyyyyyydmy\x1b[1;38;5;33m:......\x1b[1;38;5;9m`\x1b[1;38;5;33m\n ``..--.....-\x1b[1;38;5;15myNNm\x1b[1;38;5;202mhssssh\x1b[1;38;5;15mmmdo\x1b[1;38;5;33m.........```\n`-:://:.....\x1b[1;38;5;15mhNNNNN\x1b[1;38;5;202mmddm\x1b[1;38;5;15mNNNmds\x1b[1;38;5;33m.....//::--`\n  ```.:-...\x1b[1;38;5;15moNNNNNNNNNNNNNNmd/\x1b[1;38;5;33m...:-.```\n      .....\x1b[1;38;5;15mhNNNNNNNNNNNNNNmds\x1b[1;38;5;33m....`\n      --...\x1b[1;38;5;15mhNNNNNNNNNNNNNNmdo\x1b[1;38;5;33m.....\n      .:...\x1b[1;38;5;15m/NNNNNNNNNNNNNNdd\x1b[1;38;5;33m:....`\n       `-...\x1b[1;38;5;15m+mNNNNNNNNNNNmh\x1b[1;38;5;33m:...-.\n     \x1b[1;38;5;202m.:+o+/:-\x1b[1;38;5;33m:+oo+///++o+/:-\x1b[1;38;5;202m:/+ooo/:.\n       \x1b[1;38;5;202m+oo/:o-            +oooooso.`\n       \x1b[1;38;5;202m.`   `             `/  .-//-\n\x1b[0m",
+        raw_art: "\n${c1}\n               ``.---..` `--`\n            ``.---........-:.${c2}-::`${c1}\n           ${c2}./::-${c1}........${c2}--::.````${c1}\n          ${c2}.:://:::${c1}----${c2}::::-..${c1}\n          ..${c2}--:::::--::::++-${c1}.`\n  ${c2}`-:-`${c1}   .-ohy+::${c2}-:::${c1}/sdmdd:.${c2}   `-:-\n   .-:::${c1}...${c3}sNNmds$y${c1}o/+${c3}sy+NN$m${c1}d+.`${c2}-:::-.\n     `.-:-${c1}./${c3}dN${c1}()${c3}yyooosd${c1}()${c3}$m${c1}dy${c2}-.::-.`${c1}\n      ${c2}`.${c1}-...-${c3}+hNdyyyyyydmy${c1}:......${c2}`${c1}\n ``..--.....-${c3}yNNm${c4}hssssh${c3}mmdo${c1}.........```\n`-:://:.....${c3}hNNNNN${c4}mddm${c3}NNNmds${c1}.....//::--`\n  ```.:-...${c3}oNNNNNNNNNNNNNNmd/${c1}...:-.```\n      .....${c3}hNNNNNNNNNNNNNNmds${c1}....`\n      --...${c3}hNNNNNNNNNNNNNNmdo${c1}.....\n      .:...${c3}/NNNNNNNNNNNNNNdd${c1}:....`\n       `-...${c3}+mNNNNNNNNNNNmh${c1}:...-.\n     ${c4}.:+o+/:-${c1}:+oo+///++o+/:-${c4}:/+ooo/:.\n       ${c4}+oo/:o-            +oooooso.`\n       ${c4}.`   `             `/  .-//-\n",
+        colors: &[Color::Palette(33), Color::Palette(9), Color::Palette(15), Color::Palette(202)],
+        max_line_length: 40,
+    },
     Logo {
         name: "PacBSD",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m      :+sMs.\n  `:ddNMd-                         -o--`\n -sMMMMh:                          `+N+``\n yMMMMMs`     .....-/-...           `mNh/\n yMMMMMmh+-`:sdmmmmmmMmmmmddy+-``./ddNMMm\n yNMMNMMMMNdyyNNMMMMMMMMMMMMMMMhyshNmMMMm\n :yMMMMMMMMMNdooNMMMMMMMMMMMMMMMMNmy:mMMd\n  +MMMMMMMMMmy:sNMMMMMMMMMMMMMMMMMMMmshs-\n  :hNMMMMMMN+-+MMMMMMMMMMMMMMMMMMMMMMMs.\n .omysmNNhy/+yNMMMMMMMMMMNMMMMMMMMMNdNNy-\n /hMM:::::/hNMMMMMMMMMMMm/-yNMMMMMMN.mMNh`\n.hMMMMdhdMMMMMMMMMMMMMMmo  `sMMMMMMN mMMm-\n:dMMMMMMMMMMMMMMMMMMMMMdo+  oMMMMMMN`smMNo`\n/dMMMMMMMMMMMMMMMMMMMMMNd/` :yMMMMMN:-hMMM.\n:dMMMMMMMMMMMMMMMMMMMMMNh`  oMMMMMMNo/dMNN`\n:hMMMMMMMMMMMMMMMMMMMMMMNs--sMMMMMMMNNmy++`\n sNMMMMMMMMMMMMMMMMMMMMMMMmmNMMMMMMNho::o.\n :yMMMMMMMMMMMMMNho+sydNNNNNNNmysso/` -//\n  /dMMMMMMMMMMMMMs-  ````````..``\n   .oMMMMMMMMMMMMNs`               ./y:`\n     +dNMMNMMMMMMMmy`          ``./ys.\n      `/hMMMMMMMMMMMNo-``    `.+yy+-`\n        `-/hmNMNMMMMMMmmddddhhy/-`\n            `-+oooyMMMdsoo+/:.\n\x1b[0m",
+        raw_art: "${c1}      :+sMs.\n  `:ddNMd-                         -o--`\n -sMMMMh:                          `+N+``\n yMMMMMs`     .....-/-...           `mNh/\n yMMMMMmh+-`:sdmmmmmmMmmmmddy+-``./ddNMMm\n yNMMNMMMMNdyyNNMMMMMMMMMMMMMMMhyshNmMMMm\n :yMMMMMMMMMNdooNMMMMMMMMMMMMMMMMNmy:mMMd\n  +MMMMMMMMMmy:sNMMMMMMMMMMMMMMMMMMMmshs-\n  :hNMMMMMMN+-+MMMMMMMMMMMMMMMMMMMMMMMs.\n .omysmNNhy/+yNMMMMMMMMMMNMMMMMMMMMNdNNy-\n /hMM:::::/hNMMMMMMMMMMMm/-yNMMMMMMN.mMNh`\n.hMMMMdhdMMMMMMMMMMMMMMmo  `sMMMMMMN mMMm-\n:dMMMMMMMMMMMMMMMMMMMMMdo+  oMMMMMMN`smMNo`\n/dMMMMMMMMMMMMMMMMMMMMMNd/` :yMMMMMN:-hMMM.\n:dMMMMMMMMMMMMMMMMMMMMMNh`  oMMMMMMNo/dMNN`\n:hMMMMMMMMMMMMMMMMMMMMMMNs--sMMMMMMMNNmy++`\n sNMMMMMMMMMMMMMMMMMMMMMMMmmNMMMMMMNho::o.\n :yMMMMMMMMMMMMMNho+sydNNNNNNNmysso/` -//\n  /dMMMMMMMMMMMMMs-  ````````..``\n   .oMMMMMMMMMMMMNs`               ./y:`\n     +dNMMNMMMMMMMmy`          ``./ys.\n      `/hMMMMMMMMMMMNo-``    `.+yy+-`\n        `-/hmNMNMMMMMMmmddddhhy/-`\n            `-+oooyMMMdsoo+/:.\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 43,
     },
     Logo {
         name: "Parabola",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m                          `.-.    `.\n                   `.`  `:++.   `-+o+.\n             `` `:+/. `:+/.   `-+oooo+\n        ``-::-.:+/. `:+/.   `-+oooooo+\n    `.-:///-  ..`   .-.   `-+oooooooo-\n `..-..`                 `+ooooooooo:\n``                        :oooooooo/\n                          `ooooooo:\n                          `oooooo:\n                          -oooo+.\n                          +ooo/`\n                         -ooo-\n                        `+o/.\n                        /+-\n                       //`\n                      -.\n\x1b[0m",
+        raw_art: "${c1}                          `.-.    `.\n                   `.`  `:++.   `-+o+.\n             `` `:+/. `:+/.   `-+oooo+\n        ``-::-.:+/. `:+/.   `-+oooooo+\n    `.-:///-  ..`   .-.   `-+oooooooo-\n `..-..`                 `+ooooooooo:\n``                        :oooooooo/\n                          `ooooooo:\n                          `oooooo:\n                          -oooo+.\n                          +ooo/`\n                         -ooo-\n                        `+o/.\n                        /+-\n                       //`\n                      -.\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 38,
     },
     Logo {
         name: "Pardus",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m .smNdy+-    `.:/osyyso+:.`    -+ydmNs.\n/Md- -/ymMdmNNdhso/::/oshdNNmdMmy/. :dM/\nmN.     oMdyy- -y          `-dMo     .Nm\n.mN+`  sMy hN+ -:             yMs  `+Nm.\n `yMMddMs.dy `+`               sMddMMy`\n   +MMMo  .`  .                 oMMM+\n   `NM/    `````.`    `.`````    +MN`\n   yM+   `.-:yhomy    ymohy:-.`   +My\n   yM:          yo    oy          :My\n   +Ms         .N`    `N.      +h sM+\n   `MN      -   -::::::-   : :o:+`NM`\n    yM/    sh   -dMMMMd-   ho  +y+My\n    .dNhsohMh-//: /mm/ ://-yMyoshNd`\n      `-ommNMm+:/. oo ./:+mMNmmo:`\n     `/o+.-somNh- :yy: -hNmos-.+o/`\n    ./` .s/`s+sMdd+``+ddMs+s`/s. `/.\n        : -y.  -hNmddmNy.  .y- :\n         -+       `..`       +-\n\x1b[0m",
+        raw_art: "${c1} .smNdy+-    `.:/osyyso+:.`    -+ydmNs.\n/Md- -/ymMdmNNdhso/::/oshdNNmdMmy/. :dM/\nmN.     oMdyy- -y          `-dMo     .Nm\n.mN+`  sMy hN+ -:             yMs  `+Nm.\n `yMMddMs.dy `+`               sMddMMy`\n   +MMMo  .`  .                 oMMM+\n   `NM/    `````.`    `.`````    +MN`\n   yM+   `.-:yhomy    ymohy:-.`   +My\n   yM:          yo    oy          :My\n   +Ms         .N`    `N.      +h sM+\n   `MN      -   -::::::-   : :o:+`NM`\n    yM/    sh   -dMMMMd-   ho  +y+My\n    .dNhsohMh-//: /mm/ ://-yMyoshNd`\n      `-ommNMm+:/. oo ./:+mMNmmo:`\n     `/o+.-somNh- :yy: -hNmos-.+o/`\n    ./` .s/`s+sMdd+``+ddMs+s`/s. `/.\n        : -y.  -hNmddmNy.  .y- :\n         -+       `..`       +-\n",
+        colors: &[Color::Palette(3), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
         max_line_length: 40,
     },
     Logo {
         name: "Parrot",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m  `:oho/-`\n`mMMMMMMMMMMMNmmdhy-\n dMMMMMMMMMMMMMMMMMMs`\n +MMsohNMMMMMMMMMMMMMm/\n .My   .+dMMMMMMMMMMMMMh.\n  +       :NMMMMMMMMMMMMNo\n           `yMMMMMMMMMMMMMm:\n             /NMMMMMMMMMMMMMy`\n              .hMMMMMMMMMMMMMN+\n                  ``-NMMMMMMMMMd-\n                     /MMMMMMMMMMMs`\n                      mMMMMMMMsyNMN/\n                      +MMMMMMMo  :sNh.\n                      `NMMMMMMm     -o/\n                       oMMMMMMM.\n                       `NMMMMMM+\n                        +MMd/NMh\n                         mMm -mN`\n                         /MM  `h:\n                          dM`   .\n                          :M-\n                           d:\n                           -+\n                            -\n\x1b[0m",
+        raw_art: "${c1}  `:oho/-`\n`mMMMMMMMMMMMNmmdhy-\n dMMMMMMMMMMMMMMMMMMs`\n +MMsohNMMMMMMMMMMMMMm/\n .My   .+dMMMMMMMMMMMMMh.\n  +       :NMMMMMMMMMMMMNo\n           `yMMMMMMMMMMMMMm:\n             /NMMMMMMMMMMMMMy`\n              .hMMMMMMMMMMMMMN+\n                  ``-NMMMMMMMMMd-\n                     /MMMMMMMMMMMs`\n                      mMMMMMMMsyNMN/\n                      +MMMMMMMo  :sNh.\n                      `NMMMMMMm     -o/\n                       oMMMMMMM.\n                       `NMMMMMM+\n                        +MMd/NMh\n                         mMm -mN`\n                         /MM  `h:\n                          dM`   .\n                          :M-\n                           d:\n                           -+\n                            -\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 39,
     },
     Logo {
         name: "Parsix",
         is_wildcard: true,
         ascii_art: "                 \x1b[1;31m-/+/:.\n               \x1b[1;31m.syssssys.\n       \x1b[1;33m.--.    \x1b[1;31mssssssssso\x1b[1;33m   ..--.\n     :++++++:  \x1b[1;31m+ssssssss+\x1b[1;33m ./++/+++:\n    /+++++++++.\x1b[1;31m.yssooooy`\x1b[1;33m-+///////o-\n    /++++++++++.\x1b[1;31m+soooos:\x1b[1;33m:+////////+-\n     :+++++////o-\x1b[1;31moooooo-\x1b[1;33m+/////////-\n      `-/++//++-\x1b[1;38;5;8m.-----.-\x1b[1;33m:+/////:-\n  \x1b[1;37m-://::--\x1b[1;33m-:/:\x1b[1;38;5;8m.--.````.--.\x1b[1;33m:::-\x1b[1;37m--::::::.\n\x1b[1;37m-/:::::::://:\x1b[1;38;5;8m.:-`      `-:\x1b[1;37m`:/:::::::--/-\n\x1b[1;37m/::::::::::/-\x1b[1;38;5;8m--.        .-.\x1b[1;37m-/://///::::/\n\x1b[1;37m-/:::::::::/:\x1b[1;38;5;8m`:-.      .-:\x1b[1;37m`:///////////-\n `\x1b[1;37m-::::--\x1b[1;33m.-://.\x1b[1;38;5;8m---....---\x1b[1;33m`:+/:-\x1b[1;37m--::::-`\n       \x1b[1;33m-/+///+o/-\x1b[1;38;5;8m.----.\x1b[1;33m.:oo+++o+.\n     \x1b[1;33m-+/////+++o:\x1b[1;31msyyyyy.\x1b[1;33mo+++++++++:\n    \x1b[1;33m.+////+++++-\x1b[1;31m+sssssy+\x1b[1;33m.++++++++++\\\n    \x1b[1;33m.+:/++++++.\x1b[1;31m.yssssssy-\x1b[1;33m`+++++++++:\n     \x1b[1;33m:/+++++-  \x1b[1;31m+sssssssss  \x1b[1;33m-++++++-\n       \x1b[1;33m`--`    \x1b[1;31m+sssssssso    \x1b[1;33m`--`\n                \x1b[1;31m+sssssy+`\n                 \x1b[1;31m`.::-`\n\x1b[0m",
+        raw_art: "                 ${c2}-/+/:.\n               ${c2}.syssssys.\n       ${c1}.--.    ${c2}ssssssssso${c1}   ..--.\n     :++++++:  ${c2}+ssssssss+${c1} ./++/+++:\n    /+++++++++.${c2}.yssooooy`${c1}-+///////o-\n    /++++++++++.${c2}+soooos:${c1}:+////////+-\n     :+++++////o-${c2}oooooo-${c1}+/////////-\n      `-/++//++-${c4}.-----.-${c1}:+/////:-\n  ${c3}-://::--${c1}-:/:${c4}.--.````.--.${c1}:::-${c3}--::::::.\n${c3}-/:::::::://:${c4}.:-`      `-:${c3}`:/:::::::--/-\n${c3}/::::::::::/-${c4}--.        .-.${c3}-/://///::::/\n${c3}-/:::::::::/:${c4}`:-.      .-:${c3}`:///////////-\n `${c3}-::::--${c1}.-://.${c4}---....---${c1}`:+/:-${c3}--::::-`\n       ${c1}-/+///+o/-${c4}.----.${c1}.:oo+++o+.\n     ${c1}-+/////+++o:${c2}syyyyy.${c1}o+++++++++:\n    ${c1}.+////+++++-${c2}+sssssy+${c1}.++++++++++\\\n    ${c1}.+:/++++++.${c2}.yssssssy-${c1}`+++++++++:\n     ${c1}:/+++++-  ${c2}+sssssssss  ${c1}-++++++-\n       ${c1}`--`    ${c2}+sssssssso    ${c1}`--`\n                ${c2}+sssssy+`\n                 ${c2}`.::-`\n",
+        colors: &[Color::Palette(3), Color::Palette(1), Color::Palette(7), Color::Palette(8)],
         max_line_length: 40,
     },
     Logo {
         name: "Pengwin",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;13m                     ...`\n\x1b[1;38;5;13m                     `-///:-`\n\x1b[1;38;5;13m                       .+\x1b[1;35mssys\x1b[1;38;5;13m/\n\x1b[1;38;5;13m                        +\x1b[1;35myyyyy\x1b[1;38;5;13mo    \x1b[1;35m\n\x1b[1;35m                        -yyyyyy:\n\x1b[1;35m           `.:/+ooo+/:` -yyyyyy+\n\x1b[1;35m         `:oyyyyyys+:-.`syyyyyy:\n\x1b[1;35m        .syyyyyyo-`   .oyyyyyyo\n\x1b[1;35m       `syyyyyy   `-+yyyyyyy/`\n\x1b[1;35m       /yyyyyy+ -/osyyyyyyo/.\n\x1b[1;35m       +yyyyyy-  `.-:::-.`\n\x1b[1;35m       .yyyyyy-\n\x1b[1;38;5;13m        :\x1b[1;35myyyyy\x1b[1;38;5;13mo\n\x1b[1;38;5;13m         .+\x1b[1;35mooo\x1b[1;38;5;13m+\n\x1b[1;38;5;13m           `.::/:.\n\x1b[0m",
+        raw_art: "${c3}                     ...`\n${c3}                     `-///:-`\n${c3}                       .+${c2}ssys${c3}/\n${c3}                        +${c2}yyyyy${c3}o    ${c2}\n${c2}                        -yyyyyy:\n${c2}           `.:/+ooo+/:` -yyyyyy+\n${c2}         `:oyyyyyys+:-.`syyyyyy:\n${c2}        .syyyyyyo-`   .oyyyyyyo\n${c2}       `syyyyyy   `-+yyyyyyy/`\n${c2}       /yyyyyy+ -/osyyyyyyo/.\n${c2}       +yyyyyy-  `.-:::-.`\n${c2}       .yyyyyy-\n${c3}        :${c2}yyyyy${c3}o\n${c3}         .+${c2}ooo${c3}+\n${c3}           `.::/:.\n",
+        colors: &[Color::Palette(5), Color::Palette(5), Color::Palette(13)],
         max_line_length: 35,
     },
     Logo {
         name: "Pentoo",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m           `:oydNNMMMMNNdyo:`\n        :yNMMMMMMMMMMMMMMMMNy:\n      :dMMMMMMMMMMMMMMMMMMMMMMd:\n     oMMMMMMMho/-....-/ohMMMMMMMo\n    oMMMMMMy.            .yMMMMMMo\n   .MMMMMMo                oMMMMMM.\n   +MMMMMm                  mMMMMM+\n   oMMMMMh                  hMMMMMo\n //hMMMMMm//\x1b[1;35m`\x1b[1;37m          \x1b[1;35m`\x1b[1;37m////mMMMMMh//\nMMMMMMMMMMM\x1b[1;35m/\x1b[1;37m      \x1b[1;35m/o/`\x1b[1;37m  \x1b[1;35m.\x1b[1;37msmMMMMMMMMMMM\nMMMMMMMMMMm      \x1b[1;35m`NMN:\x1b[1;37m    \x1b[1;35m.\x1b[1;37myMMMMMMMMMM\nMMMMMMMMMMMh\x1b[1;35m:.\x1b[1;37m              dMMMMMMMMM\nMMMMMMMMMMMMMy\x1b[1;35m.\x1b[1;37m            \x1b[1;35m-\x1b[1;37mNMMMMMMMMM\nMMMMMMMMMMMd:\x1b[1;35m`\x1b[1;37m           \x1b[1;35m-\x1b[1;37myNMMMMMMMMMM\nMMMMMMMMMMh\x1b[1;35m`\x1b[1;37m          \x1b[1;35m./\x1b[1;37mhNMMMMMMMMMMMM\nMMMMMMMMMM\x1b[1;35ms\x1b[1;37m        \x1b[1;35m.:\x1b[1;37mymMMMMMMMMMMMMMMM\nMMMMMMMMMMN\x1b[1;35ms:..-/\x1b[1;37mohNMMMMMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n\n\x1b[0m",
+        raw_art: "${c2}           `:oydNNMMMMNNdyo:`\n        :yNMMMMMMMMMMMMMMMMNy:\n      :dMMMMMMMMMMMMMMMMMMMMMMd:\n     oMMMMMMMho/-....-/ohMMMMMMMo\n    oMMMMMMy.            .yMMMMMMo\n   .MMMMMMo                oMMMMMM.\n   +MMMMMm                  mMMMMM+\n   oMMMMMh                  hMMMMMo\n //hMMMMMm//${c1}`${c2}          ${c1}`${c2}////mMMMMMh//\nMMMMMMMMMMM${c1}/${c2}      ${c1}/o/`${c2}  ${c1}.${c2}smMMMMMMMMMMM\nMMMMMMMMMMm      ${c1}`NMN:${c2}    ${c1}.${c2}yMMMMMMMMMM\nMMMMMMMMMMMh${c1}:.${c2}              dMMMMMMMMM\nMMMMMMMMMMMMMy${c1}.${c2}            ${c1}-${c2}NMMMMMMMMM\nMMMMMMMMMMMd:${c1}`${c2}           ${c1}-${c2}yNMMMMMMMMMM\nMMMMMMMMMMh${c1}`${c2}          ${c1}./${c2}hNMMMMMMMMMMMM\nMMMMMMMMMM${c1}s${c2}        ${c1}.:${c2}ymMMMMMMMMMMMMMMM\nMMMMMMMMMMN${c1}s:..-/${c2}ohNMMMMMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\nMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 38,
     },
     Logo {
         name: "Peppermint",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m               PPPPPPPPPPPPPP\n\x1b[1;31m           PPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPPPPPPPPP\n\x1b[1;31m         PPPP\x1b[1;38;5;15mMMMMMMMMMM\x1b[1;31mPPPPPPPP\x1b[1;38;5;15mMM\x1b[1;31mPP\n\x1b[1;31m       PPPPPPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPPPPPP\x1b[1;38;5;15mMMMMM\x1b[1;31mPP\n\x1b[1;31m     PPPPPPPPPPPP\x1b[1;38;5;15mMMMMMM\x1b[1;31mPPPPPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPP\n\x1b[1;31m    PPPPPPPPPPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPP\x1b[1;38;5;15mM\x1b[1;31mP\x1b[1;38;5;15mMMMMMMMMM\x1b[1;31mPP\n\x1b[1;31m   PP\x1b[1;38;5;15mMMMM\x1b[1;31mPPPPPPPPPP\x1b[1;38;5;15mMMM\x1b[1;31mPPPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mP\x1b[1;38;5;15mMM\x1b[1;31mPPPP\n\x1b[1;31m   P\x1b[1;38;5;15mMMMMMMMMMM\x1b[1;31mPPPPPP\x1b[1;38;5;15mMM\x1b[1;31mPPPPP\x1b[1;38;5;15mMMMMMM\x1b[1;31mPPPPPPPP\n\x1b[1;31m  P\x1b[1;38;5;15mMMMMMMMMMMMM\x1b[1;31mPPPPP\x1b[1;38;5;15mMM\x1b[1;31mPP\x1b[1;38;5;15mM\x1b[1;31mP\x1b[1;38;5;15mMM\x1b[1;31mP\x1b[1;38;5;15mMM\x1b[1;31mPPPPPPPPPPP\n\x1b[1;31m  P\x1b[1;38;5;15mMMMMMMMMMMMMMMMM\x1b[1;31mPP\x1b[1;38;5;15mM\x1b[1;31mP\x1b[1;38;5;15mMMM\x1b[1;31mPPPPPPPPPPPPPPPP\n\x1b[1;31m  P\x1b[1;38;5;15mMMM\x1b[1;31mPPPPPPPPPPPPPPPPPPPPPPPPPPPPPP\x1b[1;38;5;15mMMMMM\x1b[1;31mP\n\x1b[1;31m  PPPPPPPPPPPPPPPP\x1b[1;38;5;15mMMM\x1b[1;31mP\x1b[1;38;5;15mM\x1b[1;31mP\x1b[1;38;5;15mMMMMMMMMMMMMMMMM\x1b[1;31mPP\n\x1b[1;31m  PPPPPPPPPPP\x1b[1;38;5;15mMM\x1b[1;31mP\x1b[1;38;5;15mMM\x1b[1;31mPPPP\x1b[1;38;5;15mMM\x1b[1;31mPPPPP\x1b[1;38;5;15mMMMMMMMMMMM\x1b[1;31mPP\n\x1b[1;31m   PPPPPPPP\x1b[1;38;5;15mMMMMMM\x1b[1;31mPPPPP\x1b[1;38;5;15mMM\x1b[1;31mPPPPPP\x1b[1;38;5;15mMMMMMMMMM\x1b[1;31mPP\n\x1b[1;31m   PPPP\x1b[1;38;5;15mMM\x1b[1;31mP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPPPP\x1b[1;38;5;15mMM\x1b[1;31mPPPPPPPPPP\x1b[1;38;5;15mMMMM\x1b[1;31mPP\n\x1b[1;31m    PP\x1b[1;38;5;15mMMMMMMMMM\x1b[1;31mP\x1b[1;38;5;15mM\x1b[1;31mPPPP\x1b[1;38;5;15mMMMMMM\x1b[1;31mPPPPPPPPPPPPP\n\x1b[1;31m     PP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPPPPP\x1b[1;38;5;15mMMMMMM\x1b[1;31mPPPPPPPPPPPP\n\x1b[1;31m       PP\x1b[1;38;5;15mMMMM\x1b[1;31mPPPPPPPPP\x1b[1;38;5;15mMMMMMMM\x1b[1;31mPPPPPPPP\n\x1b[1;31m         PP\x1b[1;38;5;15mMM\x1b[1;31mPPPPPPPP\x1b[1;38;5;15mMMMMMMMMMM\x1b[1;31mPPPP\n\x1b[1;31m           PPPPPPPPPP\x1b[1;38;5;15mMMMMMMMM\x1b[1;31mPPPP\n\x1b[1;31m               PPPPPPPPPPPPPP\n\x1b[0m",
+        raw_art: "${c1}               PPPPPPPPPPPPPP\n${c1}           PPPP${c2}MMMMMMM${c1}PPPPPPPPPPP\n${c1}         PPPP${c2}MMMMMMMMMM${c1}PPPPPPPP${c2}MM${c1}PP\n${c1}       PPPPPPPP${c2}MMMMMMM${c1}PPPPPPPP${c2}MMMMM${c1}PP\n${c1}     PPPPPPPPPPPP${c2}MMMMMM${c1}PPPPPPP${c2}MMMMMMM${c1}PP\n${c1}    PPPPPPPPPPPP${c2}MMMMMMM${c1}PPPP${c2}M${c1}P${c2}MMMMMMMMM${c1}PP\n${c1}   PP${c2}MMMM${c1}PPPPPPPPPP${c2}MMM${c1}PPPPP${c2}MMMMMMM${c1}P${c2}MM${c1}PPPP\n${c1}   P${c2}MMMMMMMMMM${c1}PPPPPP${c2}MM${c1}PPPPP${c2}MMMMMM${c1}PPPPPPPP\n${c1}  P${c2}MMMMMMMMMMMM${c1}PPPPP${c2}MM${c1}PP${c2}M${c1}P${c2}MM${c1}P${c2}MM${c1}PPPPPPPPPPP\n${c1}  P${c2}MMMMMMMMMMMMMMMM${c1}PP${c2}M${c1}P${c2}MMM${c1}PPPPPPPPPPPPPPPP\n${c1}  P${c2}MMM${c1}PPPPPPPPPPPPPPPPPPPPPPPPPPPPPP${c2}MMMMM${c1}P\n${c1}  PPPPPPPPPPPPPPPP${c2}MMM${c1}P${c2}M${c1}P${c2}MMMMMMMMMMMMMMMM${c1}PP\n${c1}  PPPPPPPPPPP${c2}MM${c1}P${c2}MM${c1}PPPP${c2}MM${c1}PPPPP${c2}MMMMMMMMMMM${c1}PP\n${c1}   PPPPPPPP${c2}MMMMMM${c1}PPPPP${c2}MM${c1}PPPPPP${c2}MMMMMMMMM${c1}PP\n${c1}   PPPP${c2}MM${c1}P${c2}MMMMMMM${c1}PPPPPP${c2}MM${c1}PPPPPPPPPP${c2}MMMM${c1}PP\n${c1}    PP${c2}MMMMMMMMM${c1}P${c2}M${c1}PPPP${c2}MMMMMM${c1}PPPPPPPPPPPPP\n${c1}     PP${c2}MMMMMMM${c1}PPPPPPP${c2}MMMMMM${c1}PPPPPPPPPPPP\n${c1}       PP${c2}MMMM${c1}PPPPPPPPP${c2}MMMMMMM${c1}PPPPPPPP\n${c1}         PP${c2}MM${c1}PPPPPPPP${c2}MMMMMMMMMM${c1}PPPP\n${c1}           PPPPPPPPPP${c2}MMMMMMMM${c1}PPPP\n${c1}               PPPPPPPPPPPPPP\n",
+        colors: &[Color::Palette(1), Color::Palette(15), Color::Palette(3)],
         max_line_length: 42,
     },
     Logo {
         name: "Pisi",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;12m   \\Fv/!-                      `:?lzC\n\x1b[1;38;5;12m   Q!::=zFx!  \x1b[1;37m`;v6WBCicl;`  \x1b[1;38;5;12m,vCC\\!::#.\n\x1b[1;38;5;12m  ,%:::,'` \x1b[1;37m+#%@@FQ@@.   ,cF%i\x1b[1;38;5;12m``-',::a?\n\x1b[1;38;5;12m  +m:,'```\x1b[1;37m}3,/@@Q\\@@       \"af-\x1b[1;38;5;12m `-'\"7f\n  =o'.` \x1b[1;37m/m'   :Q@:Qg         ,kl\x1b[1;38;5;12m  `.|o\n  :k` '\x1b[1;37m$+      'Narm           >d,\x1b[1;38;5;12m  ii\n   #`\x1b[1;37m!p.        `C ,            'd+\x1b[1;38;5;12m %'\n\x1b[1;37m   !0m                           `6Kv\n   =a                              m+\n  !A     !\\L|:            :|L\\!     $:\n .8`     Q''%Q#'        '#Q%''Q     `0-\n :6      E|.6QQu        uQQ6.|E      p:\n  i{      \\jts9?        ?9stj\\      u\\\n   |a`            -''.            `e>\n    ,m+     \x1b[1;38;5;12m'^ !`\x1b[1;37ms@@@@a\x1b[1;38;5;12m'\"`+`\x1b[1;37m     >e'\n      !3|\x1b[1;38;5;12m`|=>>r-  \x1b[1;37m'U%:\x1b[1;38;5;12m  '>>>=:`\\3!\n       'xopE|      \x1b[1;37m`'\x1b[1;38;5;12m     `ledoz-\n    `;=>>+`\x1b[1;37m`^llci/|==|/iclc;`\x1b[1;38;5;12m'>>>>:\n   `^`+~          \x1b[1;37m````\x1b[1;38;5;12m          !!-^\n\x1b[0m",
+        raw_art: "${c1}   \\Fv/!-                      `:?lzC\n${c1}   Q!::=zFx!  ${c2}`;v6WBCicl;`  ${c1},vCC\\!::#.\n${c1}  ,%:::,'` ${c2}+#%@@FQ@@.   ,cF%i${c1}``-',::a?\n${c1}  +m:,'```${c2}}3,/@@Q\\@@       \"af-${c1} `-'\"7f\n  =o'.` ${c2}/m'   :Q@:Qg         ,kl${c1}  `.|o\n  :k` '${c2}$+      'Narm           >d,${c1}  ii\n   #`${c2}!p.        `C ,            'd+${c1} %'\n${c2}   !0m                           `6Kv\n   =a                              m+\n  !A     !\\L|:            :|L\\!     $:\n .8`     Q''%Q#'        '#Q%''Q     `0-\n :6      E|.6QQu        uQQ6.|E      p:\n  i{      \\jts9?        ?9stj\\      u\\\n   |a`            -''.            `e>\n    ,m+     ${c1}'^ !`${c2}s@@@@a${c1}'\"`+`${c2}     >e'\n      !3|${c1}`|=>>r-  ${c2}'U%:${c1}  '>>>=:`\\3!\n       'xopE|      ${c2}`'${c1}     `ledoz-\n    `;=>>+`${c2}`^llci/|==|/iclc;`${c1}'>>>>:\n   `^`+~          ${c2}````${c1}          !!-^\n",
+        colors: &[Color::Palette(12), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
+        max_line_length: 39,
+    },
+    Logo {
+        name: "Pop!_OS",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;36m             /////////////\n         /////////////////////\n      ///////\x1b[1;37m*767\x1b[1;36m////////////////\n    //////\x1b[1;37m7676767676*\x1b[1;36m//////////////\n   /////\x1b[1;37m76767\x1b[1;36m//\x1b[1;37m7676767\x1b[1;36m//////////////\n  /////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m*76767\x1b[1;36m///////////////\n ///////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m76767\x1b[1;36m.///\x1b[1;37m7676*\x1b[1;36m///////\n/////////\x1b[1;37m767676\x1b[1;36m//\x1b[1;37m76767\x1b[1;36m///\x1b[1;37m767676\x1b[1;36m////////\n//////////\x1b[1;37m76767676767\x1b[1;36m////\x1b[1;37m76767\x1b[1;36m/////////\n///////////\x1b[1;37m76767676\x1b[1;36m//////\x1b[1;37m7676\x1b[1;36m//////////\n////////////,\x1b[1;37m7676\x1b[1;36m,///////\x1b[1;37m767\x1b[1;36m///////////\n/////////////*\x1b[1;37m7676\x1b[1;36m///////\x1b[1;37m76\x1b[1;36m////////////\n///////////////\x1b[1;37m7676\x1b[1;36m////////////////////\n ///////////////\x1b[1;37m7676\x1b[1;36m///\x1b[1;37m767\x1b[1;36m////////////\n  //////////////////////\x1b[1;37m'\x1b[1;36m////////////\n   //////\x1b[1;37m.7676767676767676767,\x1b[1;36m//////\n    /////\x1b[1;37m767676767676767676767\x1b[1;36m/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n\x1b[0m",
+        raw_art: "${c1}             /////////////\n         /////////////////////\n      ///////${c2}*767${c1}////////////////\n    //////${c2}7676767676*${c1}//////////////\n   /////${c2}76767${c1}//${c2}7676767${c1}//////////////\n  /////${c2}767676${c1}///${c2}*76767${c1}///////////////\n ///////${c2}767676${c1}///${c2}76767${c1}.///${c2}7676*${c1}///////\n/////////${c2}767676${c1}//${c2}76767${c1}///${c2}767676${c1}////////\n//////////${c2}76767676767${c1}////${c2}76767${c1}/////////\n///////////${c2}76767676${c1}//////${c2}7676${c1}//////////\n////////////,${c2}7676${c1},///////${c2}767${c1}///////////\n/////////////*${c2}7676${c1}///////${c2}76${c1}////////////\n///////////////${c2}7676${c1}////////////////////\n ///////////////${c2}7676${c1}///${c2}767${c1}////////////\n  //////////////////////${c2}'${c1}////////////\n   //////${c2}.7676767676767676767,${c1}//////\n    /////${c2}767676767676767676767${c1}/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 39,
     },
     Logo {
         name: "Porteus",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m             `.-:::-.`\n         -+ydmNNNNNNNmdy+-\n      .+dNmdhs+//////+shdmdo.\n    .smmy+-`             ./sdy:\n  `omdo.    `.-/+osssso+/-` `+dy.\n `yms.   `:shmNmdhsoo++osyyo-``oh.\n hm/   .odNmds/.`    ``.....:::-+s\n/m:  `+dNmy:`   `./oyhhhhyyooo++so\nys  `yNmy-    .+hmmho:-.`     ```\ns:  yNm+`   .smNd+.\n`` /Nm:    +dNd+`\n   yN+   `smNy.\n   dm    oNNy`\n   hy   -mNm.\n   +y   oNNo\n   `y`  sNN:\n    `:  +NN:\n     `  .mNo\n         /mm`\n          /my`\n           .sy`\n             .+:\n                `\n\x1b[0m",
+        raw_art: "${c1}             `.-:::-.`\n         -+ydmNNNNNNNmdy+-\n      .+dNmdhs+//////+shdmdo.\n    .smmy+-`             ./sdy:\n  `omdo.    `.-/+osssso+/-` `+dy.\n `yms.   `:shmNmdhsoo++osyyo-``oh.\n hm/   .odNmds/.`    ``.....:::-+s\n/m:  `+dNmy:`   `./oyhhhhyyooo++so\nys  `yNmy-    .+hmmho:-.`     ```\ns:  yNm+`   .smNd+.\n`` /Nm:    +dNd+`\n   yN+   `smNy.\n   dm    oNNy`\n   hy   -mNm.\n   +y   oNNo\n   `y`  sNN:\n    `:  +NN:\n     `  .mNo\n         /mm`\n          /my`\n           .sy`\n             .+:\n                `\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 34,
     },
     Logo {
         name: "PostMarketOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                 /\\\\\n                /  \\\\\n               /    \\\\\n              /      \\\\\n             /        \\\\\n            /          \\\\\n            \\\\           \\\\\n          /\\\\ \\\\____       \\\\\n         /  \\\\____ \\\\       \\\\\n        /       /  \\\\       \\\\\n       /       /    \\\\    ___\\\\\n      /       /      \\\\  / ____\n     /       /        \\\\/ /    \\\\\n    /       / __________/      \\\\\n   /        \\\\ \\\\                 \\\\\n  /          \\\\ \\\\                 \\\\\n /           / /                  \\\\\n/___________/ /____________________\\\\\n\x1b[0m",
+        raw_art: "${c1}                 /\\\\\n                /  \\\\\n               /    \\\\\n              /      \\\\\n             /        \\\\\n            /          \\\\\n            \\\\           \\\\\n          /\\\\ \\\\____       \\\\\n         /  \\\\____ \\\\       \\\\\n        /       /  \\\\       \\\\\n       /       /    \\\\    ___\\\\\n      /       /      \\\\  / ____\n     /       /        \\\\/ /    \\\\\n    /       / __________/      \\\\\n   /        \\\\ \\\\                 \\\\\n  /          \\\\ \\\\                 \\\\\n /           / /                  \\\\\n/___________/ /____________________\\\\\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 37,
     },
     Logo {
         name: "Precise Puppy",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n\x1b[0m",
+        raw_art: "${c1}           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 36,
     },
+    Logo {
+        name: "Profelis SambaBOX",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;33m\n                    #\n               *////#####\n           /////////#########(\n      .((((((/////    ,####(#(((((\n  /#######(((*             (#(((((((((.\n//((#(#(#,        ((##(        ,((((((//\n//////        #(##########(       //////\n//////    ((#(#(#(#(##########(/////////\n/////(    (((((((#########(##((((((/////\n/(((#(                             ((((/\n####(#                             ((###\n#########(((/////////(((((((((,    (#(#(\n########(   /////////(((((((*      #####\n####///,        *////(((         (((((((\n.///////////                .//(((((((((\n     ///////////,       *(/////((((*\n         ,/(((((((((##########/.\n             .((((((#######\n                  ((##*\n\x1b[0m",
+        raw_art: "${c1}\n                    #\n               *////#####\n           /////////#########(\n      .((((((/////    ,####(#(((((\n  /#######(((*             (#(((((((((.\n//((#(#(#,        ((##(        ,((((((//\n//////        #(##########(       //////\n//////    ((#(#(#(#(##########(/////////\n/////(    (((((((#########(##((((((/////\n/(((#(                             ((((/\n####(#                             ((###\n#########(((/////////(((((((((,    (#(#(\n########(   /////////(((((((*      #####\n####///,        *////(((         (((((((\n.///////////                .//(((((((((\n     ///////////,       *(/////((((*\n         ,/(((((((((##########/.\n             .((((((#######\n                  ((##*\n",
+        colors: &[Color::Palette(3), Color::Palette(6)],
+        max_line_length: 40,
+    },
     Logo {
         name: "Proxmox",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m         .://:`              `://:.\n       `hMMMMMMd/          /dMMMMMMh`\n        `sMMMMMMMd:      :mMMMMMMMs`\n\x1b[1;38;5;202m`-/+oo+/:\x1b[1;37m`.yMMMMMMMh-  -hMMMMMMMy.`\x1b[1;38;5;202m:/+oo+/-`\n`:oooooooo/\x1b[1;37m`-hMMMMMMMyyMMMMMMMh-`\x1b[1;38;5;202m/oooooooo:`\n  `/oooooooo:\x1b[1;37m`:mMMMMMMMMMMMMm:`\x1b[1;38;5;202m:oooooooo/`\n    ./ooooooo+-\x1b[1;37m +NMMMMMMMMN+ \x1b[1;38;5;202m-+ooooooo/.\n      .+ooooooo+-\x1b[1;37m`oNMMMMNo`\x1b[1;38;5;202m-+ooooooo+.\n        -+ooooooo/.\x1b[1;37m`sMMs`\x1b[1;38;5;202m./ooooooo+-\n          :oooooooo/\x1b[1;37m`..`\x1b[1;38;5;202m/oooooooo:\n          :oooooooo/`\x1b[1;37m..\x1b[1;38;5;202m`/oooooooo:\n        -+ooooooo/.`\x1b[1;37msMMs\x1b[1;38;5;202m`./ooooooo+-\n      .+ooooooo+-`\x1b[1;37moNMMMMNo\x1b[1;38;5;202m`-+ooooooo+.\n    ./ooooooo+-\x1b[1;37m +NMMMMMMMMN+ \x1b[1;38;5;202m-+ooooooo/.\n  `/oooooooo:`\x1b[1;37m:mMMMMMMMMMMMMm:\x1b[1;38;5;202m`:oooooooo/`\n`:oooooooo/`\x1b[1;37m-hMMMMMMMyyMMMMMMMh-\x1b[1;38;5;202m`/oooooooo:`\n`-/+oo+/:`\x1b[1;37m.yMMMMMMMh-  -hMMMMMMMy.\x1b[1;38;5;202m`:/+oo+/-`\n\x1b[1;37m        `sMMMMMMMm:      :dMMMMMMMs`\n       `hMMMMMMd/          /dMMMMMMh`\n         `://:`              `://:`\n\x1b[0m",
+        raw_art: "${c1}         .://:`              `://:.\n       `hMMMMMMd/          /dMMMMMMh`\n        `sMMMMMMMd:      :mMMMMMMMs`\n${c2}`-/+oo+/:${c1}`.yMMMMMMMh-  -hMMMMMMMy.`${c2}:/+oo+/-`\n`:oooooooo/${c1}`-hMMMMMMMyyMMMMMMMh-`${c2}/oooooooo:`\n  `/oooooooo:${c1}`:mMMMMMMMMMMMMm:`${c2}:oooooooo/`\n    ./ooooooo+-${c1} +NMMMMMMMMN+ ${c2}-+ooooooo/.\n      .+ooooooo+-${c1}`oNMMMMNo`${c2}-+ooooooo+.\n        -+ooooooo/.${c1}`sMMs`${c2}./ooooooo+-\n          :oooooooo/${c1}`..`${c2}/oooooooo:\n          :oooooooo/`${c1}..${c2}`/oooooooo:\n        -+ooooooo/.`${c1}sMMs${c2}`./ooooooo+-\n      .+ooooooo+-`${c1}oNMMMMNo${c2}`-+ooooooo+.\n    ./ooooooo+-${c1} +NMMMMMMMMN+ ${c2}-+ooooooo/.\n  `/oooooooo:`${c1}:mMMMMMMMMMMMMm:${c2}`:oooooooo/`\n`:oooooooo/`${c1}-hMMMMMMMyyMMMMMMMh-${c2}`/oooooooo:`\n`-/+oo+/:`${c1}.yMMMMMMMh-  -hMMMMMMMy.${c2}`:/+oo+/-`\n${c1}        `sMMMMMMMm:      :dMMMMMMMs`\n       `hMMMMMMd/          /dMMMMMMh`\n         `://:`              `://:`\n",
+        colors: &[Color::Palette(7), Color::Palette(202)],
         max_line_length: 44,
     },
     Logo {
         name: "PuffOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m\n              _,..._,m,\n            ,/'      '\"\";\n           /             \".\n         ,'mmmMMMMmm.      \\\n       _/-\"^^^^^\"\"\"%#%mm,   ;\n ,m,_,'              \"###)  ;,\n(###%                 \\#/  ;##mm.\n ^#/  __        ___    ;  (######)\n  ;  //.\\\\     //.\\\\   ;   \\####/\n _; (#\\\"//     \\\\\"/#)  ;  ,/\n@##\\ \\##/   =   `\"=\" ,;mm/\n`\\##>.____,...,____,<####@\n\x1b[0m",
+        raw_art: "${c1}\n              _,..._,m,\n            ,/'      '\"\";\n           /             \".\n         ,'mmmMMMMmm.      \\\n       _/-\"^^^^^\"\"\"%#%mm,   ;\n ,m,_,'              \"###)  ;,\n(###%                 \\#/  ;##mm.\n ^#/  __        ___    ;  (######)\n  ;  //.\\\\     //.\\\\   ;   \\####/\n _; (#\\\"//     \\\\\"/#)  ;  ,/\n@##\\ \\##/   =   `\"=\" ,;mm/\n`\\##>.____,...,____,<####@\n",
+        colors: &[Color::Palette(3)],
         max_line_length: 34,
     },
+    Logo {
+        name: "Puppy",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n\x1b[0m",
+        raw_art: "${c1}           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
+        max_line_length: 36,
+    },
     Logo {
         name: "PureOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;32mdmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmd\ndNm//////////////////////////////////mNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNm//////////////////////////////////mNd\ndmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmd\n\x1b[0m",
+        raw_art: "${c1}dmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmd\ndNm//////////////////////////////////mNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNd                                  dNd\ndNm//////////////////////////////////mNd\ndmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmmd\n",
+        colors: &[Color::Palette(2), Color::Palette(7), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Qubes",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m               `..--..`\n            `.----------.`\n        `..----------------..`\n     `.------------------------.``\n `..-------------....-------------..`\n.::----------..``    ``..----------:+:\n:////:----..`            `..---:/ossso\n:///////:`                  `/osssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:////////-`                .:sssssssso\n:///////////-.`        `-/osssssssssso\n`//////////////:-```.:+ssssssssssssso-\n  .-://////////////sssssssssssssso/-`\n     `.:///////////sssssssssssssso:.\n         .-:///////ssssssssssssssssss/`\n            `.:////ssss+/+ssssssssssss.\n                `--//-    `-/osssso/.\n\x1b[0m",
+        raw_art: "${c1}               `..--..`\n            `.----------.`\n        `..----------------..`\n     `.------------------------.``\n `..-------------....-------------..`\n.::----------..``    ``..----------:+:\n:////:----..`            `..---:/ossso\n:///////:`                  `/osssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:///////:                    /ssssssso\n:////////-`                .:sssssssso\n:///////////-.`        `-/osssssssssso\n`//////////////:-```.:+ssssssssssssso-\n  .-://////////////sssssssssssssso/-`\n     `.:///////////sssssssssssssso:.\n         .-:///////ssssssssssssssssss/`\n            `.:////ssss+/+ssssssssssss.\n                `--//-    `-/osssso/.\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 39,
     },
     Logo {
         name: "Qubyt",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m    ########################\x1b[1;35m(${c3}ooo\n\x1b[1;34m    ########################\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo                  \x1b[1;34m###\x1b[1;35m(${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo           \x1b[1;34m##${c3}o    \x1b[1;35m((((${c3}ooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo          o\x1b[1;35m((\x1b[1;34m###   ${c3}oooooo\n\x1b[1;34m###\x1b[1;35m(${c3}ooo           oo\x1b[1;35m((\x1b[1;34m###${c3}o\n\x1b[1;34m###\x1b[1;35m(${c3}ooo             ooo\x1b[1;35m((\x1b[1;34m###\n\x1b[1;34m################\x1b[1;35m(${c3}oo    oo\x1b[1;35m((((${c3}o\n\x1b[1;35m(((((((((((((((((${c3}ooo     ooooo\n  oooooooooooooooooo        o\n\x1b[0m",
+        raw_art: "${c1}    ########################${c2}(${c3}ooo\n${c1}    ########################${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo                  ${c1}###${c2}(${c3}ooo\n${c1}###${c2}(${c3}ooo           ${c1}##${c3}o    ${c2}((((${c3}ooo\n${c1}###${c2}(${c3}ooo          o${c2}((${c1}###   ${c3}oooooo\n${c1}###${c2}(${c3}ooo           oo${c2}((${c1}###${c3}o\n${c1}###${c2}(${c3}ooo             ooo${c2}((${c1}###\n${c1}################${c2}(${c3}oo    oo${c2}((((${c3}o\n${c2}(((((((((((((((((${c3}ooo     ooooo\n  oooooooooooooooooo        o\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(0), Color::Palette(4)],
         max_line_length: 32,
     },
     Logo {
         name: "Quibian",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m            `.--::::::::--.`\n        `.-:::-..``   ``..-::-.`\n      .::::-`   .\x1b[1;37m+\x1b[1;33m:``       `.-::.`\n    .::::.`    -::::::-`       `.::.\n  `-:::-`    -:::::::::--..``     .::`\n `::::-     .\x1b[1;37moy\x1b[1;33m:::::::---.```.:    `::`\n -::::  `.-:::::::::::-.```         `::\n.::::.`-:::::::::::::.               `:.\n-::::.:::::::::::::::                 -:\n::::::::::::::::::::`                 `:\n:::::::::::::::::::-                  `:\n:::::::::::::::::::                   --\n.:::::::::::::::::`                  `:`\n`:::::::::::::::::                   -`\n .:::::::::::::::-                  -`\n  `::::::::::::::-                `.`\n    .::::::::::::-               ``\n      `.--:::::-.\n\x1b[0m",
+        raw_art: "${c1}            `.--::::::::--.`\n        `.-:::-..``   ``..-::-.`\n      .::::-`   .${c2}+${c1}:``       `.-::.`\n    .::::.`    -::::::-`       `.::.\n  `-:::-`    -:::::::::--..``     .::`\n `::::-     .${c2}oy${c1}:::::::---.```.:    `::`\n -::::  `.-:::::::::::-.```         `::\n.::::.`-:::::::::::::.               `:.\n-::::.:::::::::::::::                 -:\n::::::::::::::::::::`                 `:\n:::::::::::::::::::-                  `:\n:::::::::::::::::::                   --\n.:::::::::::::::::`                  `:`\n`:::::::::::::::::                   -`\n .:::::::::::::::-                  -`\n  `::::::::::::::-                `.`\n    .::::::::::::-               ``\n      `.--:::::-.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 40,
     },
+    Logo {
+        name: "Quirky Werewolf",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n\x1b[0m",
+        raw_art: "${c1}           `-/osyyyysosyhhhhhyys+-\n  -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+\n yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy\n.NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/\n-NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/\n.Nm+shddyooo+/smNNNNmmmmNh.   :mmd.\n NNNNy:`   ./hmmmmmmmNNNN:     hNMh\n NMN-    -++- +NNNNNNNNNNm+..-sMMMM-\n.MMo    oNNNNo hNNNNNNNNmhdNNNMMMMM+\n.MMs    /NNNN/ dNmhs+:-`  yMMMMMMMM+\n mMM+     .. `sNN+.      hMMMMhhMMM-\n +MMMmo:...:sNMMMMMms:` hMMMMm.hMMy\n  yMMMMMMMMMMMNdMMMMMM::/+o+//dMMd`\n   sMMMMMMMMMMN+:oyyo:sMMMNNMMMNy`\n    :mMMMMMMMMMMMmddNMMMMMMMMmh/\n      /dMMMMMMMMMMMMMMMMMMNdy/`\n        .+hNMMMMMMMMMNmdhs/.\n            .:/+ooo+/:-.\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
+        max_line_length: 36,
+    },
     Logo {
         name: "RFRemix",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m          /:-------------:\\\\\n       :-------------------::\n     :-----------\x1b[1;37m/shhOHbmp\x1b[1;34m---:\\\\\n   /-----------\x1b[1;37momMMMNNNMMD  \x1b[1;34m---:\n  :-----------\x1b[1;37msMMMMNMNMP\x1b[1;34m.    ---:\n :-----------\x1b[1;37m:MMMdP\x1b[1;34m-------    ---\\\\\n,------------\x1b[1;37m:MMMd\x1b[1;34m--------    ---:\n:------------\x1b[1;37m:MMMd\x1b[1;34m-------    .---:\n:----    \x1b[1;37moNMMMMMMMMMNho\x1b[1;34m     .----:\n:--     .\x1b[1;37m+shhhMMMmhhy++\x1b[1;34m   .------/\n:-    -------\x1b[1;37m:MMMd\x1b[1;34m--------------:\n:-   --------\x1b[1;37m/MMMd\x1b[1;34m-------------;\n:-    ------\x1b[1;37m/hMMMy\x1b[1;34m------------:\n:--\x1b[1;37m :dMNdhhdNMMNo\x1b[1;34m------------;\n:---\x1b[1;37m:sdNMMMMNds:\x1b[1;34m------------:\n:------\x1b[1;37m:://:\x1b[1;34m-------------::\n:---------------------://\n\x1b[0m",
+        raw_art: "${c1}          /:-------------:\\\\\n       :-------------------::\n     :-----------${c2}/shhOHbmp${c1}---:\\\\\n   /-----------${c2}omMMMNNNMMD  ${c1}---:\n  :-----------${c2}sMMMMNMNMP${c1}.    ---:\n :-----------${c2}:MMMdP${c1}-------    ---\\\\\n,------------${c2}:MMMd${c1}--------    ---:\n:------------${c2}:MMMd${c1}-------    .---:\n:----    ${c2}oNMMMMMMMMMNho${c1}     .----:\n:--     .${c2}+shhhMMMmhhy++${c1}   .------/\n:-    -------${c2}:MMMd${c1}--------------:\n:-   --------${c2}/MMMd${c1}-------------;\n:-    ------${c2}/hMMMy${c1}------------:\n:--${c2} :dMNdhhdNMMNo${c1}------------;\n:---${c2}:sdNMMMMNds:${c1}------------:\n:------${c2}:://:${c1}-------------::\n:---------------------://\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "Radix",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                .:oyhdmNo\n             `/yhyoosdms`\n            -o+/ohmmho-\n           ..`.:/:-`\n     `.--:::-.``\x1b[1;31m\n  .+ydNMMMMMMNmhs:`\n`omMMMMMMMMMMMMMMNh-\noNMMMNmddhhyyhhhddmy.\nmMMMMNmmddhhysoo+/:-`\nyMMMMMMMMMMMMMMMMNNh.\n-dmmmmmNNMMMMMMMMMMs`\n -+oossyhmMMMMMMMMd-\n `sNMMMMMMMMMMMMMm:\n  `yMMMMMMNmdhhhh:\n   `sNMMMMMNmmho.\n    `+mMMMMMMMy.\n      .yNMMMm+`\n       `:yd+.\n\x1b[0m",
+        raw_art: "${c2}                .:oyhdmNo\n             `/yhyoosdms`\n            -o+/ohmmho-\n           ..`.:/:-`\n     `.--:::-.``${c1}\n  .+ydNMMMMMMNmhs:`\n`omMMMMMMMMMMMMMMNh-\noNMMMNmddhhyyhhhddmy.\nmMMMMNmmddhhysoo+/:-`\nyMMMMMMMMMMMMMMMMNNh.\n-dmmmmmNNMMMMMMMMMMs`\n -+oossyhmMMMMMMMMd-\n `sNMMMMMMMMMMMMMm:\n  `yMMMMMMNmdhhhh:\n   `sNMMMMMNmmho.\n    `+mMMMMMMMy.\n      .yNMMMm+`\n       `:yd+.\n",
+        colors: &[Color::Palette(1), Color::Palette(2)],
         max_line_length: 25,
     },
     Logo {
         name: "Raspbian",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m  `.::///+:/-.        --///+//-:``\n `+oooooooooooo:   `+oooooooooooo:\n  /oooo++//ooooo:  ooooo+//+ooooo.\n  `+ooooooo:-:oo-  +o+::/ooooooo:\n   `:oooooooo+``    `.oooooooo+-\n     `:++ooo/.        :+ooo+/.`\n        \x1b[1;31m...`  `.----.` ``..\n     .::::-``:::::::::.`-:::-`\n    -:::-`   .:::::::-`  `-:::-\n   `::.  `.--.`  `` `.---.``.::`\n       .::::::::`  -::::::::` `\n .::` .:::::::::- `::::::::::``::.\n-:::` ::::::::::.  ::::::::::.`:::-\n::::  -::::::::.   `-::::::::  ::::\n-::-   .-:::-.``....``.-::-.   -::-\n .. ``       .::::::::.     `..`..\n   -:::-`   -::::::::::`  .:::::`\n   :::::::` -::::::::::` :::::::.\n   .:::::::  -::::::::. ::::::::\n    `-:::::`   ..--.`   ::::::.\n      `...`  `...--..`  `...`\n            .::::::::::\n             `.-::::-`\n\x1b[0m",
+        raw_art: "${c1}  `.::///+:/-.        --///+//-:``\n `+oooooooooooo:   `+oooooooooooo:\n  /oooo++//ooooo:  ooooo+//+ooooo.\n  `+ooooooo:-:oo-  +o+::/ooooooo:\n   `:oooooooo+``    `.oooooooo+-\n     `:++ooo/.        :+ooo+/.`\n        ${c2}...`  `.----.` ``..\n     .::::-``:::::::::.`-:::-`\n    -:::-`   .:::::::-`  `-:::-\n   `::.  `.--.`  `` `.---.``.::`\n       .::::::::`  -::::::::` `\n .::` .:::::::::- `::::::::::``::.\n-:::` ::::::::::.  ::::::::::.`:::-\n::::  -::::::::.   `-::::::::  ::::\n-::-   .-:::-.``....``.-::-.   -::-\n .. ``       .::::::::.     `..`..\n   -:::-`   -::::::::::`  .:::::`\n   :::::::` -::::::::::` :::::::.\n   .:::::::  -::::::::. ::::::::\n    `-:::::`   ..--.`   ::::::.\n      `...`  `...--..`  `...`\n            .::::::::::\n             `.-::::-`\n",
+        colors: &[Color::Palette(2), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "Raspbian_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m   ..    ,.\n  :oo: .:oo:\n  'o\\\\o o/o:\n\x1b[1;31m :: . :: . ::\n:: :::  ::: ::\n:'  '',.''  ':\n ::: :::: :::\n ':,  ''  ,:'\n   ' ~::~ '\n\x1b[0m",
+        raw_art: "${c1}   ..    ,.\n  :oo: .:oo:\n  'o\\\\o o/o:\n${c2} :: . :: . ::\n:: :::  ::: ::\n:'  '',.''  ':\n ::: :::: :::\n ':,  ''  ,:'\n   ' ~::~ '\n",
+        colors: &[Color::Palette(2), Color::Palette(1)],
         max_line_length: 14,
     },
     Logo {
         name: "Reborn",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m\n        mMMMMMMMMM  MMMMMMMMMm\n       NM                    MN\n      MM  \x1b[1;32mdddddddd  dddddddd  \x1b[1;38;5;8mMN\n     mM  \x1b[1;32mdd                dd  \x1b[1;38;5;8mMM\n        \x1b[1;32mdd  hhhhhh   hhhhh  dd\n   \x1b[1;38;5;8mmM      \x1b[1;32mhh            hh      \x1b[1;38;5;8mMm\n  NM  \x1b[1;32mhd       \x1b[1;38;5;8mmMMMMMMd       \x1b[1;32mdh  \x1b[1;38;5;8mMN\n NM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\nNM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\n NM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\n  NM  \x1b[1;32mhd       \x1b[1;38;5;8mmMMMMMMm       \x1b[1;32mdh  \x1b[1;38;5;8mMN\n   mM      \x1b[1;32mhh            hh      \x1b[1;38;5;8mMm\n        \x1b[1;32mdd  hhhhhh  hhhhhh  dd\n     \x1b[1;38;5;8mMM  \x1b[1;32mdd                dd  \x1b[1;38;5;8mMM\n      MM  \x1b[1;32mdddddddd  dddddddd  \x1b[1;38;5;8mMN\n       NM                    MN\n        mMMMMMMMMM  MMMMMMMMMm\n\x1b[0m",
+        raw_art: "${c3}\n        mMMMMMMMMM  MMMMMMMMMm\n       NM                    MN\n      MM  ${c1}dddddddd  dddddddd  ${c3}MN\n     mM  ${c1}dd                dd  ${c3}MM\n        ${c1}dd  hhhhhh   hhhhh  dd\n   ${c3}mM      ${c1}hh            hh      ${c3}Mm\n  NM  ${c1}hd       ${c3}mMMMMMMd       ${c1}dh  ${c3}MN\n NM  ${c1}dd  hh   ${c3}mMMMMMMMMm   ${c1}hh  dd  ${c3}MN\nNM  ${c1}dd  hh   ${c3}mMMMMMMMMMMm   ${c1}hh  dd  ${c3}MN\n NM  ${c1}dd  hh   ${c3}mMMMMMMMMm   ${c1}hh  dd  ${c3}MN\n  NM  ${c1}hd       ${c3}mMMMMMMm       ${c1}dh  ${c3}MN\n   mM      ${c1}hh            hh      ${c3}Mm\n        ${c1}dd  hhhhhh  hhhhhh  dd\n     ${c3}MM  ${c1}dd                dd  ${c3}MM\n      MM  ${c1}dddddddd  dddddddd  ${c3}MN\n       NM                    MN\n        mMMMMMMMMM  MMMMMMMMMm\n",
+        colors: &[Color::Palette(2), Color::Palette(2), Color::Palette(8)],
         max_line_length: 38,
     },
+    Logo {
+        name: "Reborn OS",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;38;5;8m\n        mMMMMMMMMM  MMMMMMMMMm\n       NM                    MN\n      MM  \x1b[1;32mdddddddd  dddddddd  \x1b[1;38;5;8mMN\n     mM  \x1b[1;32mdd                dd  \x1b[1;38;5;8mMM\n        \x1b[1;32mdd  hhhhhh   hhhhh  dd\n   \x1b[1;38;5;8mmM      \x1b[1;32mhh            hh      \x1b[1;38;5;8mMm\n  NM  \x1b[1;32mhd       \x1b[1;38;5;8mmMMMMMMd       \x1b[1;32mdh  \x1b[1;38;5;8mMN\n NM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\nNM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\n NM  \x1b[1;32mdd  hh   \x1b[1;38;5;8mmMMMMMMMMm   \x1b[1;32mhh  dd  \x1b[1;38;5;8mMN\n  NM  \x1b[1;32mhd       \x1b[1;38;5;8mmMMMMMMm       \x1b[1;32mdh  \x1b[1;38;5;8mMN\n   mM      \x1b[1;32mhh            hh      \x1b[1;38;5;8mMm\n        \x1b[1;32mdd  hhhhhh  hhhhhh  dd\n     \x1b[1;38;5;8mMM  \x1b[1;32mdd                dd  \x1b[1;38;5;8mMM\n      MM  \x1b[1;32mdddddddd  dddddddd  \x1b[1;38;5;8mMN\n       NM                    MN\n        mMMMMMMMMM  MMMMMMMMMm\n\x1b[0m",
+        raw_art: "${c3}\n        mMMMMMMMMM  MMMMMMMMMm\n       NM                    MN\n      MM  ${c1}dddddddd  dddddddd  ${c3}MN\n     mM  ${c1}dd                dd  ${c3}MM\n        ${c1}dd  hhhhhh   hhhhh  dd\n   ${c3}mM      ${c1}hh            hh      ${c3}Mm\n  NM  ${c1}hd       ${c3}mMMMMMMd       ${c1}dh  ${c3}MN\n NM  ${c1}dd  hh   ${c3}mMMMMMMMMm   ${c1}hh  dd  ${c3}MN\nNM  ${c1}dd  hh   ${c3}mMMMMMMMMMMm   ${c1}hh  dd  ${c3}MN\n NM  ${c1}dd  hh   ${c3}mMMMMMMMMm   ${c1}hh  dd  ${c3}MN\n  NM  ${c1}hd       ${c3}mMMMMMMm       ${c1}dh  ${c3}MN\n   mM      ${c1}hh            hh      ${c3}Mm\n        ${c1}dd  hhhhhh  hhhhhh  dd\n     ${c3}MM  ${c1}dd                dd  ${c3}MM\n      MM  ${c1}dddddddd  dddddddd  ${c3}MN\n       NM                    MN\n        mMMMMMMMMM  MMMMMMMMMm\n",
+        colors: &[Color::Palette(2), Color::Palette(2), Color::Palette(8)],
+        max_line_length: 38,
+    },
+    Logo {
+        name: "Red Hat",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n\x1b[0m",
+        raw_art: "${c1}           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n",
+        colors: &[Color::Palette(1)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Red Star",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m                    ..\n                  .oK0l\n                 :0KKKKd.\n               .xKO0KKKKd\n              ,Od' .d0000l\n             .c;.   .'''...           ..'.\n.,:cloddxxxkkkkOOOOkkkkkkkkxxxxxxxxxkkkx:\n;kOOOOOOOkxOkc'...',;;;;,,,'',;;:cllc:,.\n .okkkkd,.lko  .......',;:cllc:;,,'''''.\n   .cdo. :xd' cd:.  ..';'',,,'',,;;;,'.\n      . .ddl.;doooc'..;oc;'..';::;,'.\n        coo;.oooolllllllcccc:'.  .\n       .ool''lllllccccccc:::::;.\n       ;lll. .':cccc:::::::;;;;'\n       :lcc:'',..';::::;;;;;;;,,.\n       :cccc::::;...';;;;;,,,,,,.\n       ,::::::;;;,'.  ..',,,,'''.\n        ........          ......\n\x1b[0m",
+        raw_art: "${c1}                    ..\n                  .oK0l\n                 :0KKKKd.\n               .xKO0KKKKd\n              ,Od' .d0000l\n             .c;.   .'''...           ..'.\n.,:cloddxxxkkkkOOOOkkkkkkkkxxxxxxxxxkkkx:\n;kOOOOOOOkxOkc'...',;;;;,,,'',;;:cllc:,.\n .okkkkd,.lko  .......',;:cllc:;,,'''''.\n   .cdo. :xd' cd:.  ..';'',,,'',,;;;,'.\n      . .ddl.;doooc'..;oc;'..';::;,'.\n        coo;.oooolllllllcccc:'.  .\n       .ool''lllllccccccc:::::;.\n       ;lll. .':cccc:::::::;;;;'\n       :lcc:'',..';::::;;;;;;;,,.\n       :cccc::::;...';;;;;,,,,,,.\n       ,::::::;;;,'.  ..',,,,'''.\n        ........          ......\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
+        max_line_length: 42,
+    },
     Logo {
         name: "Redcore",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                 RRRRRRRRR\n               RRRRRRRRRRRRR\n        RRRRRRRRRR      RRRRR\n   RRRRRRRRRRRRRRRRRRRRRRRRRRR\n RRRRRRR  RRR         RRR RRRRRRRR\nRRRRR    RR                 RRRRRRRRR\nRRRR    RR     RRRRRRRR      RR RRRRRR\nRRRR   R    RRRRRRRRRRRRRR   RR   RRRRR\nRRRR   R  RRRRRRRRRRRRRRRRRR  R   RRRRR\nRRRR     RRRRRRRRRRRRRRRRRRR  R   RRRR\n RRR     RRRRRRRRRRRRRRRRRRRR R   RRRR\n  RRR    RRRRRRRRRRRRRRRRRRRR    RRRR\n    RR   RRRRRRRRRRRRRRRRRRR    RRR\n     RR   RRRRRRRRRRRRRRRRR    RRR\n       RR   RRRRRRRRRRRRRR   RR\n         R       RRRR      RR\n\x1b[0m",
+        raw_art: "${c1}                 RRRRRRRRR\n               RRRRRRRRRRRRR\n        RRRRRRRRRR      RRRRR\n   RRRRRRRRRRRRRRRRRRRRRRRRRRR\n RRRRRRR  RRR         RRR RRRRRRRR\nRRRRR    RR                 RRRRRRRRR\nRRRR    RR     RRRRRRRR      RR RRRRRR\nRRRR   R    RRRRRRRRRRRRRR   RR   RRRRR\nRRRR   R  RRRRRRRRRRRRRRRRRR  R   RRRRR\nRRRR     RRRRRRRRRRRRRRRRRRR  R   RRRR\n RRR     RRRRRRRRRRRRRRRRRRRR R   RRRR\n  RRR    RRRRRRRRRRRRRRRRRRRR    RRRR\n    RR   RRRRRRRRRRRRRRRRRRR    RRR\n     RR   RRRRRRRRRRRRRRRRR    RRR\n       RR   RRRRRRRRRRRRRR   RR\n         R       RRRR      RR\n",
+        colors: &[Color::Palette(1)],
         max_line_length: 39,
     },
+    Logo {
+        name: "Redhat",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n\x1b[0m",
+        raw_art: "${c1}           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n",
+        colors: &[Color::Palette(1)],
+        max_line_length: 40,
+    },
     Logo {
         name: "Redstar",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                    ..\n                  .oK0l\n                 :0KKKKd.\n               .xKO0KKKKd\n              ,Od' .d0000l\n             .c;.   .'''...           ..'.\n.,:cloddxxxkkkkOOOOkkkkkkkkxxxxxxxxxkkkx:\n;kOOOOOOOkxOkc'...',;;;;,,,'',;;:cllc:,.\n .okkkkd,.lko  .......',;:cllc:;,,'''''.\n   .cdo. :xd' cd:.  ..';'',,,'',,;;;,'.\n      . .ddl.;doooc'..;oc;'..';::;,'.\n        coo;.oooolllllllcccc:'.  .\n       .ool''lllllccccccc:::::;.\n       ;lll. .':cccc:::::::;;;;'\n       :lcc:'',..';::::;;;;;;;,,.\n       :cccc::::;...';;;;;,,,,,,.\n       ,::::::;;;,'.  ..',,,,'''.\n        ........          ......\n\x1b[0m",
+        raw_art: "${c1}                    ..\n                  .oK0l\n                 :0KKKKd.\n               .xKO0KKKKd\n              ,Od' .d0000l\n             .c;.   .'''...           ..'.\n.,:cloddxxxkkkkOOOOkkkkkkkkxxxxxxxxxkkkx:\n;kOOOOOOOkxOkc'...',;;;;,,,'',;;:cllc:,.\n .okkkkd,.lko  .......',;:cllc:;,,'''''.\n   .cdo. :xd' cd:.  ..';'',,,'',,;;;,'.\n      . .ddl.;doooc'..;oc;'..';::;,'.\n        coo;.oooolllllllcccc:'.  .\n       .ool''lllllccccccc:::::;.\n       ;lll. .':cccc:::::::;;;;'\n       :lcc:'',..';::::;;;;;;;,,.\n       :cccc::::;...';;;;;,,,,,,.\n       ,::::::;;;,'.  ..',,,,'''.\n        ........          ......\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 42,
     },
+    Logo {
+        name: "Refracted Devuan",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m                             A\n                            VW\n                           VVW\\\\\n                         .yWWW\\\\\n ,;,,u,;yy;;v;uyyyyyyy  ,WWWWW^\n    *WWWWWWWWWWWWWWWW/  $VWWWWw      ,\n        ^*%WWWWWWVWWX  $WWWW**    ,yy\n        ,    \"**WWW/' **'   ,yy/WWW*`\n       &WWWWwy    `*`  <,ywWW%VWWW*\n     yWWWWWWWWWW*    .,   \"**WW%W\n   ,&WWWWWM*\"`  ,y/  &WWWww   ^*\n  XWWX*^   ,yWWWW09 .WWWWWWWWwy,\n *`        &WWWWWM  WWWWWWWWWWWWWww,\n           (WWWWW` /#####WWW***********\n           ^WWWW\n            VWW\n            Wh.\n            V/\n\x1b[0m",
+        raw_art: "${c2}                             A\n                            VW\n                           VVW\\\\\n                         .yWWW\\\\\n ,;,,u,;yy;;v;uyyyyyyy  ,WWWWW^\n    *WWWWWWWWWWWWWWWW/  $VWWWWw      ,\n        ^*%WWWWWWVWWX  $WWWW**    ,yy\n        ,    \"**WWW/' **'   ,yy/WWW*`\n       &WWWWwy    `*`  <,ywWW%VWWW*\n     yWWWWWWWWWW*    .,   \"**WW%W\n   ,&WWWWWM*\"`  ,y/  &WWWww   ^*\n  XWWX*^   ,yWWWW09 .WWWWWWWWwy,\n *`        &WWWWWM  WWWWWWWWWWWWWww,\n           (WWWWW` /#####WWW***********\n           ^WWWW\n            VWW\n            Wh.\n            V/\n",
+        colors: &[Color::Palette(8), Color::Palette(7)],
+        max_line_length: 39,
+    },
     Logo {
         name: "Refracted_Devuan",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                             A\n                            VW\n                           VVW\\\\\n                         .yWWW\\\\\n ,;,,u,;yy;;v;uyyyyyyy  ,WWWWW^\n    *WWWWWWWWWWWWWWWW/  $VWWWWw      ,\n        ^*%WWWWWWVWWX  $WWWW**    ,yy\n        ,    \"**WWW/' **'   ,yy/WWW*`\n       &WWWWwy    `*`  <,ywWW%VWWW*\n     yWWWWWWWWWW*    .,   \"**WW%W\n   ,&WWWWWM*\"`  ,y/  &WWWww   ^*\n  XWWX*^   ,yWWWW09 .WWWWWWWWwy,\n *`        &WWWWWM  WWWWWWWWWWWWWww,\n           (WWWWW` /#####WWW***********\n           ^WWWW\n            VWW\n            Wh.\n            V/\n\x1b[0m",
+        raw_art: "${c2}                             A\n                            VW\n                           VVW\\\\\n                         .yWWW\\\\\n ,;,,u,;yy;;v;uyyyyyyy  ,WWWWW^\n    *WWWWWWWWWWWWWWWW/  $VWWWWw      ,\n        ^*%WWWWWWVWWX  $WWWW**    ,yy\n        ,    \"**WWW/' **'   ,yy/WWW*`\n       &WWWWwy    `*`  <,ywWW%VWWW*\n     yWWWWWWWWWW*    .,   \"**WW%W\n   ,&WWWWWM*\"`  ,y/  &WWWww   ^*\n  XWWX*^   ,yWWWW09 .WWWWWWWWwy,\n *`        &WWWWWM  WWWWWWWWWWWWWww,\n           (WWWWW` /#####WWW***********\n           ^WWWW\n            VWW\n            Wh.\n            V/\n",
+        colors: &[Color::Palette(8), Color::Palette(7)],
         max_line_length: 39,
     },
     Logo {
         name: "Regata",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m            ddhso+++++osydd\n        dho/.`hh\x1b[1;31m.:/+/:.\x1b[1;37mhhh`:+yd\n      do-hhhhhh\x1b[1;31m/sssssss+`\x1b[1;37mhhhhh./yd\n    h/`hhhhhhh\x1b[1;31m-sssssssss:\x1b[1;37mhhhhhhhh-yd\n  do`hhhhhhhhh\x1b[1;31m`ossssssso.\x1b[1;37mhhhhhhhhhh/d\n d/hhhhhhhhhhhh\x1b[1;31m`/ossso/.\x1b[1;37mhhhhhhhhhhhh.h\n /hhhhhhhhhhhh\x1b[1;34m`-/osyso/-`\x1b[1;37mhhhhhhhhhhhh.h\nshh\x1b[1;35m-/ooo+-\x1b[1;37mhhh\x1b[1;34m:syyso+osyys/`\x1b[1;37mhhh\x1b[1;33m`+oo`\x1b[1;37mhhh/\nh\x1b[1;35m`ohhhhhhho`\x1b[1;34m+yyo.\x1b[1;37mhhhhh\x1b[1;34m.+yyo`\x1b[1;33m.sssssss.\x1b[1;37mh`h\ns\x1b[1;35m:hhhhhhhhho\x1b[1;34myys`\x1b[1;37mhhhhhhh\x1b[1;34m.oyy/\x1b[1;33mossssssso-\x1b[1;37mhs\ns\x1b[1;35m.yhhhhhhhy/\x1b[1;34myys`\x1b[1;37mhhhhhhh\x1b[1;34m.oyy/\x1b[1;33mossssssso-\x1b[1;37mhs\nhh\x1b[1;35m./syyys+.\x1b[1;37m \x1b[1;34m+yy+.\x1b[1;37mhhhhh\x1b[1;34m.+yyo`\x1b[1;33m.ossssso/\x1b[1;37mh`h\nshhh\x1b[1;35m``.`\x1b[1;37mhhh\x1b[1;34m`/syyso++oyys/`\x1b[1;37mhhh\x1b[1;33m`+++-`\x1b[1;37mhh:h\nd/hhhhhhhhhhhh\x1b[1;34m`-/osyso+-`\x1b[1;37mhhhhhhhhhhhh.h\n d/hhhhhhhhhhhh\x1b[1;32m`/ossso/.\x1b[1;37mhhhhhhhhhhhh.h\n  do`hhhhhhhhh\x1b[1;32m`ossssssso.\x1b[1;37mhhhhhhhhhh:h\n    h/`hhhhhhh\x1b[1;32m-sssssssss:\x1b[1;37mhhhhhhhh-yd\n      h+.hhhhhh\x1b[1;32m+sssssss+\x1b[1;37mhhhhhh`/yd\n        dho:.hhh\x1b[1;32m.:+++/.\x1b[1;37mhhh`-+yd\n            ddhso+++++osyhd\n\x1b[0m",
+        raw_art: "${c1}            ddhso+++++osydd\n        dho/.`hh${c2}.:/+/:.${c1}hhh`:+yd\n      do-hhhhhh${c2}/sssssss+`${c1}hhhhh./yd\n    h/`hhhhhhh${c2}-sssssssss:${c1}hhhhhhhh-yd\n  do`hhhhhhhhh${c2}`ossssssso.${c1}hhhhhhhhhh/d\n d/hhhhhhhhhhhh${c2}`/ossso/.${c1}hhhhhhhhhhhh.h\n /hhhhhhhhhhhh${c3}`-/osyso/-`${c1}hhhhhhhhhhhh.h\nshh${c4}-/ooo+-${c1}hhh${c3}:syyso+osyys/`${c1}hhh${c5}`+oo`${c1}hhh/\nh${c4}`ohhhhhhho`${c3}+yyo.${c1}hhhhh${c3}.+yyo`${c5}.sssssss.${c1}h`h\ns${c4}:hhhhhhhhho${c3}yys`${c1}hhhhhhh${c3}.oyy/${c5}ossssssso-${c1}hs\ns${c4}.yhhhhhhhy/${c3}yys`${c1}hhhhhhh${c3}.oyy/${c5}ossssssso-${c1}hs\nhh${c4}./syyys+.${c1} ${c3}+yy+.${c1}hhhhh${c3}.+yyo`${c5}.ossssso/${c1}h`h\nshhh${c4}``.`${c1}hhh${c3}`/syyso++oyys/`${c1}hhh${c5}`+++-`${c1}hh:h\nd/hhhhhhhhhhhh${c3}`-/osyso+-`${c1}hhhhhhhhhhhh.h\n d/hhhhhhhhhhhh${c6}`/ossso/.${c1}hhhhhhhhhhhh.h\n  do`hhhhhhhhh${c6}`ossssssso.${c1}hhhhhhhhhh:h\n    h/`hhhhhhh${c6}-sssssssss:${c1}hhhhhhhh-yd\n      h+.hhhhhh${c6}+sssssss+${c1}hhhhhh`/yd\n        dho:.hhh${c6}.:+++/.${c1}hhh`-+yd\n            ddhso+++++osyhd\n",
+        colors: &[Color::Palette(7), Color::Palette(1), Color::Palette(4), Color::Palette(5), Color::Palette(3), Color::Palette(2)],
         max_line_length: 40,
     },
     Logo {
         name: "Regolith",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m\n                 ``....```\n            `.:/++++++/::-.`\n          -/+++++++:.`\n        -++++++++:`\n      `/++++++++-\n     `/++++++++.                    -/+/\n     /++++++++/             ``   .:+++:.\n    -+++++++++/          ./++++:+++/-`\n    :+++++++++/         `+++++++/-`\n    :++++++++++`      .-/+++++++`\n   `:++++++++++/``.-/++++:-:::-`      `\n `:+++++++++++++++++/:.`            ./`\n:++/-:+++++++++/:-..              -/+.\n+++++++++/::-...:/+++/-..````..-/+++.\n`......``.::/+++++++++++++++++++++/.\n         -/+++++++++++++++++++++/.\n           .:/+++++++++++++++/-`\n              `.-:://////:-.\n\x1b[0m",
+        raw_art: "${c1}\n                 ``....```\n            `.:/++++++/::-.`\n          -/+++++++:.`\n        -++++++++:`\n      `/++++++++-\n     `/++++++++.                    -/+/\n     /++++++++/             ``   .:+++:.\n    -+++++++++/          ./++++:+++/-`\n    :+++++++++/         `+++++++/-`\n    :++++++++++`      .-/+++++++`\n   `:++++++++++/``.-/++++:-:::-`      `\n `:+++++++++++++++++/:.`            ./`\n:++/-:+++++++++/:-..              -/+.\n+++++++++/::-...:/+++/-..````..-/+++.\n`......``.::/+++++++++++++++++++++/.\n         -/+++++++++++++++++++++/.\n           .:/+++++++++++++++/-`\n              `.-:://////:-.\n",
+        colors: &[Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Rosa",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           ROSAROSAROSAROSAR\n        ROSA               AROS\n      ROS   SAROSAROSAROSAR   AROS\n    RO   ROSAROSAROSAROSAROSAR   RO\n  ARO  AROSAROSAROSARO      AROS  ROS\n ARO  ROSAROS         OSAR   ROSA  ROS\n RO  AROSA   ROSAROSAROSA    ROSAR  RO\nRO  ROSAR  ROSAROSAROSAR  R  ROSARO  RO\nRO  ROSA  AROSAROSAROSA  AR  ROSARO  AR\nRO AROS  ROSAROSAROSA   ROS  AROSARO AR\nRO AROS  ROSAROSARO   ROSARO  ROSARO AR\nRO  ROS  AROSAROS   ROSAROSA AROSAR  AR\nRO  ROSA  ROS     ROSAROSAR  ROSARO  RO\n RO  ROS     AROSAROSAROSA  ROSARO  AR\n ARO  ROSA   ROSAROSAROS   AROSAR  ARO\n  ARO  OROSA      R      ROSAROS  ROS\n    RO   AROSAROS   AROSAROSAR   RO\n     AROS   AROSAROSAROSARO   AROS\n        ROSA               SARO\n           ROSAROSAROSAROSAR\n\x1b[0m",
+        raw_art: "${c1}           ROSAROSAROSAROSAR\n        ROSA               AROS\n      ROS   SAROSAROSAROSAR   AROS\n    RO   ROSAROSAROSAROSAROSAR   RO\n  ARO  AROSAROSAROSARO      AROS  ROS\n ARO  ROSAROS         OSAR   ROSA  ROS\n RO  AROSA   ROSAROSAROSA    ROSAR  RO\nRO  ROSAR  ROSAROSAROSAR  R  ROSARO  RO\nRO  ROSA  AROSAROSAROSA  AR  ROSARO  AR\nRO AROS  ROSAROSAROSA   ROS  AROSARO AR\nRO AROS  ROSAROSARO   ROSARO  ROSARO AR\nRO  ROS  AROSAROS   ROSAROSA AROSAR  AR\nRO  ROSA  ROS     ROSAROSAR  ROSARO  RO\n RO  ROS     AROSAROSAROSA  ROSARO  AR\n ARO  ROSA   ROSAROSAROS   AROSAR  ARO\n  ARO  OROSA      R      ROSAROS  ROS\n    RO   AROSAROS   AROSAROSAR   RO\n     AROS   AROSAROSAROSARO   AROS\n        ROSA               SARO\n           ROSAROSAROSAROSAR\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 39,
     },
     Logo {
         name: "SUSE",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'\x1b[1;32m  Okxol:;,.          \x1b[1;37m:O0d.\n  .OK\x1b[1;32mKKK0kOKKKKKKKKKKOxo:,      \x1b[1;37mlKO.\n ,0K\x1b[1;32mKKKKKKKKKKKKKKK0P^\x1b[1;37m,,,\x1b[1;32m^dx:\x1b[1;37m    ;00,\n.OK\x1b[1;32mKKKKKKKKKKKKKKKk'\x1b[1;37m.oOPPb.\x1b[1;32m'0k.\x1b[1;37m   cKO.\n:KK\x1b[1;32mKKKKKKKKKKKKKKK: \x1b[1;37mkKx..dd \x1b[1;32mlKd\x1b[1;37m   'OK:\ndKK\x1b[1;32mKKKKKKKKKOx0KKKd \x1b[1;37m^0KKKO' \x1b[1;32mkKKc\x1b[1;37m   dKd\ndKK\x1b[1;32mKKKKKKKKKK;.;oOKx,..\x1b[1;37m^\x1b[1;32m..;kKKK0.\x1b[1;37m  dKd\n:KK\x1b[1;32mKKKKKKKKKK0o;...^cdxxOK0O/^^'  \x1b[1;37m.0K:\n kKK\x1b[1;32mKKKKKKKKKKKKK0x;,,......,;od  \x1b[1;37mlKk\n '0K\x1b[1;32mKKKKKKKKKKKKKKKKKKKK00KKOo^  \x1b[1;37mc00'\n  'kK\x1b[1;32mKKOxddxkOO00000Okxoc;''   \x1b[1;37m.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n\x1b[0m",
+        raw_art: "${c2}           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'${c1}  Okxol:;,.          ${c2}:O0d.\n  .OK${c1}KKK0kOKKKKKKKKKKOxo:,      ${c2}lKO.\n ,0K${c1}KKKKKKKKKKKKKKK0P^${c2},,,${c1}^dx:${c2}    ;00,\n.OK${c1}KKKKKKKKKKKKKKKk'${c2}.oOPPb.${c1}'0k.${c2}   cKO.\n:KK${c1}KKKKKKKKKKKKKKK: ${c2}kKx..dd ${c1}lKd${c2}   'OK:\ndKK${c1}KKKKKKKKKOx0KKKd ${c2}^0KKKO' ${c1}kKKc${c2}   dKd\ndKK${c1}KKKKKKKKKK;.;oOKx,..${c2}^${c1}..;kKKK0.${c2}  dKd\n:KK${c1}KKKKKKKKKK0o;...^cdxxOK0O/^^'  ${c2}.0K:\n kKK${c1}KKKKKKKKKKKKK0x;,,......,;od  ${c2}lKk\n '0K${c1}KKKKKKKKKKKKKKKKKKKK00KKOo^  ${c2}c00'\n  'kK${c1}KKOxddxkOO00000Okxoc;''   ${c2}.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 38,
     },
     Logo {
         name: "Sabayon",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m            ...........\n         ..             ..\n      ..                   ..\n    ..           \x1b[1;37mo           \x1b[1;34m..\n  ..            \x1b[1;37m:W'            \x1b[1;34m..\n ..             \x1b[1;37m.d.             \x1b[1;34m..\n:.             \x1b[1;37m.KNO              \x1b[1;34m.:\n:.             \x1b[1;37mcNNN.             \x1b[1;34m.:\n:              \x1b[1;37mdXXX,              \x1b[1;34m:\n:   \x1b[1;37m.          dXXX,       .cd,   \x1b[1;34m:\n:   \x1b[1;37m'kc ..     dKKK.    ,ll;:'    \x1b[1;34m:\n:     \x1b[1;37m.xkkxc;..dkkkc',cxkkl       \x1b[1;34m:\n:.     \x1b[1;37m.,cdddddddddddddo:.       \x1b[1;34m.:\n ..         \x1b[1;37m:lllllll:           \x1b[1;34m..\n   ..         \x1b[1;37m',,,,,          \x1b[1;34m..\n     ..                     ..\n        ..               ..\n          ...............\n\x1b[0m",
+        raw_art: "${c1}            ...........\n         ..             ..\n      ..                   ..\n    ..           ${c2}o           ${c1}..\n  ..            ${c2}:W'            ${c1}..\n ..             ${c2}.d.             ${c1}..\n:.             ${c2}.KNO              ${c1}.:\n:.             ${c2}cNNN.             ${c1}.:\n:              ${c2}dXXX,              ${c1}:\n:   ${c2}.          dXXX,       .cd,   ${c1}:\n:   ${c2}'kc ..     dKKK.    ,ll;:'    ${c1}:\n:     ${c2}.xkkxc;..dkkkc',cxkkl       ${c1}:\n:.     ${c2}.,cdddddddddddddo:.       ${c1}.:\n ..         ${c2}:lllllll:           ${c1}..\n   ..         ${c2}',,,,,          ${c1}..\n     ..                     ..\n        ..               ..\n          ...............\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "Sailfish",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                 _a@b\n              _#b (b\n            _@@   @_         _,\n          _#^@ _#*^^*gg,aa@^^\n          #- @@^  _a@^^\n          @_  *g#b\n          ^@_   ^@_\n            ^@_   @\n             @(b (b\n            #b(b#^\n          _@_#@^\n       _a@a*^\n   ,a@*^\n\x1b[0m",
+        raw_art: "${c1}                 _a@b\n              _#b (b\n            _@@   @_         _,\n          _#^@ _#*^^*gg,aa@^^\n          #- @@^  _a@^^\n          @_  *g#b\n          ^@_   ^@_\n            ^@_   @\n             @(b (b\n            #b(b#^\n          _@_#@^\n       _a@a*^\n   ,a@*^\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 31,
     },
     Logo {
         name: "SalentOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                 ``..``\n        .-:+oshdNMMMMMMNdhyo+:-.`\n  -oydmMMMMMMMMMMMMMMMMMMMMMMMMMMNdhs/\n\x1b[1;37m +hdddm\x1b[1;32mNMMMMMMMMMMMMMMMMMMMMMMMMN\x1b[1;37mmdddh+`\n\x1b[1;31m`MMMMMN\x1b[1;37mmdddddm\x1b[1;32mMMMMMMMMMMMM\x1b[1;37mmdddddm\x1b[1;33mNMMMMM-\n\x1b[1;31m mMMMMMMMMMMMN\x1b[1;37mddddhyyhhddd\x1b[1;33mNMMMMMMMMMMMM`\n\x1b[1;31m dMMMMMMMMMMMMMMMMM\x1b[1;37moo\x1b[1;33mMMMMMMMMMMMMMMMMMN`\n\x1b[1;31m yMMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMMd\n\x1b[1;31m +MMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMMy\n\x1b[1;31m :MMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMMo\n\x1b[1;31m .MMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMM/\n\x1b[1;31m `NMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMM-\n\x1b[1;31m  mMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMN`\n\x1b[1;31m  hMMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMm\n\x1b[1;31m  /MMMMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMMMMy\n\x1b[1;31m   .+hMMMMMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMMMMMms:\n\x1b[1;31m      `:smMMMMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMMMMNh+.\n\x1b[1;31m          .+hMMMMMM\x1b[1;37mhh\x1b[1;33mMMMMMMdo:\n\x1b[1;31m             `:smMM\x1b[1;37myy\x1b[1;33mMMNy/`\n                 \x1b[1;31m.- \x1b[1;37m`\x1b[1;33m:.\n\x1b[0m",
+        raw_art: "${c1}                 ``..``\n        .-:+oshdNMMMMMMNdhyo+:-.`\n  -oydmMMMMMMMMMMMMMMMMMMMMMMMMMMNdhs/\n${c4} +hdddm${c1}NMMMMMMMMMMMMMMMMMMMMMMMMN${c4}mdddh+`\n${c2}`MMMMMN${c4}mdddddm${c1}MMMMMMMMMMMM${c4}mdddddm${c3}NMMMMM-\n${c2} mMMMMMMMMMMMN${c4}ddddhyyhhddd${c3}NMMMMMMMMMMMM`\n${c2} dMMMMMMMMMMMMMMMMM${c4}oo${c3}MMMMMMMMMMMMMMMMMN`\n${c2} yMMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMMd\n${c2} +MMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMMy\n${c2} :MMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMMo\n${c2} .MMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMM/\n${c2} `NMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMM-\n${c2}  mMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMN`\n${c2}  hMMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMm\n${c2}  /MMMMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMMMMy\n${c2}   .+hMMMMMMMMMMMMM${c4}hh${c3}MMMMMMMMMMMMMms:\n${c2}      `:smMMMMMMMMM${c4}hh${c3}MMMMMMMMMNh+.\n${c2}          .+hMMMMMM${c4}hh${c3}MMMMMMdo:\n${c2}             `:smMM${c4}yy${c3}MMNy/`\n                 ${c2}.- ${c4}`${c3}:.\n",
+        colors: &[Color::Palette(2), Color::Palette(1), Color::Palette(3), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "SambaBOX",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m\n                    #\n               *////#####\n           /////////#########(\n      .((((((/////    ,####(#(((((\n  /#######(((*             (#(((((((((.\n//((#(#(#,        ((##(        ,((((((//\n//////        #(##########(       //////\n//////    ((#(#(#(#(##########(/////////\n/////(    (((((((#########(##((((((/////\n/(((#(                             ((((/\n####(#                             ((###\n#########(((/////////(((((((((,    (#(#(\n########(   /////////(((((((*      #####\n####///,        *////(((         (((((((\n.///////////                .//(((((((((\n     ///////////,       *(/////((((*\n         ,/(((((((((##########/.\n             .((((((#######\n                  ((##*\n\x1b[0m",
+        raw_art: "${c1}\n                    #\n               *////#####\n           /////////#########(\n      .((((((/////    ,####(#(((((\n  /#######(((*             (#(((((((((.\n//((#(#(#,        ((##(        ,((((((//\n//////        #(##########(       //////\n//////    ((#(#(#(#(##########(/////////\n/////(    (((((((#########(##((((((/////\n/(((#(                             ((((/\n####(#                             ((###\n#########(((/////////(((((((((,    (#(#(\n########(   /////////(((((((*      #####\n####///,        *////(((         (((((((\n.///////////                .//(((((((((\n     ///////////,       *(/////((((*\n         ,/(((((((((##########/.\n             .((((((#######\n                  ((##*\n",
+        colors: &[Color::Palette(3), Color::Palette(6)],
         max_line_length: 40,
     },
     Logo {
         name: "Scientific",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                 =/;;/-\n                +:    //\n               /;      /;\n              -X        H.\n.//;;;:;;-,   X=        :+   .-;:=;:;#;.\nM-       ,=;;;#:,      ,:#;;:=,       ,@\n:#           :#.=/++++/=.$=           #=\n ,#;         #/:+/;,,/++:+/         ;+.\n   ,+/.    ,;@+,        ,#H;,    ,/+,\n      ;+;;/= @.  \x1b[1;31m.H\x1b[1;37m#\x1b[1;31m#X   \x1b[1;34m-X :///+;\n      ;+=;;;.@,  \x1b[1;37m.X\x1b[1;31mM\x1b[1;37m@$.  \x1b[1;34m=X.//;=#/.\n   ,;:      :@#=        =$H:     .+#-\n ,#=         #;-///==///-//         =#,\n;+           :#-;;;:;;;;-X-           +:\n@-      .-;;;;M-        =M/;;;-.      -X\n :;;::;;-.    #-        :+    ,-;;-;:==\n              ,X        H.\n               ;/      #=\n                //    +;\n                 '////'\n\x1b[0m",
+        raw_art: "${c1}                 =/;;/-\n                +:    //\n               /;      /;\n              -X        H.\n.//;;;:;;-,   X=        :+   .-;:=;:;#;.\nM-       ,=;;;#:,      ,:#;;:=,       ,@\n:#           :#.=/++++/=.$=           #=\n ,#;         #/:+/;,,/++:+/         ;+.\n   ,+/.    ,;@+,        ,#H;,    ,/+,\n      ;+;;/= @.  ${c3}.H${c2}#${c3}#X   ${c1}-X :///+;\n      ;+=;;;.@,  ${c2}.X${c3}M${c2}@$.  ${c1}=X.//;=#/.\n   ,;:      :@#=        =$H:     .+#-\n ,#=         #;-///==///-//         =#,\n;+           :#-;;;:;;;;-X-           +:\n@-      .-;;;;M-        =M/;;;-.      -X\n :;;::;;-.    #-        :+    ,-;;-;:==\n              ,X        H.\n               ;/      #=\n                //    +;\n                 '////'\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Septor",
         is_wildcard: true,
         ascii_art: "\x1b[1;34mssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssss\x1b[1;37m;okOOOOOOOOOOOOOOko;\x1b[1;34mssssssssss\nsssssssss\x1b[1;37moNWWWWWWWWWWWWWWWWWWNo\x1b[1;34msssssssss\nssssssss\x1b[1;37m:WWWWWWWWWWWWWWWWWWWWWW:\x1b[1;34mssssssss\nssssssss\x1b[1;37mlWWWWWk\x1b[1;34mssssssssss\x1b[1;37mlddddd:\x1b[1;34mssssssss\nssssssss\x1b[1;37mcWWWWWNKKKKKKKKKKKKOx:\x1b[1;34mssssssssss\n\x1b[1;34myy\x1b[1;34msssssss\x1b[1;37mOWWWWWWWWWWWWWWWWWWWWx\x1b[1;34msssssss\x1b[1;34myy\nyyyyyyyyyy\x1b[1;37m:kKNNNNNNNNNNNNWWWWWW:\x1b[1;34myyyyyyyy\nyyyyyyyy\x1b[1;37msccccc;\x1b[1;34myyyyyyyyyy\x1b[1;37mkWWWWW:\x1b[1;34myyyyyyyy\nyyyyyyyy\x1b[1;37m:WWWWWWNNNNNNNNNNWWWWWW;\x1b[1;34myyyyyyyy\nyyyyyyyy\x1b[1;37m.dWWWWWWWWWWWWWWWWWWWNd\x1b[1;34myyyyyyyyy\nyyyyyyyyyy\x1b[1;37msdO0KKKKKKKKKKKK0Od;\x1b[1;34myyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\n\x1b[0m",
+        raw_art: "${c1}ssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssssssssssssssssssssssssssssssssss\nssssssssss${c2};okOOOOOOOOOOOOOOko;${c1}ssssssssss\nsssssssss${c2}oNWWWWWWWWWWWWWWWWWWNo${c1}sssssssss\nssssssss${c2}:WWWWWWWWWWWWWWWWWWWWWW:${c1}ssssssss\nssssssss${c2}lWWWWWk${c1}ssssssssss${c2}lddddd:${c1}ssssssss\nssssssss${c2}cWWWWWNKKKKKKKKKKKKOx:${c1}ssssssssss\n${c3}yy${c1}sssssss${c2}OWWWWWWWWWWWWWWWWWWWWx${c1}sssssss${c3}yy\nyyyyyyyyyy${c2}:kKNNNNNNNNNNNNWWWWWW:${c3}yyyyyyyy\nyyyyyyyy${c2}sccccc;${c3}yyyyyyyyyy${c2}kWWWWW:${c3}yyyyyyyy\nyyyyyyyy${c2}:WWWWWWNNNNNNNNNNWWWWWW;${c3}yyyyyyyy\nyyyyyyyy${c2}.dWWWWWWWWWWWWWWWWWWWNd${c3}yyyyyyyyy\nyyyyyyyyyy${c2}sdO0KKKKKKKKKKKK0Od;${c3}yyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "Serene",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m              __---''''''---__\n          .                      .\n        :                          :\n      -                       _______----_-\n     s               __----'''     __----\n __h_            _-'           _-'     h\n '-._''--.._    ;           _-'         y\n  :  ''-._  '-._/        _-'             :\n  y       ':_       _--''                y\n  m    .--'' '-._.;'                     m\n  m   :        :                         m\n  y    '.._     '-__                     y\n  :        '--._    '''----___           :\n   y            '--._         ''-- _    y\n    h                '--._          :  h\n     s                  __';         vs\n      -         __..--''             -\n        :_..--''                   :\n          .                     _ .\n            `''---______---''-``\n\x1b[0m",
+        raw_art: "${c1}              __---''''''---__\n          .                      .\n        :                          :\n      -                       _______----_-\n     s               __----'''     __----\n __h_            _-'           _-'     h\n '-._''--.._    ;           _-'         y\n  :  ''-._  '-._/        _-'             :\n  y       ':_       _--''                y\n  m    .--'' '-._.;'                     m\n  m   :        :                         m\n  y    '.._     '-__                     y\n  :        '--._    '''----___           :\n   y            '--._         ''-- _    y\n    h                '--._          :  h\n     s                  __';         vs\n      -         __..--''             -\n        :_..--''                   :\n          .                     _ .\n            `''---______---''-``\n",
+        colors: &[Color::Palette(6), Color::Palette(6)],
         max_line_length: 43,
     },
     Logo {
         name: "SharkLinux",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                              `:shd/\n                          `:yNMMMMs\n                       `-smMMMMMMN.\n                     .+dNMMMMMMMMs\n                   .smNNMMMMMMMMm`\n                 .sNNNNNNNMMMMMM/\n               `omNNNNNNNMMMMMMm\n              /dNNNNNNNNMMMMMMM+\n            .yNNNNNNNNNMMMMMMMN`\n           +mNNNNNNNNNMMMMMMMMh\n         .hNNNNNNNNNNMMMMMMMMMs\n        +mMNNNNNNNNMMMMMMMMMMMs\n      .hNMMNNNNMMMMMMMMMMMMMMMd\n    .oNNNNNNNNNNMMMMMMMMMMMMMMMo\n `:+syyssoo++++ooooossssssssssso:\n\x1b[0m",
+        raw_art: "${c1}                              `:shd/\n                          `:yNMMMMs\n                       `-smMMMMMMN.\n                     .+dNMMMMMMMMs\n                   .smNNMMMMMMMMm`\n                 .sNNNNNNNMMMMMM/\n               `omNNNNNNNMMMMMMm\n              /dNNNNNNNNMMMMMMM+\n            .yNNNNNNNNNMMMMMMMN`\n           +mNNNNNNNNNMMMMMMMMh\n         .hNNNNNNNNNNMMMMMMMMMs\n        +mMNNNNNNNNMMMMMMMMMMMs\n      .hNMMNNNNMMMMMMMMMMMMMMMd\n    .oNNNNNNNNNNMMMMMMMMMMMMMMMo\n `:+syyssoo++++ooooossssssssssso:\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 36,
     },
     Logo {
         name: "Siduction",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                _aass,\n               jQh: =$w\n               QWmwawQW\n               )$QQQQ@(   ..\n         _a_a.   ~??^  syDY?Sa,\n       _mW>-<$c       jWmi  imm.\n       ]QQwayQE       4QQmgwmQQ`\n        ?WWQWP'       -9QQQQQ@'._aas,\n _a%is.        .adYYs,. -\"?!` aQB*~^3$c\n_Qh;.nm       .QWc. {QL      ]QQp;..vmQ/\n\"QQmmQ@       -QQQggmQP      ]QQWmggmQQ(\n -???\"         \"$WQQQY`  __,  ?QQQQQQW!\n        _yZ!?q,   -   .yWY!!Sw, \"???^\n       .QQa_=qQ       mQm>..vmm\n        $QQWQQP       $QQQgmQQ@\n         \"???\"   _aa, -9WWQQWY`\n               _mB>~)$a  -~~\n               mQms_vmQ.\n               ]WQQQQQP\n                -?T??\"\n\x1b[0m",
+        raw_art: "${c1}                _aass,\n               jQh: =$w\n               QWmwawQW\n               )$QQQQ@(   ..\n         _a_a.   ~??^  syDY?Sa,\n       _mW>-<$c       jWmi  imm.\n       ]QQwayQE       4QQmgwmQQ`\n        ?WWQWP'       -9QQQQQ@'._aas,\n _a%is.        .adYYs,. -\"?!` aQB*~^3$c\n_Qh;.nm       .QWc. {QL      ]QQp;..vmQ/\n\"QQmmQ@       -QQQggmQP      ]QQWmggmQQ(\n -???\"         \"$WQQQY`  __,  ?QQQQQQW!\n        _yZ!?q,   -   .yWY!!Sw, \"???^\n       .QQa_=qQ       mQm>..vmm\n        $QQWQQP       $QQQgmQQ@\n         \"???\"   _aa, -9WWQQWY`\n               _mB>~)$a  -~~\n               mQms_vmQ.\n               ]WQQQQQP\n                -?T??\"\n",
+        colors: &[Color::Palette(4), Color::Palette(4)],
         max_line_length: 40,
     },
     Logo {
         name: "SkiffOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m\n             ,@@@@@@@@@@@w,_\n  \x1b[1;37m====~~~,,.\x1b[1;37mA@@@@@@@@@@@@@@@@@W,_\n  \x1b[1;34m`||||||||||||||L{\x1b[1;37m\"@$@@@@@@@@B\"\n   \x1b[1;34m`|||||||||||||||||||||L{\x1b[1;37m\"$D\n     \x1b[1;37m@@@@@@@@@@@@@@@@@@@@@\x1b[1;34m_||||}==,\n      \x1b[1;37m*@@@@@@@@@@@@@@@@@@@@@@@@@p\x1b[1;34m||||==,\n        \x1b[1;34m`'||LLL{{\"\"\x1b[1;37m@$B@@@@@@@@@@@@@@@p\x1b[1;34m||\n            \x1b[1;34m`~=|||||||||||L\"\x1b[1;37m$@@@@@@@@@@@\n                   \x1b[1;34m````'\"\"\"\"\"\"\"\x1b[1;37m'\"\"\"\"\"\"\"\"\n\x1b[0m",
+        raw_art: "${c2}\n             ,@@@@@@@@@@@w,_\n  ${c2}====~~~,,.${c2}A@@@@@@@@@@@@@@@@@W,_\n  ${c1}`||||||||||||||L{${c2}\"@$@@@@@@@@B\"\n   ${c1}`|||||||||||||||||||||L{${c2}\"$D\n     ${c2}@@@@@@@@@@@@@@@@@@@@@${c1}_||||}==,\n      ${c2}*@@@@@@@@@@@@@@@@@@@@@@@@@p${c1}||||==,\n        ${c1}`'||LLL{{\"\"${c2}@$B@@@@@@@@@@@@@@@p${c1}||\n            ${c1}`~=|||||||||||L\"${c2}$@@@@@@@@@@@\n                   ${c1}````'\"\"\"\"\"\"\"${c2}'\"\"\"\"\"\"\"\"\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Slackware",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                  :::::::\n            :::::::::::::::::::\n         :::::::::::::::::::::::::\n       ::::::::\x1b[1;37mcllcccccllllllll\x1b[1;34m::::::\n    :::::::::\x1b[1;37mlc               dc\x1b[1;34m:::::::\n   ::::::::\x1b[1;37mcl   clllccllll    oc\x1b[1;34m:::::::::\n  :::::::::\x1b[1;37mo   lc\x1b[1;34m::::::::\x1b[1;37mco   oc\x1b[1;34m::::::::::\n ::::::::::\x1b[1;37mo    cccclc\x1b[1;34m:::::\x1b[1;37mclcc\x1b[1;34m::::::::::::\n :::::::::::\x1b[1;37mlc        cclccclc\x1b[1;34m:::::::::::::\n::::::::::::::\x1b[1;37mlcclcc          lc\x1b[1;34m::::::::::::\n::::::::::\x1b[1;37mcclcc\x1b[1;34m:::::\x1b[1;37mlccclc     oc\x1b[1;34m:::::::::::\n::::::::::\x1b[1;37mo    l\x1b[1;34m::::::::::\x1b[1;37ml    lc\x1b[1;34m:::::::::::\n :::::\x1b[1;37mcll\x1b[1;34m:\x1b[1;37mo     clcllcccll     o\x1b[1;34m:::::::::::\n :::::\x1b[1;37mocc\x1b[1;34m:\x1b[1;37mo                  clc\x1b[1;34m:::::::::::\n  ::::\x1b[1;37mocl\x1b[1;34m:\x1b[1;37mccslclccclclccclclc\x1b[1;34m:::::::::::::\n   :::\x1b[1;37moclcccccccccccccllllllllllllll\x1b[1;34m:::::\n    ::\x1b[1;37mlcc1lcccccccccccccccccccccccco\x1b[1;34m::::\n      ::::::::::::::::::::::::::::::::\n        ::::::::::::::::::::::::::::\n           ::::::::::::::::::::::\n                ::::::::::::\n\x1b[0m",
+        raw_art: "${c1}                  :::::::\n            :::::::::::::::::::\n         :::::::::::::::::::::::::\n       ::::::::${c2}cllcccccllllllll${c1}::::::\n    :::::::::${c2}lc               dc${c1}:::::::\n   ::::::::${c2}cl   clllccllll    oc${c1}:::::::::\n  :::::::::${c2}o   lc${c1}::::::::${c2}co   oc${c1}::::::::::\n ::::::::::${c2}o    cccclc${c1}:::::${c2}clcc${c1}::::::::::::\n :::::::::::${c2}lc        cclccclc${c1}:::::::::::::\n::::::::::::::${c2}lcclcc          lc${c1}::::::::::::\n::::::::::${c2}cclcc${c1}:::::${c2}lccclc     oc${c1}:::::::::::\n::::::::::${c2}o    l${c1}::::::::::${c2}l    lc${c1}:::::::::::\n :::::${c2}cll${c1}:${c2}o     clcllcccll     o${c1}:::::::::::\n :::::${c2}occ${c1}:${c2}o                  clc${c1}:::::::::::\n  ::::${c2}ocl${c1}:${c2}ccslclccclclccclclc${c1}:::::::::::::\n   :::${c2}oclcccccccccccccllllllllllllll${c1}:::::\n    ::${c2}lcc1lcccccccccccccccccccccccco${c1}::::\n      ::::::::::::::::::::::::::::::::\n        ::::::::::::::::::::::::::::\n           ::::::::::::::::::::::\n                ::::::::::::\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 44,
     },
     Logo {
         name: "SliTaz",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m        @    @(               @\n      @@   @@                  @    @/\n     @@   @@                   @@   @@\n    @@  %@@                     @@   @@\n   @@  %@@@       @@@@@.       @@@@  @@\n  @@@    @@@@    @@@@@@@    &@@@    @@@\n   @@@@@@@ %@@@@@@@@@@@@ &@@@% @@@@@@@/\n       ,@@@@@@@@@@@@@@@@@@@@@@@@@\n  .@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@/\n@@@@@@.  @@@@@@@@@@@@@@@@@@@@@  /@@@@@@\n@@    @@@@@  @@@@@@@@@@@@,  @@@@@   @@@\n@@ @@@@.    @@@@@@@@@@@@@%    #@@@@ @@.\n@@ ,@@      @@@@@@@@@@@@@      @@@  @@\n@   @@.     @@@@@@@@@@@@@     @@@  *@\n@    @@     @@@@@@@@@@@@      @@   @\n      @      @@@@@@@@@.     #@\n       @      ,@@@@@       @\n\x1b[0m",
+        raw_art: "${c1}        @    @(               @\n      @@   @@                  @    @/\n     @@   @@                   @@   @@\n    @@  %@@                     @@   @@\n   @@  %@@@       @@@@@.       @@@@  @@\n  @@@    @@@@    @@@@@@@    &@@@    @@@\n   @@@@@@@ %@@@@@@@@@@@@ &@@@% @@@@@@@/\n       ,@@@@@@@@@@@@@@@@@@@@@@@@@\n  .@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@/\n@@@@@@.  @@@@@@@@@@@@@@@@@@@@@  /@@@@@@\n@@    @@@@@  @@@@@@@@@@@@,  @@@@@   @@@\n@@ @@@@.    @@@@@@@@@@@@@%    #@@@@ @@.\n@@ ,@@      @@@@@@@@@@@@@      @@@  @@\n@   @@.     @@@@@@@@@@@@@     @@@  *@\n@    @@     @@@@@@@@@@@@      @@   @\n      @      @@@@@@@@@.     #@\n       @      ,@@@@@       @\n",
+        colors: &[Color::Palette(3), Color::Palette(3)],
         max_line_length: 39,
     },
     Logo {
         name: "SmartOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;36myyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyys             oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyy                         syyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  syyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  oyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  oyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  syyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy              yyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\n\x1b[0m",
+        raw_art: "${c1}yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyys             oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyy  oyyyyyyyyyyyyyyyy\nyyyys  yyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyy                         syyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  syyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  oyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  oyyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy  syyyyyyyyy  syyyy\nyyyyyyyyyyyyyyyy              yyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\nyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "Solaris",
         is_wildcard: false,
         ascii_art: "\x1b[1;33m                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n\x1b[0m",
+        raw_art: "${c1}                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 36,
     },
     Logo {
         name: "Solus",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m            -```````````\n          `-+/------------.`\n       .---:mNo---------------.\n     .-----yMMMy:---------------.\n   `------oMMMMMm/----------------`\n  .------/MMMMMMMN+----------------.\n .------/NMMMMMMMMm-+/--------------.\n`------/NMMMMMMMMMN-:mh/-------------`\n.-----/NMMMMMMMMMMM:-+MMd//oso/:-----.\n-----/NMMMMMMMMMMMM+--mMMMh::smMmyo:--\n----+NMMMMMMMMMMMMMo--yMMMMNo-:yMMMMd/.\n.--oMMMMMMMMMMMMMMMy--yMMMMMMh:-yMMMy-`\n`-sMMMMMMMMMMMMMMMMh--dMMMMMMMd:/Ny+y.\n`-/+osyhhdmmNNMMMMMm-/MMMMMMMmh+/ohm+\n  .------------:://+-/++++++\x1b[1;34moshddys:\n   -hhhhyyyyyyyyyyyhhhhddddhysssso-\n    `:ossssssyysssssssssssssssso:`\n      `:+ssssssssssssssssssss+-\n         `-/+ssssssssssso+/-`\n              `.-----..`\n\x1b[0m",
+        raw_art: "${c2}            -```````````\n          `-+/------------.`\n       .---:mNo---------------.\n     .-----yMMMy:---------------.\n   `------oMMMMMm/----------------`\n  .------/MMMMMMMN+----------------.\n .------/NMMMMMMMMm-+/--------------.\n`------/NMMMMMMMMMN-:mh/-------------`\n.-----/NMMMMMMMMMMM:-+MMd//oso/:-----.\n-----/NMMMMMMMMMMMM+--mMMMh::smMmyo:--\n----+NMMMMMMMMMMMMMo--yMMMMNo-:yMMMMd/.\n.--oMMMMMMMMMMMMMMMy--yMMMMMMh:-yMMMy-`\n`-sMMMMMMMMMMMMMMMMh--dMMMMMMMd:/Ny+y.\n`-/+osyhhdmmNNMMMMMm-/MMMMMMMmh+/ohm+\n  .------------:://+-/++++++${c1}oshddys:\n   -hhhhyyyyyyyyyyyhhhhddddhysssso-\n    `:ossssssyysssssssssssssssso:`\n      `:+ssssssssssssssssssss+-\n         `-/+ssssssssssso+/-`\n              `.-----..`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 39,
     },
+    Logo {
+        name: "Source Mage",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m       :ymNMNho.\n.+sdmNMMMMMMMMMMy`\n.-::/yMMMMMMMMMMMm-\n      sMMMMMMMMMMMm/\n     /NMMMMMMMMMMMMMm:\n    .MMMMMMMMMMMMMMMMM:\n    `MMMMMMMMMMMMMMMMMN.\n     NMMMMMMMMMMMMMMMMMd\n     mMMMMMMMMMMMMMMMMMMo\n     hhMMMMMMMMMMMMMMMMMM.\n     .`/MMMMMMMMMMMMMMMMMs\n        :mMMMMMMMMMMMMMMMN`\n         `sMMMMMMMMMMMMMMM+\n           /NMMMMMMMMMMMMMN`\n             oMMMMMMMMMMMMM+\n          ./sd.-hMMMMMMMMmmN`\n      ./+oyyyh- `MMMMMMMMMmNh\n                 sMMMMMMMMMmmo\n                 `NMMMMMMMMMd:\n                  -dMMMMMMMMMo\n                    -shmNMMms.\n\x1b[0m",
+        raw_art: "${c2}       :ymNMNho.\n.+sdmNMMMMMMMMMMy`\n.-::/yMMMMMMMMMMMm-\n      sMMMMMMMMMMMm/\n     /NMMMMMMMMMMMMMm:\n    .MMMMMMMMMMMMMMMMM:\n    `MMMMMMMMMMMMMMMMMN.\n     NMMMMMMMMMMMMMMMMMd\n     mMMMMMMMMMMMMMMMMMMo\n     hhMMMMMMMMMMMMMMMMMM.\n     .`/MMMMMMMMMMMMMMMMMs\n        :mMMMMMMMMMMMMMMMN`\n         `sMMMMMMMMMMMMMMM+\n           /NMMMMMMMMMMMMMN`\n             oMMMMMMMMMMMMM+\n          ./sd.-hMMMMMMMMmmN`\n      ./+oyyyh- `MMMMMMMMMmNh\n                 sMMMMMMMMMmmo\n                 `NMMMMMMMMMd:\n                  -dMMMMMMMMMo\n                    -shmNMMms.\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 30,
+    },
     Logo {
         name: "Source_Mage",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m       :ymNMNho.\n.+sdmNMMMMMMMMMMy`\n.-::/yMMMMMMMMMMMm-\n      sMMMMMMMMMMMm/\n     /NMMMMMMMMMMMMMm:\n    .MMMMMMMMMMMMMMMMM:\n    `MMMMMMMMMMMMMMMMMN.\n     NMMMMMMMMMMMMMMMMMd\n     mMMMMMMMMMMMMMMMMMMo\n     hhMMMMMMMMMMMMMMMMMM.\n     .`/MMMMMMMMMMMMMMMMMs\n        :mMMMMMMMMMMMMMMMN`\n         `sMMMMMMMMMMMMMMM+\n           /NMMMMMMMMMMMMMN`\n             oMMMMMMMMMMMMM+\n          ./sd.-hMMMMMMMMmmN`\n      ./+oyyyh- `MMMMMMMMMmNh\n                 sMMMMMMMMMmmo\n                 `NMMMMMMMMMd:\n                  -dMMMMMMMMMo\n                    -shmNMMms.\n\x1b[0m",
+        raw_art: "${c2}       :ymNMNho.\n.+sdmNMMMMMMMMMMy`\n.-::/yMMMMMMMMMMMm-\n      sMMMMMMMMMMMm/\n     /NMMMMMMMMMMMMMm:\n    .MMMMMMMMMMMMMMMMM:\n    `MMMMMMMMMMMMMMMMMN.\n     NMMMMMMMMMMMMMMMMMd\n     mMMMMMMMMMMMMMMMMMMo\n     hhMMMMMMMMMMMMMMMMMM.\n     .`/MMMMMMMMMMMMMMMMMs\n        :mMMMMMMMMMMMMMMMN`\n         `sMMMMMMMMMMMMMMM+\n           /NMMMMMMMMMMMMMN`\n             oMMMMMMMMMMMMM+\n          ./sd.-hMMMMMMMMmmN`\n      ./+oyyyh- `MMMMMMMMMmNh\n                 sMMMMMMMMMmmo\n                 `NMMMMMMMMMd:\n                  -dMMMMMMMMMo\n                    -shmNMMms.\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 30,
     },
     Logo {
         name: "Sparky",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m\n           .            `-:-`\n          .o`       .-///-`\n         `oo`    .:/++:.\n         os+`  -/+++:` ``.........```\n        /ys+`./+++/-.-::::::----......``\n       `syyo`++o+--::::-::/+++/-``\n       -yyy+.+o+`:/:-:sdmmmmmmmmdy+-`\n::-`   :yyy/-oo.-+/`ymho++++++oyhdmdy/`\n`/yy+-`.syyo`+o..o--h..osyhhddhs+//osyy/`\n  -ydhs+-oyy/.+o.-: ` `  :/::+ydhy+```-os-\n   .sdddy::syo--/:.     `.:dy+-ohhho    ./:\n     :yddds/:+oo+//:-`- /+ +hy+.shhy:     ``\n      `:ydmmdysooooooo-.ss`/yss--oyyo\n        `./ossyyyyo+:-/oo:.osso- .oys\n       ``..-------::////.-oooo/   :so\n    `...----::::::::--.`/oooo:    .o:\n           ```````     ++o+:`     `:`\n                     ./+/-`        `\n                   `-:-.\n                   ``\n\x1b[0m",
+        raw_art: "${c1}\n           .            `-:-`\n          .o`       .-///-`\n         `oo`    .:/++:.\n         os+`  -/+++:` ``.........```\n        /ys+`./+++/-.-::::::----......``\n       `syyo`++o+--::::-::/+++/-``\n       -yyy+.+o+`:/:-:sdmmmmmmmmdy+-`\n::-`   :yyy/-oo.-+/`ymho++++++oyhdmdy/`\n`/yy+-`.syyo`+o..o--h..osyhhddhs+//osyy/`\n  -ydhs+-oyy/.+o.-: ` `  :/::+ydhy+```-os-\n   .sdddy::syo--/:.     `.:dy+-ohhho    ./:\n     :yddds/:+oo+//:-`- /+ +hy+.shhy:     ``\n      `:ydmmdysooooooo-.ss`/yss--oyyo\n        `./ossyyyyo+:-/oo:.osso- .oys\n       ``..-------::////.-oooo/   :so\n    `...----::::::::--.`/oooo:    .o:\n           ```````     ++o+:`     `:`\n                     ./+/-`        `\n                   `-:-.\n                   ``\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
         max_line_length: 44,
     },
     Logo {
         name: "Star",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                   ./\n                  `yy-\n                 `y.`y`\n    ``           s-  .y            `\n    +h//:..`    +/    /o    ``..:/so\n     /o``.-::/:/+      o/://::-.`+o`\n      :s`     `.        .`     `s/\n       .y.                    .s-\n        `y-                  :s`\n      .-//.                  /+:.\n   .:/:.                       .:/:.\n-+o:.                             .:+:.\n-///++///:::`              .-::::///+so-\n       ``..o/              d-....```\n           s.     `/.      d\n           h    .+o-+o-    h.\n           h  -o/`   `/o:  s:\n          -s/o:`       `:o/+/\n          /s-             -yo\n\x1b[0m",
+        raw_art: "${c1}                   ./\n                  `yy-\n                 `y.`y`\n    ``           s-  .y            `\n    +h//:..`    +/    /o    ``..:/so\n     /o``.-::/:/+      o/://::-.`+o`\n      :s`     `.        .`     `s/\n       .y.                    .s-\n        `y-                  :s`\n      .-//.                  /+:.\n   .:/:.                       .:/:.\n-+o:.                             .:+:.\n-///++///:::`              .-::::///+so-\n       ``..o/              d-....```\n           s.     `/.      d\n           h    .+o-+o-    h.\n           h  -o/`   `/o:  s:\n          -s/o:`       `:o/+/\n          /s-             -yo\n",
+        colors: &[Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "SteamOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m              .,,,,.\n        .,'onNMMMMMNNnn',.\n     .'oNMANKMMMMMMMMMMMNNn'.\n   .'ANMMMMMMMXKNNWWWPFFWNNMNn.\n  ;NNMMMMMMMMMMNWW'' ,.., 'WMMM,\n ;NMMMMV+##+VNWWW' .+;'':+, 'WMW,\n,VNNWP+\x1b[1;37m######\x1b[1;35m+WW,  \x1b[1;37m+:    \x1b[1;35m:+, +MMM,\n'\x1b[1;37m+#############,   +.    ,+' \x1b[1;35m+NMMM\n\x1b[1;37m  '*#########*'     '*,,*' \x1b[1;35m.+NMMMM.\n\x1b[1;37m     `'*###*'          ,.,;###\x1b[1;35m+WNM,\n\x1b[1;37m         .,;;,      .;##########\x1b[1;35m+W\n\x1b[1;37m,',.         ';  ,+##############'\n '###+. :,. .,; ,###############'\n  '####.. `'' .,###############'\n    '#####+++################'\n      '*##################*'\n         ''*##########*''\n              ''''''\n\x1b[0m",
+        raw_art: "${c1}              .,,,,.\n        .,'onNMMMMMNNnn',.\n     .'oNMANKMMMMMMMMMMMNNn'.\n   .'ANMMMMMMMXKNNWWWPFFWNNMNn.\n  ;NNMMMMMMMMMMNWW'' ,.., 'WMMM,\n ;NMMMMV+##+VNWWW' .+;'':+, 'WMW,\n,VNNWP+${c2}######${c1}+WW,  ${c2}+:    ${c1}:+, +MMM,\n'${c2}+#############,   +.    ,+' ${c1}+NMMM\n${c2}  '*#########*'     '*,,*' ${c1}.+NMMMM.\n${c2}     `'*###*'          ,.,;###${c1}+WNM,\n${c2}         .,;;,      .;##########${c1}+W\n${c2},',.         ';  ,+##############'\n '###+. :,. .,; ,###############'\n  '####.. `'' .,###############'\n    '#####+++################'\n      '*##################*'\n         ''*##########*''\n              ''''''\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "SunOS",
         is_wildcard: false,
         ascii_art: "\x1b[1;33m                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n\x1b[0m",
+        raw_art: "${c1}                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
+        max_line_length: 36,
+    },
+    Logo {
+        name: "SunOS",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;33m                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n\x1b[0m",
+        raw_art: "${c1}                 `-     `\n          `--    `+-    .:\n           .+:  `++:  -/+-     .\n    `.::`  -++/``:::`./+/  `.-/.\n      `++/-`.`          ` /++:`\n  ``   ./:`                .: `..`.-\n``./+/:-                     -+++:-\n    -/+`                      :.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 36,
     },
     Logo {
         name: "SwagArch",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m        .;ldkOKXXNNNNXXK0Oxoc,.\n   ,lkXMMNK0OkkxkkOKWMMMMMMMMMM;\n 'K0xo  ..,;:c:.     `'lKMMMMM0\n     .lONMMMMMM'         `lNMk'\n\x1b[1;37m    ;WMMMMMMMMMO.              \x1b[1;34m....::...\n\x1b[1;37m    OMMMMMMMMMMMMKl.       \x1b[1;34m.,;;;;;ccccccc,\n\x1b[1;37m    `0MMMMMMMMMMMMMM0:         \x1b[1;34m.. .ccccccc.\n\x1b[1;37m      'kWMMMMMMMMMMMMMNo.   \x1b[1;34m.,:'  .ccccccc.\n\x1b[1;37m        `c0MMMMMMMMMMMMMN,\x1b[1;34m,:c;    :cccccc:\n\x1b[1;37m ckl.      `lXMMMMMMMMMX\x1b[1;34mocccc:.. ;ccccccc.\n\x1b[1;37mdMMMMXd,     `OMMMMMMWk\x1b[1;34mccc;:''` ,ccccccc:\n\x1b[1;37mXMMMMMMMWKkxxOWMMMMMNo\x1b[1;34mccc;     .cccccccc.\n\x1b[1;37m `':ldxO0KXXXXXK0Okdo\x1b[1;34mcccc.     :cccccccc.\n                    :ccc:'     `cccccccc:,\n                                   ''\n\x1b[0m",
+        raw_art: "${c2}        .;ldkOKXXNNNNXXK0Oxoc,.\n   ,lkXMMNK0OkkxkkOKWMMMMMMMMMM;\n 'K0xo  ..,;:c:.     `'lKMMMMM0\n     .lONMMMMMM'         `lNMk'\n${c2}    ;WMMMMMMMMMO.              ${c1}....::...\n${c2}    OMMMMMMMMMMMMKl.       ${c1}.,;;;;;ccccccc,\n${c2}    `0MMMMMMMMMMMMMM0:         ${c1}.. .ccccccc.\n${c2}      'kWMMMMMMMMMMMMMNo.   ${c1}.,:'  .ccccccc.\n${c2}        `c0MMMMMMMMMMMMMN,${c1},:c;    :cccccc:\n${c2} ckl.      `lXMMMMMMMMMX${c1}occcc:.. ;ccccccc.\n${c2}dMMMMXd,     `OMMMMMMWk${c1}ccc;:''` ,ccccccc:\n${c2}XMMMMMMMWKkxxOWMMMMMNo${c1}ccc;     .cccccccc.\n${c2} `':ldxO0KXXXXXK0Okdo${c1}cccc.     :cccccccc.\n                    :ccc:'     `cccccccc:,\n                                   ''\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 43,
     },
     Logo {
         name: "Tails",
         is_wildcard: true,
-        ascii_art: "\x1b[1;35m      ``\n  ./yhNh\nsyy/Nshh         `:o/\nN:dsNshh  \u{00e2}\u{0096}\u{0088}   `ohNMMd\nN-/+Nshh      `yMMMMd\nN-yhMshh       yMMMMd\nN-s:hshh  \u{00e2}\u{0096}\u{0088}    yMMMMd so//.\nN-oyNsyh       yMMMMd d  Mms.\nN:hohhhd:.     yMMMMd  syMMM+\nNsyh+-..+y+-   yMMMMd   :mMM+\n+hy-      -ss/`yMMMM     `+d+\n  :sy/.     ./yNMMMMm      ``\n    .+ys- `:+hNMMMMMMy/`\n      `hNmmMMMMMMMMMMMMdo.\n       dMMMMMMMMMMMMMMMMMNh:\n       +hMMMMMMMMMMMMMMMMMmy.\n         -oNMMMMMMMMMMmy+.`\n           `:yNMMMds/.`\n              .//`\n\x1b[0m",
+        ascii_art: "\x1b[1;35m      ``\n  ./yhNh\nsyy/Nshh         `:o/\nN:dsNshh  \u{2588}   `ohNMMd\nN-/+Nshh      `yMMMMd\nN-yhMshh       yMMMMd\nN-s:hshh  \u{2588}    yMMMMd so//.\nN-oyNsyh       yMMMMd d  Mms.\nN:hohhhd:.     yMMMMd  syMMM+\nNsyh+-..+y+-   yMMMMd   :mMM+\n+hy-      -ss/`yMMMM     `+d+\n  :sy/.     ./yNMMMMm      ``\n    .+ys- `:+hNMMMMMMy/`\n      `hNmmMMMMMMMMMMMMdo.\n       dMMMMMMMMMMMMMMMMMNh:\n       +hMMMMMMMMMMMMMMMMMmy.\n         -oNMMMMMMMMMMmy+.`\n           `:yNMMMds/.`\n              .//`\n\x1b[0m",
+        raw_art: "${c1}      ``\n  ./yhNh\nsyy/Nshh         `:o/\nN:dsNshh  \u{2588}   `ohNMMd\nN-/+Nshh      `yMMMMd\nN-yhMshh       yMMMMd\nN-s:hshh  \u{2588}    yMMMMd so//.\nN-oyNsyh       yMMMMd d  Mms.\nN:hohhhd:.     yMMMMd  syMMM+\nNsyh+-..+y+-   yMMMMd   :mMM+\n+hy-      -ss/`yMMMM     `+d+\n  :sy/.     ./yNMMMMm      ``\n    .+ys- `:+hNMMMMMMy/`\n      `hNmmMMMMMMMMMMMMdo.\n       dMMMMMMMMMMMMMMMMMNh:\n       +hMMMMMMMMMMMMMMMMMmy.\n         -oNMMMMMMMMMMmy+.`\n           `:yNMMMds/.`\n              .//`\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 29,
     },
     Logo {
         name: "TeArch",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;39m          @@@@@@@@@@@@@@\n      @@@@@@@@@              @@@@@@\n     @@@@@                     @@@@@\n     @@                           @@\n      @%                         @@\n       @                         @\n       @@@@@@@@@@@@@@@@@@@@@@@@ @@\n       .@@@@@@@@@@@@/@@@@@@@@@@@@\n       @@@@@@@@@@@@///@@@@@@@@@@@@\n      @@@@@@@@@@@@@((((@@@@@@@@@@@@\n     @@@@@@@@@@@#(((((((#@@@@@@@@@@@\n    @@@@@@@@@@@#//////////@@@@@@@@@@&\n    @@@@@@@@@@////@@@@@////@@@@@@@@@@\n    @@@@@@@@//////@@@@@/////@@@@@@@@@\n    @@@@@@@//@@@@@@@@@@@@@@@//@@@@@@@\n @@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n@@     .@@@@@@@@@@@@@@@@@@@@@@@@@      @\n @@@@@@           @@@.           @@@@@@@\n   @@@@@@@&@@@@@@@#  #@@@@@@@@@@@@@@@@\n      @@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n          @@@@@@@@@@@@@@@@@@@@@\n\x1b[0m",
+        raw_art: "${c1}          @@@@@@@@@@@@@@\n      @@@@@@@@@              @@@@@@\n     @@@@@                     @@@@@\n     @@                           @@\n      @%                         @@\n       @                         @\n       @@@@@@@@@@@@@@@@@@@@@@@@ @@\n       .@@@@@@@@@@@@/@@@@@@@@@@@@\n       @@@@@@@@@@@@///@@@@@@@@@@@@\n      @@@@@@@@@@@@@((((@@@@@@@@@@@@\n     @@@@@@@@@@@#(((((((#@@@@@@@@@@@\n    @@@@@@@@@@@#//////////@@@@@@@@@@&\n    @@@@@@@@@@////@@@@@////@@@@@@@@@@\n    @@@@@@@@//////@@@@@/////@@@@@@@@@\n    @@@@@@@//@@@@@@@@@@@@@@@//@@@@@@@\n @@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n@@     .@@@@@@@@@@@@@@@@@@@@@@@@@      @\n @@@@@@           @@@.           @@@@@@@\n   @@@@@@@&@@@@@@@#  #@@@@@@@@@@@@@@@@\n      @@@@@@@@@@@@@@@@@@@@@@@@@@@@@\n          @@@@@@@@@@@@@@@@@@@@@\n",
+        colors: &[Color::Palette(39), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Trisquel",
         is_wildcard: true,
-        ascii_art: "\x1b[1;34m                         \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\n                      \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}\n      \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}         \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}     \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084} \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}     \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}    \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}     \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}      \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}  \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\x1b[1;34m \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\x1b[1;36m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\n\x1b[1;34m  \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}    \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\x1b[1;36m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\n    \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}     \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\n               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\n              \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n              \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}  \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n               \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}   \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n                \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}   \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}  \u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n                  \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0084}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n                     \u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0080}\u{00e2}\u{0096}\u{0080}\n\x1b[0m",
+        ascii_art: "\x1b[1;34m                         \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\n                      \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\n      \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}         \u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\n   \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}     \u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2584}\u{2584} \u{2580}\u{2588}\u{2588}\u{2588}\n \u{2584}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}   \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2588}     \u{2588}\u{2588}\u{2588}\u{2584}   \u{2584}\u{2588}   \u{2588}\u{2588}\u{2588}\n\u{2584}\u{2588}\u{2588}\u{2588}   \u{2584}\u{2584}\u{2584}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2584}    \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2584}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}   \u{2588}\u{2580}\u{2580}\u{2588}\u{2588}\u{2584}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}     \u{2580}\u{2580}   \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2580}\u{2588}\u{2588}\u{2588}      \u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\u{2584}  \u{2584}\u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\x1b[1;34m \u{2580}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2584}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\x1b[1;36m\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\n\x1b[1;34m  \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}    \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\x1b[1;36m\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\u{2580}\n    \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}     \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\n               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2584}\u{2584}\u{2584}\u{2584}\n              \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n              \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2580}  \u{2580}\u{2588}\u{2588}\u{2588}\n               \u{2588}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2588}\u{2588}\u{2584}\u{2584}\u{2584}  \u{2588}\u{2588}\u{2588}\n                \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2580}\u{2580}  \u{2584}\u{2588}\u{2588}\n                  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\u{2584}\u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\n                     \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\n\x1b[0m",
+        raw_art: "${c1}                         \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\n                      \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\n      \u{2584}\u{2584}\u{2584}\u{2584}\u{2584}\u{2584}         \u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\n   \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}     \u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2584}\u{2584} \u{2580}\u{2588}\u{2588}\u{2588}\n \u{2584}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}   \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2588}     \u{2588}\u{2588}\u{2588}\u{2584}   \u{2584}\u{2588}   \u{2588}\u{2588}\u{2588}\n\u{2584}\u{2588}\u{2588}\u{2588}   \u{2584}\u{2584}\u{2584}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2584}    \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2584}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}   \u{2588}\u{2580}\u{2580}\u{2588}\u{2588}\u{2584}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}     \u{2580}\u{2580}   \u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2580}\u{2588}\u{2588}\u{2588}      \u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\u{2584}  \u{2584}\u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n${c1} \u{2580}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2584}\u{2588}\u{2588}\u{2588}  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}${c2}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\n${c1}  \u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}    \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}${c2}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\u{2580}\n    \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}     \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\n               \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2584}\u{2584}\u{2584}\u{2584}\n              \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}   \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n              \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}   \u{2588}\u{2588}\u{2588}\u{2580}  \u{2580}\u{2588}\u{2588}\u{2588}\n               \u{2588}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2588}\u{2588}\u{2584}\u{2584}\u{2584}  \u{2588}\u{2588}\u{2588}\n                \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}   \u{2580}\u{2580}  \u{2584}\u{2588}\u{2588}\n                  \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2584}\u{2584}\u{2584}\u{2588}\u{2588}\u{2588}\u{2588}\n                     \u{2580}\u{2580}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2580}\u{2580}\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 36,
     },
     Logo {
         name: "TrueOS",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m                       ..\n                        s.\n                        +y\n                        yN\n                       -MN  `.\n                      :NMs `m\n                    .yMMm` `No\n            `-/+++sdMMMNs+-`+Ms\n        `:oo+-` .yMMMMy` `-+oNMh\n      -oo-     +NMMMM/       oMMh-\n    .s+` `    oMMMMM/     -  oMMMhy.\n   +s`- ::   :MMMMMd     -o `mMMMy`s+\n  y+  h .Ny+oNMMMMMN/    sh+NMMMMo  +y\n s+ .ds  -NMMMMMMMMMMNdhdNMMMMMMh`   +s\n-h .NM`   `hMMMMMMMMMMMMMMNMMNy:      h-\ny- hMN`     hMMmMMMMMMMMMNsdMNs.      -y\nm` mMMy`    oMMNoNMMMMMMo`  sMMMo     `m\nm` :NMMMdyydMMMMo+MdMMMs     sMMMd`   `m\nh-  `+ymMMMMMMMM--M+hMMN/    +MMMMy   -h\n:y     `.sMMMMM/ oMM+.yMMNddNMMMMMm   y:\n y:   `s  dMMN- .MMMM/ :MMMMMMMMMMh  :y\n `h:  `mdmMMM/  yMMMMs  sMMMMMMMMN- :h`\n   so  -NMMMN   /mmd+  `dMMMMMMMm- os\n    :y: `yMMM`       `+NMMMMMMNo`:y:\n      /s+`.omy      /NMMMMMNh/.+s:\n        .+oo:-.     /mdhs+::oo+.\n            -/o+++++++++++/-\n\x1b[0m",
+        raw_art: "${c1}                       ..\n                        s.\n                        +y\n                        yN\n                       -MN  `.\n                      :NMs `m\n                    .yMMm` `No\n            `-/+++sdMMMNs+-`+Ms\n        `:oo+-` .yMMMMy` `-+oNMh\n      -oo-     +NMMMM/       oMMh-\n    .s+` `    oMMMMM/     -  oMMMhy.\n   +s`- ::   :MMMMMd     -o `mMMMy`s+\n  y+  h .Ny+oNMMMMMN/    sh+NMMMMo  +y\n s+ .ds  -NMMMMMMMMMMNdhdNMMMMMMh`   +s\n-h .NM`   `hMMMMMMMMMMMMMMNMMNy:      h-\ny- hMN`     hMMmMMMMMMMMMNsdMNs.      -y\nm` mMMy`    oMMNoNMMMMMMo`  sMMMo     `m\nm` :NMMMdyydMMMMo+MdMMMs     sMMMd`   `m\nh-  `+ymMMMMMMMM--M+hMMN/    +MMMMy   -h\n:y     `.sMMMMM/ oMM+.yMMNddNMMMMMm   y:\n y:   `s  dMMN- .MMMM/ :MMMMMMMMMMh  :y\n `h:  `mdmMMM/  yMMMMs  sMMMMMMMMN- :h`\n   so  -NMMMN   /mmd+  `dMMMMMMMm- os\n    :y: `yMMM`       `+NMMMMMMNo`:y:\n      /s+`.omy      /NMMMMMNh/.+s:\n        .+oo:-.     /mdhs+::oo+.\n            -/o+++++++++++/-\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Ubuntu",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m            .-/+oossssoo+\\-.\n        \u{00b4}:+ssssssssssssssssss+:`\n      -+ssssssssssssssssssyyssss+-\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n   /sssssssssss\x1b[1;37mhdmmNNmmyNMMMMh\x1b[1;31mssssss\\\n  +sssssssss\x1b[1;37mhm\x1b[1;31myd\x1b[1;37mMMMMMMMNddddy\x1b[1;31mssssssss+\n /ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhmNMMMNh\x1b[1;31mssssssss\\\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\noss\x1b[1;37myNMMMNyMMh\x1b[1;31mssssssssssssss\x1b[1;37mhmmmh\x1b[1;31mssssssso\noss\x1b[1;37myNMMMNyMMh\x1b[1;31msssssssssssssshmmmh\x1b[1;31mssssssso\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n \\ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhdNMMMNh\x1b[1;31mssssssss/\n  +sssssssss\x1b[1;37mdm\x1b[1;31myd\x1b[1;37mMMMMMMMMddddy\x1b[1;31mssssssss+\n   \\sssssssssss\x1b[1;37mhdmNNNNmyNMMMMh\x1b[1;31mssssss/\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n      -+sssssssssssssssss\x1b[1;37myyy\x1b[1;31mssss+-\n        `:+ssssssssssssssssss+:`\n            .-\\+oossssoo+/-.\n\x1b[0m",
+        raw_art: "${c1}            .-/+oossssoo+\\-.\n        \u{00b4}:+ssssssssssssssssss+:`\n      -+ssssssssssssssssssyyssss+-\n    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.\n   /sssssssssss${c2}hdmmNNmmyNMMMMh${c1}ssssss\\\n  +sssssssss${c2}hm${c1}yd${c2}MMMMMMMNddddy${c1}ssssssss+\n /ssssssss${c2}hNMMM${c1}yh${c2}hyyyyhmNMMMNh${c1}ssssssss\\\n.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.\n+ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+\noss${c2}yNMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssssssso\noss${c2}yNMMMNyMMh${c1}sssssssssssssshmmmh${c1}ssssssso\n+ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+\n.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.\n \\ssssssss${c2}hNMMM${c1}yh${c2}hyyyyhdNMMMNh${c1}ssssssss/\n  +sssssssss${c2}dm${c1}yd${c2}MMMMMMMMddddy${c1}ssssssss+\n   \\sssssssssss${c2}hdmNNNNmyNMMMMh${c1}ssssss/\n    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.\n      -+sssssssssssssssss${c2}yyy${c1}ssss+-\n        `:+ssssssssssssssssss+:`\n            .-\\+oossssoo+/-.\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Ubuntu Budgie",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m           ./oydmMMMMMMmdyo/.\n        :smMMMMMMMMMMMhs+:++yhs:\n     `omMMMMMMMMMMMN+`        `odo`\n    /NMMMMMMMMMMMMN-            `sN/\n  `hMMMMmhhmMMMMMMh               sMh`\n .mMmo-     /yMMMMm`              `MMm.\n mN/       yMMMMMMMd-              MMMm\noN-        oMMMMMMMMMms+//+o+:    :MMMMo\nm/          +NMMMMMMMMMMMMMMMMm. :NMMMMm\nM`           .NMMMMMMMMMMMMMMMNodMMMMMMM\nM-            sMMMMMMMMMMMMMMMMMMMMMMMMM\nmm`           mMMMMMMMMMNdhhdNMMMMMMMMMm\noMm/        .dMMMMMMMMh:      :dMMMMMMMo\n mMMNyo/:/sdMMMMMMMMM+          sMMMMMm\n .mMMMMMMMMMMMMMMMMMs           `NMMMm.\n  `hMMMMMMMMMMM.oo+.            `MMMh`\n    /NMMMMMMMMMo                sMN/\n     `omMMMMMMMMy.            :dmo`\n        :smMMMMMMMh+-`   `.:ohs:\n           ./oydmMMMMMMdhyo/.\n\x1b[0m",
+        raw_art: "${c2}           ./oydmMMMMMMmdyo/.\n        :smMMMMMMMMMMMhs+:++yhs:\n     `omMMMMMMMMMMMN+`        `odo`\n    /NMMMMMMMMMMMMN-            `sN/\n  `hMMMMmhhmMMMMMMh               sMh`\n .mMmo-     /yMMMMm`              `MMm.\n mN/       yMMMMMMMd-              MMMm\noN-        oMMMMMMMMMms+//+o+:    :MMMMo\nm/          +NMMMMMMMMMMMMMMMMm. :NMMMMm\nM`           .NMMMMMMMMMMMMMMMNodMMMMMMM\nM-            sMMMMMMMMMMMMMMMMMMMMMMMMM\nmm`           mMMMMMMMMMNdhhdNMMMMMMMMMm\noMm/        .dMMMMMMMMh:      :dMMMMMMMo\n mMMNyo/:/sdMMMMMMMMM+          sMMMMMm\n .mMMMMMMMMMMMMMMMMMs           `NMMMm.\n  `hMMMMMMMMMMM.oo+.            `MMMh`\n    /NMMMMMMMMMo                sMN/\n     `omMMMMMMMMy.            :dmo`\n        :smMMMMMMMh+-`   `.:ohs:\n           ./oydmMMMMMMdhyo/.\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Ubuntu Cinnamon",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;31m            .-/+oooooooo+/-.\n        `:+oooooooooooooooooo+:`\n      -+oooooooooooooooooooooooo+-\n    .ooooooooooooooooooo\x1b[1;37m:ohNd\x1b[1;31moooooo.\n   /oooooooooooo\x1b[1;37m:/+oo++:/ohNd\x1b[1;31mooooooo/\n  +oooooooooo\x1b[1;37m:osNdhyyhdNNh+:+\x1b[1;31moooooooo+\n /ooooooooo\x1b[1;37m/dN/\x1b[1;31mooooooooo\x1b[1;37m/sNNo\x1b[1;31mooooooooo/\n.ooooooooo\x1b[1;37moMd:\x1b[1;31moooooooooooo\x1b[1;37m:yMy\x1b[1;31mooooooooo.\n+ooooo\x1b[1;37m:+o/Md\x1b[1;31moooooo\x1b[1;37m:sm/\x1b[1;31moo/ooo\x1b[1;37myMo\x1b[1;31moooooooo+\nooo\x1b[1;37m:sdMdosMo\x1b[1;31mooooo\x1b[1;37moNMd\x1b[1;31m//\x1b[1;37mdMd+\x1b[1;31mo\x1b[1;37m:so\x1b[1;31mooooooooo\noooo\x1b[1;37m+ymdosMo\x1b[1;31mooo\x1b[1;37m+mMm\x1b[1;31m+/\x1b[1;37mhMMMMMh+hs\x1b[1;31mooooooooo\n+oooooo\x1b[1;37m:\x1b[1;31m:\x1b[1;37m/Nm:\x1b[1;31m/\x1b[1;37mhMNo\x1b[1;31m:y\x1b[1;37mMMMMMMMMMM+\x1b[1;31moooooooo+\n.ooooooooo\x1b[1;37m/NNMNy\x1b[1;31m:o\x1b[1;37mNMMMMMMMMMMo\x1b[1;31mooooooooo.\n/oooooooooo\x1b[1;37m:yh:\x1b[1;31m+m\x1b[1;37mMMMMMMMMMMd/\x1b[1;31mooooooooo/\n  +oooooooooo\x1b[1;37m+\x1b[1;31m/h\x1b[1;37mmMMMMMMNds//o\x1b[1;31moooooooo+\n   /oooooooooooo\x1b[1;37m+:////:o/ymMd\x1b[1;31mooooooo/\n    .oooooooooooooooooooo\x1b[1;37m/sdh\x1b[1;31moooooo.\n      -+oooooooooooooooooooooooo+-\n        `:+oooooooooooooooooo+:`\n            .-/+oooooooo+/-.\n\x1b[0m",
+        raw_art: "${c1}            .-/+oooooooo+/-.\n        `:+oooooooooooooooooo+:`\n      -+oooooooooooooooooooooooo+-\n    .ooooooooooooooooooo${c2}:ohNd${c1}oooooo.\n   /oooooooooooo${c2}:/+oo++:/ohNd${c1}ooooooo/\n  +oooooooooo${c2}:osNdhyyhdNNh+:+${c1}oooooooo+\n /ooooooooo${c2}/dN/${c1}ooooooooo${c2}/sNNo${c1}ooooooooo/\n.ooooooooo${c2}oMd:${c1}oooooooooooo${c2}:yMy${c1}ooooooooo.\n+ooooo${c2}:+o/Md${c1}oooooo${c2}:sm/${c1}oo/ooo${c2}yMo${c1}oooooooo+\nooo${c2}:sdMdosMo${c1}ooooo${c2}oNMd${c1}//${c2}dMd+${c1}o${c2}:so${c1}ooooooooo\noooo${c2}+ymdosMo${c1}ooo${c2}+mMm${c1}+/${c2}hMMMMMh+hs${c1}ooooooooo\n+oooooo${c2}:${c1}:${c2}/Nm:${c1}/${c2}hMNo${c1}:y${c2}MMMMMMMMMM+${c1}oooooooo+\n.ooooooooo${c2}/NNMNy${c1}:o${c2}NMMMMMMMMMMo${c1}ooooooooo.\n/oooooooooo${c2}:yh:${c1}+m${c2}MMMMMMMMMMd/${c1}ooooooooo/\n  +oooooooooo${c2}+${c1}/h${c2}mMMMMMMNds//o${c1}oooooooo+\n   /oooooooooooo${c2}+:////:o/ymMd${c1}ooooooo/\n    .oooooooooooooooooooo${c2}/sdh${c1}oooooo.\n      -+oooooooooooooooooooooooo+-\n        `:+oooooooooooooooooo+:`\n            .-/+oooooooo+/-.\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Ubuntu MATE",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;32m            .:/+oossssoo+/:.`\n        `:+ssssssssssssssssss+:`\n      -+sssssssssssssss\x1b[1;37my\x1b[1;32mssssssss+-\n    .osssssssssssss\x1b[1;37myy\x1b[1;32mss\x1b[1;37mmMmh\x1b[1;32mssssssso.\n   /sssssssss\x1b[1;37mydmNNNmmd\x1b[1;32ms\x1b[1;37mmMMMMNdy\x1b[1;32msssss/\n `+ssssssss\x1b[1;37mhNNdy\x1b[1;32msssssss\x1b[1;37mmMMMMNdy\x1b[1;32mssssss+`\n +sssssss\x1b[1;37myNNh\x1b[1;32mss\x1b[1;37mhmNNNNm\x1b[1;32ms\x1b[1;37mmMmh\x1b[1;32ms\x1b[1;37mydy\x1b[1;32msssssss+\n-sssss\x1b[1;37my\x1b[1;32mss\x1b[1;37mNm\x1b[1;32mss\x1b[1;37mhNNh\x1b[1;32mssssss\x1b[1;37my\x1b[1;32ms\x1b[1;37mhh\x1b[1;32mss\x1b[1;37mmMy\x1b[1;32msssssss-\n+ssss\x1b[1;37myMNdy\x1b[1;32mss\x1b[1;37mhMd\x1b[1;32mssssssssss\x1b[1;37mhMd\x1b[1;32mss\x1b[1;37mNN\x1b[1;32msssssss+\nsssss\x1b[1;37myMMMMMmh\x1b[1;32msssssssssssss\x1b[1;37mNM\x1b[1;32mss\x1b[1;37mdMy\x1b[1;32msssssss\nsssss\x1b[1;37myMMMMMmhy\x1b[1;32mssssssssssss\x1b[1;37mNM\x1b[1;32mss\x1b[1;37mdMy\x1b[1;32msssssss\n+ssss\x1b[1;37myMNdy\x1b[1;32mss\x1b[1;37mhMd\x1b[1;32mssssssssss\x1b[1;37mhMd\x1b[1;32mss\x1b[1;37mNN\x1b[1;32msssssss+\n-sssss\x1b[1;37my\x1b[1;32mss\x1b[1;37mNm\x1b[1;32mss\x1b[1;37mhNNh\x1b[1;32mssssssss\x1b[1;37mdh\x1b[1;32mss\x1b[1;37mmMy\x1b[1;32msssssss-\n +sssssss\x1b[1;37myNNh\x1b[1;32mss\x1b[1;37mhmNNNNm\x1b[1;32ms\x1b[1;37mmNmh\x1b[1;32ms\x1b[1;37mymy\x1b[1;32msssssss+\n  +ssssssss\x1b[1;37mhNNdy\x1b[1;32msssssss\x1b[1;37mmMMMMmhy\x1b[1;32mssssss+\n   /sssssssss\x1b[1;37mydmNNNNmd\x1b[1;32ms\x1b[1;37mmMMMMNdh\x1b[1;32msssss/\n    .osssssssssssss\x1b[1;37myy\x1b[1;32mss\x1b[1;37mmMmdy\x1b[1;32msssssso.\n      -+sssssssssssssss\x1b[1;37my\x1b[1;32mssssssss+-\n        `:+ssssssssssssssssss+:`\n            .:/+oossssoo+/:.\n\n\x1b[0m",
+        raw_art: "${c1}            .:/+oossssoo+/:.`\n        `:+ssssssssssssssssss+:`\n      -+sssssssssssssss${c2}y${c1}ssssssss+-\n    .osssssssssssss${c2}yy${c1}ss${c2}mMmh${c1}ssssssso.\n   /sssssssss${c2}ydmNNNmmd${c1}s${c2}mMMMMNdy${c1}sssss/\n `+ssssssss${c2}hNNdy${c1}sssssss${c2}mMMMMNdy${c1}ssssss+`\n +sssssss${c2}yNNh${c1}ss${c2}hmNNNNm${c1}s${c2}mMmh${c1}s${c2}ydy${c1}sssssss+\n-sssss${c2}y${c1}ss${c2}Nm${c1}ss${c2}hNNh${c1}ssssss${c2}y${c1}s${c2}hh${c1}ss${c2}mMy${c1}sssssss-\n+ssss${c2}yMNdy${c1}ss${c2}hMd${c1}ssssssssss${c2}hMd${c1}ss${c2}NN${c1}sssssss+\nsssss${c2}yMMMMMmh${c1}sssssssssssss${c2}NM${c1}ss${c2}dMy${c1}sssssss\nsssss${c2}yMMMMMmhy${c1}ssssssssssss${c2}NM${c1}ss${c2}dMy${c1}sssssss\n+ssss${c2}yMNdy${c1}ss${c2}hMd${c1}ssssssssss${c2}hMd${c1}ss${c2}NN${c1}sssssss+\n-sssss${c2}y${c1}ss${c2}Nm${c1}ss${c2}hNNh${c1}ssssssss${c2}dh${c1}ss${c2}mMy${c1}sssssss-\n +sssssss${c2}yNNh${c1}ss${c2}hmNNNNm${c1}s${c2}mNmh${c1}s${c2}ymy${c1}sssssss+\n  +ssssssss${c2}hNNdy${c1}sssssss${c2}mMMMMmhy${c1}ssssss+\n   /sssssssss${c2}ydmNNNNmd${c1}s${c2}mMMMMNdh${c1}sssss/\n    .osssssssssssss${c2}yy${c1}ss${c2}mMmdy${c1}sssssso.\n      -+sssssssssssssss${c2}y${c1}ssssssss+-\n        `:+ssssssssssssssssss+:`\n            .:/+oossssoo+/:.\n\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "Ubuntu Studio",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;36m              ..-::::::-.`\n         `.:+++++++++++\x1b[1;37mooo\x1b[1;36m++:.`\n       ./+++++++++++++\x1b[1;37msMMMNdyo\x1b[1;36m+/.\n     .++++++++++++++++\x1b[1;37moyhmMMMMms\x1b[1;36m++.\n   `/+++++++++\x1b[1;37mosyhddddhys\x1b[1;36m+\x1b[1;37mosdMMMh\x1b[1;36m++/`\n  `+++++++++\x1b[1;37mydMMMMNNNMMMMNds\x1b[1;36m+\x1b[1;37moyyo\x1b[1;36m++++`\n  +++++++++\x1b[1;37mdMMNhso\x1b[1;36m++++\x1b[1;37moydNMMmo\x1b[1;36m++++++++`\n :+\x1b[1;37modmy\x1b[1;36m+++\x1b[1;37mooysoohmNMMNmyoohMMNs\x1b[1;36m+++++++:\n ++\x1b[1;37mdMMm\x1b[1;36m+\x1b[1;37moNMd\x1b[1;36m++\x1b[1;37myMMMmhhmMMNs+yMMNo\x1b[1;36m+++++++\n`++\x1b[1;37mNMMy\x1b[1;36m+\x1b[1;37mhMMd\x1b[1;36m+\x1b[1;37moMMMs\x1b[1;36m++++\x1b[1;37msMMN\x1b[1;36m++\x1b[1;37mNMMs\x1b[1;36m+++++++.\n`++\x1b[1;37mNMMy\x1b[1;36m+\x1b[1;37mhMMd\x1b[1;36m+\x1b[1;37moMMMo\x1b[1;36m++++\x1b[1;37msMMN\x1b[1;36m++\x1b[1;37mmMMs\x1b[1;36m+++++++.\n ++\x1b[1;37mdMMd\x1b[1;36m+\x1b[1;37moNMm\x1b[1;36m++\x1b[1;37myMMNdhhdMMMs\x1b[1;36m+y\x1b[1;37mMMNo\x1b[1;36m+++++++\n :+\x1b[1;37modmy\x1b[1;36m++\x1b[1;37moo\x1b[1;36m+\x1b[1;37mss\x1b[1;36m+\x1b[1;37mohNMMMMmho\x1b[1;36m+\x1b[1;37myMMMs\x1b[1;36m+++++++:\n  +++++++++\x1b[1;37mhMMmhs+ooo+oshNMMms\x1b[1;36m++++++++\n  `++++++++\x1b[1;37moymMMMMNmmNMMMMmy+oys\x1b[1;36m+++++`\n   `/+++++++++\x1b[1;37moyhdmmmmdhso+sdMMMs\x1b[1;36m++/\n     ./+++++++++++++++\x1b[1;37moyhdNMMMms\x1b[1;36m++.\n       ./+++++++++++++\x1b[1;37mhMMMNdyo\x1b[1;36m+/.\n         `.:+++++++++++\x1b[1;37msso\x1b[1;36m++:.\n              ..-::::::-..\n\x1b[0m",
+        raw_art: "${c1}              ..-::::::-.`\n         `.:+++++++++++${c2}ooo${c1}++:.`\n       ./+++++++++++++${c2}sMMMNdyo${c1}+/.\n     .++++++++++++++++${c2}oyhmMMMMms${c1}++.\n   `/+++++++++${c2}osyhddddhys${c1}+${c2}osdMMMh${c1}++/`\n  `+++++++++${c2}ydMMMMNNNMMMMNds${c1}+${c2}oyyo${c1}++++`\n  +++++++++${c2}dMMNhso${c1}++++${c2}oydNMMmo${c1}++++++++`\n :+${c2}odmy${c1}+++${c2}ooysoohmNMMNmyoohMMNs${c1}+++++++:\n ++${c2}dMMm${c1}+${c2}oNMd${c1}++${c2}yMMMmhhmMMNs+yMMNo${c1}+++++++\n`++${c2}NMMy${c1}+${c2}hMMd${c1}+${c2}oMMMs${c1}++++${c2}sMMN${c1}++${c2}NMMs${c1}+++++++.\n`++${c2}NMMy${c1}+${c2}hMMd${c1}+${c2}oMMMo${c1}++++${c2}sMMN${c1}++${c2}mMMs${c1}+++++++.\n ++${c2}dMMd${c1}+${c2}oNMm${c1}++${c2}yMMNdhhdMMMs${c1}+y${c2}MMNo${c1}+++++++\n :+${c2}odmy${c1}++${c2}oo${c1}+${c2}ss${c1}+${c2}ohNMMMMmho${c1}+${c2}yMMMs${c1}+++++++:\n  +++++++++${c2}hMMmhs+ooo+oshNMMms${c1}++++++++\n  `++++++++${c2}oymMMMMNmmNMMMMmy+oys${c1}+++++`\n   `/+++++++++${c2}oyhdmmmmdhso+sdMMMs${c1}++/\n     ./+++++++++++++++${c2}oyhdNMMMms${c1}++.\n       ./+++++++++++++${c2}hMMMNdyo${c1}+/.\n         `.:+++++++++++${c2}sso${c1}++:.\n              ..-::::::-..\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Ubuntu-Budgie",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m           ./oydmMMMMMMmdyo/.\n        :smMMMMMMMMMMMhs+:++yhs:\n     `omMMMMMMMMMMMN+`        `odo`\n    /NMMMMMMMMMMMMN-            `sN/\n  `hMMMMmhhmMMMMMMh               sMh`\n .mMmo-     /yMMMMm`              `MMm.\n mN/       yMMMMMMMd-              MMMm\noN-        oMMMMMMMMMms+//+o+:    :MMMMo\nm/          +NMMMMMMMMMMMMMMMMm. :NMMMMm\nM`           .NMMMMMMMMMMMMMMMNodMMMMMMM\nM-            sMMMMMMMMMMMMMMMMMMMMMMMMM\nmm`           mMMMMMMMMMNdhhdNMMMMMMMMMm\noMm/        .dMMMMMMMMh:      :dMMMMMMMo\n mMMNyo/:/sdMMMMMMMMM+          sMMMMMm\n .mMMMMMMMMMMMMMMMMMs           `NMMMm.\n  `hMMMMMMMMMMM.oo+.            `MMMh`\n    /NMMMMMMMMMo                sMN/\n     `omMMMMMMMMy.            :dmo`\n        :smMMMMMMMh+-`   `.:ohs:\n           ./oydmMMMMMMdhyo/.\n\x1b[0m",
+        raw_art: "${c2}           ./oydmMMMMMMmdyo/.\n        :smMMMMMMMMMMMhs+:++yhs:\n     `omMMMMMMMMMMMN+`        `odo`\n    /NMMMMMMMMMMMMN-            `sN/\n  `hMMMMmhhmMMMMMMh               sMh`\n .mMmo-     /yMMMMm`              `MMm.\n mN/       yMMMMMMMd-              MMMm\noN-        oMMMMMMMMMms+//+o+:    :MMMMo\nm/          +NMMMMMMMMMMMMMMMMm. :NMMMMm\nM`           .NMMMMMMMMMMMMMMMNodMMMMMMM\nM-            sMMMMMMMMMMMMMMMMMMMMMMMMM\nmm`           mMMMMMMMMMNdhhdNMMMMMMMMMm\noMm/        .dMMMMMMMMh:      :dMMMMMMMo\n mMMNyo/:/sdMMMMMMMMM+          sMMMMMm\n .mMMMMMMMMMMMMMMMMMs           `NMMMm.\n  `hMMMMMMMMMMM.oo+.            `MMMh`\n    /NMMMMMMMMMo                sMN/\n     `omMMMMMMMMy.            :dmo`\n        :smMMMMMMMh+-`   `.:ohs:\n           ./oydmMMMMMMdhyo/.\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Ubuntu-Cinnamon",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m            .-/+oooooooo+/-.\n        `:+oooooooooooooooooo+:`\n      -+oooooooooooooooooooooooo+-\n    .ooooooooooooooooooo\x1b[1;37m:ohNd\x1b[1;31moooooo.\n   /oooooooooooo\x1b[1;37m:/+oo++:/ohNd\x1b[1;31mooooooo/\n  +oooooooooo\x1b[1;37m:osNdhyyhdNNh+:+\x1b[1;31moooooooo+\n /ooooooooo\x1b[1;37m/dN/\x1b[1;31mooooooooo\x1b[1;37m/sNNo\x1b[1;31mooooooooo/\n.ooooooooo\x1b[1;37moMd:\x1b[1;31moooooooooooo\x1b[1;37m:yMy\x1b[1;31mooooooooo.\n+ooooo\x1b[1;37m:+o/Md\x1b[1;31moooooo\x1b[1;37m:sm/\x1b[1;31moo/ooo\x1b[1;37myMo\x1b[1;31moooooooo+\nooo\x1b[1;37m:sdMdosMo\x1b[1;31mooooo\x1b[1;37moNMd\x1b[1;31m//\x1b[1;37mdMd+\x1b[1;31mo\x1b[1;37m:so\x1b[1;31mooooooooo\noooo\x1b[1;37m+ymdosMo\x1b[1;31mooo\x1b[1;37m+mMm\x1b[1;31m+/\x1b[1;37mhMMMMMh+hs\x1b[1;31mooooooooo\n+oooooo\x1b[1;37m:\x1b[1;31m:\x1b[1;37m/Nm:\x1b[1;31m/\x1b[1;37mhMNo\x1b[1;31m:y\x1b[1;37mMMMMMMMMMM+\x1b[1;31moooooooo+\n.ooooooooo\x1b[1;37m/NNMNy\x1b[1;31m:o\x1b[1;37mNMMMMMMMMMMo\x1b[1;31mooooooooo.\n/oooooooooo\x1b[1;37m:yh:\x1b[1;31m+m\x1b[1;37mMMMMMMMMMMd/\x1b[1;31mooooooooo/\n  +oooooooooo\x1b[1;37m+\x1b[1;31m/h\x1b[1;37mmMMMMMMNds//o\x1b[1;31moooooooo+\n   /oooooooooooo\x1b[1;37m+:////:o/ymMd\x1b[1;31mooooooo/\n    .oooooooooooooooooooo\x1b[1;37m/sdh\x1b[1;31moooooo.\n      -+oooooooooooooooooooooooo+-\n        `:+oooooooooooooooooo+:`\n            .-/+oooooooo+/-.\n\x1b[0m",
+        raw_art: "${c1}            .-/+oooooooo+/-.\n        `:+oooooooooooooooooo+:`\n      -+oooooooooooooooooooooooo+-\n    .ooooooooooooooooooo${c2}:ohNd${c1}oooooo.\n   /oooooooooooo${c2}:/+oo++:/ohNd${c1}ooooooo/\n  +oooooooooo${c2}:osNdhyyhdNNh+:+${c1}oooooooo+\n /ooooooooo${c2}/dN/${c1}ooooooooo${c2}/sNNo${c1}ooooooooo/\n.ooooooooo${c2}oMd:${c1}oooooooooooo${c2}:yMy${c1}ooooooooo.\n+ooooo${c2}:+o/Md${c1}oooooo${c2}:sm/${c1}oo/ooo${c2}yMo${c1}oooooooo+\nooo${c2}:sdMdosMo${c1}ooooo${c2}oNMd${c1}//${c2}dMd+${c1}o${c2}:so${c1}ooooooooo\noooo${c2}+ymdosMo${c1}ooo${c2}+mMm${c1}+/${c2}hMMMMMh+hs${c1}ooooooooo\n+oooooo${c2}:${c1}:${c2}/Nm:${c1}/${c2}hMNo${c1}:y${c2}MMMMMMMMMM+${c1}oooooooo+\n.ooooooooo${c2}/NNMNy${c1}:o${c2}NMMMMMMMMMMo${c1}ooooooooo.\n/oooooooooo${c2}:yh:${c1}+m${c2}MMMMMMMMMMd/${c1}ooooooooo/\n  +oooooooooo${c2}+${c1}/h${c2}mMMMMMMNds//o${c1}oooooooo+\n   /oooooooooooo${c2}+:////:o/ymMd${c1}ooooooo/\n    .oooooooooooooooooooo${c2}/sdh${c1}oooooo.\n      -+oooooooooooooooooooooooo+-\n        `:+oooooooooooooooooo+:`\n            .-/+oooooooo+/-.\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Ubuntu-GNOME",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m          ./o.\n        .oooooooo\n      .oooo```soooo\n    .oooo`     `soooo\n   .ooo`   \x1b[1;36m.o.\x1b[1;37m   `\\/ooo.\n   :ooo   \x1b[1;36m:oooo.\x1b[1;37m   `\\/ooo.\n    sooo    \x1b[1;36m`ooooo\x1b[1;37m    \\/oooo\n     \\/ooo    \x1b[1;36m`soooo\x1b[1;37m    `ooooo\n      `soooo    \x1b[1;36m`\\/ooo\x1b[1;37m    `soooo\n\x1b[1;36m./oo    \x1b[1;37m`\\/ooo    \x1b[1;36m`/oooo.\x1b[1;37m   `/ooo\n\x1b[1;36m`\\/ooo.   \x1b[1;37m`/oooo.   \x1b[1;36m`/oooo.\x1b[1;37m   ``\n\x1b[1;36m  `\\/ooo.    \x1b[1;37m/oooo     \x1b[1;36m/ooo`\n\x1b[1;36m     `ooooo    \x1b[1;37m``    \x1b[1;36m.oooo\n\x1b[1;36m       `soooo.     .oooo`\n         `\\/oooooooooo`\n            ``\\/oo``\n\x1b[0m",
+        raw_art: "${c3}          ./o.\n        .oooooooo\n      .oooo```soooo\n    .oooo`     `soooo\n   .ooo`   ${c4}.o.${c3}   `\\/ooo.\n   :ooo   ${c4}:oooo.${c3}   `\\/ooo.\n    sooo    ${c4}`ooooo${c3}    \\/oooo\n     \\/ooo    ${c4}`soooo${c3}    `ooooo\n      `soooo    ${c4}`\\/ooo${c3}    `soooo\n${c4}./oo    ${c3}`\\/ooo    ${c4}`/oooo.${c3}   `/ooo\n${c4}`\\/ooo.   ${c3}`/oooo.   ${c4}`/oooo.${c3}   ``\n${c4}  `\\/ooo.    ${c3}/oooo     ${c4}/ooo`\n${c4}     `ooooo    ${c3}``    ${c4}.oooo\n${c4}       `soooo.     .oooo`\n         `\\/oooooooooo`\n            ``\\/oo``\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 33,
     },
     Logo {
         name: "Ubuntu-MATE",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m            .:/+oossssoo+/:.`\n        `:+ssssssssssssssssss+:`\n      -+sssssssssssssss\x1b[1;37my\x1b[1;32mssssssss+-\n    .osssssssssssss\x1b[1;37myy\x1b[1;32mss\x1b[1;37mmMmh\x1b[1;32mssssssso.\n   /sssssssss\x1b[1;37mydmNNNmmd\x1b[1;32ms\x1b[1;37mmMMMMNdy\x1b[1;32msssss/\n `+ssssssss\x1b[1;37mhNNdy\x1b[1;32msssssss\x1b[1;37mmMMMMNdy\x1b[1;32mssssss+`\n +sssssss\x1b[1;37myNNh\x1b[1;32mss\x1b[1;37mhmNNNNm\x1b[1;32ms\x1b[1;37mmMmh\x1b[1;32ms\x1b[1;37mydy\x1b[1;32msssssss+\n-sssss\x1b[1;37my\x1b[1;32mss\x1b[1;37mNm\x1b[1;32mss\x1b[1;37mhNNh\x1b[1;32mssssss\x1b[1;37my\x1b[1;32ms\x1b[1;37mhh\x1b[1;32mss\x1b[1;37mmMy\x1b[1;32msssssss-\n+ssss\x1b[1;37myMNdy\x1b[1;32mss\x1b[1;37mhMd\x1b[1;32mssssssssss\x1b[1;37mhMd\x1b[1;32mss\x1b[1;37mNN\x1b[1;32msssssss+\nsssss\x1b[1;37myMMMMMmh\x1b[1;32msssssssssssss\x1b[1;37mNM\x1b[1;32mss\x1b[1;37mdMy\x1b[1;32msssssss\nsssss\x1b[1;37myMMMMMmhy\x1b[1;32mssssssssssss\x1b[1;37mNM\x1b[1;32mss\x1b[1;37mdMy\x1b[1;32msssssss\n+ssss\x1b[1;37myMNdy\x1b[1;32mss\x1b[1;37mhMd\x1b[1;32mssssssssss\x1b[1;37mhMd\x1b[1;32mss\x1b[1;37mNN\x1b[1;32msssssss+\n-sssss\x1b[1;37my\x1b[1;32mss\x1b[1;37mNm\x1b[1;32mss\x1b[1;37mhNNh\x1b[1;32mssssssss\x1b[1;37mdh\x1b[1;32mss\x1b[1;37mmMy\x1b[1;32msssssss-\n +sssssss\x1b[1;37myNNh\x1b[1;32mss\x1b[1;37mhmNNNNm\x1b[1;32ms\x1b[1;37mmNmh\x1b[1;32ms\x1b[1;37mymy\x1b[1;32msssssss+\n  +ssssssss\x1b[1;37mhNNdy\x1b[1;32msssssss\x1b[1;37mmMMMMmhy\x1b[1;32mssssss+\n   /sssssssss\x1b[1;37mydmNNNNmd\x1b[1;32ms\x1b[1;37mmMMMMNdh\x1b[1;32msssss/\n    .osssssssssssss\x1b[1;37myy\x1b[1;32mss\x1b[1;37mmMmdy\x1b[1;32msssssso.\n      -+sssssssssssssss\x1b[1;37my\x1b[1;32mssssssss+-\n        `:+ssssssssssssssssss+:`\n            .:/+oossssoo+/:.\n\n\x1b[0m",
+        raw_art: "${c1}            .:/+oossssoo+/:.`\n        `:+ssssssssssssssssss+:`\n      -+sssssssssssssss${c2}y${c1}ssssssss+-\n    .osssssssssssss${c2}yy${c1}ss${c2}mMmh${c1}ssssssso.\n   /sssssssss${c2}ydmNNNmmd${c1}s${c2}mMMMMNdy${c1}sssss/\n `+ssssssss${c2}hNNdy${c1}sssssss${c2}mMMMMNdy${c1}ssssss+`\n +sssssss${c2}yNNh${c1}ss${c2}hmNNNNm${c1}s${c2}mMmh${c1}s${c2}ydy${c1}sssssss+\n-sssss${c2}y${c1}ss${c2}Nm${c1}ss${c2}hNNh${c1}ssssss${c2}y${c1}s${c2}hh${c1}ss${c2}mMy${c1}sssssss-\n+ssss${c2}yMNdy${c1}ss${c2}hMd${c1}ssssssssss${c2}hMd${c1}ss${c2}NN${c1}sssssss+\nsssss${c2}yMMMMMmh${c1}sssssssssssss${c2}NM${c1}ss${c2}dMy${c1}sssssss\nsssss${c2}yMMMMMmhy${c1}ssssssssssss${c2}NM${c1}ss${c2}dMy${c1}sssssss\n+ssss${c2}yMNdy${c1}ss${c2}hMd${c1}ssssssssss${c2}hMd${c1}ss${c2}NN${c1}sssssss+\n-sssss${c2}y${c1}ss${c2}Nm${c1}ss${c2}hNNh${c1}ssssssss${c2}dh${c1}ss${c2}mMy${c1}sssssss-\n +sssssss${c2}yNNh${c1}ss${c2}hmNNNNm${c1}s${c2}mNmh${c1}s${c2}ymy${c1}sssssss+\n  +ssssssss${c2}hNNdy${c1}sssssss${c2}mMMMMmhy${c1}ssssss+\n   /sssssssss${c2}ydmNNNNmd${c1}s${c2}mMMMMNdh${c1}sssss/\n    .osssssssssssss${c2}yy${c1}ss${c2}mMmdy${c1}sssssso.\n      -+sssssssssssssss${c2}y${c1}ssssssss+-\n        `:+ssssssssssssssssss+:`\n            .:/+oossssoo+/:.\n\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Ubuntu-Studio",
         is_wildcard: false,
         ascii_art: "\x1b[1;36m              ..-::::::-.`\n         `.:+++++++++++\x1b[1;37mooo\x1b[1;36m++:.`\n       ./+++++++++++++\x1b[1;37msMMMNdyo\x1b[1;36m+/.\n     .++++++++++++++++\x1b[1;37moyhmMMMMms\x1b[1;36m++.\n   `/+++++++++\x1b[1;37mosyhddddhys\x1b[1;36m+\x1b[1;37mosdMMMh\x1b[1;36m++/`\n  `+++++++++\x1b[1;37mydMMMMNNNMMMMNds\x1b[1;36m+\x1b[1;37moyyo\x1b[1;36m++++`\n  +++++++++\x1b[1;37mdMMNhso\x1b[1;36m++++\x1b[1;37moydNMMmo\x1b[1;36m++++++++`\n :+\x1b[1;37modmy\x1b[1;36m+++\x1b[1;37mooysoohmNMMNmyoohMMNs\x1b[1;36m+++++++:\n ++\x1b[1;37mdMMm\x1b[1;36m+\x1b[1;37moNMd\x1b[1;36m++\x1b[1;37myMMMmhhmMMNs+yMMNo\x1b[1;36m+++++++\n`++\x1b[1;37mNMMy\x1b[1;36m+\x1b[1;37mhMMd\x1b[1;36m+\x1b[1;37moMMMs\x1b[1;36m++++\x1b[1;37msMMN\x1b[1;36m++\x1b[1;37mNMMs\x1b[1;36m+++++++.\n`++\x1b[1;37mNMMy\x1b[1;36m+\x1b[1;37mhMMd\x1b[1;36m+\x1b[1;37moMMMo\x1b[1;36m++++\x1b[1;37msMMN\x1b[1;36m++\x1b[1;37mmMMs\x1b[1;36m+++++++.\n ++\x1b[1;37mdMMd\x1b[1;36m+\x1b[1;37moNMm\x1b[1;36m++\x1b[1;37myMMNdhhdMMMs\x1b[1;36m+y\x1b[1;37mMMNo\x1b[1;36m+++++++\n :+\x1b[1;37modmy\x1b[1;36m++\x1b[1;37moo\x1b[1;36m+\x1b[1;37mss\x1b[1;36m+\x1b[1;37mohNMMMMmho\x1b[1;36m+\x1b[1;37myMMMs\x1b[1;36m+++++++:\n  +++++++++\x1b[1;37mhMMmhs+ooo+oshNMMms\x1b[1;36m++++++++\n  `++++++++\x1b[1;37moymMMMMNmmNMMMMmy+oys\x1b[1;36m+++++`\n   `/+++++++++\x1b[1;37moyhdmmmmdhso+sdMMMs\x1b[1;36m++/\n     ./+++++++++++++++\x1b[1;37moyhdNMMMms\x1b[1;36m++.\n       ./+++++++++++++\x1b[1;37mhMMMNdyo\x1b[1;36m+/.\n         `.:+++++++++++\x1b[1;37msso\x1b[1;36m++:.\n              ..-::::::-..\n\x1b[0m",
+        raw_art: "${c1}              ..-::::::-.`\n         `.:+++++++++++${c2}ooo${c1}++:.`\n       ./+++++++++++++${c2}sMMMNdyo${c1}+/.\n     .++++++++++++++++${c2}oyhmMMMMms${c1}++.\n   `/+++++++++${c2}osyhddddhys${c1}+${c2}osdMMMh${c1}++/`\n  `+++++++++${c2}ydMMMMNNNMMMMNds${c1}+${c2}oyyo${c1}++++`\n  +++++++++${c2}dMMNhso${c1}++++${c2}oydNMMmo${c1}++++++++`\n :+${c2}odmy${c1}+++${c2}ooysoohmNMMNmyoohMMNs${c1}+++++++:\n ++${c2}dMMm${c1}+${c2}oNMd${c1}++${c2}yMMMmhhmMMNs+yMMNo${c1}+++++++\n`++${c2}NMMy${c1}+${c2}hMMd${c1}+${c2}oMMMs${c1}++++${c2}sMMN${c1}++${c2}NMMs${c1}+++++++.\n`++${c2}NMMy${c1}+${c2}hMMd${c1}+${c2}oMMMo${c1}++++${c2}sMMN${c1}++${c2}mMMs${c1}+++++++.\n ++${c2}dMMd${c1}+${c2}oNMm${c1}++${c2}yMMNdhhdMMMs${c1}+y${c2}MMNo${c1}+++++++\n :+${c2}odmy${c1}++${c2}oo${c1}+${c2}ss${c1}+${c2}ohNMMMMmho${c1}+${c2}yMMMs${c1}+++++++:\n  +++++++++${c2}hMMmhs+ooo+oshNMMms${c1}++++++++\n  `++++++++${c2}oymMMMMNmmNMMMMmy+oys${c1}+++++`\n   `/+++++++++${c2}oyhdmmmmdhso+sdMMMs${c1}++/\n     ./+++++++++++++++${c2}oyhdNMMMms${c1}++.\n       ./+++++++++++++${c2}hMMMNdyo${c1}+/.\n         `.:+++++++++++${c2}sso${c1}++:.\n              ..-::::::-..\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "Univention",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m         ./osssssssssssssssssssssso+-\n       `ohhhhhhhhhhhhhhhhhhhhhhhhhhhhy:\n       shhhhhhhhhhhhhhhhhhhhhhhhhhhhhhh-\n   `-//\x1b[1;37msssss\x1b[1;31m/hhhhhhhhhhhhhh+\x1b[1;37ms\x1b[1;31m.hhhhhhhhh+\n .ohhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msss\x1b[1;31m+hhhhhhh+\n.yhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37mssss\x1b[1;31m:hhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37msssss\x1b[1;31m.hhhhhhhhhhhhhh.\x1b[1;37msssss\x1b[1;31myhhhhh+\n+hhhhhy\x1b[1;37mssssss\x1b[1;31m+yhhhhhhhhhhy/\x1b[1;37mssssss\x1b[1;31myhhhhh+\n+hhhhhh:\x1b[1;37msssssss\x1b[1;31m:hhhhhhh+\x1b[1;37m.ssssssss\x1b[1;31myhhhhy.\n+hhhhhhh+`\x1b[1;37mssssssssssssssss\x1b[1;31mhh\x1b[1;37msssss\x1b[1;31myhhho`\n+hhhhhhhhhs+\x1b[1;37mssssssssssss\x1b[1;31m+hh+\x1b[1;37msssss\x1b[1;31m/:-`\n-hhhhhhhhhhhhhhhhhhhhhhhhhhhhhhho\n :yhhhhhhhhhhhhhhhhhhhhhhhhhhhh+`\n   -+ossssssssssssssssssssss+:`\n\x1b[0m",
+        raw_art: "${c1}         ./osssssssssssssssssssssso+-\n       `ohhhhhhhhhhhhhhhhhhhhhhhhhhhhy:\n       shhhhhhhhhhhhhhhhhhhhhhhhhhhhhhh-\n   `-//${c2}sssss${c1}/hhhhhhhhhhhhhh+${c2}s${c1}.hhhhhhhhh+\n .ohhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sss${c1}+hhhhhhh+\n.yhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}ssss${c1}:hhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}sssss${c1}.hhhhhhhhhhhhhh.${c2}sssss${c1}yhhhhh+\n+hhhhhy${c2}ssssss${c1}+yhhhhhhhhhhy/${c2}ssssss${c1}yhhhhh+\n+hhhhhh:${c2}sssssss${c1}:hhhhhhh+${c2}.ssssssss${c1}yhhhhy.\n+hhhhhhh+`${c2}ssssssssssssssss${c1}hh${c2}sssss${c1}yhhho`\n+hhhhhhhhhs+${c2}ssssssssssss${c1}+hh+${c2}sssss${c1}/:-`\n-hhhhhhhhhhhhhhhhhhhhhhhhhhhhhhho\n :yhhhhhhhhhhhhhhhhhhhhhhhhhhhh+`\n   -+ossssssssssssssssssssss+:`\n",
+        colors: &[Color::Palette(1), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "VNux",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;11m              `\n           ^[XOx~.\n        ^_nwdbbkp0ti'\n        <vJCZw0LQ0Uj>\n\x1b[1;38;5;8m          _j>!vC1,,\n     \x1b[1;31m,\x1b[1;38;5;8m   ,CY\x1b[1;38;5;15mO\x1b[1;38;5;8mt\x1b[1;38;5;15mO\x1b[1;38;5;8m1(l;\"\n`\x1b[1;31m~-{r(1I\x1b[1;38;5;8m ^\x1b[1;38;5;11m/zmwJuc:\x1b[1;38;5;8mI^\n'\x1b[1;31m?)|\x1b[1;38;5;11mU\x1b[1;31m/}-\x1b[1;38;5;8m ^\x1b[1;38;5;15mf\x1b[1;38;5;11mOCLLOw\x1b[1;38;5;15m_\x1b[1;38;5;8m,;\n ,\x1b[1;31mi,``. \x1b[1;38;5;8m\",\x1b[1;38;5;15mk%ooW@$d\"\x1b[1;38;5;8mI,'\n  '    ;^\x1b[1;38;5;15mu$$$$$$$$^<\x1b[1;38;5;8m:^\n   ` .>>\x1b[1;38;5;15m($$\x1b[1;37m$@@@@$$\x1b[1;38;5;15m$nl\x1b[1;38;5;8m[::\n    `!}?\x1b[1;38;5;15mB$\x1b[1;37m%&WMMW&%$\x1b[1;38;5;15m$1}-\x1b[1;38;5;8m}\":\n    ^?j\x1b[1;38;5;15mZ$\x1b[1;37mWMMWWWWMMW$\x1b[1;38;5;15mofc\x1b[1;38;5;8m;;`\n    <~x&\x1b[1;38;5;15m$\x1b[1;37m&MWWWWWWWWp\x1b[1;38;5;15m-\x1b[1;37ml>[<\n\x1b[1;38;5;11m 'ljmwn\x1b[1;38;5;8m~tk8\x1b[1;37mMWWWWM8O\x1b[1;38;5;8mX\x1b[1;38;5;11mr\x1b[1;38;5;8m+]nC\x1b[1;38;5;11m[\n!JZqwwdX\x1b[1;38;5;8m:^C8\x1b[1;37m#MMMM@\x1b[1;38;5;8mX\x1b[1;38;5;11mOdpdpq0<\n<wwwwmmpO\x1b[1;38;5;8m1\x1b[1;38;5;15m0@%%%%8\x1b[1;38;5;8md\x1b[1;38;5;11mnqmwmqqqJl\n?QOZmqqqpb\x1b[1;38;5;8mt[run/?!\x1b[1;38;5;11m0pwqqQj-,\n ^:l<{nUUv>      ^x00J(\"\n                   ^\"\n\x1b[0m",
+        raw_art: "${c1}              `\n           ^[XOx~.\n        ^_nwdbbkp0ti'\n        <vJCZw0LQ0Uj>\n${c2}          _j>!vC1,,\n     ${c4},${c2}   ,CY${c3}O${c2}t${c3}O${c2}1(l;\"\n`${c4}~-{r(1I${c2} ^${c1}/zmwJuc:${c2}I^\n'${c4}?)|${c1}U${c4}/}-${c2} ^${c3}f${c1}OCLLOw${c3}_${c2},;\n ,${c4}i,``. ${c2}\",${c3}k%ooW@$d\"${c2}I,'\n  '    ;^${c3}u$$$$$$$$^<${c2}:^\n   ` .>>${c3}($$${c5}$@@@@$$${c3}$nl${c2}[::\n    `!}?${c3}B$${c5}%&WMMW&%$${c3}$1}-${c2}}\":\n    ^?j${c3}Z$${c5}WMMWWWWMMW$${c3}ofc${c2};;`\n    <~x&${c3}$${c5}&MWWWWWWWWp${c3}-${c5}l>[<\n${c1} 'ljmwn${c2}~tk8${c5}MWWWWM8O${c2}X${c1}r${c2}+]nC${c1}[\n!JZqwwdX${c2}:^C8${c5}#MMMM@${c2}X${c1}Odpdpq0<\n<wwwwmmpO${c2}1${c3}0@%%%%8${c2}d${c1}nqmwmqqqJl\n?QOZmqqqpb${c2}t[run/?!${c1}0pwqqQj-,\n ^:l<{nUUv>      ^x00J(\"\n                   ^\"\n",
+        colors: &[Color::Palette(11), Color::Palette(8), Color::Palette(15), Color::Palette(1), Color::Palette(7)],
         max_line_length: 28,
     },
     Logo {
         name: "Venom",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m   :::::::          :::::::\n   mMMMMMMm        dMMMMMMm\n   /MMMMMMMo      +MMMMMMM/\n    yMMMMMMN      mMMMMMMy\n     NMMMMMMs    oMMMMMMm\n     +MMMMMMN:   NMMMMMM+\n      hMMMMMMy  sMMMMMMy\n      :NMMMMMM::NMMMMMN:\n       oMMMMMMyyMMMMMM+\n        dMMMMMMMMMMMMh\n        /MMMMMMMMMMMN:\n         sMMMMMMMMMMo\n          mMMMMMMMMd\n          +MMMMMMMN:\n            ::::::\n\x1b[0m",
+        raw_art: "${c1}   :::::::          :::::::\n   mMMMMMMm        dMMMMMMm\n   /MMMMMMMo      +MMMMMMM/\n    yMMMMMMN      mMMMMMMy\n     NMMMMMMs    oMMMMMMm\n     +MMMMMMN:   NMMMMMM+\n      hMMMMMMy  sMMMMMMy\n      :NMMMMMM::NMMMMMN:\n       oMMMMMMyyMMMMMM+\n        dMMMMMMMMMMMMh\n        /MMMMMMMMMMMN:\n         sMMMMMMMMMMo\n          mMMMMMMMMd\n          +MMMMMMMN:\n            ::::::\n",
+        colors: &[Color::Palette(8), Color::Palette(4)],
         max_line_length: 27,
     },
     Logo {
         name: "Void",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m                __.;=====;.__\n            _.=+==++=++=+=+===;.\n             -=+++=+===+=+=+++++=_\n        .     -=:``     `--==+=++==.\n       _vi,    `            --+=++++:\n      .uvnvi.       _._       -==+==+.\n     .vvnvnI`    .;==|==;.     :|=||=|.\n\x1b[1;38;5;8m+QmQQm\x1b[1;32mpvvnv; \x1b[1;38;5;8m_yYsyQQWUUQQQm #QmQ#\x1b[1;32m:\x1b[1;38;5;8mQQQWUV$QQm.\n\x1b[1;38;5;8m -QQWQW\x1b[1;32mpvvo\x1b[1;38;5;8mwZ?.wQQQE\x1b[1;32m==<\x1b[1;38;5;8mQWWQ/QWQW.QQWW\x1b[1;32m(: \x1b[1;38;5;8mjQWQE\n\x1b[1;38;5;8m  -$QQQQmmU'  jQQQ@\x1b[1;32m+=<\x1b[1;38;5;8mQWQQ)mQQQ.mQQQC\x1b[1;32m+;\x1b[1;38;5;8mjWQQ@'\n\x1b[1;38;5;8m   -$WQ8Y\x1b[1;32mnI:   \x1b[1;38;5;8mQWQQwgQQWV\x1b[1;32m`\x1b[1;38;5;8mmWQQ.jQWQQgyyWW@!\n\x1b[1;32m     -1vvnvv.     `~+++`        ++|+++\n      +vnvnnv,                 `-|===\n       +vnvnvns.           .      :=-\n        -Invnvvnsi..___..=sv=.     `\n          +Invnvnvnnnnnnnnvvnn;.\n            ~|Invnvnvvnvvvnnv}+`\n               -~|{*l}*|~\n\x1b[0m",
+        raw_art: "${c1}                __.;=====;.__\n            _.=+==++=++=+=+===;.\n             -=+++=+===+=+=+++++=_\n        .     -=:``     `--==+=++==.\n       _vi,    `            --+=++++:\n      .uvnvi.       _._       -==+==+.\n     .vvnvnI`    .;==|==;.     :|=||=|.\n${c2}+QmQQm${c1}pvvnv; ${c2}_yYsyQQWUUQQQm #QmQ#${c1}:${c2}QQQWUV$QQm.\n${c2} -QQWQW${c1}pvvo${c2}wZ?.wQQQE${c1}==<${c2}QWWQ/QWQW.QQWW${c1}(: ${c2}jQWQE\n${c2}  -$QQQQmmU'  jQQQ@${c1}+=<${c2}QWQQ)mQQQ.mQQQC${c1}+;${c2}jWQQ@'\n${c2}   -$WQ8Y${c1}nI:   ${c2}QWQQwgQQWV${c1}`${c2}mWQQ.jQWQQgyyWW@!\n${c1}     -1vvnvv.     `~+++`        ++|+++\n      +vnvnnv,                 `-|===\n       +vnvnvns.           .      :=-\n        -Invnvvnsi..___..=sv=.     `\n          +Invnvnvnnnnnnnnvvnn;.\n            ~|Invnvnvvnvvvnnv}+`\n               -~|{*l}*|~\n",
+        colors: &[Color::Palette(2), Color::Palette(8)],
         max_line_length: 45,
     },
     Logo {
         name: "WHPNM Linux",
         is_wildcard: true,
         ascii_art: "\n\x1b[1;38;5;33m\n               ``.---..` `--`\n            ``.---........-:.\x1b[1;38;5;9m-::`\x1b[1;38;5;33m\n           \x1b[1;38;5;9m./::-\x1b[1;38;5;33m........\x1b[1;38;5;9m--::.````\x1b[1;38;5;33m\n          \x1b[1;38;5;9m.:://:::\x1b[1;38;5;33m----\x1b[1;38;5;9m::::-..\x1b[1;38;5;33m\n          ..\x1b[1;38;5;9m--:::::--::::++-\x1b[1;38;5;33m.`\n  \x1b[1;38;5;9m`-:-`\x1b[1;38;5;33m   .-ohy+::\x1b[1;38;5;9m-:::\x1b[1;38;5;33m/sdmdd:.\x1b[1;38;5;9m   `-:-\n   .-:::\x1b[1;38;5;33m...\x1b[1;38;5;15msNNmds$y\x1b[1;38;5;33mo/+\x1b[1;38;5;15msy+NN$m\x1b[1;38;5;33md+.`\x1b[1;38;5;9m-:::-.\n     `.-:-\x1b[1;38;5;33m./\x1b[1;38;5;15mdN\x1b[1;38;5;33m()\x1b[1;38;5;15myyooosd\x1b[1;38;5;33m()\x1b[1;38;5;15m$m\x1b[1;38;5;33mdy\x1b[1;38;5;9m-.::-.`\x1b[1;38;5;33m\n      \x1b[1;38;5;9m`.\x1b[1;38;5;33m-...-\x1b[1;38;5;15m+hNdyyyyyydmy\x1b[1;38;5;33m:......\x1b[1;38;5;9m`\x1b[1;38;5;33m\n ``..--.....-\x1b[1;38;5;15myNNm\x1b[1;38;5;202mhssssh\x1b[1;38;5;15mmmdo\x1b[1;38;5;33m.........```\n`-:://:.....\x1b[1;38;5;15mhNNNNN\x1b[1;38;5;202mmddm\x1b[1;38;5;15mNNNmds\x1b[1;38;5;33m.....//::--`\n  ```.:-...\x1b[1;38;5;15moNNNNNNNNNNNNNNmd/\x1b[1;38;5;33m...:-.```\n      .....\x1b[1;38;5;15mhNNNNNNNNNNNNNNmds\x1b[1;38;5;33m....`\n      --...\x1b[1;38;5;15mhNNNNNNNNNNNNNNmdo\x1b[1;38;5;33m.....\n      .:...\x1b[1;38;5;15m/NNNNNNNNNNNNNNdd\x1b[1;38;5;33m:....`\n       `-...\x1b[1;38;5;15m+mNNNNNNNNNNNmh\x1b[1;38;5;33m:...-.\n     \x1b[1;38;5;202m.:+o+/:-\x1b[1;38;5;33m:+oo+///++o+/:-\x1b[1;38;5;202m:/+ooo/:.\n       \x1b[1;38;5;202m+oo/:o-            +oooooso.`\n       \x1b[1;38;5;202m.`   `             `/  .-//-\n\x1b[0m",
+        raw_art: "\n${c1}\n               ``.---..` `--`\n            ``.---........-:.${c2}-::`${c1}\n           ${c2}./::-${c1}........${c2}--::.````${c1}\n          ${c2}.:://:::${c1}----${c2}::::-..${c1}\n          ..${c2}--:::::--::::++-${c1}.`\n  ${c2}`-:-`${c1}   .-ohy+::${c2}-:::${c1}/sdmdd:.${c2}   `-:-\n   .-:::${c1}...${c3}sNNmds$y${c1}o/+${c3}sy+NN$m${c1}d+.`${c2}-:::-.\n     `.-:-${c1}./${c3}dN${c1}()${c3}yyooosd${c1}()${c3}$m${c1}dy${c2}-.::-.`${c1}\n      ${c2}`.${c1}-...-${c3}+hNdyyyyyydmy${c1}:......${c2}`${c1}\n ``..--.....-${c3}yNNm${c4}hssssh${c3}mmdo${c1}.........```\n`-:://:.....${c3}hNNNNN${c4}mddm${c3}NNNmds${c1}.....//::--`\n  ```.:-...${c3}oNNNNNNNNNNNNNNmd/${c1}...:-.```\n      .....${c3}hNNNNNNNNNNNNNNmds${c1}....`\n      --...${c3}hNNNNNNNNNNNNNNmdo${c1}.....\n      .:...${c3}/NNNNNNNNNNNNNNdd${c1}:....`\n       `-...${c3}+mNNNNNNNNNNNmh${c1}:...-.\n     ${c4}.:+o+/:-${c1}:+oo+///++o+/:-${c4}:/+ooo/:.\n       ${c4}+oo/:o-            +oooooso.`\n       ${c4}.`   `             `/  .-//-\n",
+        colors: &[Color::Palette(33), Color::Palette(9), Color::Palette(15), Color::Palette(202)],
         max_line_length: 40,
     },
     Logo {
         name: "Windows",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m        ,.=:!!t3Z3z.,\n       :tt:::tt333EE3\n\x1b[1;31m       Et:::ztt33EEEL\x1b[1;32m @Ee.,      ..,\n\x1b[1;31m      ;tt:::tt333EE7\x1b[1;32m ;EEEEEEttttt33#\n\x1b[1;31m     :Et:::zt333EEQ.\x1b[1;32m $EEEEEttttt33QL\n\x1b[1;31m     it::::tt333EEF\x1b[1;32m @EEEEEEttttt33F\n\x1b[1;31m    ;3=*^```\"*4EEV\x1b[1;32m :EEEEEEttttt33@.\n\x1b[1;34m    ,.=::::!t=., \x1b[1;31m`\x1b[1;32m @EEEEEEtttz33QF\n\x1b[1;34m   ;::::::::zt33)\x1b[1;32m   \"4EEEtttji3P*\n\x1b[1;34m  :t::::::::tt33.\x1b[1;33m:Z3z..\x1b[1;32m  ``\x1b[1;33m ,..g.\n\x1b[1;34m  i::::::::zt33F\x1b[1;33m AEEEtttt::::ztF\n\x1b[1;34m ;:::::::::t33V\x1b[1;33m ;EEEttttt::::t3\n\x1b[1;34m E::::::::zt33L\x1b[1;33m @EEEtttt::::z3F\n\x1b[1;34m{3=*^```\"*4E3)\x1b[1;33m ;EEEtttt:::::tZ`\n\x1b[1;34m             `\x1b[1;33m :EEEEtttt::::z7\n                 \"VEzjt:;;z>*`\n\x1b[0m",
+        raw_art: "${c1}        ,.=:!!t3Z3z.,\n       :tt:::tt333EE3\n${c1}       Et:::ztt33EEEL${c2} @Ee.,      ..,\n${c1}      ;tt:::tt333EE7${c2} ;EEEEEEttttt33#\n${c1}     :Et:::zt333EEQ.${c2} $EEEEEttttt33QL\n${c1}     it::::tt333EEF${c2} @EEEEEEttttt33F\n${c1}    ;3=*^```\"*4EEV${c2} :EEEEEEttttt33@.\n${c3}    ,.=::::!t=., ${c1}`${c2} @EEEEEEtttz33QF\n${c3}   ;::::::::zt33)${c2}   \"4EEEtttji3P*\n${c3}  :t::::::::tt33.${c4}:Z3z..${c2}  ``${c4} ,..g.\n${c3}  i::::::::zt33F${c4} AEEEtttt::::ztF\n${c3} ;:::::::::t33V${c4} ;EEEttttt::::t3\n${c3} E::::::::zt33L${c4} @EEEtttt::::z3F\n${c3}{3=*^```\"*4E3)${c4} ;EEEtttt:::::tZ`\n${c3}             `${c4} :EEEEtttt::::z7\n                 \"VEzjt:;;z>*`\n",
+        colors: &[Color::Palette(1), Color::Palette(2), Color::Palette(4), Color::Palette(3)],
         max_line_length: 36,
     },
     Logo {
-        name: "XFerience",
+        name: "Windows 10",
         is_wildcard: true,
-        ascii_art: "\x1b[1;36m           ``--:::::::-.`\n        .-/+++ooooooooo+++:-`\n     `-/+oooooooooooooooooo++:.\n    -/+oooooo/+ooooooooo+/ooo++:`\n  `/+oo++oo.   .+oooooo+.-: +:-o+-\n `/+o/.  -o.    :oooooo+ ```:.+oo+-\n`:+oo-    -/`   :oooooo+ .`-`+oooo/.\n.+ooo+.    .`   `://///+-+..oooooo+:`\n-+ooo:`                ``.-+oooooo+/`\n-+oo/`                       :+oooo/.\n.+oo:            ..-/. .      -+oo+/`\n`/++-         -:::++::/.      -+oo+-\n ./o:          `:///+-     `./ooo+:`\n  .++-         `` /-`   -:/+oooo+:`\n   .:+/:``          `-:ooooooo++-\n     ./+o+//:...../+oooooooo++:`\n       `:/++ooooooooooooo++/-`\n          `.-//++++++//:-.`\n               ``````\n\x1b[0m",
-        max_line_length: 37,
+        ascii_art: "\x1b[1;36m                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n\x1b[0m",
+        raw_art: "${c1}                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
+        max_line_length: 36,
+    },
+    Logo {
+        name: "Windows 11",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;36m\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\x1b[0m",
+        raw_art: "${c1}\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
+        max_line_length: 34,
     },
     Logo {
         name: "Xubuntu",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m           `.:/ossyyyysso/:.\n        `.yyyyyyyyyyyyyyyyyyyy.`\n      `yyyyyyyyyyyyyyyyyyyyyyyyyy`\n    `yyyyyyyyyyyyyyyyyyyy\x1b[1;37m::\x1b[1;34myyyyyyyy`\n   .yyyyyyyyyyy\x1b[1;37m/+:\x1b[1;34myyyyyyy\x1b[1;37mds\x1b[1;34myyy\x1b[1;37m+y\x1b[1;34myyyy.\n  yyyyyyy\x1b[1;37m:o/\x1b[1;34myy\x1b[1;37mdMMM+\x1b[1;34myyyyy\x1b[1;37m/M+\x1b[1;34my\x1b[1;37m:hM+\x1b[1;34myyyyyy\n yyyyyyy\x1b[1;37m+MMMy\x1b[1;34my\x1b[1;37mmMMMh\x1b[1;34myyyyy\x1b[1;37myM::mM+\x1b[1;34myyyyyyyy\n`yyyyyyy\x1b[1;37m+MMMMysMMMd\x1b[1;34myyyyy\x1b[1;37mdh:mN+\x1b[1;34myyyyyyyyy`\nyyyyyyyy\x1b[1;37m:NMMMMmMMMMmmdhyy+/y:\x1b[1;34myyyyyyyyyyy\nyyyyyyyy\x1b[1;37m+MMMMMMMMMMMMMMMMMMNho:\x1b[1;34myyyyyyyyy\nyyyyyyyy\x1b[1;37mmMMMMMMMMMMMMMMMMMMMMMMy\x1b[1;34myyyyyyyy\nyyyyyyy\x1b[1;37m+MMMMMMMMMMMMMMMMMMMMMMMM/\x1b[1;34myyyyyyy\n`yyyyyy\x1b[1;37msMMMMMMMMMMMMMMMMMMMMMMmo\x1b[1;34myyyyyyy`\n yyyyyy\x1b[1;37moMMMMMMMMMMMMMMMMMMMmy+\x1b[1;34myyyyyyyyy\n  yyyyy\x1b[1;37m:mMMMMMMMMMMMMMMNho/\x1b[1;34myyyyyyyyyyy\n   .yyyy\x1b[1;37m:yNMMMMMMMNdyo:\x1b[1;34myyyyyyyyyyyyy.\n    `yyyyyy\x1b[1;37m:/++/::\x1b[1;34myyyyyyyyyyyyyyyyy`\n      `yyyyyyyyyyyyyyyyyyyyyyyyyy`\n        `.yyyyyyyyyyyyyyyyyyyy.`\n           `.:/oosyyyysso/:.`\n\x1b[0m",
+        raw_art: "${c1}           `.:/ossyyyysso/:.\n        `.yyyyyyyyyyyyyyyyyyyy.`\n      `yyyyyyyyyyyyyyyyyyyyyyyyyy`\n    `yyyyyyyyyyyyyyyyyyyy${c2}::${c1}yyyyyyyy`\n   .yyyyyyyyyyy${c2}/+:${c1}yyyyyyy${c2}ds${c1}yyy${c2}+y${c1}yyyy.\n  yyyyyyy${c2}:o/${c1}yy${c2}dMMM+${c1}yyyyy${c2}/M+${c1}y${c2}:hM+${c1}yyyyyy\n yyyyyyy${c2}+MMMy${c1}y${c2}mMMMh${c1}yyyyy${c2}yM::mM+${c1}yyyyyyyy\n`yyyyyyy${c2}+MMMMysMMMd${c1}yyyyy${c2}dh:mN+${c1}yyyyyyyyy`\nyyyyyyyy${c2}:NMMMMmMMMMmmdhyy+/y:${c1}yyyyyyyyyyy\nyyyyyyyy${c2}+MMMMMMMMMMMMMMMMMMNho:${c1}yyyyyyyyy\nyyyyyyyy${c2}mMMMMMMMMMMMMMMMMMMMMMMy${c1}yyyyyyyy\nyyyyyyy${c2}+MMMMMMMMMMMMMMMMMMMMMMMM/${c1}yyyyyyy\n`yyyyyy${c2}sMMMMMMMMMMMMMMMMMMMMMMmo${c1}yyyyyyy`\n yyyyyy${c2}oMMMMMMMMMMMMMMMMMMMmy+${c1}yyyyyyyyy\n  yyyyy${c2}:mMMMMMMMMMMMMMMNho/${c1}yyyyyyyyyyy\n   .yyyy${c2}:yNMMMMMMMNdyo:${c1}yyyyyyyyyyyyy.\n    `yyyyyy${c2}:/++/::${c1}yyyyyyyyyyyyyyyyy`\n      `yyyyyyyyyyyyyyyyyyyyyyyyyy`\n        `.yyyyyyyyyyyyyyyyyyyy.`\n           `.:/oosyyyysso/:.`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "Zorin",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m        `osssssssssssssssssssso`\n       .osssssssssssssssssssssso.\n      .+oooooooooooooooooooooooo+.\n\n\n  `::::::::::::::::::::::.         .:`\n `+ssssssssssssssssss+:.`     `.:+ssso`\n.ossssssssssssssso/.       `-+ossssssso.\nssssssssssssso/-`      `-/osssssssssssss\n.ossssssso/-`      .-/ossssssssssssssso.\n `+sss+:.      `.:+ssssssssssssssssss+`\n  `:.         .::::::::::::::::::::::`\n\n\n      .+oooooooooooooooooooooooo+.\n       -osssssssssssssssssssssso-\n        `osssssssssssssssssssso`\n\x1b[0m",
+        raw_art: "${c1}        `osssssssssssssssssssso`\n       .osssssssssssssssssssssso.\n      .+oooooooooooooooooooooooo+.\n\n\n  `::::::::::::::::::::::.         .:`\n `+ssssssssssssssssss+:.`     `.:+ssso`\n.ossssssssssssssso/.       `-+ossssssso.\nssssssssssssso/-`      `-/osssssssssssss\n.ossssssso/-`      .-/ossssssssssssssso.\n `+sss+:.      `.:+ssssssssssssssssss+`\n  `:.         .::::::::::::::::::::::`\n\n\n      .+oooooooooooooooooooooooo+.\n       -osssssssssssssssssssssso-\n        `osssssssssssssssssssso`\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 40,
     },
-    Logo {
-        name: "_small",
-        is_wildcard: false,
-        ascii_art: "\x1b[1;32m       .:'\n    _ :'_\n\x1b[1;33m .'`_`-'_``.\n:________.-'\n\x1b[1;31m:_______:\n:_______:\n\x1b[1;35m :_______`-;\n\x1b[1;34m  `._.-._.'\n\x1b[0m",
-        max_line_length: 12,
-    },
     Logo {
         name: "alpine_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;34m   /\\\\ /\\\\\n  /\x1b[1;37m/ \x1b[1;34m\\\\  \\\\\n /\x1b[1;37m/   \x1b[1;34m\\\\  \\\\\n/\x1b[1;37m//    \x1b[1;34m\\\\  \\\\\n\x1b[1;37m//      \x1b[1;34m\\\\  \\\\\n         \\\\\n\x1b[0m",
+        raw_art: "${c1}   /\\\\ /\\\\\n  /${c2}/ ${c1}\\\\  \\\\\n /${c2}/   ${c1}\\\\  \\\\\n/${c2}//    ${c1}\\\\  \\\\\n${c2}//      ${c1}\\\\  \\\\\n         \\\\\n",
+        colors: &[Color::Palette(4), Color::Palette(7)],
         max_line_length: 14,
     },
     Logo {
         name: "android_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m  ;,           ,;\n   ';,.-----.,;'\n  ,'           ',\n /    O     O    \\\\\n|                 |\n'-----------------'\n\x1b[0m",
+        raw_art: "${c1}  ;,           ,;\n   ';,.-----.,;'\n  ,'           ',\n /    O     O    \\\\\n|                 |\n'-----------------'\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 19,
     },
     Logo {
         name: "antiX",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m\n                    \\\n         , - ~ ^ ~ - \\        /\n     , '              \\ ' ,  /\n   ,                   \\   '/\n  ,                     \\  / ,\n ,___,                   \\/   ,\n /   |   _  _  _|_ o     /\\   ,\n|,   |  / |/ |  |  |    /  \\  ,\n \\,_/\\_/  |  |_/|_/|_/_/    \\,\n   ,                  /     ,\\\n     ,               /  , '   \\\n      ' - , _ _ _ ,  '\n\x1b[0m",
+        raw_art: "${c1}\n                    \\\n         , - ~ ^ ~ - \\        /\n     , '              \\ ' ,  /\n   ,                   \\   '/\n  ,                     \\  / ,\n ,___,                   \\/   ,\n /   |   _  _  _|_ o     /\\   ,\n|,   |  / |/ |  |  |    /  \\  ,\n \\,_/\\_/  |  |_/|_/|_/_/    \\,\n   ,                  /     ,\\\n     ,               /  , '   \\\n      ' - , _ _ _ ,  '\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 31,
     },
     Logo {
         name: "arch_old",
         is_wildcard: false,
         ascii_art: "\x1b[1;36m             __\n         _=(SDGJT=_\n       _GTDJHGGFCVS)\n      ,GTDJGGDTDFBGX0\n\x1b[1;36m     JDJDIJHRORVFSBSVL\x1b[1;37m-=+=,_\n\x1b[1;36m    IJFDUFHJNXIXCDXDSV,\x1b[1;37m  \"DEBL\n\x1b[1;36m   [LKDSDJTDU=OUSCSBFLD.\x1b[1;37m   '?ZWX,\n\x1b[1;36m  ,LMDSDSWH'     `DCBOSI\x1b[1;37m     DRDS],\n\x1b[1;36m  SDDFDFH'         !YEWD,\x1b[1;37m   )HDROD\n\x1b[1;36m !KMDOCG            &GSU|\x1b[1;37m\\_GFHRGO\\'\n\x1b[1;36m HKLSGP'\x1b[1;37m           __\x1b[1;36m\\TKM0\x1b[1;37m\\GHRBV)'\n\x1b[1;36mJSNRVW'\x1b[1;37m       __+MNAEC\x1b[1;36m\\IOI,\x1b[1;37m\\BN'\n\x1b[1;36mHELK['\x1b[1;37m    __,=OFFXCBGHC\x1b[1;36m\\FD)\n\x1b[1;36m?KGHE \x1b[1;37m\\_-#DASDFLSV='\x1b[1;36m    'EF\n'EHTI                    !H\n `0F'                    '!\n\x1b[0m",
+        raw_art: "${c1}             __\n         _=(SDGJT=_\n       _GTDJHGGFCVS)\n      ,GTDJGGDTDFBGX0\n${c1}     JDJDIJHRORVFSBSVL${c2}-=+=,_\n${c1}    IJFDUFHJNXIXCDXDSV,${c2}  \"DEBL\n${c1}   [LKDSDJTDU=OUSCSBFLD.${c2}   '?ZWX,\n${c1}  ,LMDSDSWH'     `DCBOSI${c2}     DRDS],\n${c1}  SDDFDFH'         !YEWD,${c2}   )HDROD\n${c1} !KMDOCG            &GSU|${c2}\\_GFHRGO\\'\n${c1} HKLSGP'${c2}           __${c1}\\TKM0${c2}\\GHRBV)'\n${c1}JSNRVW'${c2}       __+MNAEC${c1}\\IOI,${c2}\\BN'\n${c1}HELK['${c2}    __,=OFFXCBGHC${c1}\\FD)\n${c1}?KGHE ${c2}\\_-#DASDFLSV='${c1}    'EF\n'EHTI                    !H\n `0F'                    '!\n",
+        colors: &[Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 35,
     },
     Logo {
         name: "arch_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;36m      /\\\\\n     /  \\\\\n    /\\\\   \\\\\n\x1b[1;37m   /      \\\\\n  /   ,,   \\\\\n /   |  |  -\\\\\n/_-''    ''-_\\\\\n\x1b[0m",
+        raw_art: "${c1}      /\\\\\n     /  \\\\\n    /\\\\   \\\\\n${c2}   /      \\\\\n  /   ,,   \\\\\n /   |  |  -\\\\\n/_-''    ''-_\\\\\n",
+        colors: &[Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 15,
     },
     Logo {
         name: "arcolinux_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m          A\n         ooo\n        ooooo\n       ooooooo\n      ooooooooo\n     ooooo ooooo\n    ooooo   ooooo\n   ooooo     ooooo\n  ooooo  \x1b[1;37m<oooooooo>\x1b[1;34m\n ooooo      \x1b[1;37m<oooooo>\x1b[1;34m\nooooo          \x1b[1;37m<oooo>\x1b[1;34m\n\x1b[0m",
+        raw_art: "${c2}          A\n         ooo\n        ooooo\n       ooooooo\n      ooooooooo\n     ooooo ooooo\n    ooooo   ooooo\n   ooooo     ooooo\n  ooooo  ${c1}<oooooooo>${c2}\n ooooo      ${c1}<oooooo>${c2}\nooooo          ${c1}<oooo>${c2}\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 21,
     },
     Logo {
         name: "artix_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m      /\\\\\n     /  \\\\\n    /`'.,\\\\\n   /     ',\n  /      ,`\\\\\n /   ,.'`.  \\\\\n/.,'`     `'.\\\\\n\x1b[0m",
+        raw_art: "${c1}      /\\\\\n     /  \\\\\n    /`'.,\\\\\n   /     ',\n  /      ,`\\\\\n /   ,.'`.  \\\\\n/.,'`     `'.\\\\\n",
+        colors: &[Color::Palette(6), Color::Palette(6), Color::Palette(7), Color::Palette(1)],
         max_line_length: 15,
     },
     Logo {
         name: "bonsai",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m   ,####,\n   \x1b[1;32m#######,  \x1b[1;32m,#####,\n   \x1b[1;32m#####',#  \x1b[1;32m'######\n    \x1b[1;32m''###'\x1b[1;33m';,,,'\x1b[1;32m###'\n   \x1b[1;33m       ,;  ''''\n   \x1b[1;33m      ;;;   \x1b[1;32m,#####,\n   \x1b[1;33m     ;;;'  ,,;\x1b[1;32m;;###\n   \x1b[1;33m     ';;;;''\x1b[1;32m'####'\n   \x1b[1;33m      ;;;\n   \x1b[1;33m   ,.;;';'',,,\n   \x1b[1;33m  '     '\n\x1b[1;36m #\n #                        O\n ##, ,##,',##, ,##  ,#,   ,\n # # #  # #''# #,,  # #   #\n '#' '##' #  #  ,,# '##;, #\n\x1b[0m",
+        raw_art: "${c2}   ,####,\n   ${c2}#######,  ${c2},#####,\n   ${c2}#####',#  ${c2}'######\n    ${c2}''###'${c3}';,,,'${c2}###'\n   ${c3}       ,;  ''''\n   ${c3}      ;;;   ${c2},#####,\n   ${c3}     ;;;'  ,,;${c2};;###\n   ${c3}     ';;;;''${c2}'####'\n   ${c3}      ;;;\n   ${c3}   ,.;;';'',,,\n   ${c3}  '     '\n${c1} #\n #                        O\n ##, ,##,',##, ,##  ,#,   ,\n # # #  # #''# #,,  # #   #\n '#' '##' #  #  ,,# '##;, #\n",
+        colors: &[Color::Palette(6), Color::Palette(2), Color::Palette(3)],
         max_line_length: 27,
     },
     Logo {
         name: "centos_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m ____\x1b[1;33m^\x1b[1;35m____\n\x1b[1;32m |\\\\  \x1b[1;33m|\x1b[1;35m  /|\n\x1b[1;32m | \\\\ \x1b[1;33m|\x1b[1;35m / |\n\x1b[1;35m<---- \x1b[1;34m---->\n\x1b[1;34m | / \x1b[1;32m|\x1b[1;33m \\\\ |\n\x1b[1;34m |/__\x1b[1;32m|\x1b[1;33m__\\\\|\n\x1b[1;32m     v\n\x1b[0m",
+        raw_art: "${c2} ____${c1}^${c4}____\n${c2} |\\\\  ${c1}|${c4}  /|\n${c2} | \\\\ ${c1}|${c4} / |\n${c4}<---- ${c3}---->\n${c3} | / ${c2}|${c1} \\\\ |\n${c3} |/__${c2}|${c1}__\\\\|\n${c2}     v\n",
+        colors: &[Color::Palette(3), Color::Palette(2), Color::Palette(4), Color::Palette(5), Color::Palette(7)],
         max_line_length: 11,
     },
     Logo {
         name: "cleanjaro_small",
         is_wildcard: true,
-        ascii_art: "\x1b[1;37m\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088} \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088} \u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\u{00e2}\u{0096}\u{0088}\n\x1b[0m",
+        ascii_art: "\x1b[1;37m\u{2588}\u{2588}\u{2588}\u{2588}\u{2588} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\x1b[0m",
+        raw_art: "${c1}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588} \u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\n",
+        colors: &[Color::Palette(7), Color::Palette(7)],
         max_line_length: 16,
     },
+    Logo {
+        name: "crux_small",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;34m    ___\n   (\x1b[1;37m.\u{00b7} \x1b[1;34m|\n   (\x1b[1;35m<> \x1b[1;34m|\n  / \x1b[1;37m__  \x1b[1;34m\\\\\n ( \x1b[1;37m/  \\\\ \x1b[1;34m/|\n\x1b[1;35m_\x1b[1;34m/\\\\ \x1b[1;37m__)\x1b[1;34m/\x1b[1;35m_\x1b[1;34m)\n\x1b[1;35m\\/\x1b[1;34m-____\x1b[1;35m\\/\n\x1b[0m",
+        raw_art: "${c1}    ___\n   (${c3}.\u{00b7} ${c1}|\n   (${c2}<> ${c1}|\n  / ${c3}__  ${c1}\\\\\n ( ${c3}/  \\\\ ${c1}/|\n${c2}_${c1}/\\\\ ${c3}__)${c1}/${c2}_${c1})\n${c2}\\/${c1}-____${c2}\\/\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
+        max_line_length: 11,
+    },
     Logo {
         name: "dahlia",
         is_wildcard: true,
-        ascii_art: "\x1b[1;31m\n                  .#.\n                *%@@@%*\n        .,,,,,(&@@@@@@@&/,,,,,.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n       ,#@@@@@@@&#///#&@@@@@@@#.\n     ,/%&@@@@@%/,    .,(%@@@@@&#/.\n   *#&@@@@@@#,.         .*#@@@@@@&#,\n .&@@@@@@@@@(            .(@@@@@@@@@&&.\n#@@@@@@@@@@(               )@@@@@@@@@@@#\n \u{00c2}\u{00b0}@@@@@@@@@@(            .(@@@@@@@@@@@\u{00c2}\u{00b0}\n   *%@@@@@@@(.           ,#@@@@@@@%*\n     ,(&@@@@@@%*.     ./%@@@@@@%(,\n       ,#@@@@@@@&(***(&@@@@@@@#.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n        ,*****#&@@@@@@@&(*****,\n               ,/%@@@%/.\n                  ,#,\n\x1b[0m",
+        ascii_art: "\x1b[1;31m\n                  .#.\n                *%@@@%*\n        .,,,,,(&@@@@@@@&/,,,,,.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n       ,#@@@@@@@&#///#&@@@@@@@#.\n     ,/%&@@@@@%/,    .,(%@@@@@&#/.\n   *#&@@@@@@#,.         .*#@@@@@@&#,\n .&@@@@@@@@@(            .(@@@@@@@@@&&.\n#@@@@@@@@@@(               )@@@@@@@@@@@#\n \u{00b0}@@@@@@@@@@(            .(@@@@@@@@@@@\u{00b0}\n   *%@@@@@@@(.           ,#@@@@@@@%*\n     ,(&@@@@@@%*.     ./%@@@@@@%(,\n       ,#@@@@@@@&(***(&@@@@@@@#.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n        ,*****#&@@@@@@@&(*****,\n               ,/%@@@%/.\n                  ,#,\n\x1b[0m",
+        raw_art: "${c1}\n                  .#.\n                *%@@@%*\n        .,,,,,(&@@@@@@@&/,,,,,.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n       ,#@@@@@@@&#///#&@@@@@@@#.\n     ,/%&@@@@@%/,    .,(%@@@@@&#/.\n   *#&@@@@@@#,.         .*#@@@@@@&#,\n .&@@@@@@@@@(            .(@@@@@@@@@&&.\n#@@@@@@@@@@(               )@@@@@@@@@@@#\n \u{00b0}@@@@@@@@@@(            .(@@@@@@@@@@@\u{00b0}\n   *%@@@@@@@(.           ,#@@@@@@@%*\n     ,(&@@@@@@%*.     ./%@@@@@@%(,\n       ,#@@@@@@@&(***(&@@@@@@@#.\n       ,#@@@@@@@@@@@@@@@@@@@@@#.\n        ,*****#&@@@@@@@&(*****,\n               ,/%@@@%/.\n                  ,#,\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "debian_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;31m  _____\n /  __ \\\\\n|  /    |\n|  \\\\___-\n-_\n  --_\n\x1b[0m",
+        raw_art: "${c1}  _____\n /  __ \\\\\n|  /    |\n|  \\\\___-\n-_\n  --_\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 9,
     },
     Logo {
         name: "dragonfly_old",
         is_wildcard: true,
         ascii_art: "     \x1b[1;31m                   .-.\n                 \x1b[1;33m ()\x1b[1;31mI\x1b[1;33m()\n            \x1b[1;31m \"==.__:-:__.==\"\n            \"==.__/~|~\\__.==\"\n            \"==._(  Y  )_.==\"\n \x1b[1;37m.-'~~\"\"~=--...,__\x1b[1;31m\\/|\\/\x1b[1;37m__,...--=~\"\"~~'-.\n(               ..=\x1b[1;31m\\\\=\x1b[1;31m/\x1b[1;37m=..               )\n `'-.        ,.-\"`;\x1b[1;31m/=\\\\\x1b[1;37m;\"-.,_        .-'`\n     `~\"-=-~` .-~` \x1b[1;31m|=|\x1b[1;37m `~-. `~-=-\"~`\n          .-~`    /\x1b[1;31m|=|\x1b[1;37m\\    `~-.\n       .~`       / \x1b[1;31m|=|\x1b[1;37m \\       `~.\n   .-~`        .'  \x1b[1;31m|=|\x1b[1;37m  `.        `~-.\n (`     _,.-=\"`  \x1b[1;31m  |=|\x1b[1;37m    `\"=-.,_     `)\n  `~\"~\"`        \x1b[1;31m   |=|\x1b[1;37m           `\"~\"~`\n                 \x1b[1;31m  /=\\\\\n                   \\\\=/\n                    ^\n\x1b[0m",
+        raw_art: "     ${c1}                   .-.\n                 ${c3} ()${c1}I${c3}()\n            ${c1} \"==.__:-:__.==\"\n            \"==.__/~|~\\__.==\"\n            \"==._(  Y  )_.==\"\n ${c2}.-'~~\"\"~=--...,__${c1}\\/|\\/${c2}__,...--=~\"\"~~'-.\n(               ..=${c1}\\\\=${c1}/${c2}=..               )\n `'-.        ,.-\"`;${c1}/=\\\\${c2};\"-.,_        .-'`\n     `~\"-=-~` .-~` ${c1}|=|${c2} `~-. `~-=-\"~`\n          .-~`    /${c1}|=|${c2}\\    `~-.\n       .~`       / ${c1}|=|${c2} \\       `~.\n   .-~`        .'  ${c1}|=|${c2}  `.        `~-.\n (`     _,.-=\"`  ${c1}  |=|${c2}    `\"=-.,_     `)\n  `~\"~\"`        ${c1}   |=|${c2}           `\"~\"~`\n                 ${c1}  /=\\\\\n                   \\\\=/\n                    ^\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 42,
     },
     Logo {
         name: "dragonfly_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m   ,\x1b[1;31m_\x1b[1;37m,\n('-_\x1b[1;31m|\x1b[1;37m_-')\n >--\x1b[1;31m|\x1b[1;37m--<\n(_-'\x1b[1;31m|\x1b[1;37m'-_)\n    \x1b[1;31m|\n    |\n    |\n\x1b[0m",
+        raw_art: "${c2}   ,${c1}_${c2},\n('-_${c1}|${c2}_-')\n >--${c1}|${c2}--<\n(_-'${c1}|${c2}'-_)\n    ${c1}|\n    |\n    |\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 9,
     },
     Logo {
         name: "elementary_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m  _______\n / ____  \\\\\n/  |  /  /\\\\\n|__\\\\ /  / |\n\\\\   /__/  /\n \\\\_______/\n\x1b[0m",
+        raw_art: "${c2}  _______\n / ____  \\\\\n/  |  /  /\\\\\n|__\\\\ /  / |\n\\\\   /__/  /\n \\\\_______/\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 12,
     },
     Logo {
         name: "fedora_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;38;5;12m        ,'''''.\n       |   ,.  |\n       |  |  '_'\n  ,....|  |..\n.'  ,_;|   ..'\n|  |   |  |\n|  ',_,'  |\n '.     ,'\n   '''''\n\x1b[0m",
+        raw_art: "${c1}        ,'''''.\n       |   ,.  |\n       |  |  '_'\n  ,....|  |..\n.'  ,_;|   ..'\n|  |   |  |\n|  ',_,'  |\n '.     ,'\n   '''''\n",
+        colors: &[Color::Palette(12)],
         max_line_length: 16,
     },
     Logo {
         name: "freebsd_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;31m/\\\\,-'''''-,/\\\\\n\\\\_)       (_/\n|           |\n|           |\n ;         ;\n  '-_____-'\n\x1b[0m",
+        raw_art: "${c1}/\\\\,-'''''-,/\\\\\n\\\\_)       (_/\n|           |\n|           |\n ;         ;\n  '-_____-'\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 15,
     },
     Logo {
         name: "gNewSense",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                     ..,,,,..\n               .oocchhhhhhhhhhccoo.\n        .ochhlllllllc hhhhhh ollllllhhco.\n    ochlllllllllll hhhllllllhhh lllllllllllhco\n .cllllllllllllll hlllllo  +hllh llllllllllllllc.\nollllllllllhco''  hlllllo  +hllh  ``ochllllllllllo\nhllllllllc'       hllllllllllllh       `cllllllllh\nollllllh          +llllllllllll+          hllllllo\n `cllllh.           ohllllllho           .hllllc'\n    ochllc.            ++++            .cllhco\n       `+occooo+.                .+ooocco+'\n              `+oo++++      ++++oo+'\n\x1b[0m",
+        raw_art: "${c1}                     ..,,,,..\n               .oocchhhhhhhhhhccoo.\n        .ochhlllllllc hhhhhh ollllllhhco.\n    ochlllllllllll hhhllllllhhh lllllllllllhco\n .cllllllllllllll hlllllo  +hllh llllllllllllllc.\nollllllllllhco''  hlllllo  +hllh  ``ochllllllllllo\nhllllllllc'       hllllllllllllh       `cllllllllh\nollllllh          +llllllllllll+          hllllllo\n `cllllh.           ohllllllho           .hllllc'\n    ochllc.            ++++            .cllhco\n       `+occooo+.                .+ooocco+'\n              `+oo++++      ++++oo+'\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(7), Color::Palette(6)],
         max_line_length: 50,
     },
     Logo {
         name: "gentoo_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;35m _-----_\n(       \\\\\n\\    0   \\\\\n\x1b[1;37m \\        )\n /      _/\n(     _-\n\\____-\n\x1b[0m",
+        raw_art: "${c1} _-----_\n(       \\\\\n\\    0   \\\\\n${c2} \\        )\n /      _/\n(     _-\n\\____-\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 11,
     },
     Logo {
         name: "glaucus",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m             ,,        ,d88P\n           ,d8P    ,ad8888*\n         ,888P    d88888*     ,,ad8888P*\n    d   d888P   a88888P*  ,ad8888888*\n  .d8  d8888:  d888888* ,d888888P*\n .888; 88888b d8888888b8888888P\n d8888J888888a88888888888888P*    ,d\n 88888888888888888888888888P   ,,d8*\n 888888888888888888888888888888888*\n *8888888888888888888888888888888*\n  Y888888888P* `*``*888888888888*\n   *^888^*            *Y888P**\n\x1b[0m",
+        raw_art: "${c1}             ,,        ,d88P\n           ,d8P    ,ad8888*\n         ,888P    d88888*     ,,ad8888P*\n    d   d888P   a88888P*  ,ad8888888*\n  .d8  d8888:  d888888* ,d888888P*\n .888; 88888b d8888888b8888888P\n d8888J888888a88888888888888P*    ,d\n 88888888888888888888888888P   ,,d8*\n 888888888888888888888888888888888*\n *8888888888888888888888888888888*\n  Y888888888P* `*``*888888888888*\n   *^888^*            *Y888P**\n",
+        colors: &[Color::Palette(5)],
         max_line_length: 40,
     },
     Logo {
         name: "guix_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m|.__          __.|\n|__ \\\\        / __|\n   \\\\ \\\\      / /\n    \\\\ \\\\    / /\n     \\\\ \\\\  / /\n      \\\\ \\\\/ /\n       \\\\__/\n\x1b[0m",
+        raw_art: "${c1}|.__          __.|\n|__ \\\\        / __|\n   \\\\ \\\\      / /\n    \\\\ \\\\    / /\n     \\\\ \\\\  / /\n      \\\\ \\\\/ /\n       \\\\__/\n",
+        colors: &[Color::Palette(3), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
         max_line_length: 19,
     },
     Logo {
         name: "haiku_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m       ,^,\n      /   \\\\\n*--_ ;     ; _--*\n\\\\   '\"     \"'   /\n '.           .'\n.-'\"         \"'-.\n '-.__.   .__.-'\n       |_|\n\x1b[0m",
+        raw_art: "${c1}       ,^,\n      /   \\\\\n*--_ ;     ; _--*\n\\\\   '\"     \"'   /\n '.           .'\n.-'\"         \"'-.\n '-.__.   .__.-'\n       |_|\n",
+        colors: &[Color::Palette(2), Color::Palette(8)],
         max_line_length: 18,
     },
     Logo {
         name: "hyperbola_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m    |`__.`/\n    \\____/\n    .--.\n   /    \\\\\n  /  ___ \\\\\n / .`   `.\\\\\n/.`      `.\\\\\n\x1b[0m",
+        raw_art: "${c1}    |`__.`/\n    \\____/\n    .--.\n   /    \\\\\n  /  ___ \\\\\n / .`   `.\\\\\n/.`      `.\\\\\n",
+        colors: &[Color::Palette(8)],
         max_line_length: 13,
     },
     Logo {
         name: "i3buntu",
         is_wildcard: true,
-        ascii_art: "\x1b[1;31m            .-/+oossssoo+\\-.\n        \u{00c2}\u{00b4}:+ssssssssssssssssss+:`\n      -+ssssssssssssssssssyyssss+-\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n   /sssssssssss\x1b[1;37mhdmmNNmmyNMMMMh\x1b[1;31mssssss\\\n  +sssssssss\x1b[1;37mhm\x1b[1;31myd\x1b[1;37mMMMMMMMNddddy\x1b[1;31mssssssss+\n /ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhmNMMMNh\x1b[1;31mssssssss\\\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\noss\x1b[1;37myNMMMNyMMh\x1b[1;31mssssssssssssss\x1b[1;37mhmmmh\x1b[1;31mssssssso\noss\x1b[1;37myNMMMNyMMh\x1b[1;31msssssssssssssshmmmh\x1b[1;31mssssssso\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n \\ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhdNMMMNh\x1b[1;31mssssssss/\n  +sssssssss\x1b[1;37mdm\x1b[1;31myd\x1b[1;37mMMMMMMMMddddy\x1b[1;31mssssssss+\n   \\sssssssssss\x1b[1;37mhdmNNNNmyNMMMMh\x1b[1;31mssssss/\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n      -+sssssssssssssssss\x1b[1;37myyy\x1b[1;31mssss+-\n        `:+ssssssssssssssssss+:`\n            .-\\+oossssoo+/-.\n\x1b[0m",
+        ascii_art: "\x1b[1;31m            .-/+oossssoo+\\-.\n        \u{00b4}:+ssssssssssssssssss+:`\n      -+ssssssssssssssssssyyssss+-\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n   /sssssssssss\x1b[1;37mhdmmNNmmyNMMMMh\x1b[1;31mssssss\\\n  +sssssssss\x1b[1;37mhm\x1b[1;31myd\x1b[1;37mMMMMMMMNddddy\x1b[1;31mssssssss+\n /ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhmNMMMNh\x1b[1;31mssssssss\\\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\noss\x1b[1;37myNMMMNyMMh\x1b[1;31mssssssssssssss\x1b[1;37mhmmmh\x1b[1;31mssssssso\noss\x1b[1;37myNMMMNyMMh\x1b[1;31msssssssssssssshmmmh\x1b[1;31mssssssso\n+ssss\x1b[1;37mhhhyNMMNy\x1b[1;31mssssssssssss\x1b[1;37myNMMMy\x1b[1;31msssssss+\n.ssssssss\x1b[1;37mdMMMNh\x1b[1;31mssssssssss\x1b[1;37mhNMMMd\x1b[1;31mssssssss.\n \\ssssssss\x1b[1;37mhNMMM\x1b[1;31myh\x1b[1;37mhyyyyhdNMMMNh\x1b[1;31mssssssss/\n  +sssssssss\x1b[1;37mdm\x1b[1;31myd\x1b[1;37mMMMMMMMMddddy\x1b[1;31mssssssss+\n   \\sssssssssss\x1b[1;37mhdmNNNNmyNMMMMh\x1b[1;31mssssss/\n    .ossssssssssssssssss\x1b[1;37mdMMMNy\x1b[1;31msssso.\n      -+sssssssssssssssss\x1b[1;37myyy\x1b[1;31mssss+-\n        `:+ssssssssssssssssss+:`\n            .-\\+oossssoo+/-.\n\x1b[0m",
+        raw_art: "${c1}            .-/+oossssoo+\\-.\n        \u{00b4}:+ssssssssssssssssss+:`\n      -+ssssssssssssssssssyyssss+-\n    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.\n   /sssssssssss${c2}hdmmNNmmyNMMMMh${c1}ssssss\\\n  +sssssssss${c2}hm${c1}yd${c2}MMMMMMMNddddy${c1}ssssssss+\n /ssssssss${c2}hNMMM${c1}yh${c2}hyyyyhmNMMMNh${c1}ssssssss\\\n.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.\n+ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+\noss${c2}yNMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssssssso\noss${c2}yNMMMNyMMh${c1}sssssssssssssshmmmh${c1}ssssssso\n+ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+\n.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.\n \\ssssssss${c2}hNMMM${c1}yh${c2}hyyyyhdNMMMNh${c1}ssssssss/\n  +sssssssss${c2}dm${c1}yd${c2}MMMMMMMMddddy${c1}ssssssss+\n   \\sssssssssss${c2}hdmNNNNmyNMMMMh${c1}ssssss/\n    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.\n      -+sssssssssssssssss${c2}yyy${c1}ssss+-\n        `:+ssssssssssssssssss+:`\n            .-\\+oossssoo+/-.\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "iglu",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;8m     |\n     |        |\n              |\n|    ________\n|  /\\   |    \\\n  /  \\  |     \\  |\n /    \\        \\ |\n/      \\________\\\n\\      /        /\n \\    /        /\n  \\  /        /\n   \\/________/\n\x1b[0m",
+        raw_art: "${c1}     |\n     |        |\n              |\n|    ________\n|  /\\   |    \\\n  /  \\  |     \\  |\n /    \\        \\ |\n/      \\________\\\n\\      /        /\n \\    /        /\n  \\  /        /\n   \\/________/\n",
+        colors: &[Color::Palette(8)],
+        max_line_length: 18,
+    },
+    Logo {
+        name: "iglunix",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;38;5;8m     |\n     |        |\n              |\n|    ________\n|  /\\   |    \\\n  /  \\  |     \\  |\n /    \\        \\ |\n/      \\________\\\n\\      /        /\n \\    /        /\n  \\  /        /\n   \\/________/\n\x1b[0m",
+        raw_art: "${c1}     |\n     |        |\n              |\n|    ________\n|  /\\   |    \\\n  /  \\  |     \\  |\n /    \\        \\ |\n/      \\________\\\n\\      /        /\n \\    /        /\n  \\  /        /\n   \\/________/\n",
+        colors: &[Color::Palette(8)],
         max_line_length: 18,
     },
     Logo {
         name: "instantOS",
         is_wildcard: true,
         ascii_art: "\n\x1b[1;34m\n     'cx0XWWMMWNKOd:'.\n  .;kNMMMMMMMMMMMMMWNKd'\n 'kNMMMMMMWNNNWMMMMMMMMXo.\n,0MMMMMW0o;'..,:dKWMMMMMWx.\nOMMMMMXl.        .xNMMMMMNo\nWMMMMNl           .kWWMMMMO'\nMMMMMX;            oNWMMMMK,\nNMMMMWo           .OWMMMMMK,\nkWMMMMNd.        ,kWMMMMMMK,\n'kWMMMMWXxl:;;:okNMMMMMMMMK,\n .oXMMMMMMMWWWMMMMMMMMMMMMK,\n   'oKWMMMMMMMMMMMMMMMMMMMK,\n     .;lxOKXXXXXXXXXXXXXXXO;......\n          ................,d0000000kd:.\n                          .kMMMMMMMMMW0;\n                          .kMMMMMMMMMMMX\n                          .xMMMMMMMMMMMW\n                           cXMMMMMMMMMM0\n                            :0WMMMMMMNx,\n                             .o0NMWNOc.\n\x1b[0m",
+        raw_art: "\n${c1}\n     'cx0XWWMMWNKOd:'.\n  .;kNMMMMMMMMMMMMMWNKd'\n 'kNMMMMMMWNNNWMMMMMMMMXo.\n,0MMMMMW0o;'..,:dKWMMMMMWx.\nOMMMMMXl.        .xNMMMMMNo\nWMMMMNl           .kWWMMMMO'\nMMMMMX;            oNWMMMMK,\nNMMMMWo           .OWMMMMMK,\nkWMMMMNd.        ,kWMMMMMMK,\n'kWMMMMWXxl:;;:okNMMMMMMMMK,\n .oXMMMMMMMWWWMMMMMMMMMMMMK,\n   'oKWMMMMMMMMMMMMMMMMMMMK,\n     .;lxOKXXXXXXXXXXXXXXXO;......\n          ................,d0000000kd:.\n                          .kMMMMMMMMMW0;\n                          .kMMMMMMMMMMMX\n                          .xMMMMMMMMMMMW\n                           cXMMMMMMMMMM0\n                            :0WMMMMMMNx,\n                             .o0NMWNOc.\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 40,
     },
+    Logo {
+        name: "janus",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo \x1b[1;35mlooooooc\n\x1b[1;34mlooooooooo loo   cloooooool    \x1b[1;35mlooooc\n\x1b[1;34m cooo       cooooooooooo       \x1b[1;35mlooolooooool\n\x1b[1;34m            cooo:     \x1b[1;35mcoooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n\x1b[0m",
+        raw_art: "${c1}               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo ${c2}looooooc\n${c1}looooooooo loo   cloooooool    ${c2}looooc\n${c1} cooo       cooooooooooo       ${c2}looolooooool\n${c1}            cooo:     ${c2}coooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(6), Color::Palette(2)],
+        max_line_length: 52,
+    },
+    Logo {
+        name: "januslinux",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;34m               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo \x1b[1;35mlooooooc\n\x1b[1;34mlooooooooo loo   cloooooool    \x1b[1;35mlooooc\n\x1b[1;34m cooo       cooooooooooo       \x1b[1;35mlooolooooool\n\x1b[1;34m            cooo:     \x1b[1;35mcoooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n\x1b[0m",
+        raw_art: "${c1}               'l:\n        loooooo\n          loooo coooool\n looooooooooooooooooool\n  looooooooooooooooo\n         lool   cooo\n        coooooooloooooooo\n     clooooo  ;lood  cloooo\n  :loooocooo cloo      loooo\n loooo  :ooooool       loooo\nlooo    cooooo        cooooo\nlooooooooooooo      ;loooooo ${c2}looooooc\n${c1}looooooooo loo   cloooooool    ${c2}looooc\n${c1} cooo       cooooooooooo       ${c2}looolooooool\n${c1}            cooo:     ${c2}coooooooooooooooooool\n                       loooooooooooolc:   loooc;\n                             cooo:    loooooooooooc\n                            ;oool         looooooo:\n                           coool          olc,\n                          looooc   ,,\n                        coooooc    loc\n                       :oooool,    coool:, looool:,\n                       looool:      ooooooooooooooo:\n                       cooolc        .ooooooooooool\n",
+        colors: &[Color::Palette(4), Color::Palette(5), Color::Palette(6), Color::Palette(2)],
+        max_line_length: 52,
+    },
     Logo {
         name: "linuxlite_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;33m   /\\\\\n  /  \\\\\n / \x1b[1;37m/ \x1b[1;33m/\n> \x1b[1;37m/ \x1b[1;33m/\n\\\\ \x1b[1;37m\\\\ \x1b[1;33m\\\\\n \\\\_\x1b[1;37m\\\\\x1b[1;33m_\\\\\n\x1b[1;37m    \\\\\n\x1b[0m",
+        raw_art: "${c1}   /\\\\\n  /  \\\\\n / ${c2}/ ${c1}/\n> ${c2}/ ${c1}/\n\\\\ ${c2}\\\\ ${c1}\\\\\n \\\\_${c2}\\\\${c1}_\\\\\n${c2}    \\\\\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 9,
     },
     Logo {
         name: "linuxmint_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m ___________\n|_          \\\\\n  | \x1b[1;37m| _____ \x1b[1;32m|\n  | \x1b[1;37m| | | | \x1b[1;32m|\n  | \x1b[1;37m| | | | \x1b[1;32m|\n  | \x1b[1;37m\\\\__\x1b[1;37m___/ \x1b[1;32m|\n  \\\\_________/\n\x1b[0m",
+        raw_art: "${c1} ___________\n|_          \\\\\n  | ${c2}| _____ ${c1}|\n  | ${c2}| | | | ${c1}|\n  | ${c2}| | | | ${c1}|\n  | ${c2}\\\\__${c2}___/ ${c1}|\n  \\\\_________/\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 14,
     },
+    Logo {
+        name: "mac",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;32m                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n\x1b[1;33m .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n\x1b[1;31m;MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n\x1b[1;31m.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n \x1b[1;35m'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    \x1b[1;34mkMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n\x1b[0m",
+        raw_art: "${c1}                    c.'\n                 ,xNMM.\n               .OMMMMo\n               lMM\"\n     .;loddo:.  .olloddol;.\n   cKMMMMMMMMMMNWMMMMMMMMMM0:\n${c2} .KMMMMMMMMMMMMMMMMMMMMMMMWd.\n XMMMMMMMMMMMMMMMMMMMMMMMX.\n${c3};MMMMMMMMMMMMMMMMMMMMMMMM:\n:MMMMMMMMMMMMMMMMMMMMMMMM:\n${c4}.MMMMMMMMMMMMMMMMMMMMMMMMX.\n kMMMMMMMMMMMMMMMMMMMMMMMMWd.\n ${c5}'XMMMMMMMMMMMMMMMMMMMMMMMMMMk\n  'XMMMMMMMMMMMMMMMMMMMMMMMMK.\n    ${c6}kMMMMMMMMMMMMMMMMMMMMMMd\n     ;KMMMMMMMWXXWMMMMMMMk.\n       \"cooc*\"    \"*coo'\"\n",
+        colors: &[Color::Palette(2), Color::Palette(3), Color::Palette(1), Color::Palette(1), Color::Palette(5), Color::Palette(4)],
+        max_line_length: 30,
+    },
     Logo {
         name: "mageia_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m   *\n    *\n   **\n\x1b[1;37m /\\\\__/\\\\\n/      \\\\\n\\\\      /\n \\\\____/\n\x1b[0m",
+        raw_art: "${c1}   *\n    *\n   **\n${c2} /\\\\__/\\\\\n/      \\\\\n\\\\      /\n \\\\____/\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 9,
     },
     Logo {
         name: "manjaro_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m||||||||| ||||\n||||||||| ||||\n||||      ||||\n|||| |||| ||||\n|||| |||| ||||\n|||| |||| ||||\n|||| |||| ||||\n\x1b[0m",
+        raw_art: "${c1}||||||||| ||||\n||||||||| ||||\n||||      ||||\n|||| |||| ||||\n|||| |||| ||||\n|||| |||| ||||\n|||| |||| ||||\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 14,
     },
     Logo {
         name: "mint",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m             ...-:::::-...\n\x1b[1;37m          .-MMMMMMMMMMMMMMM-.\n      .-MMMM\x1b[1;32m`..-:::::::-..`\x1b[1;37mMMMM-.\n    .:MMMM\x1b[1;32m.:MMMMMMMMMMMMMMM:.\x1b[1;37mMMMM:.\n   -MMM\x1b[1;32m-M---MMMMMMMMMMMMMMMMMMM.\x1b[1;37mMMM-\n `:MMM\x1b[1;32m:MM`  :MMMM:....::-...-MMMM:\x1b[1;37mMMM:`\n :MMM\x1b[1;32m:MMM`  :MM:`  ``    ``  `:MMM:\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM.  -MM.  .MM-  `MMMM.\x1b[1;37mMMM.\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM:\x1b[1;37mMMM:\n:MMM\x1b[1;32m:MMMM`  :MM.  -MM-  .MM:  `MMMM-\x1b[1;37mMMM:\n.MMM\x1b[1;32m.MMMM`  :MM:--:MM:--:MM:  `MMMM.\x1b[1;37mMMM.\n :MMM\x1b[1;32m:MMM-  `-MMMMMMMMMMMM-`  -MMM-\x1b[1;37mMMM:\n  :MMM\x1b[1;32m:MMM:`                `:MMM:\x1b[1;37mMMM:\n   .MMM\x1b[1;32m.MMMM:--------------:MMMM.\x1b[1;37mMMM.\n     '-MMMM\x1b[1;32m.-MMMMMMMMMMMMMMM-.\x1b[1;37mMMMM-'\n       '.-MMMM\x1b[1;32m``--:::::--``\x1b[1;37mMMMM-.'\n\x1b[1;37m            '-MMMMMMMMMMMMM-'\n\x1b[1;37m               ``-:::::-``\n\x1b[0m",
+        raw_art: "${c2}             ...-:::::-...\n${c2}          .-MMMMMMMMMMMMMMM-.\n      .-MMMM${c1}`..-:::::::-..`${c2}MMMM-.\n    .:MMMM${c1}.:MMMMMMMMMMMMMMM:.${c2}MMMM:.\n   -MMM${c1}-M---MMMMMMMMMMMMMMMMMMM.${c2}MMM-\n `:MMM${c1}:MM`  :MMMM:....::-...-MMMM:${c2}MMM:`\n :MMM${c1}:MMM`  :MM:`  ``    ``  `:MMM:${c2}MMM:\n.MMM${c1}.MMMM`  :MM.  -MM.  .MM-  `MMMM.${c2}MMM.\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM:${c2}MMM:\n:MMM${c1}:MMMM`  :MM.  -MM-  .MM:  `MMMM-${c2}MMM:\n.MMM${c1}.MMMM`  :MM:--:MM:--:MM:  `MMMM.${c2}MMM.\n :MMM${c1}:MMM-  `-MMMMMMMMMMMM-`  -MMM-${c2}MMM:\n  :MMM${c1}:MMM:`                `:MMM:${c2}MMM:\n   .MMM${c1}.MMMM:--------------:MMMM.${c2}MMM.\n     '-MMMM${c1}.-MMMMMMMMMMMMMMM-.${c2}MMMM-'\n       '.-MMMM${c1}``--:::::--``${c2}MMMM-.'\n${c2}            '-MMMMMMMMMMMMM-'\n${c2}               ``-:::::-``\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "mint_old",
         is_wildcard: true,
         ascii_art: "\x1b[1;32mMMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      \x1b[1;37m/++                \x1b[1;32m-sNMd:\nMMNso/`  \x1b[1;37mdMM    `.::-. .-::.` \x1b[1;32m.hMN:\nddddMMh  \x1b[1;37mdMM   :hNMNMNhNMNMNh: \x1b[1;32m`NMm\n    NMm  \x1b[1;37mdMM  .NMN/-+MMM+-/NMN` \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  -MMm  `MMM   dMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM  .mmd  `mmm   yMM. \x1b[1;32mdMM\n    NMm  \x1b[1;37mdMM`  ..`   ...   ydm. \x1b[1;32mdMM\n    hMM- \x1b[1;37m+MMd/-------...-:sdds  \x1b[1;32mdMM\n    -NMm- \x1b[1;37m:hNMNNNmdddddddddy/`  \x1b[1;32mdMM\n     -dMNs-\x1b[1;37m``-::::-------.``    \x1b[1;32mdMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n\x1b[0m",
+        raw_art: "${c1}MMMMMMMMMMMMMMMMMMMMMMMMMmds+.\nMMm----::-://////////////oymNMd+`\nMMd      ${c2}/++                ${c1}-sNMd:\nMMNso/`  ${c2}dMM    `.::-. .-::.` ${c1}.hMN:\nddddMMh  ${c2}dMM   :hNMNMNhNMNMNh: ${c1}`NMm\n    NMm  ${c2}dMM  .NMN/-+MMM+-/NMN` ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  -MMm  `MMM   dMM. ${c1}dMM\n    NMm  ${c2}dMM  .mmd  `mmm   yMM. ${c1}dMM\n    NMm  ${c2}dMM`  ..`   ...   ydm. ${c1}dMM\n    hMM- ${c2}+MMd/-------...-:sdds  ${c1}dMM\n    -NMm- ${c2}:hNMNNNmdddddddddy/`  ${c1}dMM\n     -dMNs-${c2}``-::::-------.``    ${c1}dMM\n      `/dMNmy+/:-------------:/yMMM\n         ./ydNMMMMMMMMMMMMMMMMMMMMM\n            .MMMMMMMMMMMMMMMMMMM\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 35,
     },
     Logo {
         name: "mx_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m    \\\\\\\\  /\n     \\\\\\\\/\n      \\\\\\\\\n   /\\\\/ \\\\\\\\\n  /  \\\\  /\\\\\n /    \\\\/  \\\\\n/__________\\\\\n\x1b[0m",
+        raw_art: "${c3}    \\\\\\\\  /\n     \\\\\\\\/\n      \\\\\\\\\n   /\\\\/ \\\\\\\\\n  /  \\\\  /\\\\\n /    \\\\/  \\\\\n/__________\\\\\n",
+        colors: &[Color::Palette(4), Color::Palette(6), Color::Palette(7)],
         max_line_length: 13,
     },
     Logo {
         name: "netbsd_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m\\\\\\\\\x1b[1;35m\\`-______,----__\n\x1b[1;37m \\\\\\\\        \x1b[1;35m__,---\\`_\n\x1b[1;37m  \\\\\\\\       \x1b[1;35m\\`.____\n\x1b[1;37m   \\\\\\\\\x1b[1;35m-______,----\\`-\n\x1b[1;37m    \\\\\\\\\n     \\\\\\\\\n      \\\\\\\\\n\x1b[0m",
+        raw_art: "${c2}\\\\\\\\${c1}\\`-______,----__\n${c2} \\\\\\\\        ${c1}__,---\\`_\n${c2}  \\\\\\\\       ${c1}\\`.____\n${c2}   \\\\\\\\${c1}-______,----\\`-\n${c2}    \\\\\\\\\n     \\\\\\\\\n      \\\\\\\\\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 22,
     },
     Logo {
         name: "nixos_old",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m          ::::.    \x1b[1;36m':::::     ::::'\n\x1b[1;34m          ':::::    \x1b[1;36m':::::.  ::::'\n\x1b[1;34m            :::::     \x1b[1;36m'::::.:::::\n\x1b[1;34m      .......:::::..... \x1b[1;36m::::::::\n\x1b[1;34m     ::::::::::::::::::. \x1b[1;36m::::::    \x1b[1;34m::::.\n    ::::::::::::::::::::: \x1b[1;36m:::::.  \x1b[1;34m.::::'\n\x1b[1;36m           .....           ::::' \x1b[1;34m:::::'\n\x1b[1;36m          :::::            '::' \x1b[1;34m:::::'\n\x1b[1;36m ........:::::               ' \x1b[1;34m:::::::::::.\n\x1b[1;36m:::::::::::::                 \x1b[1;34m:::::::::::::\n\x1b[1;36m ::::::::::: \x1b[1;34m..              \x1b[1;34m:::::\n\x1b[1;36m     .::::: \x1b[1;34m.:::            \x1b[1;34m:::::\n\x1b[1;36m    .:::::  \x1b[1;34m:::::          \x1b[1;34m'''''    \x1b[1;36m.....\n    :::::   \x1b[1;34m':::::.  \x1b[1;36m......:::::::::::::'\n     :::     \x1b[1;34m::::::. \x1b[1;36m':::::::::::::::::'\n\x1b[1;34m            .:::::::: \x1b[1;36m'::::::::::\n\x1b[1;34m           .::::''::::.     \x1b[1;36m'::::.\n\x1b[1;34m          .::::'   ::::.     \x1b[1;36m'::::.\n\x1b[1;34m         .::::      ::::      \x1b[1;36m'::::.\n\x1b[0m",
+        raw_art: "${c1}          ::::.    ${c2}':::::     ::::'\n${c1}          ':::::    ${c2}':::::.  ::::'\n${c1}            :::::     ${c2}'::::.:::::\n${c1}      .......:::::..... ${c2}::::::::\n${c1}     ::::::::::::::::::. ${c2}::::::    ${c1}::::.\n    ::::::::::::::::::::: ${c2}:::::.  ${c1}.::::'\n${c2}           .....           ::::' ${c1}:::::'\n${c2}          :::::            '::' ${c1}:::::'\n${c2} ........:::::               ' ${c1}:::::::::::.\n${c2}:::::::::::::                 ${c1}:::::::::::::\n${c2} ::::::::::: ${c1}..              ${c1}:::::\n${c2}     .::::: ${c1}.:::            ${c1}:::::\n${c2}    .:::::  ${c1}:::::          ${c1}'''''    ${c2}.....\n    :::::   ${c1}':::::.  ${c2}......:::::::::::::'\n     :::     ${c1}::::::. ${c2}':::::::::::::::::'\n${c1}            .:::::::: ${c2}'::::::::::\n${c1}           .::::''::::.     ${c2}'::::.\n${c1}          .::::'   ::::.     ${c2}'::::.\n${c1}         .::::      ::::      ${c2}'::::.\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 43,
     },
     Logo {
         name: "nixos_small",
         is_wildcard: false,
         ascii_art: "  \x1b[1;34m  \\\\\\\\  \\\\\\\\ //\n ==\\\\\\\\__\\\\\\\\/ //\n   //   \\\\\\\\//\n==//     //==\n //\\\\\\\\___//\n// /\\\\\\\\  \\\\\\\\==\n  // \\\\\\\\  \\\\\\\\\n\x1b[0m",
+        raw_art: "  ${c1}  \\\\\\\\  \\\\\\\\ //\n ==\\\\\\\\__\\\\\\\\/ //\n   //   \\\\\\\\//\n==//     //==\n //\\\\\\\\___//\n// /\\\\\\\\  \\\\\\\\==\n  // \\\\\\\\  \\\\\\\\\n",
+        colors: &[Color::Palette(4), Color::Palette(6)],
         max_line_length: 17,
     },
+    Logo {
+        name: "open SUSE",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'\x1b[1;32m  Okxol:;,.          \x1b[1;37m:O0d.\n  .OK\x1b[1;32mKKK0kOKKKKKKKKKKOxo:,      \x1b[1;37mlKO.\n ,0K\x1b[1;32mKKKKKKKKKKKKKKK0P^\x1b[1;37m,,,\x1b[1;32m^dx:\x1b[1;37m    ;00,\n.OK\x1b[1;32mKKKKKKKKKKKKKKKk'\x1b[1;37m.oOPPb.\x1b[1;32m'0k.\x1b[1;37m   cKO.\n:KK\x1b[1;32mKKKKKKKKKKKKKKK: \x1b[1;37mkKx..dd \x1b[1;32mlKd\x1b[1;37m   'OK:\ndKK\x1b[1;32mKKKKKKKKKOx0KKKd \x1b[1;37m^0KKKO' \x1b[1;32mkKKc\x1b[1;37m   dKd\ndKK\x1b[1;32mKKKKKKKKKK;.;oOKx,..\x1b[1;37m^\x1b[1;32m..;kKKK0.\x1b[1;37m  dKd\n:KK\x1b[1;32mKKKKKKKKKK0o;...^cdxxOK0O/^^'  \x1b[1;37m.0K:\n kKK\x1b[1;32mKKKKKKKKKKKKK0x;,,......,;od  \x1b[1;37mlKk\n '0K\x1b[1;32mKKKKKKKKKKKKKKKKKKKK00KKOo^  \x1b[1;37mc00'\n  'kK\x1b[1;32mKKOxddxkOO00000Okxoc;''   \x1b[1;37m.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n\x1b[0m",
+        raw_art: "${c2}           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'${c1}  Okxol:;,.          ${c2}:O0d.\n  .OK${c1}KKK0kOKKKKKKKKKKOxo:,      ${c2}lKO.\n ,0K${c1}KKKKKKKKKKKKKKK0P^${c2},,,${c1}^dx:${c2}    ;00,\n.OK${c1}KKKKKKKKKKKKKKKk'${c2}.oOPPb.${c1}'0k.${c2}   cKO.\n:KK${c1}KKKKKKKKKKKKKKK: ${c2}kKx..dd ${c1}lKd${c2}   'OK:\ndKK${c1}KKKKKKKKKOx0KKKd ${c2}^0KKKO' ${c1}kKKc${c2}   dKd\ndKK${c1}KKKKKKKKKK;.;oOKx,..${c2}^${c1}..;kKKK0.${c2}  dKd\n:KK${c1}KKKKKKKKKK0o;...^cdxxOK0O/^^'  ${c2}.0K:\n kKK${c1}KKKKKKKKKKKKK0x;,,......,;od  ${c2}lKk\n '0K${c1}KKKKKKKKKKKKKKKKKKKK00KKOo^  ${c2}c00'\n  'kK${c1}KKOxddxkOO00000Okxoc;''   ${c2}.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 38,
+    },
     Logo {
         name: "openEuler",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m                 `.cc.`\n             ``.cccccccc..`\n          `.cccccccccccccccc.`\n      ``.cccccccccccccccccccccc.``\n   `..cccccccccccccccccccccccccccc..`\n`.ccccccccccccccc\x1b[1;37m/++/\x1b[1;34mccccccccccccccccc.`\n.ccccccccccccccc\x1b[1;37mmNMMNdo+oso+\x1b[1;34mccccccccccc.\n.cccccccccc\x1b[1;37m/++odms+//+mMMMMm/:+syso/\x1b[1;34mcccc\n.ccccccccc\x1b[1;37myNNMMMs:::/::+o+/:\x1b[1;34mc\x1b[1;37mdMMMMMm\x1b[1;34mcccc\n.ccccccc\x1b[1;37m:+NmdyyhNNmNNNd:\x1b[1;34mccccc\x1b[1;34m\x1b[1;37m:oyyyo:\x1b[1;34mcccc\n.ccc\x1b[1;37m:ohdmMs:\x1b[1;34mcccc\x1b[1;37m+mNMNmy\x1b[1;34mccccccccccccccccc\n.cc\x1b[1;37m/NMMMMMo////:\x1b[1;34mc\x1b[1;37m:///:\x1b[1;34mcccccccccccccccccc\n.cc\x1b[1;37m:syysyNMNNNMNy\x1b[1;34mccccccccccccccccccccccc\n.cccccccc\x1b[1;37m+MMMMMNy\x1b[1;34mc\x1b[1;37m:/+++/\x1b[1;34mcccccccccccccccc\n.ccccccccc\x1b[1;37mohhhs/\x1b[1;34mc\x1b[1;37momMMMMNh\x1b[1;34mccccccccccccccc\n.ccccccccccccccc\x1b[1;37m:MMMMMMMM/\x1b[1;34mcccccccccccccc\n.cccccccccccccccc\x1b[1;37msNNNNNd+\x1b[1;34mcccccccccccccc.\n`..cccccccccccccccc\x1b[1;37m/+/:\x1b[1;34mcccccccccccccc..`\n   ``.cccccccccccccccccccccccccccc.``\n       `.cccccccccccccccccccccc.`\n          ``.cccccccccccccc.``\n              `.cccccccc.`\n                 `....`\n\x1b[0m",
+        raw_art: "${c1}                 `.cc.`\n             ``.cccccccc..`\n          `.cccccccccccccccc.`\n      ``.cccccccccccccccccccccc.``\n   `..cccccccccccccccccccccccccccc..`\n`.ccccccccccccccc${c2}/++/${c1}ccccccccccccccccc.`\n.ccccccccccccccc${c2}mNMMNdo+oso+${c1}ccccccccccc.\n.cccccccccc${c2}/++odms+//+mMMMMm/:+syso/${c1}cccc\n.ccccccccc${c2}yNNMMMs:::/::+o+/:${c1}c${c2}dMMMMMm${c1}cccc\n.ccccccc${c2}:+NmdyyhNNmNNNd:${c1}ccccc${c1}${c2}:oyyyo:${c1}cccc\n.ccc${c2}:ohdmMs:${c1}cccc${c2}+mNMNmy${c1}ccccccccccccccccc\n.cc${c2}/NMMMMMo////:${c1}c${c2}:///:${c1}cccccccccccccccccc\n.cc${c2}:syysyNMNNNMNy${c1}ccccccccccccccccccccccc\n.cccccccc${c2}+MMMMMNy${c1}c${c2}:/+++/${c1}cccccccccccccccc\n.ccccccccc${c2}ohhhs/${c1}c${c2}omMMMMNh${c1}ccccccccccccccc\n.ccccccccccccccc${c2}:MMMMMMMM/${c1}cccccccccccccc\n.cccccccccccccccc${c2}sNNNNNd+${c1}cccccccccccccc.\n`..cccccccccccccccc${c2}/+/:${c1}cccccccccccccc..`\n   ``.cccccccccccccccccccccccccccc.``\n       `.cccccccccccccccccccccc.`\n          ``.cccccccccccccc.``\n              `.cccccccc.`\n                 `....`\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
+        max_line_length: 40,
+    },
+    Logo {
+        name: "openSUSE",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'\x1b[1;32m  Okxol:;,.          \x1b[1;37m:O0d.\n  .OK\x1b[1;32mKKK0kOKKKKKKKKKKOxo:,      \x1b[1;37mlKO.\n ,0K\x1b[1;32mKKKKKKKKKKKKKKK0P^\x1b[1;37m,,,\x1b[1;32m^dx:\x1b[1;37m    ;00,\n.OK\x1b[1;32mKKKKKKKKKKKKKKKk'\x1b[1;37m.oOPPb.\x1b[1;32m'0k.\x1b[1;37m   cKO.\n:KK\x1b[1;32mKKKKKKKKKKKKKKK: \x1b[1;37mkKx..dd \x1b[1;32mlKd\x1b[1;37m   'OK:\ndKK\x1b[1;32mKKKKKKKKKOx0KKKd \x1b[1;37m^0KKKO' \x1b[1;32mkKKc\x1b[1;37m   dKd\ndKK\x1b[1;32mKKKKKKKKKK;.;oOKx,..\x1b[1;37m^\x1b[1;32m..;kKKK0.\x1b[1;37m  dKd\n:KK\x1b[1;32mKKKKKKKKKK0o;...^cdxxOK0O/^^'  \x1b[1;37m.0K:\n kKK\x1b[1;32mKKKKKKKKKKKKK0x;,,......,;od  \x1b[1;37mlKk\n '0K\x1b[1;32mKKKKKKKKKKKKKKKKKKKK00KKOo^  \x1b[1;37mc00'\n  'kK\x1b[1;32mKKOxddxkOO00000Okxoc;''   \x1b[1;37m.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n\x1b[0m",
+        raw_art: "${c2}           .;ldkO0000Okdl;.\n       .;d00xl:^''''''^:ok00d;.\n     .d00l'                'o00d.\n   .d0Kd'${c1}  Okxol:;,.          ${c2}:O0d.\n  .OK${c1}KKK0kOKKKKKKKKKKOxo:,      ${c2}lKO.\n ,0K${c1}KKKKKKKKKKKKKKK0P^${c2},,,${c1}^dx:${c2}    ;00,\n.OK${c1}KKKKKKKKKKKKKKKk'${c2}.oOPPb.${c1}'0k.${c2}   cKO.\n:KK${c1}KKKKKKKKKKKKKKK: ${c2}kKx..dd ${c1}lKd${c2}   'OK:\ndKK${c1}KKKKKKKKKOx0KKKd ${c2}^0KKKO' ${c1}kKKc${c2}   dKd\ndKK${c1}KKKKKKKKKK;.;oOKx,..${c2}^${c1}..;kKKK0.${c2}  dKd\n:KK${c1}KKKKKKKKKK0o;...^cdxxOK0O/^^'  ${c2}.0K:\n kKK${c1}KKKKKKKKKKKKK0x;,,......,;od  ${c2}lKk\n '0K${c1}KKKKKKKKKKKKKKKKKKKK00KKOo^  ${c2}c00'\n  'kK${c1}KKOxddxkOO00000Okxoc;''   ${c2}.dKk'\n    l0Ko.                    .c00l'\n     'l0Kk:.              .;xK0l'\n        'lkK0xl:;,,,,;:ldO0kl'\n            '^:ldxkkkkxdl:^'\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 38,
+    },
+    Logo {
+        name: "openSUSE Leap",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m                 `-++:`\n               ./oooooo/-\n            `:oooooooooooo:.\n          -+oooooooooooooooo+-`\n       ./oooooooooooooooooooooo/-\n      :oooooooooooooooooooooooooo:\n    `  `-+oooooooooooooooooooo/-   `\n `:oo/-   .:ooooooooooooooo+:`  `-+oo/.\n`/oooooo:.   -/oooooooooo/.   ./oooooo/.\n  `:+ooooo+-`  `:+oooo+-   `:oooooo+:`\n     .:oooooo/.   .::`   -+oooooo/.\n        -/oooooo:.    ./oooooo+-\n          `:+ooooo+-:+oooooo:`\n             ./oooooooooo/.\n                -/oooo+:`\n                  `:/.\n\x1b[0m",
+        raw_art: "${c2}                 `-++:`\n               ./oooooo/-\n            `:oooooooooooo:.\n          -+oooooooooooooooo+-`\n       ./oooooooooooooooooooooo/-\n      :oooooooooooooooooooooooooo:\n    `  `-+oooooooooooooooooooo/-   `\n `:oo/-   .:ooooooooooooooo+:`  `-+oo/.\n`/oooooo:.   -/oooooooooo/.   ./oooooo/.\n  `:+ooooo+-`  `:+oooo+-   `:oooooo+:`\n     .:oooooo/.   .::`   -+oooooo/.\n        -/oooooo:.    ./oooooo+-\n          `:+ooooo+-:+oooooo:`\n             ./oooooooooo/.\n                -/oooo+:`\n                  `:/.\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
+    Logo {
+        name: "openSUSE Tumbleweed",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;37m                                     ......\n     .,cdxxxoc,.               .:kKMMMNWMMMNk:.\n    cKMMN0OOOKWMMXo. ;        ;0MWk:.      .:OMMk.\n  ;WMK;.       .lKMMNM,     :NMK,             .OMW;\n cMW;            'WMMMN   ,XMK,                 oMM'\n.MMc               ..;l. xMN:                    KM0\n'MM.                   'NMO                      oMM\n.MM,                 .kMMl                       xMN\n KM0               .kMM0. .dl:,..               .WMd\n .XM0.           ,OMMK,    OMMMK.              .XMK\n   oWMO:.    .;xNMMk,       NNNMKl.          .xWMx\n     :ONMMNXMMMKx;          .  ,xNMWKkxllox0NMWk,\n         .....                    .:dOOXXKOxl,\n\x1b[0m",
+        raw_art: "${c2}                                     ......\n     .,cdxxxoc,.               .:kKMMMNWMMMNk:.\n    cKMMN0OOOKWMMXo. ;        ;0MWk:.      .:OMMk.\n  ;WMK;.       .lKMMNM,     :NMK,             .OMW;\n cMW;            'WMMMN   ,XMK,                 oMM'\n.MMc               ..;l. xMN:                    KM0\n'MM.                   'NMO                      oMM\n.MM,                 .kMMl                       xMN\n KM0               .kMM0. .dl:,..               .WMd\n .XM0.           ,OMMK,    OMMMK.              .XMK\n   oWMO:.    .;xNMMk,       NNNMKl.          .xWMx\n     :ONMMNXMMMKx;          .  ,xNMWKkxllox0NMWk,\n         .....                    .:dOOXXKOxl,\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 52,
+    },
     Logo {
         name: "openSUSE_Leap",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                 `-++:`\n               ./oooooo/-\n            `:oooooooooooo:.\n          -+oooooooooooooooo+-`\n       ./oooooooooooooooooooooo/-\n      :oooooooooooooooooooooooooo:\n    `  `-+oooooooooooooooooooo/-   `\n `:oo/-   .:ooooooooooooooo+:`  `-+oo/.\n`/oooooo:.   -/oooooooooo/.   ./oooooo/.\n  `:+ooooo+-`  `:+oooo+-   `:oooooo+:`\n     .:oooooo/.   .::`   -+oooooo/.\n        -/oooooo:.    ./oooooo+-\n          `:+ooooo+-:+oooooo:`\n             ./oooooooooo/.\n                -/oooo+:`\n                  `:/.\n\x1b[0m",
+        raw_art: "${c2}                 `-++:`\n               ./oooooo/-\n            `:oooooooooooo:.\n          -+oooooooooooooooo+-`\n       ./oooooooooooooooooooooo/-\n      :oooooooooooooooooooooooooo:\n    `  `-+oooooooooooooooooooo/-   `\n `:oo/-   .:ooooooooooooooo+:`  `-+oo/.\n`/oooooo:.   -/oooooooooo/.   ./oooooo/.\n  `:+ooooo+-`  `:+oooo+-   `:oooooo+:`\n     .:oooooo/.   .::`   -+oooooo/.\n        -/oooooo:.    ./oooooo+-\n          `:+ooooo+-:+oooooo:`\n             ./oooooooooo/.\n                -/oooo+:`\n                  `:/.\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 40,
     },
     Logo {
         name: "openSUSE_Tumbleweed",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m                                     ......\n     .,cdxxxoc,.               .:kKMMMNWMMMNk:.\n    cKMMN0OOOKWMMXo. ;        ;0MWk:.      .:OMMk.\n  ;WMK;.       .lKMMNM,     :NMK,             .OMW;\n cMW;            'WMMMN   ,XMK,                 oMM'\n.MMc               ..;l. xMN:                    KM0\n'MM.                   'NMO                      oMM\n.MM,                 .kMMl                       xMN\n KM0               .kMM0. .dl:,..               .WMd\n .XM0.           ,OMMK,    OMMMK.              .XMK\n   oWMO:.    .;xNMMk,       NNNMKl.          .xWMx\n     :ONMMNXMMMKx;          .  ,xNMWKkxllox0NMWk,\n         .....                    .:dOOXXKOxl,\n\x1b[0m",
+        raw_art: "${c2}                                     ......\n     .,cdxxxoc,.               .:kKMMMNWMMMNk:.\n    cKMMN0OOOKWMMXo. ;        ;0MWk:.      .:OMMk.\n  ;WMK;.       .lKMMNM,     :NMK,             .OMW;\n cMW;            'WMMMN   ,XMK,                 oMM'\n.MMc               ..;l. xMN:                    KM0\n'MM.                   'NMO                      oMM\n.MM,                 .kMMl                       xMN\n KM0               .kMM0. .dl:,..               .WMd\n .XM0.           ,OMMK,    OMMMK.              .XMK\n   oWMO:.    .;xNMMk,       NNNMKl.          .xWMx\n     :ONMMNXMMMKx;          .  ,xNMWKkxllox0NMWk,\n         .....                    .:dOOXXKOxl,\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 52,
     },
     Logo {
         name: "openbsd_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;33m      _____\n    \\\\-     -/\n \\\\_/         \\\\\n |        \x1b[1;37mO O\x1b[1;33m |\n |_  <   )  3 )\n / \\\\         /\n    /-_____-\\\\\n\x1b[0m",
+        raw_art: "${c1}      _____\n    \\\\-     -/\n \\\\_/         \\\\\n |        ${c2}O O${c1} |\n |_  <   )  3 )\n / \\\\         /\n    /-_____-\\\\\n",
+        colors: &[Color::Palette(3), Color::Palette(7), Color::Palette(6), Color::Palette(1), Color::Palette(8)],
         max_line_length: 16,
     },
     Logo {
         name: "openmamba",
         is_wildcard: true,
-        ascii_art: "\x1b[1;37m                 `````\n           .-/+ooooooooo+/:-`\n        ./ooooooooooooooooooo+:.\n      -+oooooooooooooooooooooooo+-\n    .+ooooooooo+/:---::/+ooooooooo+.\n   :oooooooo/-`          `-/oo\x1b[1;32ms\u{00c2}\u{00b4}\x1b[1;37moooo.\x1b[1;32ms\u{00c2}\u{00b4}\x1b[1;37m\n  :ooooooo/`                `\x1b[1;32msNds\x1b[1;37mooo\x1b[1;32msNds\x1b[1;37m\n -ooooooo-                   \x1b[1;32m:dmy\x1b[1;37mooo\x1b[1;32m:dmy\x1b[1;37m\n +oooooo:                      :oooooo-\n.ooooooo                        .://:`\n:oooooo+                        ./+o+:`\n-ooooooo`                      `oooooo+\n`ooooooo:                      /oooooo+\n -ooooooo:                    :ooooooo.\n  :ooooooo+.                .+ooooooo:\n   :oooooooo+-`          `-+oooooooo:\n    .+ooooooooo+/::::://oooooooooo+.\n      -+oooooooooooooooooooooooo+-\n        .:ooooooooooooooooooo+:.\n           `-:/ooooooooo+/:.`\n                 ``````\n\x1b[0m",
+        ascii_art: "\x1b[1;37m                 `````\n           .-/+ooooooooo+/:-`\n        ./ooooooooooooooooooo+:.\n      -+oooooooooooooooooooooooo+-\n    .+ooooooooo+/:---::/+ooooooooo+.\n   :oooooooo/-`          `-/oo\x1b[1;32ms\u{00b4}\x1b[1;37moooo.\x1b[1;32ms\u{00b4}\x1b[1;37m\n  :ooooooo/`                `\x1b[1;32msNds\x1b[1;37mooo\x1b[1;32msNds\x1b[1;37m\n -ooooooo-                   \x1b[1;32m:dmy\x1b[1;37mooo\x1b[1;32m:dmy\x1b[1;37m\n +oooooo:                      :oooooo-\n.ooooooo                        .://:`\n:oooooo+                        ./+o+:`\n-ooooooo`                      `oooooo+\n`ooooooo:                      /oooooo+\n -ooooooo:                    :ooooooo.\n  :ooooooo+.                .+ooooooo:\n   :oooooooo+-`          `-+oooooooo:\n    .+ooooooooo+/::::://oooooooooo+.\n      -+oooooooooooooooooooooooo+-\n        .:ooooooooooooooooooo+:.\n           `-:/ooooooooo+/:.`\n                 ``````\n\x1b[0m",
+        raw_art: "${c1}                 `````\n           .-/+ooooooooo+/:-`\n        ./ooooooooooooooooooo+:.\n      -+oooooooooooooooooooooooo+-\n    .+ooooooooo+/:---::/+ooooooooo+.\n   :oooooooo/-`          `-/oo${c2}s\u{00b4}${c1}oooo.${c2}s\u{00b4}${c1}\n  :ooooooo/`                `${c2}sNds${c1}ooo${c2}sNds${c1}\n -ooooooo-                   ${c2}:dmy${c1}ooo${c2}:dmy${c1}\n +oooooo:                      :oooooo-\n.ooooooo                        .://:`\n:oooooo+                        ./+o+:`\n-ooooooo`                      `oooooo+\n`ooooooo:                      /oooooo+\n -ooooooo:                    :ooooooo.\n  :ooooooo+.                .+ooooooo:\n   :oooooooo+-`          `-+oooooooo:\n    .+ooooooooo+/::::://oooooooooo+.\n      -+oooooooooooooooooooooooo+-\n        .:ooooooooooooooooooo+:.\n           `-:/ooooooooo+/:.`\n                 ``````\n",
+        colors: &[Color::Palette(7), Color::Palette(2)],
         max_line_length: 40,
     },
+    Logo {
+        name: "opensuse_small",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;32m  _______\n__|   __ \\\\\n     / .\\\\ \\\\\n     \\\\__/ |\n   _______|\n   \\\\_______\n__________/\n\x1b[0m",
+        raw_art: "${c1}  _______\n__|   __ \\\\\n     / .\\\\ \\\\\n     \\\\__/ |\n   _______|\n   \\\\_______\n__________/\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
+        max_line_length: 13,
+    },
     Logo {
         name: "osmc",
         is_wildcard: false,
         ascii_art: "\x1b[1;34m            -+shdmNNNNmdhs+-\n        .+hMNho/:..``..:/ohNMh+.\n      :hMdo.                .odMh:\n    -dMy-                      -yMd-\n   sMd-                          -dMs\n  hMy       +.            .+       yMh\n yMy        dMs.        .sMd        yMy\n:Mm         dMNMs`    `sMNMd        `mM:\nyM+         dM//mNs``sNm//Md         +My\nmM-         dM:  +NNNN+  :Md         -Mm\nmM-         dM: `oNN+    :Md         -Mm\nyM+         dM/+NNo`     :Md         +My\n:Mm`        dMMNs`       :Md        `mM:\n yMy        dMs`         -ms        yMy\n  hMy       +.                     yMh\n   sMd-                          -dMs\n    -dMy-                      -yMd-\n      :hMdo.                .odMh:\n        .+hMNho/:..``..:/ohNMh+.\n            -+shdmNNNNmdhs+-\n\x1b[0m",
+        raw_art: "${c1}            -+shdmNNNNmdhs+-\n        .+hMNho/:..``..:/ohNMh+.\n      :hMdo.                .odMh:\n    -dMy-                      -yMd-\n   sMd-                          -dMs\n  hMy       +.            .+       yMh\n yMy        dMs.        .sMd        yMy\n:Mm         dMNMs`    `sMNMd        `mM:\nyM+         dM//mNs``sNm//Md         +My\nmM-         dM:  +NNNN+  :Md         -Mm\nmM-         dM: `oNN+    :Md         -Mm\nyM+         dM/+NNo`     :Md         +My\n:Mm`        dMMNs`       :Md        `mM:\n yMy        dMs`         -ms        yMy\n  hMy       +.                     yMh\n   sMd-                          -dMs\n    -dMy-                      -yMd-\n      :hMdo.                .odMh:\n        .+hMNho/:..``..:/ohNMh+.\n            -+shdmNNNNmdhs+-\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "parabola_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;35m  __ __ __  _\n.`_//_//_/ / `.\n          /  .`\n         / .`\n        /.`\n       /`\n\x1b[0m",
+        raw_art: "${c1}  __ __ __  _\n.`_//_//_/ / `.\n          /  .`\n         / .`\n        /.`\n       /`\n",
+        colors: &[Color::Palette(5), Color::Palette(7)],
         max_line_length: 15,
     },
     Logo {
         name: "pop_os",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m             /////////////\n         /////////////////////\n      ///////\x1b[1;37m*767\x1b[1;36m////////////////\n    //////\x1b[1;37m7676767676*\x1b[1;36m//////////////\n   /////\x1b[1;37m76767\x1b[1;36m//\x1b[1;37m7676767\x1b[1;36m//////////////\n  /////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m*76767\x1b[1;36m///////////////\n ///////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m76767\x1b[1;36m.///\x1b[1;37m7676*\x1b[1;36m///////\n/////////\x1b[1;37m767676\x1b[1;36m//\x1b[1;37m76767\x1b[1;36m///\x1b[1;37m767676\x1b[1;36m////////\n//////////\x1b[1;37m76767676767\x1b[1;36m////\x1b[1;37m76767\x1b[1;36m/////////\n///////////\x1b[1;37m76767676\x1b[1;36m//////\x1b[1;37m7676\x1b[1;36m//////////\n////////////,\x1b[1;37m7676\x1b[1;36m,///////\x1b[1;37m767\x1b[1;36m///////////\n/////////////*\x1b[1;37m7676\x1b[1;36m///////\x1b[1;37m76\x1b[1;36m////////////\n///////////////\x1b[1;37m7676\x1b[1;36m////////////////////\n ///////////////\x1b[1;37m7676\x1b[1;36m///\x1b[1;37m767\x1b[1;36m////////////\n  //////////////////////\x1b[1;37m'\x1b[1;36m////////////\n   //////\x1b[1;37m.7676767676767676767,\x1b[1;36m//////\n    /////\x1b[1;37m767676767676767676767\x1b[1;36m/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n\x1b[0m",
+        raw_art: "${c1}             /////////////\n         /////////////////////\n      ///////${c2}*767${c1}////////////////\n    //////${c2}7676767676*${c1}//////////////\n   /////${c2}76767${c1}//${c2}7676767${c1}//////////////\n  /////${c2}767676${c1}///${c2}*76767${c1}///////////////\n ///////${c2}767676${c1}///${c2}76767${c1}.///${c2}7676*${c1}///////\n/////////${c2}767676${c1}//${c2}76767${c1}///${c2}767676${c1}////////\n//////////${c2}76767676767${c1}////${c2}76767${c1}/////////\n///////////${c2}76767676${c1}//////${c2}7676${c1}//////////\n////////////,${c2}7676${c1},///////${c2}767${c1}///////////\n/////////////*${c2}7676${c1}///////${c2}76${c1}////////////\n///////////////${c2}7676${c1}////////////////////\n ///////////////${c2}7676${c1}///${c2}767${c1}////////////\n  //////////////////////${c2}'${c1}////////////\n   //////${c2}.7676767676767676767,${c1}//////\n    /////${c2}767676767676767676767${c1}/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 39,
     },
     Logo {
         name: "pop_os_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;36m______\n\\\\   _ \\\\        __\n \\\\ \\\\ \\\\ \\\\      / /\n  \\\\ \\\\_\\\\ \\\\    / /\n   \\\\  ___\\\\  /_/\n    \\\\ \\\\    _\n   __\\\\_\\\\__(_)_\n  (___________)`\n\x1b[0m",
+        raw_art: "${c1}______\n\\\\   _ \\\\        __\n \\\\ \\\\ \\\\ \\\\      / /\n  \\\\ \\\\_\\\\ \\\\    / /\n   \\\\  ___\\\\  /_/\n    \\\\ \\\\    _\n   __\\\\_\\\\__(_)_\n  (___________)`\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
+        max_line_length: 21,
+    },
+    Logo {
+        name: "popos",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;36m             /////////////\n         /////////////////////\n      ///////\x1b[1;37m*767\x1b[1;36m////////////////\n    //////\x1b[1;37m7676767676*\x1b[1;36m//////////////\n   /////\x1b[1;37m76767\x1b[1;36m//\x1b[1;37m7676767\x1b[1;36m//////////////\n  /////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m*76767\x1b[1;36m///////////////\n ///////\x1b[1;37m767676\x1b[1;36m///\x1b[1;37m76767\x1b[1;36m.///\x1b[1;37m7676*\x1b[1;36m///////\n/////////\x1b[1;37m767676\x1b[1;36m//\x1b[1;37m76767\x1b[1;36m///\x1b[1;37m767676\x1b[1;36m////////\n//////////\x1b[1;37m76767676767\x1b[1;36m////\x1b[1;37m76767\x1b[1;36m/////////\n///////////\x1b[1;37m76767676\x1b[1;36m//////\x1b[1;37m7676\x1b[1;36m//////////\n////////////,\x1b[1;37m7676\x1b[1;36m,///////\x1b[1;37m767\x1b[1;36m///////////\n/////////////*\x1b[1;37m7676\x1b[1;36m///////\x1b[1;37m76\x1b[1;36m////////////\n///////////////\x1b[1;37m7676\x1b[1;36m////////////////////\n ///////////////\x1b[1;37m7676\x1b[1;36m///\x1b[1;37m767\x1b[1;36m////////////\n  //////////////////////\x1b[1;37m'\x1b[1;36m////////////\n   //////\x1b[1;37m.7676767676767676767,\x1b[1;36m//////\n    /////\x1b[1;37m767676767676767676767\x1b[1;36m/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n\x1b[0m",
+        raw_art: "${c1}             /////////////\n         /////////////////////\n      ///////${c2}*767${c1}////////////////\n    //////${c2}7676767676*${c1}//////////////\n   /////${c2}76767${c1}//${c2}7676767${c1}//////////////\n  /////${c2}767676${c1}///${c2}*76767${c1}///////////////\n ///////${c2}767676${c1}///${c2}76767${c1}.///${c2}7676*${c1}///////\n/////////${c2}767676${c1}//${c2}76767${c1}///${c2}767676${c1}////////\n//////////${c2}76767676767${c1}////${c2}76767${c1}/////////\n///////////${c2}76767676${c1}//////${c2}7676${c1}//////////\n////////////,${c2}7676${c1},///////${c2}767${c1}///////////\n/////////////*${c2}7676${c1}///////${c2}76${c1}////////////\n///////////////${c2}7676${c1}////////////////////\n ///////////////${c2}7676${c1}///${c2}767${c1}////////////\n  //////////////////////${c2}'${c1}////////////\n   //////${c2}.7676767676767676767,${c1}//////\n    /////${c2}767676767676767676767${c1}/////\n      ///////////////////////////\n         /////////////////////\n             /////////////\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
+        max_line_length: 39,
+    },
+    Logo {
+        name: "popos_small",
+        is_wildcard: true,
+        ascii_art: "\x1b[1;36m______\n\\\\   _ \\\\        __\n \\\\ \\\\ \\\\ \\\\      / /\n  \\\\ \\\\_\\\\ \\\\    / /\n   \\\\  ___\\\\  /_/\n    \\\\ \\\\    _\n   __\\\\_\\\\__(_)_\n  (___________)`\n\x1b[0m",
+        raw_art: "${c1}______\n\\\\   _ \\\\        __\n \\\\ \\\\ \\\\ \\\\      / /\n  \\\\ \\\\_\\\\ \\\\    / /\n   \\\\  ___\\\\  /_/\n    \\\\ \\\\    _\n   __\\\\_\\\\__(_)_\n  (___________)`\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 21,
     },
     Logo {
         name: "postmarketos_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;32m        /\\\\\n       /  \\\\\n      /    \\\\\n      \\\\__   \\\\\n    /\\\\__ \\\\  _\\\\\n   /   /  \\\\/ __\n  /   / ____/  \\\\\n /    \\\\ \\\\       \\\\\n/_____/ /________\\\\\n\x1b[0m",
+        raw_art: "${c1}        /\\\\\n       /  \\\\\n      /    \\\\\n      \\\\__   \\\\\n    /\\\\__ \\\\  _\\\\\n   /   /  \\\\/ __\n  /   / ____/  \\\\\n /    \\\\ \\\\       \\\\\n/_____/ /________\\\\\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 20,
     },
     Logo {
         name: "pureos_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m _____________\n|  _________  |\n| |         | |\n| |         | |\n| |_________| |\n|_____________|\n\x1b[0m",
+        raw_art: "${c1} _____________\n|  _________  |\n| |         | |\n| |         | |\n| |_________| |\n|_____________|\n",
+        colors: &[Color::Palette(2), Color::Palette(7), Color::Palette(7)],
         max_line_length: 15,
     },
+    Logo {
+        name: "redhat_old",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;31m             `.-..........`\n            `////////::.`-/.\n            -: ....-////////.\n            //:-::///////////`\n     `--::: `-://////////////:\n     //////-    ``.-:///////// .`\n     `://////:-.`    :///////::///:`\n       .-/////////:---/////////////:\n          .-://////////////////////.\n\x1b[1;37m         yMN+`.-\x1b[1;31m::///////////////-`\n\x1b[1;37m      .-`:NMMNMs`  `..-------..`\n       MN+/mMMMMMhoooyysshsss\nMMM    MMMMMMMMMMMMMMyyddMMM+\n MMMM   MMMMMMMMMMMMMNdyNMMh`     hyhMMM\n  MMMMMMMMMMMMMMMMyoNNNMMM+.   MMMMMMMM\n   MMNMMMNNMMMMMNM+ mhsMNyyyyMNMMMMsMM\n\x1b[0m",
+        raw_art: "${c1}             `.-..........`\n            `////////::.`-/.\n            -: ....-////////.\n            //:-::///////////`\n     `--::: `-://////////////:\n     //////-    ``.-:///////// .`\n     `://////:-.`    :///////::///:`\n       .-/////////:---/////////////:\n          .-://////////////////////.\n${c2}         yMN+`.-${c1}::///////////////-`\n${c2}      .-`:NMMNMs`  `..-------..`\n       MN+/mMMMMMhoooyysshsss\nMMM    MMMMMMMMMMMMMMyyddMMM+\n MMMM   MMMMMMMMMMMMMNdyNMMh`     hyhMMM\n  MMMMMMMMMMMMMMMMyoNNNMMM+.   MMMMMMMM\n   MMNMMMNNMMMMMNM+ mhsMNyyyyMNMMMMsMM\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
+        max_line_length: 40,
+    },
     Logo {
         name: "rhel",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n\x1b[0m",
+        raw_art: "${c1}           .MMM..:MMMMMMM\n          MMMMMMMMMMMMMMMMMM\n          MMMMMMMMMMMMMMMMMMMM.\n         MMMMMMMMMMMMMMMMMMMMMM\n        ,MMMMMMMMMMMMMMMMMMMMMM:\n        MMMMMMMMMMMMMMMMMMMMMMMM\n  .MMMM'  MMMMMMMMMMMMMMMMMMMMMM\n MMMMMM    `MMMMMMMMMMMMMMMMMMMM.\nMMMMMMMM      MMMMMMMMMMMMMMMMMM .\nMMMMMMMMM.       `MMMMMMMMMMMMM' MM.\nMMMMMMMMMMM.                     MMMM\n`MMMMMMMMMMMMM.                 ,MMMMM.\n `MMMMMMMMMMMMMMMMM.          ,MMMMMMMM.\n    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM:\n         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM\n            `MMMMMMMMMMMMMMMMMMMMMMMM:\n                ``MMMMMMMMMMMMMMMMM'\n",
+        colors: &[Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "rhel_old",
         is_wildcard: true,
         ascii_art: "\x1b[1;31m             `.-..........`\n            `////////::.`-/.\n            -: ....-////////.\n            //:-::///////////`\n     `--::: `-://////////////:\n     //////-    ``.-:///////// .`\n     `://////:-.`    :///////::///:`\n       .-/////////:---/////////////:\n          .-://////////////////////.\n\x1b[1;37m         yMN+`.-\x1b[1;31m::///////////////-`\n\x1b[1;37m      .-`:NMMNMs`  `..-------..`\n       MN+/mMMMMMhoooyysshsss\nMMM    MMMMMMMMMMMMMMyyddMMM+\n MMMM   MMMMMMMMMMMMMNdyNMMh`     hyhMMM\n  MMMMMMMMMMMMMMMMyoNNNMMM+.   MMMMMMMM\n   MMNMMMNNMMMMMNM+ mhsMNyyyyMNMMMMsMM\n\x1b[0m",
+        raw_art: "${c1}             `.-..........`\n            `////////::.`-/.\n            -: ....-////////.\n            //:-::///////////`\n     `--::: `-://////////////:\n     //////-    ``.-:///////// .`\n     `://////:-.`    :///////::///:`\n       .-/////////:---/////////////:\n          .-://////////////////////.\n${c2}         yMN+`.-${c1}::///////////////-`\n${c2}      .-`:NMMNMs`  `..-------..`\n       MN+/mMMMMMhoooyysshsss\nMMM    MMMMMMMMMMMMMMyyddMMM+\n MMMM   MMMMMMMMMMMMMNdyNMMh`     hyhMMM\n  MMMMMMMMMMMMMMMMyoNNNMMM+.   MMMMMMMM\n   MMNMMMNNMMMMMNM+ mhsMNyyyyMNMMMMsMM\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 40,
     },
     Logo {
         name: "rocky",
         is_wildcard: true,
         ascii_art: "\x1b[1;38;5;35m          __wgliliiligw_,\n       _williiiiiiliilililw,\n     _%iiiiiilililiiiiiiiiiii_\n   .Qliiiililiiiiiiililililiilm.\n  _iiiiiliiiiiililiiiiiiiiiiliil,\n .lililiiilililiiiilililililiiiii,\n_liiiiiiliiiiiiiliiiiiF{iiiiiilili,\njliililiiilililiiili@`  ~ililiiiiiL\niiiliiiiliiiiiiili>`      ~liililii\nliliiiliiilililii`         -9liiiil\niiiiiliiliiiiii~             \"4lili\n4ililiiiiilil~|      -w,       )4lf\n-liiiiililiF'       _liig,       )'\n )iiiliii@`       _QIililig,\n  )iiii>`       .Qliliiiililw\n   )<>~       .mliiiiiliiiiiil,\n            _gllilililiililii~\n           giliiiiiiiiiiiiT`\n          -^~$ililili@~~'\n\x1b[0m",
+        raw_art: "${c1}          __wgliliiligw_,\n       _williiiiiiliilililw,\n     _%iiiiiilililiiiiiiiiiii_\n   .Qliiiililiiiiiiililililiilm.\n  _iiiiiliiiiiililiiiiiiiiiiliil,\n .lililiiilililiiiilililililiiiii,\n_liiiiiiliiiiiiiliiiiiF{iiiiiilili,\njliililiiilililiiili@`  ~ililiiiiiL\niiiliiiiliiiiiiili>`      ~liililii\nliliiiliiilililii`         -9liiiil\niiiiiliiliiiiii~             \"4lili\n4ililiiiiilil~|      -w,       )4lf\n-liiiiililiF'       _liig,       )'\n )iiiliii@`       _QIililig,\n  )iiii>`       .Qliliiiililw\n   )<>~       .mliiiiiliiiiiil,\n            _gllilililiililii~\n           giliiiiiiiiiiiiT`\n          -^~$ililili@~~'\n",
+        colors: &[Color::Palette(35)],
         max_line_length: 35,
     },
     Logo {
         name: "rocky_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m    `-/+++++++++/-.`\n `-+++++++++++++++++-`\n.+++++++++++++++++++++.\n-+++++++++++++++++++++++.\n+++++++++++++++/-/+++++++\n+++++++++++++/.   ./+++++\n+++++++++++:.       ./+++\n+++++++++:`   `:/:`   .:/\n-++++++:`   .:+++++:`\n .+++-`   ./+++++++++:`\n  `-`   ./+++++++++++-\n       -+++++++++:-.`\n\x1b[0m",
+        raw_art: "${c1}    `-/+++++++++/-.`\n `-+++++++++++++++++-`\n.+++++++++++++++++++++.\n-+++++++++++++++++++++++.\n+++++++++++++++/-/+++++++\n+++++++++++++/.   ./+++++\n+++++++++++:.       ./+++\n+++++++++:`   `:/:`   .:/\n-++++++:`   .:+++++:`\n .+++-`   ./+++++++++:`\n  `-`   ./+++++++++++-\n       -+++++++++:-.`\n",
+        colors: &[Color::Palette(2)],
         max_line_length: 25,
     },
     Logo {
         name: "sabotage",
         is_wildcard: true,
         ascii_art: "\x1b[1;37m .|'''.|      |     '||''|.    ..|''||\n ||..  '     |||     ||   ||  .|'    ||\n  ''|||.    |  ||    ||'''|.  ||      ||\n.     '||  .''''|.   ||    || '|.     ||\n|'....|'  .|.  .||. .||...|'   ''|...|'\n\n|''||''|     |      ..|'''.|  '||''''|\n   ||       |||    .|'     '   ||  .\n   ||      |  ||   ||    ....  ||''|\n   ||     .''''|.  '|.    ||   ||\n  .||.   .|.  .||.  ''|...'|  .||.....|\n\x1b[0m",
+        raw_art: "${c2} .|'''.|      |     '||''|.    ..|''||\n ||..  '     |||     ||   ||  .|'    ||\n  ''|||.    |  ||    ||'''|.  ||      ||\n.     '||  .''''|.   ||    || '|.     ||\n|'....|'  .|.  .||. .||...|'   ''|...|'\n\n|''||''|     |      ..|'''.|  '||''''|\n   ||       |||    .|'     '   ||  .\n   ||      |  ||   ||    ....  ||''|\n   ||     .''''|.  '|.    ||   ||\n  .||.   .|.  .||.  ''|...'|  .||.....|\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 40,
     },
     Logo {
         name: "semc",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m            /\\\n     ______/  \\\n    /      |()| \x1b[1;38;5;8mE M C\n\x1b[1;32m   |   (-- |  |\n    \\   \\  |  |\n.----)   | |__|\n|_______/ / \x1b[1;31m\"\x1b[1;32m  \\\n              \x1b[1;31m\"\n            \"\n\x1b[0m",
+        raw_art: "${c1}            /\\\n     ______/  \\\n    /      |()| ${c2}E M C\n${c1}   |   (-- |  |\n    \\   \\  |  |\n.----)   | |__|\n|_______/ / ${c3}\"${c1}  \\\n              ${c3}\"\n            \"\n",
+        colors: &[Color::Palette(2), Color::Palette(8), Color::Palette(1)],
         max_line_length: 21,
     },
     Logo {
         name: "slackware_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m   ________\n  /  ______|\n  | |______\n  \\\\______  \\\\\n   ______| |\n| |________/\n|____________\n\x1b[0m",
+        raw_art: "${c1}   ________\n  /  ______|\n  | |______\n  \\\\______  \\\\\n   ______| |\n| |________/\n|____________\n",
+        colors: &[Color::Palette(4), Color::Palette(7), Color::Palette(1)],
         max_line_length: 14,
     },
     Logo {
         name: "solaris_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;33m       .   .;   .\n   .   :;  ::  ;:   .\n   .;. ..      .. .;.\n..  ..             ..  ..\n .;,                 ,;.\n\x1b[0m",
+        raw_art: "${c1}       .   .;   .\n   .   :;  ::  ;:   .\n   .;. ..      .. .;.\n..  ..             ..  ..\n .;,                 ,;.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
+        max_line_length: 25,
+    },
+    Logo {
+        name: "sunos_small",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;33m       .   .;   .\n   .   :;  ::  ;:   .\n   .;. ..      .. .;.\n..  ..             ..  ..\n .;,                 ,;.\n\x1b[0m",
+        raw_art: "${c1}       .   .;   .\n   .   :;  ::  ;:   .\n   .;. ..      .. .;.\n..  ..             ..  ..\n .;,                 ,;.\n",
+        colors: &[Color::Palette(3), Color::Palette(7)],
         max_line_length: 25,
     },
     Logo {
         name: "suse_small",
         is_wildcard: true,
         ascii_art: "\x1b[1;32m  _______\n__|   __ \\\\\n     / .\\\\ \\\\\n     \\\\__/ |\n   _______|\n   \\\\_______\n__________/\n\x1b[0m",
+        raw_art: "${c1}  _______\n__|   __ \\\\\n     / .\\\\ \\\\\n     \\\\__/ |\n   _______|\n   \\\\_______\n__________/\n",
+        colors: &[Color::Palette(2), Color::Palette(7)],
         max_line_length: 13,
     },
     Logo {
         name: "t2",
         is_wildcard: true,
         ascii_art: "\x1b[1;34m\nTTTTTTTTTT\n    tt   \x1b[1;37m222\x1b[1;34m\n    tt  \x1b[1;37m2   2\x1b[1;34m\n    tt     \x1b[1;37m2\x1b[1;34m\n    tt    \x1b[1;37m2\x1b[1;34m\n    tt  \x1b[1;37m22222\x1b[1;34m\n\x1b[0m",
+        raw_art: "${c2}\nTTTTTTTTTT\n    tt   ${c1}222${c2}\n    tt  ${c1}2   2${c2}\n    tt     ${c1}2${c2}\n    tt    ${c1}2${c2}\n    tt  ${c1}22222${c2}\n",
+        colors: &[Color::Palette(7), Color::Palette(4)],
         max_line_length: 13,
     },
     Logo {
         name: "ubuntu_old",
         is_wildcard: false,
         ascii_art: "\x1b[1;31m                         ./+o+-\n\x1b[1;37m                 yyyyy- \x1b[1;31m-yyyyyy+\n\x1b[1;37m              \x1b[1;37m://+//////\x1b[1;31m-yyyyyyo\n\x1b[1;33m          .++ \x1b[1;37m.:/++++++/-\x1b[1;31m.+sss/`\n\x1b[1;33m        .:++o:  \x1b[1;37m/++++++++/:--:/-\n\x1b[1;33m       o:+o+:++.\x1b[1;37m`..```.-/oo+++++/\n\x1b[1;33m      .:+o:+o/.\x1b[1;37m          `+sssoo+/\n\x1b[1;37m .++/+:\x1b[1;33m+oo+o:`\x1b[1;37m             /sssooo.\n\x1b[1;37m/+++//+:\x1b[1;33m`oo+o\x1b[1;37m               /::--:.\n\x1b[1;37m+/+o+++\x1b[1;33m`o++o\x1b[1;31m               ++////.\n\x1b[1;37m .++.o+\x1b[1;33m++oo+:`\x1b[1;31m             /dddhhh.\n\x1b[1;33m      .+.o+oo:.\x1b[1;31m          `oddhhhh+\n\x1b[1;33m       +.++o+o`\x1b[1;31m`-````.:ohdhhhhh+\n\x1b[1;33m        `:o+++ \x1b[1;31m`ohhhhhhhhyo++os:\n\x1b[1;33m          .o:\x1b[1;31m`.syhhhhhhh/\x1b[1;33m.oo++o`\n\x1b[1;31m              /osyyyyyyo\x1b[1;33m++ooo+++/\n\x1b[1;31m                  ````` \x1b[1;33m+oo+++o:\n\x1b[1;33m                         `oo++.\n\x1b[0m",
+        raw_art: "${c1}                         ./+o+-\n${c2}                 yyyyy- ${c1}-yyyyyy+\n${c2}              ${c2}://+//////${c1}-yyyyyyo\n${c3}          .++ ${c2}.:/++++++/-${c1}.+sss/`\n${c3}        .:++o:  ${c2}/++++++++/:--:/-\n${c3}       o:+o+:++.${c2}`..```.-/oo+++++/\n${c3}      .:+o:+o/.${c2}          `+sssoo+/\n${c2} .++/+:${c3}+oo+o:`${c2}             /sssooo.\n${c2}/+++//+:${c3}`oo+o${c2}               /::--:.\n${c2}+/+o+++${c3}`o++o${c1}               ++////.\n${c2} .++.o+${c3}++oo+:`${c1}             /dddhhh.\n${c3}      .+.o+oo:.${c1}          `oddhhhh+\n${c3}       +.++o+o`${c1}`-````.:ohdhhhhh+\n${c3}        `:o+++ ${c1}`ohhhhhhhhyo++os:\n${c3}          .o:${c1}`.syhhhhhhh/${c3}.oo++o`\n${c1}              /osyyyyyyo${c3}++ooo+++/\n${c1}                  ````` ${c3}+oo+++o:\n${c3}                         `oo++.\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 35,
     },
     Logo {
         name: "ubuntu_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;31m         _\n     ---(_)\n _/  ---  \\\\\n(_) |   |\n  \\\\  --- _/\n     ---(_)\n\x1b[0m",
+        raw_art: "${c1}         _\n     ---(_)\n _/  ---  \\\\\n(_) |   |\n  \\\\  --- _/\n     ---(_)\n",
+        colors: &[Color::Palette(1), Color::Palette(7), Color::Palette(3)],
         max_line_length: 12,
     },
     Logo {
         name: "void_small",
         is_wildcard: false,
         ascii_art: "\x1b[1;32m    _______\n _ \\\\______ -\n| \\\\  ___  \\\\ |\n| | /   \\ | |\n| | \\___/ | |\n| \\\\______ \\\\_|\n -_______\\\\\n\x1b[0m",
+        raw_art: "${c1}    _______\n _ \\\\______ -\n| \\\\  ___  \\\\ |\n| | /   \\ | |\n| | \\___/ | |\n| \\\\______ \\\\_|\n -_______\\\\\n",
+        colors: &[Color::Palette(2), Color::Palette(8)],
         max_line_length: 15,
     },
+    Logo {
+        name: "windows10",
+        is_wildcard: false,
+        ascii_art: "\x1b[1;36m                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n\x1b[0m",
+        raw_art: "${c1}                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
+        max_line_length: 36,
+    },
     Logo {
         name: "windows11",
         is_wildcard: false,
         ascii_art: "\x1b[1;36m\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\x1b[0m",
+        raw_art: "${c1}\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n################  ################\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 34,
     },
     Logo {
         name: "windows8",
         is_wildcard: false,
         ascii_art: "\x1b[1;36m                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n\x1b[0m",
+        raw_art: "${c1}                                ..,\n                    ....,,:;+ccllll\n      ...,,+:;  cllllllllllllllllll\n,cclllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\nllllllllllllll  lllllllllllllllllll\n`'ccllllllllll  lllllllllllllllllll\n       `' \\\\*::  :ccllllllllllllllll\n                       ````''*::cll\n                                 ``\n",
+        colors: &[Color::Palette(6), Color::Palette(7)],
         max_line_length: 36,
     },
 ];
 
 
 pub fn find_logo(distro_name: &str) -> Option<&'static Logo> {
-    // First try exact match for non-wildcard logos
-    if let Ok(idx) = LOGOS.binary_search_by(|logo| {
-        if logo.is_wildcard {
-            std::cmp::Ordering::Greater // Skip wildcards for binary search
-        } else {
-            logo.name.cmp(distro_name)
-        }
-    }) {
+    // First try an exact match; a wildcard entry matches its own name too
+    if let Ok(idx) = LOGOS.binary_search_by(|logo| logo.name.cmp(distro_name)) {
         return Some(&LOGOS[idx]);
     }
-    
-    // Then try prefix match for wildcard logos
+
+    // Then the same ignoring case, whitespace, '-' and '_'
+    if let Some(logo) = LOGOS.iter().find(|logo| normalized(logo.name).eq(normalized(distro_name))) {
+        return Some(logo);
+    }
+
+    // Then the longest wildcard that is a prefix, so "Ubuntu 24.04" finds
+    // "Ubuntu" and "Ubuntu Budgie 24.04" finds "Ubuntu Budgie"
     LOGOS.iter()
-        .find(|logo| logo.is_wildcard && distro_name.starts_with(&logo.name))
+        .filter(|logo| logo.is_wildcard && is_normalized_prefix(logo.name, distro_name))
+        .max_by_key(|logo| logo.name.len())
+}
+
+fn normalized(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+}
+
+fn is_normalized_prefix(prefix: &str, name: &str) -> bool {
+    let mut name = normalized(name);
+    normalized(prefix).all(|c| name.next() == Some(c))
 }
@@ -0,0 +1,75 @@
+//! Now playing
+//! Title and artist of the track in MPRIS media players, asked over the
+//! session bus
+
+use crate::dbus::{self, Connection};
+
+const PLAYER_PREFIX: &str = "org.mpris.MediaPlayer2.";
+const PATH: &str = "/org/mpris/MediaPlayer2";
+const PLAYER: &str = "org.mpris.MediaPlayer2.Player";
+
+/// e.g. `Artist - Title`, from a playing player if there is one, otherwise a
+/// paused one marked `(paused)`
+pub fn now_playing() -> Option<String> {
+    let mut conn = Connection::session().ok()?;
+    let mut names = dbus::list_names(&mut conn).ok()?;
+    names.retain(|name| name.starts_with(PLAYER_PREFIX));
+    names.sort();
+
+    let mut paused = None;
+    for name in names {
+        let Some(status) = playback_status(&mut conn, &name) else {
+            continue;
+        };
+        if status == "Stopped" {
+            continue;
+        }
+        let Some(track) = track(&mut conn, &name) else {
+            continue;
+        };
+        if status == "Playing" {
+            return Some(track);
+        }
+        paused.get_or_insert_with(|| format!("{track} (paused)"));
+    }
+    paused
+}
+
+fn playback_status(conn: &mut Connection, player: &str) -> Option<String> {
+    let reply = dbus::get_property(conn, player, PATH, PLAYER, "PlaybackStatus").ok()?;
+    let mut reader = reply.reader();
+    (reader.signature()? == "s").then_some(())?;
+    reader.string().map(str::to_string)
+}
+
+/// `Artist - Title` from the `Metadata` dictionary
+fn track(conn: &mut Connection, player: &str) -> Option<String> {
+    let reply = dbus::get_property(conn, player, PATH, PLAYER, "Metadata").ok()?;
+    let mut reader = reply.reader();
+    if reader.signature()? != "a{sv}" {
+        return None;
+    }
+
+    let mut title = None;
+    let mut artists = Vec::new();
+    let end = reader.array_end(8)?;
+    while reader.pos < end {
+        reader.align(8);
+        let key = reader.string()?;
+        let signature = reader.signature()?;
+        match (key, signature) {
+            ("xesam:title", "s") => title = reader.string(),
+            ("xesam:artist", "as") => artists = reader.strings()?,
+            _ => {
+                reader.skip(signature)?;
+            }
+        }
+    }
+
+    let title = title.filter(|t| !t.is_empty())?;
+    Some(if artists.is_empty() {
+        title.to_string()
+    } else {
+        format!("{} - {title}", artists.join(", "))
+    })
+}
@@ -0,0 +1,125 @@
+//! Memory topology and tuning details
+//! NUMA nodes from `/sys/devices/system/node` and hugepage settings from
+//! `/sys/kernel/mm`
+
+use crate::source;
+use crate::utils::format_memory;
+use std::path::Path;
+
+/// Node count and memory per node, e.g. `2 nodes (node0: 32097 MiB, ...)`
+///
+/// Returns `None` on single-node machines, where there is nothing to report.
+pub fn numa_summary() -> Option<String> {
+    let mut nodes: Vec<(u32, u64)> = source::current()
+        .read_dir(Path::new("/sys/devices/system/node"))
+        .ok()?
+        .into_iter()
+        .filter_map(|path| {
+            let id = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()?;
+            Some((id, node_memory(id).unwrap_or(0)))
+        })
+        .collect();
+
+    if nodes.len() < 2 {
+        return None;
+    }
+    nodes.sort_unstable();
+
+    let per_node = nodes
+        .iter()
+        .map(|(id, bytes)| format!("node{id}: {}", format_memory(*bytes)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("{} nodes ({per_node})", nodes.len()))
+}
+
+/// `MemTotal` of a node in bytes
+fn node_memory(id: u32) -> Option<u64> {
+    let meminfo = source::current()
+        .read_to_string(Path::new(&format!(
+            "/sys/devices/system/node/node{id}/meminfo"
+        )))
+        .ok()?;
+    // Lines look like `Node 0 MemTotal:       16318412 kB`
+    let line = meminfo.lines().find(|line| line.contains("MemTotal:"))?;
+    let kb: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Transparent hugepage mode and reserved hugepages, e.g.
+/// `THP madvise, defrag madvise, 512 x 2 MiB reserved (128 free)`
+pub fn hugepages_summary() -> String {
+    let mut parts = Vec::new();
+
+    let thp = "/sys/kernel/mm/transparent_hugepage";
+    if let Some(mode) = selected_choice(&format!("{thp}/enabled")) {
+        parts.push(format!("THP {mode}"));
+    }
+    if let Some(defrag) = selected_choice(&format!("{thp}/defrag")) {
+        parts.push(format!("defrag {defrag}"));
+    }
+
+    let mut pools: Vec<(u64, u64, u64)> = source::current()
+        .read_dir(Path::new("/sys/kernel/mm/hugepages"))
+        .into_iter()
+        .flatten()
+        .filter_map(|path| {
+            // Directories are named like `hugepages-2048kB`
+            let size_kb = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("hugepages-")?
+                .strip_suffix("kB")?
+                .parse()
+                .ok()?;
+            let read = |file| {
+                source::current()
+                    .read_to_string(&path.join(file))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<u64>().ok())
+            };
+            Some((
+                size_kb,
+                read("nr_hugepages")?,
+                read("free_hugepages").unwrap_or(0),
+            ))
+        })
+        .filter(|&(_, total, _)| total > 0)
+        .collect();
+    pools.sort_unstable();
+
+    if pools.is_empty() {
+        parts.push("no hugepages reserved".to_string());
+    }
+    for (size_kb, total, free) in pools {
+        parts.push(format!(
+            "{total} x {} reserved ({free} free)",
+            format_page_size(size_kb)
+        ));
+    }
+
+    parts.join(", ")
+}
+
+/// The bracketed entry of a sysfs choice list like `always [madvise] never`
+fn selected_choice(path: &str) -> Option<String> {
+    let choices = source::current().read_to_string(Path::new(path)).ok()?;
+    let start = choices.find('[')? + 1;
+    let len = choices[start..].find(']')?;
+    Some(choices[start..start + len].to_string())
+}
+
+fn format_page_size(kb: u64) -> String {
+    if kb >= 1 << 20 && kb.is_multiple_of(1 << 20) {
+        format!("{} GiB", kb >> 20)
+    } else if kb >= 1 << 10 && kb.is_multiple_of(1 << 10) {
+        format!("{} MiB", kb >> 10)
+    } else {
+        format!("{kb} KiB")
+    }
+}
@@ -0,0 +1,136 @@
+//! Prometheus exposition output
+//! Numeric values as gauges in the text format, for the node-exporter
+//! textfile collector to pick up from a cron-run fetch
+
+use crate::battery;
+use crate::config::Config;
+use crate::os::SysInfo;
+use std::fmt::Write;
+
+/// Every metric this program knows about, whatever modules are shown
+pub fn prometheus(config: &Config, info: &SysInfo) -> String {
+    let mut out = Metrics::default();
+
+    let mut labels = vec![
+        ("os", info.os_name.as_str()),
+        ("kernel", info.kernel.as_str()),
+    ];
+    if !config.privacy {
+        labels.push(("hostname", info.hostname.as_str()));
+    }
+    out.gauge("info", "System description, always 1", &[(&labels, 1.0)]);
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+        out.gauge(
+            "uptime_seconds",
+            "Time since boot",
+            &[(&[], info.uptime as f64)],
+        );
+        out.gauge(
+            "memory_used_bytes",
+            "Memory in use",
+            &[(&[], info.memory_used as f64)],
+        );
+        out.gauge(
+            "memory_total_bytes",
+            "Usable memory",
+            &[(&[], info.memory_total as f64)],
+        );
+    }
+    if let Some(pressure) = info.memory_pressure {
+        out.gauge(
+            "memory_pressure_percent",
+            "Share of time tasks stalled on memory, 10s average",
+            &[(&[], f64::from(pressure))],
+        );
+    }
+
+    // Collected whether or not the Disk and Packages lines are shown
+    let disks = &info.disks;
+    let disk_labels: Vec<[(&str, &str); 2]> = disks
+        .iter()
+        .map(|d| [("mount", d.mount.as_str()), ("fstype", d.fstype.as_str())])
+        .collect();
+    #[allow(clippy::cast_precision_loss)]
+    {
+        let used: Vec<_> = disk_labels
+            .iter()
+            .zip(disks)
+            .map(|(labels, d)| (&labels[..], d.used as f64))
+            .collect();
+        let total: Vec<_> = disk_labels
+            .iter()
+            .zip(disks)
+            .map(|(labels, d)| (&labels[..], d.total as f64))
+            .collect();
+        out.gauge("disk_used_bytes", "Space used on the filesystem", &used);
+        out.gauge("disk_total_bytes", "Size of the filesystem", &total);
+    }
+
+    let batteries = battery::capacities();
+    let battery_labels: Vec<[(&str, &str); 1]> = batteries
+        .iter()
+        .map(|(name, _)| [("battery", name.as_str())])
+        .collect();
+    let charge: Vec<_> = battery_labels
+        .iter()
+        .zip(&batteries)
+        .map(|(labels, (_, capacity))| (&labels[..], f64::from(*capacity)))
+        .collect();
+    out.gauge("battery_percent", "Battery charge", &charge);
+
+    let counts = &info.packages;
+    let package_labels: Vec<[(&str, &str); 1]> = counts
+        .iter()
+        .map(|(name, _)| [("manager", *name)])
+        .collect();
+    #[allow(clippy::cast_precision_loss)]
+    let installed: Vec<_> = package_labels
+        .iter()
+        .zip(counts)
+        .map(|(labels, (_, count))| (&labels[..], *count as f64))
+        .collect();
+    out.gauge(
+        "packages",
+        "Installed packages per package manager",
+        &installed,
+    );
+
+    out.text
+}
+
+#[derive(Default)]
+struct Metrics {
+    text: String,
+}
+
+impl Metrics {
+    /// Write one gauge family; families without samples are left out
+    fn gauge(&mut self, name: &str, help: &str, samples: &[(&[(&str, &str)], f64)]) {
+        if samples.is_empty() {
+            return;
+        }
+        let _ = writeln!(self.text, "# HELP tachifetch_{name} {help}");
+        let _ = writeln!(self.text, "# TYPE tachifetch_{name} gauge");
+        for (labels, value) in samples {
+            let _ = write!(self.text, "tachifetch_{name}");
+            if !labels.is_empty() {
+                let labels: Vec<String> = labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}=\"{}\"", escape(value)))
+                    .collect();
+                let _ = write!(self.text, "{{{}}}", labels.join(","));
+            }
+            let _ = writeln!(self.text, " {value}");
+        }
+    }
+}
+
+/// Label values escape backslashes, quotes and newlines
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,863 @@
+//! Info line modules
+//! Each module produces a line of the info block, disks and extras a few; the
+//! configured module list decides which lines are shown and in what order
+
+use crate::bar;
+use crate::chassis::Chassis;
+use crate::color::Color;
+use crate::config::{CgroupLimits, Config, DiskStyle, IconStyle, SeparatorStyle, TempUnit};
+use crate::disk::Disk;
+use crate::export::Format;
+use crate::extras::Extras;
+use crate::glyphs;
+use crate::greeting;
+use crate::hyperlink;
+use crate::os::{SysInfo, os_release};
+use crate::packages;
+use crate::thermal::Zone;
+use crate::utils::{
+    format_bytes, format_memory, format_uptime, format_uptime_words, get_env_var, percent,
+    sanitized,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Module {
+    Title,
+    Separator,
+    Os,
+    Kernel,
+    Uptime,
+    Boot,
+    SessionUptime,
+    InstallDate,
+    Packages,
+    Updates,
+    Shell,
+    Resolution,
+    Display,
+    De,
+    Wm,
+    Session,
+    Theme,
+    Icons,
+    Cursor,
+    Font,
+    GnomeExtensions,
+    Terminal,
+    Cpu,
+    Load,
+    Gpu,
+    Graphics,
+    Temps,
+    Fans,
+    Memory,
+    Disk,
+    Nvme,
+    Raid,
+    Encryption,
+    Numa,
+    Hugepages,
+    Network,
+    Wifi,
+    Sound,
+    Media,
+    Virtualization,
+    Preemption,
+    KernelModules,
+    Cmdline,
+    Security,
+    Systemd,
+    Containers,
+    Chassis,
+    MachineId,
+    Battery,
+    Weather,
+    Extras,
+    Greeting,
+    Colors,
+}
+
+/// Modules whose values an output needs; probes for the others are skipped
+#[derive(Clone, Copy)]
+pub struct Needs(u128);
+
+impl Needs {
+    pub fn of(modules: &[Module]) -> Self {
+        Self(
+            modules
+                .iter()
+                .fold(0, |bits, &module| bits | 1 << module as u32),
+        )
+    }
+
+    /// The configured lines, plus the values `format` reports whichever
+    /// lines are shown
+    pub fn for_output(config: &Config, format: Format) -> Self {
+        let mut needs = Self::of(&config.modules);
+        if format == Format::Prometheus {
+            needs.0 |= Self::of(&[Module::Memory, Module::Disk, Module::Packages]).0;
+        }
+        needs
+    }
+
+    pub const fn has(self, module: Module) -> bool {
+        self.0 & 1 << module as u32 != 0
+    }
+
+    /// Whether any value needs a graphical session to look at
+    pub fn graphical(self) -> bool {
+        self.0 & Self::of(GRAPHICAL).0 != 0
+    }
+
+    /// Without the graphical values, which a headless machine hasn't got
+    pub fn headless(self) -> Self {
+        Self(self.0 & !Self::of(GRAPHICAL).0)
+    }
+}
+
+/// Modules describing the graphical session; a headless machine shows a
+/// single `Display: headless` in their place
+static GRAPHICAL: &[Module] = &[
+    Module::Resolution,
+    Module::Display,
+    Module::De,
+    Module::Wm,
+    Module::Theme,
+    Module::Icons,
+    Module::Cursor,
+    Module::Font,
+    Module::GnomeExtensions,
+];
+
+/// Config names of all modules
+static MODULE_NAMES: &[(&str, Module)] = &[
+    ("title", Module::Title),
+    ("separator", Module::Separator),
+    ("os", Module::Os),
+    ("kernel", Module::Kernel),
+    ("uptime", Module::Uptime),
+    ("boot", Module::Boot),
+    ("session_uptime", Module::SessionUptime),
+    ("install_date", Module::InstallDate),
+    ("packages", Module::Packages),
+    ("updates", Module::Updates),
+    ("shell", Module::Shell),
+    ("resolution", Module::Resolution),
+    ("display", Module::Display),
+    ("de", Module::De),
+    ("wm", Module::Wm),
+    ("session", Module::Session),
+    ("theme", Module::Theme),
+    ("icons", Module::Icons),
+    ("cursor", Module::Cursor),
+    ("font", Module::Font),
+    ("gnome_extensions", Module::GnomeExtensions),
+    ("terminal", Module::Terminal),
+    ("cpu", Module::Cpu),
+    ("load", Module::Load),
+    ("gpu", Module::Gpu),
+    ("graphics", Module::Graphics),
+    ("temps", Module::Temps),
+    ("fans", Module::Fans),
+    ("memory", Module::Memory),
+    ("disk", Module::Disk),
+    ("nvme", Module::Nvme),
+    ("raid", Module::Raid),
+    ("encryption", Module::Encryption),
+    ("numa", Module::Numa),
+    ("hugepages", Module::Hugepages),
+    ("network", Module::Network),
+    ("wifi", Module::Wifi),
+    ("sound", Module::Sound),
+    ("media", Module::Media),
+    ("virtualization", Module::Virtualization),
+    ("preemption", Module::Preemption),
+    ("kernel_modules", Module::KernelModules),
+    ("cmdline", Module::Cmdline),
+    ("security", Module::Security),
+    ("systemd", Module::Systemd),
+    ("containers", Module::Containers),
+    ("chassis", Module::Chassis),
+    ("machine_id", Module::MachineId),
+    ("battery", Module::Battery),
+    ("weather", Module::Weather),
+    ("extras", Module::Extras),
+    ("greeting", Module::Greeting),
+    ("colors", Module::Colors),
+];
+
+impl Module {
+    /// Modules shown when the config doesn't list any
+    pub const DEFAULT: &[Self] = &[
+        Self::Title,
+        Self::Separator,
+        Self::Os,
+        Self::Kernel,
+        Self::Uptime,
+        Self::Shell,
+        Self::Resolution,
+        Self::De,
+        Self::Wm,
+        Self::Theme,
+        Self::Icons,
+        Self::Terminal,
+        Self::Cpu,
+        Self::Memory,
+        Self::Greeting,
+    ];
+
+    /// Modules for `--motd` when the config doesn't list any; values that go
+    /// stale by the time someone logs in are left out
+    pub const MOTD: &[Self] = &[
+        Self::Title,
+        Self::Separator,
+        Self::Os,
+        Self::Kernel,
+        Self::Boot,
+        Self::Cpu,
+        Self::Gpu,
+        Self::Memory,
+        Self::Disk,
+    ];
+
+    /// Default modules adapted to the kind of machine
+    pub fn defaults_for(chassis: Chassis) -> Vec<Self> {
+        let mut modules = Self::DEFAULT.to_vec();
+        let extra = match chassis {
+            Chassis::Laptop => Self::Battery,
+            Chassis::Server => Self::Numa,
+            _ => return modules,
+        };
+        if let Some(pos) = modules.iter().position(|&m| m == Self::Memory) {
+            modules.insert(pos + 1, extra);
+        }
+        modules
+    }
+
+    /// Name used for the module in the config
+    pub fn name(self) -> &'static str {
+        MODULE_NAMES
+            .iter()
+            .find(|(_, m)| *m == self)
+            .map_or("", |(n, _)| n)
+    }
+
+    pub fn is_graphical(self) -> bool {
+        GRAPHICAL.contains(&self)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        MODULE_NAMES
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, m)| *m)
+    }
+
+    /// Key printed in front of the value
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Title | Self::Separator | Self::Greeting | Self::Colors => "",
+            Self::Os => "OS",
+            Self::Kernel => "Kernel",
+            Self::Uptime => "Uptime",
+            Self::Boot => "Boot",
+            Self::SessionUptime => "Session Uptime",
+            Self::InstallDate => "Install Date",
+            Self::Packages => "Packages",
+            Self::Updates => "Updates",
+            Self::Shell => "Shell",
+            Self::Resolution => "Resolution",
+            Self::Display => "Display",
+            Self::De => "DE",
+            Self::Wm => "WM",
+            Self::Session => "Session",
+            Self::Theme => "Theme",
+            Self::Icons => "Icons",
+            Self::Cursor => "Cursor",
+            Self::Font => "Font",
+            Self::GnomeExtensions => "Extensions",
+            Self::Terminal => "Terminal",
+            Self::Cpu => "CPU",
+            Self::Load => "Load",
+            Self::Gpu => "GPU",
+            Self::Graphics => "Graphics",
+            Self::Temps => "Temps",
+            Self::Fans => "Fans",
+            Self::Memory => "Memory",
+            Self::Disk => "Disk",
+            Self::Nvme => "NVMe",
+            Self::Raid => "RAID",
+            Self::Encryption => "Encryption",
+            Self::Numa => "NUMA",
+            Self::Hugepages => "Hugepages",
+            Self::Network => "Network",
+            Self::Wifi => "Wi-Fi",
+            Self::Sound => "Sound",
+            Self::Media => "Media",
+            Self::Virtualization => "Virtualization",
+            Self::Preemption => "Preemption",
+            Self::KernelModules => "Kernel Modules",
+            Self::Cmdline => "Cmdline",
+            Self::Security => "Security",
+            Self::Systemd => "Systemd",
+            Self::Containers => "Containers",
+            Self::Chassis => "Chassis",
+            Self::MachineId => "Machine ID",
+            Self::Battery => "Battery",
+            Self::Weather => "Weather",
+            Self::Extras => "Extras",
+        }
+    }
+}
+
+/// A piece of the title: a template field, shown in the logo color, or the
+/// plain text around it
+pub struct TitlePart<'a> {
+    pub text: Cow<'a, str>,
+    pub field: bool,
+}
+
+/// A rendered-to-be line of the info block
+///
+/// Values borrow from the collected info where they can be shown as-is.
+pub enum Line<'a> {
+    /// `user@host` or the configured template, with the fields in the logo
+    /// color
+    Title(Vec<TitlePart<'a>>),
+    /// Underline below the title, printed without color
+    Separator(Cow<'a, str>),
+    /// Heading over a group of modules, in its own color or the key color
+    Header {
+        text: Cow<'a, str>,
+        color: Option<Color>,
+    },
+    /// `Key: value`, with the key in the logo color
+    Entry {
+        key: Cow<'a, str>,
+        value: Cow<'a, str>,
+    },
+    /// Free-form text, printed without color
+    Text(Cow<'a, str>),
+    /// A row of blocks in the scheme's colors, blank without color
+    Colors,
+}
+
+const SEPARATOR: &str = "-----------------";
+
+/// Build the info lines for the configured modules; `links` and `color` say
+/// whether values may hold hyperlinks and colors
+pub fn build_lines<'a>(
+    config: &Config,
+    info: &'a SysInfo,
+    links: bool,
+    color: bool,
+) -> Vec<Line<'a>> {
+    build_lines_with(config, info, links, color, |_, _| {})
+}
+
+/// `build_lines`, handing each module's lines to `annotate` before they're
+/// relabeled
+pub fn build_lines_with<'a>(
+    config: &Config,
+    info: &'a SysInfo,
+    links: bool,
+    color: bool,
+    mut annotate: impl FnMut(Module, &mut Line<'a>),
+) -> Vec<Line<'a>> {
+    let mut lines = Vec::with_capacity(config.modules.len());
+    // Where each line goes when read out, see `reading_rank`
+    let mut ranks = Vec::new();
+    for (index, &module) in config.modules.iter().enumerate() {
+        #[cfg(feature = "alloc-audit")]
+        let _section = crate::alloc::section(module.name());
+
+        for line in group_lines(config, index) {
+            ranks.push(reading_rank(None, &line));
+            lines.push(line);
+        }
+        let Some(module) = shown_as(config, info, module) else {
+            continue;
+        };
+        for mut line in module_lines(module, config, info, links, color) {
+            annotate(module, &mut line);
+            relabel(config, module, &mut line);
+            ranks.push(reading_rank(Some(module), &line));
+            lines.push(line);
+        }
+    }
+
+    if !config.accessible {
+        return lines;
+    }
+    let mut ranked: Vec<_> = ranks
+        .into_iter()
+        .zip(lines)
+        .filter_map(|(rank, line)| Some((rank?, line)))
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+    ranked.into_iter().map(|(_, line)| line).collect()
+}
+
+/// Reading order for a screen reader: who and where first, then the values,
+/// then free-form text such as greetings and quotes so it doesn't interrupt
+/// them; blank lines are left out, as they're read out as "blank"
+fn reading_rank(module: Option<Module>, line: &Line) -> Option<u8> {
+    match line {
+        Line::Text(text) if text.trim().is_empty() => None,
+        _ if module == Some(Module::Title) => Some(0),
+        Line::Text(_) => Some(2),
+        _ => Some(1),
+    }
+}
+
+/// The module whose lines go in `module`'s place: on a headless machine the
+/// first graphical module becomes `Display: headless` and the others go
+pub fn shown_as(config: &Config, info: &SysInfo, module: Module) -> Option<Module> {
+    if !info.headless || !module.is_graphical() {
+        return Some(module);
+    }
+    let first = config.modules.iter().find(|m| m.is_graphical());
+    (first == Some(&module)).then_some(Module::Display)
+}
+
+/// Blank lines and the header opening the group that starts at the module
+/// at `index`, if one does
+pub fn group_lines<'a>(config: &Config, index: usize) -> Vec<Line<'a>> {
+    let Some(group) = config.groups.iter().find(|g| g.start == index) else {
+        return Vec::new();
+    };
+    let spacing = if index > 0 { config.group_spacing } else { 0 };
+    let mut lines: Vec<Line> = (0..spacing)
+        .map(|_| Line::Text(Cow::Borrowed("")))
+        .collect();
+    if let Some(header) = &group.header {
+        lines.push(Line::Header {
+            text: Cow::Owned(header.clone()),
+            color: group.color,
+        });
+    }
+    lines
+}
+
+/// Swap the English label of a module's line for the configured or
+/// translated one, and put the module's icon before it; `module_lines` keeps
+/// plain English keys for exports
+pub fn relabel(config: &Config, module: Module, line: &mut Line) {
+    let Line::Entry { key, .. } = line else {
+        return;
+    };
+    let label = config.label(module);
+    // `Disk (/home)` keeps its mount point
+    if label != module.label()
+        && let Some(rest) = key.strip_prefix(module.label())
+    {
+        *key = Cow::Owned(format!("{label}{rest}"));
+    }
+
+    if config.icons == IconStyle::None || config.accessible {
+        return;
+    }
+    let configured = config
+        .module_icons
+        .iter()
+        .find(|(m, _)| *m == module)
+        .map(|(_, icon)| Cow::Borrowed(icon.as_str()));
+    let icon = configured
+        .or_else(|| glyphs::icon(config.icons, module).map(|c| Cow::Owned(c.to_string())));
+    if let Some(icon) = icon.filter(|icon| !icon.is_empty()) {
+        *key = Cow::Owned(format!("{icon} {key}"));
+    }
+}
+
+/// Lines for one module; Disk has one per mount point, the others at most one
+pub fn module_lines<'a>(
+    module: Module,
+    config: &Config,
+    info: &'a SysInfo,
+    links: bool,
+    color: bool,
+) -> SmallVec<[Line<'a>; 1]> {
+    match module {
+        Module::Disk => info
+            .disks
+            .iter()
+            .map(|disk| Line::Entry {
+                key: Cow::Owned(format!("{} ({})", module.label(), disk.mount)),
+                value: Cow::Owned(disk_value(config, disk, color)),
+            })
+            .collect(),
+        Module::Extras => extras_lines(config, &info.extras),
+        _ => build_line(module, config, info, links, color)
+            .into_iter()
+            .filter(|line| config.show_unknown || !is_unknown(line))
+            .collect(),
+    }
+}
+
+fn is_unknown(line: &Line) -> bool {
+    matches!(line, Line::Entry { value, .. } if value.trim() == "Unknown")
+}
+
+fn extras_lines<'a>(config: &Config, extras: &'a Extras) -> SmallVec<[Line<'a>; 1]> {
+    let entry = |key: &'static str, value: Cow<'a, str>| Line::Entry {
+        key: Cow::Borrowed(key),
+        value,
+    };
+
+    let mut lines = SmallVec::new();
+    if let Some(age) = &extras.install_age {
+        lines.push(entry("Installed", Cow::Borrowed(age)));
+    }
+    if let Some(record) = extras.uptime_record {
+        let mut value = if config.accessible {
+            format_uptime_words(record.seconds)
+        } else {
+            format_uptime(record.seconds)
+        };
+        if record.current {
+            value.push_str(" (current)");
+        }
+        lines.push(entry("Uptime Record", Cow::Owned(value)));
+    }
+    if let Some(quote) = &extras.quote {
+        lines.push(Line::Text(Cow::Borrowed(quote)));
+    }
+    lines
+}
+
+/// The title template with its `{field}`s filled in; unknown fields are kept
+/// as written
+fn title_parts<'a>(config: &Config, info: &'a SysInfo) -> Vec<TitlePart<'a>> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut rest = config.title.as_str();
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let value: Option<Cow<'a, str>> = match &rest[start + 1..start + len] {
+            "user" => {
+                let user = sanitized(get_env_var("USER", "user"));
+                Some(match &info.full_name {
+                    Some(full_name) => Cow::Owned(format!("{full_name} ({user})")),
+                    None => user,
+                })
+            }
+            "host" => Some(Cow::Borrowed(&info.hostname)),
+            "os" => Some(Cow::Borrowed(&info.os_name)),
+            "os_short" => Some(Cow::Owned(os_release().name.clone())),
+            "kernel" => Some(Cow::Borrowed(&info.kernel)),
+            _ => None,
+        };
+
+        match value {
+            Some(value) => {
+                text.push_str(&rest[..start]);
+                if !text.is_empty() {
+                    parts.push(TitlePart {
+                        text: Cow::Owned(std::mem::take(&mut text)),
+                        field: false,
+                    });
+                }
+                parts.push(TitlePart {
+                    text: value,
+                    field: true,
+                });
+            }
+            None => text.push_str(&rest[..=start + len]),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        parts.push(TitlePart {
+            text: Cow::Owned(text),
+            field: false,
+        });
+    }
+    parts
+}
+
+/// The title as plain text
+pub fn title_text(parts: &[TitlePart]) -> String {
+    parts.iter().map(|part| &*part.text).collect()
+}
+
+fn memory_value(config: &Config, info: &SysInfo, color: bool) -> String {
+    let host = (info.memory_used, info.memory_total);
+    let (used, total) = match (config.cgroup_limits, info.cgroup.memory) {
+        (CgroupLimits::Replace, Some(limited)) => limited,
+        _ => host,
+    };
+
+    let mut value = if config.accessible {
+        format!(
+            "{} used of {}, {} percent used",
+            format_memory(used),
+            format_memory(total),
+            percent(used, total)
+        )
+    } else if config.bar.modules.contains(&Module::Memory) {
+        let percent = percent(used, total);
+        bar::usage(&config.bar, percent, color)
+    } else {
+        format!("{} / {}", format_memory(used), format_memory(total))
+    };
+
+    match (config.cgroup_limits, info.cgroup.memory) {
+        (CgroupLimits::Replace, Some(_)) => value.push_str(" (cgroup)"),
+        (CgroupLimits::Alongside, Some((used, limit))) => value.push_str(&format!(
+            " (cgroup: {} / {})",
+            format_memory(used),
+            format_memory(limit)
+        )),
+        _ => {}
+    }
+
+    if let Some(pressure) = info.memory_pressure {
+        if config.accessible {
+            value.push_str(&format!(", pressure {pressure:.1} percent"));
+        } else {
+            value.push_str(&format!(" (pressure {pressure:.1}%)"));
+        }
+    }
+    value
+}
+
+/// The three hottest zones, e.g. `CPU 62°C, NVMe 48°C`, with the ones past
+/// `temp_warning` in red
+fn temps_value(config: &Config, zones: &[Zone], color: bool) -> Option<String> {
+    if zones.is_empty() {
+        return None;
+    }
+    let shown: Vec<String> = zones
+        .iter()
+        .take(3)
+        .map(|zone| {
+            let (degrees, unit) = config.temp_unit.convert(zone.celsius);
+            let text = if config.accessible {
+                let unit = match config.temp_unit {
+                    TempUnit::Celsius => "Celsius",
+                    TempUnit::Fahrenheit => "Fahrenheit",
+                };
+                format!("{} {degrees:.0} degrees {unit}", zone.name)
+            } else {
+                format!("{} {degrees:.0}°{unit}", zone.name)
+            };
+            #[allow(clippy::cast_precision_loss)]
+            let hot = zone.celsius >= config.temp_warning as f64;
+            if hot && color {
+                format!("\x1b[31m{text}\x1b[0m")
+            } else {
+                text
+            }
+        })
+        .collect();
+    Some(shown.join(", "))
+}
+
+fn disk_value(config: &Config, disk: &Disk, color: bool) -> String {
+    let percent = percent(disk.used, disk.total);
+    if config.accessible {
+        return format!(
+            "{} used of {}, {percent} percent used, {}{}",
+            format_bytes(disk.used),
+            format_bytes(disk.total),
+            disk.fstype,
+            if disk.read_only { ", read-only" } else { "" },
+        );
+    }
+
+    let usage = match config.disk.style {
+        DiskStyle::Usage => format!(
+            "{} / {} ({percent}%)",
+            format_bytes(disk.used),
+            format_bytes(disk.total),
+        ),
+        DiskStyle::Percent => format!("{percent}%"),
+        DiskStyle::Bar => bar::usage(&config.bar, percent, color),
+    };
+    let read_only = if disk.read_only { " [ro]" } else { "" };
+    format!("{usage} - {}{read_only}", disk.fstype)
+}
+
+/// CPU count with up to two decimals and no trailing zeros, e.g. `1.5`
+fn format_cpus(cpus: f64) -> String {
+    let text = format!("{cpus:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Made-up info lines to show next to a logo preview
+pub fn sample_lines() -> Vec<Line<'static>> {
+    let entry = |key, value| Line::Entry {
+        key: Cow::Borrowed(key),
+        value: Cow::Borrowed(value),
+    };
+
+    let field = |text| TitlePart {
+        text: Cow::Borrowed(text),
+        field: true,
+    };
+
+    vec![
+        Line::Title(vec![
+            field("user"),
+            TitlePart {
+                text: Cow::Borrowed("@"),
+                field: false,
+            },
+            field("hostname"),
+        ]),
+        Line::Separator(Cow::Borrowed(SEPARATOR)),
+        entry("OS", "Example Linux x86_64"),
+        entry("Kernel", "6.9.0"),
+        entry("Uptime", "1h 23m"),
+        entry("Shell", "bash 5.2.26"),
+        entry("Terminal", "xterm-256color"),
+        entry("CPU", "Example CPU (8) @ 3.60 GHz"),
+        entry("Memory", "4096 MiB / 16384 MiB"),
+    ]
+}
+
+fn build_line<'a>(
+    module: Module,
+    config: &Config,
+    info: &'a SysInfo,
+    links: bool,
+    color: bool,
+) -> Option<Line<'a>> {
+    let link = |text: &'a str, url: Option<&str>| match url {
+        Some(url) if links => Cow::Owned(hyperlink::wrap(text, url)),
+        _ => Cow::Borrowed(text),
+    };
+
+    let value: Cow<str> = match module {
+        Module::Title => {
+            let parts = title_parts(config, info);
+            if config.accessible {
+                // `user@host` reads as `user at host`
+                let spoken: String = parts
+                    .iter()
+                    .map(|part| match &*part.text {
+                        "@" if !part.field => " at ",
+                        text => text,
+                    })
+                    .collect();
+                return Some(Line::Text(Cow::Owned(spoken)));
+            }
+            return Some(Line::Title(parts));
+        }
+        // A row of dashes is just noise when read aloud
+        Module::Separator if config.accessible => return None,
+        Module::Separator => {
+            let separator = match config.separator_style {
+                SeparatorStyle::Fixed => Cow::Borrowed(SEPARATOR),
+                SeparatorStyle::Title => {
+                    let width = title_text(&title_parts(config, info)).chars().count();
+                    Cow::Owned("-".repeat(width))
+                }
+                SeparatorStyle::None => return None,
+            };
+            return Some(Line::Separator(separator));
+        }
+        Module::Colors if config.accessible => return None,
+        Module::Colors => return Some(Line::Colors),
+        Module::Greeting => {
+            return greeting::pick(&config.greeting).map(|g| Line::Text(Cow::Owned(g)));
+        }
+        Module::Os => link(&info.os_name, info.os_url.as_deref()),
+        Module::Kernel => link(
+            &info.kernel,
+            hyperlink::kernel_changelog_url(&info.kernel).as_deref(),
+        ),
+        Module::Uptime if config.accessible => format_uptime_words(info.uptime).into(),
+        Module::Uptime => format_uptime(info.uptime).into(),
+        Module::SessionUptime if config.accessible => {
+            format_uptime_words(info.session_uptime?).into()
+        }
+        Module::SessionUptime => format_uptime(info.session_uptime?).into(),
+        Module::Boot => info.boot_time.as_deref()?.into(),
+        Module::InstallDate => info.install_date.as_deref()?.into(),
+        Module::Packages => packages::packages_summary(&info.packages)?.into(),
+        Module::Updates => info.updates.as_deref()?.into(),
+        Module::Shell => info.shell.as_str().into(),
+        Module::Resolution => info.resolution.as_str().into(),
+        Module::Display if info.headless => "headless".into(),
+        Module::Display => info.display.as_deref()?.into(),
+        Module::De => info.de.as_str().into(),
+        Module::Wm => info.wm.as_str().into(),
+        Module::Session => info.session.as_deref()?.into(),
+        Module::Theme => {
+            let mut theme = link(&info.theme, hyperlink::theme_url(&info.theme));
+            if let Some(details) = &info.theme_details {
+                theme = format!("{theme} ({details})").into();
+            }
+            match info.color_scheme {
+                Some(scheme) => format!("{theme} [{scheme}]").into(),
+                None => theme,
+            }
+        }
+        Module::Icons => info.icons.as_str().into(),
+        Module::Cursor => info.cursor.as_deref()?.into(),
+        Module::Font => info.font.as_deref()?.into(),
+        Module::GnomeExtensions => info.gnome_extensions.as_deref()?.into(),
+        Module::Terminal => match &info.terminal_style {
+            Some(style) => format!("{} ({style})", info.terminal).into(),
+            None => info.terminal.as_str().into(),
+        },
+        Module::Cpu => match info.cgroup.cpus {
+            Some(cpus) => {
+                format!("{} (limited to {} CPUs)", info.cpu_info, format_cpus(cpus)).into()
+            }
+            None => info.cpu_info.as_str().into(),
+        },
+        Module::Load => info.load.as_deref()?.into(),
+        Module::Gpu => info.gpu.as_deref()?.into(),
+        Module::Graphics => info.graphics.as_deref()?.into(),
+        Module::Temps => temps_value(config, &info.temps, color)?.into(),
+        Module::Fans => info.fans.as_deref()?.into(),
+        Module::Memory => memory_value(config, info, color).into(),
+        // Several lines each, built by `module_lines`
+        Module::Disk | Module::Extras => return None,
+        Module::Nvme => info.nvme.as_deref()?.into(),
+        Module::Raid => info.raid.as_deref()?.into(),
+        Module::Encryption => info.encryption.as_deref()?.into(),
+        Module::Numa => info.numa.as_deref()?.into(),
+        Module::Hugepages => info.hugepages.as_str().into(),
+        Module::Network => info.network.as_deref()?.into(),
+        Module::Wifi => info.wifi.as_ref()?.describe(config.privacy).into(),
+        Module::Sound => info.sound.as_deref()?.into(),
+        Module::Media => info.media.as_deref()?.into(),
+        Module::Virtualization => info.virtualization.as_str().into(),
+        Module::Preemption => info.preemption.as_str().into(),
+        Module::KernelModules => info.kernel_modules.as_str().into(),
+        Module::Cmdline => info.cmdline.as_deref()?.into(),
+        Module::Security => info.security.as_deref()?.into(),
+        Module::Systemd => info.systemd.as_deref()?.into(),
+        Module::Containers => info.containers.as_deref()?.into(),
+        Module::Chassis => info.chassis.name().into(),
+        Module::MachineId if config.privacy => return None,
+        Module::MachineId => info.machine_id.as_deref()?.into(),
+        Module::Battery => match info.battery_charge {
+            Some(charge) if !config.accessible => {
+                let battery = info.battery.as_deref()?;
+                let bar = bar::charge(&config.bar, charge.into(), color);
+                // A single battery's own `87% (...)` starts with the charge the
+                // bar already shows; several keep theirs after the average
+                match battery.strip_prefix(&format!("{charge}%")) {
+                    Some(details) => format!("{bar}{details}").into(),
+                    None => format!("{bar} ({battery})").into(),
+                }
+            }
+            _ => info.battery.as_deref()?.into(),
+        },
+        Module::Weather => info.weather.as_deref()?.into(),
+    };
+
+    Some(Line::Entry {
+        key: module.label().into(),
+        value,
+    })
+}
@@ -0,0 +1,69 @@
+//! Network interface statistics
+//! Total bytes received and sent on the primary interface, from
+//! `/sys/class/net/<iface>/statistics`
+
+use crate::source;
+use crate::utils::format_bytes;
+use std::path::Path;
+
+/// Traffic since boot on the primary interface, e.g.
+/// `1.4 GiB RX, 212.3 MiB TX (eth0)`
+pub fn traffic_summary() -> Option<String> {
+    let iface = primary_interface()?;
+    let rx = counter(&iface, "rx_bytes")?;
+    let tx = counter(&iface, "tx_bytes")?;
+    Some(format!(
+        "{} RX, {} TX ({iface})",
+        format_bytes(rx),
+        format_bytes(tx)
+    ))
+}
+
+/// Interface holding the default route with the lowest metric, or else the
+/// first non-loopback interface that is up
+fn primary_interface() -> Option<String> {
+    default_route().or_else(|| {
+        let mut names: Vec<String> = source::current()
+            .read_dir(Path::new("/sys/class/net"))
+            .ok()?
+            .into_iter()
+            .filter_map(|path| path.file_name()?.to_str().map(str::to_string))
+            .filter(|name| name != "lo")
+            .collect();
+        names.sort_unstable();
+        names.into_iter().find(|name| {
+            source::current()
+                .read_to_string(Path::new(&format!("/sys/class/net/{name}/operstate")))
+                .is_ok_and(|state| state.trim() == "up")
+        })
+    })
+}
+
+fn default_route() -> Option<String> {
+    let routes = source::current()
+        .read_to_string(Path::new("/proc/net/route"))
+        .ok()?;
+    // Columns: Iface Destination Gateway Flags RefCnt Use Metric Mask ...
+    routes
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (&iface, &dest, &mask) = (fields.first()?, fields.get(1)?, fields.get(7)?);
+            let metric: u32 = fields.get(6)?.parse().ok()?;
+            (dest == "00000000" && mask == "00000000").then_some((metric, iface))
+        })
+        .min_by_key(|(metric, _)| *metric)
+        .map(|(_, iface)| iface.to_string())
+}
+
+fn counter(iface: &str, name: &str) -> Option<u64> {
+    source::current()
+        .read_to_string(Path::new(&format!(
+            "/sys/class/net/{iface}/statistics/{name}"
+        )))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
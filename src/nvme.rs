@@ -0,0 +1,123 @@
+//! NVMe health
+//! Model and composite temperature of each controller from sysfs, plus the
+//! SMART log's wear estimate when we may send admin commands to it
+
+use crate::config::TempUnit;
+use crate::source;
+use std::fs::File;
+use std::mem::size_of;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+
+/// e.g. `Samsung SSD 980 PRO 1TB (41°C, 3% used)`, one per controller
+pub fn health_summary(unit: TempUnit) -> Option<String> {
+    let source = source::current();
+    let mut controllers = source
+        .read_dir(Path::new("/sys/class/nvme"))
+        .unwrap_or_default();
+    controllers.sort();
+
+    let drives: Vec<String> = controllers
+        .iter()
+        .filter_map(|controller| {
+            let model = source.read_to_string(&controller.join("model")).ok()?;
+            let mut details = Vec::new();
+            if let Some(celsius) = temperature(controller) {
+                let (degrees, unit) = unit.convert(celsius);
+                details.push(format!("{degrees:.0}°{unit}"));
+            }
+            if source.is_live()
+                && let Some(used) = controller
+                    .file_name()
+                    .and_then(|n| percentage_used(n.as_ref()))
+            {
+                details.push(format!("{used}% used"));
+            }
+            Some(if details.is_empty() {
+                model.trim().to_string()
+            } else {
+                format!("{} ({})", model.trim(), details.join(", "))
+            })
+        })
+        .collect();
+    (!drives.is_empty()).then(|| drives.join(", "))
+}
+
+/// The composite temperature from the controller's hwmon device, which sits
+/// directly under it on newer kernels and under `device/hwmon` on older ones
+fn temperature(controller: &Path) -> Option<f64> {
+    let source = source::current();
+    let hwmons: Vec<PathBuf> = [controller.to_path_buf(), controller.join("device/hwmon")]
+        .iter()
+        .flat_map(|dir| source.read_dir(dir).unwrap_or_default())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with("hwmon"))
+        })
+        .collect();
+    hwmons.iter().find_map(|hwmon| {
+        let millis: i64 = source
+            .read_to_string(&hwmon.join("temp1_input"))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        #[allow(clippy::cast_precision_loss)]
+        let celsius = millis as f64 / 1000.0;
+        Some(celsius)
+    })
+}
+
+/// `struct nvme_admin_cmd`
+#[repr(C)]
+#[derive(Default)]
+struct AdminCommand {
+    opcode: u8,
+    flags: u8,
+    rsvd1: u16,
+    nsid: u32,
+    cdw2: u32,
+    cdw3: u32,
+    metadata: u64,
+    addr: u64,
+    metadata_len: u32,
+    data_len: u32,
+    cdw10: u32,
+    cdw11: u32,
+    cdw12: u32,
+    cdw13: u32,
+    cdw14: u32,
+    cdw15: u32,
+    timeout_ms: u32,
+    result: u32,
+}
+
+/// `_IOWR('N', 0x41, struct nvme_admin_cmd)`
+const IOCTL_ADMIN_CMD: u64 =
+    (3 << 30) | ((size_of::<AdminCommand>() as u64) << 16) | ((b'N' as u64) << 8) | 0x41;
+
+const GET_LOG_PAGE: u8 = 0x02;
+const SMART_LOG: u32 = 0x02;
+const SMART_LOG_LEN: usize = 512;
+/// Byte of the SMART log holding the vendor's estimate of life used, which
+/// may go past 100
+const PERCENTAGE_USED: usize = 5;
+
+/// The SMART log's percentage used; opening the character device usually
+/// takes root
+fn percentage_used(name: &Path) -> Option<u8> {
+    let device = File::open(Path::new("/dev").join(name)).ok()?;
+    let mut log = [0u8; SMART_LOG_LEN];
+    let mut cmd = AdminCommand {
+        opcode: GET_LOG_PAGE,
+        // The controller-wide log
+        nsid: u32::MAX,
+        addr: log.as_mut_ptr() as u64,
+        data_len: SMART_LOG_LEN as u32,
+        // Dwords to read, minus one, in the upper half
+        cdw10: ((SMART_LOG_LEN as u32 / 4 - 1) << 16) | SMART_LOG,
+        ..AdminCommand::default()
+    };
+    let rc = unsafe { libc::ioctl(device.as_raw_fd(), IOCTL_ADMIN_CMD as _, &raw mut cmd) };
+    (rc == 0).then_some(log[PERCENTAGE_USED])
+}
@@ -1,175 +1,631 @@
+use crate::battery;
+use crate::cgroup;
+use crate::chassis::Chassis;
+use crate::config::{CgroupLimits, Config, CpuFrequency, MemoryUsed};
+use crate::containers;
+use crate::crypt;
+use crate::disk;
 use crate::display;
-use crate::proc;
-use crate::utils::{fast_sysinfo, get_env_var};
-use libc::{self, c_char};
-use nix::sys::utsname::uname;
-use smallvec::{SmallVec, smallvec};
+use crate::extras;
+use crate::fans;
+use crate::gnome;
+use crate::gpu;
+use crate::graphics;
+use crate::hyprland;
+use crate::identity;
+use crate::install;
+use crate::kernel;
+use crate::log;
+use crate::media;
+use crate::memory;
+use crate::modules::{Module, Needs};
+use crate::net;
+use crate::nvme;
+use crate::packages;
+use crate::proc::{self, ProcFile};
+use crate::raid;
+use crate::security;
+use crate::session;
+use crate::sound;
+use crate::source;
+use crate::sway;
+use crate::systemd;
+use crate::terminal;
+use crate::theme;
+use crate::thermal;
+use crate::updates;
+use crate::utils::{fast_sysinfo, file_exists, format_local_time, get_env_var, sanitize};
+use crate::virt;
+use crate::weather;
+use crate::wifi;
+use crate::xsettings;
 use std::fs::File;
-use std::os::fd::AsRawFd;
+use std::path::Path;
 use std::sync::LazyLock;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+#[derive(Clone)]
 pub struct SysInfo {
     pub hostname: String,
+    /// Only collected when `full_name` is set
+    pub full_name: Option<String>,
     pub os_name: String,
+    pub os_url: Option<String>,
     pub kernel: String,
     pub uptime: u64,
+    /// Only collected when the boot module is enabled
+    pub boot_time: Option<String>,
+    /// Only collected when the session uptime module is enabled
+    pub session_uptime: Option<u64>,
+    /// Only collected when the install date module is enabled
+    pub install_date: Option<String>,
+    /// Only collected when the packages module is enabled
+    pub packages: Vec<(&'static str, usize)>,
+    /// Only collected when the updates module is enabled
+    pub updates: Option<String>,
     pub shell: String,
     pub terminal: String,
+    /// Opacity and padding, only collected when `terminal_details` is set
+    pub terminal_style: Option<String>,
     pub de: String,
     pub wm: String,
+    /// Only collected when the session module is enabled
+    pub session: Option<String>,
     pub theme: String,
+    /// Plasma's global theme and Kvantum's, only collected in KDE sessions
+    pub theme_details: Option<String>,
+    /// `dark` or `light`, only collected when `theme_mode` is set
+    pub color_scheme: Option<&'static str>,
     pub icons: String,
+    /// Only collected when the cursor module is enabled
+    pub cursor: Option<String>,
+    /// Only collected when the font module is enabled
+    pub font: Option<String>,
+    /// Only collected when the GNOME extensions module is enabled
+    pub gnome_extensions: Option<String>,
     pub resolution: String,
+    /// Only collected when the display module is enabled
+    pub display: Option<String>,
     pub cpu_info: String,
+    /// Only collected when the GPU module is enabled
+    pub gpu: Option<String>,
+    /// Only collected when the graphics module is enabled
+    pub graphics: Option<String>,
+    /// Hottest first, only collected when the temps module is enabled
+    pub temps: Vec<thermal::Zone>,
+    /// Only collected when the fans module is enabled
+    pub fans: Option<String>,
     pub memory_used: u64,
     pub memory_total: u64,
+    /// PSI `some avg10` percentage, only collected when enabled
+    pub memory_pressure: Option<f32>,
+    /// Only collected when the disk module is enabled
+    pub disks: Vec<disk::Disk>,
+    /// Only collected when the NVMe module is enabled
+    pub nvme: Option<String>,
+    /// Only collected when the RAID module is enabled
+    pub raid: Option<String>,
+    /// Only collected when the encryption module is enabled
+    pub encryption: Option<String>,
+    /// Only collected when cgroup limits are enabled
+    pub cgroup: cgroup::Limits,
+    /// Only collected when the NUMA module is enabled
+    pub numa: Option<String>,
+    pub hugepages: String,
+    /// Only collected when the network module is enabled
+    pub network: Option<String>,
+    /// Only collected when the Wi-Fi module is enabled
+    pub wifi: Option<wifi::Link>,
+    /// Only collected when the load module is enabled
+    pub load: Option<String>,
+    /// Only collected when the sound module is enabled
+    pub sound: Option<String>,
+    pub virtualization: String,
+    pub preemption: String,
+    pub kernel_modules: String,
+    /// Only collected when the cmdline module is enabled
+    pub cmdline: Option<String>,
+    /// Only collected when the security module is enabled
+    pub security: Option<String>,
+    /// Only collected when the systemd module is enabled
+    pub systemd: Option<String>,
+    /// Only collected when the containers module is enabled
+    pub containers: Option<String>,
+    /// Only collected when the media module is enabled
+    pub media: Option<String>,
+    /// Only collected when the weather module is enabled
+    pub weather: Option<String>,
+    /// Only collected when the extras module is enabled
+    pub extras: extras::Extras,
+    pub chassis: Chassis,
+    /// Only collected when the machine ID module is enabled outside privacy mode
+    pub machine_id: Option<String>,
+    pub battery: Option<String>,
+    /// Average charge of the system batteries; only collected for the
+    /// battery bar
+    pub battery_charge: Option<u8>,
+    /// No graphical session or no display connected; the graphical values
+    /// aren't collected then
+    pub headless: bool,
 }
 
-static DISTRO_NAME: LazyLock<String> = LazyLock::new(get_distribution_name);
+impl SysInfo {
+    /// Re-read the values that change while running
+    pub fn refresh(&mut self, config: &Config, needs: Needs) {
+        self.uptime = source::current().uptime();
+        if needs.has(Module::SessionUptime) {
+            self.session_uptime = session::session_uptime();
+        }
+        if needs.has(Module::Cpu) {
+            self.cpu_info = get_cpu_info(config.cpu_frequency);
+        }
+        if config.gpu_stats && needs.has(Module::Gpu) {
+            self.gpu = gpu::gpu_summary(true);
+        }
+        if needs.has(Module::Temps) {
+            self.temps = thermal::zones();
+        }
+        if needs.has(Module::Fans) {
+            self.fans = fans::fans_summary();
+        }
+        if needs.has(Module::Nvme) {
+            self.nvme = nvme::health_summary(config.temp_unit);
+        }
+        if needs.has(Module::Raid) {
+            self.raid = raid::raid_summary();
+        }
+        if needs.has(Module::Memory) {
+            (self.memory_used, self.memory_total) = get_memory_info(config.memory_used);
+        }
+        if config.memory_pressure {
+            self.memory_pressure = proc::memory_pressure();
+        }
+        if config.cgroup_limits != CgroupLimits::Off {
+            self.cgroup = cgroup::limits();
+        }
+        if needs.has(Module::Network) {
+            self.network = net::traffic_summary();
+        }
+        if needs.has(Module::Wifi) {
+            self.wifi = wifi::link();
+        }
+        if needs.has(Module::Systemd) {
+            self.systemd = systemd::health_summary();
+        }
+        if needs.has(Module::Containers) {
+            self.containers = containers::containers_summary();
+        }
+        if needs.has(Module::Load) {
+            self.load = proc::load_average();
+        }
+        if needs.has(Module::Media) {
+            self.media = media::now_playing();
+        }
+        if needs.has(Module::Battery) {
+            self.battery = battery::get_battery_info(config.accessible);
+            self.battery_charge = battery_charge(config);
+        }
+    }
 
-fn get_distribution_name() -> String {
-    if let Ok(file) = File::open("/etc/os-release") {
-        if let Ok(mmap) = unsafe { memmap2::MmapOptions::new().map(&file) } {
-            let data = mmap.as_ref();
+    /// Strip escape sequences and control characters from every collected value
+    pub fn sanitize(&mut self) {
+        for value in [
+            &mut self.hostname,
+            &mut self.os_name,
+            &mut self.kernel,
+            &mut self.shell,
+            &mut self.terminal,
+            &mut self.de,
+            &mut self.wm,
+            &mut self.theme,
+            &mut self.icons,
+            &mut self.resolution,
+            &mut self.cpu_info,
+            &mut self.hugepages,
+            &mut self.virtualization,
+            &mut self.preemption,
+            &mut self.kernel_modules,
+        ] {
+            sanitize(value);
+        }
 
-            let name_pattern = b"NAME=";
-            let id_pattern = b"ID=";
+        for value in [
+            &mut self.os_url,
+            &mut self.full_name,
+            &mut self.battery,
+            &mut self.numa,
+            &mut self.network,
+            &mut self.sound,
+            &mut self.gpu,
+            &mut self.graphics,
+            &mut self.fans,
+            &mut self.nvme,
+            &mut self.raid,
+            &mut self.display,
+            &mut self.session,
+            &mut self.updates,
+            &mut self.encryption,
+            &mut self.security,
+            &mut self.cmdline,
+            &mut self.systemd,
+            &mut self.containers,
+            &mut self.load,
+            &mut self.media,
+            &mut self.weather,
+            &mut self.extras.install_age,
+            &mut self.extras.quote,
+            &mut self.install_date,
+            &mut self.boot_time,
+            &mut self.machine_id,
+            &mut self.cursor,
+            &mut self.font,
+            &mut self.gnome_extensions,
+            &mut self.theme_details,
+            &mut self.terminal_style,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            sanitize(value);
+        }
 
-            if let Some(pos) = memchr::memmem::find(data, name_pattern) {
-                let start = pos + name_pattern.len();
-                if let Some(end_offset) = memchr::memchr(b'\n', &data[start..]) {
-                    let end = start + end_offset;
-                    let name = &data[start..end];
+        if let Some(link) = &mut self.wifi {
+            sanitize(&mut link.ssid);
+        }
+        for zone in &mut self.temps {
+            sanitize(&mut zone.name);
+        }
+        for disk in &mut self.disks {
+            sanitize(&mut disk.mount);
+            sanitize(&mut disk.fstype);
+        }
+    }
+}
 
-                    let name = if name.len() >= 2 && name[0] == b'"' && name[name.len() - 1] == b'"'
-                    {
-                        &name[1..name.len() - 1]
-                    } else {
-                        name
-                    };
+/// The identifying fields of `os-release`
+#[derive(Default)]
+pub struct OsRelease {
+    /// `NAME`, e.g. `Fedora Linux`
+    pub name: String,
+    /// `PRETTY_NAME`, e.g. `Fedora Linux 40 (Workstation Edition)`
+    pub pretty_name: Option<String>,
+    /// `ID`, e.g. `fedora`
+    pub id: Option<String>,
+    /// `ID_LIKE`, closest relatives first, e.g. `["rhel", "fedora"]`
+    pub id_like: Vec<String>,
+    /// `VERSION_ID`, e.g. `40`
+    pub version_id: Option<String>,
+    /// `VERSION_CODENAME`, e.g. `bookworm`
+    pub version_codename: Option<String>,
+    /// `BUILD_ID`, e.g. `rolling` or `20240610.0.241391`
+    pub build_id: Option<String>,
+    pub home_url: Option<String>,
+}
 
-                    if let Ok(name_str) = std::str::from_utf8(name) {
-                        return name_str.trim().to_string();
-                    }
-                }
-            } else if let Some(pos) = memchr::memmem::find(data, id_pattern) {
-                let start = pos + id_pattern.len();
-                if let Some(end_offset) = memchr::memchr(b'\n', &data[start..]) {
-                    let end = start + end_offset;
-                    if let Ok(id) = std::str::from_utf8(&data[start..end]) {
-                        let id = id.trim().trim_matches('"');
-                        let mut id_chars = id.chars();
-                        return id_chars.next().map_or_else(
-                            || "Linux".to_string(),
-                            |c| c.to_uppercase().collect::<String>() + id_chars.as_str() + " Linux",
-                        );
-                    }
-                }
+impl OsRelease {
+    /// Name with version for the OS line
+    pub fn display_name(&self) -> String {
+        if let Some(pretty) = &self.pretty_name {
+            return pretty.clone();
+        }
+        match &self.version_id {
+            Some(version) => format!("{} {version}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// The codename, e.g. `Bookworm`, unless the name already has it; or on
+    /// rolling releases the snapshot date, e.g. `rolling, 2024-06-10`
+    pub fn details(&self) -> Option<String> {
+        let lsb = source::current()
+            .read_to_string(Path::new("/etc/lsb-release"))
+            .unwrap_or_default();
+        let lsb_value = |key: &str| {
+            lsb.lines()
+                .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+                .map(unquote)
+                .filter(|v| !v.is_empty())
+        };
+
+        let rolling = self.build_id.as_deref() == Some("rolling")
+            || lsb_value("DISTRIB_RELEASE").as_deref() == Some("rolling")
+            || self.version_id.is_none() && self.version_codename.is_none();
+        if rolling {
+            // A dated build, as container images have, or the last upgrade
+            let date = self
+                .build_id
+                .as_deref()
+                .filter(|id| id.len() >= 8 && id.bytes().take(8).all(|b| b.is_ascii_digit()))
+                .map(|id| format!("{}-{}-{}", &id[..4], &id[4..6], &id[6..8]))
+                .or_else(last_full_upgrade);
+            return Some(match date {
+                Some(date) => format!("rolling, {date}"),
+                None => "rolling".to_string(),
+            });
+        }
+
+        let codename = self
+            .version_codename
+            .clone()
+            .or_else(|| lsb_value("DISTRIB_CODENAME"))?;
+        let shown = self.display_name().to_lowercase();
+        if shown.contains(&codename.to_lowercase()) {
+            return None;
+        }
+        let mut chars = codename.chars();
+        let first = chars.next()?;
+        Some(first.to_uppercase().chain(chars).collect())
+    }
+}
+
+/// Date of the last `pacman -Syu`, from `[2024-06-10T09:12:44+0200] [PACMAN]
+/// starting full system upgrade` in its log
+fn last_full_upgrade() -> Option<String> {
+    let log = source::current()
+        .read_to_string(Path::new("/var/log/pacman.log"))
+        .ok()?;
+    let line = log
+        .lines()
+        .rev()
+        .find(|line| line.ends_with("starting full system upgrade"))?;
+    Some(line.strip_prefix('[')?.get(..10)?.to_string())
+}
+
+static OS_RELEASE: LazyLock<OsRelease> = LazyLock::new(read_os_release);
+
+pub fn os_release() -> &'static OsRelease {
+    &OS_RELEASE
+}
+
+fn read_os_release() -> OsRelease {
+    let mut release = OsRelease::default();
+
+    // /usr/lib/os-release is the vendor copy /etc/os-release usually links to
+    let source = source::current();
+    for path in ["/etc/os-release", "/usr/lib/os-release"] {
+        if !source.is_live() {
+            if let Ok(data) = source.read(Path::new(path)) {
+                parse_os_release(&data, &mut release);
+                break;
             }
+        } else if let Ok(file) = File::open(path)
+            && let Ok(mmap) = unsafe { memmap2::MmapOptions::new().map(&file) }
+        {
+            parse_os_release(mmap.as_ref(), &mut release);
+            break;
         }
     }
 
-    if std::path::Path::new("/etc/arch-release").exists() {
+    if release.name.is_empty() {
+        release.name = fallback_distribution_name(release.id.as_deref());
+    }
+    release
+}
+
+fn parse_os_release(data: &[u8], release: &mut OsRelease) {
+    for line in data.split(|&b| b == b'\n') {
+        let Some(eq) = memchr::memchr(b'=', line) else {
+            continue;
+        };
+        let Ok(value) = std::str::from_utf8(&line[eq + 1..]) else {
+            continue;
+        };
+        let value = unquote(value.trim());
+        if value.is_empty() {
+            continue;
+        }
+
+        match line[..eq].trim_ascii() {
+            b"NAME" => release.name = value,
+            b"PRETTY_NAME" => release.pretty_name = Some(value),
+            b"ID" => release.id = Some(value),
+            b"ID_LIKE" => {
+                release.id_like = value.split_whitespace().map(str::to_string).collect();
+            }
+            b"VERSION_ID" => release.version_id = Some(value),
+            b"VERSION_CODENAME" => release.version_codename = Some(value),
+            b"BUILD_ID" => release.build_id = Some(value),
+            b"HOME_URL" => release.home_url = Some(value),
+            _ => {}
+        }
+    }
+}
+
+/// Strip the shell-style quoting os-release values may use
+fn unquote(value: &str) -> String {
+    let quoted = value.len() >= 2
+        && (value.starts_with('"') && value.ends_with('"')
+            || value.starts_with('\'') && value.ends_with('\''));
+    if !quoted {
+        return value.to_string();
+    }
+
+    let inner = &value[1..value.len() - 1];
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.extend(chars.next()),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Hostnames that don't name the machine, as containers often have
+const PLACEHOLDER_HOSTNAMES: &[&str] = &["", "localhost", "localhost.localdomain", "(none)"];
+
+/// hostnamectl's pretty hostname when one is set, otherwise the kernel's, or
+/// `/etc/hostname` when the kernel's is a placeholder
+pub fn hostname() -> String {
+    let source = source::current();
+    let machine_info = source
+        .read_to_string(Path::new("/etc/machine-info"))
+        .unwrap_or_default();
+    if let Some(pretty) = machine_info
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_HOSTNAME="))
+        .map(|value| unquote(value.trim()))
+        .filter(|value| !value.is_empty())
+    {
+        return pretty;
+    }
+
+    let hostname = source.hostname();
+    if !PLACEHOLDER_HOSTNAMES.contains(&hostname.as_str()) {
+        return hostname;
+    }
+    source
+        .read_to_string(Path::new("/etc/hostname"))
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !PLACEHOLDER_HOSTNAMES.contains(&name.as_str()))
+        .unwrap_or(hostname)
+}
+
+/// Name for systems without a usable os-release `NAME`
+fn fallback_distribution_name(id: Option<&str>) -> String {
+    if let Some(id) = id {
+        let mut id_chars = id.chars();
+        if let Some(c) = id_chars.next() {
+            return c.to_uppercase().collect::<String>() + id_chars.as_str() + " Linux";
+        }
+    }
+
+    if file_exists(Path::new("/etc/arch-release")) {
         return "Arch Linux".to_string();
-    } else if std::path::Path::new("/etc/debian_version").exists() {
+    } else if file_exists(Path::new("/etc/debian_version")) {
         return "Debian Linux".to_string();
-    } else if std::path::Path::new("/etc/redhat-release").exists() {
+    } else if file_exists(Path::new("/etc/redhat-release")) {
         return "Red Hat Linux".to_string();
     }
 
     "Linux".to_string()
 }
 
-pub fn get_cpu_info() -> String {
-    #[allow(clippy::cast_sign_loss)]
-    #[allow(clippy::cast_possible_truncation)]
-    let cpu_online = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) as usize };
+pub fn get_cpu_info(frequency: CpuFrequency) -> String {
+    let source = source::current();
+    let cpu_online = source.online_cpus();
 
     let mut model_name = String::new();
 
-    if let Ok(file) = File::open("/proc/cpuinfo") {
-        const BUF_SIZE: usize = 512;
-
-        let mut buffer = [0u8; BUF_SIZE];
-
-        let fd = file.as_raw_fd();
-
-        let bytes_read =
-            unsafe { libc::read(fd, buffer.as_mut_ptr().cast::<libc::c_void>(), BUF_SIZE) };
-        #[allow(clippy::cast_sign_loss)]
-        if bytes_read > 0 {
-            let slice = &buffer[0..bytes_read as usize];
-
-            let model_tag = b"model name\t: ";
-
-            if let Some(pos) = memchr::memmem::find(slice, model_tag) {
-                let start = pos + model_tag.len();
-
-                if let Some(end) = memchr::memchr(b'\n', &slice[start..]) {
-                    if let Ok(model) = std::str::from_utf8(&slice[start..start + end]) {
-                        let trimmed_model = model.trim();
-
-                        // Look for "-Core" pattern
-                        if let Some(core_idx) =
-                            memchr::memmem::find(trimmed_model.as_bytes(), b"-Core")
-                        {
-                            // Find the last space before "-Core"
-                            let prefix_slice = &trimmed_model.as_bytes()[..core_idx];
-
-                            // Try to find the last space before the core count
-                            if let Some(last_space) = memchr::memrchr(b' ', prefix_slice) {
-                                // Check if everything between the last space and "-Core" is numeric
-                                let potential_count = &prefix_slice[last_space + 1..];
-                                let is_numeric =
-                                    potential_count.iter().all(|&b| b >= b'0' && b <= b'9');
-
-                                if is_numeric && !potential_count.is_empty() {
-                                    // This is a format like "AMD Ryzen 7 7800X3D 8-Core"
-                                    model_name = trimmed_model[..last_space].to_string();
-                                } else {
-                                    // This is a format like "AMD EPYC 7773X 64-Core"
-                                    model_name = trimmed_model[..core_idx].to_string();
-                                }
-                            } else {
-                                // No space found, use everything before "-Core"
-                                model_name = trimmed_model[..core_idx].to_string();
-                            }
+    // The model name is on the first processor's lines
+    let _ = proc::read(ProcFile::Cpuinfo, 512, |slice| {
+        let model_tag = b"model name\t: ";
+
+        if let Some(pos) = memchr::memmem::find(slice, model_tag) {
+            let start = pos + model_tag.len();
+
+            if let Some(end) = memchr::memchr(b'\n', &slice[start..])
+                && let Ok(model) = std::str::from_utf8(&slice[start..start + end])
+            {
+                let trimmed_model = model.trim();
+
+                // Look for "-Core" pattern
+                if let Some(core_idx) = memchr::memmem::find(trimmed_model.as_bytes(), b"-Core") {
+                    // Find the last space before "-Core"
+                    let prefix_slice = &trimmed_model.as_bytes()[..core_idx];
+
+                    // Try to find the last space before the core count
+                    if let Some(last_space) = memchr::memrchr(b' ', prefix_slice) {
+                        // Check if everything between the last space and "-Core" is numeric
+                        let potential_count = &prefix_slice[last_space + 1..];
+                        let is_numeric = potential_count.iter().all(|&b| b.is_ascii_digit());
+
+                        if is_numeric && !potential_count.is_empty() {
+                            // This is a format like "AMD Ryzen 7 7800X3D 8-Core"
+                            model_name = trimmed_model[..last_space].to_string();
                         } else {
-                            model_name = trimmed_model.to_string();
+                            // This is a format like "AMD EPYC 7773X 64-Core"
+                            model_name = trimmed_model[..core_idx].to_string();
                         }
+                    } else {
+                        // No space found, use everything before "-Core"
+                        model_name = trimmed_model[..core_idx].to_string();
                     }
+                } else {
+                    model_name = trimmed_model.to_string();
                 }
             }
         }
-    }
+    });
 
-    let mut max_freq_ghz = 0.0;
+    let mut freq_ghz = 0.0;
+    let mut governor = None;
 
-    if let Ok(freq_str) =
-        std::fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq")
+    if frequency == CpuFrequency::Current
+        && let Some(avg_khz) = average_current_freq_khz()
     {
+        #[allow(clippy::cast_precision_loss)]
+        {
+            freq_ghz = avg_khz as f64 / 1_000_000.0;
+        }
+        governor = source
+            .read_to_string(Path::new(
+                "/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor",
+            ))
+            .ok()
+            .map(|g| g.trim().to_string())
+            .filter(|g| !g.is_empty());
+    } else if let Ok(freq_str) = source.read_to_string(Path::new(
+        "/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq",
+    )) {
         #[allow(clippy::cast_precision_loss)]
         if let Ok(freq_khz) = freq_str.trim().parse::<u64>() {
-            max_freq_ghz = freq_khz as f64 / 1_000_000.0;
+            freq_ghz = freq_khz as f64 / 1_000_000.0;
         }
     }
 
     if model_name.is_empty() {
         format!("Unknown CPU ({cpu_online} cores)")
     } else {
-        let freq_str = if max_freq_ghz > 0.0 {
-            format!(" @ {max_freq_ghz:.3}GHz")
+        let freq_str = if freq_ghz > 0.0 {
+            format!(" @ {freq_ghz:.3}GHz")
         } else {
             String::new()
         };
 
-        format!("{model_name} ({cpu_online}){freq_str}")
+        let governor_str = governor.map_or_else(String::new, |g| format!(" [{g}]"));
+
+        format!("{model_name} ({cpu_online}){freq_str}{governor_str}")
+    }
+}
+
+/// Average `scaling_cur_freq` (in kHz) over all cores that expose it
+fn average_current_freq_khz() -> Option<u64> {
+    let source = source::current();
+    let entries = source.read_dir(Path::new("/sys/devices/system/cpu")).ok()?;
+
+    let mut total: u64 = 0;
+    let mut count: u64 = 0;
+
+    for entry in entries {
+        let Some(index) = entry
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_prefix("cpu"))
+        else {
+            continue;
+        };
+        if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+            continue;
+        }
+
+        if let Ok(freq_str) = source.read_to_string(&entry.join("cpufreq/scaling_cur_freq"))
+            && let Ok(freq_khz) = freq_str.trim().parse::<u64>()
+        {
+            total += freq_khz;
+            count += 1;
+        }
     }
+
+    total.checked_div(count)
 }
 
-pub fn get_memory_info() -> (u64, u64) {
-    if let Ok((used, total)) = proc::fast_parse_meminfo() {
-        return (used, total);
+pub fn get_memory_info(mode: MemoryUsed) -> (u64, u64) {
+    match proc::fast_parse_meminfo(mode == MemoryUsed::Available) {
+        Ok((used, total)) => return (used, total),
+        Err(e) => log::fail!("memory: cannot parse /proc/meminfo, using sysinfo(2): {e}"),
     }
 
     // Fallback to sysinfo if our parser fails
@@ -181,71 +637,395 @@ pub fn get_memory_info() -> (u64, u64) {
     }
 }
 
-pub fn collect_system_info() -> SysInfo {
-    let uts = uname().unwrap();
-
-    let sys_info = unsafe { fast_sysinfo() };
-
-    let mut hostname: SmallVec<[u8; 64]> = smallvec![0; 64];
-    unsafe {
-        libc::gethostname(hostname.as_mut_ptr().cast::<c_char>(), hostname.len());
-        let mut i = 0;
-        while i < hostname.len() && hostname[i] != 0 {
-            i += 1;
-        }
-        hostname.truncate(i);
-    }
+/// Boot time in the user's locale
+fn boot_time(uptime: u64) -> Option<String> {
+    let btime = proc::boot_time().or_else(|| {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(now.saturating_sub(uptime))
+    })?;
+    format_local_time(i64::try_from(btime).ok()?)
+}
 
-    #[allow(clippy::cast_sign_loss)]
-    let uptime = sys_info.uptime as u64;
+pub fn collect_system_info(config: &Config, needs: Needs) -> SysInfo {
+    let source = source::current();
+    let uts = source.uname();
+    let uptime = source.uptime();
 
     let de = get_env_var("XDG_CURRENT_DESKTOP", "Unknown");
 
-    let wm = match get_env_var("XDG_SESSION_TYPE", "") {
-        "wayland" => {
-            if de.contains("GNOME") {
-                "Mutter"
-            } else if de.contains("KDE") {
-                "KWin"
-            } else {
-                "Unknown"
+    let wm = if needs.has(Module::Wm) {
+        hyprland::wm().or_else(sway::wm).unwrap_or_else(|| {
+            match get_env_var("XDG_SESSION_TYPE", "") {
+                "wayland" => {
+                    if de.contains("GNOME") {
+                        "Mutter"
+                    } else if de.contains("KDE") {
+                        "KWin"
+                    } else {
+                        "Unknown"
+                    }
+                }
+                _ => "Unknown",
             }
-        }
-        _ => "Unknown",
+            .to_string()
+        })
+    } else {
+        String::new()
+    };
+    let session = if needs.has(Module::Session) {
+        session::session_summary()
+    } else {
+        None
     };
 
     let terminal = get_env_var("TERM", "Unknown");
 
-    let resolution = display::get_screen_resolution();
-
-    let cpu_info = get_cpu_info();
+    let resolution = if needs.has(Module::Resolution) {
+        display::get_screen_resolution(&config.resolution_backends)
+    } else {
+        String::new()
+    };
+    let display = if needs.has(Module::Display) {
+        display::monitors_summary()
+    } else {
+        None
+    };
 
-    let (mem_used, mem_total) = get_memory_info();
+    let cpu_info = if needs.has(Module::Cpu) {
+        get_cpu_info(config.cpu_frequency)
+    } else {
+        String::new()
+    };
 
-    let os_name = if uts.sysname().to_string_lossy() == "Linux" {
-        format!("{} {}", &*DISTRO_NAME, uts.machine().to_string_lossy())
+    let (mem_used, mem_total) = if needs.has(Module::Memory) {
+        get_memory_info(config.memory_used)
     } else {
-        format!(
-            "{} {}",
-            uts.sysname().to_string_lossy(),
-            uts.machine().to_string_lossy()
+        (0, 0)
+    };
+    let memory_pressure = if config.memory_pressure {
+        proc::memory_pressure()
+    } else {
+        None
+    };
+
+    let (battery, battery_charge) = if needs.has(Module::Battery) {
+        (
+            battery::get_battery_info(config.accessible),
+            battery_charge(config),
         )
+    } else {
+        (None, None)
+    };
+
+    let boot_time = if needs.has(Module::Boot) {
+        boot_time(uptime)
+    } else {
+        None
+    };
+
+    let install_date = if needs.has(Module::InstallDate) {
+        install::install_date()
+    } else {
+        None
+    };
+
+    let cgroup = if config.cgroup_limits == CgroupLimits::Off {
+        cgroup::Limits::default()
+    } else {
+        cgroup::limits()
+    };
+
+    let cursor = if needs.has(Module::Cursor) {
+        xsettings::get("Gtk/CursorThemeName").or_else(|| {
+            Some(get_env_var("XCURSOR_THEME", ""))
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        })
+    } else {
+        None
+    };
+
+    let font = if needs.has(Module::Font) {
+        xsettings::get("Gtk/FontName")
+    } else {
+        None
+    };
+
+    let terminal_style = if config.terminal_details && needs.has(Module::Terminal) {
+        terminal::style_summary()
+    } else {
+        None
+    };
+
+    let theme_details = if needs.has(Module::Theme) {
+        theme::plasma_details()
+    } else {
+        None
+    };
+
+    let gnome_extensions = if needs.has(Module::GnomeExtensions) {
+        gnome::extensions_summary()
+    } else {
+        None
+    };
+
+    let machine_id = if needs.has(Module::MachineId) && !config.privacy {
+        identity::machine_identity()
+    } else {
+        None
+    };
+
+    let numa = if needs.has(Module::Numa) {
+        memory::numa_summary()
+    } else {
+        None
+    };
+
+    let hugepages = if needs.has(Module::Hugepages) {
+        memory::hugepages_summary()
+    } else {
+        String::new()
+    };
+
+    let network = if needs.has(Module::Network) {
+        net::traffic_summary()
+    } else {
+        None
+    };
+
+    let wifi = if needs.has(Module::Wifi) {
+        wifi::link()
+    } else {
+        None
+    };
+
+    let sound = if needs.has(Module::Sound) {
+        sound::sound_summary()
+    } else {
+        None
+    };
+
+    let session_uptime = if needs.has(Module::SessionUptime) {
+        session::session_uptime()
+    } else {
+        None
+    };
+
+    let virtualization = if needs.has(Module::Virtualization) {
+        virt::detect()
+    } else {
+        String::new()
+    };
+
+    let preemption = if needs.has(Module::Preemption) {
+        kernel::preemption_info(&uts.release)
+    } else {
+        String::new()
+    };
+
+    let kernel_modules = if needs.has(Module::KernelModules) {
+        kernel::modules_info()
+    } else {
+        String::new()
+    };
+
+    let cmdline = if needs.has(Module::Cmdline) {
+        kernel::cmdline_flags()
+    } else {
+        None
+    };
+
+    let systemd = if needs.has(Module::Systemd) {
+        systemd::health_summary()
+    } else {
+        None
+    };
+
+    let load = if needs.has(Module::Load) {
+        proc::load_average()
+    } else {
+        None
+    };
+
+    let media = if needs.has(Module::Media) {
+        media::now_playing()
+    } else {
+        None
+    };
+
+    let containers = if needs.has(Module::Containers) {
+        containers::containers_summary()
+    } else {
+        None
+    };
+
+    let weather = if needs.has(Module::Weather) {
+        weather::weather(&config.weather, config.privacy)
+    } else {
+        None
+    };
+
+    let extras = if needs.has(Module::Extras) {
+        extras::collect(&config.extras, uptime)
+    } else {
+        extras::Extras::default()
+    };
+
+    let security = if needs.has(Module::Security) {
+        security::security_summary()
+    } else {
+        None
+    };
+
+    let gpu = if needs.has(Module::Gpu) {
+        gpu::gpu_summary(config.gpu_stats)
+    } else {
+        None
+    };
+
+    let packages = if needs.has(Module::Packages) {
+        packages::counts(&config.package_backends)
+    } else {
+        Vec::new()
+    };
+
+    let updates = if needs.has(Module::Updates) {
+        updates::updates_summary()
+    } else {
+        None
+    };
+
+    let graphics = if needs.has(Module::Graphics) {
+        graphics::api_summary()
+    } else {
+        None
+    };
+
+    let temps = if needs.has(Module::Temps) {
+        thermal::zones()
+    } else {
+        Vec::new()
+    };
+
+    let fans = if needs.has(Module::Fans) {
+        fans::fans_summary()
+    } else {
+        None
+    };
+
+    let disks = if needs.has(Module::Disk) {
+        disk::disks(&config.disk.show, config.disk.hide_pseudo)
+    } else {
+        Vec::new()
+    };
+
+    let nvme = if needs.has(Module::Nvme) {
+        nvme::health_summary(config.temp_unit)
+    } else {
+        None
+    };
+
+    let raid = if needs.has(Module::Raid) {
+        raid::raid_summary()
+    } else {
+        None
+    };
+
+    let encryption = if needs.has(Module::Encryption) {
+        crypt::encryption_summary()
+    } else {
+        None
+    };
+
+    let full_name = if config.full_name && needs.has(Module::Title) {
+        session::full_name(get_env_var("USER", ""))
+    } else {
+        None
+    };
+
+    let details = if config.os_details {
+        OS_RELEASE.details()
+    } else {
+        None
+    };
+    let os_name = if uts.sysname == "Linux" {
+        match details {
+            Some(details) => format!("{} ({details}) {}", OS_RELEASE.display_name(), uts.machine),
+            None => format!("{} {}", OS_RELEASE.display_name(), uts.machine),
+        }
+    } else {
+        format!("{} {}", uts.sysname, uts.machine)
     };
 
     SysInfo {
-        hostname: String::from_utf8_lossy(&hostname).into_owned(),
+        hostname: hostname(),
+        full_name,
         os_name,
-        kernel: uts.release().to_string_lossy().into_owned(),
+        os_url: OS_RELEASE.home_url.clone(),
+        kernel: uts.release,
         uptime,
+        boot_time,
+        session_uptime,
+        install_date,
+        packages,
+        updates,
         shell: String::new(),
         terminal: terminal.to_string(),
+        terminal_style,
         de: de.to_string(),
-        wm: wm.to_string(),
+        wm,
+        session,
         theme: String::new(),
+        theme_details,
+        color_scheme: None,
         icons: String::new(),
+        cursor,
+        font,
+        gnome_extensions,
         resolution,
+        display,
         cpu_info,
+        gpu,
+        graphics,
+        temps,
+        fans,
         memory_used: mem_used,
         memory_total: mem_total,
+        memory_pressure,
+        disks,
+        nvme,
+        raid,
+        encryption,
+        cgroup,
+        numa,
+        hugepages,
+        network,
+        wifi,
+        sound,
+        virtualization,
+        preemption,
+        kernel_modules,
+        cmdline,
+        security,
+        systemd,
+        containers,
+        load,
+        media,
+        weather,
+        extras,
+        chassis: config.chassis,
+        machine_id,
+        battery,
+        battery_charge,
+        headless: false,
+    }
+}
+
+fn battery_charge(config: &Config) -> Option<u8> {
+    if !config.bar.modules.contains(&Module::Battery) {
+        return None;
     }
+    let capacities = battery::capacities();
+    let total: usize = capacities.iter().map(|(_, c)| usize::from(*c)).sum();
+    u8::try_from(total.checked_div(capacities.len())?).ok()
 }
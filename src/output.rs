@@ -0,0 +1,195 @@
+//! Output destinations
+//! Rendered output goes to stdout by default, or to a file, an inherited file
+//! descriptor or a unix socket (e.g. a fifo or socket a greeter reads from);
+//! everything is collected in one buffer and leaves in a single write per flush
+
+use std::fs::{self, File};
+use std::io::{self, StdoutLock, Write};
+use std::mem::ManuallyDrop;
+use std::os::fd::{FromRawFd, RawFd};
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+
+/// Where the output should be written, as given to `--output`
+pub enum Target {
+    Stdout,
+    /// `fd:N` - an already open descriptor inherited from the parent
+    Fd(RawFd),
+    /// `unix:PATH` - connect to a listening unix stream socket
+    Socket(PathBuf),
+    /// Anything else is a path; existing sockets and fifos are written to
+    /// directly, regular files are replaced atomically
+    Path(PathBuf),
+}
+
+impl Target {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value == "-" {
+            return Ok(Self::Stdout);
+        }
+        if let Some(fd) = value.strip_prefix("fd:") {
+            return fd
+                .parse::<RawFd>()
+                .map(Self::Fd)
+                .map_err(|_| format!("invalid file descriptor '{fd}'"));
+        }
+        if let Some(path) = value.strip_prefix("unix:") {
+            return Ok(Self::Socket(PathBuf::from(path)));
+        }
+        Ok(Self::Path(PathBuf::from(value)))
+    }
+}
+
+/// Room for a typical colored fetch, so the buffer rarely grows
+const BUFFER_CAPACITY: usize = 8 * 1024;
+
+pub struct Output {
+    inner: Inner,
+    /// Output written since the last flush
+    buf: Vec<u8>,
+}
+
+enum Inner {
+    Stdout(StdoutLock<'static>),
+    /// The descriptor belongs to the parent, so it is never closed here
+    Fd(RawFd, ManuallyDrop<File>),
+    Socket(UnixStream),
+    Stream(File),
+    Atomic(AtomicFile),
+}
+
+impl Output {
+    pub fn open(target: &Target) -> io::Result<Self> {
+        let inner = match target {
+            Target::Stdout => Inner::Stdout(io::stdout().lock()),
+            Target::Fd(fd) => {
+                // Make sure the descriptor is actually open before adopting it
+                if unsafe { libc::fcntl(*fd, libc::F_GETFD) } == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                Inner::Fd(*fd, ManuallyDrop::new(unsafe { File::from_raw_fd(*fd) }))
+            }
+            Target::Socket(path) => Inner::Socket(UnixStream::connect(path)?),
+            Target::Path(path) => match fs::metadata(path) {
+                Ok(meta) if meta.file_type().is_socket() => {
+                    Inner::Socket(UnixStream::connect(path)?)
+                }
+                // Renaming over a fifo or device would replace it rather than feed it
+                Ok(meta) if !meta.file_type().is_file() => {
+                    Inner::Stream(fs::OpenOptions::new().write(true).open(path)?)
+                }
+                existing => Inner::Atomic(AtomicFile::create(
+                    path,
+                    existing.ok().map(|meta| meta.permissions()),
+                )?),
+            },
+        };
+
+        Ok(Self {
+            inner,
+            buf: Vec::with_capacity(BUFFER_CAPACITY),
+        })
+    }
+
+    fn fd(&self) -> Option<RawFd> {
+        match &self.inner {
+            Inner::Stdout(_) => Some(libc::STDOUT_FILENO),
+            Inner::Fd(fd, _) => Some(*fd),
+            _ => None,
+        }
+    }
+
+    /// Whether the destination is a terminal, for color auto-detection
+    pub fn is_terminal(&self) -> bool {
+        self.fd().is_some_and(|fd| unsafe { libc::isatty(fd) == 1 })
+    }
+
+    /// Columns and rows of the destination terminal
+    pub fn terminal_size(&self) -> Option<(usize, usize)> {
+        let fd = self.fd()?;
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        if unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &raw mut size) } != 0 || size.ws_col == 0 {
+            return None;
+        }
+        Some((usize::from(size.ws_col), usize::from(size.ws_row)))
+    }
+
+    /// Flush and, for regular files, move the finished output into place
+    pub fn finish(mut self) -> io::Result<()> {
+        let flushed = self.flush();
+        match self.inner {
+            Inner::Atomic(atomic) => flushed.and_then(|()| atomic.persist()),
+            _ => flushed,
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let buf = &self.buf;
+        let result = match &mut self.inner {
+            Inner::Stdout(out) => out.write_all(buf).and_then(|()| out.flush()),
+            Inner::Fd(_, f) => f.write_all(buf),
+            Inner::Stream(f) | Inner::Atomic(AtomicFile { file: f, .. }) => f.write_all(buf),
+            Inner::Socket(s) => s.write_all(buf),
+        };
+        self.buf.clear();
+        result
+    }
+}
+
+/// Temporary file next to `dest`, renamed over it by `persist`; dropping it
+/// before that removes it, so a failed run leaves no partial output behind
+struct AtomicFile {
+    file: File,
+    tmp: PathBuf,
+    dest: PathBuf,
+    persisted: bool,
+}
+
+impl AtomicFile {
+    /// `permissions` are those of the file being replaced, which the rename
+    /// would otherwise reset to the defaults of a new file
+    fn create(dest: &Path, permissions: Option<fs::Permissions>) -> io::Result<Self> {
+        let tmp = temp_path(dest);
+        let atomic = Self {
+            file: File::create(&tmp)?,
+            tmp,
+            dest: dest.to_path_buf(),
+            persisted: false,
+        };
+        if let Some(permissions) = permissions {
+            atomic.file.set_permissions(permissions)?;
+        }
+        Ok(atomic)
+    }
+
+    fn persist(mut self) -> io::Result<()> {
+        self.file.sync_all()?;
+        fs::rename(&self.tmp, &self.dest)?;
+        self.persisted = true;
+        Ok(())
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if !self.persisted {
+            let _ = fs::remove_file(&self.tmp);
+        }
+    }
+}
+
+/// Hidden temporary file in the same directory, so the rename stays atomic
+fn temp_path(dest: &Path) -> PathBuf {
+    let name = dest
+        .file_name()
+        .map_or_else(|| "output".into(), |n| n.to_string_lossy());
+    dest.with_file_name(format!(".{name}.{}.tmp", std::process::id()))
+}
@@ -0,0 +1,268 @@
+//! Installed packages
+//! Counts per package manager, each found by its database being present
+//! rather than by its tools being on PATH
+
+use crate::backend::Backend;
+use crate::json::{self, Value};
+use crate::source;
+use crate::utils::{expand_path, get_env_var, run_command};
+use std::path::{Path, PathBuf};
+
+/// Package managers, usable in `package_backends`
+pub static PACKAGE_BACKENDS: &[&dyn Backend] = &[
+    &Manager("pacman", pacman),
+    &Manager("dpkg", dpkg),
+    &Manager("rpm", rpm),
+    &Manager("xbps", xbps),
+    &Manager("apk", apk),
+    &Manager("portage", portage),
+    &Manager("flatpak", flatpak),
+    &Manager("snap", snap),
+    &Manager("cargo", cargo),
+    &Manager("pipx", pipx),
+    &Manager("pip", pip),
+    &Manager("npm", npm),
+];
+
+/// Counted unless the config says otherwise; the language package managers
+/// have to be asked for
+pub static DEFAULT_BACKENDS: &[&str] = &[
+    "pacman", "dpkg", "rpm", "xbps", "apk", "portage", "flatpak", "snap",
+];
+
+/// A package manager's name and how to count what it installed
+struct Manager(&'static str, fn() -> Option<usize>);
+
+impl Backend for Manager {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn detect(&self) -> Option<String> {
+        (self.1)().map(|count| count.to_string())
+    }
+}
+
+/// Packages installed by each manager in `order` that has any
+pub fn counts(order: &[String]) -> Vec<(&'static str, usize)> {
+    order
+        .iter()
+        .filter_map(|name| {
+            let backend = PACKAGE_BACKENDS.iter().find(|b| b.name() == name)?;
+            let count = backend.detect()?.parse().ok()?;
+            (count > 0).then_some((backend.name(), count))
+        })
+        .collect()
+}
+
+/// e.g. `1243 (pacman), 18 (flatpak), 4 (snap)`
+pub fn packages_summary(counts: &[(&str, usize)]) -> Option<String> {
+    let counts: Vec<String> = counts
+        .iter()
+        .map(|(name, count)| format!("{count} ({name})"))
+        .collect();
+    (!counts.is_empty()).then(|| counts.join(", "))
+}
+
+fn read(path: &str) -> Option<String> {
+    source::current().read_to_string(Path::new(path)).ok()
+}
+
+/// Entries of a directory, `None` if it doesn't exist
+fn entries(path: &Path) -> Option<usize> {
+    source::current().read_dir(path).ok().map(|e| e.len())
+}
+
+/// One directory per package, next to an `ALPM_DB_VERSION` file
+fn pacman() -> Option<usize> {
+    let packages = source::current()
+        .read_dir(Path::new("/var/lib/pacman/local"))
+        .ok()?;
+    Some(
+        packages
+            .iter()
+            .filter(|p| p.file_name().is_some_and(|n| n != "ALPM_DB_VERSION"))
+            .count(),
+    )
+}
+
+/// Paragraphs of the status file, counting only installed packages and not
+/// removed ones whose config files linger
+fn dpkg() -> Option<usize> {
+    let status = read("/var/lib/dpkg/status")?;
+    Some(
+        status
+            .lines()
+            .filter(|line| *line == "Status: install ok installed")
+            .count(),
+    )
+}
+
+/// The database is Berkeley DB or SQLite depending on the release, so rpm
+/// itself reads it
+fn rpm() -> Option<usize> {
+    let source = source::current();
+    let present = ["/var/lib/rpm", "/usr/lib/sysimage/rpm"]
+        .iter()
+        .any(|dir| source.exists(Path::new(dir)));
+    if !present {
+        return None;
+    }
+    Some(run_command("rpm", &["-qa"])?.lines().count())
+}
+
+/// A plist dictionary per package, each with a `pkgver` key
+fn xbps() -> Option<usize> {
+    let db = read("/var/db/xbps/pkgdb-0.38.plist")?;
+    Some(db.matches("<key>pkgver</key>").count())
+}
+
+/// A `P:` line naming each package
+fn apk() -> Option<usize> {
+    let db = read("/lib/apk/db/installed")?;
+    Some(db.lines().filter(|line| line.starts_with("P:")).count())
+}
+
+/// `category/package-version` directories
+fn portage() -> Option<usize> {
+    let source = source::current();
+    let categories = source.read_dir(Path::new("/var/db/pkg")).ok()?;
+    Some(categories.iter().filter_map(|c| entries(c)).sum())
+}
+
+/// Apps and runtimes, system-wide and per user, each branch of each arch
+/// counted as it's installed separately
+fn flatpak() -> Option<usize> {
+    let source = source::current();
+    let installations = [
+        Path::new("/var/lib/flatpak").to_path_buf(),
+        expand_path("~/.local/share/flatpak"),
+    ];
+    if !installations.iter().any(|dir| source.exists(dir)) {
+        return None;
+    }
+
+    let mut count = 0;
+    for installation in &installations {
+        for kind in ["app", "runtime"] {
+            for name in source
+                .read_dir(&installation.join(kind))
+                .unwrap_or_default()
+            {
+                // `current` links to the default arch and branch
+                for arch in source.read_dir(&name).unwrap_or_default() {
+                    if arch.file_name().is_some_and(|n| n != "current") {
+                        count += entries(&arch).unwrap_or(0);
+                    }
+                }
+            }
+        }
+    }
+    Some(count)
+}
+
+/// Mounted snaps under `/snap`, which also holds the `bin` directory
+fn snap() -> Option<usize> {
+    let source = source::current();
+    if !source.exists(Path::new("/var/lib/snapd/state.json")) {
+        return None;
+    }
+    let snaps = source.read_dir(Path::new("/snap")).ok()?;
+    Some(
+        snaps
+            .iter()
+            .filter(|s| s.file_name().is_some_and(|n| n != "bin" && n != "README"))
+            .count(),
+    )
+}
+
+/// A directory from an environment variable, or a default under the home
+fn env_dir(var: &str, default: &str) -> PathBuf {
+    match get_env_var(var, "") {
+        "" => expand_path(default),
+        dir => PathBuf::from(dir),
+    }
+}
+
+/// Binaries from `cargo install`, one key per crate under `installs`
+fn cargo() -> Option<usize> {
+    let path = env_dir("CARGO_HOME", "~/.cargo").join(".crates2.json");
+    let crates = json::parse(&source::current().read_to_string(&path).ok()?)?;
+    match crates.get("installs")? {
+        Value::Object(installs) => Some(installs.len()),
+        _ => None,
+    }
+}
+
+/// One virtualenv per app; pipx moved its home from `~/.local/pipx`
+fn pipx() -> Option<usize> {
+    let homes = match get_env_var("PIPX_HOME", "") {
+        "" => vec![
+            expand_path("~/.local/share/pipx"),
+            expand_path("~/.local/pipx"),
+        ],
+        home => vec![PathBuf::from(home)],
+    };
+    homes.iter().find_map(|home| entries(&home.join("venvs")))
+}
+
+/// `.dist-info` directories in the user site of each Python version
+fn pip() -> Option<usize> {
+    let source = source::current();
+    let pythons = source.read_dir(&expand_path("~/.local/lib")).ok()?;
+    let mut count = None;
+    for python in pythons {
+        if !python
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with("python"))
+        {
+            continue;
+        }
+        let Ok(packages) = source.read_dir(&python.join("site-packages")) else {
+            continue;
+        };
+        *count.get_or_insert(0) += packages
+            .iter()
+            .filter(|p| p.extension().is_some_and(|e| e == "dist-info"))
+            .count();
+    }
+    count
+}
+
+/// Packages in the global `node_modules`, scoped ones counted inside their
+/// `@scope` directory
+fn npm() -> Option<usize> {
+    let source = source::current();
+    let prefixes = match get_env_var("NPM_CONFIG_PREFIX", "") {
+        "" => {
+            // A user prefix set with `npm config set prefix`
+            let npmrc = source
+                .read_to_string(&expand_path("~/.npmrc"))
+                .unwrap_or_default();
+            let user = npmrc.lines().find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                (key.trim() == "prefix").then(|| expand_path(value.trim()))
+            });
+            user.into_iter()
+                .chain(["/usr/local", "/usr"].map(PathBuf::from))
+                .collect()
+        }
+        prefix => vec![PathBuf::from(prefix)],
+    };
+
+    let modules = prefixes
+        .iter()
+        .find_map(|prefix| source.read_dir(&prefix.join("lib/node_modules")).ok())?;
+    let mut count = 0;
+    for module in modules {
+        let Some(name) = module.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        if name.starts_with('@') {
+            count += entries(&module).unwrap_or(0);
+        } else if !name.starts_with('.') {
+            count += 1;
+        }
+    }
+    Some(count)
+}
@@ -0,0 +1,196 @@
+//! Palette matching
+//! Reads the palette the terminal is using, from pywal's cache or by asking
+//! the terminal, so the logo and accents can be recolored to fit it
+
+use crate::color::Color;
+use crate::export;
+use crate::log;
+use crate::source;
+use crate::utils::expand_path;
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant};
+
+/// How long to wait for the terminal to answer
+const QUERY_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// Squared distance under which a color is hard to tell from the background
+const BACKGROUND_DISTANCE: u32 = 48 * 48 * 3;
+
+pub struct Palette {
+    /// The 16 ANSI colors
+    colors: [(u8, u8, u8); 16],
+    foreground: Option<(u8, u8, u8)>,
+    background: Option<(u8, u8, u8)>,
+}
+
+impl Palette {
+    /// The palette's version of a color: the basic 16 by index, with white
+    /// as the text color, and anything else as the nearest palette entry that
+    /// stands out from the background
+    pub fn recolor(&self, color: Color) -> Color {
+        let (r, g, b) = match color {
+            Color::Palette(7) => self.foreground.unwrap_or(self.colors[7]),
+            Color::Palette(n @ 0..=15) => self.colors[usize::from(n)],
+            other => self.nearest(export::rgb(other)),
+        };
+        Color::Rgb(r, g, b)
+    }
+
+    fn nearest(&self, target: (u8, u8, u8)) -> (u8, u8, u8) {
+        let visible = |c: &&(u8, u8, u8)| {
+            self.background
+                .is_none_or(|bg| distance(**c, bg) >= BACKGROUND_DISTANCE)
+        };
+        // Black and its bright variant are left for the background
+        self.colors
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 0 && *i != 8)
+            .map(|(_, c)| c)
+            .filter(visible)
+            .min_by_key(|c| distance(**c, target))
+            .copied()
+            .unwrap_or(target)
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// pywal's palette: 16 `#rrggbb` lines, background first and text color
+/// last
+pub fn wal() -> Option<Palette> {
+    let path = expand_path("~/.cache/wal/colors");
+    let Ok(content) = source::current().read_to_string(&path) else {
+        log::fail!("no pywal palette at {}", path.display());
+        return None;
+    };
+
+    let mut colors = [(0, 0, 0); 16];
+    let mut lines = content.lines();
+    for slot in &mut colors {
+        let Some(Color::Rgb(r, g, b)) = lines.next().and_then(|l| Color::parse(l.trim())) else {
+            log::fail!("{} doesn't hold 16 colors", path.display());
+            return None;
+        };
+        *slot = (r, g, b);
+    }
+
+    Some(Palette {
+        colors,
+        foreground: Some(colors[15]),
+        background: Some(colors[0]),
+    })
+}
+
+/// Ask the terminal for its palette (OSC 4) and its text and background
+/// colors (OSC 10, 11)
+pub fn terminal() -> Option<Palette> {
+    let Some(replies) = query_terminal() else {
+        log::fail!("the terminal didn't report its palette");
+        return None;
+    };
+
+    let mut colors = [None; 16];
+    let mut foreground = None;
+    let mut background = None;
+    // Replies look like `ESC ] 4 ; 1 ; rgb:cccc/0000/0000 BEL`
+    for reply in replies.split("\x1b]").skip(1) {
+        let reply = reply.split(['\x07', '\x1b']).next().unwrap_or("");
+        let mut fields = reply.split(';');
+        match fields.next() {
+            Some("4") => {
+                let index: Option<usize> = fields.next().and_then(|n| n.parse().ok());
+                if let Some(slot) = index.and_then(|i| colors.get_mut(i)) {
+                    *slot = fields.next().and_then(parse_rgb);
+                }
+            }
+            Some("10") => foreground = fields.next().and_then(parse_rgb),
+            Some("11") => background = fields.next().and_then(parse_rgb),
+            _ => {}
+        }
+    }
+
+    let Some(colors) = colors.into_iter().collect::<Option<Vec<_>>>() else {
+        log::fail!("the terminal didn't report all 16 colors");
+        return None;
+    };
+    Some(Palette {
+        colors: colors.try_into().ok()?,
+        foreground,
+        background,
+    })
+}
+
+/// `rgb:rrrr/gggg/bbbb`, with 1 to 4 hex digits per channel
+fn parse_rgb(spec: &str) -> Option<(u8, u8, u8)> {
+    let mut channels = spec.strip_prefix("rgb:")?.split('/').map(|hex| {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len().clamp(1, 4))) - 1;
+        u8::try_from(value * 255 / max).ok()
+    });
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
+
+/// Send the color queries to the controlling terminal and collect what it
+/// answers; a device attributes query goes last, as every terminal answers
+/// that one, so there is no need to wait out the timeout
+fn query_terminal() -> Option<String> {
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let fd = tty.as_raw_fd();
+
+    let mut saved: libc::termios = unsafe { std::mem::zeroed() };
+    if unsafe { libc::tcgetattr(fd, &raw mut saved) } != 0 {
+        return None;
+    }
+    // Read the replies as they come, without echoing them
+    let mut raw = saved;
+    raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+    if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw const raw) } != 0 {
+        return None;
+    }
+
+    let mut query = String::new();
+    for n in 0..16 {
+        query.push_str(&format!("\x1b]4;{n};?\x07"));
+    }
+    query.push_str("\x1b]10;?\x07\x1b]11;?\x07\x1b[c");
+
+    let mut replies = Vec::new();
+    if tty.write_all(query.as_bytes()).is_ok() {
+        let deadline = Instant::now() + QUERY_TIMEOUT;
+        let mut buf = [0u8; 1024];
+        while !answered(&replies) {
+            let left = deadline.saturating_duration_since(Instant::now());
+            let mut poll = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let timeout = i32::try_from(left.as_millis()).unwrap_or(0);
+            if left.is_zero() || unsafe { libc::poll(&raw mut poll, 1, timeout) } <= 0 {
+                break;
+            }
+            match tty.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => replies.extend_from_slice(&buf[..n]),
+            }
+        }
+    }
+
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw const saved) };
+    Some(String::from_utf8_lossy(&replies).into_owned())
+}
+
+/// Whether the device attributes reply (`ESC [ ? ... c`) has arrived
+fn answered(replies: &[u8]) -> bool {
+    memchr::memmem::find(replies, b"\x1b[?").is_some_and(|start| replies[start..].contains(&b'c'))
+}
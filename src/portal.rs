@@ -0,0 +1,76 @@
+//! Settings portal
+//! Desktop settings as `xdg-desktop-portal` hands them to apps, the same on
+//! GNOME, KDE and wlroots desktops and from inside a Flatpak sandbox
+
+use crate::dbus::{self, Connection};
+use crate::log;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+
+/// Namespaces asked for; the KDE portal names kdeglobals groups
+/// `org.kde.kdeglobals.<group>`
+const NAMESPACES: &[&str] = &[
+    "org.freedesktop.appearance",
+    "org.gnome.desktop.interface",
+    "org.kde.kdeglobals.*",
+];
+
+/// String and number settings by namespace and key, read once for every caller
+static SETTINGS: OnceLock<Option<FxHashMap<(String, String), String>>> = OnceLock::new();
+
+/// A setting, numbers in decimal
+pub fn setting(namespace: &str, key: &str) -> Option<String> {
+    let settings = SETTINGS.get_or_init(|| {
+        let settings = read_all();
+        if settings.is_none() {
+            log::fail!("portal: no settings portal on the session bus");
+        }
+        settings
+    });
+    settings
+        .as_ref()?
+        .get(&(namespace.to_string(), key.to_string()))
+        .filter(|value| !value.is_empty())
+        .cloned()
+}
+
+/// `org.freedesktop.portal.Settings.ReadAll`, answering `a{sa{sv}}`
+fn read_all() -> Option<FxHashMap<(String, String), String>> {
+    let mut conn = Connection::session().ok()?;
+    let mut body = dbus::Writer::default();
+    body.strings(NAMESPACES);
+    let reply = conn
+        .call(
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.Settings",
+            "ReadAll",
+            "as",
+            &body.buf,
+        )
+        .ok()?;
+
+    let mut settings = FxHashMap::default();
+    let mut reader = reply.reader();
+    let end = reader.array_end(8)?;
+    while reader.pos < end {
+        reader.align(8);
+        let namespace = reader.string()?;
+        let entries_end = reader.array_end(8)?;
+        while reader.pos < entries_end {
+            reader.align(8);
+            let key = reader.string()?;
+            let signature = reader.signature()?;
+            let value = match signature {
+                "s" => reader.string().map(str::to_string),
+                "u" => reader.u32().map(|n| n.to_string()),
+                _ => {
+                    reader.skip(signature)?;
+                    continue;
+                }
+            };
+            settings.insert((namespace.to_string(), key.to_string()), value?);
+        }
+    }
+    Some(settings)
+}
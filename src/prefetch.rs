@@ -0,0 +1,70 @@
+//! Cold-cache readahead
+//! Right after startup, asks the kernel to start reading the on-disk files
+//! the detection code will parse, so that on a cold page cache (first run
+//! after boot, slow HDD or SD card) the I/O overlaps with other work instead
+//! of stalling each reader in turn
+
+use crate::config::{Config, LogoOverride};
+use crate::gpu;
+use crate::modules::Module;
+use crate::theme;
+use crate::utils::expand_path;
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+/// Files read unconditionally while collecting info; `/proc` and `/sys` are
+/// not listed since they never touch the disk
+static STARTUP_FILES: &[&str] = &["/etc/os-release", "/usr/lib/os-release"];
+
+/// Issue `posix_fadvise(WILLNEED)` for the files this run will read
+///
+/// The opens happen on a detached thread, since path lookups themselves block
+/// on a cold cache; the thread is simply abandoned if it's still busy at exit.
+pub fn start(config: &Config, shell_path: &str) {
+    let mut paths: Vec<PathBuf> = STARTUP_FILES.iter().map(PathBuf::from).collect();
+
+    // The shell is executed for its version, so its binary is read too
+    paths.push(PathBuf::from(shell_path));
+
+    // Theme and icon detection always run on their own threads
+    paths.extend(
+        theme::THEME_CONFIG_PATHS
+            .iter()
+            .chain(theme::ICON_CONFIG_PATHS)
+            .map(|path| expand_path(path)),
+    );
+
+    if config.has_module(Module::Greeting) {
+        paths.extend(config.greeting.file.clone());
+    }
+    if config.has_module(Module::Extras) {
+        paths.extend(config.extras.quote_file.clone());
+    }
+    // Over a megabyte, scanned for the GPU's name
+    if config.has_module(Module::Gpu) {
+        paths.extend(gpu::PCI_IDS_PATHS.iter().map(PathBuf::from));
+    }
+
+    paths.extend(
+        config
+            .logos
+            .iter()
+            .map(|(_, LogoOverride { file, .. })| file.clone()),
+    );
+
+    paths.sort_unstable();
+    paths.dedup();
+
+    let _ = thread::Builder::new()
+        .name("prefetch".into())
+        .spawn(move || paths.iter().for_each(|path| will_need(path)));
+}
+
+fn will_need(path: &Path) {
+    // Whole file; the kernel caps the readahead window itself
+    if let Ok(file) = File::open(path) {
+        unsafe { libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_WILLNEED) };
+    }
+}
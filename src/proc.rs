@@ -2,26 +2,129 @@
 //! This provides zero-copy, zero-allocation parsers for /proc
 //! and can extract values with just a single pass through the file
 
+use crate::log;
+use crate::source;
 use crate::utils::{matches_at, parse_number_after};
-use std::fs::File;
-use std::io::Result;
+use std::ffi::CStr;
+use std::io::{self, Result};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::path::Path;
+use std::sync::Mutex;
 
-const REQUIRED: usize = 6;
+const REQUIRED: usize = 7;
+
+/// The /proc files read on every run, and again on every refresh
+#[derive(Clone, Copy)]
+pub enum ProcFile {
+    Meminfo,
+    Cpuinfo,
+    Stat,
+}
+
+impl ProcFile {
+    const COUNT: usize = 3;
+
+    fn path(self) -> &'static CStr {
+        match self {
+            Self::Meminfo => c"/proc/meminfo",
+            Self::Cpuinfo => c"/proc/cpuinfo",
+            Self::Stat => c"/proc/stat",
+        }
+    }
+}
+
+/// Shared reader for the hot /proc files
+///
+/// procfs can't be mapped, so each file instead stays open after its first
+/// read and is re-read from offset 0 with `pread` into a buffer that is kept
+/// for reuse: a refresh in watch or serve mode costs one syscall per file.
+struct ProcReader {
+    files: [Option<OwnedFd>; ProcFile::COUNT],
+    buf: Vec<u8>,
+}
+
+static READER: Mutex<ProcReader> = Mutex::new(ProcReader {
+    files: [None, None, None],
+    buf: Vec::new(),
+});
+
+impl ProcReader {
+    fn fd(&mut self, file: ProcFile) -> Result<i32> {
+        let slot = &mut self.files[file as usize];
+        if let Some(fd) = slot {
+            return Ok(fd.as_raw_fd());
+        }
+        let fd = unsafe { libc::open(file.path().as_ptr(), libc::O_RDONLY | libc::O_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(slot.insert(unsafe { OwnedFd::from_raw_fd(fd) }).as_raw_fd())
+    }
+
+    /// Fill the buffer with up to `limit` bytes from the start of `file`
+    fn fill(&mut self, file: ProcFile, limit: usize) -> Result<&[u8]> {
+        let fd = self.fd(file)?;
+        self.buf.clear();
+
+        // Files are generated a page at a time; keep reading until the end
+        while self.buf.len() < limit {
+            let len = self.buf.len();
+            let chunk = (limit - len).min(4096);
+            self.buf.resize(len + chunk, 0);
+            #[allow(clippy::cast_possible_wrap)]
+            let read = unsafe {
+                libc::pread(
+                    fd,
+                    self.buf[len..].as_mut_ptr().cast(),
+                    chunk,
+                    len as libc::off_t,
+                )
+            };
+            let Ok(read) = usize::try_from(read) else {
+                self.buf.truncate(len);
+                return Err(io::Error::last_os_error());
+            };
+            self.buf.truncate(len + read);
+            if read == 0 {
+                break;
+            }
+        }
+        Ok(&self.buf)
+    }
+}
+
+/// Run `parse` on at most the first `limit` bytes of `file`
+pub fn read<T>(file: ProcFile, limit: usize, parse: impl FnOnce(&[u8]) -> T) -> Result<T> {
+    let source = source::current();
+    if !source.is_live() {
+        let path = file.path().to_str().unwrap_or_default();
+        let data = source.read(Path::new(path))?;
+        return Ok(parse(&data[..limit.min(data.len())]));
+    }
+
+    // A panicking parser can't leave the buffer in a state that matters
+    let mut reader = READER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    reader.fill(file, limit).map(parse)
+}
 
 /// Fast specialized parser for memory info
-/// Returns used and total memory in bytes according to the formula:
+/// Returns used and total memory in bytes; used is either
+/// Total - `MemAvailable`, or, with `use_available` unset or on kernels
+/// without `MemAvailable`, computed with the formula:
 /// Used = Total - Free - Buffers - Cached - `SReclaimable` + Shmem
-pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
-    let mut buffer = [0u8; 4096];
-    let mut file = File::open("/proc/meminfo")?;
-
-    let bytes_read = std::io::Read::read(&mut file, &mut buffer)?;
-    if bytes_read == 0 {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::UnexpectedEof,
-            "Empty file",
-        ));
-    }
+pub fn fast_parse_meminfo(use_available: bool) -> Result<(u64, u64)> {
+    read(ProcFile::Meminfo, 4096, |buffer| {
+        if buffer.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Empty file"));
+        }
+        Ok(parse_meminfo(buffer, use_available))
+    })?
+}
+
+pub fn parse_meminfo(buffer: &[u8], use_available: bool) -> (u64, u64) {
+    let bytes_read = buffer.len();
 
     let mut total: u64 = 0;
     let mut free: u64 = 0;
@@ -29,6 +132,7 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
     let mut cached: u64 = 0;
     let mut sreclaimable: u64 = 0;
     let mut shmem: u64 = 0;
+    let mut available: u64 = 0;
 
     let total_pattern = b"MemTotal:";
     let free_pattern = b"MemFree:";
@@ -36,6 +140,7 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
     let cached_pattern = b"Cached:";
     let sreclaimable_pattern = b"SReclaimable:";
     let shmem_pattern = b"Shmem:";
+    let available_pattern = b"MemAvailable:";
 
     let mut pos = 0;
     let mut found = 0;
@@ -66,15 +171,14 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
                 continue;
             }
         } else if cached == 0 && matches_at(&buffer[pos..], cached_pattern) {
-            if pos == 0 || buffer[pos - 1] == b'\n' {
-                if let Some((value, new_pos)) =
+            if (pos == 0 || buffer[pos - 1] == b'\n')
+                && let Some((value, new_pos)) =
                     parse_number_after(&buffer[pos..], cached_pattern.len())
-                {
-                    cached = value;
-                    pos += new_pos;
-                    found += 1;
-                    continue;
-                }
+            {
+                cached = value;
+                pos += new_pos;
+                found += 1;
+                continue;
             }
         } else if sreclaimable == 0 && matches_at(&buffer[pos..], sreclaimable_pattern) {
             if let Some((value, new_pos)) =
@@ -93,6 +197,15 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
                 found += 1;
                 continue;
             }
+        } else if available == 0
+            && matches_at(&buffer[pos..], available_pattern)
+            && let Some((value, new_pos)) =
+                parse_number_after(&buffer[pos..], available_pattern.len())
+        {
+            available = value;
+            pos += new_pos;
+            found += 1;
+            continue;
         }
 
         if let Some(nl_pos) = memchr::memchr(b'\n', &buffer[pos..bytes_read]) {
@@ -103,7 +216,9 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
     }
 
     let total_bytes = total << 10;
-    let adjusted_used = if total > 0 {
+    let adjusted_used = if use_available && available > 0 {
+        total.saturating_sub(available)
+    } else if total > 0 {
         let non_used = free + buffers + cached + sreclaimable;
         let base_used = total.saturating_sub(non_used);
         base_used + shmem
@@ -113,5 +228,42 @@ pub fn fast_parse_meminfo() -> Result<(u64, u64)> {
 
     let used_bytes = adjusted_used * 1024;
 
-    Ok((used_bytes, total_bytes))
+    (used_bytes, total_bytes)
+}
+
+/// Boot time as a Unix timestamp, from the `btime` line of /proc/stat
+pub fn boot_time() -> Option<u64> {
+    read(ProcFile::Stat, usize::MAX, |stat| {
+        let pos = memchr::memmem::find(stat, b"\nbtime ")? + 1;
+        parse_number_after(stat, pos + b"btime".len()).map(|(value, _)| value)
+    })
+    .ok()?
+}
+
+/// 1, 5 and 15 minute load averages, e.g. `0.52, 0.61, 0.70`
+pub fn load_average() -> Option<String> {
+    let loadavg = source::current()
+        .read_to_string(Path::new("/proc/loadavg"))
+        .inspect_err(|e| log::fail!("load: cannot read /proc/loadavg: {e}"))
+        .ok()?;
+    let fields: Vec<&str> = loadavg.split_whitespace().take(3).collect();
+    (fields.len() == 3).then(|| fields.join(", "))
+}
+
+/// `avg10` of the "some" line in /proc/pressure/memory: the share of the last
+/// ten seconds in which at least one task stalled waiting for memory
+pub fn memory_pressure() -> Option<f32> {
+    // Needs a kernel built with CONFIG_PSI
+    let pressure = source::current()
+        .read_to_string(Path::new("/proc/pressure/memory"))
+        .inspect_err(|e| log::fail!("memory pressure: cannot read /proc/pressure/memory: {e}"))
+        .ok()?;
+    // `some avg10=0.12 avg60=0.05 avg300=0.01 total=123456`
+    pressure
+        .lines()
+        .find_map(|line| line.strip_prefix("some "))?
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("avg10="))?
+        .parse()
+        .ok()
 }
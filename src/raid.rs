@@ -0,0 +1,56 @@
+//! Software RAID
+//! md array health from `/proc/mdstat`
+
+use crate::source;
+use std::path::Path;
+
+/// e.g. `md0 active raid1 [UU], md1 active raid5 [UU_] (recovery 12.6%)`;
+/// `None` without arrays
+pub fn raid_summary() -> Option<String> {
+    let mdstat = source::current()
+        .read_to_string(Path::new("/proc/mdstat"))
+        .ok()?;
+    let arrays = parse_mdstat(&mdstat);
+    (!arrays.is_empty()).then(|| arrays.join(", "))
+}
+
+/// Each array starts with `md0 : active raid1 sdb1[1] sda1[0]`, followed by
+/// indented lines with the member map (`[2/2] [UU]`) and any resync progress
+fn parse_mdstat(mdstat: &str) -> Vec<String> {
+    let mut arrays: Vec<String> = Vec::new();
+    for line in mdstat.lines() {
+        if let Some((name, status)) = line.split_once(" : ")
+            && name.starts_with("md")
+        {
+            // State and level come before the members, e.g. `sda1[0]`; a
+            // stopped array has no level
+            let words: Vec<&str> = status
+                .split_whitespace()
+                .take_while(|w| !w.contains('[') && !w.starts_with('('))
+                .collect();
+            arrays.push(format!("{name} {}", words.join(" ")));
+            continue;
+        }
+        let Some(array) = arrays.last_mut() else {
+            continue;
+        };
+        if let Some(map) = line.split_whitespace().find(|w| is_member_map(w)) {
+            array.push(' ');
+            array.push_str(map);
+        } else if let Some((before, after)) = line.split_once(" = ")
+            && let Some(action) = before.split_whitespace().last()
+        {
+            // `[===>.....]  recovery = 12.6% (123/976) finish=...`
+            let percent = after.split_whitespace().next().unwrap_or_default();
+            array.push_str(&format!(" ({action} {percent})"));
+        }
+    }
+    arrays
+}
+
+/// `[UU_]`: up and missing members
+fn is_member_map(word: &str) -> bool {
+    word.strip_prefix('[')
+        .and_then(|w| w.strip_suffix(']'))
+        .is_some_and(|map| !map.is_empty() && map.chars().all(|c| c == 'U' || c == '_'))
+}
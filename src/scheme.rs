@@ -0,0 +1,186 @@
+//! Color schemes
+//! Named presets for the colors of the info block's keys, separator and
+//! palette blocks; configs can add their own under `[schemes.<name>]`
+
+use crate::color::{Color, Depth};
+
+/// How a part of the info block is colored
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    /// The color of the logo line next to it
+    Logo,
+    /// Left uncolored
+    Plain,
+    Color(Color),
+}
+
+impl Paint {
+    /// `logo`, `none`, a palette index or `#rrggbb`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "logo" => Some(Self::Logo),
+            "none" => Some(Self::Plain),
+            _ => Color::parse(value).map(Self::Color),
+        }
+    }
+
+    /// Escape sequence to draw with; `None` follows the logo and an empty
+    /// sequence leaves the text uncolored
+    pub fn sequence(self, depth: Depth) -> Option<String> {
+        match self {
+            Self::Logo => None,
+            Self::Plain => Some(String::new()),
+            Self::Color(color) => Some(color.sequence(depth)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Scheme {
+    /// Keys and the title
+    pub key: Paint,
+    pub separator: Paint,
+    /// Blocks of the `colors` module; the logo's colors when `None`
+    pub blocks: Option<Vec<Color>>,
+}
+
+/// The eight basic terminal colors
+const BASIC: &[Color] = &[
+    Color::Palette(0),
+    Color::Palette(1),
+    Color::Palette(2),
+    Color::Palette(3),
+    Color::Palette(4),
+    Color::Palette(5),
+    Color::Palette(6),
+    Color::Palette(7),
+];
+
+impl Default for Scheme {
+    fn default() -> Self {
+        Self {
+            key: Paint::Logo,
+            separator: Paint::Plain,
+            blocks: Some(BASIC.to_vec()),
+        }
+    }
+}
+
+/// A preset from hex colors
+fn hex(key: u32, separator: u32, blocks: [u32; 8]) -> Scheme {
+    let rgb = |c: u32| {
+        let [_, r, g, b] = c.to_be_bytes();
+        Color::Rgb(r, g, b)
+    };
+    Scheme {
+        key: Paint::Color(rgb(key)),
+        separator: Paint::Color(rgb(separator)),
+        blocks: Some(blocks.into_iter().map(rgb).collect()),
+    }
+}
+
+fn presets() -> Vec<(String, Scheme)> {
+    let catppuccin_mocha = hex(
+        0xcba6f7,
+        0x6c7086,
+        [
+            0x45475a, 0xf38ba8, 0xa6e3a1, 0xf9e2af, 0x89b4fa, 0xf5c2e7, 0x94e2d5, 0xbac2de,
+        ],
+    );
+    let gruvbox_dark = hex(
+        0xfabd2f,
+        0x665c54,
+        [
+            0x282828, 0xcc241d, 0x98971a, 0xd79921, 0x458588, 0xb16286, 0x689d6a, 0xa89984,
+        ],
+    );
+
+    [
+        ("default", Scheme::default()),
+        (
+            "distro",
+            Scheme {
+                key: Paint::Logo,
+                separator: Paint::Logo,
+                blocks: None,
+            },
+        ),
+        (
+            "mono",
+            Scheme {
+                key: Paint::Plain,
+                separator: Paint::Plain,
+                blocks: Some(vec![
+                    Color::Palette(8),
+                    Color::Palette(7),
+                    Color::Palette(15),
+                ]),
+            },
+        ),
+        ("catppuccin", catppuccin_mocha.clone()),
+        ("catppuccin-mocha", catppuccin_mocha),
+        (
+            "catppuccin-latte",
+            hex(
+                0x8839ef,
+                0x9ca0b0,
+                [
+                    0x5c5f77, 0xd20f39, 0x40a02b, 0xdf8e1d, 0x1e66f5, 0xea76cb, 0x179299, 0xacb0be,
+                ],
+            ),
+        ),
+        ("gruvbox", gruvbox_dark.clone()),
+        ("gruvbox-dark", gruvbox_dark),
+        (
+            "gruvbox-light",
+            hex(
+                0xb57614,
+                0xbdae93,
+                [
+                    0xfbf1c7, 0xcc241d, 0x98971a, 0xd79921, 0x458588, 0xb16286, 0x689d6a, 0x7c6f64,
+                ],
+            ),
+        ),
+        (
+            "nord",
+            hex(
+                0x88c0d0,
+                0x4c566a,
+                [
+                    0x3b4252, 0xbf616a, 0xa3be8c, 0xebcb8b, 0x81a1c1, 0xb48ead, 0x88c0d0, 0xe5e9f0,
+                ],
+            ),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, scheme)| (name.to_string(), scheme))
+    .collect()
+}
+
+/// The schemes `theme` can name: the presets, then those from the config
+pub struct Registry {
+    schemes: Vec<(String, Scheme)>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self { schemes: presets() }
+    }
+}
+
+impl Registry {
+    pub fn get(&self, name: &str) -> Option<&Scheme> {
+        self.schemes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, scheme)| scheme)
+    }
+
+    /// Add a scheme, replacing one of the same name
+    pub fn register(&mut self, name: &str, scheme: Scheme) {
+        match self.schemes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = scheme,
+            None => self.schemes.push((name.to_string(), scheme)),
+        }
+    }
+}
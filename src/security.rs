@@ -0,0 +1,125 @@
+//! Security posture
+//! Active Linux Security Modules with their SELinux/AppArmor state, and the
+//! firmware's Secure Boot setting from efivars
+
+use crate::source;
+use crate::utils::file_exists;
+use std::path::Path;
+
+/// LSMs that enforce a policy, as opposed to always-on helpers like
+/// `capability` or `yama`
+static MAJOR_LSMS: &[(&str, &str)] = &[
+    ("selinux", "SELinux"),
+    ("apparmor", "AppArmor"),
+    ("smack", "Smack"),
+    ("tomoyo", "TOMOYO"),
+    ("landlock", "Landlock"),
+];
+
+const SECURE_BOOT_VAR: &str =
+    "/sys/firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+/// e.g. `SELinux enforcing, Secure Boot on` or
+/// `AppArmor (42 enforce, 3 complain), Landlock, Secure Boot off`
+pub fn security_summary() -> Option<String> {
+    let mut parts: Vec<String> = active_lsms()
+        .into_iter()
+        .map(|lsm| match lsm {
+            "selinux" => selinux_mode()
+                .map_or_else(|| "SELinux".to_string(), |mode| format!("SELinux {mode}")),
+            "apparmor" => apparmor_profiles().map_or_else(
+                || "AppArmor".to_string(),
+                |profiles| format!("AppArmor ({profiles})"),
+            ),
+            _ => MAJOR_LSMS
+                .iter()
+                .find(|(id, _)| *id == lsm)
+                .map_or(lsm, |(_, name)| name)
+                .to_string(),
+        })
+        .collect();
+
+    if let Some(state) = secure_boot() {
+        parts.push(format!("Secure Boot {state}"));
+    }
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Major LSMs in load order, from securityfs or, when it isn't mounted, the
+/// filesystems the modules expose
+fn active_lsms() -> Vec<&'static str> {
+    if let Ok(list) = source::current().read_to_string(Path::new("/sys/kernel/security/lsm")) {
+        return list
+            .trim()
+            .split(',')
+            .filter_map(|lsm| MAJOR_LSMS.iter().find(|(id, _)| *id == lsm))
+            .map(|(id, _)| *id)
+            .collect();
+    }
+
+    let mut lsms = Vec::new();
+    if file_exists(Path::new("/sys/fs/selinux/enforce")) {
+        lsms.push("selinux");
+    }
+    if source::current()
+        .read_to_string(Path::new("/sys/module/apparmor/parameters/enabled"))
+        .is_ok_and(|enabled| enabled.trim() == "Y")
+    {
+        lsms.push("apparmor");
+    }
+    lsms
+}
+
+fn selinux_mode() -> Option<&'static str> {
+    match source::current()
+        .read_to_string(Path::new("/sys/fs/selinux/enforce"))
+        .ok()?
+        .trim()
+    {
+        "1" => Some("enforcing"),
+        "0" => Some("permissive"),
+        _ => None,
+    }
+}
+
+/// Loaded profile counts by mode, e.g. `42 enforce, 3 complain`; the profile
+/// list is usually only readable by root
+fn apparmor_profiles() -> Option<String> {
+    let profiles = source::current()
+        .read_to_string(Path::new("/sys/kernel/security/apparmor/profiles"))
+        .ok()?;
+
+    // Lines look like `/usr/bin/man (enforce)`
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for mode in profiles
+        .lines()
+        .filter_map(|line| line.rsplit_once(" (")?.1.strip_suffix(')'))
+    {
+        match counts.iter_mut().find(|(m, _)| *m == mode) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((mode, 1)),
+        }
+    }
+
+    if counts.is_empty() {
+        return None;
+    }
+    Some(
+        counts
+            .iter()
+            .map(|(mode, n)| format!("{n} {mode}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+/// `on`/`off` on UEFI systems; legacy BIOS boots have no efivars
+fn secure_boot() -> Option<&'static str> {
+    // 4 bytes of variable attributes, then the 1 byte value
+    let var = source::current().read(Path::new(SECURE_BOOT_VAR)).ok()?;
+    match var.get(4)? {
+        1 => Some("on"),
+        _ => Some("off"),
+    }
+}
@@ -0,0 +1,68 @@
+//! Line server for status bars
+//! Stays resident and answers every module name read from stdin with that
+//! module's current value on a line of its own, e.g. `memory` → `7.1 GiB / 32 GiB`
+
+use crate::config::Config;
+use crate::modules::{self, Line, Module, Needs};
+use crate::os::SysInfo;
+use crate::utils::strip_ansi;
+use std::io::{self, BufRead, Write};
+
+/// Answer requests until stdin is closed
+///
+/// Only configured modules are served; anything else gets an empty line, so
+/// replies always pair up with requests.
+pub fn run(out: &mut impl Write, config: Config, mut info: SysInfo) -> io::Result<()> {
+    for request in io::stdin().lock().lines() {
+        let request = request?;
+        let name = request.trim();
+
+        let module = Module::from_name(name).filter(|&m| config.has_module(m));
+        let value = match module {
+            Some(module) => {
+                // Refresh only what this module shows
+                info.refresh(&config, Needs::of(&[module]));
+                info.sanitize();
+                value(module, &config, &info)
+            }
+            None => {
+                if !name.is_empty() {
+                    eprintln!("tachi-fetch: module '{name}' is unknown or not enabled");
+                }
+                String::new()
+            }
+        };
+
+        writeln!(out, "{value}")?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// Print the value of each configured module once, one per line, for
+/// `--get`
+pub fn print(out: &mut impl Write, config: &Config, info: &SysInfo) -> io::Result<()> {
+    for &module in &config.modules {
+        writeln!(out, "{}", value(module, config, info))?;
+    }
+    Ok(())
+}
+
+/// The module's value without key or colors; multi-line modules are joined
+fn value(module: Module, config: &Config, info: &SysInfo) -> String {
+    // Every graphical module answers `headless` on a headless machine
+    let module = if info.headless && module.is_graphical() {
+        Module::Display
+    } else {
+        module
+    };
+    let values: Vec<String> = modules::module_lines(module, config, info, false, false)
+        .into_iter()
+        .filter_map(|line| match line {
+            Line::Title(parts) => Some(modules::title_text(&parts)),
+            Line::Entry { value, .. } | Line::Text(value) => Some(strip_ansi(&value)),
+            Line::Separator(_) | Line::Header { .. } | Line::Colors => None,
+        })
+        .collect();
+    values.join(", ")
+}
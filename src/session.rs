@@ -0,0 +1,196 @@
+//! Login session details
+//! The session start comes from logind's state for `XDG_SESSION_ID`, falling
+//! back to the utmp login record of the session leader or terminal; the user's
+//! full name from the passwd database; the session type and the display
+//! manager that started it
+
+use crate::source;
+use crate::utils::get_env_var;
+use std::ffi::CStr;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The GECOS full name of `user`, e.g. `Tomás` from `Tomás,,,`; asked through
+/// NSS so directory users are found too, or read from the recorded
+/// `/etc/passwd`
+pub fn full_name(user: &str) -> Option<String> {
+    let gecos = if source::current().is_live() {
+        let (login, gecos) = passwd_entry()?;
+        // Service accounts such as root repeat their login name
+        if gecos.split(',').next() == Some(login.as_str()) {
+            return None;
+        }
+        gecos
+    } else {
+        let passwd = source::current()
+            .read_to_string(Path::new("/etc/passwd"))
+            .ok()?;
+        // `name:x:1000:1000:Full Name,room,phone:/home/name:/bin/bash`
+        passwd
+            .lines()
+            .map(|line| line.split(':').collect::<Vec<_>>())
+            .find(|fields| fields.first() == Some(&user))?
+            .get(4)?
+            .to_string()
+    };
+    let name = gecos.split(',').next()?.trim();
+    (!name.is_empty() && name != user).then(|| name.to_string())
+}
+
+/// The calling user's login name and GECOS field
+fn passwd_entry() -> Option<(String, String)> {
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0 as libc::c_char; 4096];
+    let mut result = std::ptr::null_mut();
+    let rc = unsafe {
+        libc::getpwuid_r(
+            libc::getuid(),
+            &raw mut pwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &raw mut result,
+        )
+    };
+    if rc != 0 || result.is_null() || pwd.pw_name.is_null() || pwd.pw_gecos.is_null() {
+        return None;
+    }
+    let field = |ptr| {
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    Some((field(pwd.pw_name), field(pwd.pw_gecos)))
+}
+
+/// Seconds since the current login session started
+pub fn session_uptime() -> Option<u64> {
+    let start = logind_session_start().or_else(utmp_session_start)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(now.saturating_sub(start))
+}
+
+/// `REALTIME=` (microseconds) from `/run/systemd/sessions/<id>`
+fn logind_session_start() -> Option<u64> {
+    let id = std::env::var("XDG_SESSION_ID").ok()?;
+    // The id names a file, so keep it from escaping the directory
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let state = fs::read_to_string(format!("/run/systemd/sessions/{id}")).ok()?;
+    state
+        .lines()
+        .find_map(|line| line.strip_prefix("REALTIME="))
+        .and_then(|usec| usec.trim().parse::<u64>().ok())
+        .map(|usec| usec / 1_000_000)
+}
+
+/// Login time of the utmp record for our session leader or terminal
+fn utmp_session_start() -> Option<u64> {
+    let sid = unsafe { libc::getsid(0) };
+    let tty = terminal_line();
+
+    let mut start = None;
+    unsafe {
+        libc::setutxent();
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+            let entry = &*entry;
+            if entry.ut_type != libc::USER_PROCESS {
+                continue;
+            }
+
+            let line = CStr::from_ptr(entry.ut_line.as_ptr()).to_bytes();
+            if entry.ut_pid == sid || tty.as_deref() == Some(line) {
+                start = u64::try_from(entry.ut_tv.tv_sec).ok();
+                break;
+            }
+        }
+        libc::endutxent();
+    }
+    start
+}
+
+/// Controlling terminal relative to `/dev`, as utmp stores it (e.g. `pts/3`)
+fn terminal_line() -> Option<Vec<u8>> {
+    let name = unsafe { libc::ttyname(libc::STDIN_FILENO) };
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_bytes();
+    Some(name.strip_prefix(b"/dev/").unwrap_or(name).to_vec())
+}
+
+/// Display managers by the name of their binary, as run by their unit or
+/// seen in `/proc`
+const DISPLAY_MANAGERS: &[(&str, &str)] = &[
+    ("gdm", "gdm"),
+    ("gdm3", "gdm"),
+    ("sddm", "sddm"),
+    ("greetd", "greetd"),
+    ("ly", "ly"),
+    ("ly-dm", "ly"),
+    ("lightdm", "lightdm"),
+    ("lxdm", "lxdm"),
+    ("xdm", "xdm"),
+];
+
+/// e.g. `Wayland (gdm)` or `TTY`
+pub fn session_summary() -> Option<String> {
+    let kind = match get_env_var("XDG_SESSION_TYPE", "") {
+        "wayland" => "Wayland",
+        "x11" => "X11",
+        "tty" => "TTY",
+        // Without logind, the sockets the session set up
+        _ if !get_env_var("WAYLAND_DISPLAY", "").is_empty() => "Wayland",
+        _ if !get_env_var("DISPLAY", "").is_empty() => "X11",
+        _ => "TTY",
+    };
+    Some(match display_manager() {
+        Some(manager) => format!("{kind} ({manager})"),
+        None => kind.to_string(),
+    })
+}
+
+/// The display manager systemd starts, else one that's running
+fn display_manager() -> Option<&'static str> {
+    let source = source::current();
+    // The alias links to the enabled manager's unit, e.g. gdm.service
+    let unit = source
+        .read_to_string(Path::new("/etc/systemd/system/display-manager.service"))
+        .ok();
+    let enabled = unit.as_deref().and_then(|unit| {
+        let exec = unit
+            .lines()
+            .find_map(|line| line.strip_prefix("ExecStart="))?;
+        let binary = exec.split_whitespace().next()?.rsplit('/').next()?;
+        known_manager(binary)
+    });
+    enabled.or_else(|| {
+        source
+            .read_dir(Path::new("/proc"))
+            .ok()?
+            .iter()
+            .filter(|dir| {
+                dir.file_name()
+                    .is_some_and(|n| n.as_encoded_bytes().iter().all(u8::is_ascii_digit))
+            })
+            .find_map(|dir| known_manager(source.read_to_string(&dir.join("comm")).ok()?.trim()))
+    })
+}
+
+/// Whether a process named `comm` is a display manager
+pub fn is_display_manager(comm: &str) -> bool {
+    known_manager(comm).is_some()
+}
+
+fn known_manager(binary: &str) -> Option<&'static str> {
+    DISPLAY_MANAGERS
+        .iter()
+        .find(|(name, _)| *name == binary)
+        .map(|(_, manager)| *manager)
+}
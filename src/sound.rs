@@ -0,0 +1,48 @@
+//! Audio stack detection
+//! The running sound server, told apart by the sockets it creates in
+//! `$XDG_RUNTIME_DIR`, and the first card listed in `/proc/asound/cards`
+
+use crate::source;
+use crate::utils::{file_exists, get_env_var};
+use std::path::Path;
+
+/// e.g. `PipeWire (HDA Intel PCH)`
+pub fn sound_summary() -> Option<String> {
+    let server = server();
+    let card = primary_card();
+    match (server, card) {
+        (Some(server), Some(card)) => Some(format!("{server} ({card})")),
+        (Some(server), None) => Some(server.to_string()),
+        (None, Some(card)) => Some(card),
+        (None, None) => None,
+    }
+}
+
+fn server() -> Option<&'static str> {
+    let runtime = get_env_var("XDG_RUNTIME_DIR", "");
+    if !runtime.is_empty() {
+        let runtime = Path::new(runtime);
+        // pipewire-pulse also creates `pulse/native`, so PipeWire goes first
+        if runtime.join("pipewire-0").exists() {
+            return Some("PipeWire");
+        }
+        if runtime.join("pulse/native").exists() {
+            return Some("PulseAudio");
+        }
+    }
+    file_exists(Path::new("/proc/asound")).then_some("ALSA")
+}
+
+/// Long name of the lowest numbered card
+fn primary_card() -> Option<String> {
+    let cards = source::current()
+        .read_to_string(Path::new("/proc/asound/cards"))
+        .ok()?;
+    // Each card takes two lines, the first being
+    // ` 0 [PCH            ]: HDA-Intel - HDA Intel PCH`
+    cards.lines().find_map(|line| {
+        let (_, rest) = line.split_once("]: ")?;
+        let (_, name) = rest.split_once(" - ")?;
+        Some(name.trim().to_string()).filter(|name| !name.is_empty())
+    })
+}
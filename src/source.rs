@@ -0,0 +1,687 @@
+//! Data sources
+//! Where probes get files, environment variables and command output from: the
+//! live system, or a machine recorded for golden tests and bug reports
+
+use crate::identity;
+use crate::session;
+use crate::tar;
+use crate::utils::ENV_CACHE;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Output};
+use std::sync::{Mutex, OnceLock};
+
+/// What `uname(2)` reports
+pub struct Uname {
+    pub sysname: String,
+    pub release: String,
+    pub machine: String,
+}
+
+pub trait DataSource: Send + Sync {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+    /// Paths of the entries in a directory
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+    fn exists(&self, path: &Path) -> bool;
+    fn env(&self, name: &str) -> Option<&'static str>;
+    fn run(&self, cmd: &str, args: &[&str]) -> io::Result<Output>;
+    fn uname(&self) -> Uname;
+    fn hostname(&self) -> String;
+    /// Seconds since boot
+    fn uptime(&self) -> u64;
+    fn online_cpus(&self) -> usize;
+    /// Used and total bytes of the filesystem mounted at `mount`, as df(1)
+    /// counts them
+    fn statvfs(&self, mount: &Path) -> io::Result<(u64, u64)>;
+
+    /// Whether this is the machine we're running on
+    fn is_live(&self) -> bool {
+        false
+    }
+
+    /// The config recorded along with the machine
+    fn config(&self) -> Option<String> {
+        None
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+static SOURCE: OnceLock<Box<dyn DataSource>> = OnceLock::new();
+
+/// Read from `source` for the rest of the run; only possible before the first read
+pub fn set(source: Box<dyn DataSource>) -> Result<(), String> {
+    SOURCE
+        .set(source)
+        .map_err(|_| "data source already in use".to_string())
+}
+
+pub fn current() -> &'static dyn DataSource {
+    SOURCE.get_or_init(|| Box::new(System)).as_ref()
+}
+
+/// The machine we're running on
+struct System;
+
+impl DataSource for System {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        Ok(fs::read_dir(path)?.flatten().map(|e| e.path()).collect())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn env(&self, name: &str) -> Option<&'static str> {
+        ENV_CACHE.get(name).copied()
+    }
+
+    fn run(&self, cmd: &str, args: &[&str]) -> io::Result<Output> {
+        Command::new(cmd).args(args).output()
+    }
+
+    fn uname(&self) -> Uname {
+        let uts = nix::sys::utsname::uname().unwrap();
+        Uname {
+            sysname: uts.sysname().to_string_lossy().into_owned(),
+            release: uts.release().to_string_lossy().into_owned(),
+            machine: uts.machine().to_string_lossy().into_owned(),
+        }
+    }
+
+    fn hostname(&self) -> String {
+        let mut buf = [0u8; 256];
+        unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) };
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    fn uptime(&self) -> u64 {
+        #[allow(clippy::cast_sign_loss)]
+        let uptime = unsafe { crate::utils::fast_sysinfo() }.uptime as u64;
+        uptime
+    }
+
+    fn online_cpus(&self) -> usize {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) as usize };
+        count
+    }
+
+    fn statvfs(&self, mount: &Path) -> io::Result<(u64, u64)> {
+        let path = CString::new(mount.as_os_str().as_bytes())?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statvfs(path.as_ptr(), &raw mut stat) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let block = stat.f_frsize;
+        let total = stat.f_blocks * block;
+        let used = total.saturating_sub(stat.f_bfree * block);
+        Ok((used, total))
+    }
+
+    fn is_live(&self) -> bool {
+        true
+    }
+}
+
+/// A recorded machine, from a directory or a `--dump-probe-data` archive laid
+/// out as:
+///
+/// - `root/`: the files read, at their usual paths (`root/proc/meminfo`, ...)
+/// - `env`: `NAME=value` lines
+/// - `commands`: each command as a `$ cmd args` line followed by its output
+/// - `uname`: `sysname release machine`
+/// - `statvfs`: `used total mount` lines, in bytes
+/// - `config.toml`: optionally, the config to render with
+///
+/// Hostname, uptime and CPU count come from `root/proc/sys/kernel/hostname`,
+/// `root/proc/uptime` and `root/sys/devices/system/cpu/online`.
+pub struct Fixture {
+    /// Keyed by their path on the recorded machine
+    files: FxHashMap<PathBuf, Vec<u8>>,
+    dirs: FxHashSet<PathBuf>,
+    env: FxHashMap<String, &'static str>,
+    commands: FxHashMap<String, String>,
+    statvfs: FxHashMap<PathBuf, (u64, u64)>,
+    uname: [String; 3],
+    config: Option<String>,
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let entries = if path.is_dir() {
+            let mut entries = Vec::new();
+            walk(path, "", &mut entries)?;
+            entries
+        } else {
+            tar::read(&fs::read(path)?)?
+        };
+
+        let mut fixture = Self {
+            files: FxHashMap::default(),
+            dirs: FxHashSet::default(),
+            env: FxHashMap::default(),
+            commands: FxHashMap::default(),
+            statvfs: FxHashMap::default(),
+            uname: Default::default(),
+            config: None,
+        };
+        let mut uname = None;
+        for entry in entries {
+            let text = || String::from_utf8_lossy(entry.data.as_deref().unwrap_or_default());
+            match entry.path.as_str() {
+                "env" => fixture.env = parse_env(&text()),
+                "commands" => fixture.commands = parse_commands(&text()),
+                "statvfs" => fixture.statvfs = parse_statvfs(&text()),
+                "uname" => uname = Some(text().into_owned()),
+                "config.toml" => fixture.config = Some(text().into_owned()),
+                path => {
+                    let Some(path) = path
+                        .strip_prefix("root")
+                        .filter(|p| p.is_empty() || p.starts_with('/'))
+                    else {
+                        continue;
+                    };
+                    let path = Path::new("/").join(path.trim_start_matches('/'));
+                    fixture
+                        .dirs
+                        .extend(path.ancestors().skip(1).map(Path::to_path_buf));
+                    match entry.data {
+                        Some(data) => {
+                            fixture.files.insert(path, data);
+                        }
+                        None => {
+                            fixture.dirs.insert(path);
+                        }
+                    }
+                }
+            }
+        }
+
+        let uname = uname.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no uname"))?;
+        let mut fields = uname.split_whitespace().map(str::to_string);
+        let mut field = || fields.next().unwrap_or_default();
+        fixture.uname = [field(), field(), field()];
+        Ok(fixture)
+    }
+
+    fn trimmed(&self, path: &str) -> String {
+        self.read_to_string(Path::new(path))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// The files and directories under `dir`, named relative to the top
+fn walk(dir: &Path, prefix: &str, entries: &mut Vec<tar::Entry>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = format!("{prefix}{}", entry.file_name().to_string_lossy());
+        if entry.file_type()?.is_dir() {
+            walk(&entry.path(), &format!("{path}/"), entries)?;
+            entries.push(tar::Entry { path, data: None });
+        } else {
+            let data = Some(fs::read(entry.path())?);
+            entries.push(tar::Entry { path, data });
+        }
+    }
+    Ok(())
+}
+
+fn parse_env(recorded: &str) -> FxHashMap<String, &'static str> {
+    recorded
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            // Handed out as `'static` like the live environment; fixtures are
+            // loaded once per run
+            Some((name.to_string(), &*Box::leak(value.into())))
+        })
+        .collect()
+}
+
+fn parse_commands(recorded: &str) -> FxHashMap<String, String> {
+    let mut commands = FxHashMap::default();
+    let mut current: Option<(String, String)> = None;
+    for line in recorded.lines() {
+        if let Some(command) = line.strip_prefix("$ ") {
+            commands.extend(current.take());
+            current = Some((command.to_string(), String::new()));
+        } else if let Some((_, output)) = &mut current {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    commands.extend(current);
+    commands
+}
+
+fn parse_statvfs(recorded: &str) -> FxHashMap<PathBuf, (u64, u64)> {
+    recorded
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let used = fields.next()?.parse().ok()?;
+            let total = fields.next()?.parse().ok()?;
+            Some((PathBuf::from(fields.next()?), (used, total)))
+        })
+        .collect()
+}
+
+impl DataSource for Fixture {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.files.get(path).cloned().ok_or_else(not_found)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        if !self.dirs.contains(path) {
+            return Err(not_found());
+        }
+        let mut entries: Vec<PathBuf> = (self.files.keys())
+            .chain(&self.dirs)
+            .filter(|entry| entry.parent() == Some(path))
+            .cloned()
+            .collect();
+        entries.sort_unstable();
+        Ok(entries)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.contains_key(path) || self.dirs.contains(path)
+    }
+
+    fn env(&self, name: &str) -> Option<&'static str> {
+        self.env.get(name).copied()
+    }
+
+    fn run(&self, cmd: &str, args: &[&str]) -> io::Result<Output> {
+        let stdout = self
+            .commands
+            .get(&command_line(cmd, args))
+            .ok_or_else(not_found)?;
+        Ok(Output {
+            status: ExitStatus::from_raw(0),
+            stdout: stdout.clone().into_bytes(),
+            stderr: Vec::new(),
+        })
+    }
+
+    fn config(&self) -> Option<String> {
+        self.config.clone()
+    }
+
+    fn uname(&self) -> Uname {
+        let [sysname, release, machine] = self.uname.clone();
+        Uname {
+            sysname,
+            release,
+            machine,
+        }
+    }
+
+    fn hostname(&self) -> String {
+        self.trimmed("/proc/sys/kernel/hostname")
+    }
+
+    fn uptime(&self) -> u64 {
+        // `12345.67 23456.78`
+        let uptime = self.trimmed("/proc/uptime");
+        let seconds = uptime.split(['.', ' ']).next().unwrap_or("");
+        seconds.parse().unwrap_or(0)
+    }
+
+    fn online_cpus(&self) -> usize {
+        // `0-15` or `0,2-3`
+        self.trimmed("/sys/devices/system/cpu/online")
+            .split(',')
+            .filter_map(|range| match range.split_once('-') {
+                Some((first, last)) => {
+                    Some(last.parse::<usize>().ok()? + 1 - first.parse::<usize>().ok()?)
+                }
+                None => range.parse::<usize>().ok().map(|_| 1),
+            })
+            .sum()
+    }
+
+    fn statvfs(&self, mount: &Path) -> io::Result<(u64, u64)> {
+        self.statvfs.get(mount).copied().ok_or_else(not_found)
+    }
+}
+
+fn not_found() -> io::Error {
+    io::Error::from_raw_os_error(libc::ENOENT)
+}
+
+fn command_line(cmd: &str, args: &[&str]) -> String {
+    [cmd]
+        .iter()
+        .chain(args)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// What the probes consumed while recording
+#[derive(Default)]
+struct Recording {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    dirs: BTreeSet<PathBuf>,
+    env: BTreeMap<String, &'static str>,
+    commands: BTreeMap<String, Vec<u8>>,
+    statvfs: BTreeMap<PathBuf, (u64, u64)>,
+    uname: Option<String>,
+    config: Option<String>,
+}
+
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+
+fn recording(f: impl FnOnce(&mut Recording)) {
+    if let Some(recording) = RECORDING.lock().unwrap().as_mut() {
+        f(recording);
+    }
+}
+
+/// The machine we're running on, noting everything read from it
+struct Recorder;
+
+impl DataSource for Recorder {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let data = System.read(path)?;
+        recording(|r| {
+            r.files.insert(path.to_path_buf(), data.clone());
+        });
+        Ok(data)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let entries = System.read_dir(path)?;
+        recording(|r| {
+            r.dirs.insert(path.to_path_buf());
+            // Entries only listed still have to be there on replay; the ones
+            // read later get their contents then
+            for entry in &entries {
+                if entry.is_dir() {
+                    r.dirs.insert(entry.clone());
+                } else {
+                    r.files.entry(entry.clone()).or_default();
+                }
+            }
+        });
+        Ok(entries)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        let exists = System.exists(path);
+        if exists {
+            recording(|r| {
+                if path.is_dir() {
+                    r.dirs.insert(path.to_path_buf());
+                } else {
+                    r.files.entry(path.to_path_buf()).or_default();
+                }
+            });
+        }
+        exists
+    }
+
+    fn env(&self, name: &str) -> Option<&'static str> {
+        let value = System.env(name)?;
+        recording(|r| {
+            r.env.insert(name.to_string(), value);
+        });
+        Some(value)
+    }
+
+    fn run(&self, cmd: &str, args: &[&str]) -> io::Result<Output> {
+        let output = System.run(cmd, args)?;
+        // Replay treats commands it has no output for as missing, which
+        // probes handle like a failed run
+        if output.status.success() {
+            let stdout = output.stdout.clone();
+            recording(|r| {
+                r.commands.insert(command_line(cmd, args), stdout);
+            });
+        }
+        Ok(output)
+    }
+
+    fn uname(&self) -> Uname {
+        let uname = System.uname();
+        let line = format!("{} {} {}\n", uname.sysname, uname.release, uname.machine);
+        recording(|r| r.uname = Some(line));
+        uname
+    }
+
+    fn hostname(&self) -> String {
+        let hostname = System.hostname();
+        let file = format!("{hostname}\n").into_bytes();
+        recording(|r| {
+            r.files.insert("/proc/sys/kernel/hostname".into(), file);
+        });
+        hostname
+    }
+
+    fn uptime(&self) -> u64 {
+        let uptime = System.uptime();
+        let file = format!("{uptime}.00 0.00\n").into_bytes();
+        recording(|r| {
+            r.files.insert("/proc/uptime".into(), file);
+        });
+        uptime
+    }
+
+    fn online_cpus(&self) -> usize {
+        let count = System.online_cpus();
+        let file = format!("0-{}\n", count.saturating_sub(1)).into_bytes();
+        recording(|r| {
+            r.files
+                .insert("/sys/devices/system/cpu/online".into(), file);
+        });
+        count
+    }
+
+    fn statvfs(&self, mount: &Path) -> io::Result<(u64, u64)> {
+        let usage = System.statvfs(mount)?;
+        recording(|r| {
+            r.statvfs.insert(mount.to_path_buf(), usage);
+        });
+        Ok(usage)
+    }
+}
+
+/// Read from the live system for the rest of the run, noting what the probes
+/// consume for [`save_recording`]
+pub fn record() -> Result<(), String> {
+    *RECORDING.lock().unwrap() = Some(Recording::default());
+    set(Box::new(Recorder))
+}
+
+/// Note the config the run was made with
+pub fn record_config(content: &str) {
+    recording(|r| r.config = Some(content.to_string()));
+}
+
+/// Files whose contents identify the machine, with what stands in for them
+static SECRET_FILES: &[(&str, &str)] = &[
+    ("/etc/machine-id", "00000000000000000000000000000000"),
+    (
+        "/var/lib/dbus/machine-id",
+        "00000000000000000000000000000000",
+    ),
+    ("/sys/class/dmi/id/board_serial", "0000000000"),
+    ("/sys/class/dmi/id/chassis_asset_tag", "0000000000"),
+    ("/sys/class/dmi/id/chassis_serial", "0000000000"),
+    ("/sys/class/dmi/id/product_serial", "0000000000"),
+    (
+        "/sys/class/dmi/id/product_uuid",
+        "00000000-0000-0000-0000-000000000000",
+    ),
+];
+
+/// Write what was recorded as an archive `--replay` can render from
+///
+/// The user and host names, the machine ID, DMI serials and the wireless
+/// network are replaced with placeholders wherever they appear, file paths
+/// included. Of the processes only init and a display manager are kept.
+pub fn save_recording(path: &Path) -> io::Result<()> {
+    let Some(mut recording) = RECORDING.lock().unwrap().take() else {
+        return Ok(());
+    };
+
+    let hostname = System.hostname();
+    let user = System.env("USER").unwrap_or_default();
+    let mut secrets = vec![
+        (user.to_string(), "user".to_string()),
+        (hostname, "host".to_string()),
+    ];
+    for (file, alias) in SECRET_FILES {
+        if let Some(data) = recording.files.get(Path::new(file)) {
+            let value = String::from_utf8_lossy(data).trim().to_string();
+            if !identity::PLACEHOLDERS.contains(&value.as_str()) {
+                secrets.push((value, (*alias).to_string()));
+            }
+        }
+    }
+    for (command, stdout) in &recording.commands {
+        if command.starts_with("iw ") {
+            secrets.extend(wireless_secrets(&String::from_utf8_lossy(stdout)));
+        }
+    }
+    secrets.retain(|(secret, _)| !secret.is_empty());
+
+    let clean = |data: &[u8]| {
+        secrets.iter().fold(data.to_vec(), |data, (secret, alias)| {
+            replace(&data, secret.as_bytes(), alias.as_bytes())
+        })
+    };
+    let clean_text = |text: &str| String::from_utf8_lossy(&clean(text.as_bytes())).into_owned();
+    let clean_path = |path: &Path| format!("root{}", clean_text(&path.to_string_lossy()));
+
+    // Other processes' names say what the user is up to; the display manager
+    // scan reads them all
+    let kept: Vec<PathBuf> = (recording.files.iter())
+        .filter(|(path, comm)| {
+            path.ends_with("comm")
+                && (path.as_path() == Path::new("/proc/1/comm")
+                    || session::is_display_manager(String::from_utf8_lossy(comm).trim()))
+        })
+        .filter_map(|(path, _)| path.parent().map(Path::to_path_buf))
+        .collect();
+    let private = |path: &Path| {
+        let process = if path.file_name().is_some_and(|name| name == "comm") {
+            path.parent().unwrap_or(path)
+        } else {
+            path
+        };
+        process.parent() == Some(Path::new("/proc"))
+            && process
+                .file_name()
+                .is_some_and(|n| n.as_encoded_bytes().iter().all(u8::is_ascii_digit))
+            && !kept.iter().any(|dir| dir == process)
+    };
+    recording.files.retain(|path, _| !private(path));
+    recording.dirs.retain(|path| !private(path));
+
+    let mut entries = Vec::new();
+    let mut file = |path: &str, data: Vec<u8>| {
+        entries.push(tar::Entry {
+            path: path.to_string(),
+            data: Some(data),
+        });
+    };
+    file("uname", recording.uname.unwrap_or_default().into_bytes());
+
+    let mut env = String::new();
+    for (name, value) in &recording.env {
+        env.push_str(&format!("{name}={}\n", clean_text(value)));
+    }
+    file("env", env.into_bytes());
+
+    let mut commands = Vec::new();
+    for (command, stdout) in &recording.commands {
+        commands.extend_from_slice(format!("$ {}\n", clean_text(command)).as_bytes());
+        commands.extend(clean(stdout));
+        if !commands.ends_with(b"\n") {
+            commands.push(b'\n');
+        }
+    }
+    file("commands", commands);
+
+    let mut statvfs = String::new();
+    for (mount, (used, total)) in &recording.statvfs {
+        let mount = clean_text(&mount.to_string_lossy());
+        statvfs.push_str(&format!("{used} {total} {mount}\n"));
+    }
+    file("statvfs", statvfs.into_bytes());
+
+    if let Some(config) = &recording.config {
+        file("config.toml", clean(config.as_bytes()));
+    }
+    for (path, data) in &recording.files {
+        file(&clean_path(path), clean(data));
+    }
+    entries.extend(recording.dirs.iter().map(|path| tar::Entry {
+        path: clean_path(path),
+        data: None,
+    }));
+
+    fs::write(path, tar::write(&entries)?)
+}
+
+/// The network name and access point in `iw dev <iface> link` output:
+///
+/// ```text
+/// Connected to 12:34:56:78:9a:bc (on wlan0)
+///         SSID: MyNetwork
+/// ```
+fn wireless_secrets(link: &str) -> Vec<(String, String)> {
+    link.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("Connected to ") {
+                let bssid = rest.split_whitespace().next()?;
+                Some((bssid.to_string(), "00:00:00:00:00:00".to_string()))
+            } else {
+                let ssid = line.strip_prefix("SSID: ")?;
+                Some((ssid.to_string(), "network".to_string()))
+            }
+        })
+        .collect()
+}
+
+/// `data` with every whole-word `from` replaced by `to`, so that short names
+/// like `al` or `root` leave `alias` and `rootfs` alone
+fn replace(data: &[u8], from: &[u8], to: &[u8]) -> Vec<u8> {
+    let word =
+        |byte: Option<&u8>| byte.is_some_and(|b| b.is_ascii_alphanumeric() || b"_-".contains(b));
+    let mut out = Vec::with_capacity(data.len());
+    let mut rest = data;
+    while let Some(pos) = memchr::memmem::find(rest, from) {
+        let end = pos + from.len();
+        let before = pos.checked_sub(1).map_or(out.last(), |i| rest.get(i));
+        if word(before) || word(rest.get(end)) {
+            out.extend_from_slice(&rest[..end]);
+        } else {
+            out.extend_from_slice(&rest[..pos]);
+            out.extend_from_slice(to);
+        }
+        rest = &rest[end..];
+    }
+    out.extend_from_slice(rest);
+    out
+}
@@ -0,0 +1,113 @@
+//! Sway and i3
+//! WM version and output modes over the i3 IPC protocol, which sway speaks
+//! too, when `SWAYSOCK` or `I3SOCK` points at the socket
+
+use crate::display::format_scale;
+use crate::json::{self, Value};
+use crate::log;
+use crate::utils::get_env_var;
+use std::io::{self, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+/// How long to wait for the WM before giving up on it
+const TIMEOUT: Duration = Duration::from_millis(250);
+
+const MAGIC: &[u8] = b"i3-ipc";
+const GET_OUTPUTS: u32 = 3;
+const GET_VERSION: u32 = 7;
+
+/// The WM's name and its socket
+fn socket() -> Option<(&'static str, &'static str)> {
+    [("Sway", "SWAYSOCK"), ("i3", "I3SOCK")]
+        .into_iter()
+        .map(|(name, var)| (name, get_env_var(var, "")))
+        .find(|(_, path)| !path.is_empty())
+}
+
+/// Send a message without payload and read the JSON reply; lengths and types
+/// are in native byte order
+fn request(path: &str, kind: u32) -> io::Result<Vec<u8>> {
+    let mut stream = UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut msg = MAGIC.to_vec();
+    msg.extend_from_slice(&0u32.to_ne_bytes());
+    msg.extend_from_slice(&kind.to_ne_bytes());
+    stream.write_all(&msg)?;
+
+    let mut header = [0u8; 14];
+    stream.read_exact(&mut header)?;
+    if &header[..6] != MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not an i3 IPC reply",
+        ));
+    }
+    let len = u32::from_ne_bytes([header[6], header[7], header[8], header[9]]);
+    let mut payload = vec![0u8; len as usize];
+    stream.read_exact(&mut payload)?;
+    Ok(payload)
+}
+
+fn ask(kind: u32) -> Option<Value> {
+    let (name, path) = socket()?;
+    match request(path, kind) {
+        Ok(payload) => json::parse(&String::from_utf8_lossy(&payload)),
+        Err(e) => {
+            log::fail!("{name}: cannot ask {path}: {e}");
+            None
+        }
+    }
+}
+
+/// e.g. `Sway 1.9` or `i3 4.23`
+pub fn wm() -> Option<String> {
+    let (name, _) = socket()?;
+    let version = ask(GET_VERSION)?;
+    // i3's reads `4.23 (2023-10-29)`
+    let number = version
+        .get("human_readable")
+        .and_then(Value::as_str)
+        .and_then(|v| v.split_whitespace().next());
+    Some(match number {
+        Some(number) => format!("{name} {number}"),
+        None => name.to_string(),
+    })
+}
+
+/// Each active output's mode and scale, e.g. `2560x1440 @ 144Hz (1.5x)`; i3
+/// only knows the size
+pub fn outputs() -> Option<String> {
+    let outputs = ask(GET_OUTPUTS)?;
+    let modes: Vec<String> = outputs
+        .as_array()?
+        .iter()
+        .filter(|o| o.get("active").and_then(Value::as_bool) == Some(true))
+        .filter_map(|o| {
+            // Sway's current mode has the refresh rate in mHz
+            if let Some(mode) = o.get("current_mode") {
+                let width = mode.get("width")?.as_f64()?;
+                let height = mode.get("height")?.as_f64()?;
+                let refresh = mode.get("refresh")?.as_f64()? / 1000.0;
+                let mut mode = format!("{width}x{height} @ {}Hz", refresh.round());
+                if let Some(scale) = o
+                    .get("scale")
+                    .and_then(Value::as_f64)
+                    .and_then(format_scale)
+                {
+                    mode.push_str(&format!(" ({scale})"));
+                }
+                return Some(mode);
+            }
+            let rect = o.get("rect")?;
+            Some(format!(
+                "{}x{}",
+                rect.get("width")?.as_f64()?,
+                rect.get("height")?.as_f64()?
+            ))
+        })
+        .collect();
+    (!modes.is_empty()).then(|| modes.join(", "))
+}
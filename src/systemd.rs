@@ -0,0 +1,50 @@
+//! systemd health
+//! Overall system state and the number of failed units, asked from systemd
+//! over D-Bus, or from `systemctl` when the bus isn't reachable
+
+use crate::dbus::{self, Connection};
+use crate::utils::{run_command, run_command_output};
+
+const DESTINATION: &str = "org.freedesktop.systemd1";
+const PATH: &str = "/org/freedesktop/systemd1";
+const MANAGER: &str = "org.freedesktop.systemd1.Manager";
+
+/// e.g. `running, 0 failed units` or `degraded, 2 failed units`
+pub fn health_summary() -> Option<String> {
+    let (state, failed) = from_bus().or_else(from_systemctl)?;
+    let units = if failed == 1 { "unit" } else { "units" };
+    Some(match state {
+        Some(state) => format!("{state}, {failed} failed {units}"),
+        None => format!("{failed} failed {units}"),
+    })
+}
+
+fn from_bus() -> Option<(Option<String>, u32)> {
+    let mut conn = Connection::system().ok()?;
+
+    let reply = dbus::get_property(&mut conn, DESTINATION, PATH, MANAGER, "NFailedUnits").ok()?;
+    let mut reader = reply.reader();
+    if reader.signature()? != "u" {
+        return None;
+    }
+    let failed = reader.u32()?;
+
+    let state = dbus::get_property(&mut conn, DESTINATION, PATH, MANAGER, "SystemState")
+        .ok()
+        .and_then(|reply| {
+            let mut reader = reply.reader();
+            (reader.signature()? == "s").then_some(())?;
+            reader.string().map(str::to_string)
+        });
+
+    Some((state, failed))
+}
+
+/// Count the entries of `systemctl --failed --output=json`, one object per unit
+fn from_systemctl() -> Option<(Option<String>, u32)> {
+    let json = run_command("systemctl", &["--failed", "--no-legend", "--output=json"])?;
+    let failed = u32::try_from(json.matches("\"unit\":").count()).ok()?;
+    // Exits non-zero for any state but `running`, degraded included
+    let state = run_command_output("systemctl", &["is-system-running"]);
+    Some((state, failed))
+}
@@ -0,0 +1,202 @@
+//! Minimal tar archives
+//! Plain ustar files and directories, enough to carry `--dump-probe-data`
+//! captures; ownership, modes and times are not kept
+
+use std::io;
+
+const BLOCK: usize = 512;
+
+pub struct Entry {
+    /// Relative, `/`-separated
+    pub path: String,
+    /// `None` for a directory
+    pub data: Option<Vec<u8>>,
+}
+
+/// An archive holding `entries`
+pub fn write(entries: &[Entry]) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for entry in entries {
+        let mut header = [0u8; BLOCK];
+        let (prefix, name) = split_path(&entry.path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("path too long for tar: {}", entry.path),
+            )
+        })?;
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+        let (mode, size, kind) = match &entry.data {
+            Some(data) => ("0000644", data.len(), b'0'),
+            None => ("0000755", 0, b'5'),
+        };
+        header[100..107].copy_from_slice(mode.as_bytes());
+        header[108..115].copy_from_slice(b"0000000");
+        header[116..123].copy_from_slice(b"0000000");
+        header[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+        header[136..147].copy_from_slice(b"00000000000");
+        header[156] = kind;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+
+        // Summed with the checksum field itself as spaces
+        header[148..156].fill(b' ');
+        let sum: u32 = header.iter().map(|&b| u32::from(b)).sum();
+        header[148..155].copy_from_slice(format!("{sum:06o}\0").as_bytes());
+
+        out.extend_from_slice(&header);
+        if let Some(data) = &entry.data {
+            out.extend_from_slice(data);
+            out.resize(out.len().next_multiple_of(BLOCK), 0);
+        }
+    }
+    // Two empty blocks end the archive
+    out.resize(out.len() + 2 * BLOCK, 0);
+    Ok(out)
+}
+
+/// Split a path into the ustar `prefix` (155 bytes) and `name` (100 bytes)
+/// fields
+fn split_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    path.match_indices('/')
+        .map(|(i, _)| (&path[..i], &path[i + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100 && !name.is_empty())
+}
+
+/// The files and directories in an archive; links and other special entries
+/// are skipped
+pub fn read(data: &[u8]) -> io::Result<Vec<Entry>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed tar archive");
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while let Some(header) = data.get(offset..offset + BLOCK) {
+        if header.iter().all(|&b| b == 0) {
+            break;
+        }
+        offset += BLOCK;
+
+        let name = field(&header[..100]);
+        let prefix = field(&header[345..500]);
+        let path = if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}/{name}")
+        };
+        let path = path.trim_end_matches('/').to_string();
+
+        let size = field(&header[124..136]);
+        let size = usize::from_str_radix(size.trim(), 8).map_err(|_| invalid())?;
+        let contents = data.get(offset..offset + size).ok_or_else(invalid)?;
+        offset += size.next_multiple_of(BLOCK);
+
+        match header[156] {
+            b'0' | 0 => entries.push(Entry {
+                path,
+                data: Some(contents.to_vec()),
+            }),
+            b'5' => entries.push(Entry { path, data: None }),
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+/// A NUL-terminated header field
+fn field(bytes: &[u8]) -> String {
+    let len = memchr::memchr(0, bytes).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BLOCK, Entry, read, write};
+
+    fn file(path: &str, data: &[u8]) -> Entry {
+        Entry {
+            path: path.to_string(),
+            data: Some(data.to_vec()),
+        }
+    }
+
+    fn paths(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.path.as_str()).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let long = format!("root/{}/{}", "d".repeat(120), "f".repeat(90));
+        let archive = write(&[
+            file("uname", b"Linux 6.8.0 x86_64\n"),
+            Entry {
+                path: "root/proc".to_string(),
+                data: None,
+            },
+            file("root/proc/empty", b""),
+            file("root/proc/block", &[7; BLOCK]),
+            file(&long, b"deep"),
+        ])
+        .unwrap();
+        assert_eq!(archive.len() % BLOCK, 0);
+
+        let entries = read(&archive).unwrap();
+        assert_eq!(
+            paths(&entries),
+            [
+                "uname",
+                "root/proc",
+                "root/proc/empty",
+                "root/proc/block",
+                long.as_str()
+            ]
+        );
+        assert_eq!(
+            entries[0].data.as_deref(),
+            Some(&b"Linux 6.8.0 x86_64\n"[..])
+        );
+        assert!(entries[1].data.is_none());
+        assert_eq!(entries[2].data.as_deref(), Some(&b""[..]));
+        assert_eq!(entries[3].data.as_deref(), Some(&[7; BLOCK][..]));
+        assert_eq!(entries[4].data.as_deref(), Some(&b"deep"[..]));
+    }
+
+    #[test]
+    fn unsplittable_path() {
+        let name = "n".repeat(101);
+        assert!(write(&[file(&name, b"")]).is_err());
+        let path = format!("{}/{name}", "p".repeat(10));
+        assert!(write(&[file(&path, b"")]).is_err());
+    }
+
+    #[test]
+    fn special_entries_are_skipped() {
+        let mut archive = write(&[file("link", b""), file("kept", b"x")]).unwrap();
+        archive[156] = b'2';
+        assert_eq!(paths(&read(&archive).unwrap()), ["kept"]);
+    }
+
+    #[test]
+    fn missing_end_blocks() {
+        let archive = write(&[file("a", b"x")]).unwrap();
+        let entries = read(&archive[..2 * BLOCK]).unwrap();
+        assert_eq!(paths(&entries), ["a"]);
+        assert!(read(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn truncated_contents() {
+        let archive = write(&[file("a", &[1; 600])]).unwrap();
+        assert!(read(&archive[..BLOCK + 100]).is_err());
+    }
+
+    #[test]
+    fn bad_size_field() {
+        let mut archive = write(&[file("a", b"x")]).unwrap();
+        archive[124..135].copy_from_slice(b"0000000009x");
+        assert!(read(&archive).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! Terminal styling
+//! Background opacity and padding, read from the config of the terminal we're
+//! running in for the ones with a well-known config file
+
+use crate::source;
+use crate::toml;
+use crate::utils::{expand_path, get_env_var};
+
+#[derive(Clone, Copy)]
+enum Terminal {
+    Kitty,
+    Alacritty,
+    Foot,
+}
+
+#[derive(Default)]
+struct Style {
+    opacity: Option<String>,
+    padding: Option<String>,
+}
+
+/// e.g. `opacity 0.9, padding 12`
+pub fn style_summary() -> Option<String> {
+    let style = match current()? {
+        Terminal::Kitty => kitty(),
+        Terminal::Alacritty => alacritty(),
+        Terminal::Foot => foot(),
+    };
+
+    let details: Vec<String> = [("opacity", style.opacity), ("padding", style.padding)]
+        .into_iter()
+        .filter_map(|(name, value)| Some(format!("{name} {}", value?)))
+        .collect();
+    (!details.is_empty()).then(|| details.join(", "))
+}
+
+fn current() -> Option<Terminal> {
+    let term = get_env_var("TERM", "");
+    if !get_env_var("KITTY_WINDOW_ID", "").is_empty() || term == "xterm-kitty" {
+        Some(Terminal::Kitty)
+    } else if !get_env_var("ALACRITTY_SOCKET", "").is_empty() || term == "alacritty" {
+        Some(Terminal::Alacritty)
+    } else if term.starts_with("foot") {
+        Some(Terminal::Foot)
+    } else {
+        None
+    }
+}
+
+fn read(path: &str) -> Option<String> {
+    source::current().read_to_string(&expand_path(path)).ok()
+}
+
+/// `background_opacity 0.9` and `window_padding_width 10` (or up to four
+/// values) lines; later ones win
+fn kitty() -> Style {
+    let mut style = Style::default();
+    let Some(config) = read("~/.config/kitty/kitty.conf") else {
+        return style;
+    };
+    for line in config.lines() {
+        let Some((key, value)) = line.trim().split_once(char::is_whitespace) else {
+            continue;
+        };
+        let value = Some(value.trim().to_string());
+        match key {
+            "background_opacity" => style.opacity = value,
+            "window_padding_width" => style.padding = value,
+            _ => {}
+        }
+    }
+    style
+}
+
+/// `opacity` and `padding = { x = 10, y = 10 }` under `[window]`
+fn alacritty() -> Style {
+    let Some(config) = ["~/.config/alacritty/alacritty.toml", "~/.alacritty.toml"]
+        .into_iter()
+        .find_map(read)
+    else {
+        return Style::default();
+    };
+    let Ok(config) = toml::parse(&config) else {
+        return Style::default();
+    };
+    let Some(window) = config.get("window").and_then(toml::Value::as_table) else {
+        return Style::default();
+    };
+
+    let padding = window.get("padding").and_then(toml::Value::as_table);
+    let side = |name| padding?.get(name)?.as_integer();
+    Style {
+        opacity: window
+            .get("opacity")
+            .and_then(toml::Value::as_float)
+            .map(|o| o.to_string()),
+        padding: match (side("x"), side("y")) {
+            (Some(x), Some(y)) if x == y => Some(x.to_string()),
+            (Some(x), Some(y)) => Some(format!("{x}x{y}")),
+            (Some(x), None) => Some(format!("{x}x0")),
+            (None, Some(y)) => Some(format!("0x{y}")),
+            (None, None) => None,
+        },
+    }
+}
+
+/// `alpha=0.9` under `[colors]` and `pad=10x10` (maybe followed by `center`)
+/// under `[main]`; keys before any section are in `[main]` too
+fn foot() -> Style {
+    let mut style = Style::default();
+    let Some(config) = read("~/.config/foot/foot.ini") else {
+        return style;
+    };
+    let mut section = "main";
+    for line in config.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name;
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.split_whitespace().next().map(str::to_string);
+        match (section, key.trim()) {
+            ("colors", "alpha") => style.opacity = value,
+            ("main", "pad") => style.padding = value,
+            _ => {}
+        }
+    }
+    style
+}
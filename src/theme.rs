@@ -1,8 +1,15 @@
-use crate::utils::{expand_path, run_command, search_file_for_key};
+use crate::backend::{self, Backend};
+use crate::json;
+use crate::log;
+use crate::portal;
+use crate::source;
+use crate::utils::{expand_path, get_env_var, run_command, search_file_for_key};
+use crate::xsettings;
+use std::path::{Path, PathBuf};
 use std::thread::{self, JoinHandle};
 
 // Paths where theme and icon configurations might be found
-static THEME_CONFIG_PATHS: &[&str] = &[
+pub static THEME_CONFIG_PATHS: &[&str] = &[
     "~/.gtkrc-2.0",
     "~/.config/gtk-3.0/settings.ini",
     "~/.config/gtk-4.0/settings.ini",
@@ -10,7 +17,7 @@ static THEME_CONFIG_PATHS: &[&str] = &[
     "/etc/gtk-4.0/settings.ini",
 ];
 
-static ICON_CONFIG_PATHS: &[&str] = &[
+pub static ICON_CONFIG_PATHS: &[&str] = &[
     "~/.config/gtk-3.0/settings.ini",
     "~/.config/gtk-4.0/settings.ini",
     "/etc/gtk-3.0/settings.ini",
@@ -19,172 +26,421 @@ static ICON_CONFIG_PATHS: &[&str] = &[
     "/usr/share/icons/default/index.theme",
 ];
 
-// Try to detect using dconf/gsettings for GNOME-based environments
-fn query_gsettings(schema: &str, key: &str) -> Option<String> {
-    run_command("gsettings", &["get", schema, key])
+/// Where one setting lives for each backend
+struct Setting {
+    env: &'static str,
+    gsettings_key: &'static str,
+    kde: (&'static str, &'static str),
+    xfconf_property: &'static str,
+    config_paths: &'static [&'static str],
+    ini_key: &'static str,
+    /// Reads the files that aren't `.ini` style
+    other_file: fn(&Path) -> Option<String>,
+    /// Whether a name found in the files is installed; settings files are
+    /// often left behind naming a theme since removed
+    installed: fn(&str) -> bool,
 }
 
-// Try to detect using kf5-config for KDE
-fn query_kde_config(group: &str, key: &str) -> Option<String> {
-    // First try kreadconfig5
-    if let Some(value) = run_command("kreadconfig5", &["--group", group, "--key", key]) {
-        return Some(value);
+static THEME: Setting = Setting {
+    env: "GTK_THEME",
+    gsettings_key: "gtk-theme",
+    kde: ("KDE", "widgetStyle"),
+    xfconf_property: "/Net/ThemeName",
+    config_paths: THEME_CONFIG_PATHS,
+    ini_key: "gtk-theme-name",
+    other_file: gtkrc_theme,
+    installed: |_| true,
+};
+
+static ICONS: Setting = Setting {
+    env: "ICON_THEME",
+    gsettings_key: "icon-theme",
+    kde: ("Icons", "Theme"),
+    xfconf_property: "/Net/IconThemeName",
+    config_paths: ICON_CONFIG_PATHS,
+    ini_key: "gtk-icon-theme-name",
+    other_file: index_theme_icons,
+    installed: |name| icon_theme_index(name).is_some(),
+};
+
+/// Theme backends, usable in `theme_backends`
+pub static THEME_BACKENDS: &[&dyn Backend] = &[
+    &Portal(&THEME),
+    &Env(&THEME),
+    &Desktop(&THEME),
+    &Gsettings(&THEME),
+    &Kde(&THEME),
+    &XSettings(&THEME),
+    &Xfconf(&THEME),
+    &ConfigFiles(&THEME),
+];
+
+/// Icon theme backends, usable in `icon_backends`
+pub static ICON_BACKENDS: &[&dyn Backend] = &[
+    &Portal(&ICONS),
+    &Env(&ICONS),
+    &Desktop(&ICONS),
+    &Gsettings(&ICONS),
+    &Kde(&ICONS),
+    &XSettings(&ICONS),
+    &Xfconf(&ICONS),
+    &ConfigFiles(&ICONS),
+];
+
+/// Order tried unless the config says otherwise
+pub static DEFAULT_BACKENDS: &[&str] = &["portal", "env", "desktop", "xsettings", "config-files"];
+
+/// The settings portal, which exposes GNOME's and KDE's settings to apps alike
+struct Portal(&'static Setting);
+
+impl Backend for Portal {
+    fn name(&self) -> &'static str {
+        "portal"
     }
 
-    // Fall back to kreadconfig
-    run_command("kreadconfig", &["--group", group, "--key", key])
+    fn detect(&self) -> Option<String> {
+        let (group, key) = self.0.kde;
+        portal::setting("org.gnome.desktop.interface", self.0.gsettings_key)
+            .or_else(|| portal::setting(&format!("org.kde.kdeglobals.{group}"), key))
+    }
 }
 
-// Query XSETTINGS for Xfce and other desktops
-fn query_xsettings(property: &str) -> Option<String> {
-    run_command("xfconf-query", &["-c", "xsettings", "-p", property])
+/// The setting's environment variable
+struct Env(&'static Setting);
+
+impl Backend for Env {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn detect(&self) -> Option<String> {
+        Some(get_env_var(self.0.env, ""))
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    }
 }
 
-// Internal theme detection function
-fn detect_gtk_theme_internal() -> String {
-    // 1. First check environment variables (as you did)
-    if let Ok(theme) = std::env::var("GTK_THEME") {
-        if !theme.is_empty() {
-            return theme;
-        }
+/// Whichever of the other settings backends the running desktop uses
+struct Desktop(&'static Setting);
+
+impl Backend for Desktop {
+    fn name(&self) -> &'static str {
+        "desktop"
     }
 
-    // 2. Try desktop environment specific methods
-    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
-    let desktop_lower = desktop.to_lowercase();
+    fn detect(&self) -> Option<String> {
+        let desktop = get_env_var("XDG_CURRENT_DESKTOP", "").to_lowercase();
 
-    // For GNOME, Cinnamon, Budgie, etc.
-    if desktop_lower.contains("gnome")
-        || desktop_lower.contains("budgie")
-        || desktop_lower.contains("cinnamon")
-        || desktop_lower.contains("unity")
-    {
-        if let Some(theme) = query_gsettings("org.gnome.desktop.interface", "gtk-theme") {
-            return theme;
+        // For GNOME, Cinnamon, Budgie, etc.
+        if ["gnome", "budgie", "cinnamon", "unity"]
+            .iter()
+            .any(|name| desktop.contains(name))
+        {
+            Gsettings(self.0).detect()
+        } else if desktop.contains("kde") {
+            Kde(self.0).detect()
+        } else if desktop.contains("xfce") {
+            XSettings(self.0)
+                .detect()
+                .or_else(|| Xfconf(self.0).detect())
+        } else {
+            None
         }
     }
+}
 
-    // For KDE Plasma
-    if desktop_lower.contains("kde") {
-        if let Some(theme) = query_kde_config("KDE", "widgetStyle") {
-            return theme;
-        }
+/// dconf/gsettings for GNOME-based environments
+struct Gsettings(&'static Setting);
+
+impl Backend for Gsettings {
+    fn name(&self) -> &'static str {
+        "gsettings"
     }
 
-    // For Xfce
-    if desktop_lower.contains("xfce") {
-        if let Some(theme) = query_xsettings("/Net/ThemeName") {
-            return theme;
-        }
+    fn detect(&self) -> Option<String> {
+        run_command(
+            "gsettings",
+            &["get", "org.gnome.desktop.interface", self.0.gsettings_key],
+        )
     }
+}
 
-    // 3. Check config files
-    for path_str in THEME_CONFIG_PATHS {
-        let path = expand_path(path_str);
+/// kreadconfig for KDE
+struct Kde(&'static Setting);
 
-        // For .ini style files
-        if path.extension().is_some_and(|ext| ext == "ini") {
-            if let Some(theme) = search_file_for_key(&path, "gtk-theme-name") {
-                return theme;
-            }
-        }
-        // For gtk2 style files
-        else if path.file_name().is_some_and(|name| name == ".gtkrc-2.0") {
-            if let Ok(content) = std::fs::read_to_string(&path) {
-                for line in content.lines() {
-                    if line.contains("gtk-theme-name") {
-                        let parts: Vec<&str> = line.split('=').collect();
-                        if parts.len() > 1 {
-                            let theme = parts[1].trim().trim_matches('"');
-                            if !theme.is_empty() {
-                                return theme.to_string();
-                            }
-                        }
-                    }
-                }
+impl Backend for Kde {
+    fn name(&self) -> &'static str {
+        "kde"
+    }
+
+    fn detect(&self) -> Option<String> {
+        let (group, key) = self.0.kde;
+        // First try kreadconfig5, then fall back to kreadconfig
+        run_command("kreadconfig5", &["--group", group, "--key", key])
+            .or_else(|| run_command("kreadconfig", &["--group", group, "--key", key]))
+    }
+}
+
+/// The X settings daemon, whichever desktop runs it
+struct XSettings(&'static Setting);
+
+impl Backend for XSettings {
+    fn name(&self) -> &'static str {
+        "xsettings"
+    }
+
+    fn detect(&self) -> Option<String> {
+        // The same names as xfconf's, without the leading slash
+        xsettings::get(self.0.xfconf_property.trim_start_matches('/'))
+    }
+}
+
+/// XSETTINGS through xfconf, for Xfce
+struct Xfconf(&'static Setting);
+
+impl Backend for Xfconf {
+    fn name(&self) -> &'static str {
+        "xfconf"
+    }
+
+    fn detect(&self) -> Option<String> {
+        run_command(
+            "xfconf-query",
+            &["-c", "xsettings", "-p", self.0.xfconf_property],
+        )
+    }
+}
+
+/// GTK settings files in the home directory and /etc
+struct ConfigFiles(&'static Setting);
+
+impl Backend for ConfigFiles {
+    fn name(&self) -> &'static str {
+        "config-files"
+    }
+
+    fn detect(&self) -> Option<String> {
+        self.0.config_paths.iter().find_map(|path_str| {
+            let path = expand_path(path_str);
+            // For .ini style files
+            if path.extension().is_some_and(|ext| ext == "ini") {
+                search_file_for_key(&path, self.0.ini_key).filter(|name| (self.0.installed)(name))
+            } else {
+                (self.0.other_file)(&path)
             }
-        }
+        })
     }
+}
 
-    // If nothing found, return Unknown
-    "Unknown".to_string()
+/// `gtk-theme-name="Adwaita"` in a gtk2 style file
+fn gtkrc_theme(path: &Path) -> Option<String> {
+    if path.file_name().is_none_or(|name| name != ".gtkrc-2.0") {
+        return None;
+    }
+    let content = source::current().read_to_string(path).ok()?;
+    content
+        .lines()
+        .filter(|line| line.contains("gtk-theme-name"))
+        .find_map(|line| {
+            let (_, theme) = line.split_once('=')?;
+            let theme = theme.trim().trim_matches('"');
+            (!theme.is_empty()).then(|| theme.to_string())
+        })
 }
 
-// Internal icon theme detection function
-fn detect_icon_theme_internal() -> String {
-    // 1. First check environment variables
-    if let Ok(icons) = std::env::var("ICON_THEME") {
-        if !icons.is_empty() {
-            return icons;
+/// The theme a `default` index.theme stands for, following its `Inherits=`
+/// chain to the first installed theme with icons of its own; hicolor, the
+/// fallback every theme inherits, only when there is nothing else
+fn index_theme_icons(path: &Path) -> Option<String> {
+    if path.file_name().is_none_or(|name| name != "index.theme") {
+        return None;
+    }
+
+    let mut pending = index_theme_key(path, "Inherits")?;
+    let mut seen = Vec::new();
+    let mut hicolor = false;
+    while !pending.is_empty() {
+        let name = pending.remove(0);
+        if seen.contains(&name) {
+            continue;
+        }
+        seen.push(name.clone());
+        if name == "hicolor" {
+            hicolor = true;
+            continue;
+        }
+        let Some(index) = icon_theme_index(&name) else {
+            log::trace!("icons: {name} is inherited but not installed");
+            continue;
+        };
+        // Cursor themes and aliases like `default` list no icon directories
+        if index_theme_key(&index, "Directories").is_some() {
+            return Some(name);
         }
+        pending.extend(index_theme_key(&index, "Inherits").unwrap_or_default());
     }
+    hicolor.then(|| "hicolor".to_string())
+}
 
-    // 2. Try desktop environment specific methods
-    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
-    let desktop_lower = desktop.to_lowercase();
+/// The comma-separated names of a `key=` line in an index.theme file
+fn index_theme_key(path: &Path, key: &str) -> Option<Vec<String>> {
+    let content = source::current().read_to_string(path).ok()?;
+    let names: Vec<String> = content
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.trim_start().strip_prefix('='))?
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
 
-    // For GNOME, Cinnamon, Budgie, etc.
-    if desktop_lower.contains("gnome")
-        || desktop_lower.contains("budgie")
-        || desktop_lower.contains("cinnamon")
-        || desktop_lower.contains("unity")
-    {
-        if let Some(icons) = query_gsettings("org.gnome.desktop.interface", "icon-theme") {
-            return icons;
-        }
+/// Where icon themes are installed, in lookup order: `~/.icons`, then `icons`
+/// under the XDG data directories
+fn icon_dirs() -> Vec<PathBuf> {
+    let data_home = match get_env_var("XDG_DATA_HOME", "") {
+        "" => expand_path("~/.local/share"),
+        dir => PathBuf::from(dir),
+    };
+    let data_dirs = match get_env_var("XDG_DATA_DIRS", "") {
+        "" => "/usr/local/share:/usr/share",
+        dirs => dirs,
+    };
+
+    let mut dirs = vec![expand_path("~/.icons"), data_home.join("icons")];
+    dirs.extend(
+        data_dirs
+            .split(':')
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| Path::new(dir).join("icons")),
+    );
+    dirs
+}
+
+/// The index.theme of an installed icon theme
+fn icon_theme_index(name: &str) -> Option<PathBuf> {
+    icon_dirs()
+        .into_iter()
+        .map(|dir| dir.join(name).join("index.theme"))
+        .find(|index| source::current().exists(index))
+}
+
+/// Plasma's global theme and the Kvantum theme for a KDE session, e.g.
+/// `Global: Breeze Dark, Kvantum: KvArcDark`
+pub fn plasma_details() -> Option<String> {
+    if !get_env_var("XDG_CURRENT_DESKTOP", "").contains("KDE") {
+        return None;
     }
 
-    // For KDE Plasma
-    if desktop_lower.contains("kde") {
-        if let Some(icons) = query_kde_config("Icons", "Theme") {
-            return icons;
-        }
+    let mut details = Vec::new();
+    if let Some(package) =
+        search_file_for_key(&expand_path("~/.config/kdeglobals"), "LookAndFeelPackage")
+    {
+        details.push(format!(
+            "Global: {}",
+            look_and_feel_name(&package).unwrap_or(package)
+        ));
+    }
+    // `theme=` under `[General]`, the only key in that group
+    if let Some(kvantum) =
+        search_file_for_key(&expand_path("~/.config/Kvantum/kvantum.kvconfig"), "theme")
+    {
+        details.push(format!("Kvantum: {kvantum}"));
     }
+    (!details.is_empty()).then(|| details.join(", "))
+}
 
-    // For Xfce
-    if desktop_lower.contains("xfce") {
-        if let Some(icons) = query_xsettings("/Net/IconThemeName") {
-            return icons;
+/// The display name of a global theme package such as
+/// `org.kde.breezedark.desktop`, from its metadata
+fn look_and_feel_name(package: &str) -> Option<String> {
+    [
+        "~/.local/share/plasma/look-and-feel",
+        "/usr/share/plasma/look-and-feel",
+    ]
+    .iter()
+    .map(|dir| expand_path(dir).join(package))
+    .find_map(|dir| {
+        let source = source::current();
+        if let Ok(metadata) = source.read_to_string(&dir.join("metadata.json")) {
+            let metadata = json::parse(&metadata)?;
+            let name = metadata.get("KPlugin")?.get("Name")?.as_str()?;
+            return Some(name.to_string());
         }
+        search_file_for_key(&dir.join("metadata.desktop"), "Name=")
+    })
+}
+
+/// Whether apps are asked to use dark or light styles: what the settings portal
+/// hands to sandboxed apps, then GNOME's setting, then GTK's own
+pub fn color_scheme() -> Option<&'static str> {
+    let scheme = portal_color_scheme()
+        .or_else(gsettings_color_scheme)
+        .or_else(gtk_prefers_dark);
+    if scheme.is_none() {
+        log::fail!("theme: no dark or light preference set");
     }
+    scheme
+}
 
-    // 3. Check config files
-    for path_str in ICON_CONFIG_PATHS {
-        let path = expand_path(path_str);
+/// `org.freedesktop.appearance` `color-scheme`: 1 prefers dark, 2 light and
+/// 0 has no preference
+fn portal_color_scheme() -> Option<&'static str> {
+    match portal::setting("org.freedesktop.appearance", "color-scheme")?.as_str() {
+        "1" => Some("dark"),
+        "2" => Some("light"),
+        _ => None,
+    }
+}
 
-        // For .ini style files
-        if path.extension().is_some_and(|ext| ext == "ini") {
-            if let Some(icons) = search_file_for_key(&path, "gtk-icon-theme-name") {
-                return icons;
-            }
-        }
-        // For index.theme files
-        else if path.file_name().is_some_and(|name| name == "index.theme") {
-            if let Ok(content) = std::fs::read_to_string(&path) {
-                for line in content.lines() {
-                    if line.starts_with("Inherits=") {
-                        let icons = line.trim_start_matches("Inherits=").trim();
-                        if !icons.is_empty() {
-                            return icons.to_string();
-                        }
-                    }
-                }
-            }
-        }
+/// `'prefer-dark'`, `'prefer-light'` or `'default'`
+fn gsettings_color_scheme() -> Option<&'static str> {
+    let scheme = run_command(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    )?;
+    match scheme.trim_matches('\'') {
+        "prefer-dark" => Some("dark"),
+        "prefer-light" => Some("light"),
+        _ => None,
     }
+}
 
-    // If nothing found, return Unknown
-    "Unknown".to_string()
+/// `gtk-application-prefer-dark-theme` in the GTK settings files; unset or
+/// false doesn't say the user wants light
+fn gtk_prefers_dark() -> Option<&'static str> {
+    THEME_CONFIG_PATHS
+        .iter()
+        .map(|path| expand_path(path))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ini"))
+        .find_map(|path| search_file_for_key(&path, "gtk-application-prefer-dark-theme"))
+        .filter(|value| matches!(value.as_str(), "1" | "true"))
+        .map(|_| "dark")
 }
 
-/// Start theme detection in separate thread
-pub fn start_theme_detection() -> JoinHandle<String> {
-    thread::spawn(detect_gtk_theme_internal)
+fn spawn_detection(
+    what: &'static str,
+    backends: &'static [&'static dyn Backend],
+    order: Vec<String>,
+) -> JoinHandle<String> {
+    thread::spawn(move || {
+        backend::detect(what, backends, &order).unwrap_or_else(|| "Unknown".to_string())
+    })
 }
 
-/// Start icon theme detection in separate thread
-pub fn start_icon_detection() -> JoinHandle<String> {
-    thread::spawn(detect_icon_theme_internal)
+/// Start theme detection in separate thread, trying the backends in `order`
+pub fn start_theme_detection(order: Vec<String>) -> JoinHandle<String> {
+    spawn_detection("theme", THEME_BACKENDS, order)
+}
+
+/// Start icon theme detection in separate thread, trying the backends in `order`
+pub fn start_icon_detection(order: Vec<String>) -> JoinHandle<String> {
+    spawn_detection("icons", ICON_BACKENDS, order)
+}
+
+/// Start looking for the dark/light preference in a separate thread
+pub fn start_color_scheme_detection() -> JoinHandle<Option<&'static str>> {
+    thread::spawn(color_scheme)
 }
 
 /// Join theme detection thread and handle errors
@@ -194,6 +450,5 @@ pub fn join_theme_detection_thread(handle: JoinHandle<String>) -> String {
 
 /// Join icon detection thread and handle errors
 pub fn join_icon_detection_thread(handle: JoinHandle<String>) -> String {
-    handle
-        .join().unwrap_or_else(|_| "Unknown".to_string())
+    handle.join().unwrap_or_else(|_| "Unknown".to_string())
 }
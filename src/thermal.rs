@@ -0,0 +1,87 @@
+//! Temperatures
+//! The kernel's thermal zones, plus the CPU and NVMe sensors that only show up
+//! under hwmon (AMD CPUs rarely have a zone of their own)
+
+use crate::source;
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct Zone {
+    /// What the zone measures, e.g. `CPU` or `NVMe`
+    pub name: String,
+    pub celsius: f64,
+}
+
+/// hwmon drivers worth a zone, by the name they register
+const HWMON_SENSORS: &[(&str, &str)] = &[("k10temp", "CPU"), ("coretemp", "CPU"), ("nvme", "NVMe")];
+
+/// One reading per kind of zone, the hottest, hottest first
+pub fn zones() -> Vec<Zone> {
+    let source = source::current();
+    let mut zones: Vec<Zone> = Vec::new();
+    let mut add = |name: String, millis: &str| {
+        let Ok(millis) = millis.trim().parse::<i64>() else {
+            return;
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let celsius = millis as f64 / 1000.0;
+        match zones.iter_mut().find(|z| z.name == name) {
+            Some(zone) => zone.celsius = zone.celsius.max(celsius),
+            None => zones.push(Zone { name, celsius }),
+        }
+    };
+
+    for zone in source
+        .read_dir(Path::new("/sys/class/thermal"))
+        .unwrap_or_default()
+    {
+        let is_zone = zone
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with("thermal_zone"));
+        if !is_zone {
+            continue;
+        }
+        if let (Ok(kind), Ok(temp)) = (
+            source.read_to_string(&zone.join("type")),
+            source.read_to_string(&zone.join("temp")),
+        ) {
+            add(zone_name(kind.trim()), &temp);
+        }
+    }
+
+    for hwmon in source
+        .read_dir(Path::new("/sys/class/hwmon"))
+        .unwrap_or_default()
+    {
+        let Ok(driver) = source.read_to_string(&hwmon.join("name")) else {
+            continue;
+        };
+        let Some((_, name)) = HWMON_SENSORS.iter().find(|(d, _)| *d == driver.trim()) else {
+            continue;
+        };
+        // temp1 is Tctl on k10temp, the package on coretemp and the
+        // composite temperature on NVMe
+        if let Ok(temp) = source.read_to_string(&hwmon.join("temp1_input")) {
+            add((*name).to_string(), &temp);
+        }
+    }
+
+    zones.sort_by(|a, b| b.celsius.total_cmp(&a.celsius));
+    zones
+}
+
+/// A readable name for a zone type
+fn zone_name(kind: &str) -> String {
+    match kind {
+        "x86_pkg_temp" | "cpu-thermal" | "cpu_thermal" | "soc_thermal" | "k10temp" => {
+            "CPU".to_string()
+        }
+        "acpitz" => "ACPI".to_string(),
+        "pch_skylake" | "pch_cannonlake" | "pch_cometlake" => "PCH".to_string(),
+        "iwlwifi_1" | "iwlwifi" => "Wi-Fi".to_string(),
+        "gpu-thermal" | "gpu_thermal" => "GPU".to_string(),
+        kind if kind.starts_with("nvme") => "NVMe".to_string(),
+        kind if kind.starts_with("B0D4") || kind.starts_with("TCPU") => "CPU".to_string(),
+        kind => kind.to_string(),
+    }
+}
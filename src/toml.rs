@@ -0,0 +1,650 @@
+//! Minimal TOML reader
+//! Supports the subset needed for the config file: tables (including dotted
+//! and quoted names), strings (multi-line too), integers, floats, booleans
+//! and arrays
+
+use std::fmt;
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Array(Vec<Value>),
+    Table(Table),
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub const fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(f) => Some(*f),
+            Self::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub const fn as_table(&self) -> Option<&Table> {
+        match self {
+            Self::Table(t) => Some(t),
+            _ => None,
+        }
+    }
+}
+
+/// Key/value table that keeps the order keys were written in
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    entries: Vec<(String, Value)>,
+}
+
+impl Table {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Insert or replace a value
+    pub fn insert(&mut self, key: String, value: Value) {
+        if let Some(slot) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            slot.1 = value;
+        } else {
+            self.entries.push((key, value));
+        }
+    }
+
+    /// Get the sub-table at `key`, creating it if missing
+    fn table_mut(&mut self, key: &str) -> Result<&mut Self, String> {
+        let idx = match self.entries.iter().position(|(k, _)| k == key) {
+            Some(idx) => idx,
+            None => {
+                self.entries
+                    .push((key.to_string(), Value::Table(Self::default())));
+                self.entries.len() - 1
+            }
+        };
+
+        match &mut self.entries[idx].1 {
+            Value::Table(t) => Ok(t),
+            _ => Err(format!("key '{key}' is not a table")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parse a TOML document into its root table
+pub fn parse(input: &str) -> Result<Table, ParseError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+        line: 1,
+    };
+    parser.document().map_err(|message| ParseError {
+        line: parser.line,
+        message,
+    })
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Take `expected` if it comes next
+    fn next_if(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.bump();
+        }
+        found
+    }
+
+    /// Take `expected`, or fail without consuming anything so that the error
+    /// is reported on the line it happened on
+    fn eat(&mut self, expected: char) -> Result<(), String> {
+        if self.next_if(expected) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some('\r' | '\n') => Err(format!("expected '{expected}', found end of line")),
+            Some(c) => Err(format!("expected '{expected}', found '{c}'")),
+            None => Err(format!("expected '{expected}', found end of file")),
+        }
+    }
+
+    /// Skip spaces and tabs on the current line
+    fn skip_inline_ws(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip whitespace, newlines and comments
+    fn skip_ws_and_comments(&mut self) {
+        loop {
+            match self.peek() {
+                Some(' ' | '\t' | '\r' | '\n') => {
+                    self.bump();
+                }
+                Some('#') => self.skip_comment(),
+                _ => break,
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                break;
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Expect the rest of the line to be empty or a comment
+    fn end_of_line(&mut self) -> Result<(), String> {
+        self.skip_inline_ws();
+        if self.peek() == Some('#') {
+            self.skip_comment();
+        }
+        match self.peek() {
+            None | Some('\n') => Ok(()),
+            Some('\r') if self.chars.get(self.pos + 1) == Some(&'\n') => Ok(()),
+            Some(c) => Err(format!("unexpected '{c}' after value")),
+        }
+    }
+
+    fn document(&mut self) -> Result<Table, String> {
+        let mut root = Table::default();
+        let mut current: Vec<String> = Vec::new();
+
+        loop {
+            self.skip_ws_and_comments();
+            match self.peek() {
+                None => break,
+                Some('[') => {
+                    self.bump();
+                    self.skip_inline_ws();
+                    current = self.key_path()?;
+                    self.skip_inline_ws();
+                    self.eat(']')?;
+                    self.end_of_line()?;
+
+                    let mut table = &mut root;
+                    for part in &current {
+                        table = table.table_mut(part)?;
+                    }
+                }
+                Some(_) => {
+                    let path = self.key_path()?;
+                    self.skip_inline_ws();
+                    self.eat('=')?;
+                    self.skip_inline_ws();
+                    let value = self.value()?;
+                    self.end_of_line()?;
+
+                    let mut table = &mut root;
+                    for part in current.iter().chain(&path[..path.len() - 1]) {
+                        table = table.table_mut(part)?;
+                    }
+                    let key = path.last().cloned().unwrap_or_default();
+                    if table.get(&key).is_some() {
+                        return Err(format!("duplicate key '{key}'"));
+                    }
+                    table.insert(key, value);
+                }
+            }
+        }
+
+        Ok(root)
+    }
+
+    /// Parse a possibly dotted key like `a."b c".d`
+    fn key_path(&mut self) -> Result<Vec<String>, String> {
+        let mut parts = vec![self.key()?];
+        loop {
+            self.skip_inline_ws();
+            if self.peek() != Some('.') {
+                break;
+            }
+            self.bump();
+            self.skip_inline_ws();
+            parts.push(self.key()?);
+        }
+        Ok(parts)
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some('"') => self.basic_string(),
+            Some('\'') => self.literal_string(),
+            _ => {
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                if self.pos == start {
+                    return Err("expected a key".to_string());
+                }
+                Ok(self.chars[start..self.pos].iter().collect())
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) if self.chars[self.pos..].starts_with(&[quote; 3]) => {
+                self.multiline_string(quote).map(Value::String)
+            }
+            Some('"') => self.basic_string().map(Value::String),
+            Some('\'') => self.literal_string().map(Value::String),
+            Some('[') => self.array(),
+            Some('{') => self.inline_table(),
+            Some('t' | 'f') => self.boolean(),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' => self.number(),
+            Some('\r' | '\n') | None => Err("expected a value".to_string()),
+            Some(c) => Err(format!("unexpected '{c}' at start of value")),
+        }
+    }
+
+    fn basic_string(&mut self) -> Result<String, String> {
+        self.eat('"')?;
+        let mut out = String::new();
+        loop {
+            match self.single_line_char()? {
+                '"' => return Ok(out),
+                '\\' => out.push(self.escape()?),
+                c => out.push(c),
+            }
+        }
+    }
+
+    /// The character a backslash escape stands for, after the backslash
+    fn escape(&mut self) -> Result<char, String> {
+        match self.bump() {
+            Some('n') => Ok('\n'),
+            Some('t') => Ok('\t'),
+            Some('r') => Ok('\r'),
+            Some('e') => Ok('\x1b'),
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('u') => self.unicode_escape(4),
+            Some('U') => self.unicode_escape(8),
+            Some(c) => Err(format!("invalid escape '\\{c}'")),
+            None => Err("unterminated string".to_string()),
+        }
+    }
+
+    fn unicode_escape(&mut self, len: usize) -> Result<char, String> {
+        let mut code = 0u32;
+        for _ in 0..len {
+            let digit = self
+                .bump()
+                .and_then(|c| c.to_digit(16))
+                .ok_or("invalid unicode escape")?;
+            code = code * 16 + digit;
+        }
+        char::from_u32(code).ok_or_else(|| "invalid unicode scalar".to_string())
+    }
+
+    fn literal_string(&mut self) -> Result<String, String> {
+        self.eat('\'')?;
+        let mut out = String::new();
+        loop {
+            match self.single_line_char()? {
+                '\'' => return Ok(out),
+                c => out.push(c),
+            }
+        }
+    }
+
+    /// The next character of a single-line string, which must not run into
+    /// the next line
+    fn single_line_char(&mut self) -> Result<char, String> {
+        match self.peek() {
+            None | Some('\n') => Err("unterminated string".to_string()),
+            Some(c) => {
+                self.pos += 1;
+                Ok(c)
+            }
+        }
+    }
+
+    /// A `"""` or `'''` string; a newline right after the opening quotes is
+    /// dropped, and in basic ones a backslash ending a line trims the line
+    /// break and the whitespace after it
+    fn multiline_string(&mut self, quote: char) -> Result<String, String> {
+        self.pos += 3;
+        if self.peek() == Some('\r') && self.chars.get(self.pos + 1) == Some(&'\n') {
+            self.pos += 1;
+        }
+        if self.peek() == Some('\n') {
+            self.bump();
+        }
+
+        let mut out = String::new();
+        loop {
+            if self.chars[self.pos..].starts_with(&[quote; 3]) {
+                self.pos += 3;
+                // Up to two quotes can end the content right before the closing ones
+                for _ in 0..2 {
+                    if self.peek() == Some(quote) {
+                        self.pos += 1;
+                        out.push(quote);
+                    }
+                }
+                return Ok(out);
+            }
+            match self.bump() {
+                None => return Err("unterminated string".to_string()),
+                Some('\\') if quote == '"' => {
+                    let rest = &self.chars[self.pos..];
+                    let line_end = rest.iter().position(|&c| c == '\n');
+                    if line_end.is_some_and(|end| rest[..end].iter().all(|c| c.is_whitespace())) {
+                        while self.peek().is_some_and(char::is_whitespace) {
+                            self.bump();
+                        }
+                    } else {
+                        out.push(self.escape()?);
+                    }
+                }
+                Some(c) => out.push(c),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.eat('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_ws_and_comments();
+            if self.next_if(']') {
+                return Ok(Value::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_ws_and_comments();
+            if self.next_if(']') {
+                return Ok(Value::Array(items));
+            }
+            if !self.next_if(',') {
+                return Err("expected ',' or ']' in array".to_string());
+            }
+        }
+    }
+
+    fn inline_table(&mut self) -> Result<Value, String> {
+        self.eat('{')?;
+        let mut table = Table::default();
+        self.skip_inline_ws();
+        if self.next_if('}') {
+            return Ok(Value::Table(table));
+        }
+        loop {
+            self.skip_inline_ws();
+            let key = self.key()?;
+            self.skip_inline_ws();
+            self.eat('=')?;
+            self.skip_inline_ws();
+            let value = self.value()?;
+            table.insert(key, value);
+            self.skip_inline_ws();
+            if self.next_if('}') {
+                return Ok(Value::Table(table));
+            }
+            if !self.next_if(',') {
+                return Err("expected ',' or '}' in inline table".to_string());
+            }
+        }
+    }
+
+    fn boolean(&mut self) -> Result<Value, String> {
+        for (word, value) in [("true", true), ("false", false)] {
+            let end = self.pos + word.len();
+            if end <= self.chars.len() && self.chars[self.pos..end].iter().copied().eq(word.chars())
+            {
+                self.pos = end;
+                return Ok(Value::Boolean(value));
+            }
+        }
+        Err("expected a boolean".to_string())
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.' | '_') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let text: String = self.chars[start..self.pos]
+            .iter()
+            .filter(|&&c| c != '_')
+            .collect();
+
+        if let Ok(i) = text.parse::<i64>() {
+            return Ok(Value::Integer(i));
+        }
+        if let Some(hex) = text.strip_prefix("0x")
+            && let Ok(i) = i64::from_str_radix(hex, 16)
+        {
+            return Ok(Value::Integer(i));
+        }
+        text.parse::<f64>()
+            .map(Value::Float)
+            .map_err(|_| format!("invalid number '{text}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Table, Value, parse};
+
+    fn get<'a>(table: &'a Table, path: &str) -> Option<&'a Value> {
+        let mut parts = path.split('.');
+        let first = table.get(parts.next()?)?;
+        parts.try_fold(first, |value, part| value.as_table()?.get(part))
+    }
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn tables_and_dotted_keys() {
+        let doc = parse(
+            "top = 1\n\
+             a.b.c = 'x'\n\
+             [colors]\n\
+             title.fg = \"red\"\n\
+             [\"quoted name\" . inner]\n\
+             key = true\n",
+        )
+        .unwrap();
+        assert_eq!(get(&doc, "top").and_then(Value::as_integer), Some(1));
+        assert_eq!(get(&doc, "a.b.c").and_then(Value::as_str), Some("x"));
+        assert_eq!(
+            get(&doc, "colors.title.fg").and_then(Value::as_str),
+            Some("red")
+        );
+        assert_eq!(
+            get(&doc, "quoted name.inner.key").and_then(Value::as_bool),
+            Some(true)
+        );
+        // Keys keep the order they were written in
+        let keys: Vec<&str> = doc.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["top", "a", "colors", "quoted name"]);
+    }
+
+    #[test]
+    fn dotted_key_through_a_value() {
+        assert_eq!(error("a = 1\na.b = 2\n"), "line 2: key 'a' is not a table");
+    }
+
+    #[test]
+    fn duplicate_key() {
+        assert_eq!(error("[x]\nk = 1\nk = 2\n"), "line 3: duplicate key 'k'");
+    }
+
+    #[test]
+    fn multiline_strings() {
+        let doc = parse(
+            "basic = \"\"\"\n\
+             one\n\
+             two \\\n    three\\t\"\"\"\n\
+             literal = '''\n\
+             C:\\path\\''''\n",
+        )
+        .unwrap();
+        assert_eq!(
+            get(&doc, "basic").and_then(Value::as_str),
+            Some("one\ntwo three\t")
+        );
+        assert_eq!(
+            get(&doc, "literal").and_then(Value::as_str),
+            Some("C:\\path\\'")
+        );
+        assert!(error("s = \"\"\"never closed\n\n").contains("unterminated string"));
+    }
+
+    #[test]
+    fn strings_and_escapes() {
+        let doc = parse("a = \"tab\\there \\u00e9\\U0001F600\"\nb = 'no \\n escapes'\n").unwrap();
+        assert_eq!(
+            get(&doc, "a").and_then(Value::as_str),
+            Some("tab\there é😀")
+        );
+        assert_eq!(
+            get(&doc, "b").and_then(Value::as_str),
+            Some("no \\n escapes")
+        );
+
+        assert_eq!(error("a = \"bad \\q\"\n"), "line 1: invalid escape '\\q'");
+        assert_eq!(error("a = \"open\nb = 1\n"), "line 1: unterminated string");
+        assert!(error("a = \"\\ud800\"\n").contains("invalid unicode scalar"));
+    }
+
+    #[test]
+    fn numbers() {
+        let doc = parse("a = 1_000\nb = -7\nc = 0xff\nd = 1.5\ne = 2e3\n").unwrap();
+        assert_eq!(get(&doc, "a").and_then(Value::as_integer), Some(1000));
+        assert_eq!(get(&doc, "b").and_then(Value::as_integer), Some(-7));
+        assert_eq!(get(&doc, "c").and_then(Value::as_integer), Some(255));
+        assert_eq!(get(&doc, "d").and_then(Value::as_float), Some(1.5));
+        assert_eq!(get(&doc, "e").and_then(Value::as_float), Some(2000.0));
+        assert_eq!(get(&doc, "b").and_then(Value::as_float), Some(-7.0));
+        assert_eq!(error("a = 12abc\n"), "line 1: invalid number '12abc'");
+    }
+
+    #[test]
+    fn arrays_and_inline_tables() {
+        let doc = parse(
+            "modules = [\n  \"os\", # comment\n  \"cpu\",\n]\n\
+             nested = [[1, 2], []]\n\
+             point = { x = 1, y = \"two\" }\n",
+        )
+        .unwrap();
+        let modules: Vec<&str> = get(&doc, "modules")
+            .and_then(Value::as_array)
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(modules, ["os", "cpu"]);
+        let nested = get(&doc, "nested").and_then(Value::as_array).unwrap();
+        assert_eq!(nested[0].as_array().map(<[Value]>::len), Some(2));
+        assert_eq!(nested[1].as_array().map(<[Value]>::len), Some(0));
+        assert_eq!(get(&doc, "point.y").and_then(Value::as_str), Some("two"));
+
+        assert_eq!(error("a = [1 2]\n"), "line 1: expected ',' or ']' in array");
+        assert_eq!(
+            error("a = { x = 1\n"),
+            "line 1: expected ',' or '}' in inline table"
+        );
+    }
+
+    #[test]
+    fn line_structure() {
+        let doc = parse("# comment\r\na = 1 # trailing\r\n\r\n[t]\r\nb = 2").unwrap();
+        assert_eq!(get(&doc, "t.b").and_then(Value::as_integer), Some(2));
+
+        assert_eq!(error("a = 1 b = 2\n"), "line 1: unexpected 'b' after value");
+        assert_eq!(
+            error("\n\n[table\n"),
+            "line 3: expected ']', found end of line"
+        );
+        assert_eq!(error("[table"), "line 1: expected ']', found end of file");
+        assert_eq!(error("= 1\n"), "line 1: expected a key");
+        assert_eq!(error("a =\n"), "line 1: expected a value");
+        assert_eq!(
+            error("a = nope\n"),
+            "line 1: unexpected 'n' at start of value"
+        );
+        assert_eq!(error("a = tru\n"), "line 1: expected a boolean");
+    }
+}
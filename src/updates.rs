@@ -0,0 +1,121 @@
+//! Pending updates
+//! Counted from what the package manager last synced, never from the network:
+//! pacman's sync databases against the local one, or the count apt's
+//! update-notifier leaves behind; plus whether a new kernel awaits a reboot
+
+use crate::gzip;
+use crate::source;
+use crate::tar;
+use crate::utils::format_uptime;
+use rustc_hash::FxHashMap;
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// e.g. `23 pending (synced 5h 2m ago), reboot required`
+pub fn updates_summary() -> Option<String> {
+    let mut parts = Vec::new();
+
+    let pending = pacman()
+        .map(|count| (count, "/var/lib/pacman/sync"))
+        .or_else(|| apt().map(|count| (count, "/var/lib/apt/periodic/update-success-stamp")));
+    if let Some((count, stamp)) = pending {
+        parts.push(match synced_ago(Path::new(stamp)) {
+            Some(ago) => format!("{count} pending (synced {} ago)", format_uptime(ago)),
+            None => format!("{count} pending"),
+        });
+    }
+    if reboot_required() {
+        parts.push("reboot required".to_string());
+    }
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Installed packages whose version differs from the one in the sync
+/// databases, as `checkupdates` would report after the same sync
+fn pacman() -> Option<usize> {
+    let source = source::current();
+    let local = source.read_dir(Path::new("/var/lib/pacman/local")).ok()?;
+    let mut dbs = source.read_dir(Path::new("/var/lib/pacman/sync")).ok()?;
+    // Repos are searched in pacman.conf order, core before extra
+    dbs.sort();
+
+    let mut synced: FxHashMap<String, String> = FxHashMap::default();
+    for db in dbs
+        .iter()
+        .filter(|db| db.extension().is_some_and(|e| e == "db"))
+    {
+        // zstd-compressed databases are left for pacman to read
+        let Some(entries) = source
+            .read(db)
+            .ok()
+            .and_then(|data| gzip::decompress(&data))
+            .and_then(|tar| tar::read(&tar).ok())
+        else {
+            continue;
+        };
+        // `bash-5.2.026-2/desc`, with or without an entry for the directory
+        for entry in &entries {
+            let dir = entry.path.split('/').next().unwrap_or_default();
+            if let Some((name, version)) = split_package(dir) {
+                synced
+                    .entry(name.to_string())
+                    .or_insert_with(|| version.to_string());
+            }
+        }
+    }
+    if synced.is_empty() {
+        return None;
+    }
+
+    Some(
+        local
+            .iter()
+            .filter_map(|dir| split_package(dir.file_name()?.to_str()?))
+            .filter(|(name, version)| synced.get(*name).is_some_and(|v| v != version))
+            .count(),
+    )
+}
+
+/// `linux-firmware-20240610.8f01ba34-1` into name and `pkgver-pkgrel`
+fn split_package(dir: &str) -> Option<(&str, &str)> {
+    let rel = dir.rfind('-')?;
+    let ver = dir[..rel].rfind('-')?;
+    Some((&dir[..ver], &dir[ver + 1..]))
+}
+
+/// update-notifier's `23 updates can be applied immediately.`
+fn apt() -> Option<usize> {
+    let notice = source::current()
+        .read_to_string(Path::new("/var/lib/update-notifier/updates-available"))
+        .ok()?;
+    // With nothing pending the file may be empty or only mention ESM
+    let count = notice
+        .lines()
+        .find(|line| line.contains("can be applied") || line.contains("can be installed"))
+        .and_then(|line| line.split_whitespace().next()?.parse().ok())
+        .unwrap_or(0);
+    Some(count)
+}
+
+/// Seconds since `path` was last modified, on the live system only
+fn synced_ago(path: &Path) -> Option<u64> {
+    if !source::current().is_live() {
+        return None;
+    }
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    Some(SystemTime::now().duration_since(modified).ok()?.as_secs())
+}
+
+/// Debian's flag file, or the running kernel's modules having been replaced
+/// by an upgrade
+fn reboot_required() -> bool {
+    let source = source::current();
+    if source.exists(Path::new("/run/reboot-required")) {
+        return true;
+    }
+    let release = source.uname().release;
+    source.exists(Path::new("/lib/modules"))
+        && !source.exists(&Path::new("/lib/modules").join(release))
+}
@@ -1,9 +1,10 @@
+use crate::log;
+use crate::source;
 use libc::{self};
 use rustc_hash::FxHashMap;
+use std::borrow::Cow;
 use std::ffi::{CStr, CString};
-use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::sync::LazyLock;
 
 // File and path utilities
@@ -12,37 +13,92 @@ use std::sync::LazyLock;
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub fn file_exists(path: &Path) -> bool {
-    Path::new(path).exists()
+    source::current().exists(path)
 }
 
 /// Expand ~ to home directory
 pub fn expand_path(path: &str) -> PathBuf {
-    if let Some(stripped) = path.strip_prefix("~/") {
-        if let Ok(home) = std::env::var("HOME") {
-            return PathBuf::from(home).join(stripped);
-        }
+    if let Some(stripped) = path.strip_prefix("~/")
+        && let Some(home) = source::current().env("HOME")
+    {
+        return PathBuf::from(home).join(stripped);
     }
     PathBuf::from(path)
 }
 
+/// Directory for small bits of state persisted between runs
+/// (`$XDG_STATE_HOME/tachifetch`, falling back to `~/.local/state/tachifetch`)
+pub fn state_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("XDG_STATE_HOME")
+        && !dir.is_empty()
+    {
+        return Some(PathBuf::from(dir).join("tachifetch"));
+    }
+    std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".local/state/tachifetch"))
+}
+
 // Environment variable utilities
 
 /// Environment variable cache to avoid repeated lookups
-pub static ENV_CACHE: LazyLock<FxHashMap<&'static str, String>> = LazyLock::new(|| {
-    let mut map = FxHashMap::default();
-    for var in &[
-        "XDG_CURRENT_DESKTOP",
-        "XDG_SESSION_TYPE",
-        "SHELL",
-        "TERM",
-        "WAYLAND_DISPLAY",
-        "DISPLAY",
-        "DESKTOP_SESSION",
-        "GTK_THEME",
-        "ICON_THEME",
-    ] {
-        if let Ok(val) = std::env::var(*var) {
-            map.insert(*var, val);
+///
+/// Values point straight into the process environment instead of being
+/// copied; that is sound because nothing in the program modifies it.
+pub static ENV_CACHE: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    let vars: &[&CStr] = &[
+        c"HOME",
+        c"XDG_CURRENT_DESKTOP",
+        c"XDG_SESSION_TYPE",
+        c"SHELL",
+        c"TERM",
+        c"WAYLAND_DISPLAY",
+        c"DISPLAY",
+        c"DESKTOP_SESSION",
+        c"GTK_THEME",
+        c"ICON_THEME",
+        c"XDG_DATA_HOME",
+        c"XDG_DATA_DIRS",
+        c"XCURSOR_THEME",
+        c"XAUTHORITY",
+        c"NO_COLOR",
+        c"CLICOLOR",
+        c"CLICOLOR_FORCE",
+        c"FORCE_COLOR",
+        c"TERM_PROGRAM",
+        c"VTE_VERSION",
+        c"KITTY_WINDOW_ID",
+        c"ALACRITTY_SOCKET",
+        c"WEZTERM_EXECUTABLE",
+        c"WT_SESSION",
+        c"KONSOLE_VERSION",
+        c"COLORTERM",
+        c"USER",
+        c"XDG_RUNTIME_DIR",
+        c"DOCKER_HOST",
+        c"DBUS_SESSION_BUS_ADDRESS",
+        c"HYPRLAND_INSTANCE_SIGNATURE",
+        c"SWAYSOCK",
+        c"I3SOCK",
+        c"SNAP",
+        c"CARGO_HOME",
+        c"PIPX_HOME",
+        c"NPM_CONFIG_PREFIX",
+        c"LC_ALL",
+        c"LC_MESSAGES",
+        c"LANG",
+        c"GDK_SCALE",
+        c"QT_SCALE_FACTOR",
+    ];
+
+    let mut map = FxHashMap::with_capacity_and_hasher(vars.len(), Default::default());
+    for var in vars {
+        let ptr = unsafe { libc::getenv(var.as_ptr()) };
+        if ptr.is_null() {
+            continue;
+        }
+        if let (Ok(name), Ok(value)) = (var.to_str(), unsafe { CStr::from_ptr(ptr) }.to_str()) {
+            map.insert(name, value);
         }
     }
     map
@@ -51,8 +107,8 @@ pub static ENV_CACHE: LazyLock<FxHashMap<&'static str, String>> = LazyLock::new(
 /// Get environment variable from cache with default value
 #[allow(clippy::inline_always)]
 #[inline(always)]
-pub fn get_env_var<'a>(name: &'a str, default: &'a str) -> &'a str {
-    ENV_CACHE.get(name).map_or(default, |val| val)
+pub fn get_env_var<'a>(name: &str, default: &'a str) -> &'a str {
+    source::current().env(name).unwrap_or(default)
 }
 
 /// Get environment variable from raw C environment
@@ -74,15 +130,44 @@ pub unsafe fn get_raw_env(name: &str) -> Option<String> {
 
 /// Execute a command and return its trimmed output if successful
 pub fn run_command(cmd: &str, args: &[&str]) -> Option<String> {
-    let output = Command::new(cmd).args(args).output().ok()?;
+    run(cmd, args, true)
+}
 
-    if output.status.success() {
-        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if !value.is_empty() {
-            return Some(value);
+/// Execute a command and return its trimmed output whatever its exit status,
+/// for commands that report through both
+pub fn run_command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    run(cmd, args, false)
+}
+
+fn run(cmd: &str, args: &[&str], check_status: bool) -> Option<String> {
+    let command = || {
+        [cmd]
+            .iter()
+            .chain(args)
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    log::trace!("running `{}`", command());
+
+    let output = match source::current().run(cmd, args) {
+        Ok(output) => output,
+        Err(e) => {
+            log::fail!("cannot run `{cmd}`: {e}");
+            return None;
         }
+    };
+
+    if check_status && !output.status.success() {
+        log::fail!("`{}` failed ({})", command(), output.status);
+        return None;
     }
-    None
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if value.is_empty() {
+        log::fail!("`{}` printed nothing", command());
+        return None;
+    }
+    Some(value)
 }
 
 // File parsing utilities
@@ -93,7 +178,7 @@ pub fn search_file_for_key(path: &Path, key: &str) -> Option<String> {
         return None;
     }
 
-    if let Ok(content) = fs::read_to_string(path) {
+    if let Ok(content) = source::current().read_to_string(path) {
         for line in content.lines() {
             let line = line.trim();
             if line.starts_with(key) && line.contains('=') {
@@ -146,11 +231,127 @@ pub fn parse_number_after(data: &[u8], offset: usize) -> Option<(u64, usize)> {
 
 // Formatting utilities
 
+/// [`sanitize`] without copying values that are already safe
+pub fn sanitized(value: &str) -> Cow<'_, str> {
+    if value.chars().any(char::is_control) {
+        let mut value = value.to_string();
+        sanitize(&mut value);
+        Cow::Owned(value)
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Remove ANSI escape sequences (CSI colors/cursor movement and OSC
+/// hyperlinks/titles) from a string
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(pos) = rest.find('\x1b') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + escape_len(&rest[pos..])..];
+    }
+    out.push_str(rest);
+
+    out
+}
+
+/// Length in bytes of the escape sequence at the start of `s`: a CSI runs to
+/// its final byte, an OSC to BEL or the `ESC \` string terminator, anything
+/// else is ESC and the character after it
+pub fn escape_len(s: &str) -> usize {
+    let mut chars = s.char_indices().skip(1);
+    let end = match chars.next() {
+        Some((_, '[')) => chars
+            .find(|&(_, c)| ('@'..='~').contains(&c))
+            .map(|(i, c)| i + c.len_utf8()),
+        Some((_, ']')) => chars.find_map(|(i, c)| match c {
+            '\x07' => Some(i + 1),
+            '\x1b' if s[i + 1..].starts_with('\\') => Some(i + 2),
+            _ => None,
+        }),
+        Some((i, c)) => Some(i + c.len_utf8()),
+        None => None,
+    };
+    end.unwrap_or(s.len())
+}
+
+/// Terminal columns a character takes: two for East Asian wide characters,
+/// none for combining marks and direction controls
+pub fn char_width(c: char) -> usize {
+    match u32::from(c) {
+        0x0300..=0x036f
+        | 0x0591..=0x05c7
+        | 0x064b..=0x065f
+        | 0x200b..=0x200f
+        | 0x2066..=0x2069
+        | 0xfe00..=0xfe0f => 0,
+        0x1100..=0x115f
+        | 0x2e80..=0x303e
+        | 0x3041..=0x33ff
+        | 0x3400..=0x4dbf
+        | 0x4e00..=0x9fff
+        | 0xa000..=0xa4cf
+        | 0xac00..=0xd7a3
+        | 0xf900..=0xfaff
+        | 0xfe30..=0xfe4f
+        | 0xff00..=0xff60
+        | 0xffe0..=0xffe6
+        | 0x1f300..=0x1f64f
+        | 0x1f900..=0x1f9ff
+        | 0x20000..=0x3fffd => 2,
+        _ => 1,
+    }
+}
+
+/// Terminal columns a string without escape sequences takes
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Make an externally sourced value safe to print
+///
+/// Theme names, hostnames and file contents could carry escape sequences that
+/// rewrite the terminal title, move the cursor or smuggle in hyperlinks. Escape
+/// sequences are removed and any remaining control characters dropped, with
+/// line breaks and tabs turned into spaces so values stay on their line.
+pub fn sanitize(value: &mut String) {
+    if !value.chars().any(char::is_control) {
+        return;
+    }
+
+    *value = strip_ansi(value)
+        .chars()
+        .filter_map(|c| match c {
+            '\t' | '\n' | '\r' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect();
+}
+
 /// Format byte size to MiB
 pub fn format_memory(bytes: u64) -> String {
     format!("{} MiB", bytes >> 20)
 }
 
+/// Format a byte count with a binary unit, e.g. `1.4 GiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
 /// Format seconds to a human-readable uptime string
 pub fn format_uptime(seconds: u64) -> String {
     let mins = seconds / 60;
@@ -169,6 +370,83 @@ pub fn format_uptime(seconds: u64) -> String {
     format!("{days}d {hours}h {mins}m")
 }
 
+/// Format seconds as spelled-out uptime, e.g. `2 days, 1 hour, 5 minutes`
+pub fn format_uptime_words(seconds: u64) -> String {
+    let mins = seconds / 60;
+    let parts = [
+        (mins / (60 * 24), "day"),
+        (mins / 60 % 24, "hour"),
+        (mins % 60, "minute"),
+    ];
+
+    let words: Vec<String> = parts
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{n} {unit}{}", if *n == 1 { "" } else { "s" }))
+        .collect();
+
+    if words.is_empty() {
+        "0 minutes".to_string()
+    } else {
+        words.join(", ")
+    }
+}
+
+/// Integer percentage of `part` in `total`, 0 when total is 0
+pub fn percent(part: u64, total: u64) -> u64 {
+    (part * 100).checked_div(total).unwrap_or(0)
+}
+
+/// Days since the Unix epoch for a proleptic Gregorian date
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// `YYYY-MM-DD` for a Unix timestamp, in UTC
+pub fn format_date(secs: i64) -> String {
+    let days = secs.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Local date and time in the user's locale, as `%c` formats it
+pub fn format_local_time(secs: i64) -> Option<String> {
+    let time: libc::time_t = secs;
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let mut buf = [0u8; 128];
+
+    let len = unsafe {
+        // Only the time category, so number formatting elsewhere is unaffected
+        libc::setlocale(libc::LC_TIME, c"".as_ptr());
+        if libc::localtime_r(&raw const time, &raw mut tm).is_null() {
+            return None;
+        }
+        libc::strftime(
+            buf.as_mut_ptr().cast(),
+            buf.len(),
+            c"%c".as_ptr(),
+            &raw const tm,
+        )
+    };
+
+    (len > 0).then(|| String::from_utf8_lossy(&buf[..len]).into_owned())
+}
+
 // System info utilities
 
 /// Fast sysinfo call
@@ -0,0 +1,185 @@
+//! Virtualization and container detection
+//! A reimplementation of the checks `systemd-detect-virt` performs:
+//! container markers first, then the CPUID hypervisor leaf, then DMI strings
+
+use crate::source;
+use std::path::Path;
+
+/// Detect the container runtime or hypervisor we're running under
+///
+/// Returns e.g. `docker (container)`, `KVM (VM)` or `none` on bare metal.
+pub fn detect() -> String {
+    if let Some(container) = detect_container() {
+        return format!("{container} (container)");
+    }
+
+    if let Some(vm) = detect_hypervisor() {
+        return format!("{vm} (VM)");
+    }
+
+    "none".to_string()
+}
+
+/// Sandbox or container we're confined to, where parts of /sys and /proc are
+/// masked and host commands may be out of reach
+pub fn sandbox() -> Option<String> {
+    let source = source::current();
+    if source.exists(Path::new("/.flatpak-info")) {
+        return Some("flatpak".to_string());
+    }
+    if source.env("SNAP").is_some() {
+        return Some("snap".to_string());
+    }
+    // bubblewrap with its own PID namespace runs as PID 1
+    if source
+        .read_to_string(Path::new("/proc/1/comm"))
+        .is_ok_and(|comm| comm.trim() == "bwrap")
+    {
+        return Some("bubblewrap".to_string());
+    }
+    detect_container()
+}
+
+/// Name of the hypervisor if running in a virtual machine
+pub fn detect_hypervisor() -> Option<String> {
+    detect_cpuid_hypervisor().or_else(detect_dmi_hypervisor)
+}
+
+fn detect_container() -> Option<String> {
+    let source = source::current();
+
+    // Written by systemd-nspawn and most systemd-aware runtimes
+    if let Ok(name) = source.read_to_string(Path::new("/run/systemd/container"))
+        && !name.trim().is_empty()
+    {
+        return Some(name.trim().to_string());
+    }
+
+    // Usually only readable by root, but authoritative when it is
+    if let Ok(environ) = source.read(Path::new("/proc/1/environ")) {
+        for var in environ.split(|&b| b == 0) {
+            if let Some(value) = var.strip_prefix(b"container=")
+                && !value.is_empty()
+            {
+                return Some(String::from_utf8_lossy(value).into_owned());
+            }
+        }
+    }
+
+    if source.exists(Path::new("/.dockerenv")) {
+        return Some("docker".to_string());
+    }
+
+    if source.exists(Path::new("/run/.containerenv")) {
+        return Some("podman".to_string());
+    }
+
+    if let Ok(release) = source.read_to_string(Path::new("/proc/sys/kernel/osrelease"))
+        && (release.contains("Microsoft") || release.contains("microsoft"))
+    {
+        return Some("wsl".to_string());
+    }
+
+    None
+}
+
+/// Hypervisor vendor signatures reported in CPUID leaf 0x40000000
+#[cfg(target_arch = "x86_64")]
+static CPUID_VENDORS: &[(&[u8; 12], &str)] = &[
+    (b"KVMKVMKVM\0\0\0", "KVM"),
+    (b"Linux KVM Hv", "KVM"),
+    (b"TCGTCGTCGTCG", "QEMU"),
+    (b"VMwareVMware", "VMware"),
+    (b"XenVMMXenVMM", "Xen"),
+    (b"Microsoft Hv", "Hyper-V"),
+    (b"VBoxVBoxVBox", "VirtualBox"),
+    (b"bhyve bhyve ", "bhyve"),
+    (b"ACRNACRNACRN", "ACRN"),
+    (b" lrpepyh  vr", "Parallels"),
+    (b"QNXQVMBSQG\0\0", "QNX"),
+    (b"SRESRESRESRE", "SRE"),
+    (b"Apple VZ\0\0\0\0", "Apple"),
+];
+
+#[cfg(target_arch = "x86_64")]
+#[allow(unused_unsafe)]
+fn detect_cpuid_hypervisor() -> Option<String> {
+    use std::arch::x86_64::__cpuid;
+
+    // Bit 31 of ECX in leaf 1 is reserved for hypervisors to announce themselves
+    let leaf1 = unsafe { __cpuid(1) };
+    if leaf1.ecx & (1 << 31) == 0 {
+        return None;
+    }
+
+    let leaf = unsafe { __cpuid(0x4000_0000) };
+    let mut signature = [0u8; 12];
+    signature[0..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+    signature[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+    signature[8..12].copy_from_slice(&leaf.edx.to_le_bytes());
+
+    Some(
+        CPUID_VENDORS
+            .iter()
+            .find(|(sig, _)| **sig == signature)
+            .map_or("Unknown hypervisor", |(_, name)| name)
+            .to_string(),
+    )
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn detect_cpuid_hypervisor() -> Option<String> {
+    None
+}
+
+/// Substrings of DMI vendor/product fields that identify a hypervisor
+static DMI_VENDORS: &[(&str, &str)] = &[
+    ("KVM", "KVM"),
+    ("OpenStack", "KVM"),
+    ("KubeVirt", "KVM"),
+    ("Amazon EC2", "Amazon"),
+    ("QEMU", "QEMU"),
+    ("VMware", "VMware"),
+    ("VMW", "VMware"),
+    ("innotek GmbH", "VirtualBox"),
+    ("VirtualBox", "VirtualBox"),
+    ("Oracle Corporation", "VirtualBox"),
+    ("Xen", "Xen"),
+    ("Bochs", "Bochs"),
+    ("Parallels", "Parallels"),
+    ("BHYVE", "bhyve"),
+    ("Hyper-V", "Hyper-V"),
+    ("Apple Virtualization", "Apple"),
+    ("Google Compute Engine", "Google"),
+];
+
+fn detect_dmi_hypervisor() -> Option<String> {
+    for field in [
+        "product_name",
+        "sys_vendor",
+        "board_vendor",
+        "bios_vendor",
+        "product_version",
+    ] {
+        let Ok(value) =
+            source::current().read_to_string(&Path::new("/sys/class/dmi/id").join(field))
+        else {
+            continue;
+        };
+
+        if let Some((_, name)) = DMI_VENDORS.iter().find(|(pat, _)| value.contains(pat)) {
+            return Some((*name).to_string());
+        }
+
+        // Hyper-V guests report a generic Microsoft "Virtual Machine"
+        if value.contains("Microsoft Corporation")
+            && source::current()
+                .read_to_string(Path::new("/sys/class/dmi/id/product_name"))
+                .is_ok_and(|p| p.contains("Virtual Machine"))
+        {
+            return Some("Hyper-V".to_string());
+        }
+    }
+
+    None
+}
@@ -0,0 +1,185 @@
+//! Watch mode
+//! Redraws the output in place at a fixed interval, refreshing the values that
+//! change at runtime and accenting the ones that changed since the last draw;
+//! terminals get the alternate screen, restored on Ctrl-C
+
+use crate::config::Config;
+use crate::modules::{self, Line, Module, Needs};
+use crate::os::SysInfo;
+use crate::output::Output;
+use crate::utils::format_memory;
+use std::borrow::Cow;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Color for values that changed since the previous refresh
+const ACCENT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+/// Move the cursor home and clear the screen
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// Switch to the alternate screen with the cursor hidden, and back
+const ENTER_ALTERNATE: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_ALTERNATE: &str = "\x1b[?25h\x1b[?1049l";
+
+/// How often a sleep checks whether it was asked to stop
+const STOP_POLL: Duration = Duration::from_millis(50);
+
+/// Set by SIGINT/SIGTERM so the terminal can be restored before exiting
+static STOP: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn request_stop(_: libc::c_int) {
+    STOP.store(true, Ordering::Relaxed);
+}
+
+/// Redraw until writing fails (e.g. the reader went away)
+pub fn run(
+    out: &mut Output,
+    config: &Config,
+    info: SysInfo,
+    links: bool,
+    accent: bool,
+    interval: Duration,
+    draw: impl Fn(&mut dyn Write, &[Line]) -> io::Result<()>,
+) -> io::Result<()> {
+    let alternate = out.is_terminal();
+    if alternate {
+        let handler = request_stop as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        unsafe {
+            libc::signal(libc::SIGINT, handler);
+            libc::signal(libc::SIGTERM, handler);
+        }
+        write!(out, "{ENTER_ALTERNATE}")?;
+    }
+
+    let result = redraw_loop(out, config, info, links, accent, interval, draw);
+
+    if alternate {
+        write!(out, "{LEAVE_ALTERNATE}")?;
+        out.flush()?;
+    }
+    result
+}
+
+fn redraw_loop(
+    out: &mut Output,
+    config: &Config,
+    mut info: SysInfo,
+    links: bool,
+    accent: bool,
+    interval: Duration,
+    draw: impl Fn(&mut dyn Write, &[Line]) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut previous: Option<SysInfo> = None;
+
+    loop {
+        let changes = previous.as_ref().map(|prev| diff(prev, &info));
+
+        let lines = modules::build_lines_with(config, &info, links, accent, |module, line| {
+            if let Some(change) = changes.iter().flatten().find(|c| c.module == module) {
+                annotate(line, change, accent);
+            }
+        });
+
+        write!(out, "{CLEAR}")?;
+        draw(out, &lines)?;
+        out.flush()?;
+
+        if !sleep(interval) {
+            return Ok(());
+        }
+
+        previous = Some(info.clone());
+        info.refresh(config, Needs::of(&config.modules));
+        info.sanitize();
+    }
+}
+
+/// Sleep for `duration`, returning `false` early when asked to stop
+fn sleep(duration: Duration) -> bool {
+    let start = Instant::now();
+    while !STOP.load(Ordering::Relaxed) {
+        let left = duration.saturating_sub(start.elapsed());
+        if left.is_zero() {
+            return true;
+        }
+        std::thread::sleep(left.min(STOP_POLL));
+    }
+    false
+}
+
+/// A module whose value changed between two refreshes
+struct Change {
+    module: Module,
+    /// Short explanation appended to the value
+    note: Option<String>,
+}
+
+/// Compare two snapshots of the runtime values
+fn diff(prev: &SysInfo, info: &SysInfo) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    // Uptime ticks every minute, so it is deliberately not compared
+    if info.cpu_info != prev.cpu_info {
+        changes.push(Change {
+            module: Module::Cpu,
+            note: None,
+        });
+    }
+
+    // Memory use wobbles constantly; only moves of 1% of RAM or more count
+    let delta = info.memory_used.abs_diff(prev.memory_used);
+    if delta > 0 && delta >= info.memory_total / 100 {
+        let sign = if info.memory_used > prev.memory_used {
+            '+'
+        } else {
+            '-'
+        };
+        changes.push(Change {
+            module: Module::Memory,
+            note: Some(format!("{sign}{}", format_memory(delta))),
+        });
+    }
+
+    if info.media != prev.media {
+        changes.push(Change {
+            module: Module::Media,
+            note: None,
+        });
+    }
+
+    if info.battery != prev.battery {
+        let was = prev.battery.as_deref().is_some_and(charging);
+        let is = info.battery.as_deref().is_some_and(charging);
+        let note = match (was, is) {
+            (false, true) => Some("started charging".to_string()),
+            (true, false) => Some("stopped charging".to_string()),
+            _ => None,
+        };
+        changes.push(Change {
+            module: Module::Battery,
+            note,
+        });
+    }
+
+    changes
+}
+
+fn charging(battery: &str) -> bool {
+    battery.contains("(Charging)")
+}
+
+fn annotate(line: &mut Line, change: &Change, accent: bool) {
+    let Line::Entry { value, .. } = line else {
+        return;
+    };
+
+    if let Some(note) = &change.note {
+        value.to_mut().push_str(&format!(" ({note})"));
+    }
+    if accent {
+        *value = Cow::Owned(format!("{ACCENT}{value}{RESET}"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Weather line
+//! Fetched from a wttr.in-style endpoint by a detached `curl`, so the network
+//! never holds up rendering; runs in between show the cached result
+
+use crate::config::WeatherConfig;
+use crate::utils::state_dir;
+use rustc_hash::FxHasher;
+use std::borrow::Cow;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long the very first run waits for a fetch before leaving the line out
+const FIRST_FETCH_WAIT: Duration = Duration::from_millis(300);
+
+/// Give up on a fetch after this many seconds
+const FETCH_TIMEOUT_SECS: &str = "10";
+
+/// Fetch, then replace the cache in one rename; a failed fetch still touches
+/// the cache so the next attempt waits for the TTL too
+const FETCH_SCRIPT: &str = r#"curl -fsS --max-time "$3" "$1" > "$2.part" && mv "$2.part" "$2" || { rm -f "$2.part"; touch "$2"; }"#;
+
+/// The cached weather, starting a background refresh when it is stale; in
+/// privacy mode the location is left out
+pub fn weather(config: &WeatherConfig, privacy: bool) -> Option<String> {
+    let url = if privacy {
+        Cow::Owned(without_location(&config.url))
+    } else {
+        Cow::Borrowed(config.url.as_str())
+    };
+    let dir = state_dir()?;
+    let cache = dir.join(format!("weather-{:016x}", url_hash(&url)));
+
+    let age = fs::metadata(&cache)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+    let cached = read_cache(&cache);
+
+    if age.is_none_or(|age| age >= config.ttl) {
+        let _ = fs::create_dir_all(&dir);
+        if let Some(mut child) = start_fetch(&cache, &url) {
+            // Nothing to show yet: a fast endpoint can still make this run
+            if cached.is_none() {
+                let start = Instant::now();
+                while start.elapsed() < FIRST_FETCH_WAIT {
+                    if child.try_wait().ok().flatten().is_some() {
+                        return read_cache(&cache);
+                    }
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+            // Reaped here while we keep running, as in watch mode, or by init
+            // once we exit
+            thread::spawn(move || child.wait());
+        }
+    }
+
+    cached
+}
+
+/// wttr.in's format without `%l`, the location, and the separator after it
+fn without_location(url: &str) -> String {
+    url.replace("%l:", "").replace("%l", "")
+}
+
+/// Each endpoint gets its own cache, so changing the URL doesn't show the old
+/// location's weather
+fn url_hash(url: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    url.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn read_cache(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    Some(line.to_string())
+}
+
+/// Spawn the fetch unless one is already running; it outlives this process
+fn start_fetch(cache: &Path, url: &str) -> Option<Child> {
+    let mut part = cache.as_os_str().to_owned();
+    part.push(".part");
+    let in_flight = fs::metadata(&part)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < Duration::from_secs(60));
+    if in_flight {
+        return None;
+    }
+
+    Command::new("sh")
+        .args(["-c", FETCH_SCRIPT, "sh", url])
+        .arg(cache)
+        .arg(FETCH_TIMEOUT_SECS)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()
+}
@@ -0,0 +1,426 @@
+//! Wireless link details
+//! Network name, band and signal of a connected wireless interface, from
+//! nl80211 over generic netlink, falling back to `/proc/net/wireless` + `iw`
+
+use crate::source;
+use crate::utils::run_command;
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct Link {
+    pub ssid: String,
+    /// Channel frequency in MHz
+    pub freq: Option<u32>,
+    /// Signal strength in dBm
+    pub signal: Option<i32>,
+}
+
+impl Link {
+    /// e.g. `MySSID (5 GHz, -52 dBm)`; the SSID is left out in privacy mode
+    pub fn describe(&self, privacy: bool) -> String {
+        let details: Vec<String> = [
+            self.freq.and_then(band).map(str::to_string),
+            self.signal.map(|dbm| format!("{dbm} dBm")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        match (privacy || self.ssid.is_empty(), details.is_empty()) {
+            (true, true) => "Connected".to_string(),
+            (true, false) => details.join(", "),
+            (false, true) => self.ssid.clone(),
+            (false, false) => format!("{} ({})", self.ssid, details.join(", ")),
+        }
+    }
+}
+
+fn band(freq: u32) -> Option<&'static str> {
+    match freq {
+        2400..=2500 => Some("2.4 GHz"),
+        4900..=5899 => Some("5 GHz"),
+        5925..=7125 => Some("6 GHz"),
+        57000..=71000 => Some("60 GHz"),
+        _ => None,
+    }
+}
+
+/// The first wireless interface that is associated with a network
+pub fn link() -> Option<Link> {
+    // Netlink only reaches the machine we're running on
+    let live = source::current().is_live();
+    live.then(nl80211::link)
+        .flatten()
+        .or_else(proc_wireless_link)
+}
+
+/// Interfaces listed in `/proc/net/wireless` that are up, with `iw` for the
+/// details the kernel file doesn't have
+fn proc_wireless_link() -> Option<Link> {
+    let wireless = source::current()
+        .read_to_string(Path::new("/proc/net/wireless"))
+        .ok()?;
+
+    // Two header lines, then `wlan0: 0000   54.  -56.  -256 ...`
+    wireless.lines().skip(2).find_map(|line| {
+        let (iface, stats) = line.split_once(':')?;
+        let iface = iface.trim();
+        let state = source::current()
+            .read_to_string(Path::new(&format!("/sys/class/net/{iface}/operstate")))
+            .ok()?;
+        if state.trim() != "up" {
+            return None;
+        }
+
+        let level = stats
+            .split_whitespace()
+            .nth(2)
+            .and_then(|level| level.trim_end_matches('.').parse().ok());
+
+        let mut link = iw_link(iface).unwrap_or(Link {
+            ssid: String::new(),
+            freq: None,
+            signal: None,
+        });
+        link.signal = link.signal.or(level);
+        Some(link)
+    })
+}
+
+/// Parse `iw dev <iface> link`
+fn iw_link(iface: &str) -> Option<Link> {
+    let output = run_command("iw", &["dev", iface, "link"])?;
+    if output.starts_with("Not connected") {
+        return None;
+    }
+
+    let mut link = Link {
+        ssid: String::new(),
+        freq: None,
+        signal: None,
+    };
+    for line in output.lines() {
+        let Some((key, value)) = line.trim().split_once(": ") else {
+            continue;
+        };
+        match key {
+            "SSID" => link.ssid = value.to_string(),
+            // Newer versions print `5180.0`
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            "freq" => link.freq = value.parse::<f64>().ok().map(|mhz| mhz as u32),
+            "signal" => {
+                link.signal = value
+                    .split_whitespace()
+                    .next()
+                    .and_then(|dbm| dbm.parse().ok());
+            }
+            _ => {}
+        }
+    }
+    Some(link)
+}
+
+mod nl80211 {
+    use super::Link;
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    const CMD_GET_INTERFACE: u8 = 5;
+    const CMD_GET_STATION: u8 = 17;
+    const ATTR_IFINDEX: u16 = 3;
+    const ATTR_WIPHY_FREQ: u16 = 38;
+    const ATTR_STA_INFO: u16 = 21;
+    const ATTR_SSID: u16 = 52;
+    const STA_INFO_SIGNAL: u16 = 7;
+
+    const CTRL_ATTR_FAMILY_ID: u16 = 1;
+    const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+    const NLMSG_HDRLEN: usize = 16;
+    const GENL_HDRLEN: usize = 4;
+
+    pub fn link() -> Option<Link> {
+        let mut socket = Socket::open().ok()?;
+        let family = family_id(&mut socket)?;
+
+        let interfaces = socket.request(family, CMD_GET_INTERFACE, true, &[]).ok()?;
+        interfaces.iter().find_map(|payload| {
+            let mut ifindex = None;
+            let mut ssid = None;
+            let mut freq = None;
+            for (kind, data) in attributes(payload) {
+                match kind {
+                    ATTR_IFINDEX => ifindex = read_u32(data),
+                    ATTR_SSID => ssid = Some(String::from_utf8_lossy(data).into_owned()),
+                    ATTR_WIPHY_FREQ => freq = read_u32(data),
+                    _ => {}
+                }
+            }
+
+            // Only associated interfaces report an SSID
+            let ssid = ssid?;
+            let signal = ifindex.and_then(|index| station_signal(&mut socket, family, index));
+            Some(Link { ssid, freq, signal })
+        })
+    }
+
+    /// Signal of the access point we're associated with
+    fn station_signal(socket: &mut Socket, family: u16, ifindex: u32) -> Option<i32> {
+        let mut attrs = Vec::new();
+        push_attribute(&mut attrs, ATTR_IFINDEX, &ifindex.to_ne_bytes());
+
+        let stations = socket.request(family, CMD_GET_STATION, true, &attrs).ok()?;
+        stations.iter().find_map(|payload| {
+            let (_, info) = attributes(payload).find(|(kind, _)| *kind == ATTR_STA_INFO)?;
+            let (_, signal) = attributes(info).find(|(kind, _)| *kind == STA_INFO_SIGNAL)?;
+            // A u8 holding a signed dBm value
+            signal.first().map(|&dbm| i32::from(dbm.cast_signed()))
+        })
+    }
+
+    /// Resolve the dynamic id of the nl80211 family
+    fn family_id(socket: &mut Socket) -> Option<u16> {
+        let mut attrs = Vec::new();
+        push_attribute(&mut attrs, CTRL_ATTR_FAMILY_NAME, b"nl80211\0");
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let replies = socket
+            .request(
+                libc::GENL_ID_CTRL as u16,
+                libc::CTRL_CMD_GETFAMILY as u8,
+                false,
+                &attrs,
+            )
+            .ok()?;
+        replies.iter().find_map(|payload| {
+            let (_, id) = attributes(payload).find(|(kind, _)| *kind == CTRL_ATTR_FAMILY_ID)?;
+            Some(u16::from_ne_bytes(id.get(..2)?.try_into().ok()?))
+        })
+    }
+
+    struct Socket {
+        fd: OwnedFd,
+        seq: u32,
+    }
+
+    impl Socket {
+        fn open() -> io::Result<Self> {
+            let fd = unsafe {
+                libc::socket(
+                    libc::AF_NETLINK,
+                    libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                    libc::NETLINK_GENERIC,
+                )
+            };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+            // A wedged driver shouldn't hold up the whole fetch
+            let timeout = libc::timeval {
+                tv_sec: 0,
+                tv_usec: 250_000,
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            let rc = unsafe {
+                libc::setsockopt(
+                    fd.as_raw_fd(),
+                    libc::SOL_SOCKET,
+                    libc::SO_RCVTIMEO,
+                    (&raw const timeout).cast(),
+                    size_of::<libc::timeval>() as libc::socklen_t,
+                )
+            };
+            if rc < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(Self { fd, seq: 0 })
+        }
+
+        /// Send a generic netlink request and return the payload of every
+        /// reply, after the generic netlink header
+        fn request(
+            &mut self,
+            family: u16,
+            cmd: u8,
+            dump: bool,
+            attrs: &[u8],
+        ) -> io::Result<Vec<Vec<u8>>> {
+            self.seq += 1;
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let flags = if dump {
+                (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16
+            } else {
+                libc::NLM_F_REQUEST as u16
+            };
+
+            let len = NLMSG_HDRLEN + GENL_HDRLEN + attrs.len();
+            let mut msg = Vec::with_capacity(len);
+            #[allow(clippy::cast_possible_truncation)]
+            msg.extend_from_slice(&(len as u32).to_ne_bytes());
+            msg.extend_from_slice(&family.to_ne_bytes());
+            msg.extend_from_slice(&flags.to_ne_bytes());
+            msg.extend_from_slice(&self.seq.to_ne_bytes());
+            msg.extend_from_slice(&0u32.to_ne_bytes());
+            // genlmsghdr: command, version, reserved
+            msg.extend_from_slice(&[cmd, 1, 0, 0]);
+            msg.extend_from_slice(attrs);
+
+            let sent =
+                unsafe { libc::send(self.fd.as_raw_fd(), msg.as_ptr().cast(), msg.len(), 0) };
+            if sent < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let mut replies = Vec::new();
+            let mut buf = vec![0u8; 32 * 1024];
+            loop {
+                let received = unsafe {
+                    libc::recv(self.fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len(), 0)
+                };
+                let Ok(received) = usize::try_from(received) else {
+                    return Err(io::Error::last_os_error());
+                };
+
+                let mut rest = &buf[..received];
+                while rest.len() >= NLMSG_HDRLEN {
+                    let msg_len = read_u32(rest).unwrap_or(0) as usize;
+                    if msg_len < NLMSG_HDRLEN || msg_len > rest.len() {
+                        break;
+                    }
+                    let kind = i32::from(u16::from_ne_bytes([rest[4], rest[5]]));
+                    let seq = read_u32(&rest[8..]).unwrap_or(0);
+                    let body = &rest[NLMSG_HDRLEN..msg_len];
+                    rest = &rest[align(msg_len).min(rest.len())..];
+
+                    if seq != self.seq {
+                        continue;
+                    }
+                    match kind {
+                        libc::NLMSG_DONE => return Ok(replies),
+                        libc::NLMSG_ERROR => {
+                            let code = body
+                                .get(..4)
+                                .map_or(0, |b| i32::from_ne_bytes([b[0], b[1], b[2], b[3]]));
+                            if code != 0 {
+                                return Err(io::Error::from_raw_os_error(-code));
+                            }
+                            return Ok(replies);
+                        }
+                        _ => {
+                            if let Some(payload) = body.get(GENL_HDRLEN..) {
+                                replies.push(payload.to_vec());
+                            }
+                            if !dump {
+                                return Ok(replies);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    const fn align(len: usize) -> usize {
+        (len + 3) & !3
+    }
+
+    fn read_u32(data: &[u8]) -> Option<u32> {
+        Some(u32::from_ne_bytes(data.get(..4)?.try_into().ok()?))
+    }
+
+    fn push_attribute(buf: &mut Vec<u8>, kind: u16, payload: &[u8]) {
+        let len = 4 + payload.len();
+        #[allow(clippy::cast_possible_truncation)]
+        buf.extend_from_slice(&(len as u16).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(payload);
+        buf.resize(buf.len() + align(len) - len, 0);
+    }
+
+    /// Iterate over `(type, payload)` of the netlink attributes in `data`
+    fn attributes(mut data: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+        std::iter::from_fn(move || {
+            if data.len() < 4 {
+                return None;
+            }
+            let len = usize::from(u16::from_ne_bytes([data[0], data[1]]));
+            // The top bits flag nested and network byte order attributes
+            let kind = u16::from_ne_bytes([data[2], data[3]]) & 0x3fff;
+            if len < 4 || len > data.len() {
+                return None;
+            }
+            let payload = &data[4..len];
+            data = &data[align(len).min(data.len())..];
+            Some((kind, payload))
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{ATTR_IFINDEX, ATTR_SSID, ATTR_STA_INFO, STA_INFO_SIGNAL};
+        use super::{attributes, push_attribute, read_u32};
+
+        const NLA_F_NESTED: u16 = 0x8000;
+
+        #[test]
+        fn attributes_round_trip_with_padding() {
+            let mut buf = Vec::new();
+            push_attribute(&mut buf, ATTR_IFINDEX, &3u32.to_ne_bytes());
+            push_attribute(&mut buf, ATTR_SSID, b"homenet");
+            push_attribute(&mut buf, 99, &[]);
+            assert_eq!(buf.len(), 8 + 12 + 4);
+
+            let attrs: Vec<(u16, &[u8])> = attributes(&buf).collect();
+            assert_eq!(attrs.len(), 3);
+            assert_eq!((attrs[0].0, read_u32(attrs[0].1)), (ATTR_IFINDEX, Some(3)));
+            assert_eq!(attrs[1], (ATTR_SSID, &b"homenet"[..]));
+            assert_eq!(attrs[2], (99, &[][..]));
+        }
+
+        #[test]
+        fn nested_station_info() {
+            let mut info = Vec::new();
+            push_attribute(&mut info, 1, &[0; 4]);
+            push_attribute(&mut info, STA_INFO_SIGNAL, &[(-52i8).cast_unsigned()]);
+            let mut buf = Vec::new();
+            push_attribute(&mut buf, ATTR_STA_INFO | NLA_F_NESTED, &info);
+
+            let (kind, nested) = attributes(&buf).next().unwrap();
+            assert_eq!(kind, ATTR_STA_INFO);
+            let signal = attributes(nested).find(|(kind, _)| *kind == STA_INFO_SIGNAL);
+            assert_eq!(signal.map(|(_, data)| data[0].cast_signed()), Some(-52));
+        }
+
+        #[test]
+        fn last_attribute_without_padding() {
+            let mut buf = Vec::new();
+            push_attribute(&mut buf, ATTR_SSID, b"abc");
+            buf.truncate(7);
+            assert_eq!(
+                attributes(&buf).collect::<Vec<_>>(),
+                [(ATTR_SSID, &b"abc"[..])]
+            );
+        }
+
+        #[test]
+        fn malformed_lengths_end_the_list() {
+            let mut buf = Vec::new();
+            push_attribute(&mut buf, ATTR_IFINDEX, &7u32.to_ne_bytes());
+            push_attribute(&mut buf, ATTR_SSID, b"cut off");
+
+            // The second one claims more than is left
+            assert_eq!(attributes(&buf[..14]).count(), 1);
+            // A length shorter than the attribute header
+            let mut short = buf.clone();
+            short[8..10].copy_from_slice(&2u16.to_ne_bytes());
+            assert_eq!(attributes(&short).count(), 1);
+            // Less than a header
+            assert_eq!(attributes(&buf[..3]).count(), 0);
+            assert_eq!(read_u32(&[1, 2, 3]), None);
+        }
+    }
+}
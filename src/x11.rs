@@ -0,0 +1,207 @@
+//! Minimal X11 client
+//! Connects to the server in `DISPLAY` and makes the few requests needed to
+//! read window properties, without linking libxcb
+
+use crate::utils::{expand_path, get_env_var};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+/// How long to wait for the server before giving up on it
+const TIMEOUT: Duration = Duration::from_millis(250);
+
+const INTERN_ATOM: u8 = 16;
+const GET_PROPERTY: u8 = 20;
+const GET_SELECTION_OWNER: u8 = 22;
+
+const AUTH_NAME: &str = "MIT-MAGIC-COOKIE-1";
+/// Xauthority families matching any local connection
+const FAMILY_LOCAL: u16 = 256;
+const FAMILY_WILD: u16 = 65535;
+
+trait Stream: Read + Write {}
+impl<T: Read + Write> Stream for T {}
+
+pub struct Connection {
+    stream: Box<dyn Stream>,
+    /// Screen named in `DISPLAY`
+    pub screen: u32,
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+impl Connection {
+    /// The display in `DISPLAY`: `:0`, `unix:0.1` or `host:0`
+    pub fn open() -> io::Result<Self> {
+        let display = get_env_var("DISPLAY", "");
+        let (host, rest) = display
+            .rsplit_once(':')
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "DISPLAY isn't set"))?;
+        let (number, screen) = rest.split_once('.').unwrap_or((rest, "0"));
+        let screen = screen.parse().map_err(|_| invalid("bad DISPLAY"))?;
+        let port: u16 = number.parse().map_err(|_| invalid("bad DISPLAY"))?;
+
+        let stream: Box<dyn Stream> = if host.is_empty() || host == "unix" {
+            let stream = UnixStream::connect(format!("/tmp/.X11-unix/X{number}"))?;
+            stream.set_read_timeout(Some(TIMEOUT))?;
+            stream.set_write_timeout(Some(TIMEOUT))?;
+            Box::new(stream)
+        } else {
+            let stream = TcpStream::connect((host, 6000 + port))?;
+            stream.set_read_timeout(Some(TIMEOUT))?;
+            stream.set_write_timeout(Some(TIMEOUT))?;
+            Box::new(stream)
+        };
+
+        let mut conn = Self { stream, screen };
+        conn.setup(&cookie(number).unwrap_or_default())?;
+        Ok(conn)
+    }
+
+    /// Connection setup, little-endian, with the cookie if there is one
+    fn setup(&mut self, cookie: &[u8]) -> io::Result<()> {
+        let name = if cookie.is_empty() { "" } else { AUTH_NAME };
+        let mut msg = vec![b'l', 0];
+        msg.extend_from_slice(&11u16.to_le_bytes());
+        msg.extend_from_slice(&0u16.to_le_bytes());
+        msg.extend_from_slice(&len_u16(name.len())?.to_le_bytes());
+        msg.extend_from_slice(&len_u16(cookie.len())?.to_le_bytes());
+        msg.extend_from_slice(&[0, 0]);
+        padded(&mut msg, name.as_bytes());
+        padded(&mut msg, cookie);
+        self.stream.write_all(&msg)?;
+
+        // status, reason length, version, then the rest in 4-byte units
+        let mut head = [0u8; 8];
+        self.stream.read_exact(&mut head)?;
+        let mut rest = vec![0u8; usize::from(u16::from_le_bytes([head[6], head[7]])) * 4];
+        self.stream.read_exact(&mut rest)?;
+        if head[0] != 1 {
+            let reason = rest.get(..usize::from(head[1])).unwrap_or_default();
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("X server refused: {}", String::from_utf8_lossy(reason)),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Send a request and read its reply: the 32 bytes every reply has, then
+    /// its variable part
+    fn request(&mut self, opcode: u8, data: u8, body: &[u8]) -> io::Result<Vec<u8>> {
+        let mut msg = vec![opcode, data];
+        msg.extend_from_slice(&len_u16(1 + body.len() / 4)?.to_le_bytes());
+        msg.extend_from_slice(body);
+        self.stream.write_all(&msg)?;
+
+        loop {
+            let mut reply = vec![0u8; 32];
+            self.stream.read_exact(&mut reply)?;
+            match reply[0] {
+                0 => return Err(io::Error::other(format!("X error {}", reply[1]))),
+                1 => {
+                    let extra = u32_at(&reply, 4) as usize * 4;
+                    reply.resize(32 + extra, 0);
+                    self.stream.read_exact(&mut reply[32..])?;
+                    return Ok(reply);
+                }
+                // Events we didn't ask for
+                _ => {}
+            }
+        }
+    }
+
+    /// An atom by name, `None` if no client has created it
+    pub fn atom(&mut self, name: &str) -> io::Result<Option<u32>> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&len_u16(name.len())?.to_le_bytes());
+        body.extend_from_slice(&[0, 0]);
+        padded(&mut body, name.as_bytes());
+        // only-if-exists
+        let reply = self.request(INTERN_ATOM, 1, &body)?;
+        Ok(Some(u32_at(&reply, 8)).filter(|&atom| atom != 0))
+    }
+
+    /// The window owning a selection
+    pub fn selection_owner(&mut self, selection: u32) -> io::Result<Option<u32>> {
+        let reply = self.request(GET_SELECTION_OWNER, 0, &selection.to_le_bytes())?;
+        Ok(Some(u32_at(&reply, 8)).filter(|&window| window != 0))
+    }
+
+    /// The raw value of a window property, of any type
+    pub fn property(&mut self, window: u32, property: u32) -> io::Result<Vec<u8>> {
+        let mut body = Vec::new();
+        for value in [window, property, 0, 0, u32::MAX / 4] {
+            body.extend_from_slice(&value.to_le_bytes());
+        }
+        let reply = self.request(GET_PROPERTY, 0, &body)?;
+
+        // Length in units of the format: 8, 16 or 32 bits
+        let len = u32_at(&reply, 16) as usize * usize::from(reply[1] / 8);
+        reply
+            .get(32..32 + len)
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| invalid("short GetProperty reply"))
+    }
+}
+
+/// The MIT-MAGIC-COOKIE-1 for a local display in the Xauthority file
+///
+/// Read straight from disk rather than through the data source, so a
+/// `--dump-probe-data` capture never holds it.
+fn cookie(display: &str) -> Option<Vec<u8>> {
+    let path = match get_env_var("XAUTHORITY", "") {
+        "" => expand_path("~/.Xauthority"),
+        path => path.into(),
+    };
+    let data = fs::read(path).ok()?;
+
+    // Entries are a big-endian family, then address, display number, auth
+    // name and auth data, each a big-endian length and bytes
+    let mut pos = 0;
+    while let Some(family) = be_u16(&data, &mut pos) {
+        let _address = counted(&data, &mut pos)?;
+        let number = counted(&data, &mut pos)?;
+        let name = counted(&data, &mut pos)?;
+        let cookie = counted(&data, &mut pos)?;
+        if matches!(family, FAMILY_LOCAL | FAMILY_WILD)
+            && (number.is_empty() || number == display.as_bytes())
+            && name == AUTH_NAME.as_bytes()
+        {
+            return Some(cookie.to_vec());
+        }
+    }
+    None
+}
+
+fn be_u16(data: &[u8], pos: &mut usize) -> Option<u16> {
+    let bytes = data.get(*pos..*pos + 2)?;
+    *pos += 2;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// A length-prefixed Xauthority field
+fn counted<'a>(data: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+    let len = usize::from(be_u16(data, pos)?);
+    let value = data.get(*pos..*pos + len)?;
+    *pos += len;
+    Some(value)
+}
+
+fn len_u16(len: usize) -> io::Result<u16> {
+    u16::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "request too long"))
+}
+
+/// Append `data` padded to 4 bytes
+fn padded(msg: &mut Vec<u8>, data: &[u8]) {
+    msg.extend_from_slice(data);
+    msg.resize(msg.len().next_multiple_of(4), 0);
+}
+
+fn u32_at(data: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]])
+}
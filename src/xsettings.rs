@@ -0,0 +1,197 @@
+//! XSETTINGS
+//! The GTK theme, icons, cursor and font the running X settings daemon hands
+//! to apps, whichever desktop started it
+
+use crate::log;
+use crate::utils::get_env_var;
+use crate::x11::Connection;
+use rustc_hash::FxHashMap;
+use std::io;
+use std::sync::OnceLock;
+
+const INT: u8 = 0;
+const STRING: u8 = 1;
+const COLOR: u8 = 2;
+
+/// String settings, read once for every caller
+static SETTINGS: OnceLock<Option<FxHashMap<String, String>>> = OnceLock::new();
+
+/// A string setting such as `Net/ThemeName` or `Gtk/FontName`
+pub fn get(name: &str) -> Option<String> {
+    let settings = SETTINGS.get_or_init(|| {
+        if get_env_var("DISPLAY", "").is_empty() {
+            log::fail!("xsettings: DISPLAY isn't set");
+            return None;
+        }
+        match fetch() {
+            Ok(data) => parse(&data),
+            Err(e) => {
+                log::fail!("xsettings: cannot read from the X server: {e}");
+                None
+            }
+        }
+    });
+    let value = settings.as_ref()?.get(name).filter(|v| !v.is_empty());
+    if value.is_none() {
+        log::fail!("xsettings: {name} isn't set");
+    }
+    value.cloned()
+}
+
+/// The `_XSETTINGS_SETTINGS` property of the window owning the screen's
+/// `_XSETTINGS_S<n>` selection
+fn fetch() -> io::Result<Vec<u8>> {
+    let missing = |what: &str| io::Error::new(io::ErrorKind::NotFound, what.to_string());
+
+    let mut conn = Connection::open()?;
+    let selection = conn
+        .atom(&format!("_XSETTINGS_S{}", conn.screen))?
+        .ok_or_else(|| missing("no settings daemon has run"))?;
+    let owner = conn
+        .selection_owner(selection)?
+        .ok_or_else(|| missing("no settings daemon running"))?;
+    let property = conn
+        .atom("_XSETTINGS_SETTINGS")?
+        .ok_or_else(|| missing("no settings published"))?;
+    conn.property(owner, property)
+}
+
+/// The string settings in a property value: byte order, serial and count,
+/// then each setting's type, name, serial and value, padded to 4 bytes
+fn parse(data: &[u8]) -> Option<FxHashMap<String, String>> {
+    let big_endian = *data.first()? == 1;
+    let u16_at = |pos: usize| {
+        let bytes = [*data.get(pos)?, *data.get(pos + 1)?];
+        Some(if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    };
+    let u32_at = |pos: usize| {
+        let bytes: [u8; 4] = data.get(pos..pos + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    };
+
+    let mut settings = FxHashMap::default();
+    let mut pos = 12;
+    for _ in 0..u32_at(8)? {
+        let kind = *data.get(pos)?;
+        let name_len = usize::from(u16_at(pos + 2)?);
+        let name = data.get(pos + 4..pos + 4 + name_len)?;
+        // Past the name and the setting's own serial
+        pos += 4 + name_len.next_multiple_of(4) + 4;
+        match kind {
+            INT => pos += 4,
+            STRING => {
+                let len = u32_at(pos)? as usize;
+                let value = data.get(pos + 4..pos + 4 + len)?;
+                settings.insert(
+                    String::from_utf8_lossy(name).into_owned(),
+                    String::from_utf8_lossy(value).into_owned(),
+                );
+                pos += 4 + len.next_multiple_of(4);
+            }
+            COLOR => pos += 8,
+            _ => break,
+        }
+    }
+    Some(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COLOR, INT, STRING, parse};
+
+    /// A `_XSETTINGS_SETTINGS` value with `settings` as (type, name, value)
+    fn property(big_endian: bool, settings: &[(u8, &str, &[u8])]) -> Vec<u8> {
+        let u16_bytes = |n: u16| {
+            if big_endian {
+                n.to_be_bytes()
+            } else {
+                n.to_le_bytes()
+            }
+        };
+        let u32_bytes = |n: u32| {
+            if big_endian {
+                n.to_be_bytes()
+            } else {
+                n.to_le_bytes()
+            }
+        };
+        let pad = |data: &mut Vec<u8>| data.resize(data.len().next_multiple_of(4), 0);
+
+        let mut data = vec![u8::from(big_endian), 0, 0, 0];
+        data.extend(u32_bytes(7));
+        data.extend(u32_bytes(u32::try_from(settings.len()).unwrap()));
+        for &(kind, name, value) in settings {
+            data.extend([kind, 0]);
+            data.extend(u16_bytes(u16::try_from(name.len()).unwrap()));
+            data.extend(name.as_bytes());
+            pad(&mut data);
+            data.extend(u32_bytes(1));
+            if kind == STRING {
+                data.extend(u32_bytes(u32::try_from(value.len()).unwrap()));
+            }
+            data.extend(value);
+            pad(&mut data);
+        }
+        data
+    }
+
+    #[test]
+    fn strings_between_other_types() {
+        for big_endian in [false, true] {
+            let data = property(
+                big_endian,
+                &[
+                    (INT, "Xft/DPI", &[0, 0, 1, 0]),
+                    (STRING, "Net/ThemeName", b"Adwaita-dark"),
+                    (COLOR, "Gtk/Color", &[0; 8]),
+                    (STRING, "Gtk/FontName", b"Cantarell 11"),
+                    (STRING, "Net/IconThemeName", b""),
+                ],
+            );
+            let settings = parse(&data).unwrap();
+            assert_eq!(settings.len(), 3);
+            assert_eq!(settings["Net/ThemeName"], "Adwaita-dark");
+            assert_eq!(settings["Gtk/FontName"], "Cantarell 11");
+            assert_eq!(settings["Net/IconThemeName"], "");
+        }
+    }
+
+    #[test]
+    fn unknown_type_ends_the_list() {
+        let data = property(
+            false,
+            &[
+                (STRING, "Net/ThemeName", b"Arc"),
+                (9, "Future/Setting", &[0; 4]),
+                (STRING, "Gtk/FontName", b"Sans 10"),
+            ],
+        );
+        let settings = parse(&data).unwrap();
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings["Net/ThemeName"], "Arc");
+    }
+
+    #[test]
+    fn truncated() {
+        let data = property(false, &[(STRING, "Net/ThemeName", b"Adwaita")]);
+        assert!(parse(&data).is_some());
+        for len in [0, 1, 11, 16, 20, data.len() - 4] {
+            assert!(parse(&data[..len]).is_none(), "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn count_past_the_end() {
+        let mut data = property(false, &[(STRING, "Net/ThemeName", b"Arc")]);
+        data[8] = 2;
+        assert!(parse(&data).is_none());
+    }
+}
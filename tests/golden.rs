@@ -0,0 +1,56 @@
+//! Golden output of machines recorded under `tests/fixtures`
+//! Each fixture is rendered with its own `config.toml` and compared to its
+//! `expected` file; run with `UPDATE_GOLDEN=1` to rewrite those after an
+//! intended change
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn fixtures_match_golden_output() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut machines: Vec<_> = fs::read_dir(&fixtures)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    machines.sort();
+    assert!(
+        !machines.is_empty(),
+        "no fixtures in {}",
+        fixtures.display()
+    );
+
+    let mut mismatches = Vec::new();
+    for machine in &machines {
+        let output = Command::new(env!("CARGO_BIN_EXE_tachi-fetch"))
+            .arg("--fixture")
+            .arg(machine)
+            .arg("--config")
+            .arg(machine.join("config.toml"))
+            // The full layout, as on a terminal, but without colors
+            .arg("--force-ansi")
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{} failed", machine.display());
+        let rendered = String::from_utf8(output.stdout).unwrap();
+
+        let expected_path = machine.join("expected");
+        if update {
+            fs::write(&expected_path, &rendered).unwrap();
+            continue;
+        }
+        let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+        if rendered != expected {
+            mismatches.push(format!(
+                "{}:\n--- expected\n{expected}\n--- rendered\n{rendered}",
+                machine.display()
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}